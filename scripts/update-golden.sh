@@ -0,0 +1,8 @@
+#!/bin/sh
+# Regenerate the golden files under tests/snapshots/ and show what changed.
+#
+# Usage: scripts/update-golden.sh [test name filter]
+set -e
+cd "$(dirname "$0")/.."
+TIJ_UPDATE_GOLDEN=1 cargo test --test golden -- "$@"
+git status --short tests/snapshots/
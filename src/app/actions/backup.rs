@@ -0,0 +1,177 @@
+//! Backup bookmarks for destructive operations
+//!
+//! Opt-in via `jj config set --user tij.backup-bookmarks true`. When enabled,
+//! abandon, op restore and force pushes leave a timestamped
+//! `tij-backup/...` bookmark on the state they are about to hide, so it can
+//! be found in the log (or restored with `jj new`/`jj edit`) long after the
+//! undo stack has moved on. Backups are ordinary local bookmarks: delete
+//! them from Bookmark View when no longer needed.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::app::helpers::revision::short_id;
+use crate::app::state::App;
+use crate::jj::JjError;
+use crate::jj::constants::config_keys;
+use crate::model::civil_from_days;
+
+/// Name prefix shared by all backup bookmarks
+const BACKUP_PREFIX: &str = "tij-backup/";
+
+/// `tij-backup/<UTC yyyymmdd-hhmmss>-<label>`
+fn backup_name(label: &str, now: SystemTime) -> String {
+    let secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let day_secs = secs % 86_400;
+    format!(
+        "{}{:04}{:02}{:02}-{:02}{:02}{:02}-{}",
+        BACKUP_PREFIX,
+        year,
+        month,
+        day,
+        day_secs / 3600,
+        (day_secs % 3600) / 60,
+        day_secs % 60,
+        label
+    )
+}
+
+/// Notification suffix naming the backups that were created
+pub(super) fn backup_suffix(backups: &[String]) -> String {
+    if backups.is_empty() {
+        String::new()
+    } else {
+        format!(", backup: {}", backups.join(", "))
+    }
+}
+
+impl App {
+    /// Apply `tij.backup-bookmarks` (bool, off by default)
+    pub(crate) fn load_backup_setting(&mut self) {
+        self.backup_bookmarks = self
+            .jj
+            .config_get(config_keys::BACKUP_BOOKMARKS)
+            .ok()
+            .flatten()
+            .is_some_and(|value| value == "true");
+    }
+
+    /// Create a backup bookmark on `revision` when backups are enabled
+    ///
+    /// Returns the bookmark name, or `None` when backups are disabled.
+    pub(super) fn create_backup_bookmark(
+        &mut self,
+        label: &str,
+        revision: &str,
+    ) -> Result<Option<String>, JjError> {
+        if !self.backup_bookmarks {
+            return Ok(None);
+        }
+        let name = backup_name(label, SystemTime::now());
+        self.run_and_record("Backup", &["bookmark", "create", &name, "-r", revision])?;
+        Ok(Some(name))
+    }
+
+    /// Back up every commit of `revision` before it is abandoned
+    ///
+    /// The commits are resolved to full IDs first: once abandoned, a change
+    /// ID no longer names them.
+    pub(super) fn backup_abandoned(&mut self, revision: &str) -> Result<Vec<String>, JjError> {
+        let mut backups = Vec::new();
+        if !self.backup_bookmarks {
+            return Ok(backups);
+        }
+        for commit_id in self.jj.commit_ids(revision)? {
+            let label = format!("abandon-{}", short_id(&commit_id, self.id_length));
+            if let Some(backup) = self.create_backup_bookmark(&label, &commit_id)? {
+                backups.push(backup);
+            }
+        }
+        Ok(backups)
+    }
+
+    /// Back up remote bookmark targets that pushing `names` would orphan
+    ///
+    /// A push is a force push when the remote bookmark points at a commit
+    /// the local bookmark doesn't contain; that commit gets the backup.
+    pub(super) fn backup_force_push_targets(
+        &mut self,
+        names: &[String],
+    ) -> Result<Vec<String>, JjError> {
+        let mut backups = Vec::new();
+        if !self.backup_bookmarks {
+            return Ok(backups);
+        }
+        for name in names {
+            let revset = format!(
+                "latest(remote_bookmarks(exact:{0:?}) ~ ::bookmarks(exact:{0:?}))",
+                name
+            );
+            // Empty revset (fast-forward or new bookmark): nothing to lose
+            let Ok((_, commit_id)) = self.jj.full_ids(&revset) else {
+                continue;
+            };
+            let label = format!("push-{}", name);
+            if let Some(backup) = self.create_backup_bookmark(&label, &commit_id)? {
+                backups.push(backup);
+            }
+        }
+        Ok(backups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_backup_name_uses_utc_timestamp() {
+        // 2026-10-15 14:25:30 UTC
+        let now = UNIX_EPOCH + Duration::from_secs(1_792_074_330);
+        assert_eq!(
+            backup_name("abandon", now),
+            "tij-backup/20261015-142530-abandon"
+        );
+    }
+
+    #[test]
+    fn test_backup_suffix() {
+        assert_eq!(backup_suffix(&[]), "");
+        assert_eq!(
+            backup_suffix(&["tij-backup/a".to_string(), "tij-backup/b".to_string()]),
+            ", backup: tij-backup/a, tij-backup/b"
+        );
+    }
+
+    #[test]
+    fn test_create_backup_disabled_by_default() {
+        let mut app = App::new_for_test();
+        assert_eq!(app.create_backup_bookmark("abandon", "@").unwrap(), None);
+        assert!(
+            app.backup_force_push_targets(&["main".to_string()])
+                .unwrap()
+                .is_empty()
+        );
+        assert!(app.command_history.is_empty());
+    }
+
+    #[test]
+    fn test_abandon_backup_targets_the_abandoned_revision() {
+        // jj is not available here: resolving the revision fails, so the
+        // abandon is aborted instead of running without a backup
+        let mut app = App::new_for_test();
+        app.backup_bookmarks = true;
+        app.execute_abandon("kkmpptxz");
+        let entries = app.jj.command_log().entries();
+        assert!(entries.iter().any(|entry| {
+            entry.args.contains(&"log".to_string()) && entry.args.contains(&"kkmpptxz".to_string())
+        }));
+        assert!(
+            !entries
+                .iter()
+                .any(|entry| entry.args.contains(&"abandon".to_string()))
+        );
+        assert!(app.error_message.is_some());
+    }
+}
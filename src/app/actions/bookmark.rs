@@ -1,8 +1,8 @@
 //! Bookmark operations (create, move, delete, rename, forget, track, jump)
 
 use crate::app::helpers::revision::short_id;
-use crate::model::Notification;
-use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+use crate::model::{BookmarkDeleteImpact, Notification};
+use crate::ui::components::{BatchResult, Dialog, DialogCallback, SelectItem};
 
 use crate::app::state::{App, DirtyFlags, View};
 
@@ -68,7 +68,7 @@ impl App {
             .map(|c| c.display_description().to_string())
             .unwrap_or_default();
 
-        let to_id_short = &to_change_id[..8.min(to_change_id.len())];
+        let to_id_short = short_id(to_change_id, self.id_length);
 
         match from_info {
             Some((from_id, from_desc)) => {
@@ -114,28 +114,81 @@ impl App {
             return;
         }
 
-        // Create selection dialog
+        let message = format!(
+            "Select bookmarks to delete from {}:",
+            short_id(&change_id, self.id_length)
+        );
+        self.open_bookmark_delete_dialog(message, &bookmarks, false);
+    }
+
+    /// Confirm deletion of a single bookmark (from Bookmark View)
+    pub(crate) fn start_bookmark_delete_named(&mut self, name: &str) {
+        let bookmarks = [name.to_string()];
+        self.open_bookmark_delete_dialog("Delete this bookmark?".to_string(), &bookmarks, true);
+    }
+
+    /// Show the delete selection dialog with the impact of each deletion
+    fn open_bookmark_delete_dialog(
+        &mut self,
+        message: String,
+        bookmarks: &[String],
+        preselected: bool,
+    ) {
         let items: Vec<SelectItem> = bookmarks
             .iter()
             .map(|name| SelectItem {
                 label: name.clone(),
                 value: name.clone(),
-                selected: false,
+                selected: preselected,
             })
             .collect();
 
+        let mut detail: Vec<String> = self
+            .bookmark_delete_impact(bookmarks)
+            .iter()
+            .map(BookmarkDeleteImpact::summary)
+            .collect();
+        detail.push("Deletions will propagate to remotes on push.".to_string());
+
         self.active_dialog = Some(Dialog::select(
             "Delete Bookmarks",
-            format!(
-                "Select bookmarks to delete from {}:",
-                &change_id[..8.min(change_id.len())]
-            ),
+            message,
             items,
-            Some("Deletions will propagate to remotes on push.".to_string()),
+            Some(detail.join("\n")),
             DialogCallback::DeleteBookmarks,
         ));
     }
 
+    /// Tracked remotes and orphaned commits for each bookmark
+    ///
+    /// Returns an empty list if jj cannot answer, so the dialog still opens.
+    fn bookmark_delete_impact(&self, names: &[String]) -> Vec<BookmarkDeleteImpact> {
+        let Ok(all) = self.jj.bookmark_list_all() else {
+            return Vec::new();
+        };
+        names
+            .iter()
+            .map(|name| {
+                let tracked_remotes = all
+                    .iter()
+                    .filter(|b| &b.name == name && b.is_tracked)
+                    .filter_map(|b| b.remote.clone())
+                    .filter(|remote| remote != "git")
+                    .collect();
+                let orphaned_commits = self
+                    .jj
+                    .count_revisions(&orphaned_on_delete_revset(name))
+                    .ok()?;
+                Some(BookmarkDeleteImpact {
+                    name: name.clone(),
+                    tracked_remotes,
+                    orphaned_commits,
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default()
+    }
+
     /// Execute bookmark deletion
     pub(crate) fn execute_bookmark_delete(&mut self, names: &[String]) {
         if names.is_empty() {
@@ -147,6 +200,11 @@ impl App {
         args.extend(&name_refs);
         let msg = format!("Deleted bookmarks: {}", names.join(", "));
         let result = self.run_and_record("Bookmark delete", &args);
+        if result.is_err() && names.len() > 1 {
+            // One bad name fails the whole command; delete one by one to
+            // find out which
+            return self.delete_bookmarks_individually(names);
+        }
         self.run_jj_action(
             result,
             "Failed to delete bookmarks",
@@ -155,6 +213,21 @@ impl App {
         );
     }
 
+    /// Delete each bookmark with its own command and list the outcomes
+    fn delete_bookmarks_individually(&mut self, names: &[String]) {
+        let rows: Vec<BatchResult> = names
+            .iter()
+            .map(|name| {
+                match self.run_and_record("Bookmark delete", &["bookmark", "delete", name]) {
+                    Ok(_) => BatchResult::ok(name),
+                    Err(e) => BatchResult::failed(name, e.to_string()),
+                }
+            })
+            .collect();
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+        self.show_batch_results("Delete Results", "Delete bookmarks".to_string(), rows);
+    }
+
     /// Execute bookmark rename
     pub(crate) fn execute_bookmark_rename(&mut self, old_name: &str, new_name: &str) {
         if old_name == new_name {
@@ -214,7 +287,7 @@ impl App {
             .map(|info| {
                 let id = info.change_id.as_ref().map(|id| id.as_str()).unwrap_or("?");
                 let desc = info.description.as_deref().unwrap_or("(no description)");
-                let short_id = short_id(id);
+                let short_id = short_id(id, self.id_length);
                 format!("{} {}", short_id, desc)
             })
             .unwrap_or_else(|| "?".to_string());
@@ -230,7 +303,7 @@ impl App {
                 } else {
                     &c.description
                 };
-                let short_id = c.change_id.short();
+                let short_id = c.change_id.short(self.id_length);
                 format!("{} {}", short_id, desc)
             })
             .unwrap_or_else(|| "@".to_string());
@@ -375,7 +448,7 @@ impl App {
     pub(crate) fn jump_to_log(&mut self, change_id: &str) {
         // Step 1: Try to find in current log view
         if self.log_view.select_change_by_prefix(change_id) {
-            let short_id = short_id(change_id);
+            let short_id = short_id(change_id, self.id_length);
             self.notify_success(format!("Jumped to {} in log", short_id));
             self.pending_jump_change_id = None;
             self.previous_view = None;
@@ -400,7 +473,7 @@ impl App {
             }
 
             if self.log_view.select_change_by_prefix(change_id) {
-                let short_id = short_id(change_id);
+                let short_id = short_id(change_id, self.id_length);
                 self.notify_success(format!(
                     "Jumped to {} (revset expanded, r+Enter to reset)",
                     short_id
@@ -420,7 +493,7 @@ impl App {
     /// Execute bookmark jump - select the change in log view
     pub(crate) fn execute_bookmark_jump(&mut self, change_id: &str) {
         if self.log_view.select_change_by_id(change_id) {
-            let short_id = short_id(change_id);
+            let short_id = short_id(change_id, self.id_length);
             self.notify_success(format!("Jumped to {}", short_id));
         } else {
             // The change might not be visible in current revset
@@ -432,8 +505,11 @@ impl App {
     pub(crate) fn open_bookmark_view(&mut self) {
         match self.jj.bookmark_list_with_info() {
             Ok(bookmarks) => {
+                self.log_view.bookmark_tracking =
+                    super::local_tracking_map(bookmarks.iter().map(|info| &info.bookmark));
                 self.bookmark_view.set_bookmarks(bookmarks);
                 self.go_to_view(View::Bookmark);
+                self.start_open_request_query();
             }
             Err(e) => {
                 self.set_error(format!("Failed to list bookmarks: {}", e));
@@ -445,6 +521,8 @@ impl App {
     pub(crate) fn refresh_bookmark_view(&mut self) {
         match self.jj.bookmark_list_with_info() {
             Ok(bookmarks) => {
+                self.log_view.bookmark_tracking =
+                    super::local_tracking_map(bookmarks.iter().map(|info| &info.bookmark));
                 self.bookmark_view.set_bookmarks(bookmarks);
             }
             Err(e) => {
@@ -458,13 +536,12 @@ impl App {
         let display = full_name.split('@').next().unwrap_or(full_name);
         let msg = format!("Stopped tracking: {}", display);
         let result = self.run_and_record("Bookmark untrack", &["bookmark", "untrack", full_name]);
-        let dirty = DirtyFlags {
-            log: true,
-            status: true,
-            op_log: true,
-            bookmarks: true,
-        };
-        self.run_jj_action(result, "Failed to untrack", &msg, dirty);
+        self.run_jj_action(
+            result,
+            "Failed to untrack",
+            &msg,
+            DirtyFlags::log_and_bookmarks(),
+        );
     }
 
     /// Execute track for selected bookmarks
@@ -483,13 +560,12 @@ impl App {
         let mut track_args: Vec<&str> = vec!["bookmark", "track"];
         track_args.extend(&name_refs);
         let result = self.run_and_record("Bookmark track", &track_args);
-        let dirty = DirtyFlags {
-            log: true,
-            status: true,
-            op_log: true,
-            bookmarks: true,
-        };
-        self.run_jj_action(result, "Failed to track", &msg, dirty);
+        self.run_jj_action(
+            result,
+            "Failed to track",
+            &msg,
+            DirtyFlags::log_and_bookmarks(),
+        );
     }
 }
 
@@ -508,6 +584,21 @@ fn truncate_description(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Revset of unmerged commits only `name` keeps reachable by a name
+///
+/// Subtracts history reachable from other local bookmarks, other remote
+/// bookmarks (this bookmark's own remotes are deleted on push), tags and
+/// immutable heads.
+pub(crate) fn orphaned_on_delete_revset(name: &str) -> String {
+    let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+    let pattern = format!("exact:\"{}\"", escaped);
+    format!(
+        "::bookmarks({p}) ~ ::(bookmarks() ~ bookmarks({p})) \
+         ~ ::(remote_bookmarks() ~ remote_bookmarks({p})) ~ ::tags() ~ ::immutable_heads()",
+        p = pattern
+    )
+}
+
 /// Check if a JjError indicates that a bookmark already exists
 ///
 /// This is used to determine whether to fallback from `bookmark create` to `bookmark set`.
@@ -524,6 +615,22 @@ fn is_bookmark_exists_error(error: &crate::jj::JjError) -> bool {
 mod tests {
     use super::*;
     use crate::jj::JjError;
+    use crate::ui::components::DialogKind;
+
+    #[test]
+    fn test_failed_multi_delete_lists_each_bookmark() {
+        let mut app = App::new_for_test();
+        app.execute_bookmark_delete(&["main".to_string(), "feature".to_string()]);
+        // jj is not available in tests, so every single delete fails too
+        let dialog = app.active_dialog.as_ref().expect("results dialog");
+        assert_eq!(dialog.callback_id, DialogCallback::BatchResults);
+        let DialogKind::Results { rows, .. } = &dialog.kind else {
+            panic!("expected a Results dialog");
+        };
+        let items: Vec<&str> = rows.iter().map(|row| row.item.as_str()).collect();
+        assert_eq!(items, ["main", "feature"]);
+        assert!(rows.iter().all(|row| row.error.is_some()));
+    }
 
     #[test]
     fn test_is_bookmark_exists_error_with_already_exists() {
@@ -567,6 +674,21 @@ mod tests {
         assert!(!is_bookmark_exists_error(&error));
     }
 
+    #[test]
+    fn test_orphaned_on_delete_revset() {
+        assert_eq!(
+            orphaned_on_delete_revset("feature"),
+            "::bookmarks(exact:\"feature\") ~ ::(bookmarks() ~ bookmarks(exact:\"feature\")) \
+             ~ ::(remote_bookmarks() ~ remote_bookmarks(exact:\"feature\")) ~ ::tags() ~ ::immutable_heads()"
+        );
+    }
+
+    #[test]
+    fn test_orphaned_on_delete_revset_escapes_quotes() {
+        let revset = orphaned_on_delete_revset(r#"odd"name"#);
+        assert!(revset.contains(r#"exact:"odd\"name""#));
+    }
+
     #[test]
     fn test_truncate_description_short_string() {
         assert_eq!(truncate_description("hello", 10), "hello");
@@ -0,0 +1,167 @@
+//! Renamed remote bookmarks: reconcile the local bookmark after a fetch
+//!
+//! A bookmark renamed on the forge arrives as a deleted `old@remote` and a
+//! new untracked `new@remote`, leaving the local `old` without its remote.
+//! After a fetch, tracked remote bookmarks that disappeared are paired with
+//! new untracked ones on the same remote that point at the old target;
+//! unambiguous pairs are offered in a dialog, unchecked.
+
+use crate::app::state::{App, DirtyFlags};
+use crate::jj::JjError;
+use crate::model::BookmarkRename;
+use crate::ui::components::{BatchResult, Dialog, DialogCallback, SelectItem};
+
+impl App {
+    /// Remote bookmarks the fetch since `pre_op` looks to have renamed
+    fn detect_bookmark_renames(&self, pre_op: &str) -> Vec<BookmarkRename> {
+        let (Ok(before), Ok(after)) = (
+            self.jj.bookmark_targets(Some(pre_op)),
+            self.jj.bookmark_targets(None),
+        ) else {
+            return Vec::new();
+        };
+        BookmarkRename::unambiguous(BookmarkRename::candidates(&before, &after))
+    }
+
+    /// Whether `ancestor` is `descendant` or one of its ancestors
+    fn is_ancestor(&self, ancestor: &str, descendant: &str) -> bool {
+        self.jj
+            .count_revisions(&format!("{} & ::{}", ancestor, descendant))
+            .is_ok_and(|count| count > 0)
+    }
+
+    /// Offer to reconcile the bookmarks a fetch since `pre_op` renamed
+    pub(crate) fn offer_bookmark_reconcile(&mut self, pre_op: Option<&str>) {
+        let Some(pre_op) = pre_op else {
+            return;
+        };
+        let renames = self.detect_bookmark_renames(pre_op);
+        if renames.is_empty() {
+            return;
+        }
+        let items = renames
+            .iter()
+            .enumerate()
+            .map(|(index, rename)| SelectItem {
+                label: rename.label(),
+                value: index.to_string(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select(
+            "Renamed Bookmarks",
+            "Remote bookmarks this fetch looks to have renamed. Check the ones to track under the new name and move the local bookmark to (Space: toggle):",
+            items,
+            None,
+            DialogCallback::ReconcileBookmarks { renames },
+        ));
+    }
+
+    /// Reconcile the renames picked in the dialog (values are indices)
+    pub(crate) fn execute_bookmark_reconcile(
+        &mut self,
+        renames: &[BookmarkRename],
+        picked: &[String],
+    ) {
+        let picked: Vec<&BookmarkRename> = picked
+            .iter()
+            .filter_map(|index| renames.get(index.parse::<usize>().ok()?))
+            .collect();
+        if picked.is_empty() {
+            return;
+        }
+        let rows: Vec<BatchResult> = picked
+            .iter()
+            .map(|rename| match self.reconcile_bookmark(rename) {
+                Ok(()) => BatchResult::ok(rename.label()),
+                Err(e) => BatchResult::failed(rename.label(), e.to_string()),
+            })
+            .collect();
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+        if rows.iter().all(|row| row.error.is_none()) {
+            let names: Vec<&str> = picked.iter().map(|r| r.new_name.as_str()).collect();
+            self.notify_success(format!("Now tracking renamed: {}", names.join(", ")));
+        } else {
+            self.show_batch_results(
+                "Reconcile Results",
+                "Reconcile renamed bookmarks".to_string(),
+                rows,
+            );
+        }
+    }
+
+    /// Track `new@remote`, then hand the local `old` over to `new`
+    ///
+    /// Unpushed commits on the local `old` stay named: `new` moves forward
+    /// to them. `old` is forgotten rather than deleted, so that no push
+    /// deletes it on another remote.
+    fn reconcile_bookmark(&mut self, rename: &BookmarkRename) -> Result<(), JjError> {
+        let remote_bookmark = format!("{}@{}", rename.new_name, rename.remote);
+        self.run_and_record("Bookmark track", &["bookmark", "track", &remote_bookmark])?;
+        if let Some(local) = &rename.local_commit {
+            if *local != rename.new_commit && self.is_ancestor(&rename.new_commit, local) {
+                self.run_and_record(
+                    "Bookmark set",
+                    &["bookmark", "set", &rename.new_name, "-r", local],
+                )?;
+            }
+            self.run_and_record("Bookmark forget", &["bookmark", "forget", &rename.old_name])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(local_commit: Option<&str>) -> BookmarkRename {
+        BookmarkRename {
+            remote: "origin".into(),
+            old_name: "feat".into(),
+            new_name: "feature/login".into(),
+            old_commit: "c1".into(),
+            new_commit: "c1".into(),
+            local_commit: local_commit.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn test_offer_without_pre_op_does_nothing() {
+        let mut app = App::new_for_test();
+        app.offer_bookmark_reconcile(None);
+        assert!(app.active_dialog.is_none());
+    }
+
+    #[test]
+    fn test_reconcile_tracks_new_name_first() {
+        let mut app = App::new_for_test();
+        app.execute_bookmark_reconcile(&[rename(Some("c1"))], &["0".to_string()]);
+        // jj is not available here: the track fails and stops the rename
+        let entries = app.jj.command_log().entries();
+        assert!(entries.iter().any(|entry| {
+            entry.args.ends_with(&[
+                "bookmark".to_string(),
+                "track".to_string(),
+                "feature/login@origin".to_string(),
+            ])
+        }));
+        assert!(
+            !entries
+                .iter()
+                .any(|entry| entry.args.contains(&"forget".to_string()))
+        );
+        assert!(
+            app.active_dialog.is_some(),
+            "failure is listed in a results dialog"
+        );
+    }
+
+    #[test]
+    fn test_reconcile_ignores_unpicked() {
+        let mut app = App::new_for_test();
+        let before = app.jj.command_log().entries().len();
+        app.execute_bookmark_reconcile(&[rename(None)], &[]);
+        assert_eq!(app.jj.command_log().entries().len(), before);
+    }
+}
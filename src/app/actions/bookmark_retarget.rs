@@ -0,0 +1,246 @@
+//! Re-place bookmarks left on rewritten commits
+//!
+//! A bookmark still pointing at an old version of a rewritten change keeps
+//! that version visible as a divergent copy. Local bookmarks on divergent
+//! commits are matched with the latest visible rewrite of their commit
+//! (found through the evolution logs of the change's other commits) and
+//! offered in a dialog; moving them lets the old version be hidden. The
+//! evolution logs take one jj call per commit, so detection runs on a
+//! worker thread and the dialog opens when it is done.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::app::state::{App, DirtyFlags};
+use crate::jj::JjExecutor;
+use crate::jj::parser::parse_evolog;
+use crate::model::BookmarkRetarget;
+use crate::ui::components::{BatchResult, Dialog, DialogCallback, SelectItem};
+
+/// Local bookmarks whose commit has a single latest rewrite
+pub(crate) fn detect_bookmark_retargets(jj: &JjExecutor) -> Vec<BookmarkRetarget> {
+    let (Ok(targets), Ok(divergent)) = (jj.bookmark_targets(None), jj.divergent_ids("bookmarks()"))
+    else {
+        return Vec::new();
+    };
+    let mut successors: HashMap<String, Option<String>> = HashMap::new();
+    // Commits of one change are candidates for each of its other commits
+    let mut histories: HashMap<String, Option<Vec<String>>> = HashMap::new();
+    let mut retargets = Vec::new();
+    for target in targets.iter().filter(|t| t.remote.is_none()) {
+        let Some(commit) = &target.commit_id else {
+            continue;
+        };
+        let Some((change_id, _)) = divergent.iter().find(|(_, id)| id == commit) else {
+            continue;
+        };
+        let successor = successors
+            .entry(commit.clone())
+            .or_insert_with(|| find_successor(jj, &mut histories, change_id, commit));
+        if let Some(successor) = successor {
+            retargets.push(BookmarkRetarget {
+                name: target.name.clone(),
+                commit: commit.clone(),
+                successor: successor.clone(),
+            });
+        }
+    }
+    retargets
+}
+
+/// Latest visible rewrite of `commit` among the other commits of its change
+///
+/// `histories` caches the evolution log (commit IDs) of each commit asked.
+fn find_successor(
+    jj: &JjExecutor,
+    histories: &mut HashMap<String, Option<Vec<String>>>,
+    change_id: &str,
+    commit: &str,
+) -> Option<String> {
+    let others = jj
+        .commit_ids(&format!("change_id({}) ~ {}", change_id, commit))
+        .ok()?;
+    let candidates: Vec<(String, Vec<String>)> = others
+        .into_iter()
+        .filter_map(|id| {
+            let history = histories
+                .entry(id.clone())
+                .or_insert_with(|| {
+                    let evolog = jj.evolog(&id).ok()?;
+                    Some(
+                        parse_evolog(&evolog)
+                            .into_iter()
+                            .map(|entry| entry.commit_id.to_string())
+                            .collect(),
+                    )
+                })
+                .clone()?;
+            Some((id, history))
+        })
+        .collect();
+    BookmarkRetarget::latest_successor(commit, &candidates)
+}
+
+impl App {
+    /// Look for bookmarks left on rewritten commits in the background
+    pub(crate) fn offer_bookmark_retarget(&mut self) {
+        if self.bookmark_retarget_job.is_some() {
+            self.notify_info("Still looking for bookmarks left on rewritten commits...");
+            return;
+        }
+        let jj = self.jj.without_cancel_hook();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            // Receiver may be gone if the app quit mid-detection
+            let _ = tx.send(detect_bookmark_retargets(&jj));
+        });
+        self.bookmark_retarget_job = Some(rx);
+        self.notify_info("Looking for bookmarks left on rewritten commits...");
+    }
+
+    /// Offer to move the detected bookmarks to the rewrites
+    pub(crate) fn on_bookmark_retargets_found(&mut self, retargets: Vec<BookmarkRetarget>) {
+        if retargets.is_empty() {
+            self.notify_info("No bookmarks left on rewritten commits");
+            return;
+        }
+        let items = retargets
+            .iter()
+            .enumerate()
+            .map(|(index, retarget)| SelectItem {
+                label: retarget.label(self.id_length),
+                value: index.to_string(),
+                selected: true,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select(
+            "Re-place Bookmarks",
+            "Bookmarks left on an old version of a rewritten change. Move them to the latest rewrite (Space: toggle):",
+            items,
+            None,
+            DialogCallback::RetargetBookmarks { retargets },
+        ));
+    }
+
+    /// Move the bookmarks picked in the dialog (values are indices)
+    pub(crate) fn execute_bookmark_retarget(
+        &mut self,
+        retargets: &[BookmarkRetarget],
+        picked: &[String],
+    ) {
+        let picked: Vec<&BookmarkRetarget> = picked
+            .iter()
+            .filter_map(|index| retargets.get(index.parse::<usize>().ok()?))
+            .collect();
+        if picked.is_empty() {
+            return;
+        }
+        // The successor is a sibling of the old commit, not a descendant
+        let rows: Vec<BatchResult> = picked
+            .iter()
+            .map(|retarget| {
+                match self.run_and_record(
+                    "Bookmark set",
+                    &[
+                        "bookmark",
+                        "set",
+                        &retarget.name,
+                        "-r",
+                        &retarget.successor,
+                        "--allow-backwards",
+                    ],
+                ) {
+                    Ok(_) => BatchResult::ok(retarget.label(self.id_length)),
+                    Err(e) => BatchResult::failed(retarget.label(self.id_length), e.to_string()),
+                }
+            })
+            .collect();
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+        if rows.iter().all(|row| row.error.is_none()) {
+            let names: Vec<&str> = picked.iter().map(|r| r.name.as_str()).collect();
+            self.notify_success(format!("Re-placed: {}", names.join(", ")));
+        } else {
+            self.show_batch_results(
+                "Re-place Results",
+                "Move bookmarks to rewritten commits".to_string(),
+                rows,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn retarget() -> BookmarkRetarget {
+        BookmarkRetarget {
+            name: "feat".into(),
+            commit: "aaaa1111".into(),
+            successor: "cccc3333".into(),
+        }
+    }
+
+    #[test]
+    fn test_detection_runs_in_the_background() {
+        let mut app = App::new_for_test();
+        app.offer_bookmark_retarget();
+        assert!(app.bookmark_retarget_job.is_some());
+        assert!(app.active_dialog.is_none());
+        // jj is not available in tests, so nothing is detected
+        let retargets = app.bookmark_retarget_job.take().unwrap().recv().unwrap();
+        assert!(retargets.is_empty());
+    }
+
+    #[test]
+    fn test_no_candidates_only_notifies() {
+        let mut app = App::new_for_test();
+        app.on_bookmark_retargets_found(Vec::new());
+        assert!(app.active_dialog.is_none());
+        assert!(app.notification.is_some());
+    }
+
+    #[test]
+    fn test_found_retargets_open_the_dialog() {
+        use crate::app::{AppEvent, JobOutcome};
+
+        let mut app = App::new_for_test();
+        app.handle_event(AppEvent::JobFinished(JobOutcome::BookmarkRetargets {
+            retargets: vec![retarget()],
+        }));
+        assert!(matches!(
+            app.active_dialog.as_ref().map(|d| &d.callback_id),
+            Some(DialogCallback::RetargetBookmarks { .. })
+        ));
+    }
+
+    #[test]
+    fn test_retarget_sets_bookmark_on_successor() {
+        let mut app = App::new_for_test();
+        app.execute_bookmark_retarget(&[retarget()], &["0".to_string()]);
+        let entries = app.jj.command_log().entries();
+        assert!(entries.iter().any(|entry| {
+            entry.args.ends_with(&[
+                "bookmark".to_string(),
+                "set".to_string(),
+                "feat".to_string(),
+                "-r".to_string(),
+                "cccc3333".to_string(),
+                "--allow-backwards".to_string(),
+            ])
+        }));
+        assert!(
+            app.active_dialog.is_some(),
+            "failure is listed in a results dialog"
+        );
+    }
+
+    #[test]
+    fn test_retarget_ignores_unpicked() {
+        let mut app = App::new_for_test();
+        let before = app.jj.command_log().entries().len();
+        app.execute_bookmark_retarget(&[retarget()], &[]);
+        assert_eq!(app.jj.command_log().entries().len(), before);
+    }
+}
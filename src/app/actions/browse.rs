@@ -0,0 +1,174 @@
+//! Open the selection on the forge in a web browser
+//!
+//! `Ctrl+O` opens the selected change's commit page (Log View) or the
+//! current file at the current line (Diff/Blame View). URLs are built for
+//! GitHub, GitLab and Bitbucket from the push remote's URL. Other hosts or
+//! layouts are set with `tij.browse-commit-url` / `tij.browse-file-url`
+//! templates using `{host}`, `{repo}`, `{commit}`, `{path}` and `{line}`.
+
+use crate::app::forge::{ForgeRepo, expand_url_template, open_in_browser};
+use crate::app::state::{App, View};
+use crate::jj::constants::config_keys;
+
+/// What `Ctrl+O` opens
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BrowseTarget {
+    Commit {
+        revision: String,
+    },
+    File {
+        revision: String,
+        path: String,
+        line: Option<usize>,
+    },
+}
+
+impl BrowseTarget {
+    fn revision(&self) -> &str {
+        match self {
+            Self::Commit { revision } | Self::File { revision, .. } => revision,
+        }
+    }
+}
+
+impl App {
+    /// Target under the cursor of the current view
+    fn browse_target(&self) -> Option<BrowseTarget> {
+        match self.current_view {
+            View::Log => self
+                .log_view
+                .selected_change()
+                .map(|change| BrowseTarget::Commit {
+                    revision: change.commit_id.to_string(),
+                }),
+            View::Diff => {
+                let view = self.diff_view.as_ref()?;
+                // Compare/interdiff files are shown as of the "to" side
+                let revision = view.compare_info.as_ref().map_or_else(
+                    || view.revision.clone(),
+                    |info| info.to.commit_id.to_string(),
+                );
+                Some(match view.current_file_path() {
+                    Some(path) => BrowseTarget::File {
+                        revision,
+                        path,
+                        line: view.current_line_number(),
+                    },
+                    None => BrowseTarget::Commit { revision },
+                })
+            }
+            View::Blame => {
+                let view = self.blame_view.as_ref()?;
+                Some(BrowseTarget::File {
+                    revision: view.revision().unwrap_or("@").to_string(),
+                    path: view.file_path().to_string(),
+                    line: view.selected_line_number(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Web URL of `target` at `commit`: config template first, then the forge
+    fn browse_url(&self, target: &BrowseTarget, remote_url: &str, commit: &str) -> Option<String> {
+        let (key, path, line) = match target {
+            BrowseTarget::Commit { .. } => (config_keys::BROWSE_COMMIT_URL, "", None),
+            BrowseTarget::File { path, line, .. } => {
+                (config_keys::BROWSE_FILE_URL, path.as_str(), *line)
+            }
+        };
+        if let Some(template) = self.jj.config_get(key).ok().flatten() {
+            return expand_url_template(template.trim_matches('"'), remote_url, commit, path, line);
+        }
+        let forge = ForgeRepo::from_remote_url(remote_url)?;
+        Some(match target {
+            BrowseTarget::Commit { .. } => forge.commit_url(commit),
+            BrowseTarget::File { .. } => forge.file_url(commit, path, line),
+        })
+    }
+
+    /// Open the selected commit or file on the forge
+    pub(crate) fn browse_on_forge(&mut self) {
+        let Some(target) = self.browse_target() else {
+            self.notify_info("Nothing selected to open in the browser");
+            return;
+        };
+        let Some(remote_url) = self.remote_url(None) else {
+            self.set_error("No git remote to build a web URL from");
+            return;
+        };
+        let commit = match self.jj.full_ids(target.revision()) {
+            Ok((_, commit)) => commit,
+            Err(e) => {
+                self.set_error(format!("Failed to resolve {}: {}", target.revision(), e));
+                return;
+            }
+        };
+        let Some(url) = self.browse_url(&target, &remote_url, &commit) else {
+            self.set_error(format!(
+                "Remote is not on GitHub, GitLab or Bitbucket: set {} / {}",
+                config_keys::BROWSE_COMMIT_URL,
+                config_keys::BROWSE_FILE_URL
+            ));
+            return;
+        };
+
+        // The page only exists once the commit is pushed
+        let pushed = self
+            .jj
+            .count_revisions(&format!("{} & ::remote_bookmarks()", commit))
+            .is_ok_and(|count| count > 0);
+        match open_in_browser(&url) {
+            Ok(()) if pushed => self.notify_info(format!("Opened {}", url)),
+            Ok(()) => self.notify_warning(format!(
+                "Opened {} (commit not on a remote yet: the page may not exist)",
+                url
+            )),
+            Err(e) => self.set_error(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{AnnotationContent, AnnotationLine, ChangeId, CommitId};
+    use crate::ui::views::BlameView;
+
+    #[test]
+    fn test_blame_target_is_file_at_selected_line() {
+        let mut content = AnnotationContent::new("src/lib.rs".to_string());
+        content.lines.push(AnnotationLine {
+            change_id: ChangeId::new("change01".to_string()),
+            commit_id: CommitId::new("commit01".to_string()),
+            author: "test".to_string(),
+            timestamp: "2026-01-30 10:00".to_string(),
+            line_number: 1,
+            content: "line".to_string(),
+            first_in_hunk: true,
+        });
+        let mut view = BlameView::new();
+        view.set_content(content, None);
+
+        let mut app = App::new_for_test();
+        app.blame_view = Some(view);
+        app.current_view = View::Blame;
+        assert_eq!(
+            app.browse_target(),
+            Some(BrowseTarget::File {
+                revision: "@".to_string(),
+                path: "src/lib.rs".to_string(),
+                line: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_browse_without_selection_notifies() {
+        let mut app = App::new_for_test();
+        app.current_view = View::Status;
+        app.browse_on_forge();
+        let notification = app.notification.as_ref().unwrap();
+        assert!(notification.message.contains("Nothing selected"));
+    }
+}
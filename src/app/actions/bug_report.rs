@@ -0,0 +1,308 @@
+//! Bug report bundle
+//!
+//! `B` in Command History View writes one text file to attach to a GitHub
+//! issue: tij/jj versions, platform, the recent command log, errors shown
+//! this session and, unless left out, the effective jj config. The redacted
+//! variant masks identity/credential-like config values and the home
+//! directory.
+
+use std::time::SystemTime;
+
+use crate::app::state::App;
+use crate::model::{CommandRecord, CommandStatus};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+use crate::ui::views::format_timestamp;
+
+use super::unique_filename;
+
+/// Most recent commands included in the report
+const MAX_REPORT_COMMANDS: usize = 50;
+
+/// Config keys whose last segment contains one of these are masked
+const SENSITIVE_KEY_PARTS: &[&str] = &[
+    "email",
+    "name",
+    "token",
+    "password",
+    "secret",
+    "credential",
+    "auth",
+    "key",
+    "url",
+];
+
+/// Replacement for masked values
+const REDACTED: &str = "\"<redacted>\"";
+
+/// How to include the jj config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigMode {
+    Redacted,
+    Full,
+    Omitted,
+}
+
+impl ConfigMode {
+    const ALL: [Self; 3] = [Self::Redacted, Self::Full, Self::Omitted];
+
+    fn value(self) -> &'static str {
+        match self {
+            Self::Redacted => "redacted",
+            Self::Full => "full",
+            Self::Omitted => "omitted",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Redacted => "Include config (names, emails, URLs, tokens redacted)",
+            Self::Full => "Include config as is",
+            Self::Omitted => "Leave config out",
+        }
+    }
+
+    fn from_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.value() == value)
+    }
+}
+
+/// Everything the report is built from, gathered before formatting
+struct ReportInput<'a> {
+    jj_version: String,
+    view: String,
+    commands: Vec<&'a CommandRecord>,
+    total_commands: usize,
+    errors: Vec<&'a (SystemTime, String)>,
+    config: Option<String>,
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let last = key.rsplit('.').next().unwrap_or(key).to_lowercase();
+    SENSITIVE_KEY_PARTS.iter().any(|part| last.contains(part))
+}
+
+/// Mask sensitive values in `jj config list` output
+///
+/// Multi-line string values (`"""`/`'''`) of a masked key are dropped as a
+/// whole, not just their first line.
+fn redact_config(config: &str) -> String {
+    let mut out = Vec::new();
+    let mut skip_until: Option<&str> = None;
+    for line in config.lines() {
+        if let Some(delimiter) = skip_until {
+            if line.contains(delimiter) {
+                skip_until = None;
+            }
+            continue;
+        }
+        match line.split_once(" = ") {
+            Some((key, value)) if is_sensitive_key(key) => {
+                out.push(format!("{} = {}", key, REDACTED));
+                let value = value.trim();
+                for delimiter in ["\"\"\"", "'''"] {
+                    if value.starts_with(delimiter) && !value[3..].contains(delimiter) {
+                        skip_until = Some(delimiter);
+                    }
+                }
+            }
+            _ => out.push(line.to_string()),
+        }
+    }
+    out.join("\n")
+}
+
+/// `HH:MM:SS  OK  Operation  jj args  (N ms)` plus the error, if any
+fn format_command(record: &CommandRecord) -> String {
+    let status = match record.status {
+        CommandStatus::Success => "OK",
+        CommandStatus::Failed => "NG",
+    };
+    let mut line = format!(
+        "{}  {}  {:<12} jj {}  ({} ms)",
+        format_timestamp(&record.timestamp),
+        status,
+        record.operation,
+        record.args.join(" "),
+        record.duration_ms
+    );
+    if let Some(ref error) = record.error {
+        for error_line in error.lines() {
+            line.push_str("\n          ");
+            line.push_str(error_line);
+        }
+    }
+    line
+}
+
+fn format_report(input: &ReportInput) -> String {
+    let mut report = format!(
+        "tij bug report\n\
+         tij: {}\n\
+         jj: {}\n\
+         os: {} ({})\n\
+         TERM: {}\n\
+         view: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        input.jj_version,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        std::env::var("TERM").unwrap_or_else(|_| "(unset)".to_string()),
+        input.view,
+    );
+
+    report.push_str(&format!(
+        "\n--- recent errors ({}) ---\n",
+        input.errors.len()
+    ));
+    for (timestamp, message) in &input.errors {
+        report.push_str(&format!("{}  {}\n", format_timestamp(timestamp), message));
+    }
+
+    report.push_str(&format!(
+        "\n--- recent commands ({} of {}) ---\n",
+        input.commands.len(),
+        input.total_commands
+    ));
+    for record in &input.commands {
+        report.push_str(&format_command(record));
+        report.push('\n');
+    }
+
+    if let Some(ref config) = input.config {
+        report.push_str("\n--- jj config list ---\n");
+        report.push_str(config.trim_end());
+        report.push('\n');
+    }
+    report
+}
+
+impl App {
+    /// Ask how to include the config, then write the bundle
+    pub(crate) fn start_bug_report(&mut self) {
+        let items = ConfigMode::ALL
+            .into_iter()
+            .map(|mode| SelectItem {
+                label: mode.label().to_string(),
+                value: mode.value().to_string(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select_single(
+            "Bug Report",
+            "Versions, recent commands and errors are always included:",
+            items,
+            Some("Review the file before attaching: command arguments are kept".to_string()),
+            DialogCallback::BugReport,
+        ));
+    }
+
+    /// Write `tij-bug-report.txt` in the current directory
+    pub(crate) fn write_bug_report(&mut self, mode_value: &str) {
+        let Some(mode) = ConfigMode::from_value(mode_value) else {
+            return;
+        };
+        let jj_version = self
+            .jj
+            .version()
+            .unwrap_or_else(|e| format!("unavailable ({})", e));
+        let config = (mode != ConfigMode::Omitted).then(|| match self.jj.config_list() {
+            Ok(config) if mode == ConfigMode::Redacted => redact_config(&config),
+            Ok(config) => config,
+            Err(e) => format!("unavailable ({})", e),
+        });
+
+        let records = self.command_history.records();
+        let skip = records.len().saturating_sub(MAX_REPORT_COMMANDS);
+        let input = ReportInput {
+            jj_version,
+            view: format!("{:?}", self.current_view),
+            commands: records.iter().skip(skip).collect(),
+            total_commands: records.len(),
+            errors: self.recent_errors.iter().collect(),
+            config,
+        };
+        let mut report = format_report(&input);
+        if mode == ConfigMode::Redacted
+            && let Ok(home) = std::env::var("HOME")
+            && !home.is_empty()
+        {
+            report = report.replace(&home, "~");
+        }
+
+        let filename = unique_filename("tij-bug-report", "txt");
+        match std::fs::write(&filename, report) {
+            Ok(()) => self.notify_success(format!("Bug report saved to {}", filename)),
+            Err(e) => self.set_error(format!("Failed to write {}: {}", filename, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::MAX_RECENT_ERRORS;
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_redact_config_masks_sensitive_keys() {
+        let config = "user.name = \"Jane Doe\"\n\
+                      user.email = \"jane@example.com\"\n\
+                      ui.diff-editor = \":builtin\"\n\
+                      signing.key = \"\"\"\n\
+                      ssh-ed25519 AAAA\n\
+                      \"\"\"\n\
+                      tij.preview-ratio = 40";
+        assert_eq!(
+            redact_config(config),
+            "user.name = \"<redacted>\"\n\
+             user.email = \"<redacted>\"\n\
+             ui.diff-editor = \":builtin\"\n\
+             signing.key = \"<redacted>\"\n\
+             tij.preview-ratio = 40"
+        );
+    }
+
+    #[test]
+    fn test_format_report_sections() {
+        let record = CommandRecord {
+            operation: "Push".to_string(),
+            args: vec!["git".to_string(), "push".to_string()],
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(3600),
+            duration_ms: 1200,
+            status: CommandStatus::Failed,
+            error: Some("Error: refusing to push".to_string()),
+        };
+        let error = (SystemTime::UNIX_EPOCH, "Push failed: refusing".to_string());
+        let input = ReportInput {
+            jj_version: "jj 0.41.0".to_string(),
+            view: "Log".to_string(),
+            commands: vec![&record],
+            total_commands: 7,
+            errors: vec![&error],
+            config: None,
+        };
+        let report = format_report(&input);
+        assert!(report.starts_with("tij bug report\n"));
+        assert!(report.contains("jj: jj 0.41.0\n"));
+        assert!(report.contains("--- recent errors (1) ---\n"));
+        assert!(report.contains("  Push failed: refusing\n"));
+        assert!(report.contains("--- recent commands (1 of 7) ---\n"));
+        assert!(report.contains("  NG  Push         jj git push  (1200 ms)\n"));
+        assert!(report.contains("\n          Error: refusing to push\n"));
+        assert!(!report.contains("jj config list"));
+    }
+
+    #[test]
+    fn test_set_error_keeps_bounded_history() {
+        let mut app = App::new_for_test();
+        for i in 0..MAX_RECENT_ERRORS + 5 {
+            app.set_error(format!("error {}", i));
+        }
+        assert_eq!(app.recent_errors.len(), MAX_RECENT_ERRORS);
+        assert_eq!(app.recent_errors.front().unwrap().1, "error 5");
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some(app.recent_errors.back().unwrap().1.as_str())
+        );
+    }
+}
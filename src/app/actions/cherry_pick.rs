@@ -0,0 +1,167 @@
+//! Guided cherry-pick: bring a change from another branch onto @
+//!
+//! `@` in the Log View duplicates the selected revision into the working
+//! copy's stack in one confirmed step, either inserted below @
+//! (`jj duplicate -B @`, so @ is rebased on top of the copy) or as a new
+//! child of @ (`--onto @`). Focus moves to the copy, and if it came out
+//! conflicted the Resolve View opens on it.
+
+use std::time::Instant;
+
+use crate::app::helpers::revision::{is_root_by_commit_id, short_id};
+use crate::app::state::{App, DirtyFlags};
+use crate::jj::constants::{commands, flags};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Dialog value: insert the copy between @ and its parent
+const BELOW_WC: &str = "below";
+/// Dialog value: create the copy as a child of @
+const ONTO_WC: &str = "onto";
+
+/// `jj duplicate` arguments for a placement choice (None if unknown)
+fn duplicate_args<'a>(revision: &'a str, placement: &str) -> Option<Vec<&'a str>> {
+    let destination: &[&str] = match placement {
+        BELOW_WC => &[flags::INSERT_BEFORE, "@"],
+        ONTO_WC => &[flags::ONTO, "@"],
+        _ => return None,
+    };
+    let mut args = vec![commands::DUPLICATE, revision];
+    args.extend_from_slice(destination);
+    Some(args)
+}
+
+impl App {
+    /// Ask where to put a copy of `revision` in the working copy's stack
+    pub(crate) fn start_cherry_pick(&mut self, revision: &str) {
+        if is_root_by_commit_id(&self.log_view.changes, revision) {
+            self.notify_info("Cannot cherry-pick: root commit");
+            return;
+        }
+        if let Ok(count) = self.jj.count_revisions(&format!("({}) & ::@", revision))
+            && count > 0
+        {
+            self.notify_warning(format!(
+                "{} is already in @'s ancestry; use Y to duplicate it anyway",
+                short_id(revision, self.id_length)
+            ));
+            return;
+        }
+        let items = vec![
+            SelectItem {
+                label: "Insert below @ (jj duplicate -B @; @ is rebased onto it)".to_string(),
+                value: BELOW_WC.to_string(),
+                selected: false,
+            },
+            SelectItem {
+                label: "On top of @ (jj duplicate --onto @)".to_string(),
+                value: ONTO_WC.to_string(),
+                selected: false,
+            },
+        ];
+        self.active_dialog = Some(Dialog::select_single(
+            "Cherry-pick",
+            format!(
+                "Bring a copy of {} onto your stack:",
+                short_id(revision, self.id_length)
+            ),
+            items,
+            Some("The original stays where it is. Undo with 'u' if needed.".to_string()),
+            DialogCallback::CherryPick {
+                revision: revision.to_string(),
+            },
+        ));
+    }
+
+    /// Duplicate `revision` next to @, focus the copy and follow up on conflicts
+    pub(crate) fn execute_cherry_pick(&mut self, revision: &str, placement: &str) {
+        let Some(args) = duplicate_args(revision, placement) else {
+            return;
+        };
+        // `jj duplicate` reports the new change on stderr
+        let start = Instant::now();
+        let result = self.jj.run(&args);
+        self.record_command("Cherry-pick", &args, start, &result);
+        let output = match result {
+            Ok(r) => r.stderr,
+            Err(e) => {
+                self.set_error(format!("Cherry-pick failed: {}", e));
+                return;
+            }
+        };
+
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+        if self.error_message.is_some() {
+            return;
+        }
+
+        let Some(new_id) = Self::parse_duplicate_output(&output) else {
+            self.notify_success(format!(
+                "Cherry-picked {}",
+                short_id(revision, self.id_length)
+            ));
+            return;
+        };
+        let short = short_id(&new_id, self.id_length).to_string();
+        let visible = self.log_view.select_change_by_prefix(&new_id);
+
+        if self.jj.has_conflict(&new_id).unwrap_or(false) {
+            self.notify_warning(format!("Cherry-picked as {} with conflicts", short));
+            self.open_resolve_view(&new_id, false);
+        } else if visible {
+            self.notify_success(format!("Cherry-picked as {}", short));
+        } else {
+            self.notify_success(format!(
+                "Cherry-picked as {} (not in current revset)",
+                short
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::components::DialogKind;
+
+    #[test]
+    fn test_duplicate_args_inserts_below_wc() {
+        assert_eq!(
+            duplicate_args("abc123", BELOW_WC),
+            Some(vec!["duplicate", "abc123", "-B", "@"])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_args_puts_copy_on_top_of_wc() {
+        assert_eq!(
+            duplicate_args("abc123", ONTO_WC),
+            Some(vec!["duplicate", "abc123", "--onto", "@"])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_args_rejects_unknown_placement() {
+        assert_eq!(duplicate_args("abc123", "sideways"), None);
+    }
+
+    #[test]
+    fn test_start_cherry_pick_offers_both_placements() {
+        let mut app = App::new_for_test();
+        app.start_cherry_pick("abc123def456");
+        let dialog = app.active_dialog.as_ref().expect("select dialog");
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::CherryPick {
+                revision: "abc123def456".to_string(),
+            }
+        );
+        let DialogKind::Select { items, .. } = &dialog.kind else {
+            panic!("expected select dialog");
+        };
+        let values: Vec<&str> = items.iter().map(|i| i.value.as_str()).collect();
+        assert_eq!(values, vec![BELOW_WC, ONTO_WC]);
+        assert!(items.iter().all(|i| !i.selected));
+        assert!(items[0].label.contains("jj duplicate -B @"));
+        assert!(items[1].label.contains("jj duplicate --onto @"));
+    }
+}
@@ -0,0 +1,272 @@
+//! Named compare sessions
+//!
+//! A compare (`=` in Log View, `jj diff --from --to`) can be saved from the
+//! Diff View with `S`; sessions live in jj config, e.g.
+//! `jj config set --user tij.compare-sessions.release.from v1.0`.
+//! `+` in Log View lists them to re-open, export as a `.patch` or delete.
+
+use std::time::Instant;
+
+use crate::app::state::App;
+use crate::jj::constants::config_keys;
+use crate::model::{CompareRevisionInfo, CompareSession, DiffMode};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+use super::unique_filename;
+
+/// Session action: open the compare in Diff View
+const ACTION_OPEN: &str = "open";
+/// Session action: write `jj diff --git --from --to` to a `.patch` file
+const ACTION_EXPORT: &str = "export";
+/// Session action: remove the session from config
+const ACTION_DELETE: &str = "delete";
+
+/// Revision to store for one side of a compare
+///
+/// Prefers a bookmark so the session follows the branch as it moves (the
+/// typical release-branch delta); otherwise the change ID, which survives
+/// rewrites unlike the commit ID.
+fn session_revision(info: &CompareRevisionInfo) -> String {
+    info.bookmarks
+        .first()
+        .cloned()
+        .unwrap_or_else(|| info.change_id.to_string())
+}
+
+impl App {
+    /// List saved compare sessions
+    pub(crate) fn open_compare_session_menu(&mut self) {
+        let sessions = match self.jj.compare_sessions() {
+            Ok(sessions) => sessions,
+            Err(e) => {
+                self.set_error(format!("Failed to read compare sessions: {}", e));
+                return;
+            }
+        };
+        if sessions.is_empty() {
+            self.notify_info("No compare sessions: compare with = and press S in the diff to save");
+            return;
+        }
+
+        let items = sessions
+            .iter()
+            .map(|session| SelectItem {
+                label: format!("{}: {} → {}", session.name, session.from, session.to),
+                value: session.name.clone(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select_single(
+            "Compare Sessions",
+            "Select a session:",
+            items,
+            Some(format!(
+                "Stored in jj config under [{}]",
+                config_keys::COMPARE_SESSIONS
+            )),
+            DialogCallback::CompareSessionMenu,
+        ));
+    }
+
+    /// Ask for a name to save the compare shown in Diff View
+    pub(crate) fn start_save_compare_session(&mut self) {
+        let Some(info) = self
+            .diff_view
+            .as_ref()
+            .filter(|view| view.mode == DiffMode::Compare)
+            .and_then(|view| view.compare_info.as_ref())
+        else {
+            return;
+        };
+        let from = session_revision(&info.from);
+        let to = session_revision(&info.to);
+        self.active_dialog = Some(Dialog::input(
+            "Save Compare Session",
+            format!("Name for {} → {} (letters, digits, - and _)", from, to),
+            DialogCallback::CompareSessionSave { from, to },
+        ));
+    }
+
+    /// Handle confirmed compare session dialogs
+    pub(crate) fn handle_compare_session_dialog(
+        &mut self,
+        callback: DialogCallback,
+        values: Vec<String>,
+    ) {
+        match callback {
+            DialogCallback::CompareSessionMenu => {
+                let Some(name) = values.first() else { return };
+                let session = self
+                    .jj
+                    .compare_sessions()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|s| s.name == *name);
+                if let Some(session) = session {
+                    self.open_compare_session_actions(session);
+                }
+            }
+            DialogCallback::CompareSessionAction { name, from, to } => {
+                match values.first().map(String::as_str) {
+                    Some(ACTION_OPEN) => self.open_compare_diff(&from, &to),
+                    Some(ACTION_EXPORT) => self.export_compare_session(&name, &from, &to),
+                    Some(ACTION_DELETE) => {
+                        self.active_dialog = Some(Dialog::confirm(
+                            "Delete Compare Session",
+                            format!("Delete compare session '{}' ({} → {})?", name, from, to),
+                            None,
+                            DialogCallback::CompareSessionDelete { name },
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+            DialogCallback::CompareSessionSave { from, to } => {
+                let Some(name) = values.first() else { return };
+                self.save_compare_session(name.trim(), &from, &to);
+            }
+            DialogCallback::CompareSessionDelete { name } => {
+                self.delete_compare_session(&name);
+            }
+            _ => {}
+        }
+    }
+
+    fn open_compare_session_actions(&mut self, session: CompareSession) {
+        let item = |label: &str, value: &str| SelectItem {
+            label: label.to_string(),
+            value: value.to_string(),
+            selected: false,
+        };
+        self.active_dialog = Some(Dialog::select_single(
+            format!("Compare Session: {}", session.name),
+            format!("{} → {}", session.from, session.to),
+            vec![
+                item("Open diff", ACTION_OPEN),
+                item("Export as .patch", ACTION_EXPORT),
+                item("Delete session", ACTION_DELETE),
+            ],
+            None,
+            DialogCallback::CompareSessionAction {
+                name: session.name,
+                from: session.from,
+                to: session.to,
+            },
+        ));
+    }
+
+    fn save_compare_session(&mut self, name: &str, from: &str, to: &str) {
+        if !CompareSession::is_valid_name(name) {
+            self.set_error(format!(
+                "Invalid session name '{}': use letters, digits, '-' and '_'",
+                name
+            ));
+            return;
+        }
+
+        let start = Instant::now();
+        let result = self
+            .jj
+            .save_compare_session(name, from, to)
+            .map(|()| String::new());
+        let key = format!("{}.{}", config_keys::COMPARE_SESSIONS, name);
+        self.record_str_command(
+            "Save compare session",
+            &["config", "set", "--user", &key, from, to],
+            start,
+            &result,
+        );
+
+        match result {
+            Ok(_) => self.notify_success(format!("Saved compare session '{}'", name)),
+            Err(e) => self.set_error(format!("Failed to save compare session: {}", e)),
+        }
+    }
+
+    fn delete_compare_session(&mut self, name: &str) {
+        let start = Instant::now();
+        let result = self.jj.delete_compare_session(name);
+        let key = format!("{}.{}", config_keys::COMPARE_SESSIONS, name);
+        self.record_str_command(
+            "Delete compare session",
+            &["config", "unset", "--user", &key],
+            start,
+            &result,
+        );
+
+        match result {
+            Ok(_) => self.notify_success(format!("Deleted compare session '{}'", name)),
+            Err(e) => self.set_error(format!("Failed to delete compare session: {}", e)),
+        }
+    }
+
+    /// Write the session's diff to `<name>.patch` (git format)
+    fn export_compare_session(&mut self, name: &str, from: &str, to: &str) {
+        match self.jj.diff_range_git(from, to) {
+            Ok(text) => {
+                let filename = unique_filename(name, "patch");
+                match std::fs::write(&filename, &text) {
+                    Ok(()) => self.notify_success(format!("Exported to {}", filename)),
+                    Err(e) => self.set_error(format!("Failed to write {}: {}", filename, e)),
+                }
+            }
+            Err(e) => self.set_error(format!("Failed to get diff: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ChangeId, CommitId};
+
+    fn revision_info(bookmarks: &[&str]) -> CompareRevisionInfo {
+        CompareRevisionInfo {
+            change_id: ChangeId::new("kkmpptxz".to_string()),
+            commit_id: CommitId::new("7aa68914".to_string()),
+            bookmarks: bookmarks.iter().map(|b| b.to_string()).collect(),
+            author: String::new(),
+            timestamp: String::new(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_session_revision_prefers_bookmark() {
+        assert_eq!(
+            session_revision(&revision_info(&["release-1.x", "main"])),
+            "release-1.x"
+        );
+        assert_eq!(session_revision(&revision_info(&[])), "kkmpptxz");
+    }
+
+    #[test]
+    fn test_save_rejects_invalid_name() {
+        let mut app = App::new_for_test();
+        app.save_compare_session("my session", "main", "release");
+        assert!(
+            app.error_message
+                .as_ref()
+                .unwrap()
+                .contains("Invalid session name")
+        );
+    }
+
+    #[test]
+    fn test_session_action_delete_asks_for_confirmation() {
+        let mut app = App::new_for_test();
+        app.handle_compare_session_dialog(
+            DialogCallback::CompareSessionAction {
+                name: "release".to_string(),
+                from: "v1.0".to_string(),
+                to: "release-1.x".to_string(),
+            },
+            vec![ACTION_DELETE.to_string()],
+        );
+        let dialog = app.active_dialog.as_ref().unwrap();
+        assert!(matches!(
+            &dialog.callback_id,
+            DialogCallback::CompareSessionDelete { name } if name == "release"
+        ));
+    }
+}
@@ -0,0 +1,272 @@
+//! Startup health check of the jj configuration
+//!
+//! Problems that would otherwise surface deep inside a flow (no author
+//! identity on describe/commit, a merge editor that isn't installed when
+//! resolving, a malformed remote URL on push) are listed once at startup.
+//! Enter on an issue copies a command that fixes it; Esc dismisses the list.
+//! `tij.config-check = false` turns the check off.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::app::clipboard;
+use crate::app::state::App;
+use crate::jj::constants::config_keys;
+use crate::model::RemoteInfo;
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Editor settings whose program must be installed
+const EDITOR_KEYS: &[&str] = &["ui.merge-editor", "ui.diff-editor"];
+
+/// URL schemes git can push to and fetch from
+const URL_SCHEMES: &[&str] = &["https", "http", "ssh", "git", "file"];
+
+/// A config problem and the command that fixes it
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ConfigIssue {
+    problem: String,
+    fix: String,
+}
+
+/// Single-line `key = value` entries of `jj config list`, values unquoted
+pub(super) fn parse_config(config: &str) -> HashMap<&str, &str> {
+    config
+        .lines()
+        .filter_map(|line| line.split_once(" = "))
+        .map(|(key, value)| (key.trim(), value.trim().trim_matches('"')))
+        .collect()
+}
+
+/// Program an editor setting runs, `None` for built-in tools
+///
+/// The value is a tool name (`merge-tools.<name>.program` if configured, the
+/// name itself otherwise), a command line, or a `["program", args...]` array.
+pub(super) fn editor_program(value: &str, config: &HashMap<&str, &str>) -> Option<String> {
+    let value = value.trim_start_matches('[').trim_start();
+    let first = value
+        .split([',', ' ', ']'])
+        .next()
+        .unwrap_or_default()
+        .trim_matches(['"', '\'']);
+    if first.is_empty() || first.starts_with(':') {
+        return None;
+    }
+    let program = config
+        .get(format!("merge-tools.{}.program", first).as_str())
+        .copied()
+        .unwrap_or(first);
+    Some(program.to_string())
+}
+
+/// Whether git can use `url` as a remote URL
+///
+/// scp-like `user@host:path` and local paths are accepted as they are;
+/// URLs with a scheme need a known scheme and a host.
+fn is_valid_remote_url(url: &str) -> bool {
+    if url.is_empty() || url.chars().any(char::is_whitespace) {
+        return false;
+    }
+    match url.split_once("://") {
+        Some((scheme, rest)) => {
+            URL_SCHEMES.contains(&scheme) && (scheme == "file" || !rest.starts_with('/'))
+        }
+        None => true,
+    }
+}
+
+/// Problems in `jj config list` output and the remote URLs
+fn check_config(
+    config: &str,
+    remotes: &[RemoteInfo],
+    program_exists: impl Fn(&str) -> bool,
+) -> Vec<ConfigIssue> {
+    let config = parse_config(config);
+    let mut issues = Vec::new();
+
+    for (key, example) in [
+        ("user.name", "Your Name"),
+        ("user.email", "you@example.com"),
+    ] {
+        if config.get(key).is_none_or(|value| value.is_empty()) {
+            issues.push(ConfigIssue {
+                problem: format!("{} is not set: new commits get no author", key),
+                fix: format!("jj config set --user {} \"{}\"", key, example),
+            });
+        }
+    }
+
+    for key in EDITOR_KEYS {
+        if let Some(program) = config
+            .get(key)
+            .and_then(|value| editor_program(value, &config))
+            && !program_exists(&program)
+        {
+            issues.push(ConfigIssue {
+                problem: format!("{}: '{}' is not installed or not on PATH", key, program),
+                fix: format!("jj config set --user {} :builtin", key),
+            });
+        }
+    }
+
+    for remote in remotes {
+        if !is_valid_remote_url(&remote.url) {
+            issues.push(ConfigIssue {
+                problem: format!(
+                    "Remote '{}' has an unusable URL: {}",
+                    remote.name, remote.url
+                ),
+                fix: format!("jj git remote set-url {} <url>", remote.name),
+            });
+        }
+    }
+    issues
+}
+
+/// Absolute paths are checked directly, bare names on PATH
+pub(super) fn program_exists(program: &str) -> bool {
+    let path = Path::new(program);
+    if path.is_absolute() {
+        path.exists()
+    } else {
+        clipboard::is_available(program)
+    }
+}
+
+impl App {
+    /// List config problems at startup (unless `tij.config-check = false`)
+    pub(crate) fn run_config_check(&mut self) {
+        let enabled = self
+            .jj
+            .config_get(config_keys::CONFIG_CHECK)
+            .ok()
+            .flatten()
+            .is_none_or(|value| value != "false");
+        if !enabled || self.active_dialog.is_some() {
+            return;
+        }
+        // The defaults define the built-in merge tools (`vscode` runs `code`)
+        let Ok(config) = self.jj.config_list_with_defaults() else {
+            return;
+        };
+        let remotes = self.jj.git_remote_list_with_urls().unwrap_or_default();
+        let issues = check_config(&config, &remotes, program_exists);
+        self.show_config_issues(issues);
+    }
+
+    fn show_config_issues(&mut self, issues: Vec<ConfigIssue>) {
+        if issues.is_empty() {
+            return;
+        }
+        let count = issues.len();
+        let items = issues
+            .into_iter()
+            .map(|issue| SelectItem {
+                label: issue.problem,
+                value: issue.fix,
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select_single(
+            "Config Check",
+            format!(
+                "{} problem(s) in the jj config. Enter copies the fix:",
+                count
+            ),
+            items,
+            Some(format!(
+                "Esc dismisses. Disable with: jj config set --user {} false",
+                config_keys::CONFIG_CHECK
+            )),
+            DialogCallback::ConfigCheck,
+        ));
+    }
+
+    /// Copy the fix command of the chosen issue
+    pub(crate) fn copy_config_fix(&mut self, fix: &str) {
+        match clipboard::copy_to_clipboard(fix) {
+            Ok(()) => self.notify_success(format!("Copied: {}", fix)),
+            // No clipboard: the command is still readable in the message
+            Err(_) => self.notify_info(format!("Run: {}", fix)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote(name: &str, url: &str) -> RemoteInfo {
+        RemoteInfo {
+            name: name.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_config_reports_identity_editor_and_remote() {
+        let config = "user.name = \"Jane\"\n\
+                      user.email = \"\"\n\
+                      ui.merge-editor = \"meld\"\n\
+                      ui.diff-editor = \":builtin\"\n";
+        let remotes = [
+            remote("origin", "git@github.com:user/repo.git"),
+            remote("broken", "htps://github.com/user/repo"),
+        ];
+        let issues = check_config(config, &remotes, |_| false);
+        let problems: Vec<&str> = issues.iter().map(|i| i.problem.as_str()).collect();
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+        assert!(problems[0].starts_with("user.email"));
+        assert!(problems[1].contains("'meld'"));
+        assert!(problems[2].contains("'broken'"));
+        assert_eq!(issues[2].fix, "jj git remote set-url broken <url>");
+    }
+
+    #[test]
+    fn test_check_config_clean() {
+        let config = "user.name = \"Jane\"\nuser.email = \"jane@example.com\"\n";
+        let remotes = [remote("local", "../repo.git")];
+        assert!(check_config(config, &remotes, |_| false).is_empty());
+    }
+
+    #[test]
+    fn test_check_config_resolves_built_in_merge_tools() {
+        // `jj config list --include-defaults` lists the built-in tools
+        let config = "user.name = \"Jane\"\n\
+                      user.email = \"jane@example.com\"\n\
+                      merge-tools.vscode.program = \"code\"\n\
+                      ui.merge-editor = \"vscode\"\n";
+        assert!(check_config(config, &[], |program| program == "code").is_empty());
+        let issues = check_config(config, &[], |_| false);
+        assert!(issues[0].problem.contains("'code'"));
+    }
+
+    #[test]
+    fn test_editor_program_forms() {
+        let config = parse_config("merge-tools.kdiff.program = \"/opt/kdiff3\"\n");
+        assert_eq!(editor_program(":builtin", &config), None);
+        assert_eq!(
+            editor_program("kdiff", &config).as_deref(),
+            Some("/opt/kdiff3")
+        );
+        assert_eq!(
+            editor_program("[\"vimdiff\", \"$left\"]", &config).as_deref(),
+            Some("vimdiff")
+        );
+        assert_eq!(
+            editor_program("code --wait", &config).as_deref(),
+            Some("code")
+        );
+    }
+
+    #[test]
+    fn test_is_valid_remote_url() {
+        assert!(is_valid_remote_url("https://github.com/user/repo"));
+        assert!(is_valid_remote_url("ssh://git@host:22/repo.git"));
+        assert!(is_valid_remote_url("file:///srv/repo.git"));
+        assert!(is_valid_remote_url("user@host:repo.git"));
+        assert!(!is_valid_remote_url(""));
+        assert!(!is_valid_remote_url("https:///repo"));
+        assert!(!is_valid_remote_url("htps://github.com/user/repo"));
+        assert!(!is_valid_remote_url("https://github.com/user/my repo"));
+    }
+}
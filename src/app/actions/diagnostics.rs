@@ -0,0 +1,345 @@
+//! Environment diagnostics (`D` in Command History View)
+//!
+//! Collects what support conversations usually start by asking for: tij,
+//! jj and git versions, the configured editors and merge tools (and whether
+//! their programs are installed), the remotes with their protocols, and
+//! which optional tij features the environment supports, including the jj
+//! features that need a minimum version.
+
+use std::collections::HashMap;
+use std::process::Command;
+
+use super::config_check::{editor_program, parse_config, program_exists};
+use super::keymap_check::keymap_sections;
+use crate::app::clipboard;
+use crate::app::state::{App, View};
+use crate::jj::constants::config_keys;
+use crate::jj::{feature_gates, parse_jj_version};
+use crate::model::{
+    DiagnosticItem, DiagnosticSection, DiagnosticStatus, RemoteInfo, format_diagnostics,
+};
+
+/// Editor and tool settings shown with their program's availability
+const TOOL_KEYS: &[&str] = &[
+    "ui.editor",
+    "ui.diff-editor",
+    "ui.merge-editor",
+    "ui.pager",
+    config_keys::DIFF_TOOL,
+];
+
+/// Program `open_in_browser` starts
+const BROWSER_OPENER: &str = if cfg!(target_os = "macos") {
+    "open"
+} else {
+    "xdg-open"
+};
+
+/// Transport git uses for a remote URL
+fn remote_protocol(url: &str) -> &'static str {
+    match url.split_once("://") {
+        Some(("https", _)) => "https",
+        Some(("http", _)) => "http",
+        Some(("ssh" | "git+ssh" | "ssh+git", _)) => "ssh",
+        Some(("git", _)) => "git",
+        Some(("file", _)) => "file",
+        Some(_) => "unknown",
+        None if url.is_empty() => "none",
+        // scp-like `[user@]host:path`; a `/` before the `:` means a local path
+        None => match url.split_once(':') {
+            Some((host, _)) if !host.contains('/') && host.len() > 1 => "ssh",
+            _ => "local path",
+        },
+    }
+}
+
+/// Editor/tool settings with the availability of the program they run
+fn tool_items(config: &HashMap<&str, &str>, exists: impl Fn(&str) -> bool) -> Vec<DiagnosticItem> {
+    TOOL_KEYS
+        .iter()
+        .map(
+            |key| match config.get(key).filter(|value| !value.is_empty()) {
+                None => DiagnosticItem::info(*key, "(not set)"),
+                Some(value) => match editor_program(value, config) {
+                    None => DiagnosticItem::info(*key, *value),
+                    Some(program) if exists(&program) => {
+                        DiagnosticItem::new(*key, *value, DiagnosticStatus::Available)
+                    }
+                    Some(program) => DiagnosticItem::new(
+                        *key,
+                        format!("{} ('{}' not found)", value, program),
+                        DiagnosticStatus::Missing,
+                    ),
+                },
+            },
+        )
+        .collect()
+}
+
+/// Features that need a minimum jj version, checked against `jj_version`
+fn gate_items(jj_version: &str) -> Vec<DiagnosticItem> {
+    let Some(version) = parse_jj_version(jj_version) else {
+        return vec![DiagnosticItem::new(
+            "jj version",
+            "could not be read",
+            DiagnosticStatus::Missing,
+        )];
+    };
+    feature_gates(version)
+        .into_iter()
+        .map(|(feature, (major, minor), ok)| {
+            let status = if ok {
+                DiagnosticStatus::Available
+            } else {
+                DiagnosticStatus::Missing
+            };
+            DiagnosticItem::new(feature, format!("jj {}.{}+", major, minor), status)
+        })
+        .collect()
+}
+
+fn remote_items(remotes: &[RemoteInfo]) -> Vec<DiagnosticItem> {
+    if remotes.is_empty() {
+        return vec![DiagnosticItem::info("(none)", "no git remotes")];
+    }
+    remotes
+        .iter()
+        .map(|remote| {
+            DiagnosticItem::info(
+                &remote.name,
+                format!("{} [{}]", remote.url, remote_protocol(&remote.url)),
+            )
+        })
+        .collect()
+}
+
+/// Optional features and what they depend on
+///
+/// `clipboard_tool` is the detected clipboard program; `available` checks
+/// programs on PATH.
+fn feature_items(
+    config: &HashMap<&str, &str>,
+    clipboard_tool: Option<&str>,
+    available: impl Fn(&str) -> bool,
+) -> Vec<DiagnosticItem> {
+    let program = |label: &str, program: &str| {
+        if available(program) {
+            DiagnosticItem::new(label, program, DiagnosticStatus::Available)
+        } else {
+            DiagnosticItem::new(
+                label,
+                format!("{} not on PATH", program),
+                DiagnosticStatus::Missing,
+            )
+        }
+    };
+    let mut items = vec![match clipboard_tool {
+        Some(tool) => DiagnosticItem::new("Clipboard copy", tool, DiagnosticStatus::Available),
+        None => DiagnosticItem::new(
+            "Clipboard copy",
+            "no pbcopy/wl-copy/xclip/xsel (kill ring only)",
+            DiagnosticStatus::Missing,
+        ),
+    }];
+    items.push(program("Create GitHub PRs", "gh"));
+    items.push(program("Create GitLab MRs", "glab"));
+    if !cfg!(target_os = "windows") {
+        items.push(program("Open in browser", BROWSER_OPENER));
+    }
+    items.push(
+        match config.get(config_keys::DIFF_TOOL).filter(|v| !v.is_empty()) {
+            Some(tool) => DiagnosticItem::new("Difftool", *tool, DiagnosticStatus::Available),
+            None => DiagnosticItem::new(
+                "Difftool",
+                format!("{} not set", config_keys::DIFF_TOOL),
+                DiagnosticStatus::Missing,
+            ),
+        },
+    );
+    items.push(DiagnosticItem::info(
+        "Command log file",
+        config
+            .get(config_keys::COMMAND_LOG_FILE)
+            .filter(|v| !v.is_empty())
+            .copied()
+            .unwrap_or("off"),
+    ));
+    items.push(DiagnosticItem::info(
+        "Startup config check",
+        if config.get(config_keys::CONFIG_CHECK) == Some(&"false") {
+            "off"
+        } else {
+            "on"
+        },
+    ));
+    items
+}
+
+/// First line of `git --version`
+fn git_version() -> String {
+    match Command::new("git").arg("--version").output() {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+        Ok(output) => format!("failed (exit {})", output.status.code().unwrap_or(-1)),
+        Err(e) => format!("unavailable ({})", e),
+    }
+}
+
+impl App {
+    /// Collect the environment diagnostics
+    fn collect_diagnostics(&self) -> Vec<DiagnosticSection> {
+        let jj_version = self
+            .jj
+            .version()
+            .unwrap_or_else(|e| format!("unavailable ({})", e));
+        // Built-in merge tools (`vscode`, `meld`, ...) are defaults
+        let config_text = self.jj.config_list_with_defaults().unwrap_or_default();
+        let config = parse_config(&config_text);
+        let remotes = self.jj.git_remote_list_with_urls().unwrap_or_default();
+
+        let mut sections = vec![
+            DiagnosticSection {
+                title: "Versions".to_string(),
+                items: vec![
+                    DiagnosticItem::info("tij", env!("CARGO_PKG_VERSION")),
+                    DiagnosticItem::info("jj", &jj_version),
+                    DiagnosticItem::info("git", git_version()),
+                    DiagnosticItem::info(
+                        "Platform",
+                        format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+                    ),
+                ],
+            },
+            DiagnosticSection {
+                title: "jj feature gates".to_string(),
+                items: gate_items(&jj_version),
+            },
+            DiagnosticSection {
+                title: "Editors & merge tools".to_string(),
+                items: tool_items(&config, program_exists),
+            },
+            DiagnosticSection {
+                title: "Remotes".to_string(),
+                items: remote_items(&remotes),
+            },
+            DiagnosticSection {
+                title: "Features".to_string(),
+                items: feature_items(&config, clipboard::detect_tool(), clipboard::is_available),
+            },
+        ];
+        sections.extend(keymap_sections(self.keymap, &self.key_remaps));
+        sections
+    }
+
+    /// Open the Diagnostics View
+    pub(crate) fn open_diagnostics(&mut self) {
+        self.refresh_diagnostics_view();
+        self.go_to_view(View::Diagnostics);
+    }
+
+    /// Collect the diagnostics again
+    pub(crate) fn refresh_diagnostics_view(&mut self) {
+        let sections = self.collect_diagnostics();
+        self.diagnostics_view.set_sections(sections);
+    }
+
+    /// Copy the plain-text report
+    pub(crate) fn copy_diagnostics(&mut self) {
+        let report = format_diagnostics(self.diagnostics_view.sections());
+        match clipboard::copy_to_clipboard(&report) {
+            Ok(()) => self.notify_success("Copied diagnostics report"),
+            Err(e) => self.set_error(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_protocol() {
+        assert_eq!(remote_protocol("https://github.com/a/b.git"), "https");
+        assert_eq!(remote_protocol("ssh://git@github.com/a/b"), "ssh");
+        assert_eq!(remote_protocol("git@github.com:a/b.git"), "ssh");
+        assert_eq!(remote_protocol("file:///srv/repo"), "file");
+        assert_eq!(remote_protocol("/srv/repo.git"), "local path");
+        assert_eq!(remote_protocol("../repo"), "local path");
+        assert_eq!(remote_protocol(""), "none");
+    }
+
+    #[test]
+    fn test_tool_items_check_programs() {
+        let config = HashMap::from([
+            ("ui.editor", "nvim"),
+            ("ui.diff-editor", ":builtin"),
+            ("ui.merge-editor", "meld"),
+        ]);
+        let items = tool_items(&config, |program| program == "nvim");
+        assert_eq!(
+            items[0],
+            DiagnosticItem::new("ui.editor", "nvim", DiagnosticStatus::Available)
+        );
+        assert_eq!(items[1], DiagnosticItem::info("ui.diff-editor", ":builtin"));
+        assert_eq!(
+            items[2],
+            DiagnosticItem::new(
+                "ui.merge-editor",
+                "meld ('meld' not found)",
+                DiagnosticStatus::Missing
+            )
+        );
+        assert_eq!(items[3], DiagnosticItem::info("ui.pager", "(not set)"));
+    }
+
+    #[test]
+    fn test_feature_items_report_missing_tools() {
+        let config = HashMap::from([(config_keys::DIFF_TOOL, "meld")]);
+        let items = feature_items(&config, None, |program| program == "gh");
+        let status = |label: &str| {
+            items
+                .iter()
+                .find(|item| item.label == label)
+                .map(|item| item.status)
+        };
+        assert_eq!(status("Clipboard copy"), Some(DiagnosticStatus::Missing));
+        assert_eq!(
+            status("Create GitHub PRs"),
+            Some(DiagnosticStatus::Available)
+        );
+        assert_eq!(status("Create GitLab MRs"), Some(DiagnosticStatus::Missing));
+        assert_eq!(status("Difftool"), Some(DiagnosticStatus::Available));
+        assert_eq!(status("Command log file"), Some(DiagnosticStatus::Info));
+    }
+
+    #[test]
+    fn test_gate_items_follow_the_jj_version() {
+        let items = gate_items("jj 0.40.2");
+        assert!(
+            items
+                .iter()
+                .any(|item| item.status == DiagnosticStatus::Missing)
+        );
+        assert!(
+            gate_items("jj 0.41.0")
+                .iter()
+                .all(|item| item.status == DiagnosticStatus::Available)
+        );
+        assert_eq!(
+            gate_items("unavailable (jj not found)")[0].status,
+            DiagnosticStatus::Missing
+        );
+    }
+
+    #[test]
+    fn test_remote_items_without_remotes() {
+        assert_eq!(
+            remote_items(&[]),
+            vec![DiagnosticItem::info("(none)", "no git remotes")]
+        );
+    }
+}
@@ -24,6 +24,8 @@ impl App {
         match result {
             DialogResult::Cancelled => self.handle_dialog_cancel(callback),
             DialogResult::Confirmed(values) => match callback {
+                // Nothing to do: the table was only for reading
+                DialogCallback::BatchResults => {}
                 // Git Push
                 DialogCallback::GitPush
                 | DialogCallback::GitPushChange { .. }
@@ -31,11 +33,15 @@ impl App {
                 | DialogCallback::GitPushModeSelect { .. }
                 | DialogCallback::GitPushBulkConfirm { .. }
                 | DialogCallback::GitPushRevisions { .. }
-                | DialogCallback::GitPushMultiBookmarkMode { .. } => {
+                | DialogCallback::GitPushMultiBookmarkMode { .. }
+                | DialogCallback::PushQueue => {
                     self.handle_git_push_dialog(callback, values);
                 }
                 // Git Fetch
-                DialogCallback::GitFetch | DialogCallback::GitFetchBranch => {
+                DialogCallback::GitFetch
+                | DialogCallback::GitFetchBranch
+                | DialogCallback::GitFetchBranchPattern
+                | DialogCallback::GitFetchReviewIncoming { .. } => {
                     self.handle_git_fetch_dialog(callback, values);
                 }
                 // Bookmark
@@ -57,12 +63,39 @@ impl App {
                 | DialogCallback::WorkspaceRename { .. } => {
                     self.handle_workspace_dialog(callback, values);
                 }
+                // Remote
+                DialogCallback::RemoteAdd
+                | DialogCallback::RemoteRemove { .. }
+                | DialogCallback::RemoteRename { .. }
+                | DialogCallback::RemoteSetUrl { .. } => {
+                    self.handle_remote_dialog(callback, values);
+                }
+                DialogCallback::FetchAfterCancelledPush => {
+                    self.execute_fetch();
+                }
+                DialogCallback::ReconcileBookmarks { renames } => {
+                    self.execute_bookmark_reconcile(&renames, &values);
+                }
+                DialogCallback::RetargetBookmarks { retargets } => {
+                    self.execute_bookmark_retarget(&retargets, &values);
+                }
+                DialogCallback::JjCommand => {
+                    self.handle_jj_command_dialog(values);
+                }
+                // Sparse
+                DialogCallback::SparseAdd | DialogCallback::SparseApply { .. } => {
+                    self.handle_sparse_dialog(callback, values);
+                }
                 // Misc
-                DialogCallback::OpRestore
+                DialogCallback::OpRestore { .. }
                 | DialogCallback::Track
                 | DialogCallback::RestoreFile { .. }
+                | DialogCallback::DiffRestoreFile { .. }
                 | DialogCallback::RestoreAll
+                | DialogCallback::Abandon { .. }
+                | DialogCallback::Rebase { .. }
                 | DialogCallback::Revert { .. }
+                | DialogCallback::RevertRange { .. }
                 | DialogCallback::SimplifyParents { .. }
                 | DialogCallback::Parallelize { .. }
                 | DialogCallback::Fix { .. }
@@ -72,6 +105,138 @@ impl App {
                 | DialogCallback::MetaeditNewChangeId { .. } => {
                     self.handle_misc_dialog(callback, values);
                 }
+                // Revset presets
+                DialogCallback::RevsetPresetMenu | DialogCallback::RevsetPresetSave { .. } => {
+                    self.handle_revset_preset_dialog(callback, values);
+                }
+                // Hunk-level restore
+                DialogCallback::HunkRestoreSelect { .. }
+                | DialogCallback::HunkRestore { .. }
+                | DialogCallback::HunkSquashSelect { .. } => {
+                    self.handle_hunk_restore_dialog(callback, values);
+                }
+                DialogCallback::BugReport => {
+                    if let Some(mode) = values.first() {
+                        self.write_bug_report(mode);
+                    }
+                }
+                // Operation abandon / GC
+                DialogCallback::OpAbandonScope { .. }
+                | DialogCallback::OpAbandon { .. }
+                | DialogCallback::OpGc => {
+                    self.handle_op_prune_dialog(callback, values);
+                }
+                DialogCallback::ConfigCheck => {
+                    if let Some(fix) = values.first() {
+                        self.copy_config_fix(fix);
+                    }
+                }
+                DialogCallback::RestoreHidden { commit_id } => {
+                    self.execute_restore_hidden(&commit_id);
+                }
+                DialogCallback::RebaseDestination {
+                    source,
+                    mode,
+                    skip_emptied,
+                    simplify_parents,
+                    use_revset,
+                } => {
+                    if let Some(destination) = values.first() {
+                        self.resolve_rebase_destination(
+                            destination.trim(),
+                            source,
+                            mode,
+                            skip_emptied,
+                            simplify_parents,
+                            use_revset,
+                        );
+                    }
+                }
+                DialogCallback::SquashMessage {
+                    source,
+                    destination,
+                    source_desc,
+                    destination_desc,
+                } => {
+                    if let Some(choice) = values.first() {
+                        self.execute_squash_message_choice(
+                            &source,
+                            &destination,
+                            &source_desc,
+                            &destination_desc,
+                            choice,
+                        );
+                    }
+                }
+                DialogCallback::SquashFiles {
+                    source,
+                    destination,
+                    total,
+                    renamed_from,
+                } => {
+                    self.execute_squash_files(&source, &destination, &values, total, &renamed_from);
+                }
+                DialogCallback::SplitFiles {
+                    revision,
+                    total,
+                    renamed_from,
+                } => {
+                    self.execute_split_files(&revision, &values, total, &renamed_from);
+                }
+                DialogCallback::EditNote { change_id } => {
+                    if let Some(text) = values.first() {
+                        self.execute_edit_note(&change_id, text);
+                    }
+                }
+                DialogCallback::CherryPick { revision } => {
+                    if let Some(placement) = values.first() {
+                        self.execute_cherry_pick(&revision, placement);
+                    }
+                }
+                DialogCallback::GitignoreAdd { file_path } => {
+                    if let Some(choice) = values.first() {
+                        self.execute_gitignore_add(&file_path, choice);
+                    }
+                }
+                DialogCallback::PullRequest { bookmark, web_url } => {
+                    if let Some(choice) = values.first() {
+                        self.execute_pull_request_action(&bookmark, &web_url, choice);
+                    }
+                }
+                // Merge helper
+                DialogCallback::MergeTarget { .. }
+                | DialogCallback::MergeSources { .. }
+                | DialogCallback::MergeDescribe { .. }
+                | DialogCallback::MergeMarked { .. } => {
+                    self.handle_merge_helper_dialog(callback, values);
+                }
+                // Compare sessions
+                DialogCallback::CompareSessionMenu
+                | DialogCallback::CompareSessionAction { .. }
+                | DialogCallback::CompareSessionSave { .. }
+                | DialogCallback::CompareSessionDelete { .. } => {
+                    self.handle_compare_session_dialog(callback, values);
+                }
+                // Diff file menu
+                DialogCallback::DiffFileMenu {
+                    file_path,
+                    revision,
+                } => {
+                    if let Some(choice) = values.first() {
+                        self.execute_diff_file_action(&file_path, revision.as_deref(), choice);
+                    }
+                }
+                DialogCallback::DiffJumpToFile => {
+                    if let Some(file_name) = values.first() {
+                        self.jump_to_diff_file(file_name);
+                    }
+                }
+                // Clipboard
+                DialogCallback::Yank { entries, revision } => {
+                    if let Some(choice) = values.first() {
+                        self.execute_yank(&entries, revision.as_deref(), choice);
+                    }
+                }
             },
         }
     }
@@ -95,18 +260,50 @@ impl App {
                 self.pending_forget_bookmark = None;
             }
             // All others: no cleanup needed on cancel
-            DialogCallback::DeleteBookmarks
+            DialogCallback::BatchResults
+            | DialogCallback::DeleteBookmarks
             | DialogCallback::MoveBookmark { .. }
-            | DialogCallback::OpRestore
+            | DialogCallback::OpRestore { .. }
+            | DialogCallback::OpAbandonScope { .. }
+            | DialogCallback::OpAbandon { .. }
+            | DialogCallback::OpGc
+            | DialogCallback::FetchAfterCancelledPush
+            | DialogCallback::JjCommand
+            | DialogCallback::SparseAdd
+            | DialogCallback::SparseApply { .. }
             | DialogCallback::Track
             | DialogCallback::BookmarkJump
             | DialogCallback::GitFetch
             | DialogCallback::GitFetchBranch
+            | DialogCallback::GitFetchBranchPattern
+            | DialogCallback::GitFetchReviewIncoming { .. }
+            | DialogCallback::ReconcileBookmarks { .. }
+            | DialogCallback::RetargetBookmarks { .. }
             | DialogCallback::BookmarkMoveToWc { .. }
             | DialogCallback::BookmarkMoveBackwards { .. }
             | DialogCallback::RestoreFile { .. }
+            | DialogCallback::DiffRestoreFile { .. }
+            | DialogCallback::DiffFileMenu { .. }
+            | DialogCallback::DiffJumpToFile
             | DialogCallback::RestoreAll
+            | DialogCallback::Abandon { .. }
+            | DialogCallback::Rebase { .. }
+            | DialogCallback::RebaseDestination { .. }
+            | DialogCallback::GitignoreAdd { .. }
+            | DialogCallback::RestoreHidden { .. }
+            | DialogCallback::CherryPick { .. }
+            | DialogCallback::SquashMessage { .. }
+            | DialogCallback::SquashFiles { .. }
+            | DialogCallback::SplitFiles { .. }
+            | DialogCallback::EditNote { .. }
+            | DialogCallback::PushQueue
+            | DialogCallback::BugReport
+            | DialogCallback::ConfigCheck
+            | DialogCallback::HunkRestoreSelect { .. }
+            | DialogCallback::HunkRestore { .. }
+            | DialogCallback::HunkSquashSelect { .. }
             | DialogCallback::Revert { .. }
+            | DialogCallback::RevertRange { .. }
             | DialogCallback::SimplifyParents { .. }
             | DialogCallback::Parallelize { .. }
             | DialogCallback::Fix { .. }
@@ -116,9 +313,25 @@ impl App {
             | DialogCallback::MetaeditSelect { .. }
             | DialogCallback::MetaeditSetAuthor { .. }
             | DialogCallback::MetaeditNewChangeId { .. }
+            | DialogCallback::Yank { .. }
+            | DialogCallback::RevsetPresetMenu
+            | DialogCallback::RevsetPresetSave { .. }
+            | DialogCallback::CompareSessionMenu
+            | DialogCallback::CompareSessionAction { .. }
+            | DialogCallback::CompareSessionSave { .. }
+            | DialogCallback::CompareSessionDelete { .. }
             | DialogCallback::WorkspaceAdd
             | DialogCallback::WorkspaceForget { .. }
-            | DialogCallback::WorkspaceRename { .. } => {}
+            | DialogCallback::WorkspaceRename { .. }
+            | DialogCallback::RemoteAdd
+            | DialogCallback::RemoteRemove { .. }
+            | DialogCallback::RemoteRename { .. }
+            | DialogCallback::RemoteSetUrl { .. }
+            | DialogCallback::MergeTarget { .. }
+            | DialogCallback::MergeSources { .. }
+            | DialogCallback::MergeDescribe { .. }
+            | DialogCallback::MergeMarked { .. }
+            | DialogCallback::PullRequest { .. } => {}
         }
     }
 
@@ -136,6 +349,9 @@ impl App {
             DialogCallback::GitPushChange { change_id } => {
                 self.execute_push_change(&change_id);
             }
+            DialogCallback::PushQueue => {
+                self.execute_push_queue(&values);
+            }
             DialogCallback::GitPushRemoteSelect => {
                 if let Some(remote) = values.first() {
                     self.push_target_remote = Some(remote.clone());
@@ -177,18 +393,25 @@ impl App {
         match callback {
             DialogCallback::GitFetch => {
                 if let Some(value) = values.first() {
-                    if value == "__branch__" {
-                        self.start_fetch_branch_select();
-                    } else {
-                        self.execute_fetch_with_option(value);
+                    match value.as_str() {
+                        "__branch__" => self.start_fetch_branch_select(),
+                        "__branch_pattern__" => self.start_fetch_branch_pattern(),
+                        _ => self.execute_fetch_with_option(value),
                     }
                 }
             }
             DialogCallback::GitFetchBranch => {
-                if let Some(branch) = values.first() {
-                    self.execute_fetch_branch(branch);
+                self.execute_fetch_branches(&values);
+            }
+            DialogCallback::GitFetchBranchPattern => {
+                if let Some(input) = values.first() {
+                    let patterns = super::parse_branch_patterns(input);
+                    self.execute_fetch_branches(&patterns);
                 }
             }
+            DialogCallback::GitFetchReviewIncoming { revset } => {
+                self.show_incoming_changes(&revset);
+            }
             _ => {}
         }
     }
@@ -223,8 +446,8 @@ impl App {
     /// Handle confirmed Misc dialog results (restore, revert, etc.)
     fn handle_misc_dialog(&mut self, callback: DialogCallback, values: Vec<String>) {
         match callback {
-            DialogCallback::OpRestore => {
-                // TODO: Implement op restore with dialog
+            DialogCallback::OpRestore { operation_id } => {
+                self.confirm_op_restore(&operation_id);
             }
             DialogCallback::Track => {
                 self.execute_track(&values);
@@ -232,12 +455,41 @@ impl App {
             DialogCallback::RestoreFile { file_path } => {
                 self.execute_restore_file(&file_path);
             }
+            DialogCallback::DiffRestoreFile {
+                revision,
+                file_path,
+            } => {
+                self.execute_diff_restore_file(&revision, &file_path);
+            }
             DialogCallback::RestoreAll => {
                 self.execute_restore_all();
             }
+            DialogCallback::Abandon { revision } => {
+                self.execute_abandon(&revision);
+            }
+            DialogCallback::Rebase {
+                source,
+                destination,
+                mode,
+                skip_emptied,
+                simplify_parents,
+                use_revset,
+            } => {
+                self.execute_rebase(
+                    &source,
+                    &destination,
+                    mode,
+                    skip_emptied,
+                    simplify_parents,
+                    use_revset,
+                );
+            }
             DialogCallback::Revert { revision } => {
                 self.execute_revert(&revision);
             }
+            DialogCallback::RevertRange { revset, count } => {
+                self.execute_revert_range(&revset, count);
+            }
             DialogCallback::SimplifyParents { revision } => {
                 self.execute_simplify_parents(&revision);
             }
@@ -264,16 +516,7 @@ impl App {
                         "update-author" => {
                             self.execute_metaedit(&commit_id, &change_id, &["--update-author"]);
                         }
-                        "set-author" => {
-                            self.active_dialog = Some(Dialog::input(
-                                "Set author",
-                                "Enter author (Name <email>):",
-                                DialogCallback::MetaeditSetAuthor {
-                                    commit_id,
-                                    change_id,
-                                },
-                            ));
-                        }
+                        "set-author" => self.start_metaedit_set_author(commit_id, change_id),
                         "update-timestamp" => {
                             self.execute_metaedit(
                                 &commit_id,
@@ -281,8 +524,15 @@ impl App {
                                 &["--update-author-timestamp"],
                             );
                         }
+                        "reset-author-timestamp" => {
+                            self.execute_metaedit(
+                                &commit_id,
+                                &change_id,
+                                &["--update-author", "--update-author-timestamp"],
+                            );
+                        }
                         "new-change-id" => {
-                            let short = short_id(&change_id);
+                            let short = short_id(&change_id, self.id_length);
                             self.active_dialog = Some(Dialog::confirm(
                                 "Generate new change-id",
                                 format!("Generate new change-id for {}?", short),
@@ -308,10 +558,7 @@ impl App {
                 change_id,
             } => {
                 if let Some(author) = values.first() {
-                    let trimmed = author.trim();
-                    if !trimmed.is_empty() {
-                        self.execute_metaedit(&commit_id, &change_id, &["--author", trimmed]);
-                    }
+                    self.execute_metaedit_set_author(&commit_id, &change_id, author);
                 }
             }
             DialogCallback::MetaeditNewChangeId {
@@ -548,6 +795,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_metaedit_select_reset_author_timestamp_passes_both_flags() {
+        let mut app = App::new_for_test();
+        let (dialog, values) = metaedit_select_dialog("reset-author-timestamp");
+        app.active_dialog = Some(dialog);
+        app.handle_dialog_result(DialogResult::Confirmed(values));
+        let args = last_command_args(&app);
+        assert_eq!(
+            args,
+            vec![
+                "metaedit",
+                "-r",
+                "def67890",
+                "--update-author",
+                "--update-author-timestamp"
+            ]
+        );
+    }
+
     #[test]
     fn test_metaedit_select_set_author_opens_input_dialog() {
         let mut app = App::new_for_test();
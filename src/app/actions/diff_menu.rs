@@ -0,0 +1,341 @@
+//! Diff View file menu: per-file actions behind Enter/Space
+//!
+//! Gathers the file-level operations (jump, blame, restore, squash into
+//! parent, edit, copy path) in one popup for the file under the cursor.
+//! Actions that rewrite a revision are offered only for single-revision
+//! diffs; compare and interdiff views get the read-only ones.
+
+use super::merge::run_editor;
+use super::suspend_tui;
+use crate::app::clipboard;
+use crate::app::helpers::revision::short_id;
+use crate::app::state::{App, DirtyFlags};
+use crate::model::{CommitId, DiffMode};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Menu values
+const JUMP: &str = "jump";
+const BLAME: &str = "blame";
+const RESTORE: &str = "restore";
+const SQUASH: &str = "squash";
+const EDIT: &str = "edit";
+const COPY_PATH: &str = "copy-path";
+
+/// Menu entries for a diff with `file_count` files; `single` enables the
+/// entries that need a single revision
+fn menu_items(file_count: usize, single: bool) -> Vec<SelectItem> {
+    let mut entries = Vec::new();
+    if file_count > 1 {
+        entries.push((JUMP, "Jump to file..."));
+    }
+    if single {
+        entries.push((BLAME, "Blame"));
+        entries.push((RESTORE, "Restore file (drop its changes here)"));
+        entries.push((SQUASH, "Squash file into parent"));
+    }
+    entries.push((EDIT, "Open in editor"));
+    entries.push((COPY_PATH, "Copy path"));
+
+    entries
+        .into_iter()
+        .map(|(value, label)| SelectItem {
+            label: label.to_string(),
+            value: value.to_string(),
+            selected: false,
+        })
+        .collect()
+}
+
+impl App {
+    /// Open the action menu for `file_path` in the current diff
+    pub(crate) fn open_diff_file_menu(&mut self, file_path: &str) {
+        let Some(ref diff_view) = self.diff_view else {
+            return;
+        };
+        let revision = (diff_view.mode == DiffMode::Single).then(|| diff_view.revision.clone());
+        let items = menu_items(diff_view.file_count(), revision.is_some());
+
+        self.active_dialog = Some(Dialog::select_single(
+            "File Actions",
+            file_path,
+            items,
+            None,
+            DialogCallback::DiffFileMenu {
+                file_path: file_path.to_string(),
+                revision,
+            },
+        ));
+    }
+
+    /// Handle the chosen file menu item
+    pub(crate) fn execute_diff_file_action(
+        &mut self,
+        file_path: &str,
+        revision: Option<&str>,
+        choice: &str,
+    ) {
+        match (choice, revision) {
+            (JUMP, _) => self.open_diff_file_jump(),
+            (BLAME, Some(revision)) => self.open_blame(file_path, Some(revision)),
+            (RESTORE, Some(revision)) => {
+                self.active_dialog = Some(Dialog::confirm(
+                    "Restore File",
+                    format!(
+                        "Restore '{}' in {}?\nThis drops the file's changes in that revision.",
+                        file_path,
+                        short_id(revision, self.id_length)
+                    ),
+                    Some("Undo with 'u' if needed.".to_string()),
+                    DialogCallback::DiffRestoreFile {
+                        revision: revision.to_string(),
+                        file_path: file_path.to_string(),
+                    },
+                ));
+            }
+            (SQUASH, Some(revision)) => self.execute_diff_squash_file(revision, file_path),
+            (EDIT, _) => self.edit_diff_file(file_path),
+            (COPY_PATH, _) => match clipboard::copy_to_clipboard(file_path) {
+                Ok(()) => self.notify_success(format!("Copied path: {}", file_path)),
+                Err(e) => self.set_error(e),
+            },
+            _ => {}
+        }
+    }
+
+    /// Show a file picker for the current diff
+    fn open_diff_file_jump(&mut self) {
+        let Some(ref diff_view) = self.diff_view else {
+            return;
+        };
+        let items = diff_view
+            .file_names
+            .iter()
+            .map(|name| SelectItem {
+                label: name.clone(),
+                value: name.clone(),
+                selected: false,
+            })
+            .collect();
+        let current = diff_view.current_file_index;
+
+        let mut dialog = Dialog::select_single(
+            "Jump to File",
+            "Type to search, Enter to jump",
+            items,
+            None,
+            DialogCallback::DiffJumpToFile,
+        );
+        dialog.cursor = current;
+        self.active_dialog = Some(dialog);
+    }
+
+    /// Scroll the diff to `file_name` (as listed in the diff headers)
+    pub(crate) fn jump_to_diff_file(&mut self, file_name: &str) {
+        if let Some(ref mut diff_view) = self.diff_view {
+            diff_view.jump_to_file(file_name);
+        }
+    }
+
+    /// `jj restore --changes-in <rev> <file>`: undo the file's changes in `revision`
+    pub(crate) fn execute_diff_restore_file(&mut self, revision: &str, file_path: &str) {
+        let result =
+            self.run_and_record("Restore", &["restore", "--changes-in", revision, file_path]);
+        match result {
+            Ok(_) => {
+                self.notify_success(format!(
+                    "Restored {} in {} (undo: u)",
+                    file_path,
+                    short_id(revision, self.id_length)
+                ));
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.remove_file_from_diff_view(file_path);
+            }
+            Err(e) => self.set_error(format!("Restore failed: {}", e)),
+        }
+    }
+
+    /// Move the file's changes in `revision` into its parent
+    ///
+    /// `--keep-emptied` keeps the revision (and its description) even when
+    /// this was its only file, so jj never has to ask for a combined message.
+    fn execute_diff_squash_file(&mut self, revision: &str, file_path: &str) {
+        let result = self.run_and_record(
+            "Squash file",
+            &["squash", "-r", revision, "--keep-emptied", file_path],
+        );
+        match result {
+            Ok(_) => {
+                self.notify_success(format!(
+                    "Squashed {} from {} into parent (undo: u)",
+                    file_path,
+                    short_id(revision, self.id_length)
+                ));
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.remove_file_from_diff_view(file_path);
+            }
+            Err(e) => self.set_error(format!("Squash failed: {}", e)),
+        }
+    }
+
+    /// Open the working-copy version of `file_path` in the user's editor
+    fn edit_diff_file(&mut self, file_path: &str) {
+        let path = match self.workspace_file(file_path) {
+            Ok((path, _)) => path,
+            Err(e) => {
+                self.set_error(e);
+                return;
+            }
+        };
+        if !path.is_file() {
+            self.notify_warning(format!("{} is not in the working copy", file_path));
+            return;
+        }
+
+        let result = {
+            let _guard = suspend_tui();
+            run_editor(&self.jj, &path)
+        };
+        match result {
+            Ok(status) if status.success() => {
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.reload_diff_view(file_path);
+            }
+            Ok(_) => self.notify_info("Editor exited with an error"),
+            Err(e) => self.set_error(format!("Failed to run editor: {}", e)),
+        }
+    }
+
+    /// Drop `file_path` from a single-revision diff after its changes left
+    /// the revision (restore, squash into parent)
+    ///
+    /// Only the rewritten commit ID is re-read instead of the whole diff,
+    /// which is slow for large revisions; falls back to a full reload when
+    /// the file can't be removed locally.
+    fn remove_file_from_diff_view(&mut self, file_path: &str) {
+        let Some(ref mut diff_view) = self.diff_view else {
+            return;
+        };
+        if diff_view.mode != DiffMode::Single {
+            return;
+        }
+        if !diff_view.remove_file(file_path) {
+            self.reload_diff_view(file_path);
+            return;
+        }
+        let revision = diff_view.revision.clone();
+        match self.jj.full_ids(&revision) {
+            Ok((_, commit_id)) => {
+                if let Some(ref mut diff_view) = self.diff_view {
+                    diff_view.content.commit_id = CommitId::new(commit_id);
+                }
+            }
+            Err(_) => self.reload_diff_view(file_path),
+        }
+    }
+
+    /// Re-fetch a single-revision diff after a rewrite, staying on `file_path`
+    fn reload_diff_view(&mut self, file_path: &str) {
+        let Some(ref diff_view) = self.diff_view else {
+            return;
+        };
+        if diff_view.mode != DiffMode::Single {
+            return;
+        }
+        let revision = diff_view.revision.clone();
+        let format = diff_view.display_format;
+
+        match self.fetch_diff_content(&revision, format, None, DiffMode::Single) {
+            Ok(content) => {
+                let diff_view = self.diff_view.as_mut().unwrap();
+                diff_view.set_content(revision, content);
+                diff_view.jump_to_file(file_path);
+            }
+            Err(e) => self.set_error(format!("Failed to reload diff: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{CommitId, DiffContent, DiffLine};
+    use crate::ui::views::DiffView;
+
+    fn values(items: &[SelectItem]) -> Vec<&str> {
+        items.iter().map(|item| item.value.as_str()).collect()
+    }
+
+    fn app_with_diff() -> App {
+        let mut app = App::new_for_test();
+        let content = DiffContent {
+            commit_id: CommitId::new("abc123".to_string()),
+            lines: vec![
+                DiffLine::file_header("src/a.rs"),
+                DiffLine::added(1, "a"),
+                DiffLine::file_header("src/b.rs"),
+                DiffLine::added(1, "b"),
+            ],
+            ..Default::default()
+        };
+        app.diff_view = Some(DiffView::new("kkmpptxz".to_string(), content));
+        app
+    }
+
+    #[test]
+    fn test_menu_items_single_revision() {
+        assert_eq!(
+            values(&menu_items(3, true)),
+            vec![JUMP, BLAME, RESTORE, SQUASH, EDIT, COPY_PATH]
+        );
+    }
+
+    #[test]
+    fn test_menu_items_compare_and_single_file() {
+        assert_eq!(values(&menu_items(1, false)), vec![EDIT, COPY_PATH]);
+    }
+
+    #[test]
+    fn test_open_file_menu_carries_revision() {
+        let mut app = app_with_diff();
+        app.open_diff_file_menu("src/b.rs");
+
+        let dialog = app.active_dialog.as_ref().unwrap();
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::DiffFileMenu {
+                file_path: "src/b.rs".to_string(),
+                revision: Some("kkmpptxz".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_jump_choice_opens_picker_at_current_file() {
+        let mut app = app_with_diff();
+        app.diff_view.as_mut().unwrap().jump_to_file("src/b.rs");
+        app.execute_diff_file_action("src/b.rs", Some("kkmpptxz"), JUMP);
+
+        let dialog = app.active_dialog.as_ref().unwrap();
+        assert_eq!(dialog.callback_id, DialogCallback::DiffJumpToFile);
+        assert_eq!(dialog.cursor, 1);
+
+        app.jump_to_diff_file("src/a.rs");
+        assert_eq!(app.diff_view.as_ref().unwrap().current_file_index, 0);
+    }
+
+    #[test]
+    fn test_restore_choice_asks_for_confirmation() {
+        let mut app = app_with_diff();
+        app.execute_diff_file_action("src/a.rs", Some("kkmpptxz"), RESTORE);
+
+        let dialog = app.active_dialog.as_ref().unwrap();
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::DiffRestoreFile {
+                revision: "kkmpptxz".to_string(),
+                file_path: "src/a.rs".to_string(),
+            }
+        );
+        assert!(app.command_history.is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+//! Diff View options: whitespace, context lines and file mode changes
+//!
+//! Ignoring whitespace and the context line count are jj flags, so
+//! changing them re-fetches the diff; hiding mode changes is a filter of
+//! the Diff View. The options are kept for the session: every diff opened
+//! afterwards is loaded with them. Clipboard copies, patch exports and
+//! hunk operations always use plain jj output.
+
+use crate::app::state::App;
+use crate::jj::JjExecutor;
+use crate::model::DiffOptions;
+
+impl App {
+    /// Executor for the Diff View, passing the session's options to jj
+    pub(crate) fn diff_jj(&self) -> JjExecutor {
+        self.jj.with_diff_flags(self.diff_options.jj_flags())
+    }
+
+    /// Toggle `--ignore-all-space` and re-fetch the diff
+    pub(crate) fn toggle_diff_ignore_whitespace(&mut self) {
+        let options = DiffOptions {
+            ignore_whitespace: !self.diff_options.ignore_whitespace,
+            ..self.diff_options
+        };
+        let notice = if options.ignore_whitespace {
+            "Ignoring whitespace changes"
+        } else {
+            "Showing whitespace changes"
+        };
+        self.reload_diff_with(options, notice.to_string());
+    }
+
+    /// Show more or fewer context lines and re-fetch the diff
+    pub(crate) fn step_diff_context(&mut self, more: bool) {
+        let Some(context) = self.diff_options.step_context(more) else {
+            self.notify_info(if more {
+                "Already showing the most context lines"
+            } else {
+                "Already showing no context lines"
+            });
+            return;
+        };
+        let options = DiffOptions {
+            context: Some(context),
+            ..self.diff_options
+        };
+        self.reload_diff_with(options, format!("{} context line(s)", context));
+    }
+
+    /// Toggle hiding file mode changes (no re-fetch)
+    pub(crate) fn toggle_diff_mode_changes(&mut self) {
+        self.diff_options.hide_mode_changes = !self.diff_options.hide_mode_changes;
+        if let Some(diff_view) = self.diff_view.as_mut() {
+            diff_view.set_options(self.diff_options);
+        }
+        self.notify_info(if self.diff_options.hide_mode_changes {
+            "Hiding file mode changes"
+        } else {
+            "Showing file mode changes"
+        });
+    }
+
+    /// Re-fetch the shown diff with `options`, keeping them only if it loads
+    fn reload_diff_with(&mut self, options: DiffOptions, notice: String) {
+        let Some(ref diff_view) = self.diff_view else {
+            return;
+        };
+        let revision = diff_view.revision.clone();
+        let format = diff_view.display_format;
+        let compare_info = diff_view.compare_info.clone();
+        let mode = diff_view.mode;
+        let file = diff_view.current_file_name().map(str::to_string);
+
+        let previous = std::mem::replace(&mut self.diff_options, options);
+        match self.fetch_diff_content(&revision, format, compare_info.as_ref(), mode) {
+            Ok(content) => {
+                let diff_view = self.diff_view.as_mut().unwrap();
+                diff_view.set_content(revision, content);
+                diff_view.set_options(options);
+                if let Some(file) = file {
+                    diff_view.jump_to_file(&file);
+                }
+                self.notify_info(notice);
+            }
+            Err(e) => {
+                self.diff_options = previous;
+                self.set_error(format!("Failed to reload diff: {}", e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{CommitId, DiffContent, DiffLine};
+    use crate::ui::views::DiffView;
+
+    fn app_with_diff() -> App {
+        let mut app = App::new_for_test();
+        let content = DiffContent {
+            commit_id: CommitId::new("abc123".to_string()),
+            lines: vec![DiffLine::file_header("src/a.rs"), DiffLine::added(1, "a")],
+            ..Default::default()
+        };
+        app.diff_view = Some(DiffView::new("kkmpptxz".to_string(), content));
+        app
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_previous_options() {
+        // jj is not available in tests, so the re-fetch fails
+        let mut app = app_with_diff();
+        app.toggle_diff_ignore_whitespace();
+        app.step_diff_context(true);
+        assert_eq!(app.diff_options, DiffOptions::default());
+        assert!(app.error_message.is_some());
+    }
+
+    #[test]
+    fn test_mode_changes_toggle_is_kept_for_the_session() {
+        let mut app = app_with_diff();
+        app.toggle_diff_mode_changes();
+        assert!(app.diff_options.hide_mode_changes);
+        assert!(app.diff_view.as_ref().unwrap().options.hide_mode_changes);
+        // Options survive the Diff View being closed and reopened
+        app.diff_view = None;
+        app.toggle_diff_mode_changes();
+        assert!(!app.diff_options.hide_mode_changes);
+    }
+
+    #[test]
+    fn test_context_at_the_end_of_the_steps_is_not_refetched() {
+        let mut app = app_with_diff();
+        app.diff_options.context = Some(0);
+        app.step_diff_context(false);
+        assert_eq!(app.diff_options.context, Some(0));
+        assert!(app.error_message.is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! Log View `diff_stats` column: lines added/removed per change
+//!
+//! Stats are not part of `jj log` (that would compute every diff of the
+//! log); on each idle tick the rows on screen that lack them are fetched in
+//! one `jj log` call. Results are cached by commit ID next to the preview
+//! cache, so they survive refreshes and are never stale; a rewritten change
+//! gets a new commit ID and is fetched again.
+
+use crate::app::state::{App, View};
+
+/// Entries kept before the cache starts over
+const DIFF_STAT_CACHE_CAPACITY: usize = 2000;
+
+impl App {
+    /// Fill in the cached stats of the loaded changes
+    pub(crate) fn apply_cached_diff_stats(&mut self) {
+        for change in &mut self.log_view.changes {
+            if let Some(stat) = self.diff_stat_cache.get(change.commit_id.as_str()) {
+                change.extras.diff_stat = *stat;
+            }
+        }
+    }
+
+    /// Fetch the stats of the visible rows not seen yet (idle tick)
+    pub(crate) fn resolve_visible_diff_stats(&mut self) {
+        if self.current_view != View::Log || !self.log_view.row_layout.diff_stats() {
+            return;
+        }
+        let missing: Vec<String> = self
+            .log_view
+            .visible_changes()
+            .map(|change| change.commit_id.to_string())
+            .filter(|commit_id| !self.diff_stat_cache.contains_key(commit_id))
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+        if self.diff_stat_cache.len() + missing.len() > DIFF_STAT_CACHE_CAPACITY {
+            self.diff_stat_cache.clear();
+        }
+        // `present()`: one commit gone since the log loaded must not fail the rest
+        let revset = missing
+            .iter()
+            .map(|commit_id| format!("present({})", commit_id))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        // A failed call (e.g. the repo is locked) is tried again next tick
+        let Ok(stats) = self.jj.log_diff_stats(&revset) else {
+            return;
+        };
+        for (commit_id, stat) in stats {
+            self.diff_stat_cache.insert(commit_id, Some(stat));
+        }
+        // Rows jj gave nothing for are not asked again
+        for commit_id in missing {
+            self.diff_stat_cache.entry(commit_id).or_insert(None);
+        }
+        self.apply_cached_diff_stats();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Change, LogRow};
+
+    fn app_with_rows(rows: usize) -> App {
+        let mut app = App::new_for_test();
+        app.log_view.row_layout = LogRow::parse("change_id diff_stats description").unwrap();
+        app.log_view.set_changes(
+            (0..rows)
+                .map(|i| Change {
+                    change_id: format!("change{}", i).into(),
+                    commit_id: format!("commit{}", i).into(),
+                    ..Default::default()
+                })
+                .collect(),
+        );
+        app.log_view.visible_rows = 2;
+        app
+    }
+
+    #[test]
+    fn test_cached_stats_are_applied() {
+        let mut app = app_with_rows(2);
+        app.diff_stat_cache
+            .insert("commit1".to_string(), Some((4, 2)));
+        app.apply_cached_diff_stats();
+        assert_eq!(app.log_view.changes[0].extras.diff_stat, None);
+        assert_eq!(app.log_view.changes[1].extras.diff_stat, Some((4, 2)));
+    }
+
+    #[test]
+    fn test_resolve_fetches_visible_rows_once() {
+        let mut app = app_with_rows(5);
+        app.resolve_visible_diff_stats();
+        // Only the two rows on screen were asked for, in one call
+        let entries = app.jj.command_log().entries();
+        assert!(entries.last().is_some_and(|entry| {
+            entry
+                .args
+                .contains(&"present(commit0) | present(commit1)".to_string())
+        }));
+        // The call failed (no jj here): nothing is cached, so it is retried
+        assert!(app.diff_stat_cache.is_empty());
+        app.resolve_visible_diff_stats();
+        assert_eq!(app.jj.command_log().entries().len(), entries.len() + 1);
+    }
+
+    #[test]
+    fn test_resolve_skips_layout_without_column() {
+        let mut app = app_with_rows(2);
+        app.log_view.row_layout = LogRow::default();
+        app.resolve_visible_diff_stats();
+        assert!(app.diff_stat_cache.is_empty());
+    }
+}
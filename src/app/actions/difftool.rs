@@ -0,0 +1,73 @@
+//! Open diffs in a graphical difftool via `jj diff --tool`
+//!
+//! The tool is a jj merge tool name from `tij.diff-tool`, e.g.
+//! `jj config set --user tij.diff-tool meld` (any `merge-tools.<name>`
+//! entry with a `diff-args` works).
+
+use std::time::Instant;
+
+use super::suspend_tui;
+use crate::app::state::App;
+use crate::jj::constants::config_keys;
+
+impl App {
+    /// Launch the configured difftool for `revisions`, optionally one file
+    ///
+    /// `revisions` is `["-r", rev]` or `["--from", a, "--to", b]`. The TUI
+    /// is suspended until the tool exits; nothing is refreshed since the
+    /// diff is read-only.
+    pub(crate) fn open_diff_tool(&mut self, revisions: &[&str], file: Option<&str>) {
+        let tool = match self.jj.config_get(config_keys::DIFF_TOOL) {
+            Ok(Some(tool)) if !tool.trim().is_empty() => tool.trim().to_string(),
+            Ok(_) => {
+                self.set_error(format!(
+                    "No diff tool configured: set {} to a jj merge tool (e.g. meld)",
+                    config_keys::DIFF_TOOL
+                ));
+                return;
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to read {}: {}", config_keys::DIFF_TOOL, e));
+                return;
+            }
+        };
+
+        let guard = suspend_tui();
+        let start = Instant::now();
+        let result = self.jj.diff_tool_interactive(&tool, revisions, file);
+        let mut args = vec!["diff", "--tool", tool.as_str()];
+        args.extend_from_slice(revisions);
+        args.extend(file);
+        self.record_interactive_command("Diff tool", &args, start, &result);
+        drop(guard);
+
+        match result {
+            Ok(status) if status.success() => {
+                self.notify_info(format!("Closed diff tool {}", tool));
+            }
+            Ok(status) => {
+                self.set_error(format!(
+                    "Diff tool {} exited with error (code: {})",
+                    tool,
+                    status.code().unwrap_or(-1)
+                ));
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to launch diff tool {}: {}", tool, e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_diff_tool_without_config_reports_error() {
+        // No jj binary / no config in tests → nothing is launched
+        let mut app = App::new_for_test();
+        app.open_diff_tool(&["-r", "@"], None);
+        assert!(app.error_message.is_some());
+    }
+}
@@ -0,0 +1,196 @@
+//! Add files to `.gitignore` from the Status View
+//!
+//! `i` offers the selected file's exact path, an extension glob or its
+//! directory as a pattern. The pattern is appended to the workspace root's
+//! `.gitignore` and the matching files are untracked (`jj file untrack`),
+//! since jj keeps tracking files that were snapshotted before being ignored.
+//! Patterns and filesets use the path relative to the root (`root-file:`
+//! and friends), not the one jj printed relative to the current directory.
+
+use std::path::Path;
+
+use crate::app::state::{App, DirtyFlags};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// One way to ignore a file: `.gitignore` pattern plus the fileset to untrack
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnoreChoice {
+    /// Dialog value
+    key: &'static str,
+    /// Dialog label prefix
+    label: &'static str,
+    /// Line appended to `.gitignore`
+    pattern: String,
+    /// `jj file untrack` argument
+    fileset: String,
+}
+
+/// Quote a path as a jj fileset string literal
+fn fileset_string(path: &str) -> String {
+    format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Anchor `path` to the `.gitignore` directory (the leading `/` also keeps
+/// names starting with `#` or `!` literal)
+fn anchored_pattern(path: &str) -> String {
+    format!("/{}", path)
+}
+
+/// Patterns offered for `path` (relative to the workspace root): exact
+/// file, `*.ext` (if it has an extension) and its directory (if it isn't at
+/// the root)
+fn ignore_choices(path: &str) -> Vec<IgnoreChoice> {
+    let mut choices = vec![IgnoreChoice {
+        key: "exact",
+        label: "Exact path",
+        pattern: anchored_pattern(path),
+        fileset: format!("root-file:{}", fileset_string(path)),
+    }];
+
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    if let Some((stem, ext)) = file_name.rsplit_once('.')
+        && !stem.is_empty()
+        && !ext.is_empty()
+    {
+        choices.push(IgnoreChoice {
+            key: "extension",
+            label: "Extension",
+            pattern: format!("*.{}", ext),
+            fileset: format!("root-glob:{}", fileset_string(&format!("**/*.{}", ext))),
+        });
+    }
+
+    if let Some((dir, _)) = path.rsplit_once('/') {
+        choices.push(IgnoreChoice {
+            key: "directory",
+            label: "Directory",
+            pattern: format!("{}/", anchored_pattern(dir)),
+            fileset: format!("root:{}", fileset_string(dir)),
+        });
+    }
+    choices
+}
+
+/// `.gitignore` content with `pattern` appended (unchanged if already listed)
+fn append_pattern(existing: &str, pattern: &str) -> Option<String> {
+    if existing.lines().any(|line| line.trim_end() == pattern) {
+        return None;
+    }
+    let mut content = existing.to_string();
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(pattern);
+    content.push('\n');
+    Some(content)
+}
+
+impl App {
+    /// Ask how to ignore `file_path` (as jj printed it)
+    pub(crate) fn start_gitignore_add(&mut self, file_path: &str) {
+        let relative = match self.workspace_file(file_path) {
+            Ok((_, relative)) => relative,
+            Err(e) => {
+                self.set_error(e);
+                return;
+            }
+        };
+        let items = ignore_choices(&relative)
+            .into_iter()
+            .map(|choice| SelectItem {
+                label: format!("{:<11} {}", choice.label, choice.pattern),
+                value: choice.key.to_string(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select_single(
+            "Add to .gitignore",
+            format!("Ignore {}:", file_path),
+            items,
+            Some("Appended to .gitignore at the workspace root, then untracked".to_string()),
+            DialogCallback::GitignoreAdd {
+                file_path: relative,
+            },
+        ));
+    }
+
+    /// Append the chosen pattern to `.gitignore` and untrack what it matches
+    ///
+    /// `file_path` is relative to the workspace root.
+    pub(crate) fn execute_gitignore_add(&mut self, file_path: &str, key: &str) {
+        let Some(choice) = ignore_choices(file_path)
+            .into_iter()
+            .find(|choice| choice.key == key)
+        else {
+            return;
+        };
+        let root = match self.jj.workspace_root() {
+            Ok(root) => root,
+            Err(e) => {
+                self.set_error(format!("Failed to locate workspace root: {}", e));
+                return;
+            }
+        };
+        let gitignore = Path::new(&root).join(".gitignore");
+        let existing = std::fs::read_to_string(&gitignore).unwrap_or_default();
+        if let Some(content) = append_pattern(&existing, &choice.pattern)
+            && let Err(e) = std::fs::write(&gitignore, content)
+        {
+            self.set_error(format!("Failed to write .gitignore: {}", e));
+            return;
+        }
+
+        let result = self.run_and_record("Untrack", &["file", "untrack", &choice.fileset]);
+        self.run_jj_action(
+            result,
+            "Untrack failed",
+            &format!("Ignored {} (added to .gitignore)", choice.pattern),
+            DirtyFlags::log_and_status(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignore_choices_for_nested_file() {
+        let choices = ignore_choices("logs/debug/app.log");
+        let patterns: Vec<(&str, &str)> = choices
+            .iter()
+            .map(|c| (c.pattern.as_str(), c.fileset.as_str()))
+            .collect();
+        assert_eq!(
+            patterns,
+            [
+                ("/logs/debug/app.log", "root-file:\"logs/debug/app.log\""),
+                ("*.log", "root-glob:\"**/*.log\""),
+                ("/logs/debug/", "root:\"logs/debug\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ignore_choices_for_root_dotfile() {
+        // `.envrc` has no extension and no directory
+        let choices = ignore_choices(".envrc");
+        assert_eq!(choices.len(), 1);
+        assert_eq!(choices[0].pattern, "/.envrc");
+    }
+
+    #[test]
+    fn test_fileset_string_escapes_quotes() {
+        assert_eq!(fileset_string(r#"a "b"\c"#), r#""a \"b\"\\c""#);
+    }
+
+    #[test]
+    fn test_append_pattern() {
+        assert_eq!(append_pattern("", "*.log").unwrap(), "*.log\n");
+        assert_eq!(
+            append_pattern("target/", "*.log").unwrap(),
+            "target/\n*.log\n"
+        );
+        assert!(append_pattern("target/\n*.log\n", "*.log").is_none());
+    }
+}
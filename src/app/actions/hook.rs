@@ -0,0 +1,143 @@
+//! User-configured command hooks run through `jj util exec`
+//!
+//! Currently only `tij.pre-push`: a shell command that must succeed before
+//! any push is sent. Configure it with e.g.
+//! `jj config set --repo tij.pre-push "cargo test"`.
+
+use std::io::{self, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::Instant;
+
+use super::suspend_tui;
+use crate::app::state::App;
+use crate::jj::JjExecutor;
+use crate::jj::constants::config_keys;
+
+/// Pager used for failed hook output: `$PAGER`, then `less`
+fn pager_command() -> String {
+    std::env::var("PAGER")
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "less".to_string())
+}
+
+/// Pipe `text` into the user's pager with the terminal inherited
+fn show_in_pager(text: &str) -> io::Result<ExitStatus> {
+    let pager = pager_command();
+    let mut parts = pager.split_whitespace();
+    let program = parts.next().unwrap_or("less");
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager may quit before reading everything; a broken pipe is fine
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child.wait()
+}
+
+/// Copy `reader` to `echo` as it arrives, returning everything read
+fn tee_output(mut reader: impl Read, mut echo: impl Write) -> io::Result<String> {
+    let mut captured = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        captured.extend_from_slice(&buf[..n]);
+        echo.write_all(&buf[..n])?;
+        echo.flush()?;
+    }
+    Ok(String::from_utf8_lossy(&captured).into_owned())
+}
+
+/// Run a hook, showing its output live and returning it with the exit status
+fn run_hook(jj: &JjExecutor, command: &str) -> io::Result<(ExitStatus, String)> {
+    // The executor reaps the child even if echoing failed
+    let (status, output) = jj.util_exec_hook(command, |child| match child.stdout.take() {
+        Some(stdout) => tee_output(stdout, io::stdout()),
+        None => Ok(String::new()),
+    })?;
+    Ok((status, output?))
+}
+
+impl App {
+    /// Run the `tij.pre-push` hook before a push
+    ///
+    /// Returns `true` when the push may proceed: no hook is configured, or
+    /// the hook exited successfully. On failure the captured output is
+    /// shown in a pager and an error notification explains the abort.
+    pub(super) fn run_pre_push_hook(&mut self) -> bool {
+        let Ok(Some(command)) = self.jj.config_get(config_keys::PRE_PUSH) else {
+            return true;
+        };
+
+        let guard = suspend_tui();
+        println!("Running pre-push hook: {}\n", command);
+
+        let start = Instant::now();
+        let result = run_hook(&self.jj, &command);
+        let status = result
+            .as_ref()
+            .map(|(status, _)| *status)
+            .map_err(|e| io::Error::new(e.kind(), e.to_string()));
+        self.record_interactive_command(
+            "Pre-push hook",
+            &["util", "exec", "--", "sh", "-c", &command],
+            start,
+            &status,
+        );
+
+        match result {
+            Ok((status, _)) if status.success() => true,
+            Ok((status, output)) => {
+                if !output.trim().is_empty() {
+                    let _ = show_in_pager(&output);
+                }
+                drop(guard);
+                self.set_error(format!(
+                    "Push aborted: pre-push hook failed (exit code {})",
+                    status.code().unwrap_or(-1)
+                ));
+                false
+            }
+            Err(e) => {
+                drop(guard);
+                self.set_error(format!("Push aborted: pre-push hook could not run: {}", e));
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tee_output_echoes_and_captures() {
+        let mut echoed = Vec::new();
+        let captured = tee_output(b"running tests\nok\n".as_slice(), &mut echoed).unwrap();
+        assert_eq!(captured, "running tests\nok\n");
+        assert_eq!(echoed, b"running tests\nok\n");
+    }
+
+    #[test]
+    fn test_tee_output_empty() {
+        let mut echoed = Vec::new();
+        assert_eq!(tee_output(io::empty(), &mut echoed).unwrap(), "");
+        assert!(echoed.is_empty());
+    }
+
+    #[test]
+    fn test_pre_push_hook_without_config_allows_push() {
+        // No jj binary / no config in tests → nothing to run
+        let mut app = App::new_for_test();
+        assert!(app.run_pre_push_hook());
+        assert!(app.error_message.is_none());
+    }
+}
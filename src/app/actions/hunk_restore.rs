@@ -0,0 +1,423 @@
+//! Hunk- and line-level restore and squash from the Status View
+//!
+//! `x` lists the hunks of the selected file's working-copy diff
+//! (`jj diff --git -r @ <file>`), each followed by its added and removed
+//! lines; checking a hunk picks all of its lines. The picked lines are
+//! reverted in the file on disk and the working copy is snapshotted,
+//! leaving the rest as it was. Whole-file restore stays on `r`
+//! (`jj restore <file>`).
+//!
+//! `m` picks the same way and moves the picked lines into `@-`: the
+//! parent's file with only those lines applied is written to a temp file,
+//! and `jj squash --tool` runs a one-off diff editor that copies it over
+//! the file, so no editor opens. The copy is tij itself started with
+//! [`COPY_FILE_ARG`], which works where `cp` doesn't exist.
+
+use std::io::Write;
+
+use crate::app::state::App;
+use crate::jj::parser::Parser;
+use crate::model::{Hunk, LinePick, discard_lines, hunk_picks, squash_lines};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Diff editor name for the `--config` of a line squash
+const SQUASH_TOOL: &str = "tij-squash-lines";
+
+/// Hidden command-line argument: `tij --copy-file <from> <to>` copies a
+/// file and exits (the diff editor of a line squash)
+pub const COPY_FILE_ARG: &str = "--copy-file";
+
+/// Hunk headers (value `h`) each followed by their changed lines (`h:l`)
+fn pick_items(hunks: &[Hunk]) -> Vec<SelectItem> {
+    let mut items = Vec::new();
+    for (h, hunk) in hunks.iter().enumerate() {
+        items.push(SelectItem {
+            label: format!("{}  {}", hunk.header, hunk.summary()),
+            value: h.to_string(),
+            selected: false,
+        });
+        items.extend(hunk.changed_lines().map(|l| SelectItem {
+            label: format!("    {}", hunk.lines[l].label()),
+            value: format!("{}:{}", h, l),
+            selected: false,
+        }));
+    }
+    items
+}
+
+/// Picked lines of the checked items, in order and without duplicates
+fn parse_picks(values: &[String], hunks: &[Hunk]) -> Vec<LinePick> {
+    let mut picks: Vec<LinePick> = values
+        .iter()
+        .flat_map(|value| match value.split_once(':') {
+            Some((h, l)) => h.parse().ok().zip(l.parse().ok()).into_iter().collect(),
+            None => value
+                .parse()
+                .map(|h| hunk_picks(hunks, &[h]))
+                .unwrap_or_default(),
+        })
+        .collect();
+    picks.sort_unstable();
+    picks.dedup();
+    picks
+}
+
+/// `"text"` as a TOML basic string
+fn toml_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// `jj squash` whose diff editor (`copier --copy-file`) copies `prepared`
+/// over `$right/<root_path>`
+///
+/// `$right` is the workspace root, so it takes the root-relative path;
+/// `file_path` (as jj printed it) limits the squash to the file.
+/// `--keep-emptied` keeps `@` even when every change moved, so jj never
+/// asks for a combined description.
+fn squash_lines_args(
+    copier: &str,
+    prepared: &str,
+    root_path: &str,
+    file_path: &str,
+) -> Vec<String> {
+    let right = format!("$right/{}", root_path);
+    vec![
+        "squash".to_string(),
+        "--keep-emptied".to_string(),
+        "--tool".to_string(),
+        SQUASH_TOOL.to_string(),
+        "--config".to_string(),
+        format!(
+            "merge-tools.{}.program={}",
+            SQUASH_TOOL,
+            toml_string(copier)
+        ),
+        "--config".to_string(),
+        format!(
+            "merge-tools.{}.edit-args=[{}, {}, {}]",
+            SQUASH_TOOL,
+            toml_string(COPY_FILE_ARG),
+            toml_string(prepared),
+            toml_string(&right)
+        ),
+        "--".to_string(),
+        file_path.to_string(),
+    ]
+}
+
+/// `3 line(s)` or `3 line(s) in 2 hunk(s)` for messages
+fn picks_summary(picks: &[LinePick]) -> String {
+    let mut hunks: Vec<usize> = picks.iter().map(|&(h, _)| h).collect();
+    hunks.dedup();
+    match hunks.len() {
+        1 => format!("{} line(s)", picks.len()),
+        n => format!("{} line(s) in {} hunk(s)", picks.len(), n),
+    }
+}
+
+/// Added or deleted files can only be restored as a whole
+fn is_whole_file_change(git_diff: &str) -> bool {
+    git_diff
+        .lines()
+        .any(|line| line.starts_with("new file mode") || line.starts_with("deleted file mode"))
+}
+
+impl App {
+    /// Hunks of `file_path` in the working copy, or `None` (with a message)
+    /// when there is nothing to pick
+    fn working_copy_hunks(&mut self, file_path: &str) -> Option<Vec<Hunk>> {
+        let diff = match self.jj.diff_file_git("@", file_path) {
+            Ok(diff) => diff,
+            Err(e) => {
+                self.set_error(format!("Failed to get diff: {}", e));
+                return None;
+            }
+        };
+        if is_whole_file_change(&diff) {
+            self.notify_info(format!(
+                "{} was added or deleted: use r to restore the whole file",
+                file_path
+            ));
+            return None;
+        }
+        let hunks = Parser::parse_git_hunks(&diff);
+        if hunks.is_empty() {
+            self.notify_info(format!("No text hunks in {}", file_path));
+            return None;
+        }
+        Some(hunks)
+    }
+
+    /// List the file's hunks and lines for picking what to discard
+    pub(crate) fn start_hunk_restore(&mut self, file_path: &str) {
+        let Some(hunks) = self.working_copy_hunks(file_path) else {
+            return;
+        };
+        self.active_dialog = Some(Dialog::select(
+            "Discard Changes",
+            format!("Select hunks or lines to discard in {}:", file_path),
+            pick_items(&hunks),
+            Some("A hunk picks all its lines; unselected lines are kept".to_string()),
+            DialogCallback::HunkRestoreSelect {
+                file_path: file_path.to_string(),
+            },
+        ));
+    }
+
+    /// List the file's hunks and lines for picking what to move into @-
+    pub(crate) fn start_hunk_squash(&mut self, file_path: &str) {
+        let Some(hunks) = self.working_copy_hunks(file_path) else {
+            return;
+        };
+        self.active_dialog = Some(Dialog::select(
+            "Squash Lines",
+            format!("Select hunks or lines of {} to move into @-:", file_path),
+            pick_items(&hunks),
+            Some("A hunk picks all its lines; unselected lines stay in @".to_string()),
+            DialogCallback::HunkSquashSelect {
+                file_path: file_path.to_string(),
+            },
+        ));
+    }
+
+    /// Handle confirmed hunk restore dialogs
+    pub(crate) fn handle_hunk_restore_dialog(
+        &mut self,
+        callback: DialogCallback,
+        values: Vec<String>,
+    ) {
+        match callback {
+            DialogCallback::HunkRestoreSelect { file_path } => {
+                if values.is_empty() {
+                    self.notify_info("No lines selected");
+                    return;
+                }
+                self.confirm_hunk_restore(file_path, &values);
+            }
+            DialogCallback::HunkRestore { file_path, lines } => {
+                self.execute_hunk_restore(&file_path, &lines);
+            }
+            DialogCallback::HunkSquashSelect { file_path } => {
+                if values.is_empty() {
+                    self.notify_info("No lines selected");
+                    return;
+                }
+                self.execute_hunk_squash(&file_path, &values);
+            }
+            _ => {}
+        }
+    }
+
+    /// Confirmation listing the hunks with lines about to be discarded
+    fn confirm_hunk_restore(&mut self, file_path: String, values: &[String]) {
+        let Some(hunks) = self.working_copy_hunks(&file_path) else {
+            return;
+        };
+        let picks = parse_picks(values, &hunks);
+        let listing: Vec<String> = hunks
+            .iter()
+            .enumerate()
+            .filter_map(|(h, hunk)| {
+                let count = picks.iter().filter(|(picked, _)| *picked == h).count();
+                let total = hunk.changed_lines().count();
+                (count > 0).then(|| format!("  {}  {} of {} line(s)", hunk.header, count, total))
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::confirm(
+            "Discard Changes",
+            format!(
+                "Discard {} in '{}'?\n{}",
+                picks_summary(&picks),
+                file_path,
+                listing.join("\n")
+            ),
+            Some("Undo with 'u' if needed.".to_string()),
+            DialogCallback::HunkRestore {
+                file_path,
+                lines: picks,
+            },
+        ));
+    }
+
+    /// Revert the picked lines in the working copy file and snapshot it
+    fn execute_hunk_restore(&mut self, file_path: &str, picked: &[LinePick]) {
+        let Some(hunks) = self.working_copy_hunks(file_path) else {
+            return;
+        };
+        let path = match self.workspace_file(file_path) {
+            Ok((path, _)) => path,
+            Err(e) => {
+                self.set_error(e);
+                return;
+            }
+        };
+        let current = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_error(format!("Failed to read {}: {}", file_path, e));
+                return;
+            }
+        };
+        let Some(restored) = discard_lines(&current, &hunks, picked) else {
+            self.set_error(format!(
+                "{} changed since the lines were listed; nothing was discarded",
+                file_path
+            ));
+            return;
+        };
+        if let Err(e) = std::fs::write(&path, restored) {
+            self.set_error(format!("Failed to write {}: {}", file_path, e));
+            return;
+        }
+
+        if let Err(e) = self.run_and_record("Restore lines", &["debug", "snapshot"]) {
+            self.set_error(format!("Snapshot failed: {}", e));
+            return;
+        }
+        self.refresh_after_file_change(file_path);
+        self.notify_success(format!(
+            "Discarded {} in {} (undo: u)",
+            picks_summary(picked),
+            file_path
+        ));
+    }
+
+    /// Move the picked lines into @- with `jj squash`
+    fn execute_hunk_squash(&mut self, file_path: &str, values: &[String]) {
+        let Some(hunks) = self.working_copy_hunks(file_path) else {
+            return;
+        };
+        let picks = parse_picks(values, &hunks);
+        let parent = match self.jj.file_show(file_path, Some("@-")) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_error(format!("Failed to read {} in @-: {}", file_path, e));
+                return;
+            }
+        };
+        let Some(prepared) = squash_lines(&parent, &hunks, &picks) else {
+            self.set_error(format!(
+                "{} in @- doesn't match the diff; nothing was squashed",
+                file_path
+            ));
+            return;
+        };
+        let root_path = match self.workspace_file(file_path) {
+            Ok((_, relative)) => relative,
+            Err(e) => {
+                self.set_error(e);
+                return;
+            }
+        };
+        let copier = match std::env::current_exe() {
+            Ok(path) => path,
+            Err(e) => {
+                self.set_error(format!("Failed to locate the tij executable: {}", e));
+                return;
+            }
+        };
+        // Removed when dropped, after jj has run
+        let temp = tempfile::Builder::new()
+            .prefix("tij-squash-lines-")
+            .tempfile()
+            .and_then(|mut temp| temp.write_all(prepared.as_bytes()).map(|()| temp));
+        let temp = match temp {
+            Ok(temp) => temp,
+            Err(e) => {
+                self.set_error(format!("Failed to create temp file: {}", e));
+                return;
+            }
+        };
+        let args = squash_lines_args(
+            &copier.to_string_lossy(),
+            &temp.path().to_string_lossy(),
+            &root_path,
+            file_path,
+        );
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let result = self.run_and_record("Squash lines", &args);
+
+        match result {
+            Ok(_) => {
+                self.refresh_after_file_change(file_path);
+                self.notify_success(format!(
+                    "Squashed {} of {} into @- (undo: u)",
+                    picks_summary(&picks),
+                    file_path
+                ));
+            }
+            Err(e) => self.set_error(format!("Squash failed: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_whole_file_change_detection() {
+        assert!(is_whole_file_change(
+            "diff --git a/new.rs b/new.rs\nnew file mode 100644\nindex 0000000..1234567\n"
+        ));
+        assert!(is_whole_file_change(
+            "diff --git a/old.rs b/old.rs\ndeleted file mode 100644\n"
+        ));
+        assert!(!is_whole_file_change(
+            "diff --git a/lib.rs b/lib.rs\nindex 1234567..89abcde 100644\n@@ -1 +1 @@\n"
+        ));
+    }
+
+    fn sample_hunks() -> Vec<Hunk> {
+        Parser::parse_git_hunks("@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n@@ -9,0 +10,2 @@\n+x\n+y\n")
+    }
+
+    #[test]
+    fn test_pick_items_list_lines_under_their_hunk() {
+        let items = pick_items(&sample_hunks());
+        let values: Vec<&str> = items.iter().map(|item| item.value.as_str()).collect();
+        assert_eq!(values, ["0", "0:1", "0:2", "1", "1:0", "1:1"]);
+        assert_eq!(items[1].label, "    - b");
+    }
+
+    #[test]
+    fn test_parse_picks_expands_hunks() {
+        let hunks = sample_hunks();
+        let values: Vec<String> = ["1:1", "0", "0:2"].map(String::from).to_vec();
+        assert_eq!(parse_picks(&values, &hunks), [(0, 1), (0, 2), (1, 1)]);
+        assert_eq!(picks_summary(&[(0, 1), (1, 1)]), "2 line(s) in 2 hunk(s)");
+    }
+
+    #[test]
+    fn test_squash_lines_args_copy_prepared_file() {
+        // Run from `src/`: jj printed `lib.rs`, `$right` is the root
+        let args = squash_lines_args("/usr/bin/tij", "/tmp/tij \"1\"", "src/lib.rs", "lib.rs");
+        assert_eq!(
+            args[..4],
+            ["squash", "--keep-emptied", "--tool", SQUASH_TOOL]
+        );
+        assert_eq!(
+            args[5],
+            "merge-tools.tij-squash-lines.program=\"/usr/bin/tij\""
+        );
+        assert_eq!(
+            args[7],
+            "merge-tools.tij-squash-lines.edit-args=[\"--copy-file\", \"/tmp/tij \\\"1\\\"\", \"$right/src/lib.rs\"]"
+        );
+        assert_eq!(args[8..], ["--", "lib.rs"]);
+    }
+
+    #[test]
+    fn test_empty_pick_is_a_noop() {
+        let mut app = App::new_for_test();
+        app.handle_hunk_restore_dialog(
+            DialogCallback::HunkRestoreSelect {
+                file_path: "src/lib.rs".to_string(),
+            },
+            vec![],
+        );
+        assert!(app.active_dialog.is_none());
+        let notification = app.notification.as_ref().unwrap();
+        assert!(notification.message.contains("No lines selected"));
+    }
+}
@@ -0,0 +1,68 @@
+//! Read-only jj commands typed at the `:` prompt
+//!
+//! The output opens in the Pager View. Only allowlisted subcommands run
+//! (see `jj::check_read_only`), with `--no-integrate-operation` like every
+//! other read in tij, so nothing reaches the operation log.
+
+use crate::app::state::{App, View};
+use crate::jj::split_command_line;
+use crate::ui::components::{Dialog, DialogCallback};
+
+impl App {
+    /// Ask for a read-only jj command
+    pub(crate) fn start_jj_command(&mut self) {
+        self.active_dialog = Some(Dialog::input(
+            "Run jj Command (read-only)",
+            "log / show / diff / evolog / status / op log|show|diff / file list|show / ...",
+            DialogCallback::JjCommand,
+        ));
+    }
+
+    /// Run `line` and show its output in the Pager View
+    fn execute_jj_command(&mut self, line: &str) {
+        let args = match split_command_line(line) {
+            Ok(args) => args,
+            Err(e) => {
+                self.set_error(format!("Invalid command: {}", e));
+                return;
+            }
+        };
+        match self.jj.run_user_command(&args) {
+            Ok(output) => {
+                self.pager_view.set_output(args, &output);
+                self.go_to_view(View::Pager);
+            }
+            Err(e) => self.set_error(format!("jj {} failed: {}", line.trim(), e)),
+        }
+    }
+
+    /// Run the shown command again
+    pub(crate) fn refresh_pager_view(&mut self) {
+        let args = self.pager_view.command().to_vec();
+        match self.jj.run_user_command(&args) {
+            Ok(output) => self.pager_view.set_text(&output),
+            Err(e) => self.set_error(format!("{} failed: {}", self.pager_view.command_line(), e)),
+        }
+    }
+
+    /// Handle the command prompt result
+    pub(crate) fn handle_jj_command_dialog(&mut self, values: Vec<String>) {
+        if let Some(line) = values.first().filter(|line| !line.trim().is_empty()) {
+            self.execute_jj_command(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_command_is_refused_before_running() {
+        let mut app = App::new_for_test();
+        app.handle_jj_command_dialog(vec!["abandon @".to_string()]);
+        assert_eq!(app.current_view, View::Log);
+        let error = app.error_message.as_deref().unwrap_or_default();
+        assert!(error.contains("not a read-only command"), "{}", error);
+    }
+}
@@ -0,0 +1,371 @@
+//! Startup check of the `[tij.keys]` remaps
+//!
+//! A remap takes over whatever its key did before, so a typo in the config
+//! can silently hide an action. The remaps are compared against the key
+//! tables the help panel shows: keys whose built-in binding a remap (or a
+//! per-view remap over a global one) takes over, with the binding that
+//! wins, and actions no key reaches any more. Problems open the Diagnostics
+//! View at startup; the same sections are part of the full diagnostics.
+
+use crate::app::state::{App, View};
+use crate::keys::{
+    self, GLOBAL_KEYS, HELP_VIEW_ORDER, KEY_NAMES, KeyBindEntry, KeyRemap, KeymapPreset, key_name,
+    parse_key_name,
+};
+use crate::model::{DiagnosticItem, DiagnosticSection, DiagnosticStatus};
+
+use crossterm::event::KeyCode;
+
+/// A key whose built-in binding a remap takes over
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyConflict {
+    /// `Global` or the view's help title
+    scope: &'static str,
+    key: KeyCode,
+    /// What the key does now
+    winner: String,
+    /// What it did before
+    shadowed: String,
+}
+
+/// A binding no key reaches any more
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct UnboundAction {
+    scope: &'static str,
+    key: &'static str,
+    description: &'static str,
+}
+
+#[derive(Debug, Default)]
+struct KeymapReport {
+    conflicts: Vec<KeyConflict>,
+    unbound: Vec<UnboundAction>,
+}
+
+/// Unmodified keys of a help label (`j/k`, `Esc/^C`, `</>`)
+///
+/// Ctrl/Alt keys (`Ctrl+A/E` is Ctrl+A and Ctrl+E) and ranges like `a-z`
+/// are left out: remaps can't reach them.
+fn label_keys(label: &str) -> Vec<KeyCode> {
+    if label == "/" {
+        return vec![KeyCode::Char('/')];
+    }
+    let mut modified = false;
+    label
+        .split('/')
+        .filter_map(|token| {
+            let single = token.chars().count() == 1;
+            if !single && (token.contains('+') || token.starts_with('^')) {
+                modified = true;
+                return None;
+            }
+            if modified && single {
+                return None;
+            }
+            modified = false;
+            if token.chars().count() == 3 && token.chars().nth(1) == Some('-') {
+                return None;
+            }
+            parse_key_name(token)
+        })
+        .collect()
+}
+
+/// First entry bound to `code`
+fn binding(entries: &'static [KeyBindEntry], code: KeyCode) -> Option<&'static KeyBindEntry> {
+    entries
+        .iter()
+        .find(|entry| label_keys(entry.key).contains(&code))
+}
+
+/// What pressing a key that stands for `target` does
+fn describe_target(entries: &'static [KeyBindEntry], target: KeyCode) -> String {
+    match binding(entries, target).or_else(|| binding(GLOBAL_KEYS, target)) {
+        Some(entry) => format!("{} ({})", entry.description, key_name(target)),
+        None => format!("acts as {} (unbound here)", key_name(target)),
+    }
+}
+
+/// Whether any key reaches `entry` in `view` under `remaps`
+fn reachable(
+    preset: KeymapPreset,
+    remaps: &[KeyRemap],
+    view: View,
+    candidates: &[KeyCode],
+    entry: &KeyBindEntry,
+) -> bool {
+    let wanted = label_keys(entry.key);
+    wanted.is_empty()
+        || candidates
+            .iter()
+            .any(|code| wanted.contains(&keys::resolve_key(preset, remaps, view, *code)))
+}
+
+/// Conflicts and unbound actions of `scope` (`view` is `None` for the global keys)
+fn check_scope(
+    report: &mut KeymapReport,
+    preset: KeymapPreset,
+    remaps: &[KeyRemap],
+    view: Option<View>,
+) {
+    let global_remaps: Vec<KeyRemap> = remaps
+        .iter()
+        .filter(|r| r.view.is_none())
+        .copied()
+        .collect();
+    let (scope, entries, target_view) = match view {
+        Some(view) => match keys::view_keymap(view) {
+            Some((title, entries)) => (title, entries, view),
+            None => return,
+        },
+        // No remap or preset letter belongs to Help: only the global layer applies
+        None => ("Global", GLOBAL_KEYS, View::Help),
+    };
+
+    for remap in remaps {
+        let applies = match (view, remap.view) {
+            (None, None) => true,
+            (Some(view), Some(remap_view)) => view == remap_view,
+            // A global remap counts in a view unless the view remaps the key itself
+            (Some(view), None) => !remaps
+                .iter()
+                .any(|r| r.view == Some(view) && r.key == remap.key),
+            (None, Some(_)) => false,
+        };
+        if !applies {
+            continue;
+        }
+        let builtin = keys::translate_key(preset, target_view, remap.key);
+        let winner = describe_target(entries, remap.target);
+        // A per-view remap takes over the global remap first
+        if let Some(global) = global_remaps
+            .iter()
+            .filter(|_| remap.view.is_some())
+            .find(|r| r.key == remap.key && r.target != remap.target)
+        {
+            report.conflicts.push(KeyConflict {
+                scope,
+                key: remap.key,
+                winner: winner.clone(),
+                shadowed: format!("global remap to {}", key_name(global.target)),
+            });
+            continue;
+        }
+        if builtin == remap.target {
+            continue;
+        }
+        // Global remaps over global keys are reported once, under Global
+        let shadowed = match remap.view {
+            Some(_) => binding(entries, builtin).or_else(|| binding(GLOBAL_KEYS, builtin)),
+            None => binding(entries, builtin),
+        };
+        if let Some(shadowed) = shadowed {
+            report.conflicts.push(KeyConflict {
+                scope,
+                key: remap.key,
+                winner,
+                shadowed: format!("{} ({})", shadowed.description, shadowed.key),
+            });
+        }
+    }
+
+    // Every key a remap or preset can start from
+    let candidates: Vec<KeyCode> = (' '..='~')
+        .map(KeyCode::Char)
+        .chain(KEY_NAMES.iter().map(|(_, code)| *code))
+        .chain(remaps.iter().map(|remap| remap.key))
+        .collect();
+    for entry in entries {
+        if !reachable(preset, remaps, target_view, &candidates, entry) {
+            report.unbound.push(UnboundAction {
+                scope,
+                key: entry.key,
+                description: entry.description,
+            });
+        }
+    }
+    // Global keys lost only in this view (per-view remaps)
+    if view.is_some() {
+        for entry in GLOBAL_KEYS {
+            if !reachable(preset, remaps, target_view, &candidates, entry)
+                && reachable(preset, &global_remaps, View::Help, &candidates, entry)
+            {
+                report.unbound.push(UnboundAction {
+                    scope,
+                    key: entry.key,
+                    description: entry.description,
+                });
+            }
+        }
+    }
+}
+
+/// Conflicts and unbound actions of `remaps` over `preset`
+fn check_keymap(preset: KeymapPreset, remaps: &[KeyRemap]) -> KeymapReport {
+    let mut report = KeymapReport::default();
+    if remaps.is_empty() {
+        return report;
+    }
+    check_scope(&mut report, preset, remaps, None);
+    for view in HELP_VIEW_ORDER {
+        check_scope(&mut report, preset, remaps, Some(*view));
+    }
+    report
+}
+
+/// Diagnostics sections of the keymap: preset, remaps and their problems
+pub(super) fn keymap_sections(preset: KeymapPreset, remaps: &[KeyRemap]) -> Vec<DiagnosticSection> {
+    let report = check_keymap(preset, remaps);
+    let preset_name = match preset {
+        KeymapPreset::Vim => "vim",
+        KeymapPreset::Arrows => "arrows",
+    };
+    let mut sections = vec![DiagnosticSection {
+        title: "Keymap".to_string(),
+        items: vec![
+            DiagnosticItem::info("tij.keymap", preset_name),
+            DiagnosticItem::info("tij.keys remaps", remaps.len().to_string()),
+        ],
+    }];
+    if !report.conflicts.is_empty() {
+        sections.push(DiagnosticSection {
+            title: "Key conflicts (winner > shadowed)".to_string(),
+            items: report
+                .conflicts
+                .into_iter()
+                .map(|conflict| {
+                    DiagnosticItem::new(
+                        format!("{}: {}", conflict.scope, key_name(conflict.key)),
+                        format!("{} > {}", conflict.winner, conflict.shadowed),
+                        DiagnosticStatus::Missing,
+                    )
+                })
+                .collect(),
+        });
+    }
+    if !report.unbound.is_empty() {
+        sections.push(DiagnosticSection {
+            title: "Unbound actions".to_string(),
+            items: report
+                .unbound
+                .into_iter()
+                .map(|action| {
+                    DiagnosticItem::new(
+                        format!("{}: {}", action.scope, action.key),
+                        action.description,
+                        DiagnosticStatus::Missing,
+                    )
+                })
+                .collect(),
+        });
+    }
+    sections
+}
+
+impl App {
+    /// Open the keymap report at startup when the remaps conflict
+    pub(crate) fn run_keymap_check(&mut self) {
+        let sections = keymap_sections(self.keymap, &self.key_remaps);
+        let problems: usize = sections.iter().skip(1).map(|s| s.items.len()).sum();
+        if problems == 0 {
+            return;
+        }
+        self.diagnostics_view.set_sections(sections);
+        self.go_to_view(View::Diagnostics);
+        self.notify_warning(format!(
+            "{} keymap problem(s) in [tij.keys] (q to continue)",
+            problems
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remap(view: Option<View>, key: char, target: &str) -> KeyRemap {
+        KeyRemap {
+            view,
+            key: KeyCode::Char(key),
+            target: parse_key_name(target).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_label_keys() {
+        assert_eq!(label_keys("j/k"), [KeyCode::Char('j'), KeyCode::Char('k')]);
+        assert_eq!(label_keys("/"), [KeyCode::Char('/')]);
+        assert_eq!(label_keys("Esc/^C"), [KeyCode::Esc]);
+        assert!(label_keys("Ctrl+A/E").is_empty());
+        assert!(label_keys("a-z").is_empty());
+        assert_eq!(label_keys("+/-"), [KeyCode::Char('+'), KeyCode::Char('-')]);
+    }
+
+    #[test]
+    fn test_presets_without_remaps_are_clean() {
+        for preset in [KeymapPreset::Vim, KeymapPreset::Arrows] {
+            let report = check_keymap(preset, &[]);
+            assert!(report.conflicts.is_empty() && report.unbound.is_empty());
+        }
+        // A remap to a free key is no conflict either
+        let report = check_keymap(KeymapPreset::Vim, &[remap(Some(View::Log), '§', "d")]);
+        assert!(report.conflicts.is_empty() && report.unbound.is_empty());
+    }
+
+    #[test]
+    fn test_remap_over_bound_key_shadows_it() {
+        // p (preview) now describes; nothing reaches the preview toggle
+        let report = check_keymap(KeymapPreset::Vim, &[remap(Some(View::Log), 'p', "d")]);
+        assert_eq!(report.conflicts.len(), 1);
+        let conflict = &report.conflicts[0];
+        assert_eq!(conflict.scope, "Log View");
+        assert!(conflict.winner.starts_with("Describe"));
+        assert!(conflict.shadowed.starts_with("Toggle preview pane"));
+        assert!(
+            report
+                .unbound
+                .iter()
+                .any(|a| a.scope == "Log View" && a.key == "p")
+        );
+    }
+
+    #[test]
+    fn test_view_remap_wins_over_global_remap() {
+        let remaps = [remap(None, 'q', "?"), remap(Some(View::Diff), 'q', "Esc")];
+        let report = check_keymap(KeymapPreset::Vim, &remaps);
+        // The global remap takes over quit, reported once
+        assert!(
+            report
+                .conflicts
+                .iter()
+                .any(|c| c.scope == "Global" && c.shadowed.starts_with("Quit"))
+        );
+        assert!(
+            report
+                .conflicts
+                .iter()
+                .any(|c| c.scope == "Diff View" && c.shadowed == "global remap to ?")
+        );
+        assert!(
+            report
+                .unbound
+                .iter()
+                .any(|a| a.scope == "Global" && a.key == "q")
+        );
+    }
+
+    #[test]
+    fn test_sections_list_problems_after_summary() {
+        let sections = keymap_sections(KeymapPreset::Vim, &[remap(Some(View::Log), 'p', "d")]);
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            [
+                "Keymap",
+                "Key conflicts (winner > shadowed)",
+                "Unbound actions"
+            ]
+        );
+        assert_eq!(sections[1].items[0].label, "Log View: p");
+    }
+}
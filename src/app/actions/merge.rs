@@ -0,0 +1,213 @@
+//! Built-in merge editor operations (open, edit region, write back)
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, ExitStatus};
+
+use super::suspend_tui;
+use crate::app::state::{App, DirtyFlags, View};
+use crate::jj::JjExecutor;
+use crate::jj::constants::config_keys;
+use crate::jj::parser::Parser;
+use crate::model::{MergeChoice, MergeSegment};
+use crate::ui::views::MergeView;
+
+/// Editor for hand-editing a file, in jj's order: `$JJ_EDITOR`, jj's
+/// `ui.editor`, `$VISUAL`, `$EDITOR`, then `vi`
+fn editor_command(ui_editor: Option<&str>) -> Vec<String> {
+    let env = |var: &str| std::env::var(var).ok().filter(|v| !v.trim().is_empty());
+    let words = |command: String| command.split_whitespace().map(str::to_string).collect();
+    env("JJ_EDITOR")
+        .map(words)
+        .or_else(|| ui_editor.map(parse_editor_value).filter(|e| !e.is_empty()))
+        .or_else(|| env("VISUAL").or_else(|| env("EDITOR")).map(words))
+        .unwrap_or_else(|| vec!["vi".to_string()])
+}
+
+/// Arguments of a `ui.editor` value as `jj config get` prints it: a plain
+/// command line, or a TOML array such as `["code", "-w"]`
+fn parse_editor_value(value: &str) -> Vec<String> {
+    let value = value.trim();
+    if value.starts_with('[') {
+        // Quoted items are every other piece between the quotes
+        value
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            .map(str::to_string)
+            .collect()
+    } else {
+        value
+            .trim_matches('"')
+            .split_whitespace()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Open `path` in the user's editor with inherited stdio
+pub(super) fn run_editor(jj: &JjExecutor, path: &Path) -> io::Result<ExitStatus> {
+    let ui_editor = jj.config_get(config_keys::UI_EDITOR).ok().flatten();
+    let editor = editor_command(ui_editor.as_deref());
+    let (program, args) = editor.split_first().expect("editor_command is never empty");
+    Command::new(program).args(args).arg(path).status()
+}
+
+impl App {
+    /// Open the merge editor for a conflicted file in the working copy
+    pub(crate) fn open_merge_view(&mut self, file_path: &str) {
+        let Some(revision) = self.resolve_view.as_ref().map(|v| v.revision.clone()) else {
+            return;
+        };
+
+        let content = match self.jj.file_show(file_path, Some(&revision)) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_error(format!("Failed to load {}: {}", file_path, e));
+                return;
+            }
+        };
+
+        let segments = match Parser::parse_merge_segments(&content) {
+            Ok(segments) => segments,
+            Err(e) => {
+                self.notify_warning(format!("Merge editor unavailable: {}", e));
+                return;
+            }
+        };
+        if !segments
+            .iter()
+            .any(|s| matches!(s, MergeSegment::Conflict(_)))
+        {
+            self.notify_info(format!("No conflict markers in {}", file_path));
+            return;
+        }
+
+        self.merge_view = Some(MergeView::new(
+            revision,
+            file_path.to_string(),
+            segments,
+            &content,
+        ));
+        self.go_to_view(View::Merge);
+    }
+
+    /// Close the merge editor and return to the resolve view
+    pub(crate) fn close_merge_view(&mut self) {
+        self.merge_view = None;
+        self.go_to_view(View::Resolve);
+        // Merge view is gone; don't let Resolve's "back" return to it
+        self.previous_view = Some(View::Log);
+    }
+
+    /// Hand-edit the current region in an external editor
+    ///
+    /// The temp file is pre-filled with the current resolution, or both
+    /// sides if the region is still unresolved.
+    pub(crate) fn execute_merge_edit_region(&mut self) {
+        let Some(ref view) = self.merge_view else {
+            return;
+        };
+        let Some(region) = view.current_region() else {
+            return;
+        };
+        let initial = region.resolved_lines().unwrap_or_else(|| {
+            region
+                .ours
+                .iter()
+                .chain(region.theirs.iter())
+                .cloned()
+                .collect()
+        });
+        let file_name = Path::new(&view.file_path)
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut text = initial.join("\n");
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        // Keeps the file name for the editor's syntax detection; removed
+        // when dropped, after the edit has been read back
+        let temp = tempfile::Builder::new()
+            .prefix("tij-merge-")
+            .suffix(&format!("-{}", file_name))
+            .tempfile()
+            .and_then(|mut temp| temp.write_all(text.as_bytes()).map(|()| temp));
+        let temp = match temp {
+            Ok(temp) => temp,
+            Err(e) => {
+                self.set_error(format!("Failed to create temp file: {}", e));
+                return;
+            }
+        };
+
+        let result = {
+            let _guard = suspend_tui();
+            run_editor(&self.jj, temp.path())
+        };
+        let edited = std::fs::read_to_string(temp.path());
+        drop(temp);
+
+        match (result, edited) {
+            (Ok(status), Ok(edited)) if status.success() => {
+                let lines = edited.lines().map(str::to_string).collect();
+                if let Some(ref mut view) = self.merge_view {
+                    view.choose(MergeChoice::Edited(lines));
+                }
+            }
+            (Ok(_), _) => self.notify_info("Edit cancelled"),
+            (Err(e), _) => self.set_error(format!("Failed to run editor: {}", e)),
+        }
+    }
+
+    /// Write the merged result to the working copy and snapshot it
+    ///
+    /// jj marks the file resolved once the snapshot no longer contains
+    /// conflict markers.
+    pub(crate) fn execute_merge_write(&mut self) {
+        let Some(ref view) = self.merge_view else {
+            return;
+        };
+        let Some(content) = view.merged_content() else {
+            return;
+        };
+        let file_path = view.file_path.clone();
+
+        let path = match self.workspace_file(&file_path) {
+            Ok((path, _)) => path,
+            Err(e) => {
+                self.set_error(e);
+                return;
+            }
+        };
+        if let Err(e) = std::fs::write(path, content) {
+            self.set_error(format!("Failed to write {}: {}", file_path, e));
+            return;
+        }
+
+        if let Err(e) = self.run_and_record("Merge editor", &["debug", "snapshot"]) {
+            self.set_error(format!("Snapshot failed: {}", e));
+            return;
+        }
+
+        self.close_merge_view();
+        self.notify_success(format!("Resolved {} (undo: u)", file_path));
+        // Back in the Resolve View: this also reloads its conflict list
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_editor_value() {
+        assert_eq!(parse_editor_value("nvim -f"), ["nvim", "-f"]);
+        assert_eq!(parse_editor_value("\"hx\"\n"), ["hx"]);
+        assert_eq!(parse_editor_value(r#"["code", "-w"]"#), ["code", "-w"]);
+        assert!(parse_editor_value("").is_empty());
+    }
+}
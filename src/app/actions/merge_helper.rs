@@ -0,0 +1,335 @@
+//! Merge helper: create a merge change from bookmarks in one guided flow
+//!
+//! `Ctrl+N` in Log View asks for the first parent ("merge into"), then the
+//! bookmarks/revisions merged into it, then a description pre-filled as
+//! `Merge X into Y`. The merge is created with `jj new <parents> -m <msg>`;
+//! if it has conflicts the Resolve View opens on the new `@`.
+//!
+//! Changes marked with Space in Log View can also be merged directly: `C`
+//! confirms the marked parents (in mark order) and runs `jj new a b c`,
+//! optionally opening the describe editor on the result.
+
+use crate::app::state::{App, DirtyFlags};
+use crate::model::short_id;
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// `Merge a, b into main`
+fn default_merge_message(target: &str, sources: &[String]) -> String {
+    format!("Merge {} into {}", sources.join(", "), target)
+}
+
+/// Choice values of the marked-parents dialog
+const MARKED_CREATE: &str = "create";
+const MARKED_CREATE_DESCRIBE: &str = "describe";
+
+/// Dialog label for a candidate: bookmark names as is, the selected change
+/// with a marker
+fn candidate_label(value: &str, change_id: &str, id_length: usize) -> String {
+    if value == change_id {
+        format!("{} (selected change)", short_id(value, id_length))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Display name used in the description: short id for the selected change
+fn candidate_name(value: &str, change_id: &str, id_length: usize) -> String {
+    if value == change_id {
+        short_id(value, id_length).to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+impl App {
+    /// Merge candidates: the selected change followed by local bookmarks
+    fn merge_candidates(&mut self, change_id: &str) -> Option<Vec<String>> {
+        match self.jj.bookmark_list_all() {
+            Ok(bookmarks) => {
+                let mut candidates = vec![change_id.to_string()];
+                candidates.extend(
+                    bookmarks
+                        .into_iter()
+                        .filter(|b| b.remote.is_none())
+                        .map(|b| b.name),
+                );
+                Some(candidates)
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to list bookmarks: {}", e));
+                None
+            }
+        }
+    }
+
+    /// Step 1: pick the first parent of the merge
+    pub(crate) fn start_merge_helper(&mut self, change_id: &str) {
+        let Some(candidates) = self.merge_candidates(change_id) else {
+            return;
+        };
+        if candidates.len() < 2 {
+            self.notify_info("Merge needs at least one local bookmark besides the selected change");
+            return;
+        }
+        let items = candidates
+            .iter()
+            .map(|value| SelectItem {
+                label: candidate_label(value, change_id, self.id_length),
+                value: value.clone(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select_single(
+            "Merge (1/3)",
+            "Merge into (first parent):",
+            items,
+            None,
+            DialogCallback::MergeTarget {
+                change_id: change_id.to_string(),
+            },
+        ));
+    }
+
+    /// Step 2: pick what gets merged into `target`
+    fn select_merge_sources(&mut self, change_id: &str, target: &str) {
+        let Some(candidates) = self.merge_candidates(change_id) else {
+            return;
+        };
+        let items = candidates
+            .iter()
+            .filter(|value| value.as_str() != target)
+            .map(|value| SelectItem {
+                label: candidate_label(value, change_id, self.id_length),
+                value: value.clone(),
+                // Common case: selected feature change merged into a bookmark
+                selected: value == change_id,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select(
+            "Merge (2/3)",
+            format!(
+                "Merge into {}:",
+                candidate_name(target, change_id, self.id_length)
+            ),
+            items,
+            Some("Each checked item becomes another parent of the merge".to_string()),
+            DialogCallback::MergeSources {
+                change_id: change_id.to_string(),
+                target: target.to_string(),
+            },
+        ));
+    }
+
+    /// Confirm the parents marked in Log View before creating the merge change
+    pub(crate) fn start_new_merge_change(&mut self, parents: Vec<String>, labels: Vec<String>) {
+        let items = [
+            (MARKED_CREATE, "Create"),
+            (MARKED_CREATE_DESCRIBE, "Create and describe"),
+        ]
+        .into_iter()
+        .map(|(value, label)| SelectItem {
+            label: label.to_string(),
+            value: value.to_string(),
+            selected: false,
+        })
+        .collect();
+        let parent_list = labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| format!("{}. {}", i + 1, label))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.active_dialog = Some(Dialog::select_single(
+            "New Merge Change",
+            format!("New change with {} parents:", parents.len()),
+            items,
+            Some(parent_list),
+            DialogCallback::MergeMarked { parents },
+        ));
+    }
+
+    /// Handle the merge helper dialog steps
+    pub(crate) fn handle_merge_helper_dialog(
+        &mut self,
+        callback: DialogCallback,
+        values: Vec<String>,
+    ) {
+        match callback {
+            DialogCallback::MergeTarget { change_id } => {
+                if let Some(target) = values.first() {
+                    self.select_merge_sources(&change_id, target);
+                }
+            }
+            DialogCallback::MergeSources { change_id, target } => {
+                let names: Vec<String> = values
+                    .iter()
+                    .map(|value| candidate_name(value, &change_id, self.id_length))
+                    .collect();
+                let default_message = default_merge_message(
+                    &candidate_name(&target, &change_id, self.id_length),
+                    &names,
+                );
+                let mut parents = vec![target];
+                parents.extend(values);
+                self.active_dialog = Some(Dialog::input(
+                    "Merge (3/3)",
+                    format!("Description (empty: \"{}\")", default_message),
+                    DialogCallback::MergeDescribe {
+                        parents,
+                        default_message,
+                    },
+                ));
+            }
+            DialogCallback::MergeDescribe {
+                parents,
+                default_message,
+            } => {
+                let message = values
+                    .first()
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or(&default_message);
+                self.execute_merge(&parents, message);
+            }
+            DialogCallback::MergeMarked { parents } => {
+                if let Some(choice) = values.first() {
+                    self.execute_merge_marked(&parents, choice == MARKED_CREATE_DESCRIBE);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Create the merge change and open conflict resolution if needed
+    fn execute_merge(&mut self, parents: &[String], message: &str) {
+        let mut args = vec!["new"];
+        args.extend(parents.iter().map(String::as_str));
+        args.extend(["-m", message]);
+        if let Err(e) = self.run_and_record("Merge", &args) {
+            self.set_error(format!("Merge failed: {}", e));
+            return;
+        }
+        self.mark_dirty_and_refresh_current(DirtyFlags::working_copy());
+        if !self.open_merge_conflicts() {
+            self.notify_success(format!("Created merge change: {}", message));
+        }
+    }
+
+    /// Create a change whose parents are the marked changes, then clear the marks
+    fn execute_merge_marked(&mut self, parents: &[String], describe: bool) {
+        let mut args = vec!["new"];
+        args.extend(parents.iter().map(String::as_str));
+        if let Err(e) = self.run_and_record("New merge", &args) {
+            self.set_error(format!("Failed to create merge change: {}", e));
+            return;
+        }
+        self.log_view.marked.clear();
+        self.mark_dirty_and_refresh_current(DirtyFlags::working_copy());
+        if self.open_merge_conflicts() {
+            return;
+        }
+        if describe {
+            self.start_describe_input("@");
+        } else {
+            self.notify_success(format!(
+                "Created merge change with {} parents",
+                parents.len()
+            ));
+        }
+    }
+
+    /// Open the Resolve View when the new `@` has conflicts
+    ///
+    /// Returns true if it was opened.
+    fn open_merge_conflicts(&mut self) -> bool {
+        // `jj resolve --list` fails when there is nothing to resolve
+        let conflicts = self.jj.resolve_list(Some("@")).unwrap_or_default();
+        if conflicts.is_empty() {
+            return false;
+        }
+        self.open_resolve_view("@", true);
+        self.notify_warning(format!(
+            "Merge has conflicts in {} file(s): resolve them here",
+            conflicts.len()
+        ));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::components::DialogKind;
+
+    #[test]
+    fn test_default_merge_message() {
+        let sources = vec!["feature".to_string(), "fix".to_string()];
+        assert_eq!(
+            default_merge_message("main", &sources),
+            "Merge feature, fix into main"
+        );
+    }
+
+    #[test]
+    fn test_candidate_names_shorten_selected_change() {
+        let change_id = "abcdefghijklmnop";
+        assert_eq!(
+            candidate_name(change_id, change_id, 8),
+            short_id(change_id, 8)
+        );
+        assert_eq!(candidate_name("main", change_id, 8), "main");
+        assert!(candidate_label(change_id, change_id, 8).ends_with("(selected change)"));
+    }
+
+    #[test]
+    fn test_marked_parents_dialog_lists_parents_in_order() {
+        let mut app = App::new_for_test();
+        app.start_new_merge_change(
+            vec!["aaa".to_string(), "bbb".to_string()],
+            vec!["main".to_string(), "xyz98765".to_string()],
+        );
+        let dialog = app.active_dialog.as_ref().unwrap();
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::MergeMarked {
+                parents: vec!["aaa".to_string(), "bbb".to_string()],
+            }
+        );
+        match &dialog.kind {
+            DialogKind::Select { detail, items, .. } => {
+                assert_eq!(detail.as_deref(), Some("1. main\n2. xyz98765"));
+                assert_eq!(items[1].value, MARKED_CREATE_DESCRIBE);
+            }
+            other => panic!("unexpected dialog: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sources_step_prefills_description() {
+        let mut app = App::new_for_test();
+        app.handle_merge_helper_dialog(
+            DialogCallback::MergeSources {
+                change_id: "abcdefghijklmnop".to_string(),
+                target: "main".to_string(),
+            },
+            vec!["abcdefghijklmnop".to_string(), "fix".to_string()],
+        );
+        let dialog = app.active_dialog.as_ref().unwrap();
+        match &dialog.callback_id {
+            DialogCallback::MergeDescribe {
+                parents,
+                default_message,
+            } => {
+                assert_eq!(parents, &["main", "abcdefghijklmnop", "fix"]);
+                assert_eq!(
+                    default_message,
+                    &format!(
+                        "Merge {}, fix into main",
+                        short_id("abcdefghijklmnop", app.id_length)
+                    )
+                );
+            }
+            other => panic!("unexpected callback: {:?}", other),
+        }
+    }
+}
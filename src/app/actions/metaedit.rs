@@ -0,0 +1,151 @@
+//! Metaedit: edit a change's author, change-id and timestamps
+//!
+//! `v` in Log View opens a menu of `jj metaedit` operations on the selected
+//! change. Resetting the author names the configured identity
+//! (`user.name`/`user.email`), and "Set author..." starts from the current
+//! author so that only the name or the email needs editing.
+
+use crate::app::state::App;
+use crate::jj::constants::config_keys;
+use crate::model::short_id;
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// `Name <email>`, as `--author` expects it
+///
+/// Returns the trimmed signature, or None when the name or the `<email>`
+/// part is missing.
+fn parse_author(input: &str) -> Option<&str> {
+    let signature = input.trim();
+    let (name, rest) = signature.split_once('<')?;
+    let email = rest.strip_suffix('>')?;
+    if name.trim().is_empty() || email.trim().is_empty() || email.contains(['<', '>']) {
+        return None;
+    }
+    Some(signature)
+}
+
+/// Label of the reset entry, naming the identity when it is configured
+fn reset_author_label(identity: Option<&str>) -> String {
+    match identity {
+        Some(identity) => format!("Reset author to {}", identity),
+        None => "Reset author to configured user".to_string(),
+    }
+}
+
+impl App {
+    /// The configured identity as `Name <email>`, if both parts are set
+    fn configured_author(&self) -> Option<String> {
+        let name = self.jj.config_get(config_keys::USER_NAME).ok()??;
+        let email = self.jj.config_get(config_keys::USER_EMAIL).ok()??;
+        Some(format!("{} <{}>", name, email))
+    }
+
+    /// Open the metaedit menu for a change (immutable commits are refused)
+    pub(crate) fn start_metaedit(&mut self, commit_id: String, change_id: String) {
+        if self.jj.is_immutable(&commit_id) {
+            self.set_error("Cannot metaedit: commit is immutable");
+            return;
+        }
+        let identity = self.configured_author();
+        let items = [
+            ("update-author", reset_author_label(identity.as_deref())),
+            ("set-author", "Set author...".to_string()),
+            ("update-timestamp", "Update author timestamp".to_string()),
+            (
+                "reset-author-timestamp",
+                "Reset author and update timestamp".to_string(),
+            ),
+            ("new-change-id", "Generate new change-id".to_string()),
+            ("force-rewrite", "Force rewrite".to_string()),
+        ]
+        .into_iter()
+        .map(|(value, label)| SelectItem {
+            label,
+            value: value.to_string(),
+            selected: false,
+        })
+        .collect();
+        self.active_dialog = Some(Dialog::select_single(
+            "Metaedit",
+            format!("Edit metadata for {}", short_id(&change_id, self.id_length)),
+            items,
+            None,
+            DialogCallback::MetaeditSelect {
+                commit_id,
+                change_id,
+            },
+        ));
+    }
+
+    /// Ask for the new author, pre-filled with the current one
+    pub(crate) fn start_metaedit_set_author(&mut self, commit_id: String, change_id: String) {
+        let current = self.jj.author_signature(&commit_id).unwrap_or_default();
+        self.active_dialog = Some(Dialog::input_with_text(
+            "Set author",
+            "Enter author (Name <email>):",
+            &current,
+            DialogCallback::MetaeditSetAuthor {
+                commit_id,
+                change_id,
+            },
+        ));
+    }
+
+    /// Run `jj metaedit --author` once the input looks like `Name <email>`
+    pub(crate) fn execute_metaedit_set_author(
+        &mut self,
+        commit_id: &str,
+        change_id: &str,
+        input: &str,
+    ) {
+        if input.trim().is_empty() {
+            return;
+        }
+        match parse_author(input) {
+            Some(author) => self.execute_metaedit(commit_id, change_id, &["--author", author]),
+            None => self.set_error(format!(
+                "Invalid author \"{}\": expected Name <email>",
+                input.trim()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_author() {
+        assert_eq!(
+            parse_author("  Test User <test@example.com> "),
+            Some("Test User <test@example.com>")
+        );
+        assert_eq!(parse_author("test@example.com"), None);
+        assert_eq!(parse_author("<test@example.com>"), None);
+        assert_eq!(parse_author("Test User <>"), None);
+        assert_eq!(parse_author("Test User <test@example.com"), None);
+    }
+
+    #[test]
+    fn test_reset_author_label_names_identity() {
+        assert_eq!(
+            reset_author_label(Some("Test User <test@example.com>")),
+            "Reset author to Test User <test@example.com>"
+        );
+        assert_eq!(reset_author_label(None), "Reset author to configured user");
+    }
+
+    #[test]
+    fn test_invalid_author_is_rejected_without_running() {
+        let mut app = App::new_for_test();
+        app.execute_metaedit_set_author("def67890", "abc12345", "test@example.com");
+        assert!(
+            app.error_message
+                .as_deref()
+                .unwrap()
+                .contains("Name <email>")
+        );
+        assert!(app.command_history.is_empty());
+    }
+}
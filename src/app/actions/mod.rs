@@ -1,31 +1,84 @@
 //! jj operations (actions that modify repository state)
 
+mod backup;
 mod bookmark;
+mod bookmark_reconcile;
+mod bookmark_retarget;
+mod browse;
+mod bug_report;
+mod cherry_pick;
+mod compare_session;
+mod config_check;
+mod diagnostics;
 mod dialog;
+mod diff_menu;
+mod diff_options;
+mod diff_stats;
+mod difftool;
+mod gitignore;
+mod hook;
+mod hunk_restore;
+mod jj_command;
+mod keymap_check;
+mod merge;
+mod merge_helper;
+mod metaedit;
+mod network_cancel;
+mod note;
+mod op_preview;
+mod op_prune;
+mod op_restore;
+mod parse_report;
+mod pin;
+mod preview;
+mod pull_request;
 mod push;
+mod push_queue;
+mod rebase_destination;
+mod remote;
+mod reorder;
+mod revert;
+mod revset_preset;
+mod sparse;
+mod split;
+mod squash;
+mod stack;
+mod stack_rebase;
+mod status_bar;
 mod tag;
 mod workspace;
+mod yank;
 
+pub use hunk_restore::COPY_FILE_ARG;
+
+use std::collections::HashMap;
 use std::io;
+use std::path::PathBuf;
 use std::process::ExitStatus;
-use std::time::{Instant, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 
+use crate::jj::constants::config_keys;
 use crate::jj::{JjError, RunResult};
 use crate::model::{
-    CommandRecord, CommandStatus, CompareInfo, DiffContent, DiffDisplayFormat, DiffMode,
-    Notification, RebaseMode,
+    Bookmark, CommandRecord, CommandStatus, CompareInfo, DiffContent, DiffDisplayFormat, DiffMode,
+    Notification, RebaseMode, TrackingCounts,
 };
-use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+use crate::ui::components::{BatchResult, Dialog, DialogCallback, SelectItem};
 
+use crate::app::helpers::paths::root_relative;
 use crate::app::helpers::revision::{SelectedRevision, is_root_by_commit_id, short_id};
 
+use super::auto_fetch::AutoFetch;
 use super::state::{App, DirtyFlags, View};
 
+/// Errors kept in `App::recent_errors` for bug reports
+const MAX_RECENT_ERRORS: usize = 20;
+
 /// Suspend TUI mode (raw mode off, leave alternate screen).
 ///
 /// Returns a scope guard that restores TUI mode on drop.
 /// Use this before running interactive jj commands (describe --edit, split, diffedit, resolve).
-fn suspend_tui() -> impl Drop {
+pub(crate) fn suspend_tui() -> impl Drop {
     use crossterm::execute;
     use crossterm::terminal::{
         Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
@@ -61,8 +114,29 @@ impl App {
     }
 
     /// Set an error message (displayed in error area)
+    /// Locate `file_path` as jj printed it: its absolute path and its path
+    /// relative to the workspace root (`Err` holds a message)
+    pub(crate) fn workspace_file(&self, file_path: &str) -> Result<(PathBuf, String), String> {
+        let root = self
+            .jj
+            .workspace_root()
+            .map_err(|e| format!("Failed to locate workspace root: {}", e))?;
+        let root = PathBuf::from(root);
+        let cwd = std::env::current_dir()
+            .map_err(|e| format!("Failed to read the current directory: {}", e))?;
+        let relative = root_relative(&root, &cwd, file_path)
+            .ok_or_else(|| format!("{} is outside the workspace", file_path))?;
+        Ok((root.join(&relative), relative))
+    }
+
     pub(crate) fn set_error(&mut self, msg: impl Into<String>) {
-        self.error_message = Some(msg.into());
+        let msg = msg.into();
+        if self.recent_errors.len() == MAX_RECENT_ERRORS {
+            self.recent_errors.pop_front();
+        }
+        self.recent_errors
+            .push_back((SystemTime::now(), msg.clone()));
+        self.error_message = Some(msg);
     }
 
     // ── Command history recording helpers ─────────────────────────────
@@ -78,6 +152,7 @@ impl App {
         start: Instant,
         result: &Result<RunResult, JjError>,
     ) {
+        self.note_cancelled_network(args, result.as_ref().err());
         let (status, error) = match result {
             Ok(_) => (CommandStatus::Success, None),
             Err(e) => (CommandStatus::Failed, Some(e.to_string())),
@@ -142,6 +217,7 @@ impl App {
         start: Instant,
         result: &Result<String, JjError>,
     ) {
+        self.note_cancelled_network(args, result.as_ref().err());
         let (status, error) = match result {
             Ok(_) => (CommandStatus::Success, None),
             Err(e) => (CommandStatus::Failed, Some(e.to_string())),
@@ -179,6 +255,14 @@ impl App {
         }
     }
 
+    /// List each item of a batch with its outcome in a Results dialog
+    ///
+    /// Used when part of a multi-item operation failed, so every error is
+    /// readable instead of being joined into one truncated line.
+    fn show_batch_results(&mut self, title: &str, message: String, rows: Vec<BatchResult>) {
+        self.active_dialog = Some(Dialog::results(title, message, rows));
+    }
+
     /// Execute undo operation
     ///
     /// jj 0.39+ outputs "Undid operation: ..." to stderr.
@@ -224,20 +308,13 @@ impl App {
 
     /// Start describe input mode by fetching the full description
     ///
-    /// If the description is multi-line, automatically opens the external
-    /// editor instead of the 1-line input bar to prevent data loss.
+    /// The whole (possibly multi-line) description is loaded into the
+    /// in-TUI editor; Ctrl+E still opens the external editor instead.
     pub(crate) fn start_describe_input(&mut self, revision: &str) {
         // Fetch the full (multi-line) description from jj
         match self.jj.get_description(revision) {
             Ok(full_description) => {
                 let description = full_description.trim_end_matches('\n').to_string();
-
-                // Multi-line: fall through to external editor directly
-                if description.lines().nth(1).is_some() {
-                    self.execute_describe_external(revision);
-                    return;
-                }
-
                 self.log_view
                     .set_describe_input(revision.to_string(), description);
             }
@@ -312,7 +389,7 @@ impl App {
         }
 
         // Refresh views (only on success — refresh_log clears error_message)
-        self.mark_dirty_and_refresh_current(DirtyFlags::log());
+        self.mark_dirty_and_refresh_current(DirtyFlags::rewrite_metadata());
     }
 
     /// Execute describe operation
@@ -322,16 +399,16 @@ impl App {
             result,
             "Failed to update description",
             "Description updated",
-            DirtyFlags::log(),
+            DirtyFlags::rewrite_metadata(),
         );
     }
 
     /// Execute edit operation (set working-copy to specified change)
     pub(crate) fn execute_edit(&mut self, revision: &str) {
-        let short_id = short_id(revision);
+        let short_id = short_id(revision, self.id_length);
         let msg = format!("Now editing: {}", short_id);
         let result = self.run_and_record("Edit", &["edit", revision]);
-        self.run_jj_action(result, "Failed to edit", &msg, DirtyFlags::log_and_status());
+        self.run_jj_action(result, "Failed to edit", &msg, DirtyFlags::working_copy());
     }
 
     /// Execute new change operation
@@ -341,7 +418,7 @@ impl App {
             result,
             "Failed to create change",
             "Created new change",
-            DirtyFlags::log_and_status(),
+            DirtyFlags::working_copy(),
         );
     }
 
@@ -353,7 +430,7 @@ impl App {
             result,
             "Failed to create change",
             &msg,
-            DirtyFlags::log_and_status(),
+            DirtyFlags::working_copy(),
         );
     }
 
@@ -393,8 +470,8 @@ impl App {
         // 4. Handle result (io::Result<ExitStatus>)
         match result {
             Ok(status) if status.success() => {
-                let src_short = short_id(source);
-                let dst_short = short_id(destination);
+                let src_short = short_id(source, self.id_length);
+                let dst_short = short_id(destination, self.id_length);
                 self.notify_success(format!(
                     "Squashed {} into {} (undo: u)",
                     src_short, dst_short
@@ -414,21 +491,101 @@ impl App {
         self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
     }
 
+    /// Squash @ into the selected revision (quick amend / fix-up)
+    ///
+    /// Unlike `S`, there is no destination selection: `jj squash --from @
+    /// --into <rev> --use-destination-message` keeps the destination's
+    /// description so no editor opens. Conflicts introduced in the
+    /// destination or its descendants are reported afterwards.
+    pub(crate) fn execute_quick_amend(&mut self, destination: &str, change_id: &str) {
+        let target = self
+            .log_view
+            .changes
+            .iter()
+            .find(|c| c.commit_id.as_str() == destination);
+        if target.is_some_and(|c| c.is_working_copy) {
+            self.notify_info("Select a revision below @ to amend into");
+            return;
+        }
+        if target.is_some_and(|c| c.is_immutable)
+            || is_root_by_commit_id(&self.log_view.changes, destination)
+        {
+            self.notify_info(format!(
+                "Cannot amend into {}: immutable",
+                short_id(destination, self.id_length)
+            ));
+            return;
+        }
+        if self
+            .log_view
+            .changes
+            .iter()
+            .any(|c| c.is_working_copy && c.is_empty)
+        {
+            self.notify_info("Nothing to amend: @ has no changes");
+            return;
+        }
+
+        // Conflicts already present below the destination are not ours to report
+        let affected = format!("{}::", change_id);
+        let conflicts_before = self.jj.count_conflicts(&affected).unwrap_or(0);
+
+        let result = self.run_and_record(
+            "Quick amend",
+            &[
+                "squash",
+                "--from",
+                "@",
+                "--into",
+                destination,
+                "--use-destination-message",
+            ],
+        );
+        if let Err(e) = result {
+            self.set_error(format!("Amend failed: {}", e));
+            return;
+        }
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+
+        let conflicts_after = self.jj.count_conflicts(&affected).unwrap_or(0);
+        let short = short_id(destination, self.id_length);
+        if conflicts_after > conflicts_before {
+            self.notify_warning(format!(
+                "Amended @ into {} with conflicts in {} change(s) - resolve with X (undo: u)",
+                short,
+                conflicts_after - conflicts_before
+            ));
+        } else {
+            self.notify_success(format!("Amended @ into {} (undo: u)", short));
+        }
+    }
+
     /// Execute abandon operation (abandon a change)
     pub(crate) fn execute_abandon(&mut self, revision: &str) {
         if is_root_by_commit_id(&self.log_view.changes, revision) {
             self.notify_info("Cannot abandon: root commit");
             return;
         }
-        let short_id = short_id(revision);
-        let msg = format!("Abandoned {} (undo: u)", short_id);
+        let backups = match self.backup_abandoned(revision) {
+            Ok(backups) => backups,
+            Err(e) => {
+                self.set_error(format!("Abandon aborted: backup bookmark failed: {}", e));
+                return;
+            }
+        };
+        let short_id = short_id(revision, self.id_length);
+        let msg = format!(
+            "Abandoned {}{} (undo: u)",
+            short_id,
+            backup::backup_suffix(&backups)
+        );
         let result = self.run_and_record("Abandon", &["abandon", revision]);
         self.run_jj_action(result, "Abandon failed", &msg, DirtyFlags::log_and_status());
     }
 
     /// Execute revert operation (creates reverse-diff commit)
     pub(crate) fn execute_revert(&mut self, revision: &str) {
-        let short_id = short_id(revision);
+        let short_id = short_id(revision, self.id_length);
         let msg = format!("Reverted {} (undo: u)", short_id);
         let result = self.run_and_record("Revert", &["revert", "-r", revision, "--onto", "@"]);
         self.run_jj_action(result, "Revert failed", &msg, DirtyFlags::log());
@@ -441,18 +598,24 @@ impl App {
     pub(crate) fn execute_metaedit(&mut self, commit_id: &str, change_id: &str, options: &[&str]) {
         let mut args = vec!["metaedit", "-r", commit_id];
         args.extend_from_slice(options);
-        let action_desc = match options.first() {
-            Some(&"--update-author") => "author updated",
-            Some(&"--author") => "author set",
-            Some(&"--update-author-timestamp") => "author timestamp updated",
-            Some(&"--update-change-id") => "change-id regenerated",
-            Some(&"--force-rewrite") => "force rewritten",
+        let action_desc = match options {
+            ["--update-author", "--update-author-timestamp"] => "author and timestamp reset",
+            ["--update-author", ..] => "author updated",
+            ["--author", ..] => "author set",
+            ["--update-author-timestamp", ..] => "author timestamp updated",
+            ["--update-change-id", ..] => "change-id regenerated",
+            ["--force-rewrite", ..] => "force rewritten",
             _ => "completed",
         };
-        let short = short_id(change_id);
+        let short = short_id(change_id, self.id_length);
         let success_msg = format!("Metaedit {}: {} (undo: u)", short, action_desc);
         let result = self.run_and_record("Metaedit", &args);
-        self.run_jj_action(result, "Metaedit failed", &success_msg, DirtyFlags::log());
+        self.run_jj_action(
+            result,
+            "Metaedit failed",
+            &success_msg,
+            DirtyFlags::rewrite_metadata(),
+        );
     }
 
     /// Execute redo operation
@@ -489,13 +652,31 @@ impl App {
     /// Execute operation restore
     ///
     /// **Warning**: This is a destructive operation that modifies repository history.
-    /// TODO: Add confirmation dialog (Phase 5.2) before executing.
-    /// Currently, users can undo with `u` key if needed.
+    /// Confirmed via the impact preview from `start_op_restore`; undo with `u`.
+    ///
+    /// With `tij.backup-bookmarks`, the pre-restore `@` gets a backup bookmark
+    /// afterwards (one created before would be rolled back by the restore).
     pub(crate) fn execute_op_restore(&mut self, operation_id: &str) {
+        let previous_wc = if self.backup_bookmarks {
+            self.jj.full_ids("@").ok().map(|(_, commit_id)| commit_id)
+        } else {
+            None
+        };
         match self.run_and_record("Op restore", &["op", "restore", operation_id]) {
             Ok(_) => {
                 let short_id = &operation_id[..12.min(operation_id.len())];
-                self.notify_success(format!("Restored to {} (undo: u)", short_id));
+                let mut backups = Vec::new();
+                if let Some(commit_id) = previous_wc {
+                    match self.create_backup_bookmark("restore", &commit_id) {
+                        Ok(backup) => backups.extend(backup),
+                        Err(e) => self.notify_warning(format!("Backup bookmark failed: {}", e)),
+                    }
+                }
+                self.notify_success(format!(
+                    "Restored to {}{} (undo: u)",
+                    short_id,
+                    backup::backup_suffix(&backups)
+                ));
                 self.mark_dirty_and_refresh_current(DirtyFlags::all());
                 // Go back to log view
                 self.go_to_view(View::Log);
@@ -532,7 +713,7 @@ impl App {
         // Note: _guard will restore terminal when this function returns
         match result {
             Ok(status) if status.success() => {
-                let short_id = short_id(revision);
+                let short_id = short_id(revision, self.id_length);
                 self.notify_success(format!("Split {} complete (undo: u)", short_id));
             }
             Ok(_) => {
@@ -571,7 +752,7 @@ impl App {
         // 4. Handle result
         match result {
             Ok(status) if status.success() => {
-                let short_id = short_id(revision);
+                let short_id = short_id(revision, self.id_length);
                 self.notify_success(format!("Diffedit {} complete (undo: u)", short_id));
             }
             Ok(_) => {
@@ -671,9 +852,13 @@ impl App {
 
     /// Execute restore for a single file
     pub(crate) fn execute_restore_file(&mut self, file_path: &str) {
-        let msg = format!("Restored: {}", file_path);
-        let result = self.run_and_record("Restore", &["restore", file_path]);
-        self.run_jj_action(result, "Restore failed", &msg, DirtyFlags::log_and_status());
+        match self.run_and_record("Restore", &["restore", file_path]) {
+            Ok(_) => {
+                self.notify_success(format!("Restored: {}", file_path));
+                self.refresh_after_file_change(file_path);
+            }
+            Err(e) => self.set_error(format!("Restore failed: {}", e)),
+        }
     }
 
     /// Execute restore for all files
@@ -691,7 +876,7 @@ impl App {
     pub(crate) fn execute_next(&mut self) {
         match self.run_and_record("Next", &["next", "--edit"]) {
             Ok(output) => {
-                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.mark_dirty_and_refresh_current(DirtyFlags::working_copy());
 
                 // Move cursor to @ position
                 self.log_view.select_working_copy();
@@ -710,7 +895,7 @@ impl App {
     pub(crate) fn execute_prev(&mut self) {
         match self.run_and_record("Prev", &["prev", "--edit"]) {
             Ok(output) => {
-                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.mark_dirty_and_refresh_current(DirtyFlags::working_copy());
 
                 // Move cursor to @ position
                 self.log_view.select_working_copy();
@@ -802,6 +987,36 @@ impl App {
         }
     }
 
+    /// Ask before copying a hidden commit's content over @
+    pub(crate) fn start_restore_hidden(&mut self, commit_id: &str) {
+        self.active_dialog = Some(Dialog::confirm(
+            "Restore Hidden Commit",
+            format!(
+                "Restore the content of hidden commit {} into @?",
+                short_id(commit_id, self.id_length)
+            ),
+            Some("Files in @ are replaced by the hidden commit's. Undo with 'u'.".to_string()),
+            DialogCallback::RestoreHidden {
+                commit_id: commit_id.to_string(),
+            },
+        ));
+    }
+
+    /// `jj restore --from <hidden commit> --into @`
+    pub(crate) fn execute_restore_hidden(&mut self, commit_id: &str) {
+        let result =
+            self.run_and_record("Restore", &["restore", "--from", commit_id, "--into", "@"]);
+        self.run_jj_action(
+            result,
+            "Restore failed",
+            &format!(
+                "Restored content of {} into @ (undo: u)",
+                short_id(commit_id, self.id_length)
+            ),
+            DirtyFlags::log_and_status(),
+        );
+    }
+
     /// Parse the new change ID from `jj duplicate` output
     ///
     /// Output format: "Duplicated <commit_id> as <new_change_id> <new_commit_id> <description>"
@@ -854,7 +1069,7 @@ impl App {
                 {
                     Notification::info("No redundant parents found")
                 } else {
-                    let short_id = short_id(revision);
+                    let short_id = short_id(revision, self.id_length);
                     Notification::success(format!("Simplified parents for {} (undo: u)", short_id))
                 };
                 self.notification = Some(notification);
@@ -887,7 +1102,7 @@ impl App {
             Ok(_) => {
                 self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
 
-                let short_id = short_id(change_id);
+                let short_id = short_id(change_id, self.id_length);
 
                 // Compare commit_id after refresh to detect actual changes
                 let commit_id_after = self
@@ -949,6 +1164,7 @@ impl App {
 
     /// Execute git fetch (default behavior)
     pub(crate) fn execute_fetch(&mut self) {
+        let pre_op = self.current_op_id();
         match self.run_and_record("Fetch", &["git", "fetch"]) {
             Ok(output) => {
                 self.mark_dirty_and_refresh_current(DirtyFlags::all());
@@ -956,6 +1172,9 @@ impl App {
                 let notification = if output.trim().is_empty() {
                     Notification::info("Already up to date")
                 } else {
+                    self.offer_bookmark_reconcile(pre_op.as_deref());
+                    self.offer_incoming_review(pre_op.as_deref());
+                    self.suggest_stack_rebase(pre_op.as_deref());
                     Notification::success("Fetched from remote")
                 };
                 self.notification = Some(notification);
@@ -1004,10 +1223,15 @@ impl App {
                         });
                     }
                     items.push(SelectItem {
-                        label: "Specific branch...".to_string(),
+                        label: "Specific branches...".to_string(),
                         value: "__branch__".to_string(),
                         selected: false,
                     });
+                    items.push(SelectItem {
+                        label: "Branch pattern (glob)...".to_string(),
+                        value: "__branch_pattern__".to_string(),
+                        selected: false,
+                    });
                     self.active_dialog = Some(Dialog::select_single(
                         "Git Fetch",
                         "Select remote to fetch from:",
@@ -1026,6 +1250,7 @@ impl App {
 
     /// Execute fetch with specific remote option
     pub(crate) fn execute_fetch_with_option(&mut self, option: &str) {
+        let pre_op = self.current_op_id();
         let (label, result) = match option {
             "__default__" => ("Fetch", self.run_and_record("Fetch", &["git", "fetch"])),
             "__all_remotes__" => (
@@ -1053,6 +1278,9 @@ impl App {
                     };
                     Notification::info(msg)
                 } else {
+                    self.offer_bookmark_reconcile(pre_op.as_deref());
+                    self.offer_incoming_review(pre_op.as_deref());
+                    self.suggest_stack_rebase(pre_op.as_deref());
                     let source = match option {
                         "__default__" => "default remotes",
                         "__all_remotes__" => "all remotes",
@@ -1071,9 +1299,9 @@ impl App {
 
     /// Show 2nd-step Select dialog for branch selection
     ///
-    /// Gets local bookmark names via `jj bookmark list` and shows a Select dialog.
-    /// If no bookmarks found, falls back to default fetch with notification.
-    fn start_fetch_branch_select(&mut self) {
+    /// Gets local bookmark names via `jj bookmark list` and shows a multi-select
+    /// dialog. If no bookmarks found, falls back to default fetch with notification.
+    pub(crate) fn start_fetch_branch_select(&mut self) {
         match self.jj.bookmark_list_all() {
             Ok(bookmarks) => {
                 // Filter to local-only bookmarks (no remote)
@@ -1098,9 +1326,9 @@ impl App {
                     })
                     .collect();
 
-                self.active_dialog = Some(Dialog::select_single(
-                    "Fetch Branch",
-                    "Select branch to fetch:",
+                self.active_dialog = Some(Dialog::select(
+                    "Fetch Branches",
+                    "Select branches to fetch (Space: toggle):",
                     items,
                     None,
                     DialogCallback::GitFetchBranch,
@@ -1115,16 +1343,38 @@ impl App {
         }
     }
 
-    /// Execute `jj git fetch --branch <name>` for a specific branch
-    fn execute_fetch_branch(&mut self, branch: &str) {
-        match self.run_and_record("Fetch branch", &["git", "fetch", "--branch", branch]) {
+    /// Show Input dialog for a branch glob pattern (e.g. `feature/*`)
+    pub(crate) fn start_fetch_branch_pattern(&mut self) {
+        self.active_dialog = Some(Dialog::input(
+            "Fetch Branch Pattern",
+            "Branch names or globs (space/comma separated):",
+            DialogCallback::GitFetchBranchPattern,
+        ));
+    }
+
+    /// Execute a single `jj git fetch` with one `--branch` flag per pattern
+    pub(crate) fn execute_fetch_branches(&mut self, branches: &[String]) {
+        if branches.is_empty() {
+            return;
+        }
+        let mut args = vec!["git", "fetch"];
+        for branch in branches {
+            args.push("--branch");
+            args.push(branch);
+        }
+        let label = branches.join(", ");
+        let pre_op = self.current_op_id();
+        match self.run_and_record("Fetch branch", &args) {
             Ok(output) => {
                 self.mark_dirty_and_refresh_current(DirtyFlags::all());
 
                 let notification = if output.trim().is_empty() {
-                    Notification::info(format!("Branch '{}': already up to date", branch))
+                    Notification::info(format!("Branch {}: already up to date", label))
                 } else {
-                    Notification::success(format!("Fetched branch '{}'", branch))
+                    self.offer_bookmark_reconcile(pre_op.as_deref());
+                    self.offer_incoming_review(pre_op.as_deref());
+                    self.suggest_stack_rebase(pre_op.as_deref());
+                    Notification::success(format!("Fetched branch {}", label))
                 };
                 self.notification = Some(notification);
             }
@@ -1134,10 +1384,143 @@ impl App {
         }
     }
 
+    /// ID of the current operation (the "before" point of a fetch)
+    fn current_op_id(&self) -> Option<String> {
+        let ops = self.jj.op_log(Some(1)).ok()?;
+        ops.into_iter().find(|op| op.is_current).map(|op| op.id)
+    }
+
+    /// Offer to review revisions that arrived on tracked remote bookmarks
+    ///
+    /// Compares tracked remote bookmarks before (`pre_op`) and after the
+    /// fetch. Does nothing if nothing new arrived, the query fails, or
+    /// another fetch dialog (renamed bookmarks) is already open.
+    fn offer_incoming_review(&mut self, pre_op: Option<&str>) {
+        let Some(pre_op) = pre_op else {
+            return;
+        };
+        if self.active_dialog.is_some() {
+            return;
+        }
+        let revset = incoming_revset(pre_op);
+        let count = match self.jj.log_changes(Some(&revset), false) {
+            Ok(changes) => changes.iter().filter(|c| !c.is_graph_only).count(),
+            Err(_) => return,
+        };
+        if count == 0 {
+            return;
+        }
+        self.active_dialog = Some(Dialog::confirm(
+            "Incoming Changes",
+            format!(
+                "{} new revision(s) arrived on tracked remote bookmarks.\n\nReview them in the log?",
+                count
+            ),
+            None,
+            DialogCallback::GitFetchReviewIncoming { revset },
+        ));
+    }
+
+    /// Show incoming revisions in the Log View as a revset filter
+    pub(crate) fn show_incoming_changes(&mut self, revset: &str) {
+        self.go_to_view(View::Log);
+        self.refresh_log(Some(revset));
+        self.notify_info("Incoming changes (clear revset to return)");
+    }
+
+    /// Enable periodic background fetch if `tij.auto-fetch-minutes` is set
+    pub(crate) fn start_auto_fetch(&mut self) {
+        // A read-only second instance leaves fetching to the first one
+        if self.jj.is_read_only() {
+            return;
+        }
+        let minutes: u64 = self
+            .jj
+            .config_get(config_keys::AUTO_FETCH_MINUTES)
+            .ok()
+            .flatten()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0);
+        if minutes == 0 {
+            return;
+        }
+        self.auto_fetch = Some(AutoFetch::new(
+            Duration::from_secs(minutes * 60),
+            Instant::now(),
+        ));
+        self.refresh_bookmark_tracking();
+    }
+
+    /// Start a background fetch if one is due
+    ///
+    /// Called on idle ticks; not while a dialog is open. The result arrives
+    /// later as `AppEvent::JobFinished`.
+    pub(crate) fn start_auto_fetch_if_due(&mut self) {
+        if self.active_dialog.is_some() {
+            return;
+        }
+        let now = Instant::now();
+        if !self.auto_fetch.as_ref().is_some_and(|f| f.is_due(now)) {
+            return;
+        }
+        // Read before the fetch starts, so that it can't be the fetch itself
+        self.auto_fetch_pre_op = self.current_op_id();
+        if let Some(auto_fetch) = self.auto_fetch.as_mut() {
+            auto_fetch.start(&self.jj, now);
+        }
+    }
+
+    /// Apply a finished background fetch
+    ///
+    /// When the remote moved, views are marked dirty and the ahead/behind
+    /// badges are refreshed; the log cursor stays on the same change.
+    pub(crate) fn on_auto_fetch_finished(
+        &mut self,
+        started: Instant,
+        result: Result<String, JjError>,
+    ) {
+        self.record_str_command("Auto fetch", &["git", "fetch"], started, &result);
+        let pre_op = self.auto_fetch_pre_op.take();
+        match result {
+            Ok(output) if output.trim().is_empty() => {}
+            Ok(_) => {
+                let selected = self
+                    .log_view
+                    .selected_change()
+                    .map(|c| c.change_id.to_string());
+                self.mark_dirty_and_refresh_current(DirtyFlags::all());
+                if let Some(change_id) = selected {
+                    self.log_view.select_change_by_id(&change_id);
+                }
+                self.refresh_bookmark_tracking();
+                self.suggest_stack_rebase(pre_op.as_deref());
+
+                if self.notification.as_ref().is_none_or(|n| n.is_expired()) {
+                    let behind = remote_moved_summary(&self.log_view.bookmark_tracking);
+                    self.notify_info(match behind {
+                        Some(summary) => format!("Background fetch: {}", summary),
+                        None => "Background fetch: remote updated".to_string(),
+                    });
+                }
+            }
+            Err(e) => {
+                self.notify_warning(format!("Background fetch failed: {}", e));
+            }
+        }
+    }
+
+    /// Reload ahead/behind counts shown next to Log bookmark badges
+    pub(crate) fn refresh_bookmark_tracking(&mut self) {
+        if let Ok(bookmarks) = self.jj.bookmark_list_all() {
+            self.log_view.bookmark_tracking = local_tracking_map(&bookmarks);
+            self.apply_git_sync(&bookmarks);
+        }
+    }
+
     /// Resolve a conflict using :ours tool
     pub(crate) fn execute_resolve_ours(&mut self, file_path: &str) {
-        let (change_id, is_wc) = match self.resolve_view {
-            Some(ref v) => (v.revision.clone(), v.is_working_copy),
+        let change_id = match self.resolve_view {
+            Some(ref v) => v.revision.clone(),
             None => return,
         };
 
@@ -1147,7 +1530,7 @@ impl App {
         ) {
             Ok(_) => {
                 self.notify_success(format!("Resolved {} with :ours", file_path));
-                self.refresh_resolve_list(&change_id, is_wc);
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
             }
             Err(e) => {
                 self.set_error(format!("Resolve failed: {}", e));
@@ -1157,8 +1540,8 @@ impl App {
 
     /// Resolve a conflict using :theirs tool
     pub(crate) fn execute_resolve_theirs(&mut self, file_path: &str) {
-        let (change_id, is_wc) = match self.resolve_view {
-            Some(ref v) => (v.revision.clone(), v.is_working_copy),
+        let change_id = match self.resolve_view {
+            Some(ref v) => v.revision.clone(),
             None => return,
         };
 
@@ -1168,7 +1551,7 @@ impl App {
         ) {
             Ok(_) => {
                 self.notify_success(format!("Resolved {} with :theirs", file_path));
-                self.refresh_resolve_list(&change_id, is_wc);
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
             }
             Err(e) => {
                 self.set_error(format!("Resolve failed: {}", e));
@@ -1215,8 +1598,8 @@ impl App {
             }
         }
 
-        // 5. Refresh resolve list
-        self.refresh_resolve_list(&change_id, is_wc);
+        // 5. Refresh resolve list (and the views the resolution touched)
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
     }
 
     /// Execute rebase with specified mode
@@ -1325,11 +1708,11 @@ impl App {
                 }
                 RebaseMode::Branch => format!("Rebased branch successfully{}", suffix),
                 RebaseMode::InsertAfter => {
-                    let short = &destination[..8.min(destination.len())];
+                    let short = short_id(destination, self.id_length);
                     format!("Inserted after {} successfully{}", short, suffix)
                 }
                 RebaseMode::InsertBefore => {
-                    let short = &destination[..8.min(destination.len())];
+                    let short = short_id(destination, self.id_length);
                     format!("Inserted before {} successfully{}", short, suffix)
                 }
             };
@@ -1412,12 +1795,54 @@ impl App {
         }
     }
 
+    /// Schedule a diff preview fetch for the file selected in Status View
+    ///
+    /// Same deferral as [`Self::update_preview_if_needed`]: cache hits are
+    /// shown immediately, misses are fetched on the next idle tick.
+    pub(crate) fn update_status_preview_if_needed(&mut self) {
+        if !self.preview_enabled {
+            return;
+        }
+        let Some(path) = self.status_view.selected_tracked_path() else {
+            return;
+        };
+        if self.status_preview_cache.peek(path).is_some() {
+            self.status_preview_cache.touch(path);
+            return;
+        }
+        self.status_preview_pending = Some(path.to_string());
+    }
+
+    /// Fetch one file's working copy diff (`jj diff -r @ <path>`)
+    fn fetch_status_preview(&mut self, path: &str) {
+        match self.jj.diff_file("@", path) {
+            Ok(content) => {
+                self.status_preview_cache
+                    .insert(super::state::PreviewCacheEntry {
+                        change_id: path.to_string(),
+                        commit_id: String::new(),
+                        content,
+                        bookmarks: Vec::new(),
+                    });
+            }
+            Err(_) => {
+                self.status_preview_cache.remove(path);
+            }
+        }
+    }
+
     /// Called from the event loop idle handler (when no key is pressed).
     /// Resolves any pending preview fetch that was deferred by debounce or auto-disable.
     pub fn resolve_pending_preview(&mut self) {
         if !self.preview_enabled || self.preview_auto_disabled {
             return;
         }
+        if let Some(pending_path) = self.status_preview_pending.take()
+            && self.status_view.selected_tracked_path() == Some(pending_path.as_str())
+        {
+            self.fetch_status_preview(&pending_path);
+        }
+        self.resolve_pending_op_preview();
         if let Some(pending_id) = self.preview_pending_id.take() {
             // Verify the selection hasn't changed
             let still_selected = self
@@ -1541,15 +1966,15 @@ impl App {
                 let ci = compare.expect("Compare mode requires compare_info");
                 match format {
                     DiffDisplayFormat::ColorWords => self
-                        .jj
+                        .diff_jj()
                         .diff_range(ci.from.commit_id.as_str(), ci.to.commit_id.as_str())
                         .map(|o| Parser::parse_diff_body(&o)),
                     DiffDisplayFormat::Stat => self
-                        .jj
+                        .diff_jj()
                         .diff_range_stat(ci.from.commit_id.as_str(), ci.to.commit_id.as_str())
                         .map(|o| Parser::parse_diff_body_stat(&o)),
                     DiffDisplayFormat::Git => self
-                        .jj
+                        .diff_jj()
                         .diff_range_git(ci.from.commit_id.as_str(), ci.to.commit_id.as_str())
                         .map(|o| Parser::parse_diff_body_git(&o)),
                 }
@@ -1558,27 +1983,27 @@ impl App {
                 let ci = compare.expect("Interdiff mode requires compare_info");
                 match format {
                     DiffDisplayFormat::ColorWords => self
-                        .jj
+                        .diff_jj()
                         .interdiff(ci.from.commit_id.as_str(), ci.to.commit_id.as_str())
                         .map(|o| Parser::parse_diff_body(&o)),
                     DiffDisplayFormat::Stat => self
-                        .jj
+                        .diff_jj()
                         .interdiff_stat(ci.from.commit_id.as_str(), ci.to.commit_id.as_str())
                         .map(|o| Parser::parse_diff_body_stat(&o)),
                     DiffDisplayFormat::Git => self
-                        .jj
+                        .diff_jj()
                         .interdiff_git(ci.from.commit_id.as_str(), ci.to.commit_id.as_str())
                         .map(|o| Parser::parse_diff_body_git(&o)),
                 }
             }
             DiffMode::Single => match format {
-                DiffDisplayFormat::ColorWords => self.jj.show(change_id),
+                DiffDisplayFormat::ColorWords => self.diff_jj().show(change_id),
                 DiffDisplayFormat::Stat => self
-                    .jj
+                    .diff_jj()
                     .show_stat(change_id)
                     .and_then(|o| Parser::parse_show_stat(&o)),
                 DiffDisplayFormat::Git => self
-                    .jj
+                    .diff_jj()
                     .show_git(change_id)
                     .and_then(|o| Parser::parse_show_git(&o)),
             },
@@ -1602,9 +2027,10 @@ impl App {
         match self.fetch_diff_content(&revision, new_format, compare_info.as_ref(), mode) {
             Ok(content) => {
                 let diff_view = self.diff_view.as_mut().unwrap();
+                // Format first: set_content decides whether the line filter applies
+                diff_view.display_format = new_format;
                 diff_view.set_content(revision, content);
                 diff_view.compare_info = compare_info;
-                diff_view.display_format = new_format;
 
                 self.notify_info(format!(
                     "Display: {} ({}/{})",
@@ -1637,8 +2063,8 @@ impl App {
         let (short_id, result) = match mode {
             DiffMode::Compare | DiffMode::Interdiff => {
                 let ci = compare_info.as_ref().unwrap();
-                let from_short = short_id(ci.from.change_id.as_str());
-                let to_short = short_id(ci.to.change_id.as_str());
+                let from_short = short_id(ci.from.change_id.as_str(), self.id_length);
+                let to_short = short_id(ci.to.change_id.as_str(), self.id_length);
                 let prefix = if mode == DiffMode::Interdiff {
                     "interdiff_"
                 } else {
@@ -1655,7 +2081,7 @@ impl App {
                 (label, result)
             }
             DiffMode::Single => {
-                let short = short_id(&revision).to_string();
+                let short = short_id(&revision, self.id_length).to_string();
                 let result = self.jj.diff_git_raw(&revision);
                 (short, result)
             }
@@ -1683,12 +2109,17 @@ impl App {
 
 /// Generate a unique .patch filename, appending -1, -2, etc. if the file already exists
 fn unique_patch_filename(short_id: &str) -> String {
-    let base = format!("{}.patch", short_id);
+    unique_filename(short_id, "patch")
+}
+
+/// Generate `<stem>.<ext>`, appending -1, -2, etc. if the file already exists
+pub(crate) fn unique_filename(stem: &str, ext: &str) -> String {
+    let base = format!("{}.{}", stem, ext);
     if !std::path::Path::new(&base).exists() {
         return base;
     }
     for i in 1.. {
-        let candidate = format!("{}-{}.patch", short_id, i);
+        let candidate = format!("{}-{}.{}", stem, i, ext);
         if !std::path::Path::new(&candidate).exists() {
             return candidate;
         }
@@ -1696,48 +2127,137 @@ fn unique_patch_filename(short_id: &str) -> String {
     unreachable!()
 }
 
+/// Split user input into `--branch` patterns for `jj git fetch`
+///
+/// Accepts space- or comma-separated names. Bare names containing glob
+/// metacharacters get an explicit `glob:` prefix; names that already carry a
+/// string-pattern prefix (`exact:`, `glob:`, `regex:`, ...) are passed as-is.
+pub(crate) fn parse_branch_patterns(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if !s.contains(':') && s.contains(['*', '?', '[']) {
+                format!("glob:{}", s)
+            } else {
+                s.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Ahead/behind counts of local bookmarks, keyed by bookmark name
+pub(crate) fn local_tracking_map<'a>(
+    bookmarks: impl IntoIterator<Item = &'a Bookmark>,
+) -> HashMap<String, TrackingCounts> {
+    bookmarks
+        .into_iter()
+        .filter(|b| b.remote.is_none())
+        .filter_map(|b| b.tracking.map(|counts| (b.name.clone(), counts)))
+        .collect()
+}
+
+/// Summary of bookmarks whose remote has moved, e.g. "main ↓3, dev ↓1"
+pub(crate) fn remote_moved_summary(tracking: &HashMap<String, TrackingCounts>) -> Option<String> {
+    let mut behind: Vec<_> = tracking
+        .iter()
+        .filter(|(_, counts)| counts.behind > 0)
+        .map(|(name, counts)| format!("{} ↓{}", name, counts.behind))
+        .collect();
+    if behind.is_empty() {
+        return None;
+    }
+    behind.sort();
+    Some(behind.join(", "))
+}
+
+/// Revset for revisions added on tracked remote bookmarks since `pre_op`
+pub(crate) fn incoming_revset(pre_op: &str) -> String {
+    format!(
+        "::tracked_remote_bookmarks() ~ ::at_operation({}, tracked_remote_bookmarks())",
+        pre_op
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_incoming_revset_uses_pre_fetch_operation() {
+        assert_eq!(
+            incoming_revset("75ea3c2331bf"),
+            "::tracked_remote_bookmarks() ~ ::at_operation(75ea3c2331bf, tracked_remote_bookmarks())"
+        );
+    }
+
     // =========================================================================
-    // Describe multi-line detection tests
-    //
-    // The App::start_describe_input() method uses `desc.lines().nth(1).is_some()`
-    // to detect multi-line descriptions and fall through to the external editor.
-    // These tests verify the detection logic matches expectations.
+    // Background fetch tracking tests
     // =========================================================================
 
+    fn tracked(name: &str, remote: Option<&str>, ahead: usize, behind: usize) -> Bookmark {
+        Bookmark {
+            name: name.to_string(),
+            remote: remote.map(|r| r.to_string()),
+            is_tracked: true,
+            tracking: Some(TrackingCounts { ahead, behind }),
+        }
+    }
+
     #[test]
-    fn test_multiline_detection_single_line() {
-        let desc = "single line description";
-        assert!(desc.lines().nth(1).is_none());
+    fn test_local_tracking_map_skips_remote_entries() {
+        let bookmarks = [
+            tracked("main", None, 0, 3),
+            tracked("main", Some("origin"), 0, 3),
+        ];
+        let map = local_tracking_map(&bookmarks);
+        assert_eq!(map.len(), 1);
+        assert_eq!(
+            map["main"],
+            TrackingCounts {
+                ahead: 0,
+                behind: 3
+            }
+        );
     }
 
     #[test]
-    fn test_multiline_detection_two_lines() {
-        let desc = "first line\nsecond line";
-        assert!(desc.lines().nth(1).is_some());
+    fn test_remote_moved_summary() {
+        let bookmarks = [
+            tracked("main", None, 0, 3),
+            tracked("dev", None, 2, 1),
+            tracked("wip", None, 4, 0),
+        ];
+        let map = local_tracking_map(&bookmarks);
+        assert_eq!(remote_moved_summary(&map).unwrap(), "dev ↓1, main ↓3");
+
+        let synced = local_tracking_map(&[tracked("wip", None, 4, 0)]);
+        assert!(remote_moved_summary(&synced).is_none());
     }
 
+    // =========================================================================
+    // parse_branch_patterns tests
+    // =========================================================================
+
     #[test]
-    fn test_multiline_detection_empty_string() {
-        let desc = "";
-        assert!(desc.lines().nth(1).is_none());
+    fn test_parse_branch_patterns_multiple() {
+        assert_eq!(
+            parse_branch_patterns("main, feature/*  release-?"),
+            vec!["main", "glob:feature/*", "glob:release-?"]
+        );
     }
 
     #[test]
-    fn test_multiline_detection_trailing_newline_only() {
-        // After trim_end_matches('\n'), a single-line desc with trailing \n becomes single-line
-        let desc = "single line\n".trim_end_matches('\n');
-        assert!(desc.lines().nth(1).is_none());
+    fn test_parse_branch_patterns_keeps_explicit_prefix() {
+        assert_eq!(
+            parse_branch_patterns("exact:weird*name regex:^fix"),
+            vec!["exact:weird*name", "regex:^fix"]
+        );
     }
 
     #[test]
-    fn test_multiline_detection_two_lines_with_trailing_newline() {
-        // After trim_end_matches('\n'), multi-line desc is still multi-line
-        let desc = "first\nsecond\n".trim_end_matches('\n');
-        assert!(desc.lines().nth(1).is_some());
+    fn test_parse_branch_patterns_empty() {
+        assert!(parse_branch_patterns("  , ").is_empty());
     }
 
     // =========================================================================
@@ -2181,4 +2701,63 @@ mod tests {
                 .contains("editor not found")
         );
     }
+
+    // =========================================================================
+    // Quick amend tests
+    // =========================================================================
+
+    fn amend_changes(working_copy_empty: bool) -> Vec<crate::model::Change> {
+        use crate::model::{Change, ChangeId, CommitId};
+        vec![
+            Change {
+                change_id: ChangeId::new("wcwcwcwc".to_string()),
+                commit_id: CommitId::new("c0ffee00".to_string()),
+                is_working_copy: true,
+                is_empty: working_copy_empty,
+                ..Change::default()
+            },
+            Change {
+                change_id: ChangeId::new("prprprpr".to_string()),
+                commit_id: CommitId::new("beef0000".to_string()),
+                ..Change::default()
+            },
+            Change {
+                change_id: ChangeId::new("trtrtrtr".to_string()),
+                commit_id: CommitId::new("feed0000".to_string()),
+                is_immutable: true,
+                ..Change::default()
+            },
+        ]
+    }
+
+    fn notification_message(app: &App) -> &str {
+        app.notification.as_ref().unwrap().message.as_str()
+    }
+
+    #[test]
+    fn test_quick_amend_rejects_working_copy_target() {
+        let mut app = App::new_for_test();
+        app.log_view.set_changes(amend_changes(false));
+        app.execute_quick_amend("c0ffee00", "wcwcwcwc");
+        assert!(notification_message(&app).contains("below @"));
+        assert!(app.command_history.is_empty());
+    }
+
+    #[test]
+    fn test_quick_amend_rejects_immutable_target() {
+        let mut app = App::new_for_test();
+        app.log_view.set_changes(amend_changes(false));
+        app.execute_quick_amend("feed0000", "trtrtrtr");
+        assert!(notification_message(&app).contains("immutable"));
+        assert!(app.command_history.is_empty());
+    }
+
+    #[test]
+    fn test_quick_amend_with_empty_working_copy_is_noop() {
+        let mut app = App::new_for_test();
+        app.log_view.set_changes(amend_changes(true));
+        app.execute_quick_amend("beef0000", "prprprpr");
+        assert!(notification_message(&app).contains("Nothing to amend"));
+        assert!(app.command_history.is_empty());
+    }
 }
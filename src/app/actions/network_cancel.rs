@@ -0,0 +1,129 @@
+//! Aftermath of a cancelled fetch/push
+//!
+//! Esc or Ctrl+C while `jj git fetch/push` runs kills its process group
+//! (see `jj::CancelHook`). Whether the command still got through is then
+//! read from the operation log: jj records a fetch/push operation only
+//! after it finished. A push killed before that may already have updated
+//! the remote, so a fetch is offered to find out.
+
+use crate::app::state::{App, CancelledNetwork, DirtyFlags};
+use crate::jj::JjError;
+use crate::jj::constants::commands;
+use crate::ui::components::{Dialog, DialogCallback};
+
+/// Operations newer than `before_op` searched for the fetch/push record
+const RECHECK_OP_LIMIT: usize = 20;
+
+impl App {
+    /// Remember a cancelled fetch/push (from the command recorders)
+    pub(crate) fn note_cancelled_network(&mut self, args: &[&str], error: Option<&JjError>) {
+        if let Some(JjError::Cancelled { before_op }) = error {
+            self.cancelled_network = Some(CancelledNetwork {
+                is_push: args.contains(&commands::GIT_PUSH),
+                before_op: before_op.clone(),
+            });
+        }
+    }
+
+    /// Description of the fetch/push operation recorded after `before_op`
+    fn recorded_network_op(&self, before_op: &str, is_push: bool) -> Option<String> {
+        let verb = if is_push {
+            commands::GIT_PUSH
+        } else {
+            commands::GIT_FETCH
+        };
+        let ops = self.jj.op_log(Some(RECHECK_OP_LIMIT)).ok()?;
+        ops.into_iter()
+            .take_while(|op| op.id != before_op)
+            .find(|op| op.description.starts_with(verb))
+            .map(|op| op.description)
+    }
+
+    /// Recheck the repo after a cancel and say what happened
+    pub(crate) fn finish_cancelled_network(&mut self) {
+        let Some(CancelledNetwork { is_push, before_op }) = self.cancelled_network.take() else {
+            return;
+        };
+        // Replaces the "... failed: cancelled by user" error
+        self.error_message = None;
+        let label = if is_push { "Push" } else { "Fetch" };
+        let recorded = before_op
+            .as_deref()
+            .and_then(|before| self.recorded_network_op(before, is_push));
+        match recorded {
+            Some(description) => {
+                self.notify_warning(format!(
+                    "{} cancelled too late: it completed ({})",
+                    label, description
+                ));
+                self.mark_dirty_and_refresh_current(DirtyFlags::all());
+            }
+            None if !is_push => {
+                self.notify_info("Fetch cancelled: nothing was imported, the repo is unchanged");
+            }
+            None => {
+                self.notify_warning(
+                    "Push cancelled before jj recorded it: the remote may already have it",
+                );
+                if self.active_dialog.is_none() {
+                    self.active_dialog = Some(Dialog::confirm(
+                        "Push Cancelled",
+                        "Fetch now to see what the remote has?",
+                        Some("jj git fetch updates the remote bookmarks shown in tij".to_string()),
+                        DialogCallback::FetchAfterCancelledPush,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cancelled() -> JjError {
+        JjError::Cancelled { before_op: None }
+    }
+
+    #[test]
+    fn test_note_cancelled_network_only_for_cancel() {
+        let mut app = App::new_for_test();
+        let failed = JjError::CommandFailed {
+            stderr: "x".to_string(),
+            exit_code: 1,
+        };
+        app.note_cancelled_network(&["git", "push"], Some(&failed));
+        assert!(app.cancelled_network.is_none());
+        app.note_cancelled_network(&["git", "push", "--bookmark", "main"], Some(&cancelled()));
+        assert_eq!(
+            app.cancelled_network,
+            Some(CancelledNetwork {
+                is_push: true,
+                before_op: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_cancelled_fetch_clears_error() {
+        let mut app = App::new_for_test();
+        app.note_cancelled_network(&["git", "fetch"], Some(&cancelled()));
+        app.set_error("Fetch failed: cancelled by user");
+        app.finish_cancelled_network();
+        assert!(app.error_message.is_none());
+        assert!(app.cancelled_network.is_none());
+        assert!(app.active_dialog.is_none());
+    }
+
+    #[test]
+    fn test_cancelled_push_offers_fetch() {
+        let mut app = App::new_for_test();
+        app.note_cancelled_network(&["git", "push"], Some(&cancelled()));
+        app.finish_cancelled_network();
+        assert_eq!(
+            app.active_dialog.map(|d| d.callback_id),
+            Some(DialogCallback::FetchAfterCancelledPush)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Local notes: free-text reminders attached to changes
+//!
+//! `;` in Log View edits the note on the selected change. Notes are stored
+//! by full change ID in the repo config (`tij.notes.<change_id>`), so they
+//! survive rewrites but never enter jj history or get pushed. They show as a
+//! badge on the log row and above the description in the preview.
+
+use crate::app::helpers::revision::short_id;
+use crate::app::state::App;
+use crate::ui::components::{Dialog, DialogCallback};
+
+impl App {
+    /// Read the notes from config into the Log View
+    pub(crate) fn load_notes(&mut self) {
+        if let Ok(notes) = self.jj.notes() {
+            self.log_view.notes = notes;
+        }
+    }
+
+    /// Ask for the note on a change, pre-filled with the current one
+    pub(crate) fn start_edit_note(&mut self, change_id: &str) {
+        let full_id = match self.jj.full_ids(change_id) {
+            Ok((full_id, _)) => full_id,
+            Err(e) => {
+                self.set_error(format!(
+                    "Failed to resolve {}: {}",
+                    short_id(change_id, self.id_length),
+                    e
+                ));
+                return;
+            }
+        };
+        let current = self.log_view.notes.get(&full_id).unwrap_or_default();
+        self.active_dialog = Some(Dialog::input_with_text(
+            "Note",
+            format!(
+                "Note on {} (local, not in history; empty removes it):",
+                short_id(change_id, self.id_length)
+            ),
+            current,
+            DialogCallback::EditNote { change_id: full_id },
+        ));
+    }
+
+    /// Save, replace or (for empty text) remove the note on a change
+    pub(crate) fn execute_edit_note(&mut self, change_id: &str, text: &str) {
+        let text = text.trim();
+        let had_note = self.log_view.notes.get(change_id).is_some();
+        if text.is_empty() && !had_note {
+            return;
+        }
+        let (result, message) = if text.is_empty() {
+            (self.jj.remove_note(change_id), "Removed note from")
+        } else {
+            (self.jj.set_note(change_id, text), "Saved note on")
+        };
+        match result {
+            Ok(_) => {
+                self.load_notes();
+                self.notify_success(format!(
+                    "{} {}",
+                    message,
+                    short_id(change_id, self.id_length)
+                ));
+            }
+            Err(e) => self.set_error(format!("Failed to save note: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_note_without_existing_note_is_noop() {
+        let mut app = App::new_for_test();
+        app.execute_edit_note("abc12345", "   ");
+        assert!(app.error_message.is_none());
+        assert!(app.notification.is_none());
+    }
+}
@@ -0,0 +1,113 @@
+//! Operation View preview pane: `jj op show` for the selected operation
+//!
+//! Shares the Log View preview toggle and split layout. Fetches are deferred
+//! to the idle tick like the log preview, so holding `j` doesn't run one
+//! `jj op show` per row. Operations never change once recorded, so cached
+//! entries stay valid until evicted.
+
+use crate::app::state::{App, PreviewCacheEntry};
+
+impl App {
+    /// Schedule a preview fetch for the operation selected in Operation View
+    ///
+    /// Cache hits are shown immediately, misses are fetched on the next idle
+    /// tick (see [`Self::resolve_pending_preview`]).
+    pub(crate) fn update_op_preview_if_needed(&mut self) {
+        if !self.preview_enabled {
+            return;
+        }
+        let Some(op_id) = self.operation_view.selected_operation().map(|op| &op.id) else {
+            return;
+        };
+        if self.op_preview_cache.peek(op_id).is_some() {
+            self.op_preview_cache.touch(op_id);
+            self.op_preview_pending = None;
+            return;
+        }
+        self.op_preview_pending = Some(op_id.clone());
+    }
+
+    /// Fetch the pending operation preview if it is still selected
+    pub(super) fn resolve_pending_op_preview(&mut self) {
+        let Some(op_id) = self.op_preview_pending.take() else {
+            return;
+        };
+        let still_selected = self
+            .operation_view
+            .selected_operation()
+            .is_some_and(|op| op.id == op_id);
+        if !still_selected {
+            return;
+        }
+        match self.jj.op_show(&op_id) {
+            Ok(content) => self.op_preview_cache.insert(PreviewCacheEntry {
+                change_id: op_id,
+                commit_id: String::new(),
+                content,
+                bookmarks: Vec::new(),
+            }),
+            Err(_) => self.op_preview_cache.remove(&op_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DiffContent, Operation};
+
+    fn app_with_operations() -> App {
+        let mut app = App::new_for_test();
+        app.operation_view.set_operations(vec![
+            Operation {
+                id: "aaa111".to_string(),
+                ..Default::default()
+            },
+            Operation {
+                id: "bbb222".to_string(),
+                ..Default::default()
+            },
+        ]);
+        app
+    }
+
+    #[test]
+    fn test_cache_miss_is_deferred_to_idle_tick() {
+        let mut app = app_with_operations();
+        app.update_op_preview_if_needed();
+        assert_eq!(app.op_preview_pending.as_deref(), Some("aaa111"));
+        assert!(app.jj.command_log().entries().is_empty());
+
+        app.resolve_pending_op_preview();
+        assert!(app.op_preview_pending.is_none());
+        let entries = app.jj.command_log().entries();
+        assert!(entries[0].args.ends_with(&[
+            "op".to_string(),
+            "show".to_string(),
+            "aaa111".to_string(),
+            "--no-graph".to_string(),
+        ]));
+    }
+
+    #[test]
+    fn test_cache_hit_needs_no_fetch() {
+        let mut app = app_with_operations();
+        app.op_preview_cache.insert(PreviewCacheEntry {
+            change_id: "aaa111".to_string(),
+            commit_id: String::new(),
+            content: DiffContent::default(),
+            bookmarks: Vec::new(),
+        });
+        app.update_op_preview_if_needed();
+        assert!(app.op_preview_pending.is_none());
+    }
+
+    #[test]
+    fn test_moved_selection_skips_stale_fetch() {
+        let mut app = app_with_operations();
+        app.update_op_preview_if_needed();
+        app.operation_view.select_next();
+        app.resolve_pending_op_preview();
+        assert!(app.jj.command_log().entries().is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+//! Operation abandon and garbage collection
+//!
+//! `A` in Operation History abandons the selected operation, it and all
+//! older operations (`jj op abandon ..<op>`), or the range up to the
+//! operation marked with Space. A confirmation shows how many loaded
+//! operations go away. `C` runs `jj util gc`, which reclaims abandoned
+//! operations right away only with `--expire=now`.
+
+use crate::app::state::{App, DirtyFlags};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+const SCOPE_SINGLE: &str = "single";
+const SCOPE_OLDER: &str = "older";
+const SCOPE_RANGE: &str = "range";
+
+/// `jj util gc --expire` value pruning everything unreachable
+const EXPIRE_NOW: &str = "now";
+
+fn short_op_id(id: &str) -> &str {
+    &id[..12.min(id.len())]
+}
+
+/// `3 operations`, or `50+ operations` when more exist beyond the loaded list
+fn operation_count_label(count: usize, more: bool) -> String {
+    format!(
+        "{}{} operation{}",
+        count,
+        if more { "+" } else { "" },
+        if count == 1 && !more { "" } else { "s" }
+    )
+}
+
+impl App {
+    /// Marked range end and the selection, newer first
+    fn abandon_range(&self, operation_id: &str, mark: &str) -> Option<(String, String)> {
+        let view = &self.operation_view;
+        let selected = view.operation_index(operation_id)?;
+        let marked = view.operation_index(mark)?;
+        Some(if selected <= marked {
+            (operation_id.to_string(), mark.to_string())
+        } else {
+            (mark.to_string(), operation_id.to_string())
+        })
+    }
+
+    fn is_current_operation(&self, operation_id: &str) -> bool {
+        self.operation_view
+            .operation(operation_id)
+            .is_some_and(|op| op.is_current)
+    }
+
+    /// Offer the abandon scopes for the selected operation
+    pub(crate) fn start_op_abandon(&mut self) {
+        let Some(op) = self.operation_view.selected_operation().cloned() else {
+            return;
+        };
+        let mark = self
+            .operation_view
+            .range_mark()
+            .map(|op| op.id.clone())
+            .filter(|mark| *mark != op.id);
+        let mut items = Vec::new();
+        if !op.is_current {
+            let older = self.operation_view.operations_between(&op.id, None);
+            items.push(SelectItem {
+                label: format!("Only {} ({})", op.short_id(), op.description),
+                value: SCOPE_SINGLE.to_string(),
+                selected: false,
+            });
+            items.push(SelectItem {
+                label: format!(
+                    "{} and all older operations ({})",
+                    op.short_id(),
+                    operation_count_label(older, self.operation_view.is_truncated())
+                ),
+                value: SCOPE_OLDER.to_string(),
+                selected: false,
+            });
+        }
+        if let Some((newer, older)) = mark
+            .as_deref()
+            .and_then(|mark| self.abandon_range(&op.id, mark))
+            .filter(|(newer, _)| !self.is_current_operation(newer))
+        {
+            let count = self.operation_view.operations_between(&newer, Some(&older));
+            items.push(SelectItem {
+                label: format!(
+                    "Marked range {}..{} ({})",
+                    short_op_id(&older),
+                    short_op_id(&newer),
+                    operation_count_label(count, false)
+                ),
+                value: SCOPE_RANGE.to_string(),
+                selected: false,
+            });
+        }
+        if items.is_empty() {
+            self.notify_info("The current operation can't be abandoned: select an older one");
+            return;
+        }
+        self.active_dialog = Some(Dialog::select_single(
+            "Abandon Operations",
+            "Remove from the operation log:",
+            items,
+            Some("Repository content is unchanged; only undo/restore history is lost".to_string()),
+            DialogCallback::OpAbandonScope {
+                operation_id: op.id.clone(),
+                mark,
+            },
+        ));
+    }
+
+    /// Confirm the chosen scope with the estimated number of operations
+    fn confirm_op_abandon(&mut self, operation_id: &str, mark: Option<&str>, scope: &str) {
+        let view = &self.operation_view;
+        let (expression, count, more) = match scope {
+            SCOPE_SINGLE => (operation_id.to_string(), 1, false),
+            SCOPE_OLDER => (
+                format!("..{}", operation_id),
+                view.operations_between(operation_id, None),
+                view.is_truncated(),
+            ),
+            SCOPE_RANGE => {
+                let Some((newer, older)) = mark.and_then(|m| self.abandon_range(operation_id, m))
+                else {
+                    return;
+                };
+                // `a..b` excludes `a`; the marked end is meant to go too
+                (
+                    format!("{}-..{}", older, newer),
+                    view.operations_between(&newer, Some(&older)),
+                    false,
+                )
+            }
+            _ => return,
+        };
+        self.active_dialog = Some(Dialog::confirm(
+            "Abandon Operations",
+            format!(
+                "Abandon {}?\njj op abandon {}",
+                operation_count_label(count, more),
+                expression
+            ),
+            Some("Press C afterwards to reclaim the space with jj util gc".to_string()),
+            DialogCallback::OpAbandon { expression, count },
+        ));
+    }
+
+    fn execute_op_abandon(&mut self, expression: &str, count: usize) {
+        match self.run_and_record("Op abandon", &["op", "abandon", expression]) {
+            Ok(_) => {
+                self.abandoned_operations += count;
+                self.notify_success(format!("Abandoned {}", operation_count_label(count, false)));
+                self.mark_dirty_and_refresh_current(DirtyFlags::op_log());
+            }
+            Err(e) => self.set_error(format!("Op abandon failed: {}", e)),
+        }
+    }
+
+    /// Ask how much `jj util gc` may prune
+    pub(crate) fn start_op_gc(&mut self) {
+        let items = vec![
+            SelectItem {
+                label: "Prune data unreachable for 2+ weeks (default)".to_string(),
+                value: String::new(),
+                selected: false,
+            },
+            SelectItem {
+                label: format!(
+                    "Prune all unreachable data now (reclaims ~{} abandoned this session)",
+                    operation_count_label(self.abandoned_operations, false)
+                ),
+                value: EXPIRE_NOW.to_string(),
+                selected: false,
+            },
+        ];
+        self.active_dialog = Some(Dialog::select_single(
+            "Garbage Collection",
+            "Run jj util gc:",
+            items,
+            Some("Pruned operations and commits can't be restored".to_string()),
+            DialogCallback::OpGc,
+        ));
+    }
+
+    fn execute_op_gc(&mut self, expire: &str) {
+        let mut args = vec!["util", "gc"];
+        if !expire.is_empty() {
+            args.extend(["--expire", expire]);
+        }
+        match self.run_and_record("GC", &args) {
+            Ok(_) => {
+                if expire == EXPIRE_NOW {
+                    self.abandoned_operations = 0;
+                }
+                self.notify_success("Garbage collection finished");
+            }
+            Err(e) => self.set_error(format!("GC failed: {}", e)),
+        }
+    }
+
+    /// Handle the abandon/GC dialogs
+    pub(crate) fn handle_op_prune_dialog(&mut self, callback: DialogCallback, values: Vec<String>) {
+        match callback {
+            DialogCallback::OpAbandonScope { operation_id, mark } => {
+                if let Some(scope) = values.first() {
+                    self.confirm_op_abandon(&operation_id, mark.as_deref(), scope);
+                }
+            }
+            DialogCallback::OpAbandon { expression, count } => {
+                self.execute_op_abandon(&expression, count);
+            }
+            DialogCallback::OpGc => {
+                if let Some(expire) = values.first() {
+                    self.execute_op_gc(expire);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Operation;
+
+    fn op(id: &str, is_current: bool) -> Operation {
+        Operation {
+            id: id.to_string(),
+            description: format!("op {}", id),
+            is_current,
+            ..Default::default()
+        }
+    }
+
+    fn app_with_operations() -> App {
+        let mut app = App::new_for_test();
+        app.operation_view.set_operations(vec![
+            op("cccccccccccc", true),
+            op("bbbbbbbbbbbb", false),
+            op("aaaaaaaaaaaa", false),
+            op("000000000000", false),
+        ]);
+        app
+    }
+
+    #[test]
+    fn test_operation_count_label() {
+        assert_eq!(operation_count_label(1, false), "1 operation");
+        assert_eq!(operation_count_label(3, false), "3 operations");
+        assert_eq!(operation_count_label(50, true), "50+ operations");
+    }
+
+    #[test]
+    fn test_current_operation_is_not_offered() {
+        let mut app = app_with_operations();
+        app.start_op_abandon();
+        assert!(app.active_dialog.is_none());
+        assert!(app.notification.is_some());
+    }
+
+    #[test]
+    fn test_abandon_older_confirms_with_count() {
+        let mut app = app_with_operations();
+        app.operation_view.select_next();
+        app.start_op_abandon();
+        let dialog = app.active_dialog.take().expect("scope dialog");
+        app.handle_op_prune_dialog(dialog.callback_id, vec![SCOPE_OLDER.to_string()]);
+        let dialog = app.active_dialog.as_ref().expect("confirm dialog");
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::OpAbandon {
+                expression: "..bbbbbbbbbbbb".to_string(),
+                count: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_abandon_marked_range_orders_ends() {
+        let mut app = app_with_operations();
+        app.operation_view.select_next();
+        app.operation_view.toggle_range_mark();
+        app.operation_view.select_next();
+        app.start_op_abandon();
+        let dialog = app.active_dialog.take().expect("scope dialog");
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::OpAbandonScope {
+                operation_id: "aaaaaaaaaaaa".to_string(),
+                mark: Some("bbbbbbbbbbbb".to_string()),
+            }
+        );
+        app.handle_op_prune_dialog(dialog.callback_id, vec![SCOPE_RANGE.to_string()]);
+        let dialog = app.active_dialog.as_ref().expect("confirm dialog");
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::OpAbandon {
+                expression: "aaaaaaaaaaaa-..bbbbbbbbbbbb".to_string(),
+                count: 2,
+            }
+        );
+    }
+}
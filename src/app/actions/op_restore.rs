@@ -0,0 +1,119 @@
+//! Operation restore with an impact preview
+//!
+//! Restoring an operation can hide many commits at once, so Operation
+//! History and Divergent Operations first run `jj op diff --from @ --to <op>`
+//! and show which commits and bookmarks the restore adds or removes before
+//! asking for confirmation.
+
+use crate::app::state::{App, View};
+use crate::model::OpDiff;
+use crate::ui::components::{Dialog, DialogCallback};
+
+/// Commit lines listed per direction before collapsing into "... N more"
+const MAX_PREVIEW_COMMITS: usize = 5;
+
+/// Push up to `MAX_PREVIEW_COMMITS` `<sign> <commit>` lines plus an overflow line
+fn push_commit_lines(lines: &mut Vec<String>, sign: char, commits: &[String]) {
+    for commit in commits.iter().take(MAX_PREVIEW_COMMITS) {
+        lines.push(format!("{} {}", sign, commit));
+    }
+    if commits.len() > MAX_PREVIEW_COMMITS {
+        lines.push(format!(
+            "{} ... {} more",
+            sign,
+            commits.len() - MAX_PREVIEW_COMMITS
+        ));
+    }
+}
+
+/// Dialog body: question, diffstat, then the affected commits and bookmarks
+fn format_restore_preview(operation_id: &str, diff: &OpDiff) -> String {
+    let short_id = &operation_id[..12.min(operation_id.len())];
+    let mut lines = vec![
+        format!("Restore to operation {}?", short_id),
+        format!("Impact: {}", diff.summary()),
+    ];
+    push_commit_lines(&mut lines, '+', &diff.added_commits);
+    push_commit_lines(&mut lines, '-', &diff.removed_commits);
+    if !diff.changed_refs.is_empty() {
+        lines.push(format!("Bookmarks: {}", diff.changed_refs.join(", ")));
+    }
+    lines.join("\n")
+}
+
+impl App {
+    /// Preview the impact of restoring `operation_id`, then ask to confirm
+    ///
+    /// Falls back to a plain confirmation when `jj op diff` fails.
+    pub(crate) fn start_op_restore(&mut self, operation_id: &str) {
+        let body = match self.jj.op_diff("@", operation_id) {
+            Ok(diff) => format_restore_preview(operation_id, &diff),
+            Err(e) => format!(
+                "Restore to operation {}?\n(preview unavailable: {})",
+                &operation_id[..12.min(operation_id.len())],
+                e
+            ),
+        };
+        self.active_dialog = Some(Dialog::confirm(
+            "Restore Operation",
+            body,
+            Some("Undo with 'u' if needed.".to_string()),
+            DialogCallback::OpRestore {
+                operation_id: operation_id.to_string(),
+            },
+        ));
+    }
+
+    /// Run the confirmed restore
+    pub(super) fn confirm_op_restore(&mut self, operation_id: &str) {
+        // Restoring from Divergent Operations leaves that view for good
+        if self.current_view == View::OpHeads {
+            self.op_heads_view = None;
+        }
+        self.execute_op_restore(operation_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_restore_preview_lists_changes() {
+        let diff = OpDiff {
+            added_commits: vec!["kkmpptxz 1a2b3c4d Add parser".to_string()],
+            removed_commits: (0..7).map(|i| format!("commit {}", i)).collect(),
+            changed_refs: vec!["main".to_string(), "main@origin".to_string()],
+        };
+        let preview = format_restore_preview("0123456789abcdef", &diff);
+        let lines: Vec<&str> = preview.lines().collect();
+        assert_eq!(lines[0], "Restore to operation 0123456789ab?");
+        assert_eq!(lines[1], "Impact: +1 -7 commits, 2 bookmarks changed");
+        assert_eq!(lines[2], "+ kkmpptxz 1a2b3c4d Add parser");
+        assert_eq!(lines[3], "- commit 0");
+        assert_eq!(lines[8], "- ... 2 more");
+        assert_eq!(lines[9], "Bookmarks: main, main@origin");
+    }
+
+    #[test]
+    fn test_format_restore_preview_without_changes() {
+        let preview = format_restore_preview("abc", &OpDiff::default());
+        assert_eq!(
+            preview,
+            "Restore to operation abc?\nImpact: no visible changes"
+        );
+    }
+
+    #[test]
+    fn test_start_op_restore_opens_confirm_dialog() {
+        let mut app = App::new_for_test();
+        app.start_op_restore("0123456789abcdef");
+        let dialog = app.active_dialog.as_ref().expect("dialog");
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::OpRestore {
+                operation_id: "0123456789abcdef".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Parse issue reports for partially parsed `jj log` output
+//!
+//! When the log template output can't be fully parsed, Log View keeps the
+//! rows that did parse and `!` writes the offending raw output to a file
+//! that can be attached to a bug report.
+
+use crate::app::state::App;
+use crate::model::LogParseIssue;
+
+use super::unique_filename;
+
+/// Render the report: summary header followed by the untouched jj output
+fn format_report(issue: &LogParseIssue, revset: Option<&str>) -> String {
+    format!(
+        "tij {} log parse issue\n\
+         revset: {}\n\
+         line {}: {}\n\
+         offending line: {}\n\
+         \n\
+         --- raw jj log output ---\n\
+         {}",
+        env!("CARGO_PKG_VERSION"),
+        revset.unwrap_or("(default)"),
+        issue.line_number,
+        issue.message,
+        issue.line,
+        issue.raw_output,
+    )
+}
+
+impl App {
+    /// Write the current log parse issue to `tij-log-parse-issue.txt`
+    pub(crate) fn report_log_parse_issue(&mut self) {
+        let Some(ref issue) = self.log_view.parse_issue else {
+            return;
+        };
+        let report = format_report(issue, self.log_view.current_revset.as_deref());
+        let filename = unique_filename("tij-log-parse-issue", "txt");
+        match std::fs::write(&filename, report) {
+            Ok(()) => self.notify_success(format!("Parse issue report saved to {}", filename)),
+            Err(e) => self.set_error(format!("Failed to write {}: {}", filename, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_report_includes_issue_and_raw_output() {
+        let issue = LogParseIssue {
+            line_number: 3,
+            line: "○  abc\tbroken".to_string(),
+            message: "Expected at least 6 fields after change_id, got 1".to_string(),
+            raw_output: "@  first\n│\n○  abc\tbroken\n".to_string(),
+        };
+        let report = format_report(&issue, Some("mine()"));
+        assert!(report.contains("revset: mine()"));
+        assert!(report.contains("line 3: Expected at least 6 fields"));
+        assert!(report.contains("offending line: ○  abc\tbroken"));
+        assert!(report.ends_with("--- raw jj log output ---\n@  first\n│\n○  abc\tbroken\n"));
+    }
+
+    #[test]
+    fn test_report_without_issue_is_noop() {
+        let mut app = App::new_for_test();
+        app.report_log_parse_issue();
+        assert!(app.notification.is_none());
+        assert!(app.error_message.is_none());
+    }
+}
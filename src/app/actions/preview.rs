@@ -0,0 +1,239 @@
+//! Dry-run previews for abandon, rebase and restore-all
+//!
+//! jj has no `--dry-run` for these commands, so, as with the push preview,
+//! the confirmation dialog first summarizes what they will touch: the
+//! descendants that get rebased (counted with `jj log -r descendants(...)`)
+//! and the changes that are discarded (`jj diff --stat`). A failed query
+//! leaves its line out rather than blocking the action.
+
+use crate::app::helpers::revision::{is_root_by_commit_id, short_id};
+use crate::app::state::App;
+use crate::model::RebaseMode;
+use crate::ui::components::{Dialog, DialogCallback};
+
+/// The `N files changed, ...` line of `jj diff --stat` (None without changes)
+fn stat_summary(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .filter(|line| line.contains(" changed") && !line.starts_with("0 files"))
+}
+
+/// `1 commit` / `3 commits`
+pub(super) fn counted(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// Revsets of the commits a rebase moves and of the other commits it
+/// rebases as a side effect (descendants reparented, or the commits
+/// an insert lands in front of)
+fn rebase_revsets(source: &str, destination: &str, mode: RebaseMode) -> (String, Option<String>) {
+    let s = format!("({})", source);
+    let d = format!("({})", destination);
+    match mode {
+        RebaseMode::Revision => (s.clone(), Some(format!("descendants({s}) ~ {s}"))),
+        RebaseMode::Source => (format!("descendants({s})"), None),
+        RebaseMode::Branch => (format!("roots({d}..{s})::"), None),
+        RebaseMode::InsertAfter => (
+            s.clone(),
+            Some(format!(
+                "(descendants({s}) | (descendants({d}) ~ {d})) ~ {s}"
+            )),
+        ),
+        RebaseMode::InsertBefore => (
+            s.clone(),
+            Some(format!("(descendants({s}) | descendants({d})) ~ {s}")),
+        ),
+    }
+}
+
+/// What the rebased side-effect commits are, per mode
+fn side_effect_label(mode: RebaseMode) -> &'static str {
+    match mode {
+        RebaseMode::InsertAfter | RebaseMode::InsertBefore => "rebased onto the inserted commit",
+        _ => "reparented onto the old parents",
+    }
+}
+
+impl App {
+    /// Preview an abandon (descendants rebased, changes discarded), then ask
+    pub(crate) fn start_abandon(&mut self, revision: &str) {
+        if is_root_by_commit_id(&self.log_view.changes, revision) {
+            self.notify_info("Cannot abandon: root commit");
+            return;
+        }
+        let mut lines = vec![format!("Abandon {}?", short_id(revision, self.id_length))];
+        let descendants = format!("descendants({r}) ~ {r}", r = revision);
+        if let Ok(count) = self.jj.count_revisions(&descendants)
+            && count > 0
+        {
+            lines.push(format!(
+                "{} rebased onto its parent",
+                counted(count, "descendant")
+            ));
+        }
+        if let Ok(output) = self.jj.diff_stat(revision) {
+            lines.push(match stat_summary(&output) {
+                Some(summary) => format!("Discards: {}", summary),
+                None => "Empty change: nothing is discarded".to_string(),
+            });
+        }
+        self.active_dialog = Some(Dialog::confirm(
+            "Abandon Change",
+            lines.join("\n"),
+            Some("Undo with 'u' if needed.".to_string()),
+            DialogCallback::Abandon {
+                revision: revision.to_string(),
+            },
+        ));
+    }
+
+    /// Preview a rebase (commits moved, other commits rebased), then ask
+    ///
+    /// `use_revset`: `source` is a revset typed by the user rather than a
+    /// single commit.
+    pub(crate) fn start_rebase(
+        &mut self,
+        source: String,
+        destination: String,
+        mode: RebaseMode,
+        skip_emptied: bool,
+        simplify_parents: bool,
+        use_revset: bool,
+    ) {
+        if !use_revset && source == destination {
+            self.notify_warning("Cannot rebase to itself");
+            return;
+        }
+        let (moved, side_effect) = rebase_revsets(&source, &destination, mode);
+        let shown_source = if use_revset {
+            source.clone()
+        } else {
+            short_id(&source, self.id_length).to_string()
+        };
+        let mut lines = vec![format!(
+            "Rebase {} ({}) onto {}?",
+            shown_source,
+            mode.flag(),
+            short_id(&destination, self.id_length)
+        )];
+        if let Ok(count) = self.jj.count_revisions(&moved) {
+            lines.push(format!("Moves {}", counted(count, "commit")));
+        }
+        if let Some(revset) = side_effect
+            && let Ok(count) = self.jj.count_revisions(&revset)
+            && count > 0
+        {
+            lines.push(format!(
+                "{} {}",
+                counted(count, "commit"),
+                side_effect_label(mode)
+            ));
+        }
+        if skip_emptied {
+            lines.push("Commits that become empty are abandoned".to_string());
+        }
+        self.active_dialog = Some(Dialog::confirm(
+            "Rebase",
+            lines.join("\n"),
+            Some("Undo with 'u' if needed.".to_string()),
+            DialogCallback::Rebase {
+                source,
+                destination,
+                mode,
+                skip_emptied,
+                simplify_parents,
+                use_revset,
+            },
+        ));
+    }
+
+    /// Preview restoring every file of @ (what is discarded), then ask
+    pub(crate) fn start_restore_all(&mut self) {
+        let mut lines = vec!["Restore all files?".to_string()];
+        if let Ok(output) = self.jj.diff_stat("@")
+            && let Some(summary) = stat_summary(&output)
+        {
+            lines.push(format!("Discards: {}", summary));
+        }
+        lines.push("This discards ALL your changes in the working copy.".to_string());
+        self.active_dialog = Some(Dialog::confirm(
+            "Restore All Files",
+            lines.join("\n"),
+            Some("Undo with 'u' if needed.".to_string()),
+            DialogCallback::RestoreAll,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stat_summary() {
+        let output = "src/a.rs | 4 ++--\nsrc/b.rs | 9 +++++++++\n2 files changed, 11 insertions(+), 2 deletions(-)\n";
+        assert_eq!(
+            stat_summary(output),
+            Some("2 files changed, 11 insertions(+), 2 deletions(-)")
+        );
+        assert_eq!(
+            stat_summary("0 files changed, 0 insertions(+), 0 deletions(-)\n"),
+            None
+        );
+        assert_eq!(stat_summary(""), None);
+    }
+
+    #[test]
+    fn test_rebase_revsets() {
+        assert_eq!(
+            rebase_revsets("abc", "main", RebaseMode::Revision),
+            (
+                "(abc)".to_string(),
+                Some("descendants((abc)) ~ (abc)".to_string())
+            )
+        );
+        assert_eq!(
+            rebase_revsets("abc", "main", RebaseMode::Branch),
+            ("roots((main)..(abc))::".to_string(), None)
+        );
+        let (_, side) = rebase_revsets("abc", "def", RebaseMode::InsertBefore);
+        assert_eq!(
+            side.as_deref(),
+            Some("(descendants((abc)) | descendants((def))) ~ (abc)")
+        );
+    }
+
+    #[test]
+    fn test_start_abandon_opens_confirm_dialog() {
+        let mut app = App::new_for_test();
+        app.start_abandon("abc12345");
+        let dialog = app.active_dialog.as_ref().expect("dialog");
+        assert_eq!(
+            dialog.callback_id,
+            DialogCallback::Abandon {
+                revision: "abc12345".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_start_rebase_refuses_self() {
+        let mut app = App::new_for_test();
+        app.start_rebase(
+            "abc".to_string(),
+            "abc".to_string(),
+            RebaseMode::Revision,
+            false,
+            false,
+            false,
+        );
+        assert!(app.active_dialog.is_none());
+    }
+}
@@ -0,0 +1,226 @@
+//! Pull/merge requests for pushed bookmarks
+//!
+//! After pushing a single bookmark (and with `O` in Bookmark View) a menu
+//! offers to open the request page, copy its URL or create the request with
+//! `gh`/`glab`, which runs in the background. Open requests are listed once
+//! per session in the background when the Bookmark View is first opened, and
+//! shown as `PR`/`MR` badges.
+
+use std::sync::mpsc;
+use std::thread;
+
+use crate::app::clipboard;
+use crate::app::event::CreateRequestResult;
+use crate::app::forge::{ForgeRepo, open_in_browser};
+use crate::app::state::App;
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+const ACTION_OPEN: &str = "open";
+const ACTION_COPY: &str = "copy";
+const ACTION_CREATE: &str = "create";
+
+/// Remote used when none was chosen for the push
+const DEFAULT_REMOTE: &str = "origin";
+
+impl App {
+    /// Forge of `remote` (default: `git.push`, then `origin`), if it is one
+    fn forge_for_remote(&self, remote: Option<&str>) -> Option<ForgeRepo> {
+        ForgeRepo::from_remote_url(&self.remote_url(remote)?)
+    }
+
+    /// URL of `remote` (default: `git.push`, then `origin`)
+    pub(crate) fn remote_url(&self, remote: Option<&str>) -> Option<String> {
+        let name = match remote {
+            Some(name) => name.to_string(),
+            None => self
+                .jj
+                .config_get("git.push")
+                .ok()
+                .flatten()
+                .map(|value| value.trim_matches('"').to_string())
+                .unwrap_or_else(|| DEFAULT_REMOTE.to_string()),
+        };
+        let remotes = self.jj.git_remote_list_with_urls().ok()?;
+        remotes.into_iter().find(|r| r.name == name).map(|r| r.url)
+    }
+
+    /// Offer the request actions for `bookmark`
+    ///
+    /// `pushed` is set right after a push; a remote that isn't on a known
+    /// forge is then skipped silently instead of reported.
+    pub(crate) fn offer_pull_request(
+        &mut self,
+        bookmark: &str,
+        remote: Option<&str>,
+        pushed: bool,
+    ) {
+        let Some(forge) = self.forge_for_remote(remote) else {
+            if !pushed {
+                self.notify_info("Remote is not on GitHub, GitLab or Bitbucket: no request URL");
+            }
+            return;
+        };
+        let label = forge.kind.request_label();
+        let mut items = vec![
+            SelectItem {
+                label: format!("Open {} page in browser", label),
+                value: ACTION_OPEN.to_string(),
+                selected: false,
+            },
+            SelectItem {
+                label: format!("Copy {} URL", label),
+                value: ACTION_COPY.to_string(),
+                selected: false,
+            },
+        ];
+        if let Some(cli) = forge.installed_cli() {
+            items.push(SelectItem {
+                label: format!("Create {} with {} (title/body from commits)", label, cli),
+                value: ACTION_CREATE.to_string(),
+                selected: false,
+            });
+        }
+        let message = if pushed {
+            format!("Pushed {}:", bookmark)
+        } else {
+            format!("{}:", bookmark)
+        };
+        self.active_dialog = Some(Dialog::select_single(
+            format!("{} for {}", label, bookmark),
+            message,
+            items,
+            Some(forge.request_url(bookmark)),
+            DialogCallback::PullRequest {
+                bookmark: bookmark.to_string(),
+                web_url: forge.web_url.clone(),
+            },
+        ));
+    }
+
+    /// Run the chosen request action
+    pub(crate) fn execute_pull_request_action(
+        &mut self,
+        bookmark: &str,
+        web_url: &str,
+        choice: &str,
+    ) {
+        let Some(forge) = ForgeRepo::from_remote_url(web_url) else {
+            return;
+        };
+        let url = forge.request_url(bookmark);
+        match choice {
+            ACTION_OPEN => match open_in_browser(&url) {
+                Ok(()) => self.notify_info(format!("Opened {}", url)),
+                Err(e) => self.set_error(e),
+            },
+            ACTION_COPY => match clipboard::copy_to_clipboard(&url) {
+                Ok(()) => self.notify_success(format!("Copied {}", url)),
+                Err(e) => self.set_error(e),
+            },
+            ACTION_CREATE => self.start_create_request(forge, bookmark),
+            _ => {}
+        }
+    }
+
+    /// Create the request with the forge CLI without blocking the UI
+    fn start_create_request(&mut self, forge: ForgeRepo, bookmark: &str) {
+        let label = forge.kind.request_label();
+        if self.create_request_job.is_some() {
+            self.notify_info(format!("A {} is already being created", label));
+            return;
+        }
+        let bookmark = bookmark.to_string();
+        self.notify_info(format!("Creating {} for {}...", label, bookmark));
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let result = forge.create_request(&bookmark);
+            // Receiver may be gone if the app quit mid-request
+            let _ = tx.send((bookmark, label, result));
+        });
+        self.create_request_job = Some(rx);
+    }
+
+    /// Report a finished request creation
+    pub(crate) fn on_create_request_finished(&mut self, result: CreateRequestResult) {
+        let (bookmark, label, result) = result;
+        match result {
+            Ok(created) => {
+                self.bookmark_view.add_open_request(&bookmark);
+                self.notify_success(format!("Created {}: {}", label, created));
+            }
+            Err(e) => self.set_error(format!("Create {} failed: {}", label, e)),
+        }
+    }
+
+    /// List open requests in the background, once per session
+    pub(crate) fn start_open_request_query(&mut self) {
+        if self.open_request_query_started {
+            return;
+        }
+        self.open_request_query_started = true;
+        let Some(forge) = self.forge_for_remote(None).filter(ForgeRepo::has_cli) else {
+            return;
+        };
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let label = forge.kind.request_label();
+            // Receiver may be gone if the app quit mid-query
+            let _ = tx.send(forge.open_request_branches().map(|b| (b, label)));
+        });
+        self.open_request_query = Some(rx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_open_request_query_runs_once() {
+        let mut app = App::new_for_test();
+        app.open_request_query_started = true;
+        app.start_open_request_query();
+        assert!(app.open_request_query.is_none());
+    }
+
+    #[test]
+    fn test_created_request_event_adds_badge() {
+        use crate::app::{AppEvent, JobOutcome};
+
+        let mut app = App::new_for_test();
+        app.handle_event(AppEvent::JobFinished(JobOutcome::CreateRequest {
+            result: (
+                "feature".to_string(),
+                "PR",
+                Ok("https://x/pull/1".to_string()),
+            ),
+        }));
+        assert!(app.bookmark_view.request_badge("feature").is_some());
+        let notification = app.notification.as_ref().unwrap();
+        assert!(notification.message.contains("Created PR"));
+
+        app.handle_event(AppEvent::JobFinished(JobOutcome::CreateRequest {
+            result: ("fix".to_string(), "PR", Err("not logged in".to_string())),
+        }));
+        assert!(app.bookmark_view.request_badge("fix").is_none());
+        assert!(
+            app.error_message
+                .as_deref()
+                .is_some_and(|e| e.contains("not logged in"))
+        );
+    }
+
+    #[test]
+    fn test_open_requests_event_sets_badges() {
+        use crate::app::{AppEvent, JobOutcome};
+
+        let mut app = App::new_for_test();
+        let branches: HashSet<String> = ["feature".to_string()].into();
+        app.handle_event(AppEvent::JobFinished(JobOutcome::OpenRequests {
+            result: Ok((branches, "MR")),
+        }));
+        assert_eq!(app.bookmark_view.request_badge("feature"), Some("MR"));
+        assert_eq!(app.bookmark_view.request_badge("main"), None);
+    }
+}
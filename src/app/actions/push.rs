@@ -1,8 +1,8 @@
 //! Git push operations
 
 use crate::app::helpers::revision::short_id;
-use crate::jj::{PushBulkMode, PushPreviewResult, parse_push_dry_run, parse_push_skipped};
-use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+use crate::jj::{JjError, PushBulkMode, PushPreviewResult, parse_push_dry_run, parse_push_skipped};
+use crate::ui::components::{BatchResult, Dialog, DialogCallback, SelectItem};
 
 use std::time::Instant;
 
@@ -108,7 +108,7 @@ impl App {
                         }
                         PushPreviewResult::Changes(actions) => {
                             // Include dry-run result in message (multi-line)
-                            let preview_text = format_preview_actions(&actions);
+                            let preview_text = format_preview_actions(&actions, self.id_length);
                             let is_force = has_force_push(&actions);
                             let is_protected = is_immutable_bookmark(name);
 
@@ -172,7 +172,7 @@ impl App {
             }
         } else {
             // Multiple bookmarks: first ask user to choose push mode
-            let short_id = short_id(&change_id);
+            let short_id = short_id(&change_id, self.id_length);
             let items = vec![
                 SelectItem {
                     label: "All bookmarks on this revision (--revisions)".to_string(),
@@ -218,21 +218,84 @@ impl App {
 
         // Take remote at the top → guaranteed cleanup on success/error
         let remote = self.push_target_remote.take();
+        if !self.run_pre_push_hook() {
+            return;
+        }
+        let backups = match self.backup_force_push_targets(bookmark_names) {
+            Ok(backups) => backups,
+            Err(e) => {
+                self.set_error(format!("Push aborted: backup bookmark failed: {}", e));
+                self.pending_push_bookmarks.clear();
+                return;
+            }
+        };
 
-        let mut successes = Vec::new();
-        let mut errors = Vec::new();
-        let mut used_allow_new = false;
-        let mut retry_notes: Vec<&str> = Vec::new();
+        let batch = self.push_bookmark_batch(bookmark_names, remote.as_deref());
+        let successes = &batch.successes;
+
+        // Show result (include remote name if specified)
+        if !successes.is_empty() {
+            let names = successes.join(", ");
+            let suffix = format!(
+                "{}{}",
+                build_push_suffix(batch.used_allow_new, &batch.retry_notes),
+                super::backup::backup_suffix(&backups)
+            );
+            let msg = if let Some(r) = remote.as_deref() {
+                format!("Pushed bookmark: {} to {}{}", names, r, suffix)
+            } else {
+                format!("Pushed bookmark: {}{}", names, suffix)
+            };
+            self.notify_success(msg);
+        }
+        if bookmark_names.len() > 1 && !batch.errors.is_empty() {
+            let message = match remote.as_deref() {
+                Some(r) => format!("Push to {}", r),
+                None => "Push".to_string(),
+            };
+            self.show_batch_results("Push Results", message, batch.results(bookmark_names));
+        } else if !batch.errors.is_empty() {
+            self.set_error(format!("Push failed: {}", batch.error_summary()));
+        }
 
-        for name in bookmark_names {
+        // Always clear pending state after execution (prevent stale data)
+        self.pending_push_bookmarks.clear();
+
+        // Refresh after push
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+
+        if let [bookmark] = batch.successes.as_slice()
+            && batch.errors.is_empty()
+        {
+            self.offer_pull_request(bookmark, remote.as_deref(), true);
+        }
+    }
+
+    /// Push each bookmark with `jj git push --bookmark`, retrying once with
+    /// `--allow-new`/`--allow-private`/`--allow-empty-description` when the
+    /// error calls for it
+    ///
+    /// Runs no hook, backup or refresh; callers report the outcome.
+    pub(super) fn push_bookmark_batch(
+        &mut self,
+        names: &[String],
+        remote: Option<&str>,
+    ) -> PushBatch {
+        let mut batch = PushBatch::default();
+
+        for name in names {
+            // A cancelled push stops the batch; the rest is left unpushed
+            if self.cancelled_network.is_some() {
+                break;
+            }
             let start = Instant::now();
-            let result = if let Some(ref r) = remote {
+            let result = if let Some(r) = remote {
                 self.jj.git_push_bookmark_to_remote(name, r)
             } else {
                 self.jj.git_push_bookmark(name)
             };
 
-            let push_args: Vec<&str> = if let Some(ref r) = remote {
+            let push_args: Vec<&str> = if let Some(r) = remote {
                 vec!["git", "push", "--bookmark", name, "--remote", r]
             } else {
                 vec!["git", "push", "--bookmark", name]
@@ -241,7 +304,7 @@ impl App {
 
             match result {
                 Ok(_) => {
-                    successes.push(name.clone());
+                    batch.successes.push(name.clone());
                 }
                 Err(e) => {
                     let err_msg = format!("{}", e);
@@ -260,7 +323,7 @@ impl App {
 
                     if !extra_flags.is_empty() {
                         let retry_start = Instant::now();
-                        let retry = if let Some(ref r) = remote {
+                        let retry = if let Some(r) = remote {
                             self.jj
                                 .git_push_bookmark_to_remote_with_flags(name, r, &extra_flags)
                         } else {
@@ -274,55 +337,35 @@ impl App {
 
                         match retry {
                             Ok(_) => {
-                                successes.push(name.clone());
+                                batch.successes.push(name.clone());
                                 if extra_flags.contains(&crate::jj::constants::flags::ALLOW_NEW) {
-                                    used_allow_new = true;
+                                    batch.used_allow_new = true;
                                 }
                                 if extra_flags.contains(&crate::jj::constants::flags::ALLOW_PRIVATE)
-                                    && !retry_notes.contains(&"private commit allowed")
+                                    && !batch.retry_notes.contains(&"private commit allowed")
                                 {
-                                    retry_notes.push("private commit allowed");
+                                    batch.retry_notes.push("private commit allowed");
                                 }
                                 if extra_flags
                                     .contains(&crate::jj::constants::flags::ALLOW_EMPTY_DESC)
-                                    && !retry_notes.contains(&"empty description allowed")
+                                    && !batch.retry_notes.contains(&"empty description allowed")
                                 {
-                                    retry_notes.push("empty description allowed");
+                                    batch.retry_notes.push("empty description allowed");
                                 }
                                 continue;
                             }
                             Err(e2) => {
-                                errors.push(format!("{}: {}", name, e2));
+                                batch.errors.push((name.clone(), e2.to_string()));
                             }
                         }
                     } else {
-                        errors.push(format!("{}: {}", name, e));
+                        batch.errors.push((name.clone(), e.to_string()));
                     }
                 }
             }
         }
 
-        // Show result (include remote name if specified)
-        if !successes.is_empty() {
-            let names = successes.join(", ");
-            let suffix = build_push_suffix(used_allow_new, &retry_notes);
-            let msg = if let Some(r) = remote.as_deref() {
-                format!("Pushed bookmark: {} to {}{}", names, r, suffix)
-            } else {
-                format!("Pushed bookmark: {}{}", names, suffix)
-            };
-            self.notify_success(msg);
-        }
-        if !errors.is_empty() {
-            let msg = errors.join("; ");
-            self.set_error(format!("Push failed: {}", msg));
-        }
-
-        // Always clear pending state after execution (prevent stale data)
-        self.pending_push_bookmarks.clear();
-
-        // Refresh after push
-        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+        batch
     }
 
     /// Execute `jj git push --change <change_id>` and refresh
@@ -332,6 +375,9 @@ impl App {
     /// On private/empty-description errors, retries with appropriate flags.
     pub(crate) fn execute_push_change(&mut self, change_id: &str) {
         let remote = self.push_target_remote.take();
+        if !self.run_pre_push_hook() {
+            return;
+        }
         let start = Instant::now();
         let result = if let Some(ref r) = remote {
             self.jj.git_push_change_to_remote(change_id, r)
@@ -348,7 +394,7 @@ impl App {
         match result {
             Ok(output) => {
                 self.notify_push_change_success(&output, change_id, remote.as_deref(), &[]);
-                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
             }
             Err(e) => {
                 let err_msg = format!("{}", e);
@@ -381,7 +427,7 @@ impl App {
                                 remote.as_deref(),
                                 &extra_flags,
                             );
-                            self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                            self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
                         }
                         Err(e2) => {
                             self.set_error(format!("Push failed: {}", e2));
@@ -402,8 +448,8 @@ impl App {
         remote: Option<&str>,
         extra_flags: &[&str],
     ) {
-        let bookmark_name = Self::parse_push_change_bookmark(output, change_id);
-        let short_id = short_id(change_id);
+        let bookmark_name = Self::parse_push_change_bookmark(output, change_id, self.id_length);
+        let short_id = short_id(change_id, self.id_length);
         let notes = retry_notes_from_flags(extra_flags);
         let suffix = build_push_suffix(false, &notes);
         let msg = match (bookmark_name, remote) {
@@ -428,7 +474,11 @@ impl App {
     /// Parse the auto-created bookmark name from `jj git push --change` output
     ///
     /// Output format: "Creating bookmark push-XXXXX for revision XXXXX"
-    fn parse_push_change_bookmark(output: &str, change_id: &str) -> Option<String> {
+    fn parse_push_change_bookmark(
+        output: &str,
+        change_id: &str,
+        id_length: usize,
+    ) -> Option<String> {
         for line in output.lines() {
             if let Some(rest) = line.strip_prefix("Creating bookmark ")
                 && let Some(name) = rest.split_whitespace().next()
@@ -437,7 +487,7 @@ impl App {
             }
         }
         // Fallback: construct expected name
-        Some(format!("push-{}", short_id(change_id)))
+        Some(format!("push-{}", short_id(change_id, id_length)))
     }
 
     /// Start push-by-change flow (extracted for reuse from mode selection)
@@ -452,7 +502,7 @@ impl App {
         match dry_run_result {
             Ok(output) => {
                 let preview = output.trim();
-                let short_id = short_id(change_id);
+                let short_id = short_id(change_id, self.id_length);
                 let body = if preview.is_empty() {
                     format!("Push by change ID? (creates push-{})", short_id)
                 } else {
@@ -476,7 +526,7 @@ impl App {
                 let err_msg = format!("{}", e);
                 let retry_flags = detect_push_retry_flags(&err_msg);
                 if !retry_flags.is_empty() {
-                    let short_id = short_id(change_id);
+                    let short_id = short_id(change_id, self.id_length);
                     self.active_dialog = Some(Dialog::confirm(
                         "Push to Remote",
                         format!(
@@ -514,7 +564,7 @@ impl App {
                         self.notify_info(format!("Nothing to push ({})", mode.label()));
                     }
                     PushPreviewResult::Changes(actions) => {
-                        let preview_text = format_preview_actions(&actions);
+                        let preview_text = format_preview_actions(&actions, self.id_length);
                         let is_force = has_force_push(&actions);
                         // Check if any action targets a protected bookmark
                         let has_protected = actions.iter().any(|a| {
@@ -585,6 +635,9 @@ impl App {
     /// Execute bulk push (called after confirmation)
     pub(super) fn execute_push_bulk(&mut self, mode: PushBulkMode, remote: Option<&str>) {
         self.push_target_remote = None;
+        if !self.run_pre_push_hook() {
+            return;
+        }
 
         let start = Instant::now();
         let result = self.jj.git_push_bulk(mode, remote);
@@ -609,7 +662,7 @@ impl App {
                     )
                 };
                 self.notify_success(msg);
-                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
             }
             Err(e) => {
                 self.set_error(format!("Push failed: {}", e));
@@ -635,7 +688,11 @@ impl App {
             let status = match dry_run {
                 Ok(output) => {
                     let preview = parse_push_dry_run(&output);
-                    format_bookmark_status(&preview, name)
+                    format_bookmark_status(&preview, name, self.id_length)
+                }
+                Err(JjError::Cancelled { .. }) => {
+                    self.notify_info("Push preview cancelled");
+                    return;
                 }
                 Err(_) => String::new(),
             };
@@ -655,7 +712,10 @@ impl App {
 
         self.active_dialog = Some(Dialog::select(
             "Push to Remote",
-            format!("Select bookmarks to push from {}:", short_id(change_id)),
+            format!(
+                "Select bookmarks to push from {}:",
+                short_id(change_id, self.id_length)
+            ),
             items,
             Some("Remote changes cannot be undone with 'u'.".to_string()),
             DialogCallback::GitPush,
@@ -685,7 +745,7 @@ impl App {
                         );
                     }
                     PushPreviewResult::Changes(actions) => {
-                        let preview_text = format_preview_actions(&actions);
+                        let preview_text = format_preview_actions(&actions, self.id_length);
                         let is_force = has_force_push(&actions);
                         let has_protected = actions.iter().any(|a| {
                             let name = match a {
@@ -698,7 +758,7 @@ impl App {
                             is_immutable_bookmark(name)
                         });
 
-                        let short_id = short_id(change_id);
+                        let short_id = short_id(change_id, self.id_length);
                         let (body, detail) = if is_force && has_protected {
                             (
                                 format!(
@@ -736,7 +796,7 @@ impl App {
                     }
                     PushPreviewResult::Unparsed => {
                         // Fallback: show confirm without parsed preview
-                        let short_id = short_id(change_id);
+                        let short_id = short_id(change_id, self.id_length);
                         self.active_dialog = Some(Dialog::confirm(
                             "Push to Remote",
                             format!("Push all bookmarks on {}?", short_id),
@@ -754,7 +814,7 @@ impl App {
                 if !detect_push_retry_flags(&err_msg).is_empty() {
                     // Dry-run failed due to private/empty-description: show confirm
                     // dialog anyway. The actual push will retry with flags.
-                    let short_id = short_id(change_id);
+                    let short_id = short_id(change_id, self.id_length);
                     self.active_dialog = Some(Dialog::confirm(
                         "Push to Remote",
                         format!(
@@ -779,6 +839,9 @@ impl App {
     /// On private/empty-description errors, retries with appropriate flags.
     pub(super) fn execute_push_revisions(&mut self, change_id: &str, _bookmarks: &[String]) {
         let remote = self.push_target_remote.take();
+        if !self.run_pre_push_hook() {
+            return;
+        }
         let start = Instant::now();
         let result = if let Some(ref r) = remote {
             self.jj.git_push_revisions_to_remote(change_id, r)
@@ -794,14 +857,14 @@ impl App {
 
         match result {
             Ok(_) => {
-                let sid = short_id(change_id);
+                let sid = short_id(change_id, self.id_length);
                 let msg = if let Some(r) = remote.as_deref() {
                     format!("Pushed all bookmarks on {} to {}", sid, r)
                 } else {
                     format!("Pushed all bookmarks on {}", sid)
                 };
                 self.notify_success(msg);
-                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
             }
             Err(e) => {
                 let err_msg = format!("{}", e);
@@ -828,7 +891,7 @@ impl App {
 
                     match retry {
                         Ok(_) => {
-                            let sid = short_id(change_id);
+                            let sid = short_id(change_id, self.id_length);
                             let notes = retry_notes_from_flags(&extra_flags);
                             let suffix = build_push_suffix(false, &notes);
                             let msg = if let Some(r) = remote.as_deref() {
@@ -837,7 +900,7 @@ impl App {
                                 format!("Pushed all bookmarks on {}{}", sid, suffix)
                             };
                             self.notify_success(msg);
-                            self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+                            self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
                         }
                         Err(e2) => {
                             self.set_error(format!("Push failed: {}", e2));
@@ -853,13 +916,51 @@ impl App {
 
 // ── Free functions ───────────────────────────────────────────────────────
 
+/// Outcome of pushing several bookmarks one by one
+#[derive(Debug, Default)]
+pub(super) struct PushBatch {
+    pub successes: Vec<String>,
+    /// `(bookmark, error)` per failed bookmark
+    pub errors: Vec<(String, String)>,
+    pub used_allow_new: bool,
+    pub retry_notes: Vec<&'static str>,
+}
+
+impl PushBatch {
+    /// `"<bookmark>: <error>"` per failure, joined for a one-line report
+    pub fn error_summary(&self) -> String {
+        self.errors
+            .iter()
+            .map(|(name, error)| format!("{}: {}", name, error))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// One row per bookmark of `names`, in order; bookmarks the batch never
+    /// reached (cancelled push) count as failed
+    pub fn results(&self, names: &[String]) -> Vec<BatchResult> {
+        names
+            .iter()
+            .map(|name| {
+                if self.successes.contains(name) {
+                    BatchResult::ok(name)
+                } else if let Some((_, error)) = self.errors.iter().find(|(n, _)| n == name) {
+                    BatchResult::failed(name, error)
+                } else {
+                    BatchResult::failed(name, "not pushed (cancelled)")
+                }
+            })
+            .collect()
+    }
+}
+
 /// Check if any push actions involve a force push (non-fast-forward)
 ///
 /// Uses safe-side detection: anything that is NOT a known-safe action
 /// (MoveForward, Add, Delete) is treated as a force push. This ensures
 /// that future jj action types (e.g. new move directions) are flagged
 /// as potentially dangerous by default.
-fn has_force_push(actions: &[crate::jj::PushPreviewAction]) -> bool {
+pub(super) fn has_force_push(actions: &[crate::jj::PushPreviewAction]) -> bool {
     use crate::jj::PushPreviewAction;
     actions.iter().any(|a| {
         !matches!(
@@ -888,9 +989,9 @@ fn is_immutable_bookmark(name: &str) -> bool {
 
 /// Format preview actions for confirm dialog display
 ///
-/// Produces a compact single-line per action, with hashes truncated to 8 chars.
+/// Produces a compact single-line per action, with hashes truncated to `id_length` chars.
 /// Force push actions are prefixed with a warning symbol.
-fn format_preview_actions(actions: &[crate::jj::PushPreviewAction]) -> String {
+fn format_preview_actions(actions: &[crate::jj::PushPreviewAction], id_length: usize) -> String {
     use crate::jj::PushPreviewAction;
     actions
         .iter()
@@ -899,31 +1000,31 @@ fn format_preview_actions(actions: &[crate::jj::PushPreviewAction]) -> String {
                 format!(
                     "Move forward {} from {}.. to {}..",
                     bookmark,
-                    short_id(from),
-                    short_id(to)
+                    short_id(from, id_length),
+                    short_id(to, id_length)
                 )
             }
             PushPreviewAction::MoveSideways { bookmark, from, to } => {
                 format!(
                     "\u{26A0} Move sideways {} from {}.. to {}..",
                     bookmark,
-                    short_id(from),
-                    short_id(to)
+                    short_id(from, id_length),
+                    short_id(to, id_length)
                 )
             }
             PushPreviewAction::MoveBackward { bookmark, from, to } => {
                 format!(
                     "\u{26A0} Move backward {} from {}.. to {}..",
                     bookmark,
-                    short_id(from),
-                    short_id(to)
+                    short_id(from, id_length),
+                    short_id(to, id_length)
                 )
             }
             PushPreviewAction::Add { bookmark, to } => {
-                format!("Add {} to {}..", bookmark, short_id(to))
+                format!("Add {} to {}..", bookmark, short_id(to, id_length))
             }
             PushPreviewAction::Delete { bookmark, from } => {
-                format!("Delete {} from {}..", bookmark, short_id(from))
+                format!("Delete {} from {}..", bookmark, short_id(from, id_length))
             }
         })
         .collect::<Vec<_>>()
@@ -931,14 +1032,18 @@ fn format_preview_actions(actions: &[crate::jj::PushPreviewAction]) -> String {
 }
 
 /// Format a single bookmark's dry-run status for select dialog label
-fn format_bookmark_status(preview: &crate::jj::PushPreviewResult, name: &str) -> String {
+pub(super) fn format_bookmark_status(
+    preview: &crate::jj::PushPreviewResult,
+    name: &str,
+    id_length: usize,
+) -> String {
     use crate::jj::{PushPreviewAction, PushPreviewResult};
     match preview {
         PushPreviewResult::Changes(actions) => actions
             .iter()
             .find_map(|a| match a {
                 PushPreviewAction::MoveForward { bookmark, from, .. } if bookmark == name => {
-                    Some(format!("move from {}..", short_id(from)))
+                    Some(format!("move from {}..", short_id(from, id_length)))
                 }
                 PushPreviewAction::MoveSideways { bookmark, .. } if bookmark == name => {
                     if is_immutable_bookmark(name) {
@@ -1033,7 +1138,7 @@ fn retry_notes_from_flags<'a>(extra_flags: &[&str]) -> Vec<&'a str> {
 /// - `" (used deprecated --allow-new)"` when allow_new is true
 /// - `" (private commit allowed)"` for private retry
 /// - `" (private commit allowed + empty description allowed)"` for both
-fn build_push_suffix(used_allow_new: bool, retry_notes: &[&str]) -> String {
+pub(super) fn build_push_suffix(used_allow_new: bool, retry_notes: &[&str]) -> String {
     let mut parts: Vec<&str> = Vec::new();
     if used_allow_new {
         parts.push("used deprecated --allow-new");
@@ -1051,6 +1156,32 @@ mod tests {
     use super::*;
     use crate::ui::components::{Dialog, DialogCallback, DialogResult};
 
+    // =========================================================================
+    // PushBatch reporting tests
+    // =========================================================================
+
+    #[test]
+    fn test_push_batch_results_keep_bookmark_order() {
+        let batch = PushBatch {
+            successes: vec!["docs".to_string()],
+            errors: vec![("main".to_string(), "rejected".to_string())],
+            ..PushBatch::default()
+        };
+        let names: Vec<String> = ["main", "docs", "feature"]
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+        assert_eq!(
+            batch.results(&names),
+            vec![
+                BatchResult::failed("main", "rejected"),
+                BatchResult::ok("docs"),
+                BatchResult::failed("feature", "not pushed (cancelled)"),
+            ]
+        );
+        assert_eq!(batch.error_summary(), "main: rejected");
+    }
+
     // =========================================================================
     // has_force_push tests
     // =========================================================================
@@ -1125,7 +1256,7 @@ mod tests {
             from: "aaa111bbb222".to_string(),
             to: "ccc333ddd444".to_string(),
         }]);
-        let status = format_bookmark_status(&preview, "main");
+        let status = format_bookmark_status(&preview, "main", 8);
         assert_eq!(status, "\u{26A0} PROTECTED force");
     }
 
@@ -1138,7 +1269,7 @@ mod tests {
             from: "aaa111bbb222".to_string(),
             to: "ccc333ddd444".to_string(),
         }]);
-        let status = format_bookmark_status(&preview, "feature-x");
+        let status = format_bookmark_status(&preview, "feature-x", 8);
         assert_eq!(status, "\u{26A0} force");
     }
 
@@ -1150,7 +1281,7 @@ mod tests {
             from: "aaa111bbb222".to_string(),
             to: "ccc333ddd444".to_string(),
         }]);
-        let status = format_bookmark_status(&preview, "main");
+        let status = format_bookmark_status(&preview, "main", 8);
         assert!(status.starts_with("move from"));
     }
 
@@ -1162,7 +1293,7 @@ mod tests {
     fn test_push_change_output_parsing() {
         let output = "Creating bookmark push-ryxwqxsq for revision ryxwqxsq\n\
                        Add bookmark push-ryxwqxsq to abc1234567890";
-        let result = App::parse_push_change_bookmark(output, "ryxwqxsq");
+        let result = App::parse_push_change_bookmark(output, "ryxwqxsq", 8);
         assert_eq!(result, Some("push-ryxwqxsq".to_string()));
     }
 
@@ -1170,13 +1301,13 @@ mod tests {
     fn test_push_change_output_parsing_fallback() {
         // No "Creating bookmark" in output → fallback to constructed name
         let output = "Some other output";
-        let result = App::parse_push_change_bookmark(output, "abcd1234");
+        let result = App::parse_push_change_bookmark(output, "abcd1234", 8);
         assert_eq!(result, Some("push-abcd1234".to_string()));
     }
 
     #[test]
     fn test_push_change_output_parsing_empty() {
-        let result = App::parse_push_change_bookmark("", "xyz98765");
+        let result = App::parse_push_change_bookmark("", "xyz98765", 8);
         assert_eq!(result, Some("push-xyz98765".to_string()));
     }
 
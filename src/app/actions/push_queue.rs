@@ -0,0 +1,219 @@
+//! Push queue: stage bookmarks from several views, push them in one batch
+//!
+//! `Q` in Log View (bookmarks of the selected change) and Bookmark View
+//! (selected local bookmark) adds bookmarks to the queue, or removes them
+//! when they are all queued already. `Ctrl+P` runs a dry-run per queued
+//! bookmark, lists the results for review and pushes the checked ones to the
+//! default remote. Pushed bookmarks leave the queue; failed ones stay for
+//! another try.
+
+use crate::jj::{JjError, PushPreviewResult, parse_push_dry_run};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+use crate::app::state::{App, DirtyFlags};
+
+use super::push::{PushBatch, build_push_suffix, format_bookmark_status, has_force_push};
+
+/// Add `names` to the queue, or remove them if all are queued
+///
+/// Returns `true` when bookmarks were added.
+fn toggle_queued(queue: &mut Vec<String>, names: &[String]) -> bool {
+    if names.iter().all(|name| queue.contains(name)) {
+        queue.retain(|queued| !names.contains(queued));
+        false
+    } else {
+        for name in names {
+            if !queue.contains(name) {
+                queue.push(name.clone());
+            }
+        }
+        true
+    }
+}
+
+/// Single report for a queue push: pushed and failed bookmarks together
+fn format_queue_report(batch: &PushBatch, suffix: &str) -> String {
+    let mut parts = Vec::new();
+    if !batch.successes.is_empty() {
+        parts.push(format!("pushed {}{}", batch.successes.join(", "), suffix));
+    }
+    if !batch.errors.is_empty() {
+        parts.push(format!("failed {}", batch.error_summary()));
+    }
+    format!("Push queue: {}", parts.join("; "))
+}
+
+impl App {
+    /// Queue or unqueue bookmarks for the next batched push
+    pub(crate) fn toggle_push_queue(&mut self, names: &[String]) {
+        if names.is_empty() {
+            self.notify_info("No bookmarks to queue on this change");
+            return;
+        }
+        let added = toggle_queued(&mut self.push_queue, names);
+        self.bookmark_view.set_queued(&self.push_queue);
+        let count = self.push_queue.len();
+        if added {
+            self.notify_info(format!(
+                "Queued {} for push ({} queued, Ctrl+P to review)",
+                names.join(", "),
+                count
+            ));
+        } else {
+            self.notify_info(format!(
+                "Removed {} from push queue ({} queued)",
+                names.join(", "),
+                count
+            ));
+        }
+    }
+
+    /// Dry-run every queued bookmark and show the batch for confirmation
+    pub(crate) fn start_push_queue(&mut self) {
+        if self.push_queue.is_empty() {
+            self.notify_info("Push queue is empty: Q queues bookmarks in Log or Bookmark View");
+            return;
+        }
+
+        let mut items = Vec::new();
+        let mut force = Vec::new();
+        for name in self.push_queue.clone() {
+            let (status, pending) = match self.jj.git_push_dry_run(&name) {
+                Ok(output) => {
+                    let preview = parse_push_dry_run(&output);
+                    if let PushPreviewResult::Changes(ref actions) = preview
+                        && has_force_push(actions)
+                    {
+                        force.push(name.clone());
+                    }
+                    let pending = !matches!(preview, PushPreviewResult::NothingChanged);
+                    (
+                        format_bookmark_status(&preview, &name, self.id_length),
+                        pending,
+                    )
+                }
+                Err(JjError::Cancelled { .. }) => {
+                    self.notify_info("Push preview cancelled");
+                    return;
+                }
+                // Untracked bookmarks fail the dry-run but push with --allow-new
+                Err(e) => {
+                    let first_line = e.to_string().lines().next().unwrap_or_default().to_string();
+                    (format!("dry-run failed: {}", first_line), true)
+                }
+            };
+            items.push(SelectItem {
+                label: format!("{:<24} {}", name, status),
+                value: name,
+                selected: pending,
+            });
+        }
+
+        let detail = if force.is_empty() {
+            "Pushed to the default remote. Remote changes cannot be undone with 'u'.".to_string()
+        } else {
+            format!(
+                "\u{26A0} Force push: {}. Remote changes cannot be undone with 'u'.",
+                force.join(", ")
+            )
+        };
+        self.active_dialog = Some(Dialog::select(
+            "Push Queue",
+            format!(
+                "{} queued bookmark(s). Push the checked ones together:",
+                items.len()
+            ),
+            items,
+            Some(detail),
+            DialogCallback::PushQueue,
+        ));
+    }
+
+    /// Push the confirmed bookmarks and report all outcomes at once
+    pub(crate) fn execute_push_queue(&mut self, names: &[String]) {
+        if names.is_empty() || !self.run_pre_push_hook() {
+            return;
+        }
+        let backups = match self.backup_force_push_targets(names) {
+            Ok(backups) => backups,
+            Err(e) => {
+                self.set_error(format!("Push aborted: backup bookmark failed: {}", e));
+                return;
+            }
+        };
+
+        let batch = self.push_bookmark_batch(names, None);
+        self.push_queue
+            .retain(|queued| !batch.successes.contains(queued));
+        self.bookmark_view.set_queued(&self.push_queue);
+
+        let suffix = format!(
+            "{}{}",
+            build_push_suffix(batch.used_allow_new, &batch.retry_notes),
+            super::backup::backup_suffix(&backups)
+        );
+        let report = format_queue_report(&batch, &suffix);
+        if batch.errors.is_empty() {
+            self.notify_success(report);
+        } else if names.len() > 1 {
+            self.show_batch_results("Push Queue Results", report, batch.results(names));
+        } else {
+            self.set_error(report);
+        }
+
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_toggle_queued_adds_missing_then_removes_all() {
+        let mut queue = strings(&["main"]);
+        assert!(toggle_queued(&mut queue, &strings(&["main", "feature"])));
+        assert_eq!(queue, ["main", "feature"]);
+
+        assert!(!toggle_queued(&mut queue, &strings(&["feature", "main"])));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_format_queue_report_mixed_outcome() {
+        let batch = PushBatch {
+            successes: strings(&["main", "docs"]),
+            errors: vec![("feature".to_string(), "rejected".to_string())],
+            ..PushBatch::default()
+        };
+        assert_eq!(
+            format_queue_report(&batch, " (used deprecated --allow-new)"),
+            "Push queue: pushed main, docs (used deprecated --allow-new); failed feature: rejected"
+        );
+    }
+
+    #[test]
+    fn test_empty_queue_shows_hint() {
+        let mut app = App::new_for_test();
+        app.start_push_queue();
+        assert!(app.active_dialog.is_none());
+        let notification = app.notification.as_ref().unwrap();
+        assert!(notification.message.contains("Push queue is empty"));
+    }
+
+    #[test]
+    fn test_toggle_push_queue_syncs_bookmark_view() {
+        let mut app = App::new_for_test();
+        app.toggle_push_queue(&strings(&["main"]));
+        assert_eq!(app.push_queue, ["main"]);
+        assert!(app.bookmark_view.is_queued("main"));
+
+        app.toggle_push_queue(&strings(&["main"]));
+        assert!(app.push_queue.is_empty());
+        assert!(!app.bookmark_view.is_queued("main"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Typed rebase destinations
+//!
+//! `t` in RebaseSelect mode asks for the destination as text instead of a
+//! log row, so a target outside the current revset can still be used. The
+//! input accepts a bookmark, tag, change-id prefix or any revset that
+//! resolves to exactly one revision; bookmark and tag names Tab-complete.
+//! The rest of the flow is the usual rebase preview.
+
+use crate::app::state::App;
+use crate::model::{Bookmark, RebaseMode, TagInfo};
+use crate::ui::components::{Dialog, DialogCallback};
+
+/// Completion candidates: local bookmarks, `name@remote` for remote ones
+/// (jj's internal `git` remote excluded) and local tags
+fn destination_candidates(bookmarks: &[Bookmark], tags: &[TagInfo]) -> Vec<String> {
+    let mut candidates: Vec<String> = bookmarks
+        .iter()
+        .filter_map(|b| match b.remote.as_deref() {
+            None => Some(b.name.clone()),
+            Some("git") => None,
+            Some(remote) => Some(format!("{}@{}", b.name, remote)),
+        })
+        .chain(
+            tags.iter()
+                .filter(|t| t.remote.is_none() && t.present)
+                .map(|t| t.name.clone()),
+        )
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+impl App {
+    /// Ask for the rebase destination as text (RebaseSelect `t`)
+    pub(crate) fn start_pick_rebase_destination(
+        &mut self,
+        source: String,
+        mode: RebaseMode,
+        skip_emptied: bool,
+        simplify_parents: bool,
+        use_revset: bool,
+    ) {
+        let bookmarks = self.jj.bookmark_list_all().unwrap_or_default();
+        let tags = self.jj.tag_list().unwrap_or_default();
+        self.active_dialog = Some(Dialog::input_with_completions(
+            "Rebase Destination",
+            format!(
+                "Rebase {} onto (bookmark, change ID or revset):",
+                mode.flag()
+            ),
+            destination_candidates(&bookmarks, &tags),
+            DialogCallback::RebaseDestination {
+                source,
+                mode,
+                skip_emptied,
+                simplify_parents,
+                use_revset,
+            },
+        ));
+    }
+
+    /// Resolve a typed destination to a single commit, then preview the rebase
+    pub(crate) fn resolve_rebase_destination(
+        &mut self,
+        destination: &str,
+        source: String,
+        mode: RebaseMode,
+        skip_emptied: bool,
+        simplify_parents: bool,
+        use_revset: bool,
+    ) {
+        if destination.is_empty() {
+            self.notify_info("Rebase cancelled: no destination");
+            return;
+        }
+        match self.jj.count_revisions(destination) {
+            Ok(1) => {}
+            Ok(0) => {
+                self.notify_warning(format!("'{}' matches no revision", destination));
+                return;
+            }
+            Ok(count) => {
+                self.notify_warning(format!(
+                    "'{}' matches {} revisions; the destination must be one",
+                    destination, count
+                ));
+                return;
+            }
+            Err(e) => {
+                self.set_error(format!("Invalid destination '{}': {}", destination, e));
+                return;
+            }
+        }
+        match self.jj.full_ids(destination) {
+            Ok((_, commit_id)) => self.start_rebase(
+                source,
+                commit_id,
+                mode,
+                skip_emptied,
+                simplify_parents,
+                use_revset,
+            ),
+            Err(e) => self.set_error(format!("Invalid destination '{}': {}", destination, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(name: &str, remote: Option<&str>) -> Bookmark {
+        Bookmark {
+            name: name.to_string(),
+            remote: remote.map(str::to_string),
+            is_tracked: remote.is_some(),
+            tracking: None,
+        }
+    }
+
+    fn tag(name: &str, remote: Option<&str>) -> TagInfo {
+        TagInfo {
+            name: name.to_string(),
+            remote: remote.map(str::to_string),
+            present: true,
+            change_id: None,
+            commit_id: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn candidates_cover_bookmarks_remotes_and_tags() {
+        let bookmarks = vec![
+            bookmark("main", None),
+            bookmark("main", Some("origin")),
+            bookmark("main", Some("git")),
+            bookmark("feature", Some("upstream")),
+        ];
+        let tags = vec![tag("v1.0", None), tag("v1.0", Some("origin"))];
+        assert_eq!(
+            destination_candidates(&bookmarks, &tags),
+            vec!["feature@upstream", "main", "main@origin", "v1.0"]
+        );
+    }
+
+    #[test]
+    fn empty_destination_does_not_open_preview() {
+        let mut app = App::new_for_test();
+        app.resolve_rebase_destination(
+            "",
+            "abc123".to_string(),
+            RebaseMode::Revision,
+            false,
+            false,
+            false,
+        );
+        assert!(app.active_dialog.is_none());
+    }
+}
@@ -0,0 +1,177 @@
+//! Git remote operations (list, add, remove, rename, set-url, fetch, push)
+//! and `jj git import`/`jj git export`
+
+use crate::app::state::{App, DirtyFlags, View};
+use crate::jj::PushBulkMode;
+use crate::ui::components::{Dialog, DialogCallback};
+use crate::ui::views::RemoteAction;
+
+/// Split the add dialog input `<name> <url>`
+fn parse_add_input(input: &str) -> Option<(&str, &str)> {
+    let (name, url) = input.trim().split_once(char::is_whitespace)?;
+    let url = url.trim();
+    (!url.is_empty()).then_some((name, url))
+}
+
+impl App {
+    /// Open the remote view
+    pub(crate) fn open_remote_view(&mut self) {
+        match self.jj.git_remote_list_with_urls() {
+            Ok(remotes) => {
+                self.remote_view.set_remotes(remotes);
+                self.refresh_git_sync();
+                self.go_to_view(View::Remote);
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to list remotes: {}", e));
+            }
+        }
+    }
+
+    /// Refresh the remote view data
+    pub(crate) fn refresh_remote_view(&mut self) {
+        match self.jj.git_remote_list_with_urls() {
+            Ok(remotes) => {
+                self.remote_view.set_remotes(remotes);
+                self.refresh_git_sync();
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to list remotes: {}", e));
+            }
+        }
+    }
+
+    /// Handle remote view actions
+    pub(crate) fn handle_remote_action(&mut self, action: RemoteAction) {
+        match action {
+            RemoteAction::None => {}
+            RemoteAction::StartAdd => {
+                self.active_dialog = Some(Dialog::input(
+                    "Add Remote",
+                    "Name and URL (e.g., upstream https://github.com/org/repo.git)",
+                    DialogCallback::RemoteAdd,
+                ));
+            }
+            RemoteAction::Remove(name) => {
+                self.active_dialog = Some(Dialog::confirm(
+                    "Remove Remote",
+                    format!(
+                        "Remove remote '{}'?\n(its remote bookmarks are forgotten)",
+                        name
+                    ),
+                    None,
+                    DialogCallback::RemoteRemove { name },
+                ));
+            }
+            RemoteAction::StartRename(old_name) => {
+                self.active_dialog = Some(Dialog::input(
+                    "Rename Remote",
+                    format!("New name for '{}'", old_name),
+                    DialogCallback::RemoteRename { old_name },
+                ));
+            }
+            RemoteAction::StartSetUrl(name) => {
+                self.active_dialog = Some(Dialog::input(
+                    "Set Remote URL",
+                    format!("New URL for '{}'", name),
+                    DialogCallback::RemoteSetUrl { name },
+                ));
+            }
+            RemoteAction::Fetch(name) => self.execute_fetch_with_option(&name),
+            RemoteAction::Push(name) => {
+                self.push_target_remote = Some(name);
+                self.start_push_bulk(PushBulkMode::Tracked);
+            }
+            RemoteAction::GitImport => self.execute_git_sync("Git import", "import"),
+            RemoteAction::GitExport => self.execute_git_sync("Git export", "export"),
+        }
+    }
+
+    /// Handle confirmed remote dialog results
+    pub(crate) fn handle_remote_dialog(&mut self, callback: DialogCallback, values: Vec<String>) {
+        let input = values.first().map(|v| v.trim()).unwrap_or_default();
+        match callback {
+            DialogCallback::RemoteAdd => match parse_add_input(input) {
+                Some((name, url)) => self.execute_remote_command(
+                    "Remote add",
+                    &["git", "remote", "add", name, url],
+                    format!("Remote '{}' added", name),
+                ),
+                None if input.is_empty() => {}
+                None => self.notify_warning("Enter a remote name and URL separated by a space"),
+            },
+            DialogCallback::RemoteRemove { name } => self.execute_remote_command(
+                "Remote remove",
+                &["git", "remote", "remove", &name],
+                format!("Remote '{}' removed", name),
+            ),
+            DialogCallback::RemoteRename { old_name } if !input.is_empty() && input != old_name => {
+                self.execute_remote_command(
+                    "Remote rename",
+                    &["git", "remote", "rename", &old_name, input],
+                    format!("Remote '{}' renamed to '{}'", old_name, input),
+                );
+            }
+            DialogCallback::RemoteSetUrl { name } if !input.is_empty() => {
+                self.execute_remote_command(
+                    "Remote set-url",
+                    &["git", "remote", "set-url", &name, input],
+                    format!("Remote '{}' now points to {}", name, input),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Run `jj git import` or `jj git export` and reload the sync state
+    fn execute_git_sync(&mut self, operation: &str, subcommand: &str) {
+        match self.run_and_record(operation, &["git", subcommand]) {
+            Ok(output) => {
+                // jj reports e.g. "Nothing changed." or the refs it updated
+                let summary = output.lines().next().unwrap_or_default().trim();
+                if summary.is_empty() {
+                    self.notify_success(format!("{} done", operation));
+                } else {
+                    self.notify_success(format!("{}: {}", operation, summary));
+                }
+                self.mark_dirty_and_refresh_current(DirtyFlags::all());
+                self.refresh_git_sync();
+            }
+            Err(e) => {
+                self.set_error(format!("{} failed: {}", operation, e));
+            }
+        }
+    }
+
+    /// Run a `jj git remote` subcommand and reload the list
+    ///
+    /// Removing and renaming remotes changes remote bookmarks, so the log and
+    /// Bookmark View are marked dirty as well.
+    fn execute_remote_command(&mut self, operation: &str, args: &[&str], success_msg: String) {
+        match self.run_and_record(operation, args) {
+            Ok(_) => {
+                self.notify_success(success_msg);
+                self.refresh_remote_view();
+                self.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+            }
+            Err(e) => {
+                self.set_error(format!("{} failed: {}", operation, e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_add_input() {
+        assert_eq!(
+            parse_add_input("  upstream   https://github.com/org/repo.git "),
+            Some(("upstream", "https://github.com/org/repo.git"))
+        );
+        assert_eq!(parse_add_input("upstream"), None);
+        assert_eq!(parse_add_input(""), None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Log View reorder mode: move a change past its child or parent
+//!
+//! Uses `jj rebase -r <change> --insert-after <child>` / `--insert-before
+//! <parent>`; jj reattaches the change's descendants to its old parent, so
+//! repeated presses walk the change along a linear chain.
+
+use crate::app::state::App;
+use crate::model::RebaseMode;
+
+impl App {
+    /// Move `change_id` one step up (past its child) or down (before its parent)
+    ///
+    /// Only linear neighbours are supported: a change with several children
+    /// (or parents) has no single position to swap with.
+    pub(crate) fn reorder_commit(&mut self, change_id: &str, up: bool) {
+        let (revset, mode, relation) = if up {
+            (
+                format!("children({})", change_id),
+                RebaseMode::InsertAfter,
+                "children",
+            )
+        } else {
+            (
+                format!("parents({}) ~ root()", change_id),
+                RebaseMode::InsertBefore,
+                "parents",
+            )
+        };
+
+        let neighbours = match self.jj.change_ids(&revset) {
+            Ok(ids) => ids,
+            Err(e) => {
+                self.set_error(format!("Reorder failed: {}", e));
+                return;
+            }
+        };
+
+        match neighbours.as_slice() {
+            [] if up => self.notify_info("Already at the top: no child to move past"),
+            [] => self.notify_info("Already at the bottom: no parent to move before"),
+            [target] => {
+                self.execute_rebase(change_id, target, mode, false, false, false);
+                // The refresh resets the selection; keep following the moved change
+                if self.error_message.is_none() {
+                    self.log_view.select_change_by_id(change_id);
+                }
+            }
+            many => self.notify_warning(format!(
+                "{} has {} {}: reorder only moves along a linear chain",
+                change_id,
+                many.len(),
+                relation
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reorder_without_repo_reports_error() {
+        // No jj repository in tests: the neighbour lookup fails cleanly
+        let mut app = App::new_for_test();
+        app.reorder_commit("abc12345", true);
+        assert!(app.error_message.is_some());
+    }
+}
@@ -0,0 +1,79 @@
+//! Reverting a range of changes (Ctrl+Z in the Log View)
+//!
+//! The two ends are picked like a parallelize range, in either order. The
+//! whole range is reverted by a single `jj revert -r <range> --onto @`, so
+//! one `u` takes all the reverse commits back.
+
+use crate::app::helpers::revision::short_id;
+use crate::app::state::{App, DirtyFlags};
+use crate::ui::components::{Dialog, DialogCallback};
+
+/// Revset for everything between two ends, whichever is the ancestor
+fn range_revset(from: &str, to: &str) -> String {
+    format!("({from}::{to}) | ({to}::{from})")
+}
+
+impl App {
+    /// Count the range and ask before reverting it
+    pub(crate) fn start_revert_range(&mut self, from: &str, to: &str) {
+        let revset = range_revset(from, to);
+        let count = match self.jj.count_revisions(&revset) {
+            Ok(0) => {
+                self.notify_warning(format!(
+                    "{} and {} are not ancestors of each other",
+                    short_id(from, self.id_length),
+                    short_id(to, self.id_length)
+                ));
+                return;
+            }
+            Ok(count) => count,
+            Err(e) => {
+                self.set_error(format!("Revert failed: {}", e));
+                return;
+            }
+        };
+        self.active_dialog = Some(Dialog::confirm(
+            "Revert Range",
+            format!(
+                "Revert {} changes between {} and {}?",
+                count,
+                short_id(from, self.id_length),
+                short_id(to, self.id_length)
+            ),
+            Some(format!(
+                "Creates {} reverse commits on top of @ in one operation. Undo with 'u' if needed.",
+                count
+            )),
+            DialogCallback::RevertRange { revset, count },
+        ));
+    }
+
+    /// `jj revert -r <revset> --onto @`
+    pub(crate) fn execute_revert_range(&mut self, revset: &str, count: usize) {
+        let msg = format!("Reverted {} changes (undo: u)", count);
+        let result = self.run_and_record("Revert", &["revert", "-r", revset, "--onto", "@"]);
+        self.run_jj_action(result, "Revert failed", &msg, DirtyFlags::log());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_revset_accepts_either_order() {
+        assert_eq!(range_revset("aaa", "bbb"), "(aaa::bbb) | (bbb::aaa)");
+    }
+
+    #[test]
+    fn revert_range_confirmed_runs_revert() {
+        let mut app = App::new_for_test();
+        app.execute_revert_range("(aaa::bbb) | (bbb::aaa)", 3);
+        // jj is not available in tests, so the failure proves the call
+        assert!(
+            app.error_message
+                .as_ref()
+                .is_some_and(|e| e.contains("Revert failed"))
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Named revset presets for Log View
+//!
+//! Presets live in jj config, e.g.
+//! `jj config set --user tij.revset-presets.mine 'mine() & ~empty()'`.
+//! `*` cycles through them (then back to the default view); `#` opens a
+//! menu to pick one or save the current revset under a new name.
+
+use std::time::Instant;
+
+use crate::app::state::App;
+use crate::jj::constants::config_keys;
+use crate::model::RevsetPreset;
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Menu value: go back to the default view
+const MENU_DEFAULT: &str = "+default";
+/// Menu value: save the current revset (never a valid preset name)
+const MENU_SAVE: &str = "+save";
+
+impl App {
+    /// Load `[tij.revset-presets]` into Log View (errors leave presets empty)
+    pub(crate) fn load_revset_presets(&mut self) {
+        self.log_view.revset_presets = self.jj.revset_presets().unwrap_or_default();
+    }
+
+    /// Apply the preset after the active one, or the default view after the last
+    pub(crate) fn cycle_revset_preset(&mut self) {
+        let total = self.log_view.revset_presets.len();
+        if total == 0 {
+            self.notify_info(format!(
+                "No revset presets: add [{}] to jj config or save one with #",
+                config_keys::REVSET_PRESETS
+            ));
+            return;
+        }
+
+        match self.log_view.next_preset().cloned() {
+            Some(preset) => {
+                let position = self
+                    .log_view
+                    .revset_presets
+                    .iter()
+                    .position(|p| *p == preset)
+                    .map_or(0, |i| i + 1);
+                self.apply_revset_preset(&preset);
+                if self.error_message.is_none() {
+                    self.notify_info(format!(
+                        "Revset preset: {} ({}/{})",
+                        preset.name, position, total
+                    ));
+                }
+            }
+            None => {
+                self.refresh_log(None);
+                self.notify_info("Revset preset: off (default view)");
+            }
+        }
+    }
+
+    /// Open the preset menu (apply a preset, reset, or save the current revset)
+    pub(crate) fn open_revset_preset_menu(&mut self) {
+        let active = self.log_view.active_preset().map(|p| p.name.clone());
+        let mut items: Vec<SelectItem> = self
+            .log_view
+            .revset_presets
+            .iter()
+            .map(|preset| {
+                let marker = if active.as_deref() == Some(preset.name.as_str()) {
+                    "* "
+                } else {
+                    "  "
+                };
+                SelectItem {
+                    label: format!("{}{}: {}", marker, preset.name, preset.revset),
+                    value: preset.name.clone(),
+                    selected: false,
+                }
+            })
+            .collect();
+        items.push(SelectItem {
+            label: "  Default view (no revset)".to_string(),
+            value: MENU_DEFAULT.to_string(),
+            selected: false,
+        });
+        if let Some(ref revset) = self.log_view.current_revset {
+            items.push(SelectItem {
+                label: format!("  Save current revset as preset... ({})", revset),
+                value: MENU_SAVE.to_string(),
+                selected: false,
+            });
+        }
+
+        self.active_dialog = Some(Dialog::select_single(
+            "Revset Presets",
+            "Apply a preset:",
+            items,
+            Some(format!(
+                "Stored in jj config under [{}]",
+                config_keys::REVSET_PRESETS
+            )),
+            DialogCallback::RevsetPresetMenu,
+        ));
+    }
+
+    /// Handle confirmed preset menu / save dialogs
+    pub(crate) fn handle_revset_preset_dialog(
+        &mut self,
+        callback: DialogCallback,
+        values: Vec<String>,
+    ) {
+        let Some(value) = values.first() else { return };
+        match callback {
+            DialogCallback::RevsetPresetMenu => match value.as_str() {
+                MENU_DEFAULT => self.refresh_log(None),
+                MENU_SAVE => {
+                    if let Some(revset) = self.log_view.current_revset.clone() {
+                        self.active_dialog = Some(Dialog::input(
+                            "Save Revset Preset",
+                            format!("Name for '{}' (letters, digits, - and _)", revset),
+                            DialogCallback::RevsetPresetSave { revset },
+                        ));
+                    }
+                }
+                name => {
+                    let preset = self
+                        .log_view
+                        .revset_presets
+                        .iter()
+                        .find(|p| p.name == name)
+                        .cloned();
+                    if let Some(preset) = preset {
+                        self.apply_revset_preset(&preset);
+                    }
+                }
+            },
+            DialogCallback::RevsetPresetSave { revset } => {
+                self.save_revset_preset(value.trim(), &revset);
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_revset_preset(&mut self, preset: &RevsetPreset) {
+        self.log_view.revset_history.push(preset.revset.clone());
+        self.refresh_log(Some(&preset.revset));
+    }
+
+    /// Write a preset to user config and reload the list
+    fn save_revset_preset(&mut self, name: &str, revset: &str) {
+        if !RevsetPreset::is_valid_name(name) {
+            self.set_error(format!(
+                "Invalid preset name '{}': use letters, digits, '-' and '_'",
+                name
+            ));
+            return;
+        }
+
+        let start = Instant::now();
+        let result = self.jj.save_revset_preset(name, revset);
+        let key = format!("{}.{}", config_keys::REVSET_PRESETS, name);
+        self.record_str_command(
+            "Save revset preset",
+            &["config", "set", "--user", &key, revset],
+            start,
+            &result,
+        );
+
+        match result {
+            Ok(_) => {
+                self.load_revset_presets();
+                self.notify_success(format!("Saved revset preset '{}'", name));
+            }
+            Err(e) => self.set_error(format!("Failed to save revset preset: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(name: &str, revset: &str) -> RevsetPreset {
+        RevsetPreset {
+            name: name.to_string(),
+            revset: revset.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_cycle_without_presets_notifies() {
+        let mut app = App::new_for_test();
+        app.cycle_revset_preset();
+        assert!(app.notification.is_some());
+        assert!(app.log_view.current_revset.is_none());
+    }
+
+    #[test]
+    fn test_menu_lists_presets_and_save_entry() {
+        let mut app = App::new_for_test();
+        app.log_view.revset_presets = vec![preset("mine", "mine()")];
+        app.log_view.current_revset = Some("trunk()..@".to_string());
+        app.open_revset_preset_menu();
+
+        let Some(dialog) = app.active_dialog.as_ref() else {
+            panic!("menu should open");
+        };
+        let crate::ui::components::DialogKind::Select { items, .. } = &dialog.kind else {
+            panic!("expected select dialog");
+        };
+        let values: Vec<&str> = items.iter().map(|i| i.value.as_str()).collect();
+        assert_eq!(values, vec!["mine", MENU_DEFAULT, MENU_SAVE]);
+    }
+
+    #[test]
+    fn test_save_rejects_invalid_name() {
+        let mut app = App::new_for_test();
+        app.save_revset_preset("my stack", "trunk()..@");
+        assert!(app.error_message.is_some());
+    }
+}
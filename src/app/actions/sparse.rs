@@ -0,0 +1,134 @@
+//! Sparse checkout patterns (`jj sparse list/set/reset`)
+//!
+//! The Sparse View stages pattern edits; applying them first counts the
+//! files of `@` that would materialize or disappear and asks to confirm.
+
+use crate::app::state::{App, DirtyFlags, View};
+use crate::model::{FULL_CHECKOUT, SparseImpact};
+use crate::ui::components::{Dialog, DialogCallback};
+use crate::ui::views::SparseAction;
+
+/// `jj` arguments that switch the working copy to `patterns`
+fn sparse_set_args(patterns: &[String]) -> Vec<&str> {
+    if patterns == [FULL_CHECKOUT] {
+        return vec!["sparse", "reset"];
+    }
+    let mut args = vec!["sparse", "set", "--clear"];
+    for pattern in patterns {
+        args.extend(["--add", pattern.as_str()]);
+    }
+    args
+}
+
+impl App {
+    /// Open the sparse view
+    pub(crate) fn open_sparse_view(&mut self) {
+        match self.jj.sparse_list() {
+            Ok(patterns) => {
+                self.sparse_view.set_patterns(patterns);
+                self.go_to_view(View::Sparse);
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to list sparse patterns: {}", e));
+            }
+        }
+    }
+
+    /// Refresh the sparse view data (drops staged edits)
+    pub(crate) fn refresh_sparse_view(&mut self) {
+        match self.jj.sparse_list() {
+            Ok(patterns) => self.sparse_view.set_patterns(patterns),
+            Err(e) => {
+                self.set_error(format!("Failed to list sparse patterns: {}", e));
+            }
+        }
+    }
+
+    /// Handle sparse view actions
+    pub(crate) fn handle_sparse_action(&mut self, action: SparseAction) {
+        match action {
+            SparseAction::None => {}
+            SparseAction::StartAdd => {
+                self.active_dialog = Some(Dialog::input(
+                    "Add Sparse Pattern",
+                    "Path to check out (e.g., src or docs/api; . for everything)",
+                    DialogCallback::SparseAdd,
+                ));
+            }
+            SparseAction::Apply => self.start_sparse_apply(),
+        }
+    }
+
+    /// Preview the staged patterns, then ask to confirm
+    fn start_sparse_apply(&mut self) {
+        let patterns = self.sparse_view.pending_patterns().to_vec();
+        let impact = match self.jj.file_list() {
+            Ok(files) => {
+                SparseImpact::between(&files, self.sparse_view.applied_patterns(), &patterns)
+                    .summary()
+            }
+            Err(e) => format!("preview unavailable: {}", e),
+        };
+        let target = if patterns.is_empty() {
+            "no paths (empty working copy)".to_string()
+        } else {
+            patterns.join(", ")
+        };
+        self.active_dialog = Some(Dialog::confirm(
+            "Apply Sparse Patterns",
+            format!("Check out: {}\nImpact: {}", target, impact),
+            Some(format!("jj {}", sparse_set_args(&patterns).join(" "))),
+            DialogCallback::SparseApply { patterns },
+        ));
+    }
+
+    /// Handle confirmed sparse dialog results
+    pub(crate) fn handle_sparse_dialog(&mut self, callback: DialogCallback, values: Vec<String>) {
+        match callback {
+            DialogCallback::SparseAdd => {
+                let input = values.first().map(|v| v.trim()).unwrap_or_default();
+                if !input.is_empty() && !self.sparse_view.add_pattern(input) {
+                    self.notify_info(format!("'{}' is already in the list", input));
+                }
+            }
+            DialogCallback::SparseApply { patterns } => {
+                match self.run_and_record("Sparse set", &sparse_set_args(&patterns)) {
+                    Ok(_) => {
+                        self.notify_success("Sparse patterns applied");
+                        self.refresh_sparse_view();
+                        self.mark_dirty_and_refresh_current(DirtyFlags::working_copy());
+                    }
+                    Err(e) => self.set_error(format!("Sparse set failed: {}", e)),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_set_args() {
+        assert_eq!(
+            sparse_set_args(&[FULL_CHECKOUT.to_string()]),
+            ["sparse", "reset"]
+        );
+        assert_eq!(
+            sparse_set_args(&["src".to_string(), "docs".to_string()]),
+            ["sparse", "set", "--clear", "--add", "src", "--add", "docs"]
+        );
+        assert_eq!(sparse_set_args(&[]), ["sparse", "set", "--clear"]);
+    }
+
+    #[test]
+    fn test_add_dialog_stages_pattern() {
+        let mut app = App::new_for_test();
+        app.sparse_view
+            .set_patterns(vec![FULL_CHECKOUT.to_string()]);
+        app.handle_sparse_dialog(DialogCallback::SparseAdd, vec![" src/ ".to_string()]);
+        assert_eq!(app.sparse_view.pending_patterns(), ["src"]);
+    }
+}
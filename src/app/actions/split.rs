@@ -0,0 +1,130 @@
+//! Split by file list: `jj split <paths>` without the diff editor
+//!
+//! `Ctrl+X` in Log View lists the files the selected change touches. The
+//! checked files stay in the first change, the rest move to a new change on
+//! top of it. The current description is passed with `-m` so that jj opens
+//! no editor either; it can be edited afterwards with `d`.
+
+use super::squash::{file_label, picked_paths, renamed_from};
+use crate::app::helpers::revision::{is_root_by_commit_id, short_id};
+use crate::app::state::{App, DirtyFlags};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// `jj split -r <revision> -m <message> -- <paths>`
+fn split_args<'a>(revision: &'a str, message: &'a str, paths: &'a [String]) -> Vec<&'a str> {
+    let mut args = vec!["split", "-r", revision, "-m", message, "--"];
+    args.extend(paths.iter().map(String::as_str));
+    args
+}
+
+impl App {
+    /// List the files of `revision` to pick the first half of a split
+    pub(crate) fn start_split_files(&mut self, revision: &str) {
+        if is_root_by_commit_id(&self.log_view.changes, revision) {
+            self.notify_info("Cannot split: root commit");
+            return;
+        }
+        let files = match self.jj.diff_summary(revision) {
+            Ok(files) => files,
+            Err(e) => {
+                self.set_error(format!("Failed to list files: {}", e));
+                return;
+            }
+        };
+        if files.len() < 2 {
+            self.notify_info(format!(
+                "Cannot split {}: needs at least two changed files (x splits hunks)",
+                short_id(revision, self.id_length)
+            ));
+            return;
+        }
+        let items = files
+            .iter()
+            .map(|file| SelectItem {
+                label: file_label(file),
+                value: file.path.clone(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select(
+            "Split Files",
+            format!(
+                "Files of {} to keep in the first change:",
+                short_id(revision, self.id_length)
+            ),
+            items,
+            Some("The other files move to a new change on top of it.".to_string()),
+            DialogCallback::SplitFiles {
+                revision: revision.to_string(),
+                total: files.len(),
+                renamed_from: renamed_from(&files),
+            },
+        ));
+    }
+
+    /// Split off the unchecked files; none or all checked leaves nothing to split
+    pub(crate) fn execute_split_files(
+        &mut self,
+        revision: &str,
+        checked: &[String],
+        total: usize,
+        renamed_from: &[(String, String)],
+    ) {
+        if checked.is_empty() || checked.len() == total {
+            self.notify_info("Nothing to split: check some of the files, not all");
+            return;
+        }
+        let description = match self.jj.get_description(revision) {
+            Ok(description) => description,
+            Err(e) => {
+                self.set_error(format!("Split failed: {}", e));
+                return;
+            }
+        };
+        let paths = picked_paths(checked, renamed_from);
+        let result = self.run_and_record(
+            "Split files",
+            &split_args(revision, description.trim_end(), &paths),
+        );
+        let msg = format!(
+            "Split {}: kept {} of {} files (undo: u)",
+            short_id(revision, self.id_length),
+            checked.len(),
+            total
+        );
+        self.run_jj_action(result, "Split failed", &msg, DirtyFlags::log_and_status());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_args_keep_description_and_paths() {
+        let paths = vec!["old.rs".to_string(), "new.rs".to_string()];
+        assert_eq!(
+            split_args("def67890", "Add feature", &paths),
+            vec![
+                "split",
+                "-r",
+                "def67890",
+                "-m",
+                "Add feature",
+                "--",
+                "old.rs",
+                "new.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn checking_none_or_all_files_does_not_run() {
+        let mut app = App::new_for_test();
+        app.execute_split_files("def67890", &[], 2, &[]);
+        let all = vec!["a.rs".to_string(), "b.rs".to_string()];
+        app.execute_split_files("def67890", &all, 2, &[]);
+        assert!(app.command_history.is_empty());
+        assert!(app.error_message.is_none());
+    }
+}
@@ -0,0 +1,296 @@
+//! How descriptions combine when squashing into another revision
+//!
+//! `jj squash --from <a> --into <b>` opens an editor when both revisions
+//! have a description. Before that happens a dialog offers to keep the
+//! destination's message (`--use-destination-message`), keep the source's
+//! or concatenate both (`--message`), or still open the editor. Without two
+//! descriptions to choose from the squash runs straight away.
+//!
+//! `f` instead of Enter in squash select mode lists the files the source
+//! changes and moves only the checked ones (`jj squash ... -- <paths>`).
+
+use crate::app::helpers::revision::{is_root_by_commit_id, short_id};
+use crate::app::state::{App, DirtyFlags};
+use crate::model::{FileState, FileStatus};
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Dialog values
+const KEEP_DESTINATION: &str = "destination";
+const KEEP_SOURCE: &str = "source";
+const CONCATENATE: &str = "concatenate";
+const EDITOR: &str = "editor";
+
+/// Message flags for a dialog choice (None: open the editor instead)
+fn message_flags(choice: &str, source_desc: &str, destination_desc: &str) -> Option<Vec<String>> {
+    match choice {
+        KEEP_DESTINATION => Some(vec!["--use-destination-message".to_string()]),
+        KEEP_SOURCE => Some(vec![
+            "--message".to_string(),
+            source_desc.trim().to_string(),
+        ]),
+        CONCATENATE => Some(vec![
+            "--message".to_string(),
+            format!("{}\n\n{}", destination_desc.trim(), source_desc.trim()),
+        ]),
+        _ => None,
+    }
+}
+
+/// `[M] path` / `[R] old -> new` labels for the file picker
+pub(super) fn file_label(file: &FileStatus) -> String {
+    match &file.state {
+        FileState::Renamed { from } => format!("[R] {} -> {}", from, file.path),
+        _ => format!("[{}] {}", file.indicator(), file.path),
+    }
+}
+
+/// `(new, old)` path pairs of the renamed files
+pub(super) fn renamed_from(files: &[FileStatus]) -> Vec<(String, String)> {
+    files
+        .iter()
+        .filter_map(|file| match &file.state {
+            FileState::Renamed { from } => Some((file.path.clone(), from.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Paths to pass for the checked files; a rename needs its old path too,
+/// or the deletion would stay behind in the source
+pub(super) fn picked_paths(checked: &[String], renamed_from: &[(String, String)]) -> Vec<String> {
+    let mut paths = Vec::new();
+    for path in checked {
+        if let Some((_, from)) = renamed_from.iter().find(|(to, _)| to == path) {
+            paths.push(from.clone());
+        }
+        paths.push(path.clone());
+    }
+    paths
+}
+
+/// First line of a description, for the dialog labels
+fn first_line(description: &str) -> &str {
+    description.lines().next().unwrap_or_default().trim()
+}
+
+impl App {
+    /// Squash `source` into `destination`, asking how to combine messages
+    /// when both have one
+    pub(crate) fn start_squash_into(&mut self, source: &str, destination: &str) {
+        if is_root_by_commit_id(&self.log_view.changes, source) {
+            self.notify_info("Cannot squash: root commit has no parent");
+            return;
+        }
+        let descriptions = self
+            .jj
+            .get_description(source)
+            .and_then(|src| Ok((src, self.jj.get_description(destination)?)));
+        let (source_desc, destination_desc) = match descriptions {
+            Ok(descriptions) => descriptions,
+            // Let jj decide (it opens the editor if it has to)
+            Err(_) => return self.execute_squash_into(source, destination),
+        };
+        if source_desc.trim().is_empty() || destination_desc.trim().is_empty() {
+            // No editor would open: jj keeps the only description there is
+            return self.execute_squash_with_flags(source, destination, &[]);
+        }
+
+        let items = vec![
+            SelectItem {
+                label: format!("Keep destination: {}", first_line(&destination_desc)),
+                value: KEEP_DESTINATION.to_string(),
+                selected: false,
+            },
+            SelectItem {
+                label: format!("Keep source: {}", first_line(&source_desc)),
+                value: KEEP_SOURCE.to_string(),
+                selected: false,
+            },
+            SelectItem {
+                label: "Concatenate (destination, then source)".to_string(),
+                value: CONCATENATE.to_string(),
+                selected: false,
+            },
+            SelectItem {
+                label: "Edit in editor".to_string(),
+                value: EDITOR.to_string(),
+                selected: false,
+            },
+        ];
+        self.active_dialog = Some(Dialog::select_single(
+            "Squash Message",
+            format!(
+                "Squash {} into {}: which description?",
+                short_id(source, self.id_length),
+                short_id(destination, self.id_length)
+            ),
+            items,
+            Some("Undo with 'u' if needed.".to_string()),
+            DialogCallback::SquashMessage {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                source_desc,
+                destination_desc,
+            },
+        ));
+    }
+
+    /// Run the squash with the chosen message handling
+    pub(crate) fn execute_squash_message_choice(
+        &mut self,
+        source: &str,
+        destination: &str,
+        source_desc: &str,
+        destination_desc: &str,
+        choice: &str,
+    ) {
+        match message_flags(choice, source_desc, destination_desc) {
+            Some(flags) => {
+                let flags: Vec<&str> = flags.iter().map(String::as_str).collect();
+                self.execute_squash_with_flags(source, destination, &flags);
+            }
+            None => self.execute_squash_into(source, destination),
+        }
+    }
+
+    /// List the files `source` changes to pick what moves into `destination`
+    pub(crate) fn start_squash_files(&mut self, source: &str, destination: &str) {
+        if is_root_by_commit_id(&self.log_view.changes, source) {
+            self.notify_info("Cannot squash: root commit has no parent");
+            return;
+        }
+        let files = match self.jj.diff_summary(source) {
+            Ok(files) => files,
+            Err(e) => {
+                self.set_error(format!("Failed to list files: {}", e));
+                return;
+            }
+        };
+        if files.is_empty() {
+            self.notify_info(format!(
+                "{} has no changes to squash",
+                short_id(source, self.id_length)
+            ));
+            return;
+        }
+        let renamed_from = renamed_from(&files);
+        let items = files
+            .iter()
+            .map(|file| SelectItem {
+                label: file_label(file),
+                value: file.path.clone(),
+                selected: false,
+            })
+            .collect();
+        self.active_dialog = Some(Dialog::select(
+            "Squash Files",
+            format!(
+                "Files of {} to move into {}:",
+                short_id(source, self.id_length),
+                short_id(destination, self.id_length)
+            ),
+            items,
+            Some("Checking every file squashes the whole change.".to_string()),
+            DialogCallback::SquashFiles {
+                source: source.to_string(),
+                destination: destination.to_string(),
+                total: files.len(),
+                renamed_from,
+            },
+        ));
+    }
+
+    /// Move the checked files; all of them is a plain squash
+    pub(crate) fn execute_squash_files(
+        &mut self,
+        source: &str,
+        destination: &str,
+        checked: &[String],
+        total: usize,
+        renamed_from: &[(String, String)],
+    ) {
+        if checked.len() == total {
+            // The source ends up empty, so the descriptions need combining
+            return self.start_squash_into(source, destination);
+        }
+        let paths = picked_paths(checked, renamed_from);
+        let mut args = vec!["squash", "--from", source, "--into", destination, "--"];
+        args.extend(paths.iter().map(String::as_str));
+        let result = self.run_and_record("Squash files", &args);
+        let msg = format!(
+            "Squashed {} of {} files from {} into {} (undo: u)",
+            checked.len(),
+            total,
+            short_id(source, self.id_length),
+            short_id(destination, self.id_length)
+        );
+        self.run_jj_action(result, "Squash failed", &msg, DirtyFlags::log_and_status());
+    }
+
+    /// `jj squash --from <source> --into <destination> <flags>` without a TTY
+    fn execute_squash_with_flags(&mut self, source: &str, destination: &str, flags: &[&str]) {
+        let mut args = vec!["squash", "--from", source, "--into", destination];
+        args.extend_from_slice(flags);
+        let result = self.run_and_record("Squash into", &args);
+        let msg = format!(
+            "Squashed {} into {} (undo: u)",
+            short_id(source, self.id_length),
+            short_id(destination, self.id_length)
+        );
+        self.run_jj_action(result, "Squash failed", &msg, DirtyFlags::log_and_status());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_flags_per_choice() {
+        assert_eq!(
+            message_flags(KEEP_DESTINATION, "src\n", "dst\n"),
+            Some(vec!["--use-destination-message".to_string()])
+        );
+        assert_eq!(
+            message_flags(KEEP_SOURCE, "src\n\nbody\n", "dst\n"),
+            Some(vec!["--message".to_string(), "src\n\nbody".to_string()])
+        );
+        assert_eq!(
+            message_flags(CONCATENATE, "src\n", "dst\n"),
+            Some(vec!["--message".to_string(), "dst\n\nsrc".to_string()])
+        );
+        assert_eq!(message_flags(EDITOR, "src", "dst"), None);
+    }
+
+    #[test]
+    fn picked_paths_include_rename_sources() {
+        let renamed_from = vec![("new.rs".to_string(), "old.rs".to_string())];
+        let checked = vec!["a.rs".to_string(), "new.rs".to_string()];
+        assert_eq!(
+            picked_paths(&checked, &renamed_from),
+            vec!["a.rs", "old.rs", "new.rs"]
+        );
+    }
+
+    #[test]
+    fn file_labels_show_status() {
+        let modified = FileStatus {
+            path: "src/lib.rs".to_string(),
+            state: FileState::Modified,
+        };
+        let renamed = FileStatus {
+            path: "b.rs".to_string(),
+            state: FileState::Renamed {
+                from: "a.rs".to_string(),
+            },
+        };
+        assert_eq!(file_label(&modified), "[M] src/lib.rs");
+        assert_eq!(file_label(&renamed), "[R] a.rs -> b.rs");
+    }
+
+    #[test]
+    fn first_line_of_description() {
+        assert_eq!(first_line("Fix parser\n\nDetails\n"), "Fix parser");
+        assert_eq!(first_line(""), "");
+    }
+}
@@ -0,0 +1,75 @@
+//! Stack View operations (list `trunk()..@`, reorder commits)
+
+use crate::app::state::{App, View};
+use crate::model::RebaseMode;
+use crate::ui::views::StackAction;
+
+impl App {
+    /// Open the stack view
+    pub(crate) fn open_stack_view(&mut self) {
+        match self.jj.stack() {
+            Ok(entries) => {
+                self.stack_view.set_entries(entries, None);
+                self.go_to_view(View::Stack);
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to load stack: {}", e));
+            }
+        }
+    }
+
+    /// Reload the stack, keeping the selected change selected
+    pub(crate) fn refresh_stack_view(&mut self) {
+        let keep = self
+            .stack_view
+            .selected_entry()
+            .map(|e| e.change_id.to_string());
+        match self.jj.stack() {
+            Ok(entries) => self.stack_view.set_entries(entries, keep.as_deref()),
+            Err(e) => self.set_error(format!("Failed to load stack: {}", e)),
+        }
+    }
+
+    /// Handle stack view actions
+    pub(crate) fn handle_stack_action(&mut self, action: StackAction) {
+        match action {
+            StackAction::None => {}
+            StackAction::Jump(change_id) => self.jump_to_log(&change_id),
+            StackAction::MoveBlocked(reason) => self.notify_info(reason),
+            // Selection follows the moved change (refresh keeps it by change ID)
+            StackAction::MoveUp { source, target } => {
+                self.execute_rebase(
+                    &source,
+                    &target,
+                    RebaseMode::InsertAfter,
+                    false,
+                    false,
+                    false,
+                );
+            }
+            StackAction::MoveDown { source, target } => {
+                self.execute_rebase(
+                    &source,
+                    &target,
+                    RebaseMode::InsertBefore,
+                    false,
+                    false,
+                    false,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_blocked_notifies() {
+        let mut app = App::new_for_test();
+        app.handle_stack_action(StackAction::MoveBlocked("Already at the top of the stack"));
+        assert!(app.notification.is_some());
+        assert!(app.error_message.is_none());
+    }
+}
@@ -0,0 +1,115 @@
+//! Rebase suggestion when a fetch moves `trunk()` under the current stack
+//!
+//! After a fetch in which `trunk()` advanced, a banner above the Log View
+//! status bar offers to move the stack `@` sits on (`trunk()..@`) onto the
+//! new trunk. `R` opens the usual rebase preview for `jj rebase -s <stack
+//! root> -d trunk()`, Esc dismisses the banner. The stack is looked up again
+//! when `R` is pressed, so a stale banner just reports that there is nothing
+//! left to do.
+
+use super::preview::counted;
+use crate::app::state::App;
+use crate::model::RebaseMode;
+
+/// First commit of the stack under `@`
+const STACK_ROOT: &str = "roots(trunk()..@)";
+
+/// Commits `trunk()` gained since the operation before the fetch
+fn trunk_advance_revset(pre_op: &str) -> String {
+    format!("at_operation({}, trunk())..trunk()", pre_op)
+}
+
+impl App {
+    /// Stack root and trunk commit IDs, when `@` sits on a single-rooted,
+    /// mutable stack that is not based on the current `trunk()`
+    fn stack_rebase_target(&self) -> Option<(String, String)> {
+        if self.jj.count_revisions("trunk() & ::@").ok()? > 0
+            || self.jj.count_revisions(STACK_ROOT).ok()? != 1
+            || self
+                .jj
+                .count_revisions(&format!("({}) & mutable()", STACK_ROOT))
+                .ok()?
+                != 1
+        {
+            return None;
+        }
+        let (_, root) = self.jj.full_ids(STACK_ROOT).ok()?;
+        let (_, trunk) = self.jj.full_ids("trunk()").ok()?;
+        Some((root, trunk))
+    }
+
+    /// Suggest rebasing the stack if the fetch after `pre_op` moved trunk
+    pub(crate) fn suggest_stack_rebase(&mut self, pre_op: Option<&str>) {
+        let Some(pre_op) = pre_op else {
+            return;
+        };
+        let moved = match self.jj.count_revisions(&trunk_advance_revset(pre_op)) {
+            Ok(moved) if moved > 0 => moved,
+            _ => return,
+        };
+        if self.stack_rebase_target().is_some() {
+            self.rebase_suggestion = Some(moved);
+        }
+    }
+
+    /// Banner text while a stack rebase is suggested
+    pub(crate) fn rebase_suggestion_text(&self) -> Option<String> {
+        self.rebase_suggestion.map(|moved| {
+            format!(
+                "trunk moved by {} — rebase your stack? (R, Esc: dismiss)",
+                counted(moved, "commit")
+            )
+        })
+    }
+
+    /// Preview `jj rebase -s <stack root> -d trunk()` (`R` on the banner)
+    pub(crate) fn accept_rebase_suggestion(&mut self) {
+        self.rebase_suggestion = None;
+        match self.stack_rebase_target() {
+            Some((root, trunk)) => {
+                self.start_rebase(root, trunk, RebaseMode::Source, false, false, false)
+            }
+            None => self.notify_info("Nothing to rebase: the stack is already on trunk()"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trunk_advance_revset_uses_pre_fetch_operation() {
+        assert_eq!(
+            trunk_advance_revset("abc123"),
+            "at_operation(abc123, trunk())..trunk()"
+        );
+    }
+
+    #[test]
+    fn test_no_suggestion_without_pre_fetch_operation() {
+        let mut app = App::new_for_test();
+        app.suggest_stack_rebase(None);
+        assert!(app.rebase_suggestion.is_none());
+        assert!(app.rebase_suggestion_text().is_none());
+    }
+
+    #[test]
+    fn test_suggestion_text_counts_commits() {
+        let mut app = App::new_for_test();
+        app.rebase_suggestion = Some(3);
+        assert_eq!(
+            app.rebase_suggestion_text().as_deref(),
+            Some("trunk moved by 3 commits — rebase your stack? (R, Esc: dismiss)")
+        );
+    }
+
+    #[test]
+    fn test_accept_clears_stale_suggestion() {
+        let mut app = App::new_for_test();
+        app.rebase_suggestion = Some(2);
+        app.accept_rebase_suggestion();
+        assert!(app.rebase_suggestion.is_none());
+        assert!(app.active_dialog.is_none());
+    }
+}
@@ -0,0 +1,106 @@
+//! Status bar template: `tij.status-bar` segments next to the key hints
+//!
+//! The template is parsed once at startup (a broken one is reported and
+//! ignored). Values that cost a jj call are only fetched while the template
+//! shows them: the operation ID on each log refresh, ahead/behind together
+//! with the Log View sync badges.
+
+use crate::app::state::App;
+use crate::jj::constants::config_keys;
+use crate::keys;
+use crate::model::clock_time;
+use crate::ui::widgets::{StatusTemplate, StatusVar, StatusVars};
+
+impl App {
+    /// Parse `tij.status-bar`
+    pub(crate) fn load_status_template(&mut self) {
+        let Some(template) = self.jj.config_get(config_keys::STATUS_BAR).ok().flatten() else {
+            return;
+        };
+        match StatusTemplate::parse(template.trim_matches('"')) {
+            Ok(template) => self.status_template = Some(template),
+            Err(e) => self.notify_warning(format!("Ignoring tij.status-bar: {}", e)),
+        }
+    }
+
+    /// Whether the status bar template shows `var`
+    pub(crate) fn status_template_uses(&self, var: StatusVar) -> bool {
+        self.status_template
+            .as_ref()
+            .is_some_and(|template| template.uses(var))
+    }
+
+    /// Re-read the current operation ID if the template shows it
+    pub(crate) fn refresh_status_op_id(&mut self) {
+        if self.status_template_uses(StatusVar::OpId) {
+            self.status_op_id = self
+                .jj
+                .op_log(Some(1))
+                .ok()
+                .and_then(|ops| ops.into_iter().next())
+                .map(|op| op.id)
+                .unwrap_or_default();
+        }
+    }
+
+    /// The rendered status template, empty when none is configured
+    pub(crate) fn status_text(&self) -> String {
+        let Some(template) = &self.status_template else {
+            return String::new();
+        };
+        let changes = &self.log_view.changes;
+        let working_copy = changes.iter().find(|change| change.is_working_copy);
+        let bookmark = working_copy
+            .and_then(|change| change.bookmarks.first())
+            .cloned()
+            .unwrap_or_default();
+        let ahead_behind = self
+            .log_view
+            .bookmark_tracking
+            .get(&bookmark)
+            .map(|counts| counts.indicator())
+            .unwrap_or_default();
+        let view = keys::view_keymap(self.current_view)
+            .map_or("Help", |(title, _)| title.trim_end_matches(" View"));
+        let reference = working_copy
+            .or_else(|| changes.iter().find(|change| !change.is_graph_only))
+            .map(|change| change.timestamp.as_str());
+        template.render(&StatusVars {
+            view: view.to_string(),
+            revset: self.log_view.current_revset.clone().unwrap_or_default(),
+            bookmark,
+            change_id: working_copy
+                .map(|change| change.change_id.to_string())
+                .unwrap_or_default(),
+            op_id: self.status_op_id.clone(),
+            ahead_behind,
+            time: clock_time(reference, &self.timestamp_format),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Change;
+
+    #[test]
+    fn test_status_text_without_template_is_empty() {
+        let app = App::new_for_test();
+        assert_eq!(app.status_text(), "");
+    }
+
+    #[test]
+    fn test_status_text_uses_working_copy() {
+        let mut app = App::new_for_test();
+        app.status_template = StatusTemplate::parse("{view}[ · {revset}][ @{change_id}]").ok();
+        assert_eq!(app.status_text(), "Log");
+
+        app.log_view.set_changes(vec![Change {
+            change_id: "kxqyzprt".to_string().into(),
+            is_working_copy: true,
+            ..Default::default()
+        }]);
+        assert_eq!(app.status_text(), "Log @kxqyzprt");
+    }
+}
@@ -0,0 +1,226 @@
+//! Yank submenu: copy IDs, descriptions and `jj show` text to the clipboard
+//!
+//! Opened with `y` in Log, Evolog and Operation views. Cheap values are
+//! captured when the menu opens; the full description and `jj show` output
+//! are fetched only when chosen.
+
+use crate::app::clipboard;
+use crate::app::state::App;
+use crate::ui::components::{Dialog, DialogCallback, SelectItem};
+
+/// Menu value for the full (multi-line) description
+const YANK_DESCRIPTION: &str = "description";
+/// Menu value for the full `jj show` output
+const YANK_SHOW: &str = "show";
+
+/// Longest value preview shown in a menu label
+const PREVIEW_LEN: usize = 40;
+
+/// One-line preview of a value for the menu label
+fn preview(text: &str) -> String {
+    let first_line = text.lines().next().unwrap_or_default();
+    if first_line.chars().count() > PREVIEW_LEN {
+        let cut: String = first_line.chars().take(PREVIEW_LEN - 3).collect();
+        format!("{}...", cut)
+    } else {
+        first_line.to_string()
+    }
+}
+
+impl App {
+    /// Open the yank menu for the change selected in the Log View
+    pub(crate) fn start_yank_log(&mut self) {
+        let Some(change) = self.log_view.selected_change() else {
+            return;
+        };
+        let revision = change.commit_id.to_string();
+        let bookmarks = change.bookmarks.join(" ");
+        let (change_id, commit_id) = self
+            .jj
+            .full_ids(&revision)
+            .unwrap_or_else(|_| (change.change_id.to_string(), revision.clone()));
+
+        let mut entries = vec![
+            ("Change ID".to_string(), change_id),
+            ("Commit ID".to_string(), commit_id),
+        ];
+        // With the commit ID column on, that's the ID people cross-reference
+        if self.log_view.show_commit_ids {
+            entries.swap(0, 1);
+        }
+        if !bookmarks.is_empty() {
+            entries.push(("Bookmarks".to_string(), bookmarks));
+        }
+        self.open_yank_menu(entries, Some(revision));
+    }
+
+    /// Open the yank menu for the entry selected in the Evolog View
+    pub(crate) fn start_yank_evolog(&mut self) {
+        let Some(entry) = self
+            .evolog_view
+            .as_ref()
+            .and_then(|view| view.selected_entry())
+        else {
+            return;
+        };
+        let revision = entry.commit_id.to_string();
+        let (change_id, commit_id) = self
+            .jj
+            .full_ids(&revision)
+            .unwrap_or_else(|_| (entry.change_id.to_string(), revision.clone()));
+
+        let entries = vec![
+            ("Change ID".to_string(), change_id),
+            ("Commit ID".to_string(), commit_id),
+        ];
+        self.open_yank_menu(entries, Some(revision));
+    }
+
+    /// Open the yank menu for the operation selected in the Operation View
+    pub(crate) fn start_yank_operation(&mut self) {
+        let Some(op) = self.operation_view.selected_operation() else {
+            return;
+        };
+        let mut entries = vec![
+            ("Operation ID".to_string(), op.id.clone()),
+            ("Description".to_string(), op.description.clone()),
+        ];
+        if let Some(args) = op.args() {
+            entries.push(("Command".to_string(), args.to_string()));
+        }
+        self.open_yank_menu(entries, None);
+    }
+
+    /// Show the yank menu for `entries` (plus description/show for a revision)
+    fn open_yank_menu(&mut self, entries: Vec<(String, String)>, revision: Option<String>) {
+        let mut items: Vec<SelectItem> = entries
+            .iter()
+            .enumerate()
+            .map(|(idx, (what, text))| SelectItem {
+                label: format!("{:<13} {}", what, preview(text)),
+                value: idx.to_string(),
+                selected: false,
+            })
+            .collect();
+        if revision.is_some() {
+            items.push(SelectItem {
+                label: "Description   (full message)".to_string(),
+                value: YANK_DESCRIPTION.to_string(),
+                selected: false,
+            });
+            items.push(SelectItem {
+                label: "jj show       (full output)".to_string(),
+                value: YANK_SHOW.to_string(),
+                selected: false,
+            });
+        }
+
+        self.active_dialog = Some(Dialog::select_single(
+            "Yank",
+            "Copy to clipboard",
+            items,
+            None,
+            DialogCallback::Yank { entries, revision },
+        ));
+    }
+
+    /// Handle the chosen yank menu item
+    pub(crate) fn execute_yank(
+        &mut self,
+        entries: &[(String, String)],
+        revision: Option<&str>,
+        choice: &str,
+    ) {
+        let (what, text) = match (choice, revision) {
+            (YANK_DESCRIPTION, Some(revision)) => match self.jj.get_description(revision) {
+                Ok(description) => ("description", description.trim_end().to_string()),
+                Err(e) => {
+                    self.set_error(format!("Failed to get description: {}", e));
+                    return;
+                }
+            },
+            (YANK_SHOW, Some(revision)) => match self.jj.show_raw(revision) {
+                Ok(output) => ("jj show output", output),
+                Err(e) => {
+                    self.set_error(format!("Failed to run jj show: {}", e));
+                    return;
+                }
+            },
+            _ => match choice
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| entries.get(idx))
+            {
+                Some((what, text)) => (what.as_str(), text.clone()),
+                None => return,
+            },
+        };
+
+        if text.is_empty() {
+            self.notify_warning(format!("Nothing to copy: {} is empty", what));
+            return;
+        }
+        match clipboard::copy_to_clipboard(&text) {
+            Ok(()) => {
+                let line_count = text.lines().count();
+                if line_count > 1 {
+                    self.notify_success(format!("Copied {} ({} lines)", what, line_count));
+                } else {
+                    self.notify_success(format!("Copied {}: {}", what, preview(&text)));
+                }
+            }
+            Err(e) => self.set_error(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_truncates_first_line() {
+        assert_eq!(preview("short"), "short");
+        assert_eq!(preview("first\nsecond"), "first");
+        let long = "x".repeat(60);
+        let result = preview(&long);
+        assert_eq!(result.chars().count(), PREVIEW_LEN);
+        assert!(result.ends_with("..."));
+    }
+
+    #[test]
+    fn test_yank_operation_menu_lists_entries() {
+        let mut app = App::new_for_test();
+        app.operation_view
+            .set_operations(vec![crate::model::Operation {
+                id: "75ea3c2331bf".to_string(),
+                description: "describe commit".to_string(),
+                tags: vec!["args: jj describe -m x".to_string()],
+                ..Default::default()
+            }]);
+        app.start_yank_operation();
+
+        let dialog = app.active_dialog.as_ref().unwrap();
+        let DialogCallback::Yank { entries, revision } = &dialog.callback_id else {
+            panic!("expected yank dialog");
+        };
+        assert!(revision.is_none());
+        assert_eq!(
+            entries[0],
+            ("Operation ID".to_string(), "75ea3c2331bf".to_string())
+        );
+        assert_eq!(
+            entries[2],
+            ("Command".to_string(), "jj describe -m x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_yank_without_selection_does_nothing() {
+        let mut app = App::new_for_test();
+        app.start_yank_log();
+        app.start_yank_evolog();
+        app.start_yank_operation();
+        assert!(app.active_dialog.is_none());
+    }
+}
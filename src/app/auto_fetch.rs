@@ -0,0 +1,114 @@
+//! Periodic background `jj git fetch`
+//!
+//! Opt-in via `tij.auto-fetch-minutes = N` in jj config. The fetch runs on a
+//! worker thread so the UI stays responsive; its result is picked up on an
+//! idle tick and dispatched as `AppEvent::JobFinished`.
+
+use std::io;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::jj::{JjError, JjExecutor};
+
+/// Result channel of a fetch in flight
+type Pending = Receiver<Result<String, JjError>>;
+
+/// Fetch timer and the in-flight fetch (with its start time), if any
+#[derive(Debug)]
+pub(crate) struct AutoFetch {
+    interval: Duration,
+    next_run: Instant,
+    running: Option<(Instant, Pending)>,
+}
+
+impl AutoFetch {
+    /// First fetch happens one `interval` after `now`
+    pub fn new(interval: Duration, now: Instant) -> Self {
+        Self {
+            interval,
+            next_run: now + interval,
+            running: None,
+        }
+    }
+
+    /// Whether a new fetch should start (never while one is in flight)
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.running.is_none() && now >= self.next_run
+    }
+
+    /// Spawn `jj git fetch` on a worker thread and schedule the next run
+    pub fn start(&mut self, jj: &JjExecutor, now: Instant) {
+        let jj = jj.without_cancel_hook();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            // Receiver may be gone if the app quit mid-fetch
+            let _ = tx.send(jj.git_fetch());
+        });
+        self.running = Some((now, rx));
+        self.next_run = now + self.interval;
+    }
+
+    /// Collect the in-flight fetch once it has finished, with its start time
+    pub fn try_finish(&mut self) -> Option<(Instant, Result<String, JjError>)> {
+        let (started, rx) = self.running.as_ref()?;
+        let started = *started;
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err(JjError::IoError(io::Error::other(
+                "background fetch worker exited unexpectedly",
+            ))),
+        };
+        self.running = None;
+        Some((started, result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_due_before_interval() {
+        let now = Instant::now();
+        let fetch = AutoFetch::new(Duration::from_secs(300), now);
+        assert!(!fetch.is_due(now));
+        assert!(!fetch.is_due(now + Duration::from_secs(299)));
+        assert!(fetch.is_due(now + Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn test_not_due_while_running() {
+        let now = Instant::now();
+        let mut fetch = AutoFetch::new(Duration::from_secs(60), now);
+        let (_tx, rx) = mpsc::channel();
+        fetch.running = Some((now, rx));
+        assert!(!fetch.is_due(now + Duration::from_secs(120)));
+        assert!(fetch.try_finish().is_none());
+    }
+
+    #[test]
+    fn test_try_finish_collects_result() {
+        let now = Instant::now();
+        let mut fetch = AutoFetch::new(Duration::from_secs(60), now);
+        let (tx, rx) = mpsc::channel();
+        fetch.running = Some((now, rx));
+        tx.send(Ok("fetched".to_string())).unwrap();
+        let (started, result) = fetch.try_finish().unwrap();
+        assert_eq!(started, now);
+        assert_eq!(result.unwrap(), "fetched");
+        assert!(fetch.running.is_none());
+    }
+
+    #[test]
+    fn test_try_finish_reports_dead_worker() {
+        let now = Instant::now();
+        let mut fetch = AutoFetch::new(Duration::from_secs(60), now);
+        let (tx, rx) = mpsc::channel::<Result<String, JjError>>();
+        fetch.running = Some((now, rx));
+        drop(tx);
+        assert!(fetch.try_finish().unwrap().1.is_err());
+        assert!(fetch.running.is_none());
+    }
+}
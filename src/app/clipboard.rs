@@ -6,6 +6,16 @@
 use std::io::Write;
 use std::process::{Command, Stdio};
 
+use crate::ui::components::line_input;
+
+/// Clipboard commands in detection order (program first)
+const CLIPBOARD_COMMANDS: &[&[&str]] = &[
+    &["pbcopy"],
+    &["wl-copy"],
+    &["xclip", "-selection", "clipboard"],
+    &["xsel", "--clipboard", "--input"],
+];
+
 /// Copy text to system clipboard.
 ///
 /// Tries platform-specific commands in priority order:
@@ -13,15 +23,13 @@ use std::process::{Command, Stdio};
 /// 2. `wl-copy` (Wayland Linux)
 /// 3. `xclip -selection clipboard` (X11 Linux)
 /// 4. `xsel --clipboard --input` (X11 Linux fallback)
+///
+/// The text is also added to the input kill ring (Ctrl+Y in any prompt),
+/// even when no clipboard tool is found.
 pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
-    let commands: &[&[&str]] = &[
-        &["pbcopy"],
-        &["wl-copy"],
-        &["xclip", "-selection", "clipboard"],
-        &["xsel", "--clipboard", "--input"],
-    ];
+    line_input::remember_kill(text);
 
-    for cmd_args in commands {
+    for cmd_args in CLIPBOARD_COMMANDS {
         let program = cmd_args[0];
         if !is_available(program) {
             continue;
@@ -53,8 +61,16 @@ pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
     Err("No clipboard tool found (install pbcopy, xclip, or wl-copy)".to_string())
 }
 
+/// The clipboard tool `copy_to_clipboard` would try first, if any
+pub(crate) fn detect_tool() -> Option<&'static str> {
+    CLIPBOARD_COMMANDS
+        .iter()
+        .map(|cmd_args| cmd_args[0])
+        .find(|program| is_available(program))
+}
+
 /// Check if a command is available on the system
-fn is_available(program: &str) -> bool {
+pub(crate) fn is_available(program: &str) -> bool {
     Command::new("which")
         .arg(program)
         .stdout(Stdio::null())
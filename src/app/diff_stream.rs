@@ -0,0 +1,190 @@
+//! Streamed loading of large diffs into the Diff View
+//!
+//! `jj show` runs on a worker thread that parses its output as jj writes it
+//! and sends the parsed lines in pieces. The first piece opens the Diff View
+//! right away; the rest is appended on idle ticks while the context bar
+//! shows that loading is still going on. A diff that fits in one piece
+//! arrives finished, exactly like a plain `jj show`.
+
+use std::io;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+use crate::jj::parser::DiffStreamParser;
+use crate::jj::{JjError, JjExecutor};
+use crate::model::DiffContent;
+
+/// Output lines parsed before a piece is sent
+const CHUNK_LINES: usize = 2000;
+
+/// A piece of a streamed diff
+#[derive(Debug)]
+pub(crate) enum DiffPiece {
+    /// Header and the lines parsed so far; more follow
+    Partial(DiffContent),
+    /// The remaining lines, or why jj failed
+    Finished(Result<DiffContent, JjError>),
+}
+
+/// A `jj show` in flight for the Diff View
+#[derive(Debug)]
+pub(crate) struct DiffStream {
+    revision: String,
+    rx: Receiver<DiffPiece>,
+}
+
+impl DiffStream {
+    /// Spawn `jj show <revision>` on a worker thread
+    pub fn start(jj: &JjExecutor, revision: &str) -> Self {
+        let jj = jj.without_cancel_hook();
+        let (tx, rx) = mpsc::channel();
+        let worker_revision = revision.to_string();
+        thread::spawn(move || {
+            let mut parser = DiffStreamParser::show();
+            let mut parsed = 0;
+            let result = jj.show_streamed(&worker_revision, |line| {
+                parser.push_line(line);
+                parsed += 1;
+                if parsed < CHUNK_LINES {
+                    return true;
+                }
+                match parser.take() {
+                    Some(piece) => {
+                        parsed = 0;
+                        // Receiver gone: the diff was closed, stop jj
+                        tx.send(DiffPiece::Partial(piece)).is_ok()
+                    }
+                    None => true,
+                }
+            });
+            let _ = tx.send(DiffPiece::Finished(result.map(|()| parser.finish())));
+        });
+        Self {
+            revision: revision.to_string(),
+            rx,
+        }
+    }
+
+    /// Revision being loaded
+    pub fn revision(&self) -> &str {
+        &self.revision
+    }
+
+    /// Wait for the first piece
+    pub fn first(&self) -> DiffPiece {
+        self.rx.recv().unwrap_or_else(|_| Self::worker_gone())
+    }
+
+    /// The next piece, if one has arrived
+    pub fn try_next(&self) -> Option<DiffPiece> {
+        match self.rx.try_recv() {
+            Ok(piece) => Some(piece),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Self::worker_gone()),
+        }
+    }
+
+    fn worker_gone() -> DiffPiece {
+        DiffPiece::Finished(Err(JjError::IoError(io::Error::other(
+            "diff loading worker exited unexpectedly",
+        ))))
+    }
+}
+
+#[cfg(test)]
+impl DiffStream {
+    /// A stream fed by the returned sender instead of jj
+    pub fn for_test(revision: &str) -> (Self, mpsc::Sender<DiffPiece>) {
+        let (tx, rx) = mpsc::channel();
+        let stream = Self {
+            revision: revision.to_string(),
+            rx,
+        };
+        (stream, tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::model::DiffLine;
+    use crate::ui::views::DiffView;
+
+    #[test]
+    fn test_try_next_is_empty_until_a_piece_arrives() {
+        let (stream, tx) = DiffStream::for_test("abc");
+        assert!(stream.try_next().is_none());
+        tx.send(DiffPiece::Partial(DiffContent::default())).unwrap();
+        assert!(matches!(stream.try_next(), Some(DiffPiece::Partial(_))));
+    }
+
+    #[test]
+    fn test_dead_worker_finishes_with_error() {
+        let (stream, tx) = DiffStream::for_test("abc");
+        drop(tx);
+        assert!(matches!(
+            stream.try_next(),
+            Some(DiffPiece::Finished(Err(_)))
+        ));
+        assert!(matches!(stream.first(), DiffPiece::Finished(Err(_))));
+    }
+
+    fn piece(path: &str) -> DiffContent {
+        DiffContent {
+            lines: vec![DiffLine::file_header(path), DiffLine::added(1, "x")],
+            ..Default::default()
+        }
+    }
+
+    fn app_streaming(revision: &str) -> (App, mpsc::Sender<DiffPiece>) {
+        let mut app = App::new_for_test();
+        let (stream, tx) = DiffStream::for_test(revision);
+        let mut view = DiffView::new(revision.to_string(), piece("a.rs"));
+        view.loading = true;
+        app.diff_view = Some(view);
+        app.diff_stream = Some(stream);
+        (app, tx)
+    }
+
+    #[test]
+    fn test_poll_appends_pieces_until_finished() {
+        let (mut app, tx) = app_streaming("abc");
+        tx.send(DiffPiece::Partial(piece("b.rs"))).unwrap();
+        app.poll_diff_stream();
+        let view = app.diff_view.as_ref().unwrap();
+        assert_eq!(view.file_names, ["a.rs", "b.rs"]);
+        assert!(view.loading);
+        assert!(app.diff_stream.is_some());
+
+        tx.send(DiffPiece::Finished(Ok(piece("c.rs")))).unwrap();
+        app.poll_diff_stream();
+        let view = app.diff_view.as_ref().unwrap();
+        assert_eq!(view.file_count(), 3);
+        assert!(!view.loading);
+        assert!(app.diff_stream.is_none());
+    }
+
+    #[test]
+    fn test_poll_drops_stream_once_content_is_replaced() {
+        let (mut app, tx) = app_streaming("abc");
+        app.diff_view
+            .as_mut()
+            .unwrap()
+            .set_content("abc".to_string(), piece("z.rs"));
+        tx.send(DiffPiece::Partial(piece("b.rs"))).unwrap();
+        app.poll_diff_stream();
+        assert!(app.diff_stream.is_none());
+        assert_eq!(app.diff_view.as_ref().unwrap().file_names, ["z.rs"]);
+    }
+
+    #[test]
+    fn test_poll_reports_failure_after_partial_output() {
+        let (mut app, tx) = app_streaming("abc");
+        tx.send(DiffPiece::Finished(Err(JjError::JjNotFound)))
+            .unwrap();
+        app.poll_diff_stream();
+        assert!(!app.diff_view.as_ref().unwrap().loading);
+        assert!(app.error_message.is_some());
+    }
+}
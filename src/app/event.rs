@@ -0,0 +1,246 @@
+//! App-level events
+//!
+//! Everything the main loop feeds into [`App`] goes through [`AppEvent`], so
+//! handling does not depend on crossterm directly. Terminal input is
+//! converted with [`AppEvent::from_terminal`]; background sources (file
+//! watcher, worker threads) are collected on each [`AppEvent::Tick`] and
+//! dispatched as their own events.
+
+use std::collections::HashSet;
+use std::sync::mpsc::TryRecvError;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use super::state::App;
+use crate::jj::JjError;
+use crate::model::BookmarkRetarget;
+
+/// Event handled by [`App::handle_event`]
+#[derive(Debug)]
+pub enum AppEvent {
+    /// Key press
+    Key(KeyEvent),
+    /// Idle tick (no terminal input within the poll timeout)
+    Tick,
+    /// A background job finished
+    JobFinished(JobOutcome),
+    /// The working copy changed on disk (debounced)
+    FsChanged,
+    /// Terminal resized to (columns, rows)
+    Resize(u16, u16),
+}
+
+/// Branches with an open pull/merge request and the forge's badge label
+pub type OpenRequestsResult = Result<(HashSet<String>, &'static str), String>;
+
+/// Bookmark, request label ("PR"/"MR") and the created request's URL
+pub type CreateRequestResult = (String, &'static str, Result<String, String>);
+
+/// Result of a background job
+#[derive(Debug)]
+pub enum JobOutcome {
+    /// Periodic `jj git fetch`, with the time it started
+    AutoFetch {
+        started: Instant,
+        result: Result<String, JjError>,
+    },
+    /// `gh`/`glab` listing of open requests
+    OpenRequests { result: OpenRequestsResult },
+    /// `gh pr create`/`glab mr create`
+    CreateRequest { result: CreateRequestResult },
+    /// Bookmarks found on old versions of rewritten changes
+    BookmarkRetargets { retargets: Vec<BookmarkRetarget> },
+}
+
+impl AppEvent {
+    /// Convert a terminal event; `None` for events tij doesn't handle
+    ///
+    /// Only key presses are kept (release/repeat are reported by some
+    /// terminals and would double every action).
+    pub fn from_terminal(event: Event) -> Option<Self> {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => Some(Self::Key(key)),
+            Event::Resize(cols, rows) => Some(Self::Resize(cols, rows)),
+            _ => None,
+        }
+    }
+}
+
+/// Whether Esc or Ctrl+C was pressed (polled while a fetch/push runs)
+///
+/// Other keys typed meanwhile are dropped rather than replayed against a
+/// screen the user hasn't seen yet.
+pub(crate) fn cancel_requested() -> bool {
+    while event::poll(Duration::ZERO).unwrap_or(false) {
+        if let Ok(Event::Key(key)) = event::read()
+            && key.kind == KeyEventKind::Press
+            && (key.code == KeyCode::Esc
+                || (key.modifiers.contains(KeyModifiers::CONTROL)
+                    && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'))))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+impl App {
+    /// Handle one event from the main loop
+    pub fn handle_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::Key(key) => {
+                self.on_key_event(key);
+                self.finish_cancelled_network();
+            }
+            AppEvent::Tick => self.on_tick(),
+            AppEvent::JobFinished(JobOutcome::AutoFetch { started, result }) => {
+                self.on_auto_fetch_finished(started, result);
+            }
+            // Not logged in, no network, ...: simply no badges
+            AppEvent::JobFinished(JobOutcome::OpenRequests { result }) => {
+                if let Ok((branches, label)) = result {
+                    self.bookmark_view.set_open_requests(branches, label);
+                }
+            }
+            AppEvent::JobFinished(JobOutcome::CreateRequest { result }) => {
+                self.on_create_request_finished(result);
+            }
+            AppEvent::JobFinished(JobOutcome::BookmarkRetargets { retargets }) => {
+                self.on_bookmark_retargets_found(retargets);
+            }
+            AppEvent::FsChanged => self.on_fs_changed(),
+            // The next draw picks up the new size
+            AppEvent::Resize(..) => {}
+        }
+    }
+
+    /// Idle processing: resolve any pending preview fetch and the visible
+    /// diff stats, poll the op log in follow mode, start a due background
+    /// fetch, then dispatch whatever the background sources produced
+    fn on_tick(&mut self) {
+        self.resolve_pending_preview();
+        self.resolve_visible_diff_stats();
+        self.poll_diff_stream();
+        self.poll_operation_follow();
+        self.start_auto_fetch_if_due();
+        for event in self.collect_background_events() {
+            self.handle_event(event);
+        }
+    }
+
+    /// Drain the file watcher and finished background jobs
+    ///
+    /// Nothing is collected while a dialog is open, so the data behind it
+    /// doesn't shift; the sources keep their state until the next tick.
+    fn collect_background_events(&mut self) -> Vec<AppEvent> {
+        let mut events = Vec::new();
+        if self.active_dialog.is_some() {
+            return events;
+        }
+        let now = Instant::now();
+        if let Some(watcher) = self.fs_watcher.as_mut()
+            && watcher.poll(now)
+        {
+            events.push(AppEvent::FsChanged);
+        }
+        if let Some(auto_fetch) = self.auto_fetch.as_mut()
+            && let Some((started, result)) = auto_fetch.try_finish()
+        {
+            events.push(AppEvent::JobFinished(JobOutcome::AutoFetch {
+                started,
+                result,
+            }));
+        }
+        if let Some(rx) = self.open_request_query.as_ref() {
+            let result = match rx.try_recv() {
+                Ok(result) => Some(result),
+                Err(TryRecvError::Empty) => None,
+                Err(TryRecvError::Disconnected) => Some(Err("worker exited".to_string())),
+            };
+            if let Some(result) = result {
+                self.open_request_query = None;
+                events.push(AppEvent::JobFinished(JobOutcome::OpenRequests { result }));
+            }
+        }
+        if let Some(rx) = self.create_request_job.as_ref() {
+            match rx.try_recv() {
+                Ok(result) => {
+                    self.create_request_job = None;
+                    events.push(AppEvent::JobFinished(JobOutcome::CreateRequest { result }));
+                }
+                Err(TryRecvError::Empty) => {}
+                // The worker always sends; nothing to report without its bookmark
+                Err(TryRecvError::Disconnected) => self.create_request_job = None,
+            }
+        }
+        if let Some(rx) = self.bookmark_retarget_job.as_ref() {
+            let retargets = match rx.try_recv() {
+                Ok(retargets) => Some(retargets),
+                Err(TryRecvError::Empty) => None,
+                Err(TryRecvError::Disconnected) => Some(Vec::new()),
+            };
+            if let Some(retargets) = retargets {
+                self.bookmark_retarget_job = None;
+                events.push(AppEvent::JobFinished(JobOutcome::BookmarkRetargets {
+                    retargets,
+                }));
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::View;
+    use crossterm::event::{KeyCode, KeyEventState, KeyModifiers};
+
+    #[test]
+    fn test_from_terminal_keeps_presses_and_resizes() {
+        let press = KeyEvent::from(KeyCode::Char('j'));
+        assert!(matches!(
+            AppEvent::from_terminal(Event::Key(press)),
+            Some(AppEvent::Key(k)) if k.code == KeyCode::Char('j')
+        ));
+        let release = KeyEvent {
+            code: KeyCode::Char('j'),
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Release,
+            state: KeyEventState::NONE,
+        };
+        assert!(AppEvent::from_terminal(Event::Key(release)).is_none());
+        assert!(matches!(
+            AppEvent::from_terminal(Event::Resize(120, 40)),
+            Some(AppEvent::Resize(120, 40))
+        ));
+        assert!(AppEvent::from_terminal(Event::FocusGained).is_none());
+    }
+
+    #[test]
+    fn test_key_event_is_routed_to_key_handling() {
+        let mut app = App::new_for_test();
+        app.handle_event(AppEvent::Key(KeyEvent::from(KeyCode::Char('?'))));
+        assert_eq!(app.current_view, View::Help);
+    }
+
+    #[test]
+    fn test_failed_auto_fetch_shows_warning() {
+        let mut app = App::new_for_test();
+        app.handle_event(AppEvent::JobFinished(JobOutcome::AutoFetch {
+            started: Instant::now(),
+            result: Err(JjError::IoError(std::io::Error::other("offline"))),
+        }));
+        let notification = app.notification.as_ref().unwrap();
+        assert!(notification.message.contains("Background fetch failed"));
+    }
+
+    #[test]
+    fn test_tick_without_background_sources_is_noop() {
+        let mut app = App::new_for_test();
+        app.handle_event(AppEvent::Tick);
+        assert!(app.notification.is_none());
+        assert!(app.running);
+    }
+}
@@ -0,0 +1,429 @@
+//! GitHub/GitLab/Bitbucket integration
+//!
+//! The forge and repository are derived from the remote URL, so opening
+//! commits, files or pull/merge request pages needs no setup. Creating
+//! requests and listing open ones goes through the `gh`/`glab` CLI when it is
+//! installed.
+
+use std::collections::HashSet;
+use std::process::{Command, Stdio};
+
+use super::clipboard::is_available;
+
+/// Most open requests listed for the badges
+const LIST_LIMIT: &str = "500";
+
+/// Supported forges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ForgeKind {
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+impl ForgeKind {
+    /// CLI used to create and list requests, if the forge has one
+    pub fn cli(self) -> Option<&'static str> {
+        match self {
+            Self::GitHub => Some("gh"),
+            Self::GitLab => Some("glab"),
+            Self::Bitbucket => None,
+        }
+    }
+
+    /// What the forge calls a request ("PR"/"MR")
+    pub fn request_label(self) -> &'static str {
+        match self {
+            Self::GitHub | Self::Bitbucket => "PR",
+            Self::GitLab => "MR",
+        }
+    }
+}
+
+/// Host and repository path (without `.git`) of a git remote URL
+///
+/// Accepts `https://host/path(.git)`, `ssh://[user@]host[:port]/path` and
+/// scp-like `user@host:path`.
+pub(crate) fn parse_remote_url(url: &str) -> Option<(String, String)> {
+    let url = url.trim();
+    let (host, path) = if let Some((_, rest)) = url.split_once("://") {
+        let rest = rest.rsplit_once('@').map_or(rest, |(_, r)| r);
+        let (host, path) = rest.split_once('/')?;
+        // Drop the port of `ssh://host:22/...`
+        (host.split(':').next().unwrap_or(host), path)
+    } else {
+        let rest = url.rsplit_once('@').map_or(url, |(_, r)| r);
+        rest.split_once(':')?
+    };
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    if host.is_empty() || path.is_empty() {
+        return None;
+    }
+    Some((host.to_string(), path.to_string()))
+}
+
+/// Fill a `tij.browse-*-url` template
+///
+/// Placeholders: `{host}`, `{repo}`, `{commit}`, `{path}` and `{line}`
+/// (empty for whole-file links).
+pub(crate) fn expand_url_template(
+    template: &str,
+    remote_url: &str,
+    commit: &str,
+    path: &str,
+    line: Option<usize>,
+) -> Option<String> {
+    let (host, repo) = parse_remote_url(remote_url)?;
+    Some(
+        template
+            .replace("{host}", &host)
+            .replace("{repo}", &repo)
+            .replace("{commit}", commit)
+            .replace("{path}", path)
+            .replace("{line}", &line.map(|l| l.to_string()).unwrap_or_default()),
+    )
+}
+
+/// Repository on a forge, e.g. `https://github.com/user/repo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ForgeRepo {
+    pub kind: ForgeKind,
+    /// Repository page without trailing slash or `.git`
+    pub web_url: String,
+}
+
+impl ForgeRepo {
+    /// Derive the forge from a git remote URL (see [`parse_remote_url`])
+    ///
+    /// The forge is recognized by its host name.
+    pub fn from_remote_url(url: &str) -> Option<Self> {
+        let (host, path) = parse_remote_url(url)?;
+        let kind = if host.contains("github") {
+            ForgeKind::GitHub
+        } else if host.contains("gitlab") {
+            ForgeKind::GitLab
+        } else if host.contains("bitbucket") {
+            ForgeKind::Bitbucket
+        } else {
+            return None;
+        };
+        Some(Self {
+            kind,
+            web_url: format!("https://{}/{}", host, path),
+        })
+    }
+
+    /// Page of a commit (full commit id)
+    pub fn commit_url(&self, commit: &str) -> String {
+        match self.kind {
+            ForgeKind::GitHub => format!("{}/commit/{}", self.web_url, commit),
+            ForgeKind::GitLab => format!("{}/-/commit/{}", self.web_url, commit),
+            ForgeKind::Bitbucket => format!("{}/commits/{}", self.web_url, commit),
+        }
+    }
+
+    /// Page of a file at a commit, optionally scrolled to a 1-based line
+    pub fn file_url(&self, commit: &str, path: &str, line: Option<usize>) -> String {
+        let (prefix, anchor) = match self.kind {
+            ForgeKind::GitHub => ("blob", "#L"),
+            ForgeKind::GitLab => ("-/blob", "#L"),
+            ForgeKind::Bitbucket => ("src", "#lines-"),
+        };
+        let mut url = format!("{}/{}/{}/{}", self.web_url, prefix, commit, path);
+        if let Some(line) = line {
+            url.push_str(&format!("{}{}", anchor, line));
+        }
+        url
+    }
+
+    /// Page that opens (or links to) the request for `bookmark`
+    pub fn request_url(&self, bookmark: &str) -> String {
+        match self.kind {
+            ForgeKind::GitHub => {
+                format!("{}/pull/new/{}", self.web_url, encode_path(bookmark))
+            }
+            ForgeKind::GitLab => format!(
+                "{}/-/merge_requests/new?merge_request%5Bsource_branch%5D={}",
+                self.web_url,
+                encode_query_value(bookmark)
+            ),
+            ForgeKind::Bitbucket => format!(
+                "{}/pull-requests/new?source={}",
+                self.web_url,
+                encode_query_value(bookmark)
+            ),
+        }
+    }
+
+    /// CLI arguments selecting this repository
+    ///
+    /// The CLIs otherwise pick the repository from the git remotes of the
+    /// cwd, which a jj repo without a colocated `.git` doesn't have.
+    fn repo_args(&self) -> Vec<String> {
+        match self.kind {
+            // `[HOST/]OWNER/REPO`
+            ForgeKind::GitHub => vec![
+                "--repo".to_string(),
+                self.web_url
+                    .strip_prefix("https://")
+                    .unwrap_or(&self.web_url)
+                    .to_string(),
+            ],
+            // glab takes the full URL (a bare path would be read as a group)
+            ForgeKind::GitLab => vec!["-R".to_string(), self.web_url.clone()],
+            ForgeKind::Bitbucket => Vec::new(),
+        }
+    }
+
+    /// CLI arguments creating a request from `bookmark` (title/body from commits)
+    pub fn create_args(&self, bookmark: &str) -> Vec<String> {
+        let args: &[&str] = match self.kind {
+            ForgeKind::GitHub => &["pr", "create", "--fill", "--head"],
+            ForgeKind::GitLab => &["mr", "create", "--fill", "--yes", "--source-branch"],
+            ForgeKind::Bitbucket => &[],
+        };
+        args.iter()
+            .map(|a| a.to_string())
+            .chain([bookmark.to_string()])
+            .chain(self.repo_args())
+            .collect()
+    }
+
+    /// CLI arguments listing open requests
+    fn list_args(&self) -> Vec<String> {
+        let args: &[&str] = match self.kind {
+            ForgeKind::GitHub => &[
+                "pr",
+                "list",
+                "--state",
+                "open",
+                // gh stops at 30 by default
+                "--limit",
+                LIST_LIMIT,
+                "--json",
+                "headRefName",
+                "--jq",
+                ".[].headRefName",
+            ],
+            ForgeKind::GitLab => &["mr", "list", "--per-page", LIST_LIMIT, "--output", "json"],
+            ForgeKind::Bitbucket => &[],
+        };
+        args.iter()
+            .map(|a| a.to_string())
+            .chain(self.repo_args())
+            .collect()
+    }
+
+    /// The forge CLI, if it is installed
+    pub fn installed_cli(&self) -> Option<&'static str> {
+        self.kind.cli().filter(|cli| is_available(cli))
+    }
+
+    /// Whether the forge CLI is installed
+    pub fn has_cli(&self) -> bool {
+        self.installed_cli().is_some()
+    }
+
+    /// Create a request with the CLI; returns its last output line (the URL)
+    pub fn create_request(&self, bookmark: &str) -> Result<String, String> {
+        let cli = self.kind.cli().ok_or("no CLI for this forge")?;
+        let output = run_cli(cli, &self.create_args(bookmark))?;
+        Ok(output.lines().last().unwrap_or_default().trim().to_string())
+    }
+
+    /// Source branches of open requests
+    pub fn open_request_branches(&self) -> Result<HashSet<String>, String> {
+        let cli = self.kind.cli().ok_or("no CLI for this forge")?;
+        let output = run_cli(cli, &self.list_args())?;
+        Ok(parse_open_branches(self.kind, &output))
+    }
+}
+
+/// Branch names from the list output: one per line (`gh --jq`) or the
+/// `"source_branch"` fields of glab's JSON
+fn parse_open_branches(kind: ForgeKind, output: &str) -> HashSet<String> {
+    match kind {
+        ForgeKind::GitHub => output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect(),
+        ForgeKind::GitLab => output
+            .split("\"source_branch\":")
+            .skip(1)
+            .filter_map(|rest| {
+                let value = rest.trim_start().strip_prefix('"')?;
+                value.split_once('"').map(|(name, _)| name.to_string())
+            })
+            .collect(),
+        ForgeKind::Bitbucket => HashSet::new(),
+    }
+}
+
+/// Escape the characters that would end a query parameter value
+fn encode_query_value(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('&', "%26")
+        .replace('#', "%23")
+}
+
+/// Percent-encode a URL path, keeping `/` between its segments
+fn encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn run_cli<S: AsRef<std::ffi::OsStr>>(program: &str, args: &[S]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to start {}: {}", program, e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(stderr.lines().next().unwrap_or("failed").trim().to_string())
+    }
+}
+
+/// Open `url` in the default browser
+pub(crate) fn open_in_browser(url: &str) -> Result<(), String> {
+    let (program, args): (&str, Vec<&str>) = if cfg!(target_os = "macos") {
+        ("open", vec![url])
+    } else if cfg!(target_os = "windows") {
+        ("cmd", vec!["/C", "start", "", url])
+    } else {
+        ("xdg-open", vec![url])
+    };
+    Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to start {}: {}", program, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_remote_url_variants() {
+        for url in [
+            "git@github.com:user/repo.git",
+            "https://github.com/user/repo.git",
+            "https://token@github.com/user/repo/",
+            "ssh://git@github.com:22/user/repo.git",
+        ] {
+            let repo = ForgeRepo::from_remote_url(url).unwrap();
+            assert_eq!(repo.kind, ForgeKind::GitHub, "{}", url);
+            assert_eq!(repo.web_url, "https://github.com/user/repo", "{}", url);
+        }
+
+        let gitlab = ForgeRepo::from_remote_url("git@gitlab.com:group/sub/repo.git").unwrap();
+        assert_eq!(gitlab.kind, ForgeKind::GitLab);
+        assert_eq!(gitlab.web_url, "https://gitlab.com/group/sub/repo");
+
+        assert!(ForgeRepo::from_remote_url("https://example.com/user/repo.git").is_none());
+        assert!(ForgeRepo::from_remote_url("/srv/git/repo.git").is_none());
+    }
+
+    #[test]
+    fn test_request_url() {
+        let github = ForgeRepo::from_remote_url("git@github.com:user/repo.git").unwrap();
+        assert_eq!(
+            github.request_url("feature/x"),
+            "https://github.com/user/repo/pull/new/feature/x"
+        );
+        assert_eq!(
+            github.request_url("fix#12 ü?"),
+            "https://github.com/user/repo/pull/new/fix%2312%20%C3%BC%3F"
+        );
+        let gitlab = ForgeRepo::from_remote_url("https://gitlab.com/group/repo").unwrap();
+        assert_eq!(
+            gitlab.request_url("fix"),
+            "https://gitlab.com/group/repo/-/merge_requests/new?merge_request%5Bsource_branch%5D=fix"
+        );
+    }
+
+    #[test]
+    fn test_commit_and_file_urls() {
+        let github = ForgeRepo::from_remote_url("git@github.com:user/repo.git").unwrap();
+        assert_eq!(
+            github.commit_url("abc123"),
+            "https://github.com/user/repo/commit/abc123"
+        );
+        assert_eq!(
+            github.file_url("abc123", "src/main.rs", Some(42)),
+            "https://github.com/user/repo/blob/abc123/src/main.rs#L42"
+        );
+        let bitbucket = ForgeRepo::from_remote_url("git@bitbucket.org:team/repo.git").unwrap();
+        assert_eq!(bitbucket.kind, ForgeKind::Bitbucket);
+        assert_eq!(
+            bitbucket.file_url("abc123", "README.md", Some(3)),
+            "https://bitbucket.org/team/repo/src/abc123/README.md#lines-3"
+        );
+        let gitlab = ForgeRepo::from_remote_url("https://gitlab.com/group/repo").unwrap();
+        assert_eq!(
+            gitlab.file_url("abc123", "a.txt", None),
+            "https://gitlab.com/group/repo/-/blob/abc123/a.txt"
+        );
+    }
+
+    #[test]
+    fn test_expand_url_template() {
+        assert_eq!(
+            expand_url_template(
+                "https://{host}/{repo}/src/{commit}/{path}?line={line}",
+                "git@git.example.com:team/repo.git",
+                "abc123",
+                "lib.rs",
+                Some(7),
+            )
+            .as_deref(),
+            Some("https://git.example.com/team/repo/src/abc123/lib.rs?line=7")
+        );
+    }
+
+    #[test]
+    fn test_cli_args_select_the_remote_repository() {
+        let github = ForgeRepo::from_remote_url("git@github.com:user/repo.git").unwrap();
+        let create = github.create_args("feature");
+        assert!(create.ends_with(&["--repo".to_string(), "github.com/user/repo".to_string()]));
+        let list = github.list_args();
+        assert!(list.windows(2).any(|w| w == ["--limit", LIST_LIMIT]));
+        assert!(list.ends_with(&["--repo".to_string(), "github.com/user/repo".to_string()]));
+
+        let gitlab = ForgeRepo::from_remote_url("git@gitlab.com:group/sub/repo.git").unwrap();
+        assert!(gitlab.list_args().ends_with(&[
+            "-R".to_string(),
+            "https://gitlab.com/group/sub/repo".to_string()
+        ]));
+    }
+
+    #[test]
+    fn test_parse_open_branches() {
+        let github = parse_open_branches(ForgeKind::GitHub, "feature\nfix\n\n");
+        assert_eq!(github.len(), 2);
+        assert!(github.contains("feature"));
+
+        let json = r#"[{"iid":1,"source_branch":"feature","target_branch":"main"},
+                       {"iid":2,"source_branch": "docs"}]"#;
+        let gitlab = parse_open_branches(ForgeKind::GitLab, json);
+        assert_eq!(gitlab.len(), 2);
+        assert!(gitlab.contains("docs"));
+    }
+}
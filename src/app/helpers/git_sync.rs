@@ -0,0 +1,85 @@
+//! Colocated git repository awareness
+//!
+//! In a colocated workspace `.git` sits next to `.jj` and git tools see the
+//! same refs. jj records the git side of each bookmark as `<name>@git`, so a
+//! bookmark is out of sync when its `@git` ref is missing (not exported),
+//! exists without a local bookmark (not imported) or points elsewhere.
+
+use std::path::Path;
+
+use crate::model::Bookmark;
+
+/// Pseudo-remote jj uses for the git repository's own refs
+const GIT_REMOTE: &str = "git";
+
+/// Whether the workspace at `root` is colocated with a git working tree
+pub fn is_colocated(root: &Path) -> bool {
+    root.join(".git").exists()
+}
+
+/// Names of bookmarks whose jj and git sides differ, sorted
+pub fn git_ref_divergence<'a>(bookmarks: impl IntoIterator<Item = &'a Bookmark>) -> Vec<String> {
+    let bookmarks: Vec<&Bookmark> = bookmarks.into_iter().collect();
+    let git_ref = |name: &str| {
+        bookmarks
+            .iter()
+            .find(|b| b.name == name && b.remote.as_deref() == Some(GIT_REMOTE))
+    };
+    let has_local = |name: &str| {
+        bookmarks
+            .iter()
+            .any(|b| b.name == name && b.remote.is_none())
+    };
+
+    let mut diverged: Vec<String> = bookmarks
+        .iter()
+        .filter(|b| match b.remote.as_deref() {
+            None => git_ref(&b.name)
+                .is_none_or(|g| g.tracking.is_some_and(|t| t.ahead > 0 || t.behind > 0)),
+            Some(GIT_REMOTE) => !has_local(&b.name),
+            Some(_) => false,
+        })
+        .map(|b| b.name.clone())
+        .collect();
+    diverged.sort();
+    diverged.dedup();
+    diverged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TrackingCounts;
+
+    fn bookmark(name: &str, remote: Option<&str>, tracking: Option<(usize, usize)>) -> Bookmark {
+        Bookmark {
+            name: name.to_string(),
+            remote: remote.map(str::to_string),
+            is_tracked: remote.is_some(),
+            tracking: tracking.map(|(ahead, behind)| TrackingCounts { ahead, behind }),
+        }
+    }
+
+    #[test]
+    fn test_git_ref_divergence() {
+        let bookmarks = vec![
+            // In sync
+            bookmark("main", None, None),
+            bookmark("main", Some("git"), Some((0, 0))),
+            bookmark("main", Some("origin"), Some((2, 0))),
+            // Local moved, not exported
+            bookmark("feature", None, None),
+            bookmark("feature", Some("git"), Some((1, 0))),
+            // Never exported
+            bookmark("draft", None, None),
+            // Git branch jj hasn't imported
+            bookmark("hotfix", Some("git"), None),
+            // Other remotes don't count
+            bookmark("release", Some("origin"), None),
+        ];
+        assert_eq!(
+            git_ref_divergence(&bookmarks),
+            ["draft", "feature", "hotfix"]
+        );
+    }
+}
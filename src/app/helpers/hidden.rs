@@ -0,0 +1,95 @@
+//! Hidden commits in the Log View
+//!
+//! jj's `all()` only covers visible commits, so hidden ones (abandoned or
+//! rewritten) are found by evaluating `mutable()` at recent operations and
+//! dropping what is still visible. Hidden rows share their change ID with
+//! the visible successor (or with nothing), so actions on them are limited
+//! to ones that can address the commit ID.
+
+use crate::ui::views::LogAction;
+
+/// `revset` plus the hidden commits of the given operations
+pub fn with_hidden_commits(revset: &str, op_ids: &[String]) -> String {
+    if op_ids.is_empty() {
+        return revset.to_string();
+    }
+    let past: Vec<String> = op_ids
+        .iter()
+        .map(|op| format!("at_operation({}, mutable())", op))
+        .collect();
+    format!("({}) | (({}) ~ all())", revset, past.join(" | "))
+}
+
+/// The action to run when a hidden row is selected, or `None` to refuse it
+///
+/// Diff and evolog are redirected to the commit ID; actions that don't
+/// target the selected revision pass through.
+pub fn hidden_row_action(action: LogAction, commit_id: &str) -> Option<LogAction> {
+    match action {
+        LogAction::OpenDiff(_) => Some(LogAction::OpenDiff(commit_id.to_string())),
+        LogAction::OpenEvolog(_) => Some(LogAction::OpenEvolog(commit_id.to_string())),
+        LogAction::None
+        | LogAction::Duplicate(_)
+        | LogAction::CherryPick(_)
+        | LogAction::PickRebaseDestination { .. }
+        | LogAction::RestoreHidden(_)
+        | LogAction::ExecuteRevset(_)
+        | LogAction::ClearRevset
+        | LogAction::OpenBookmarkView
+        | LogAction::OpenTagView
+        | LogAction::OpenWorkspaceView
+        | LogAction::OpenRemoteView
+        | LogAction::OpenStackView
+        | LogAction::OpenCommandHistory
+        | LogAction::Fetch
+        | LogAction::NewChange
+        | LogAction::ToggleReversed
+        | LogAction::ToggleElided
+        | LogAction::ToggleHidden
+        | LogAction::ToggleCenterCursor
+        | LogAction::ToggleCommitIds
+        | LogAction::CycleRevsetPreset
+        | LogAction::OpenRevsetPresets
+        | LogAction::OpenCompareSessions
+        | LogAction::StartJjCommand
+        | LogAction::ReportParseIssue
+        | LogAction::JumpToPin(_)
+        | LogAction::StartYank => Some(action),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_hidden_commits() {
+        let ops = vec!["aaa".to_string(), "bbb".to_string()];
+        assert_eq!(
+            with_hidden_commits("trunk()..@", &ops),
+            "(trunk()..@) | ((at_operation(aaa, mutable()) | at_operation(bbb, mutable())) ~ all())"
+        );
+        assert_eq!(with_hidden_commits("@", &[]), "@");
+    }
+
+    #[test]
+    fn test_hidden_row_action_redirects_or_refuses() {
+        assert_eq!(
+            hidden_row_action(LogAction::OpenDiff("kxyz".to_string()), "abc123"),
+            Some(LogAction::OpenDiff("abc123".to_string()))
+        );
+        assert_eq!(
+            hidden_row_action(LogAction::Duplicate("abc123".to_string()), "abc123"),
+            Some(LogAction::Duplicate("abc123".to_string()))
+        );
+        assert_eq!(
+            hidden_row_action(LogAction::Abandon("kxyz".to_string()), "abc123"),
+            None
+        );
+        assert_eq!(
+            hidden_row_action(LogAction::Edit("kxyz".to_string()), "abc123"),
+            None
+        );
+    }
+}
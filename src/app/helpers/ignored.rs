@@ -0,0 +1,127 @@
+//! Ignored path discovery for the Status View
+//!
+//! jj doesn't list ignored files, so they are found by walking the workspace
+//! and dropping everything `jj file list` (tracked) or `jj status`
+//! (untracked) knows about. Directories without any known path are reported
+//! once as `dir/` instead of descending into them (e.g. `target/`).
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Top-level directories that belong to the VCS, never reported
+const VCS_DIRS: &[&str] = &[".jj", ".git"];
+
+/// Ignored paths under `root`, sorted; directories end with `/`
+///
+/// `tracked` and `untracked` are workspace-relative paths; untracked
+/// directories may end with `/`.
+pub fn ignored_paths(root: &Path, tracked: &[String], untracked: &[String]) -> Vec<String> {
+    let mut known: HashSet<&str> = HashSet::new();
+    let mut untracked_dirs: HashSet<&str> = HashSet::new();
+    for path in tracked.iter().chain(untracked) {
+        match path.strip_suffix('/') {
+            Some(dir) => untracked_dirs.insert(dir),
+            None => known.insert(path.as_str()),
+        };
+    }
+
+    // Every directory on the way to a known path must be walked
+    let mut parents: HashSet<&str> = HashSet::new();
+    for path in known.iter().chain(untracked_dirs.iter()) {
+        let mut current = *path;
+        while let Some((parent, _)) = current.rsplit_once('/') {
+            if !parents.insert(parent) {
+                break;
+            }
+            current = parent;
+        }
+    }
+
+    let sets = KnownPaths {
+        files: &known,
+        untracked_dirs: &untracked_dirs,
+        parents: &parents,
+    };
+    let mut ignored = Vec::new();
+    walk(root, "", &sets, &mut ignored);
+    ignored.sort();
+    ignored
+}
+
+struct KnownPaths<'a> {
+    files: &'a HashSet<&'a str>,
+    untracked_dirs: &'a HashSet<&'a str>,
+    parents: &'a HashSet<&'a str>,
+}
+
+fn walk(root: &Path, dir: &str, known: &KnownPaths, ignored: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(root.join(dir)) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if dir.is_empty() && VCS_DIRS.contains(&name.as_str()) {
+            continue;
+        }
+        let path = if dir.is_empty() {
+            name
+        } else {
+            format!("{}/{}", dir, name)
+        };
+        let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+
+        if !is_dir {
+            if !known.files.contains(path.as_str()) {
+                ignored.push(path);
+            }
+        } else if known.parents.contains(path.as_str()) {
+            walk(root, &path, known, ignored);
+        } else if !known.untracked_dirs.contains(path.as_str()) && !is_empty_dir(&root.join(&path))
+        {
+            ignored.push(format!("{}/", path));
+        }
+    }
+}
+
+/// Empty directories aren't ignored, just invisible to jj
+fn is_empty_dir(path: &Path) -> bool {
+    fs::read_dir(path).map_or(true, |mut entries| entries.next().is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_ignored_paths_collapses_unknown_directories() {
+        let root = std::env::temp_dir().join(format!("tij-ignored-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in [".jj/repo", "src", "target/debug", "notes", "empty"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in [
+            ".jj/repo/store",
+            "Cargo.toml",
+            "src/main.rs",
+            "src/main.rs.orig",
+            "target/debug/tij",
+            "notes/todo.txt",
+            ".env",
+        ] {
+            fs::write(root.join(file), "").unwrap();
+        }
+
+        let ignored = ignored_paths(
+            &root,
+            &strings(&["Cargo.toml", "src/main.rs"]),
+            &strings(&["notes/"]),
+        );
+        assert_eq!(ignored, [".env", "src/main.rs.orig", "target/"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
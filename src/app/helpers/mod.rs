@@ -1 +1,5 @@
+pub mod git_sync;
+pub mod hidden;
+pub mod ignored;
+pub mod paths;
 pub mod revision;
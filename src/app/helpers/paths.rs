@@ -0,0 +1,58 @@
+//! Paths printed by jj
+//!
+//! jj prints file paths relative to the current directory, while files are
+//! read and written under the workspace root. The two only agree when tij
+//! runs from the root, so paths from `jj status`/`jj diff` are converted
+//! before they are joined onto the root.
+
+use std::path::{Component, Path, PathBuf};
+
+/// `path` (relative to `cwd`) relative to the workspace `root`,
+/// `/`-separated; `None` when it lies outside the workspace
+pub fn root_relative(root: &Path, cwd: &Path, path: &str) -> Option<String> {
+    let mut absolute = PathBuf::new();
+    for component in cwd.join(path).components() {
+        match component {
+            Component::ParentDir => {
+                absolute.pop();
+            }
+            Component::CurDir => {}
+            other => absolute.push(other),
+        }
+    }
+    let relative = absolute.strip_prefix(root).ok()?;
+    let parts: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    (!parts.is_empty()).then(|| parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_relative_from_a_subdirectory() {
+        let root = Path::new("/repo");
+        assert_eq!(
+            root_relative(root, Path::new("/repo/src"), "app/main.rs").as_deref(),
+            Some("src/app/main.rs")
+        );
+        assert_eq!(
+            root_relative(root, Path::new("/repo/src"), "../README.md").as_deref(),
+            Some("README.md")
+        );
+        assert_eq!(
+            root_relative(root, Path::new("/repo"), "./a.txt").as_deref(),
+            Some("a.txt")
+        );
+    }
+
+    #[test]
+    fn test_root_relative_outside_the_workspace() {
+        let root = Path::new("/repo");
+        assert_eq!(root_relative(root, Path::new("/repo"), "../other"), None);
+        assert_eq!(root_relative(root, Path::new("/repo/src"), ".."), None);
+    }
+}
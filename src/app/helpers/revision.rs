@@ -3,8 +3,7 @@
 //! Centralizes common patterns for ID truncation, lookup between
 //! change_id and commit_id, and root commit detection.
 
-use crate::jj::constants::ROOT_CHANGE_ID;
-use crate::model::{Change, ChangeId, CommitId};
+use crate::model::{Change, ChangeId, CommitId, is_root_change_id};
 use crate::ui::views::LogView;
 
 /// Common payload extracted from `selected_change()`.
@@ -29,12 +28,8 @@ impl SelectedRevision {
     }
 }
 
-/// Truncate an ID to 8 characters for display.
-///
-/// Safe for inputs shorter than 8 characters (returns the full string).
-pub fn short_id(id: &str) -> &str {
-    &id[..8.min(id.len())]
-}
+/// Display form of an ID (see [`crate::model::short_id`])
+pub use crate::model::short_id;
 
 /// Reverse-lookup: find the change_id for a given commit_id.
 ///
@@ -60,10 +55,10 @@ pub fn commit_id_for_change<'a>(changes: &'a [Change], change_id: &str) -> Optio
 
 /// Check if a commit_id corresponds to the root commit.
 ///
-/// Reverse-looks up the change_id and compares with ROOT_CHANGE_ID.
+/// Reverse-looks up the change_id and checks it is the (all-`z`) root ID.
 pub fn is_root_by_commit_id(changes: &[Change], commit_id: &str) -> bool {
     change_id_for_commit(changes, commit_id)
-        .map(|cid| cid == ROOT_CHANGE_ID)
+        .map(is_root_change_id)
         .unwrap_or(false)
 }
 
@@ -83,22 +78,25 @@ mod tests {
 
     #[test]
     fn test_short_id_normal() {
-        assert_eq!(short_id("abcdef1234567890"), "abcdef12");
+        assert_eq!(
+            short_id("abcdef1234567890abcdef1234567890abcdef12", 8),
+            "abcdef12"
+        );
     }
 
     #[test]
     fn test_short_id_exact_8() {
-        assert_eq!(short_id("abcdef12"), "abcdef12");
+        assert_eq!(short_id("abcdef12", 8), "abcdef12");
     }
 
     #[test]
     fn test_short_id_short_input() {
-        assert_eq!(short_id("abc"), "abc");
+        assert_eq!(short_id("abc", 8), "abc");
     }
 
     #[test]
     fn test_short_id_empty() {
-        assert_eq!(short_id(""), "");
+        assert_eq!(short_id("", 8), "");
     }
 
     // ── change_id_for_commit ─────────────────────────────────────────
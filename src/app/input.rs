@@ -2,13 +2,16 @@
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+use crate::app::helpers::hidden::hidden_row_action;
 use crate::app::helpers::revision::short_id;
 
 use super::state::{App, View};
 use crate::keys;
+use crate::ui::components::compact;
 use crate::ui::views::{
-    BlameAction, BookmarkAction, CommandHistoryAction, DiffAction, EvologAction, InputMode,
-    LogAction, OperationAction, RenameState, ResolveAction, StatusAction, StatusInputMode,
+    BlameAction, BookmarkAction, CommandHistoryAction, CommandLogAction, DiagnosticsAction,
+    DiffAction, EvologAction, FileLogAction, InputMode, LogAction, MergeAction, OpHeadsAction,
+    OperationAction, OperationSort, RenameState, ResolveAction, StatusAction, StatusInputMode,
     TagAction,
 };
 
@@ -50,21 +53,43 @@ impl App {
             return;
         }
 
-        // Handle Ctrl+L for refresh (all views, normal mode)
-        if keys::is_refresh_key(&key) {
-            // Skip if in input mode or special mode (like RebaseSelect)
-            let in_special_mode = match self.current_view {
-                View::Log => !matches!(self.log_view.input_mode, InputMode::Normal),
-                View::Status => self.status_view.input_mode != StatusInputMode::Normal,
-                View::Help => self.help_search_input,
+        // Handle Ctrl+P for the push queue (Log/Bookmark View, normal mode)
+        if keys::is_push_queue_key(&key) {
+            let in_normal_mode = match self.current_view {
+                View::Log => matches!(self.log_view.input_mode, InputMode::Normal),
+                View::Bookmark => self.bookmark_view.rename_state.is_none(),
                 _ => false,
             };
-            if !in_special_mode {
-                self.execute_refresh();
+            if in_normal_mode {
+                self.start_push_queue();
                 return;
             }
         }
 
+        // Handle Ctrl+L for refresh (all views, normal mode)
+        if keys::is_refresh_key(&key) && !self.in_special_mode() {
+            self.execute_refresh();
+            return;
+        }
+
+        // Handle Ctrl+O to open the selection on the forge (Log/Diff/Blame, normal mode)
+        if keys::is_browse_key(&key)
+            && matches!(self.current_view, View::Log | View::Diff | View::Blame)
+            && !self.in_special_mode()
+        {
+            self.browse_on_forge();
+            return;
+        }
+
+        // Handle Ctrl+F for fullscreen (all views, normal mode)
+        if keys::is_fullscreen_key(&key) && !self.in_special_mode() {
+            self.toggle_fullscreen();
+            return;
+        }
+
+        // Keymap preset and remapped keys stand for the vim keys the views handle
+        let key = self.translate_preset_key(key);
+
         // If in input mode or rebase select mode, delegate all keys to the view (skip global handling)
         if self.current_view == View::Log && !matches!(self.log_view.input_mode, InputMode::Normal)
         {
@@ -82,6 +107,13 @@ impl App {
             return;
         }
 
+        // Handle Operation View filter input (skip global keys so q/Esc/Tab stay in the input)
+        if self.current_view == View::Operation && self.operation_view.is_filter_input() {
+            let action = self.operation_view.handle_key(key);
+            self.handle_operation_action(action);
+            return;
+        }
+
         // Handle Help search input mode (skip global keys so Esc/q/Tab stay in search)
         if self.current_view == View::Help && self.help_search_input {
             self.handle_view_key(key);
@@ -96,7 +128,17 @@ impl App {
     }
 
     fn handle_global_key(&mut self, key: KeyEvent) -> bool {
+        // Alt+1..3 switch the tabs of the compact layout (plain digits stay
+        // pin jumps and type-ahead)
+        if self.compact_tabs_active()
+            && let Some(view) = compact::tab_for_key(key)
+        {
+            self.go_to_tab(view);
+            return true;
+        }
         match key.code {
+            // Merge view discards its state on back; let the view handle it
+            keys::QUIT | keys::ESC if self.current_view == View::Merge => false,
             keys::QUIT => {
                 self.handle_quit();
                 true
@@ -107,6 +149,20 @@ impl App {
                 {
                     return false;
                 }
+                // Esc clears the Log View's merge marks before leaving the view
+                if self.current_view == View::Log && !self.log_view.marked.is_empty() {
+                    return false;
+                }
+                // Esc dismisses the stack rebase suggestion first
+                if self.current_view == View::Log && self.rebase_suggestion.take().is_some() {
+                    return true;
+                }
+                // Esc cancels Evolog compare mode before leaving the view
+                if self.current_view == View::Evolog
+                    && self.evolog_view.as_ref().is_some_and(|v| v.is_comparing())
+                {
+                    return false;
+                }
                 self.handle_back();
                 true
             }
@@ -114,6 +170,10 @@ impl App {
                 self.go_to_view(View::Help);
                 true
             }
+            keys::TAB if self.compact_tabs_active() => {
+                self.go_to_tab(compact::next_tab(self.current_view));
+                true
+            }
             keys::TAB => {
                 self.next_view();
                 true
@@ -122,11 +182,20 @@ impl App {
                 self.go_to_view(View::Status);
                 true
             }
-            keys::UNDO if matches!(self.current_view, View::Log | View::Bookmark | View::Tag) => {
+            keys::UNDO
+                if matches!(
+                    self.current_view,
+                    View::Log | View::Bookmark | View::Tag | View::Stack
+                ) =>
+            {
                 self.notification = None; // Clear any existing notification
                 self.execute_undo();
                 true
             }
+            keys::REBASE if self.current_view == View::Log && self.rebase_suggestion.is_some() => {
+                self.accept_rebase_suggestion();
+                true
+            }
             keys::OPERATION_HISTORY if self.current_view == View::Log => {
                 self.open_operation_history();
                 true
@@ -135,6 +204,71 @@ impl App {
         }
     }
 
+    /// Tab bar keys apply: compact layout, a tab shown, no text being typed
+    fn compact_tabs_active(&self) -> bool {
+        self.compact
+            && compact::is_tab(self.current_view)
+            && self.bookmark_view.rename_state.is_none()
+    }
+
+    /// `p`: toggle the preview, or in the compact layout switch the pane
+    /// between the list and the preview; returns whether it is now shown
+    fn toggle_preview_pane(&mut self) -> bool {
+        if !(self.compact && compact::is_tab(self.current_view)) {
+            self.preview_enabled = !self.preview_enabled;
+            return self.preview_enabled;
+        }
+        self.compact_preview = !self.compact_preview;
+        if self.compact_preview {
+            // Fetch right away rather than after the next render
+            self.preview_enabled = true;
+            self.preview_auto_disabled = false;
+        }
+        self.compact_preview
+    }
+
+    /// Switch to a tab of the compact layout
+    fn go_to_tab(&mut self, view: View) {
+        match view {
+            View::Bookmark => self.open_bookmark_view(),
+            _ => self.go_to_view(view),
+        }
+    }
+
+    /// Input mode or special mode (like RebaseSelect) that takes all keys
+    fn in_special_mode(&self) -> bool {
+        match self.current_view {
+            View::Log => !matches!(self.log_view.input_mode, InputMode::Normal),
+            View::Status => self.status_view.input_mode != StatusInputMode::Normal,
+            View::Help => self.help_search_input,
+            View::Operation => self.operation_view.is_filter_input(),
+            _ => false,
+        }
+    }
+
+    /// Map a `tij.keymap` preset or `[tij.keys]` remapped key to its vim key
+    /// (outside text input)
+    fn translate_preset_key(&self, key: KeyEvent) -> KeyEvent {
+        let renaming =
+            self.current_view == View::Bookmark && self.bookmark_view.rename_state.is_some();
+        if self.in_special_mode() || renaming || !(key.modifiers - KeyModifiers::SHIFT).is_empty() {
+            return key;
+        }
+        KeyEvent {
+            code: keys::resolve_key(self.keymap, &self.key_remaps, self.current_view, key.code),
+            ..key
+        }
+    }
+
+    /// Maximize the current view, or restore the layout
+    fn toggle_fullscreen(&mut self) {
+        self.fullscreen = !self.fullscreen;
+        // Deferred preview fetches resume on idle once the layout is back
+        if self.fullscreen {
+            self.notify_info("Fullscreen (Ctrl+F to restore)");
+        }
+    }
+
     fn handle_quit(&mut self) {
         if self.current_view == View::Log {
             self.quit();
@@ -149,6 +283,27 @@ impl App {
         }
     }
 
+    /// Move or resize the Log preview split; false if `code` isn't a split key
+    fn handle_preview_layout_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            k if k == keys::PREVIEW_LAYOUT => {
+                self.preview_layout.toggle_direction();
+                self.notify_info(format!(
+                    "Preview at {}",
+                    self.preview_layout.direction.as_str()
+                ));
+            }
+            k if k == keys::PREVIEW_GROW => {
+                self.preview_layout.grow();
+            }
+            k if k == keys::PREVIEW_SHRINK => {
+                self.preview_layout.shrink();
+            }
+            _ => return false,
+        }
+        true
+    }
+
     fn handle_view_key(&mut self, key: KeyEvent) {
         match self.current_view {
             View::Log => {
@@ -156,8 +311,9 @@ impl App {
                 if key.code == keys::PREVIEW
                     && matches!(self.log_view.input_mode, InputMode::Normal)
                 {
-                    self.preview_enabled = !self.preview_enabled;
-                    if self.preview_enabled {
+                    if self.toggle_preview_pane() {
+                        // Bookmark sync state is only loaded while the preview is on
+                        self.refresh_bookmark_tracking();
                         // Immediate fetch on toggle-ON (no 200ms wait)
                         self.update_preview_if_needed();
                         self.resolve_pending_preview();
@@ -168,6 +324,14 @@ impl App {
                     return;
                 }
 
+                // Preview split placement/size (only while the preview is on)
+                if self.preview_enabled
+                    && matches!(self.log_view.input_mode, InputMode::Normal)
+                    && self.handle_preview_layout_key(key.code)
+                {
+                    return;
+                }
+
                 let action = self.log_view.handle_key(key);
                 self.handle_log_action(action);
 
@@ -185,13 +349,52 @@ impl App {
                 }
             }
             View::Status => {
+                let normal_mode = self.status_view.input_mode == StatusInputMode::Normal;
+                // Same preview toggle and split keys as Log View
+                if normal_mode && key.code == keys::PREVIEW {
+                    if self.toggle_preview_pane() {
+                        self.update_status_preview_if_needed();
+                        self.resolve_pending_preview();
+                    } else {
+                        self.status_preview_pending = None;
+                    }
+                    return;
+                }
+                if self.preview_enabled && normal_mode && self.handle_preview_layout_key(key.code) {
+                    return;
+                }
+
                 let visible_height = self.last_frame_height.get() as usize;
                 let action = self.status_view.handle_key_with_height(key, visible_height);
                 self.handle_status_action(action);
+
+                if self.preview_enabled && self.current_view == View::Status {
+                    self.update_status_preview_if_needed();
+                }
             }
             View::Operation => {
+                let normal_mode = !self.operation_view.is_filter_input();
+                // Same preview toggle and split keys as Log View
+                if normal_mode && key.code == keys::PREVIEW {
+                    self.preview_enabled = !self.preview_enabled;
+                    if self.preview_enabled {
+                        self.update_op_preview_if_needed();
+                        self.resolve_pending_preview();
+                    } else {
+                        self.op_preview_pending = None;
+                    }
+                    return;
+                }
+                if self.preview_enabled && normal_mode && self.handle_preview_layout_key(key.code) {
+                    return;
+                }
+
                 let action = self.operation_view.handle_key(key);
                 self.handle_operation_action(action);
+
+                if self.preview_enabled && self.current_view == View::Operation {
+                    self.update_op_preview_if_needed();
+                }
             }
             View::Blame => {
                 if let Some(ref mut blame_view) = self.blame_view {
@@ -211,10 +414,27 @@ impl App {
                 let action = self.workspace_view.handle_key(key);
                 self.handle_workspace_action(action);
             }
+            View::Remote => {
+                let action = self.remote_view.handle_key(key);
+                self.handle_remote_action(action);
+            }
+            View::Pager => {
+                let visible_height = self.last_frame_height.get() as usize;
+                self.pager_view.handle_key(key, visible_height);
+            }
+            View::Sparse => {
+                let action = self.sparse_view.handle_key(key);
+                self.handle_sparse_action(action);
+            }
+            View::Stack => {
+                let action = self.stack_view.handle_key(key);
+                self.handle_stack_action(action);
+            }
             View::Resolve => {
                 if let Some(ref mut resolve_view) = self.resolve_view {
                     let action = resolve_view.handle_key(key);
                     self.handle_resolve_action(action);
+                    self.refresh_resolve_preview();
                 }
             }
             View::Evolog => {
@@ -223,11 +443,42 @@ impl App {
                     self.handle_evolog_action(action);
                 }
             }
+            View::FileLog => {
+                if let Some(ref mut file_log_view) = self.file_log_view {
+                    let action = file_log_view.handle_key(key);
+                    self.handle_file_log_action(action);
+                }
+            }
+            View::Merge => {
+                if let Some(ref mut merge_view) = self.merge_view {
+                    let action = merge_view.handle_key(key);
+                    self.handle_merge_action(action);
+                }
+            }
+            View::OpHeads => {
+                if let Some(ref mut op_heads_view) = self.op_heads_view {
+                    let action = op_heads_view.handle_key(key);
+                    self.handle_op_heads_action(action);
+                }
+            }
             View::CommandHistory => {
                 let total = self.command_history.len();
                 let action = self.command_history_view.handle_key(key, total);
                 self.handle_command_history_action(action);
             }
+            View::Diagnostics => {
+                let visible_height = self.last_frame_height.get() as usize;
+                if self.diagnostics_view.handle_key(key, visible_height)
+                    == DiagnosticsAction::CopyReport
+                {
+                    self.copy_diagnostics();
+                }
+            }
+            View::CommandLog => {
+                let entries = self.jj.command_log().entries();
+                let action = self.command_log_view.handle_key(key, &entries);
+                self.handle_command_log_action(action);
+            }
             View::Help => {
                 if self.help_search_input {
                     // Search input mode: capture text
@@ -237,30 +488,26 @@ impl App {
                             self.help_input_buffer.clear();
                         }
                         KeyCode::Enter => {
-                            let query = std::mem::take(&mut self.help_input_buffer);
+                            let query = self.help_input_buffer.take();
                             self.help_search_input = false;
                             if query.is_empty() {
                                 self.help_search_query = None;
                             } else {
                                 self.help_search_query = Some(query.clone());
                                 // Jump to first match
-                                let indices = crate::ui::widgets::matching_line_indices(&query);
+                                let indices = crate::ui::widgets::matching_line_indices(
+                                    self.help_context(),
+                                    self.keymap,
+                                    &query,
+                                );
                                 if let Some(&first) = indices.first() {
                                     self.help_scroll = first;
                                 }
                             }
                         }
-                        KeyCode::Char(c)
-                            if !key
-                                .modifiers
-                                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
-                        {
-                            self.help_input_buffer.push(c);
-                        }
-                        KeyCode::Backspace => {
-                            self.help_input_buffer.pop();
+                        _ => {
+                            self.help_input_buffer.handle_key(key);
                         }
-                        _ => {}
                     }
                 } else {
                     // Normal mode: scrolling + search start + n/N navigation
@@ -277,7 +524,11 @@ impl App {
                         self.help_input_buffer.clear();
                     } else if key.code == keys::SEARCH_NEXT {
                         if let Some(ref query) = self.help_search_query {
-                            let indices = crate::ui::widgets::matching_line_indices(query);
+                            let indices = crate::ui::widgets::matching_line_indices(
+                                self.help_context(),
+                                self.keymap,
+                                query,
+                            );
                             if let Some(next) = indices.iter().find(|&&i| i > self.help_scroll) {
                                 self.help_scroll = *next;
                             } else if let Some(&first) = indices.first() {
@@ -288,7 +539,11 @@ impl App {
                     } else if key.code == keys::SEARCH_PREV
                         && let Some(ref query) = self.help_search_query
                     {
-                        let indices = crate::ui::widgets::matching_line_indices(query);
+                        let indices = crate::ui::widgets::matching_line_indices(
+                            self.help_context(),
+                            self.keymap,
+                            query,
+                        );
                         if let Some(prev) = indices.iter().rev().find(|&&i| i < self.help_scroll) {
                             self.help_scroll = *prev;
                         } else if let Some(&last) = indices.last() {
@@ -302,6 +557,23 @@ impl App {
     }
 
     fn handle_log_action(&mut self, action: LogAction) {
+        let action = match self.log_view.selected_change().filter(|c| c.is_hidden) {
+            Some(change) => {
+                let commit_id = change.commit_id.to_string();
+                match hidden_row_action(action, &commit_id) {
+                    Some(action) => action,
+                    None => {
+                        self.notify_info(format!(
+                            "{} is hidden: Y duplicates it, U restores its content into @",
+                            short_id(&commit_id, self.id_length)
+                        ));
+                        return;
+                    }
+                }
+            }
+            None => action,
+        };
+
         match action {
             LogAction::None => {}
 
@@ -312,6 +584,8 @@ impl App {
             | LogAction::OpenBookmarkView
             | LogAction::OpenTagView
             | LogAction::OpenWorkspaceView
+            | LogAction::OpenRemoteView
+            | LogAction::OpenStackView
             | LogAction::OpenCommandHistory
             | LogAction::OpenEvolog(_)
             | LogAction::OpenResolveList { .. } => {
@@ -326,12 +600,21 @@ impl App {
             | LogAction::NewChange
             | LogAction::NewChangeFrom { .. }
             | LogAction::NewChangeFromCurrent
+            | LogAction::NewMergeChange { .. }
+            | LogAction::StartMerge(_)
             | LogAction::SquashInto { .. }
+            | LogAction::SquashFilesInto { .. }
+            | LogAction::QuickAmend { .. }
             | LogAction::Abandon(_)
             | LogAction::Split(_)
+            | LogAction::SplitFiles(_)
             | LogAction::Duplicate(_)
+            | LogAction::CherryPick(_)
+            | LogAction::RestoreHidden(_)
             | LogAction::DiffEdit(_)
             | LogAction::Revert(_)
+            | LogAction::StartRevertRange(_)
+            | LogAction::RevertRange { .. }
             | LogAction::SimplifyParents(_)
             | LogAction::Fix { .. }
             | LogAction::Metaedit { .. } => {
@@ -341,21 +624,31 @@ impl App {
             // Bookmark
             LogAction::CreateBookmark { .. }
             | LogAction::StartBookmarkDelete
-            | LogAction::StartBookmarkJump => {
+            | LogAction::StartBookmarkJump
+            | LogAction::TogglePin { .. }
+            | LogAction::JumpToPin(_)
+            | LogAction::EditNote(_) => {
                 self.handle_log_bookmark(action);
             }
 
             // Git
-            LogAction::Fetch | LogAction::StartPush | LogAction::StartTrack => {
+            LogAction::Fetch
+            | LogAction::StartPush
+            | LogAction::TogglePushQueue(_)
+            | LogAction::StartTrack => {
                 self.handle_log_git(action);
             }
 
             // Rebase / Parallelize
             LogAction::Rebase { .. }
+            | LogAction::PickRebaseDestination { .. }
             | LogAction::Absorb
             | LogAction::StartParallelize(_)
             | LogAction::Parallelize { .. }
-            | LogAction::ParallelizeSameRevision => {
+            | LogAction::ParallelizeSameRevision
+            | LogAction::StartReorder
+            | LogAction::MoveCommitUp(_)
+            | LogAction::MoveCommitDown(_) => {
                 self.handle_log_rebase(action);
             }
 
@@ -382,7 +675,19 @@ impl App {
             }
 
             // Misc
-            LogAction::NextChange | LogAction::PrevChange | LogAction::ToggleReversed => {
+            LogAction::NextChange
+            | LogAction::PrevChange
+            | LogAction::ToggleReversed
+            | LogAction::ToggleElided
+            | LogAction::ToggleHidden
+            | LogAction::ToggleCenterCursor
+            | LogAction::ToggleCommitIds
+            | LogAction::CycleRevsetPreset
+            | LogAction::OpenRevsetPresets
+            | LogAction::OpenCompareSessions
+            | LogAction::StartJjCommand
+            | LogAction::ReportParseIssue
+            | LogAction::StartYank => {
                 self.handle_log_misc(action);
             }
         }
@@ -396,6 +701,8 @@ impl App {
             LogAction::OpenBookmarkView => self.open_bookmark_view(),
             LogAction::OpenTagView => self.open_tag_view(),
             LogAction::OpenWorkspaceView => self.open_workspace_view(),
+            LogAction::OpenRemoteView => self.open_remote_view(),
+            LogAction::OpenStackView => self.open_stack_view(),
             LogAction::OpenCommandHistory => self.go_to_view(View::CommandHistory),
             LogAction::OpenEvolog(change_id) => self.open_evolog(&change_id),
             LogAction::OpenResolveList {
@@ -424,16 +731,31 @@ impl App {
             LogAction::NewChangeFromCurrent => {
                 self.notify_info("Use 'c' to create from current change");
             }
+            LogAction::NewMergeChange { parents, labels } => {
+                self.start_new_merge_change(parents, labels)
+            }
+            LogAction::StartMerge(change_id) => self.start_merge_helper(&change_id),
             LogAction::SquashInto {
                 source,
                 destination,
-            } => self.execute_squash_into(&source, &destination),
-            LogAction::Abandon(revision) => self.execute_abandon(&revision),
+            } => self.start_squash_into(&source, &destination),
+            LogAction::SquashFilesInto {
+                source,
+                destination,
+            } => self.start_squash_files(&source, &destination),
+            LogAction::QuickAmend {
+                destination,
+                change_id,
+            } => self.execute_quick_amend(&destination, &change_id),
+            LogAction::Abandon(revision) => self.start_abandon(&revision),
             LogAction::Split(revision) => self.execute_split(&revision),
+            LogAction::SplitFiles(revision) => self.start_split_files(&revision),
             LogAction::Duplicate(revision) => self.duplicate(&revision),
+            LogAction::CherryPick(revision) => self.start_cherry_pick(&revision),
+            LogAction::RestoreHidden(commit_id) => self.start_restore_hidden(&commit_id),
             LogAction::DiffEdit(revision) => self.execute_diffedit(&revision, None),
             LogAction::Revert(revision) => {
-                let short_id = short_id(&revision);
+                let short_id = short_id(&revision, self.id_length);
                 self.active_dialog = Some(Dialog::confirm(
                     "Revert Change",
                     format!("Revert changes from {}?", short_id),
@@ -444,8 +766,15 @@ impl App {
                     DialogCallback::Revert { revision },
                 ));
             }
+            LogAction::StartRevertRange(from_id) => {
+                self.notify_info(format!(
+                    "From: {}. Select other end and press Enter",
+                    from_id
+                ));
+            }
+            LogAction::RevertRange { from, to } => self.start_revert_range(&from, &to),
             LogAction::SimplifyParents(revision) => {
-                let short_id = short_id(&revision);
+                let short_id = short_id(&revision, self.id_length);
                 self.active_dialog = Some(Dialog::confirm(
                     "Simplify Parents",
                     format!("Simplify parents for {}?", short_id),
@@ -461,7 +790,7 @@ impl App {
                     self.set_error("Cannot fix: commit is immutable");
                     return;
                 }
-                let short_id = short_id(&revision);
+                let short_id = short_id(&revision, self.id_length);
                 let items = vec![
                     SelectItem {
                         label: "Default (respect fix.tools line-range-arg)".to_string(),
@@ -488,50 +817,7 @@ impl App {
             LogAction::Metaedit {
                 change_id,
                 commit_id,
-            } => {
-                if self.jj.is_immutable(&commit_id) {
-                    self.set_error("Cannot metaedit: commit is immutable");
-                    return;
-                }
-                let short = short_id(&change_id);
-                let items = vec![
-                    SelectItem {
-                        label: "Update author".to_string(),
-                        value: "update-author".to_string(),
-                        selected: false,
-                    },
-                    SelectItem {
-                        label: "Set author...".to_string(),
-                        value: "set-author".to_string(),
-                        selected: false,
-                    },
-                    SelectItem {
-                        label: "Update author timestamp".to_string(),
-                        value: "update-timestamp".to_string(),
-                        selected: false,
-                    },
-                    SelectItem {
-                        label: "Generate new change-id".to_string(),
-                        value: "new-change-id".to_string(),
-                        selected: false,
-                    },
-                    SelectItem {
-                        label: "Force rewrite".to_string(),
-                        value: "force-rewrite".to_string(),
-                        selected: false,
-                    },
-                ];
-                self.active_dialog = Some(Dialog::select_single(
-                    "Metaedit",
-                    format!("Edit metadata for {}", short),
-                    items,
-                    None,
-                    DialogCallback::MetaeditSelect {
-                        commit_id,
-                        change_id,
-                    },
-                ));
-            }
+            } => self.start_metaedit(commit_id, change_id),
             _ => {}
         }
     }
@@ -543,6 +829,12 @@ impl App {
             }
             LogAction::StartBookmarkDelete => self.start_bookmark_delete(),
             LogAction::StartBookmarkJump => self.start_bookmark_jump(),
+            LogAction::TogglePin {
+                change_id,
+                bookmark,
+            } => self.toggle_pin(&change_id, bookmark.as_deref()),
+            LogAction::JumpToPin(slot) => self.jump_to_pin(slot),
+            LogAction::EditNote(change_id) => self.start_edit_note(&change_id),
             _ => {}
         }
     }
@@ -551,6 +843,10 @@ impl App {
         match action {
             LogAction::Fetch => self.start_fetch(),
             LogAction::StartPush => self.start_push(),
+            LogAction::TogglePushQueue(mut bookmarks) => {
+                bookmarks.dedup();
+                self.toggle_push_queue(&bookmarks);
+            }
             LogAction::StartTrack => self.start_track(),
             _ => {}
         }
@@ -567,9 +863,22 @@ impl App {
                 skip_emptied,
                 use_revset,
                 simplify_parents,
-            } => self.execute_rebase(
-                &source,
-                &destination,
+            } => self.start_rebase(
+                source,
+                destination,
+                mode,
+                skip_emptied,
+                simplify_parents,
+                use_revset,
+            ),
+            LogAction::PickRebaseDestination {
+                source,
+                mode,
+                skip_emptied,
+                use_revset,
+                simplify_parents,
+            } => self.start_pick_rebase_destination(
+                source,
                 mode,
                 skip_emptied,
                 simplify_parents,
@@ -580,8 +889,8 @@ impl App {
                 self.notify_info(format!("From: {}. Select end and press Enter", from_id));
             }
             LogAction::Parallelize { from, to } => {
-                let from_short = &from[..8.min(from.len())];
-                let to_short = &to[..8.min(to.len())];
+                let from_short = short_id(&from, self.id_length);
+                let to_short = short_id(&to, self.id_length);
                 self.active_dialog = Some(Dialog::confirm(
                     "Parallelize",
                     format!("Parallelize {}::{}?", from_short, to_short),
@@ -592,6 +901,11 @@ impl App {
             LogAction::ParallelizeSameRevision => {
                 self.notify_info("Cannot parallelize single revision");
             }
+            LogAction::StartReorder => {
+                self.notify_info("Reorder: K/J move the change up/down, Esc to finish");
+            }
+            LogAction::MoveCommitUp(change_id) => self.reorder_commit(&change_id, true),
+            LogAction::MoveCommitDown(change_id) => self.reorder_commit(&change_id, false),
             _ => {}
         }
     }
@@ -679,6 +993,71 @@ impl App {
                 };
                 self.notify_info(format!("Log order: {}", label));
             }
+            LogAction::ToggleElided => {
+                let selected_id = self
+                    .log_view
+                    .selected_change()
+                    .map(|c| c.change_id.to_string());
+                self.log_view.expand_elided = !self.log_view.expand_elided;
+                let revset = self.log_view.current_revset.clone();
+                self.refresh_log(revset.as_deref());
+                if let Some(ref id) = selected_id
+                    && !self.log_view.select_change_by_id(id)
+                {
+                    self.log_view.select_working_copy();
+                }
+                let label = if self.log_view.expand_elided {
+                    "expanded"
+                } else {
+                    "collapsed"
+                };
+                self.notify_info(format!("Elided revisions: {}", label));
+            }
+            LogAction::ToggleHidden => {
+                let selected_id = self
+                    .log_view
+                    .selected_change()
+                    .filter(|c| !c.is_hidden)
+                    .map(|c| c.change_id.to_string());
+                self.log_view.show_hidden = !self.log_view.show_hidden;
+                let revset = self.log_view.current_revset.clone();
+                self.refresh_log(revset.as_deref());
+                if let Some(ref id) = selected_id
+                    && !self.log_view.select_change_by_id(id)
+                {
+                    self.log_view.select_working_copy();
+                }
+                let label = if self.log_view.show_hidden {
+                    "shown (dimmed)"
+                } else {
+                    "hidden"
+                };
+                self.notify_info(format!("Hidden commits: {}", label));
+            }
+            LogAction::ToggleCenterCursor => {
+                self.log_view.center_cursor = !self.log_view.center_cursor;
+                let label = if self.log_view.center_cursor {
+                    "on"
+                } else {
+                    "off"
+                };
+                self.notify_info(format!("Centered cursorline: {}", label));
+            }
+            LogAction::ToggleCommitIds => {
+                self.log_view.show_commit_ids = !self.log_view.show_commit_ids;
+                let label = if self.log_view.show_commit_ids {
+                    "shown"
+                } else {
+                    "hidden"
+                };
+                self.notify_info(format!("Commit IDs: {}", label));
+            }
+            LogAction::CycleRevsetPreset => self.cycle_revset_preset(),
+            LogAction::OpenRevsetPresets => self.open_revset_preset_menu(),
+            LogAction::OpenCompareSessions => self.open_compare_session_menu(),
+            LogAction::StartJjCommand => self.start_jj_command(),
+            LogAction::ReportParseIssue => self.report_log_parse_issue(),
+            LogAction::StartYank => self.start_yank_log(),
             _ => {}
         }
     }
@@ -697,7 +1076,7 @@ impl App {
                 self.execute_untrack(&full_name);
             }
             BookmarkAction::Delete(name) => {
-                self.execute_bookmark_delete(&[name]);
+                self.start_bookmark_delete_named(&name);
             }
             BookmarkAction::StartRename(old_name) => {
                 self.bookmark_view.rename_state = Some(RenameState::new(old_name));
@@ -730,6 +1109,19 @@ impl App {
             BookmarkAction::MoveUnavailable => {
                 self.notify_info("Move is available only for local bookmarks");
             }
+            BookmarkAction::TogglePushQueue(name) => {
+                self.toggle_push_queue(&[name]);
+            }
+            BookmarkAction::PullRequest(name) => {
+                self.offer_pull_request(&name, None, false);
+            }
+            BookmarkAction::CycleSort => {
+                let sort = self.bookmark_view.cycle_sort();
+                self.notify_info(format!("Bookmarks sorted by {}", sort.label()));
+            }
+            BookmarkAction::Retarget => {
+                self.offer_bookmark_retarget();
+            }
         }
     }
 
@@ -770,6 +1162,17 @@ impl App {
                 let revision = self.diff_view.as_ref().map(|v| v.revision.clone());
                 self.open_blame(&file_path, revision.as_deref());
             }
+            DiffAction::OpenFileLog { file_path } => {
+                let Some(ref diff_view) = self.diff_view else {
+                    return;
+                };
+                // Compare/interdiff: history as of the newer side
+                let revision = match diff_view.compare_info {
+                    Some(ref info) => info.to.commit_id.to_string(),
+                    None => diff_view.revision.clone(),
+                };
+                self.open_file_log(&file_path, &revision);
+            }
             DiffAction::ShowNotification(message) => {
                 self.notify_info(&message);
             }
@@ -779,9 +1182,43 @@ impl App {
             DiffAction::ExportToFile => {
                 self.export_diff_to_file();
             }
+            DiffAction::ToggleIgnoreWhitespace => {
+                self.toggle_diff_ignore_whitespace();
+            }
+            DiffAction::StepContext { more } => {
+                self.step_diff_context(more);
+            }
+            DiffAction::ToggleModeChanges => {
+                self.toggle_diff_mode_changes();
+            }
             DiffAction::CycleFormat => {
                 self.cycle_diff_format();
             }
+            DiffAction::OpenDiffTool => {
+                let Some(ref diff_view) = self.diff_view else {
+                    return;
+                };
+                let revisions: Vec<String> = match diff_view.compare_info {
+                    Some(ref info) => vec![
+                        "--from".to_string(),
+                        info.from.commit_id.to_string(),
+                        "--to".to_string(),
+                        info.to.commit_id.to_string(),
+                    ],
+                    None => vec!["-r".to_string(), diff_view.revision.clone()],
+                };
+                let revisions: Vec<&str> = revisions.iter().map(String::as_str).collect();
+                self.open_diff_tool(&revisions, None);
+            }
+            DiffAction::OpenFileMenu { file_path } => {
+                self.open_diff_file_menu(&file_path);
+            }
+            DiffAction::SaveCompareSession => {
+                self.start_save_compare_session();
+            }
+            DiffAction::OpenResolve { file_path } => {
+                self.open_resolve_from_diff(&file_path);
+            }
         }
     }
 
@@ -797,9 +1234,18 @@ impl App {
             StatusAction::OpenBlame { file_path } => {
                 self.open_blame(&file_path, None);
             }
+            StatusAction::OpenFileLog { file_path } => {
+                self.open_file_log(&file_path, "@");
+            }
             StatusAction::Commit { message } => {
                 self.execute_commit(&message);
             }
+            StatusAction::LoadIgnored => {
+                self.refresh_ignored_paths();
+            }
+            StatusAction::OpenSparseView => {
+                self.open_sparse_view();
+            }
             StatusAction::JumpToConflict => {
                 // Selection already moved by StatusView; no further action needed
             }
@@ -818,18 +1264,22 @@ impl App {
                     },
                 ));
             }
-            StatusAction::RestoreAll => {
-                use crate::ui::components::{Dialog, DialogCallback};
-                self.active_dialog = Some(Dialog::confirm(
-                    "Restore All Files",
-                    "Restore all files?\nThis discards ALL your changes in the working copy.",
-                    Some("Undo with 'u' if needed.".to_string()),
-                    DialogCallback::RestoreAll,
-                ));
+            StatusAction::RestoreHunks { file_path } => {
+                self.start_hunk_restore(&file_path);
+            }
+            StatusAction::SquashLines { file_path } => {
+                self.start_hunk_squash(&file_path);
+            }
+            StatusAction::AddToGitignore { file_path } => {
+                self.start_gitignore_add(&file_path);
             }
+            StatusAction::RestoreAll => self.start_restore_all(),
             StatusAction::DiffEdit { file_path } => {
                 self.execute_diffedit("@", Some(&file_path));
             }
+            StatusAction::OpenDiffTool { file_path } => {
+                self.open_diff_tool(&["-r", "@"], Some(&file_path));
+            }
         }
     }
 
@@ -840,7 +1290,50 @@ impl App {
                 self.go_back();
             }
             OperationAction::Restore(operation_id) => {
-                self.execute_op_restore(&operation_id);
+                self.start_op_restore(&operation_id);
+            }
+            OperationAction::ToggleFollow => {
+                if self.operation_view.toggle_follow() {
+                    self.notify_info("Follow mode ON (polling op log)");
+                } else {
+                    self.notify_info("Follow mode OFF");
+                }
+            }
+            OperationAction::OpenDivergence => {
+                self.open_op_heads_view();
+            }
+            OperationAction::Yank => {
+                self.start_yank_operation();
+            }
+            OperationAction::Abandon => {
+                self.start_op_abandon();
+            }
+            OperationAction::Gc => {
+                self.start_op_gc();
+            }
+            OperationAction::ToggleSort => {
+                let message = match self.operation_view.toggle_sort() {
+                    OperationSort::Chronological => "Operations in chronological order",
+                    OperationSort::ByType => "Operations grouped by type",
+                };
+                self.notify_info(message);
+            }
+        }
+    }
+
+    fn handle_op_heads_action(&mut self, action: OpHeadsAction) {
+        match action {
+            OpHeadsAction::None => {}
+            OpHeadsAction::Back => {
+                self.go_back();
+            }
+            OpHeadsAction::KeepSide(operation_id) => {
+                self.start_op_restore(&operation_id);
+            }
+            OpHeadsAction::KeepMerged => {
+                self.op_heads_view = None;
+                self.go_to_view(View::Log);
+                self.notify_info("Keeping the reconciled state");
             }
         }
     }
@@ -850,9 +1343,6 @@ impl App {
             ResolveAction::None => {}
             ResolveAction::Back => {
                 self.resolve_view = None;
-                // Mark log dirty so conflict indicators update on return
-                self.dirty.log = true;
-                self.dirty.op_log = true;
                 self.go_back();
             }
             ResolveAction::ResolveExternal(file_path) => {
@@ -864,6 +1354,9 @@ impl App {
             ResolveAction::ResolveTheirs(file_path) => {
                 self.execute_resolve_theirs(&file_path);
             }
+            ResolveAction::OpenMerge(file_path) => {
+                self.open_merge_view(&file_path);
+            }
             ResolveAction::ShowDiff(file_path) => {
                 // Open diff for the change, jumping to the file
                 let revision = self
@@ -876,6 +1369,40 @@ impl App {
         }
     }
 
+    fn handle_merge_action(&mut self, action: MergeAction) {
+        match action {
+            MergeAction::None => {}
+            MergeAction::Back => {
+                self.close_merge_view();
+            }
+            MergeAction::EditRegion => {
+                self.execute_merge_edit_region();
+            }
+            MergeAction::Write => {
+                self.execute_merge_write();
+            }
+            MergeAction::Unresolved(count) => {
+                self.notify_warning(format!("{} conflict region(s) still unresolved", count));
+            }
+        }
+    }
+
+    fn handle_file_log_action(&mut self, action: FileLogAction) {
+        match action {
+            FileLogAction::None => {}
+            FileLogAction::Back => {
+                self.go_back();
+            }
+            FileLogAction::OpenDiff(change_id) => {
+                let Some(file_path) = self.file_log_view.as_ref().map(|v| v.file_path.clone())
+                else {
+                    return;
+                };
+                self.open_diff_at_file(&change_id, &file_path);
+            }
+        }
+    }
+
     fn handle_evolog_action(&mut self, action: EvologAction) {
         match action {
             EvologAction::None => {}
@@ -885,6 +1412,25 @@ impl App {
             EvologAction::OpenDiff(change_id) => {
                 self.open_diff(&change_id);
             }
+            EvologAction::Yank => {
+                self.start_yank_evolog();
+            }
+            EvologAction::StartCompare(commit_id) => {
+                self.notify_info(format!(
+                    "Compare: {}. Select the other version and press Enter",
+                    commit_id
+                ));
+            }
+            EvologAction::Compare { from, to } => {
+                let msg = format!("Comparing versions {} -> {}", from, to);
+                self.open_compare_diff(&from, &to);
+                if self.error_message.is_none() {
+                    self.notify_info(&msg);
+                }
+            }
+            EvologAction::CompareSameVersion => {
+                self.notify_info("Cannot compare a version with itself");
+            }
         }
     }
 
@@ -897,6 +1443,30 @@ impl App {
             CommandHistoryAction::ToggleDetail(_) => {
                 // Detail toggle is handled internally by CommandHistoryView
             }
+            CommandHistoryAction::BugReport => self.start_bug_report(),
+            CommandHistoryAction::OpenCommandLog => {
+                self.command_log_view.reset();
+                self.go_to_view(View::CommandLog);
+            }
+            CommandHistoryAction::OpenDiagnostics => self.open_diagnostics(),
+        }
+    }
+
+    fn handle_command_log_action(&mut self, action: CommandLogAction) {
+        match action {
+            CommandLogAction::None => {}
+            CommandLogAction::Back => self.go_back(),
+            CommandLogAction::CopyCommand(id) => {
+                let Some(entry) = self.jj.command_log().entry(id) else {
+                    self.notify_info("Command is no longer in the log");
+                    return;
+                };
+                let command = entry.shell_command();
+                match super::clipboard::copy_to_clipboard(&command) {
+                    Ok(()) => self.notify_success(format!("Copied: {}", command)),
+                    Err(e) => self.set_error(e),
+                }
+            }
         }
     }
 
@@ -912,6 +1482,20 @@ impl App {
             BlameAction::JumpToLog(change_id) => {
                 self.jump_to_log(&change_id);
             }
+            BlameAction::BlameParent(commit_id) => {
+                self.blame_at_parent(&commit_id);
+            }
+            BlameAction::ShowNotification(message) => {
+                self.notify_info(&message);
+            }
+            BlameAction::OpenFileLog => {
+                let Some(ref blame_view) = self.blame_view else {
+                    return;
+                };
+                let file_path = blame_view.file_path().to_string();
+                let revision = blame_view.revision().unwrap_or("@").to_string();
+                self.open_file_log(&file_path, &revision);
+            }
         }
     }
 }
@@ -919,6 +1503,7 @@ impl App {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::components::LineInput;
     use crossterm::event::KeyEvent;
 
     /// Simulate a key press on the App (through on_key_event, which runs
@@ -934,6 +1519,68 @@ mod tests {
         app.help_input_buffer.clear();
     }
 
+    #[test]
+    fn keymap_preset_is_read_from_app() {
+        let mut app = App::new_for_test();
+        let end = KeyEvent::from(KeyCode::End);
+        assert_eq!(app.translate_preset_key(end).code, KeyCode::End);
+        app.keymap = keys::KeymapPreset::Arrows;
+        assert_eq!(app.translate_preset_key(end).code, keys::GO_BOTTOM);
+    }
+
+    #[test]
+    fn key_remaps_are_read_from_app() {
+        let mut app = App::new_for_test();
+        let x = KeyEvent::from(KeyCode::Char('x'));
+        assert_eq!(app.translate_preset_key(x).code, KeyCode::Char('x'));
+        app.key_remaps = vec![keys::KeyRemap::parse("x", "d").unwrap()];
+        assert_eq!(app.translate_preset_key(x).code, KeyCode::Char('d'));
+    }
+
+    // =========================================================================
+    // Compact layout tabs
+    // =========================================================================
+
+    fn press_alt(app: &mut App, c: char) {
+        app.on_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT));
+    }
+
+    #[test]
+    fn compact_alt_number_keys_and_tab_switch_tabs() {
+        let mut app = App::new_for_test();
+        app.compact = true;
+        press_alt(&mut app, '2');
+        assert_eq!(app.current_view, View::Status);
+        press_alt(&mut app, '1');
+        assert_eq!(app.current_view, View::Log);
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.current_view, View::Status);
+    }
+
+    #[test]
+    fn plain_number_keys_stay_with_the_view_in_compact_layout() {
+        let mut app = App::new_for_test();
+        app.compact = true;
+        // Pin jumps in Log View
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.current_view, View::Log);
+        // Tab bar keys only apply on the tabbed views
+        app.current_view = View::Diagnostics;
+        press_alt(&mut app, '1');
+        assert_eq!(app.current_view, View::Diagnostics);
+    }
+
+    #[test]
+    fn compact_preview_toggle_keeps_the_split_setting() {
+        let mut app = App::new_for_test();
+        app.compact = true;
+        press(&mut app, keys::PREVIEW);
+        assert!(app.compact_preview);
+        press(&mut app, keys::PREVIEW);
+        assert!(!app.compact_preview);
+        assert!(app.preview_enabled, "the split layout keeps its preview");
+    }
+
     // =========================================================================
     // Help search input: global key conflict tests
     // =========================================================================
@@ -998,7 +1645,7 @@ mod tests {
     fn help_search_enter_confirms_and_exits_input() {
         let mut app = App::new_for_test();
         enter_help_search(&mut app);
-        app.help_input_buffer = "quit".to_string();
+        app.help_input_buffer = LineInput::new("quit");
 
         press(&mut app, KeyCode::Enter);
 
@@ -1028,7 +1675,7 @@ mod tests {
     fn help_search_backspace_removes_char() {
         let mut app = App::new_for_test();
         enter_help_search(&mut app);
-        app.help_input_buffer = "test".to_string();
+        app.help_input_buffer = LineInput::new("test");
 
         press(&mut app, KeyCode::Backspace);
 
@@ -1072,4 +1719,176 @@ mod tests {
         // Should go back from Help view
         assert_ne!(app.current_view, View::Help);
     }
+
+    // =========================================================================
+    // Merge view: back discards state and returns to Resolve
+    // =========================================================================
+
+    #[test]
+    fn merge_view_q_returns_to_resolve_and_drops_state() {
+        use crate::model::{ConflictRegion, MergeSegment};
+        use crate::ui::views::{MergeView, ResolveView};
+
+        let mut app = App::new_for_test();
+        app.resolve_view = Some(ResolveView::new("@".to_string(), true, vec![]));
+        app.go_to_view(View::Resolve);
+        app.merge_view = Some(MergeView::new(
+            "@".to_string(),
+            "a.txt".to_string(),
+            vec![MergeSegment::Conflict(ConflictRegion::default())],
+            "\n",
+        ));
+        app.go_to_view(View::Merge);
+
+        press(&mut app, KeyCode::Char('q'));
+
+        assert_eq!(app.current_view, View::Resolve);
+        assert!(app.merge_view.is_none());
+        // Back from Resolve must not return to the closed merge view
+        assert_eq!(app.previous_view, Some(View::Log));
+    }
+
+    #[test]
+    fn test_esc_cancels_evolog_compare_before_leaving() {
+        use crate::model::{ChangeId, CommitId, EvologEntry};
+        use crate::ui::views::EvologView;
+
+        let mut app = App::new_for_test();
+        let entries = vec![EvologEntry {
+            commit_id: CommitId::new("43a4bc7d".to_string()),
+            change_id: ChangeId::new("zxsrvopz".to_string()),
+            author: "user@example.com".to_string(),
+            timestamp: "2025-10-03 18:10:00".to_string(),
+            is_empty: false,
+            description: "my feature".to_string(),
+        }];
+        app.evolog_view = Some(EvologView::new("zxsrvopz".to_string(), entries));
+        app.go_to_view(View::Evolog);
+
+        press(&mut app, KeyCode::Char('='));
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.current_view, View::Evolog);
+        assert!(!app.evolog_view.as_ref().unwrap().is_comparing());
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.current_view, View::Log);
+    }
+
+    #[test]
+    fn test_file_log_back_returns_to_origin() {
+        use crate::ui::views::FileLogView;
+
+        let mut app = App::new_for_test();
+        app.go_to_view(View::Blame);
+        app.file_log_view = Some(FileLogView::new(
+            "src/main.rs".to_string(),
+            "@".to_string(),
+            vec![],
+        ));
+        app.go_to_view(View::FileLog);
+
+        press(&mut app, KeyCode::Char('q'));
+        assert_eq!(app.current_view, View::Blame);
+    }
+
+    #[test]
+    fn test_preview_layout_keys_move_and_resize_split() {
+        use crate::ui::components::{SplitDirection, SplitLayout};
+
+        let mut app = App::new_for_test();
+        assert_eq!(app.preview_layout, SplitLayout::default());
+
+        press(&mut app, KeyCode::Char('\\'));
+        assert_eq!(app.preview_layout.direction, SplitDirection::Right);
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(
+            app.preview_layout.ratio(),
+            SplitLayout::DEFAULT_RATIO + SplitLayout::STEP
+        );
+        press(&mut app, KeyCode::Char('<'));
+        press(&mut app, KeyCode::Char('<'));
+        assert_eq!(
+            app.preview_layout.ratio(),
+            SplitLayout::DEFAULT_RATIO - SplitLayout::STEP
+        );
+
+        // Ignored while the preview is off
+        press(&mut app, KeyCode::Char('p'));
+        press(&mut app, KeyCode::Char('\\'));
+        assert_eq!(app.preview_layout.direction, SplitDirection::Right);
+    }
+
+    #[test]
+    fn test_ctrl_f_toggles_fullscreen_outside_text_input() {
+        let ctrl_f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL);
+        let mut app = App::new_for_test();
+        app.on_key_event(ctrl_f);
+        assert!(app.fullscreen);
+        app.on_key_event(ctrl_f);
+        assert!(!app.fullscreen);
+
+        // Help search input keeps Ctrl+F
+        enter_help_search(&mut app);
+        app.on_key_event(ctrl_f);
+        assert!(!app.fullscreen);
+    }
+
+    // =========================================================================
+    // Stack rebase suggestion: R accepts, Esc dismisses
+    // =========================================================================
+
+    #[test]
+    fn rebase_suggestion_takes_r_once() {
+        use crate::model::{Change, ChangeId, CommitId};
+
+        let mut app = App::new_for_test();
+        app.log_view.set_changes(vec![Change {
+            change_id: ChangeId::new("kxyzabcd".to_string()),
+            commit_id: CommitId::new("abc12345".to_string()),
+            ..Default::default()
+        }]);
+        app.rebase_suggestion = Some(2);
+
+        press(&mut app, keys::REBASE);
+        assert!(app.rebase_suggestion.is_none());
+        assert_eq!(app.log_view.input_mode, InputMode::Normal);
+
+        // Without a suggestion R starts the usual rebase flow
+        press(&mut app, keys::REBASE);
+        assert_ne!(app.log_view.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn esc_dismisses_rebase_suggestion() {
+        let mut app = App::new_for_test();
+        app.rebase_suggestion = Some(2);
+        press(&mut app, KeyCode::Esc);
+        assert!(app.rebase_suggestion.is_none());
+        assert_eq!(app.current_view, View::Log);
+        assert!(app.running);
+    }
+
+    // =========================================================================
+    // Hidden commits: only commit-ID based actions run
+    // =========================================================================
+
+    #[test]
+    fn hidden_row_refuses_change_id_actions() {
+        use crate::model::{Change, ChangeId, CommitId};
+
+        let mut app = App::new_for_test();
+        app.log_view.set_changes(vec![Change {
+            change_id: ChangeId::new("kxyzabcd".to_string()),
+            commit_id: CommitId::new("abc12345".to_string()),
+            is_hidden: true,
+            ..Default::default()
+        }]);
+        app.handle_log_action(LogAction::Abandon("kxyzabcd".to_string()));
+        assert!(app.active_dialog.is_none());
+        let notification = app.notification.as_ref().unwrap();
+        assert!(notification.message.contains("is hidden"));
+
+        app.handle_log_action(LogAction::RestoreHidden("abc12345".to_string()));
+        assert!(app.active_dialog.is_some());
+    }
 }
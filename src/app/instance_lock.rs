@@ -0,0 +1,152 @@
+//! Detection of another tij running on the same workspace
+//!
+//! On startup tij creates `.jj/tij.pid` in the workspace root (containing its
+//! process ID) and removes it on exit. A marker left by a process that is no
+//! longer running (crash, `kill -9`) is treated as stale and replaced.
+//!
+//! The marker is written to a temp file first and moved into place only if
+//! no marker exists, so another instance never reads it half-written.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Marker file name inside the workspace's `.jj` directory
+const MARKER_FILE: &str = "tij.pid";
+
+/// Marker owned by this process; removed when dropped
+#[derive(Debug)]
+pub(crate) struct InstanceLock {
+    path: PathBuf,
+}
+
+/// Result of trying to take the marker
+#[derive(Debug)]
+pub(crate) enum LockOutcome {
+    /// No other instance: this process now owns the marker
+    Acquired(InstanceLock),
+    /// Another live instance owns the marker
+    Held { pid: u32 },
+}
+
+impl InstanceLock {
+    /// Take the marker in `<workspace_root>/.jj`
+    pub fn acquire(workspace_root: &Path) -> io::Result<LockOutcome> {
+        Self::acquire_with(workspace_root, std::process::id(), process_alive)
+    }
+
+    fn acquire_with(
+        workspace_root: &Path,
+        own_pid: u32,
+        is_alive: impl Fn(u32) -> bool,
+    ) -> io::Result<LockOutcome> {
+        let dir = workspace_root.join(".jj");
+        let path = dir.join(MARKER_FILE);
+        // Two attempts: the second one after removing a stale marker
+        for _ in 0..2 {
+            let mut temp = tempfile::Builder::new()
+                .prefix(".tij.pid-")
+                .tempfile_in(&dir)?;
+            writeln!(temp, "{}", own_pid)?;
+            match temp.persist_noclobber(&path) {
+                Ok(_) => return Ok(LockOutcome::Acquired(Self { path })),
+                Err(e) if e.error.kind() == io::ErrorKind::AlreadyExists => {
+                    let owner = fs::read_to_string(&path)
+                        .ok()
+                        .and_then(|content| content.trim().parse::<u32>().ok());
+                    match owner {
+                        Some(pid) if pid != own_pid && is_alive(pid) => {
+                            return Ok(LockOutcome::Held { pid });
+                        }
+                        // Unreadable, our own or a dead process's marker
+                        _ => fs::remove_file(&path)?,
+                    }
+                }
+                Err(e) => return Err(e.error),
+            }
+        }
+        Err(io::Error::other("marker file keeps reappearing"))
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Whether a process with `pid` is running
+///
+/// Uses `/proc` on Linux and `kill -0` elsewhere; if neither can tell, the
+/// process is assumed alive so the warning errs on the side of showing.
+fn process_alive(pid: u32) -> bool {
+    if cfg!(target_os = "linux") {
+        return Path::new("/proc").join(pid.to_string()).exists();
+    }
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map_or(true, |status| status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fresh workspace-like directory with a `.jj` subdirectory
+    fn temp_workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("tij-lock-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join(".jj")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_second_instance_sees_live_owner() {
+        let root = temp_workspace("live");
+        let first = InstanceLock::acquire_with(&root, 100, |_| true).unwrap();
+        assert!(matches!(first, LockOutcome::Acquired(_)));
+
+        let second = InstanceLock::acquire_with(&root, 200, |_| true).unwrap();
+        assert!(matches!(second, LockOutcome::Held { pid: 100 }));
+
+        // Dropping the owner removes the marker
+        drop(first);
+        let third = InstanceLock::acquire_with(&root, 200, |_| true).unwrap();
+        assert!(matches!(third, LockOutcome::Acquired(_)));
+        drop(third);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_marker_holds_pid_once_visible() {
+        let root = temp_workspace("atomic");
+        let outcome = InstanceLock::acquire_with(&root, 100, |_| true).unwrap();
+        assert!(matches!(outcome, LockOutcome::Acquired(_)));
+        let entries: Vec<_> = fs::read_dir(root.join(".jj"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        // No temp file is left next to the marker
+        assert_eq!(entries, [MARKER_FILE]);
+        let content = fs::read_to_string(root.join(".jj").join(MARKER_FILE)).unwrap();
+        assert_eq!(content.trim(), "100");
+        drop(outcome);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_stale_marker_is_replaced() {
+        let root = temp_workspace("stale");
+        fs::write(root.join(".jj").join(MARKER_FILE), "100\n").unwrap();
+
+        let outcome = InstanceLock::acquire_with(&root, 200, |_| false).unwrap();
+        assert!(matches!(outcome, LockOutcome::Acquired(_)));
+        let content = fs::read_to_string(root.join(".jj").join(MARKER_FILE)).unwrap();
+        assert_eq!(content.trim(), "200");
+        drop(outcome);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -5,16 +5,30 @@
 //! - `actions`: jj operations (describe, edit, squash, bookmark, etc.)
 //! - `navigation`: Opening views with data loading (diff, blame, resolve)
 //! - `refresh`: Data refresh operations (reload from jj)
+//! - `event`: App-level events (key, tick, job results, fs changes, resize)
 //! - `input`: Key event handling
 //! - `render`: UI rendering
+//! - `watcher`: Filesystem watcher for auto-refresh (opt-in)
+//! - `auto_fetch`: Periodic background `jj git fetch` (opt-in)
+//! - `diff_stream`: Streamed loading of large diffs into the Diff View
+//! - `instance_lock`: Detection of another tij on the same workspace
+//! - `forge`: GitHub/GitLab request URLs and `gh`/`glab` CLI calls
 
 mod actions;
+mod auto_fetch;
 pub(crate) mod clipboard;
+mod diff_stream;
+mod event;
+mod forge;
 pub(crate) mod helpers;
 mod input;
+mod instance_lock;
 mod navigation;
 mod refresh;
 mod render;
 mod state;
+mod watcher;
 
+pub use actions::COPY_FILE_ARG;
+pub use event::{AppEvent, JobOutcome};
 pub use state::{App, View};
@@ -1,31 +1,83 @@
 //! View navigation (opening views with data loading)
 
+use crate::app::helpers::revision::short_id;
 use crate::jj::parser::{Parser, parse_evolog};
 use crate::model::{ChangeId, CommitId, CompareInfo, CompareRevisionInfo, Notification};
-use crate::ui::views::{BlameView, DiffView, EvologView, ResolveView};
+use crate::ui::views::{BlameView, DiffView, EvologView, FileLogView, OpHeadsView, ResolveView};
 
+use super::diff_stream::{DiffPiece, DiffStream};
 use super::state::{App, View};
 
 impl App {
     /// Open diff view for a specific change
+    ///
+    /// Waits only for the first piece of a large diff; the rest is appended
+    /// by [`Self::poll_diff_stream`].
     pub(crate) fn open_diff(&mut self, revision: &str) {
-        match self.jj.show(revision) {
-            Ok(content) => {
-                self.diff_view = Some(DiffView::new(revision.to_string(), content));
-                self.go_to_view(View::Diff);
-                self.error_message = None;
-            }
-            Err(e) => {
+        let stream = DiffStream::start(&self.diff_jj(), revision);
+        let (content, stream) = match stream.first() {
+            DiffPiece::Partial(content) => (content, Some(stream)),
+            DiffPiece::Finished(Ok(content)) => (content, None),
+            DiffPiece::Finished(Err(e)) => {
                 self.set_error(format!("Failed to load diff: {}", e));
+                return;
+            }
+        };
+        let mut diff_view = DiffView::new(revision.to_string(), content);
+        diff_view.set_options(self.diff_options);
+        diff_view.id_length = self.id_length;
+        diff_view.timestamp_format = self.timestamp_format.clone();
+        diff_view.loading = stream.is_some();
+        self.diff_view = Some(diff_view);
+        self.diff_stream = stream;
+        self.go_to_view(View::Diff);
+        self.error_message = None;
+    }
+
+    /// Append the pieces of a streamed diff that arrived since the last tick
+    ///
+    /// The stream is dropped (stopping jj) once the Diff View shows other
+    /// content, e.g. after a format switch or opening another diff.
+    pub(crate) fn poll_diff_stream(&mut self) {
+        let Some(stream) = &self.diff_stream else {
+            return;
+        };
+        let still_shown = self
+            .diff_view
+            .as_ref()
+            .is_some_and(|view| view.loading && view.revision == stream.revision());
+        if !still_shown {
+            self.diff_stream = None;
+            return;
+        }
+        let pieces: Vec<DiffPiece> = std::iter::from_fn(|| stream.try_next()).collect();
+        let Some(diff_view) = self.diff_view.as_mut() else {
+            return;
+        };
+        for piece in pieces {
+            match piece {
+                DiffPiece::Partial(content) => diff_view.append_lines(content.lines),
+                DiffPiece::Finished(result) => {
+                    diff_view.loading = false;
+                    self.diff_stream = None;
+                    match result {
+                        Ok(content) => diff_view.append_lines(content.lines),
+                        Err(e) => self.set_error(format!("Diff loading stopped: {}", e)),
+                    }
+                    return;
+                }
             }
         }
     }
 
     /// Open diff view for a specific change and jump to a file
     pub(crate) fn open_diff_at_file(&mut self, revision: &str, file_path: &str) {
-        match self.jj.show(revision) {
+        match self.diff_jj().show(revision) {
             Ok(content) => {
                 let mut diff_view = DiffView::new(revision.to_string(), content);
+                diff_view.set_options(self.diff_options);
+                diff_view.id_length = self.id_length;
+                diff_view.timestamp_format = self.timestamp_format.clone();
                 // Jump to the specified file
                 diff_view.jump_to_file(file_path);
                 self.diff_view = Some(diff_view);
@@ -46,6 +98,7 @@ impl App {
             Ok(content) => {
                 let mut blame_view = BlameView::new();
                 blame_view.set_content(content, revision.map(|s| s.to_string()));
+                blame_view.timestamp_format = self.timestamp_format.clone();
                 self.blame_view = Some(blame_view);
                 self.go_to_view(View::Blame);
                 self.error_message = None;
@@ -59,7 +112,7 @@ impl App {
     /// Open compare diff view between two revisions
     pub(crate) fn open_compare_diff(&mut self, from: &str, to: &str) {
         // Get diff output
-        let diff_output = match self.jj.diff_range(from, to) {
+        let diff_output = match self.diff_jj().diff_range(from, to) {
             Ok(output) => output,
             Err(e) => {
                 self.set_error(format!("Failed to load diff: {}", e));
@@ -112,7 +165,10 @@ impl App {
             to: to_info,
         };
 
-        let diff_view = DiffView::new_compare(content, compare_info);
+        let mut diff_view = DiffView::new_compare(content, compare_info);
+        diff_view.set_options(self.diff_options);
+        diff_view.id_length = self.id_length;
+        diff_view.timestamp_format = self.timestamp_format.clone();
         self.diff_view = Some(diff_view);
         self.go_to_view(View::Diff);
         self.error_message = None;
@@ -121,7 +177,7 @@ impl App {
     /// Open interdiff view between two revisions
     pub(crate) fn open_interdiff(&mut self, from: &str, to: &str) {
         // Get interdiff output
-        let diff_output = match self.jj.interdiff(from, to) {
+        let diff_output = match self.diff_jj().interdiff(from, to) {
             Ok(output) => output,
             Err(e) => {
                 self.set_error(format!("Failed to load interdiff: {}", e));
@@ -174,7 +230,10 @@ impl App {
             to: to_info,
         };
 
-        let diff_view = DiffView::new_interdiff(content, compare_info);
+        let mut diff_view = DiffView::new_interdiff(content, compare_info);
+        diff_view.set_options(self.diff_options);
+        diff_view.id_length = self.id_length;
+        diff_view.timestamp_format = self.timestamp_format.clone();
         self.diff_view = Some(diff_view);
         self.go_to_view(View::Diff);
         self.error_message = None;
@@ -183,6 +242,23 @@ impl App {
     /// Open operation history view
     pub(crate) fn open_operation_history(&mut self) {
         self.go_to_view(View::Operation);
+        self.update_op_preview_if_needed();
+    }
+
+    /// Open the Divergent Operations View for the latest reconciled op heads
+    pub(crate) fn open_op_heads_view(&mut self) {
+        match self.jj.op_divergence() {
+            Ok(Some(divergence)) => {
+                self.op_heads_view = Some(OpHeadsView::new(divergence));
+                self.go_to_view(View::OpHeads);
+            }
+            Ok(None) => {
+                self.notify_info("No divergent operations in recent history");
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to load op log: {}", e));
+            }
+        }
     }
 
     /// Open evolution log view for a change
@@ -194,7 +270,9 @@ impl App {
                     self.notification =
                         Some(Notification::info("No evolution history for this change"));
                 } else {
-                    self.evolog_view = Some(EvologView::new(revision.to_string(), entries));
+                    let mut evolog_view = EvologView::new(revision.to_string(), entries);
+                    evolog_view.timestamp_format = self.timestamp_format.clone();
+                    self.evolog_view = Some(evolog_view);
                     self.go_to_view(View::Evolog);
                 }
             }
@@ -204,6 +282,72 @@ impl App {
         }
     }
 
+    /// Re-annotate the current blame file at the parent of `commit_id`
+    ///
+    /// The current annotation stays on the Blame View breadcrumb.
+    pub(crate) fn blame_at_parent(&mut self, commit_id: &str) {
+        let Some(ref blame_view) = self.blame_view else {
+            return;
+        };
+        let file_path = blame_view.file_path().to_string();
+        let revision = format!("{}-", commit_id);
+        match self.jj.file_annotate(&file_path, Some(&revision)) {
+            Ok(content) => {
+                if let Some(ref mut blame_view) = self.blame_view {
+                    blame_view.push_content(content, revision);
+                }
+                self.error_message = None;
+            }
+            Err(e) if e.to_string().contains("No such path") => {
+                self.notify_info(format!(
+                    "{} does not exist before {}",
+                    file_path,
+                    short_id(commit_id, self.id_length)
+                ));
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to blame parent: {}", e));
+            }
+        }
+    }
+
+    /// Open the history of `file_path` among the ancestors of `revision`
+    pub(crate) fn open_file_log(&mut self, file_path: &str, revision: &str) {
+        match self.jj.file_log(file_path, revision) {
+            Ok(entries) => {
+                let mut file_log_view =
+                    FileLogView::new(file_path.to_string(), revision.to_string(), entries);
+                file_log_view.timestamp_format = self.timestamp_format.clone();
+                self.file_log_view = Some(file_log_view);
+                self.go_to_view(View::FileLog);
+                self.error_message = None;
+            }
+            Err(e) => {
+                self.set_error(format!("Failed to load file history: {}", e));
+            }
+        }
+    }
+
+    /// Open the Resolve View on a conflicted file shown in DiffView
+    pub(crate) fn open_resolve_from_diff(&mut self, file_path: &str) {
+        let Some(diff_view) = self.diff_view.as_ref() else {
+            return;
+        };
+        let commit_id = diff_view.content.commit_id.to_string();
+        let is_working_copy = self
+            .log_view
+            .changes
+            .iter()
+            .any(|c| c.is_working_copy && c.commit_id == commit_id);
+        self.open_resolve_view(&commit_id, is_working_copy);
+        let Some(resolve_view) = self.resolve_view.as_mut() else {
+            return;
+        };
+        if self.current_view == View::Resolve && resolve_view.select_path(file_path) {
+            self.refresh_resolve_preview();
+        }
+    }
+
     /// Open resolve view for a change
     ///
     /// Runs `jj resolve --list` and opens the Resolve List View if conflicts exist.
@@ -218,6 +362,9 @@ impl App {
                         is_working_copy,
                         files,
                     ));
+                    self.refresh_resolve_preview();
+                    // Just loaded
+                    self.dirty.resolve_list = false;
                     self.go_to_view(View::Resolve);
                 }
             }
@@ -9,9 +9,23 @@
 //! navigation via `go_to_view()`. This design (from Phase 17.1 DirtyFlags)
 //! makes parallel refresh unnecessary for the current architecture.
 
-use crate::ui::views::ResolveView;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
+use crate::app::helpers::hidden::with_hidden_commits;
+use crate::jj::constants::{HIDDEN_COMMITS_OP_DEPTH, config_keys};
+use crate::jj::parser::Parser;
+use crate::keys;
+use crate::model::{Bookmark, ConflictLine, ConflictSide, FileState, LogRow, TimestampFormat};
+use crate::ui::components::{CompactLayout, SplitDirection, SplitLayout};
+use crate::ui::views::{GraphStyle, ResolveView};
+use crate::ui::widgets::StatusVar;
+
+use super::helpers::git_sync;
+use super::helpers::ignored::ignored_paths;
+use super::instance_lock::{InstanceLock, LockOutcome};
 use super::state::{App, DirtyFlags, View};
+use super::watcher::{DEFAULT_DEBOUNCE, FsWatcher};
 
 impl App {
     /// Set dirty flags and immediately refresh only the current view if affected.
@@ -19,9 +33,8 @@ impl App {
     /// Other views will be refreshed lazily when navigated to (via `go_to_view()`).
     /// This avoids spawning unnecessary jj subprocesses for views that aren't visible.
     pub(crate) fn mark_dirty_and_refresh_current(&mut self, affected: DirtyFlags) {
-        // Clear entire preview cache when all flags are dirty (undo/redo/fetch/op_restore)
-        // since we can't know what changed
-        if affected == DirtyFlags::all() {
+        // Undo/redo/fetch/op_restore: we can't know what changed
+        if affected.preview {
             self.preview_cache.clear();
         }
 
@@ -30,6 +43,7 @@ impl App {
         self.dirty.status |= affected.status;
         self.dirty.op_log |= affected.op_log;
         self.dirty.bookmarks |= affected.bookmarks;
+        self.dirty.resolve_list |= affected.resolve_list;
 
         // Refresh only the currently visible view if it's dirty
         match self.current_view {
@@ -54,6 +68,14 @@ impl App {
                 self.refresh_bookmark_view();
                 self.dirty.bookmarks = false;
             }
+            View::Resolve if self.dirty.resolve_list => {
+                self.dirty.resolve_list = false;
+                self.refresh_open_resolve_list();
+            }
+            // Stack is a slice of the log: reload it, but leave the log dirty
+            View::Stack if self.dirty.log => {
+                self.refresh_stack_view();
+            }
             _ => {}
         }
     }
@@ -66,8 +88,33 @@ impl App {
         self.preview_pending_id = None;
 
         let reversed = self.log_view.reversed;
-        match self.jj.log_changes(revset, reversed) {
-            Ok(changes) => {
+        // Expanding elided revisions fills in the gaps between visible commits
+        let connected = self
+            .log_view
+            .expand_elided
+            .then(|| format!("connected({})", revset.unwrap_or(&self.default_log_revset)));
+        let base = connected.as_deref().or(revset);
+        // Hidden commits are added on top of whatever is shown
+        let with_hidden = self
+            .log_view
+            .show_hidden
+            .then(|| self.jj.op_log(Some(HIDDEN_COMMITS_OP_DEPTH)).ok())
+            .flatten()
+            .map(|ops| {
+                // Graph-only rows of the op log have no ID
+                let op_ids: Vec<String> = ops
+                    .into_iter()
+                    .map(|op| op.id)
+                    .filter(|id| !id.is_empty())
+                    .skip(1)
+                    .collect();
+                with_hidden_commits(base.unwrap_or(&self.default_log_revset), &op_ids)
+            });
+        match self
+            .jj
+            .log_changes_partial(with_hidden.as_deref().or(base), reversed)
+        {
+            Ok((changes, parse_issue)) => {
                 // Detect truncation: if selectable (non-graph-only) count equals
                 // the limit, results were likely truncated by --limit
                 let limit: usize = crate::jj::constants::DEFAULT_LOG_LIMIT
@@ -78,11 +125,28 @@ impl App {
 
                 self.log_view.set_changes(changes);
                 self.log_view.truncated = truncated;
+                self.apply_cached_diff_stats();
                 // Validate cache against new change list: evict stale entries,
                 // update bookmarks for entries whose commit_id still matches
                 self.preview_cache.validate(&self.log_view.changes);
                 self.log_view.current_revset = revset.map(|s| s.to_string());
                 self.error_message = None;
+                // Sync badges in the Log and preview need ahead/behind counts
+                if self.auto_fetch.is_some()
+                    || self.preview_enabled
+                    || self.status_template_uses(StatusVar::AheadBehind)
+                {
+                    self.refresh_bookmark_tracking();
+                }
+                self.refresh_status_op_id();
+                // Keep the parsed prefix visible instead of a blank view
+                if let Some(ref issue) = parse_issue {
+                    self.notify_warning(format!(
+                        "Log output could not be fully parsed at line {}: press ! to save a report",
+                        issue.line_number
+                    ));
+                }
+                self.log_view.parse_issue = parse_issue;
             }
             Err(e) => {
                 self.set_error(format!("jj error: {}", e));
@@ -91,11 +155,19 @@ impl App {
     }
 
     /// Refresh the status view
+    ///
+    /// Also drops the file preview cache: the working copy may have changed.
     pub fn refresh_status(&mut self) {
+        self.status_preview_cache.clear();
+        self.status_preview_pending = None;
         match self.jj.status() {
             Ok(status) => {
                 self.status_view.set_status(status);
                 self.error_message = None;
+                if self.status_view.show_ignored() {
+                    self.refresh_ignored_paths();
+                }
+                self.update_status_preview_if_needed();
             }
             Err(e) => {
                 self.set_error(format!("jj status error: {}", e));
@@ -103,12 +175,76 @@ impl App {
         }
     }
 
+    /// Refresh after an operation that only touched `path` in the working copy
+    ///
+    /// From the Status View only that file's row and cached diff are
+    /// reloaded ([`Self::refresh_status_file`]); the log is marked dirty and
+    /// reloads when it is shown next.
+    pub(crate) fn refresh_after_file_change(&mut self, path: &str) {
+        if self.current_view != View::Status {
+            self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+            return;
+        }
+        self.mark_dirty_and_refresh_current(DirtyFlags::log());
+        self.refresh_status_file(path);
+    }
+
+    /// Reload one Status View row and its cached diff
+    ///
+    /// Runs `jj diff --summary -r @ <path>` instead of a full `jj status`,
+    /// which can take seconds in large repos. Conflicted and renamed rows
+    /// (the summary shows neither the conflict nor the other side of the
+    /// rename), unknown paths and errors fall back to [`Self::refresh_status`].
+    pub(crate) fn refresh_status_file(&mut self, path: &str) {
+        let scoped = self.status_view.file_state(path).is_some_and(|state| {
+            !matches!(state, FileState::Conflicted | FileState::Renamed { .. })
+        });
+        if !scoped {
+            self.refresh_status();
+            return;
+        }
+        match self.jj.diff_summary_file("@", path) {
+            Ok(files) => {
+                let entry = files.into_iter().find(|f| f.path == path);
+                self.status_view.replace_file(path, entry);
+                self.status_preview_cache.remove(path);
+                if self.status_preview_pending.as_deref() == Some(path) {
+                    self.status_preview_pending = None;
+                }
+                self.update_status_preview_if_needed();
+            }
+            Err(_) => self.refresh_status(),
+        }
+    }
+
+    /// Scan the workspace for ignored paths (Status View `I`)
+    pub(crate) fn refresh_ignored_paths(&mut self) {
+        let root = match self.jj.workspace_root() {
+            Ok(root) => root,
+            Err(e) => {
+                self.set_error(format!("Failed to locate workspace root: {}", e));
+                return;
+            }
+        };
+        let tracked = match self.jj.file_list() {
+            Ok(tracked) => tracked,
+            Err(e) => {
+                self.set_error(format!("jj file list error: {}", e));
+                return;
+            }
+        };
+        let untracked = self.status_view.untracked_paths();
+        let ignored = ignored_paths(Path::new(&root), &tracked, &untracked);
+        self.status_view.set_ignored(ignored);
+    }
+
     /// Refresh the operation history view
     pub fn refresh_operation_log(&mut self) {
         match self.jj.op_log(Some(50)) {
             Ok(operations) => {
                 self.operation_view.set_operations(operations);
                 self.error_message = None;
+                self.update_op_preview_if_needed();
             }
             Err(e) => {
                 self.set_error(format!("jj op log error: {}", e));
@@ -116,6 +252,388 @@ impl App {
         }
     }
 
+    /// Poll the op log while the Operation View is in follow mode
+    ///
+    /// Called on idle ticks. New operations are merged into the view without
+    /// moving the selection. Since the repo changed underneath us, the other
+    /// views are marked dirty so they reload on navigation.
+    pub fn poll_operation_follow(&mut self) {
+        let now = Instant::now();
+        if self.current_view != View::Operation || !self.operation_view.follow_poll_due(now) {
+            return;
+        }
+        self.operation_view.mark_polled(now);
+
+        match self.jj.op_log(Some(50)) {
+            Ok(operations) => {
+                let added = self.operation_view.merge_operations(operations);
+                if added > 0 {
+                    self.dirty.log = true;
+                    self.dirty.status = true;
+                    self.dirty.bookmarks = true;
+                    self.dirty.resolve_list = true;
+                    self.preview_cache.clear();
+                    self.notify_info(format!("{} new operation(s)", added));
+                }
+            }
+            Err(e) => {
+                self.operation_view.follow = false;
+                self.set_error(format!("Follow mode stopped: {}", e));
+            }
+        }
+    }
+
+    /// Apply `tij.command-log-file`: append every jj invocation to that file
+    ///
+    /// A leading `~/` is expanded to `$HOME`.
+    pub(crate) fn load_command_log_file(&mut self) {
+        let path = self
+            .jj
+            .config_get(config_keys::COMMAND_LOG_FILE)
+            .ok()
+            .flatten()
+            .map(|value| value.trim_matches('"').to_string())
+            .filter(|value| !value.is_empty())
+            .map(
+                |value| match (value.strip_prefix("~/"), std::env::var("HOME")) {
+                    (Some(rest), Ok(home)) => Path::new(&home).join(rest),
+                    _ => PathBuf::from(value),
+                },
+            );
+        self.jj.command_log().set_file(path);
+    }
+
+    /// Apply `tij.id-length` (minimum change/commit ID display length)
+    ///
+    /// Invalid values are ignored; out-of-range values are clamped. The
+    /// executor gets the same length for the `shortest(<len>)` templates.
+    pub(crate) fn load_id_length(&mut self) {
+        if let Some(len) = self
+            .jj
+            .config_get(config_keys::ID_LENGTH)
+            .ok()
+            .flatten()
+            .and_then(|value| value.parse().ok())
+        {
+            self.jj.set_id_length(len);
+            self.log_view.id_length = self.jj.id_length();
+            self.id_length = self.jj.id_length();
+        }
+    }
+
+    /// Apply `tij.timestamp-format` and `tij.timestamp-utc` to timestamp display
+    pub(crate) fn load_timestamp_settings(&mut self) {
+        let config = |key| self.jj.config_get(key).ok().flatten();
+        let pattern = config(config_keys::TIMESTAMP_FORMAT);
+        let utc = config(config_keys::TIMESTAMP_UTC).is_some_and(|value| value == "true");
+        self.timestamp_format = TimestampFormat::new(pattern, utc);
+        self.log_view.timestamp_format = self.timestamp_format.clone();
+    }
+
+    /// Apply `tij.scroll-off` and `tij.center-cursor` to Log View scrolling
+    ///
+    /// Invalid values are ignored.
+    pub(crate) fn load_scroll_settings(&mut self) {
+        if let Some(margin) = self
+            .jj
+            .config_get(config_keys::SCROLL_OFF)
+            .ok()
+            .flatten()
+            .and_then(|value| value.parse().ok())
+        {
+            self.log_view.scroll_off = margin;
+        }
+        self.log_view.center_cursor = self
+            .jj
+            .config_get(config_keys::CENTER_CURSOR)
+            .ok()
+            .flatten()
+            .is_some_and(|value| value == "true");
+    }
+
+    /// Follow jj's `ui.graph.style` (unknown values keep the default)
+    pub(crate) fn load_graph_style(&mut self) {
+        if let Some(style) = self
+            .jj
+            .config_get(config_keys::GRAPH_STYLE)
+            .ok()
+            .flatten()
+            .and_then(|value| GraphStyle::from_config(&value))
+        {
+            self.log_view.graph_style = style;
+        }
+    }
+
+    /// Follow jj's `revsets.log` for the unfiltered log
+    pub(crate) fn load_default_log_revset(&mut self) {
+        if let Some(revset) = self.jj.config_get(config_keys::LOG_REVSET).ok().flatten() {
+            self.default_log_revset = revset;
+        }
+    }
+
+    /// Apply `tij.show-commit-ids` (Log View commit ID column)
+    pub(crate) fn load_commit_id_column(&mut self) {
+        self.log_view.show_commit_ids = self
+            .jj
+            .config_get(config_keys::SHOW_COMMIT_IDS)
+            .ok()
+            .flatten()
+            .is_some_and(|value| value == "true");
+    }
+
+    /// Apply `tij.keymap` (`vim` or `arrows`); an unknown preset keeps vim
+    pub(crate) fn load_keymap_preset(&mut self) {
+        let Some(name) = self.jj.config_get(config_keys::KEYMAP).ok().flatten() else {
+            return;
+        };
+        match keys::KeymapPreset::parse(name.trim_matches('"')) {
+            Ok(preset) => self.keymap = preset,
+            Err(e) => self.notify_warning(format!("Ignoring tij.keymap: {}", e)),
+        }
+    }
+
+    /// Apply the `[tij.keys]` remaps; invalid entries are reported and skipped
+    pub(crate) fn load_key_remaps(&mut self) {
+        let entries = self
+            .jj
+            .config_strings(config_keys::KEYS)
+            .unwrap_or_default();
+        let mut remaps = Vec::new();
+        for (name, target) in entries {
+            match keys::KeyRemap::parse(&name, &target) {
+                Ok(remap) => remaps.push(remap),
+                Err(e) => self.notify_warning(format!("Ignoring tij.keys.{}: {}", name, e)),
+            }
+        }
+        self.key_remaps = remaps;
+    }
+
+    /// Apply `tij.log-row` (Log View columns and the fields `jj log` fetches)
+    ///
+    /// An invalid layout is reported and the default row kept. Listing
+    /// `commit_id` shows that column from the start (`%` still toggles it).
+    pub(crate) fn load_log_row(&mut self) {
+        let Some(spec) = self.jj.config_get(config_keys::LOG_ROW).ok().flatten() else {
+            return;
+        };
+        match LogRow::parse(spec.trim_matches('"')) {
+            Ok(row) => {
+                if row.has_commit_id() {
+                    self.log_view.show_commit_ids = true;
+                }
+                self.jj.set_log_row(row.clone());
+                self.log_view.row_layout = row;
+            }
+            Err(e) => self.notify_warning(format!("Ignoring tij.log-row: {}", e)),
+        }
+    }
+
+    /// Load the Log preview split from `tij.preview-layout` / `tij.preview-ratio`
+    pub(crate) fn load_preview_layout(&mut self) {
+        let direction = self
+            .jj
+            .config_get(config_keys::PREVIEW_LAYOUT)
+            .ok()
+            .flatten()
+            .and_then(|value| SplitDirection::parse(value.trim_matches('"')))
+            .unwrap_or_default();
+        let ratio = self
+            .jj
+            .config_get(config_keys::PREVIEW_RATIO)
+            .ok()
+            .flatten()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(SplitLayout::DEFAULT_RATIO);
+        self.preview_layout = SplitLayout::new(direction, ratio);
+        self.saved_preview_layout = self.preview_layout;
+    }
+
+    /// Load the terminal size that switches to the compact tabbed layout
+    pub(crate) fn load_compact_layout(&mut self) {
+        let Some(value) = self
+            .jj
+            .config_get(config_keys::COMPACT_LAYOUT)
+            .ok()
+            .flatten()
+        else {
+            return;
+        };
+        match CompactLayout::parse(value.trim_matches('"')) {
+            Ok(layout) => self.compact_layout = layout,
+            Err(e) => self.notify_warning(format!("Ignoring tij.compact-layout: {}", e)),
+        }
+    }
+
+    /// Write the Log preview split to user config if it changed this session
+    ///
+    /// Called on quit, so failures are silent: the next session simply
+    /// starts from the previous layout.
+    pub(crate) fn save_preview_layout(&mut self) {
+        let layout = self.preview_layout;
+        if layout == self.saved_preview_layout {
+            return;
+        }
+        if layout.direction != self.saved_preview_layout.direction {
+            let value = format!("\"{}\"", layout.direction.as_str());
+            let _ = self.jj.config_set_user(config_keys::PREVIEW_LAYOUT, &value);
+        }
+        if layout.ratio() != self.saved_preview_layout.ratio() {
+            let _ = self
+                .jj
+                .config_set_user(config_keys::PREVIEW_RATIO, &layout.ratio().to_string());
+        }
+        self.saved_preview_layout = layout;
+    }
+
+    /// Take the `.jj/tij.pid` marker, or warn that another instance runs
+    ///
+    /// With `tij.second-instance = "read-only"` this instance then refuses
+    /// repository-modifying commands, so two instances don't refresh against
+    /// each other's writes or interleave undo chains.
+    pub(crate) fn acquire_instance_lock(&mut self) {
+        let Ok(root) = self.jj.workspace_root() else {
+            return;
+        };
+        match InstanceLock::acquire(Path::new(&root)) {
+            Ok(LockOutcome::Acquired(lock)) => self.instance_lock = Some(lock),
+            Ok(LockOutcome::Held { pid }) => {
+                let read_only = self
+                    .jj
+                    .config_get(config_keys::SECOND_INSTANCE)
+                    .ok()
+                    .flatten()
+                    .is_some_and(|value| value.trim_matches('"') == "read-only");
+                self.enter_second_instance_mode(pid, read_only);
+            }
+            // Marker can't be written (read-only filesystem, ...): no detection
+            Err(_) => {}
+        }
+    }
+
+    /// Detect a colocated git repository and load its sync state
+    pub(crate) fn load_git_colocation(&mut self) {
+        self.colocated = self
+            .jj
+            .workspace_root()
+            .is_ok_and(|root| git_sync::is_colocated(Path::new(&root)));
+        self.refresh_git_sync();
+    }
+
+    /// Recompute which bookmarks differ between jj and git (colocated only)
+    pub(crate) fn refresh_git_sync(&mut self) {
+        if !self.colocated {
+            return;
+        }
+        if let Ok(bookmarks) = self.jj.bookmark_list_all() {
+            self.apply_git_sync(&bookmarks);
+        }
+    }
+
+    /// Show the jj/git divergence of `bookmarks` in the Log and Remote View
+    pub(crate) fn apply_git_sync(&mut self, bookmarks: &[Bookmark]) {
+        if !self.colocated {
+            return;
+        }
+        let diverged = git_sync::git_ref_divergence(bookmarks);
+        self.log_view.git_diverged = diverged.len();
+        self.remote_view.set_git_sync(Some(diverged));
+    }
+
+    /// Warn about the other instance and optionally go read-only
+    pub(crate) fn enter_second_instance_mode(&mut self, pid: u32, read_only: bool) {
+        if read_only {
+            self.jj.set_read_only(true);
+            self.log_view.read_only = true;
+            self.notify_warning(format!(
+                "Another tij (pid {}) is running on this workspace: read-only mode",
+                pid
+            ));
+        } else {
+            self.notify_warning(format!(
+                "Another tij (pid {}) is running on this workspace: refreshes and undo may interleave",
+                pid
+            ));
+        }
+    }
+
+    /// Start the working-copy watcher if `tij.auto-refresh` is enabled
+    ///
+    /// Failures are reported but non-fatal: manual refresh keeps working.
+    pub(crate) fn start_fs_watcher(&mut self) {
+        let enabled = self
+            .jj
+            .config_get(config_keys::AUTO_REFRESH)
+            .ok()
+            .flatten()
+            .is_some_and(|value| value == "true");
+        if !enabled {
+            return;
+        }
+
+        let debounce = self
+            .jj
+            .config_get(config_keys::AUTO_REFRESH_DEBOUNCE_MS)
+            .ok()
+            .flatten()
+            .and_then(|value| value.parse().ok())
+            .map_or(DEFAULT_DEBOUNCE, Duration::from_millis);
+
+        let root = match self.jj.workspace_root() {
+            Ok(root) => PathBuf::from(root),
+            Err(e) => {
+                self.set_error(format!("Auto-refresh disabled: {}", e));
+                return;
+            }
+        };
+        match FsWatcher::start(root, debounce) {
+            Ok(watcher) => self.fs_watcher = Some(watcher),
+            Err(e) => self.set_error(format!("Auto-refresh disabled: {}", e)),
+        }
+    }
+
+    /// Refresh Status/Log after the working copy changed on disk
+    ///
+    /// Handles `AppEvent::FsChanged`. A short "Working copy changed" notice
+    /// is shown unless another notification is still visible.
+    pub(crate) fn on_fs_changed(&mut self) {
+        // A snapshot keeps change IDs stable, so the cursor can be restored
+        let selected = self
+            .log_view
+            .selected_change()
+            .map(|c| c.change_id.to_string());
+        self.mark_dirty_and_refresh_current(DirtyFlags::log_and_status());
+        if let Some(change_id) = selected {
+            self.log_view.select_change_by_id(&change_id);
+        }
+        if self.notification.as_ref().is_none_or(|n| n.is_expired()) {
+            self.notify_info("Working copy changed");
+        }
+    }
+
+    /// Load the conflict preview for the selected file in the resolve view
+    ///
+    /// No-op if the preview for the selected file is already loaded.
+    pub(crate) fn refresh_resolve_preview(&mut self) {
+        let Some(ref view) = self.resolve_view else {
+            return;
+        };
+        let Some(path) = view.preview_target().map(str::to_string) else {
+            return;
+        };
+        let revision = view.revision.clone();
+
+        let lines = match self.jj.file_show(&path, Some(&revision)) {
+            Ok(content) => Parser::parse_conflict_markers(&content),
+            Err(e) => vec![ConflictLine {
+                side: ConflictSide::Context,
+                content: format!("Failed to load file: {}", e),
+            }],
+        };
+        if let Some(ref mut view) = self.resolve_view {
+            view.set_preview(path, lines);
+        }
+    }
+
     /// Refresh the resolve list for the current resolve view
     pub(crate) fn refresh_resolve_list(&mut self, revision: &str, is_working_copy: bool) {
         match self.jj.resolve_list(Some(revision)) {
@@ -124,10 +642,9 @@ impl App {
                     // All resolved - go back (simple message for Log View title bar)
                     self.notify_success("All conflicts resolved!");
                     self.resolve_view = None;
+                    // Reload the log on return to update conflict indicators
+                    self.dirty.log = true;
                     self.go_back();
-                    // Refresh log to update conflict indicators
-                    let revset = self.log_view.current_revset.clone();
-                    self.refresh_log(revset.as_deref());
                 } else if let Some(ref mut view) = self.resolve_view {
                     view.set_files(files);
                 } else {
@@ -145,9 +662,8 @@ impl App {
                     // All resolved - simple message for Log View title bar
                     self.notify_success("All conflicts resolved!");
                     self.resolve_view = None;
+                    self.dirty.log = true;
                     self.go_back();
-                    let revset = self.log_view.current_revset.clone();
-                    self.refresh_log(revset.as_deref());
                 } else {
                     self.set_error(format!("Failed to refresh conflicts: {}", e));
                 }
@@ -155,6 +671,18 @@ impl App {
         }
     }
 
+    /// Reload the conflict list of the open resolve view, if any
+    pub(crate) fn refresh_open_resolve_list(&mut self) {
+        let Some((revision, is_wc)) = self
+            .resolve_view
+            .as_ref()
+            .map(|v| (v.revision.clone(), v.is_working_copy))
+        else {
+            return;
+        };
+        self.refresh_resolve_list(&revision, is_wc);
+    }
+
     /// Execute refresh for current view (Ctrl+L)
     ///
     /// Force-refreshes the data for the current view and clears only that
@@ -169,6 +697,7 @@ impl App {
                 self.refresh_log(revset.as_deref());
                 self.dirty.log = false;
                 self.update_preview_if_needed();
+                self.refresh_git_sync();
                 self.notify_info("Refreshed");
             }
             View::Status => {
@@ -208,11 +737,9 @@ impl App {
                 // If diff_view is None, do nothing (no notification)
             }
             View::Resolve => {
-                // Refresh resolve list
-                if let Some(ref resolve_view) = self.resolve_view {
-                    let revision = resolve_view.revision.clone();
-                    let is_wc = resolve_view.is_working_copy;
-                    self.refresh_resolve_list(&revision, is_wc);
+                if self.resolve_view.is_some() {
+                    self.dirty.resolve_list = false;
+                    self.refresh_open_resolve_list();
                     self.notify_info("Refreshed");
                 }
             }
@@ -241,6 +768,16 @@ impl App {
                     }
                 }
             }
+            View::FileLog => {
+                if let Some(ref file_log_view) = self.file_log_view {
+                    let file_path = file_log_view.file_path.clone();
+                    let revision = file_log_view.revision.clone();
+                    self.open_file_log(&file_path, &revision);
+                    if self.error_message.is_none() {
+                        self.notify_info("Refreshed");
+                    }
+                }
+            }
             View::Tag => {
                 self.refresh_tag_view();
                 self.notify_info("Refreshed");
@@ -249,8 +786,43 @@ impl App {
                 self.refresh_workspace_view();
                 self.notify_info("Refreshed");
             }
-            View::CommandHistory => {
-                // Command history is in-memory data, no external refresh needed
+            View::Remote => {
+                self.refresh_remote_view();
+                self.notify_info("Refreshed");
+            }
+            View::Pager => {
+                self.refresh_pager_view();
+                if self.error_message.is_none() {
+                    self.notify_info("Refreshed");
+                }
+            }
+            View::Sparse => {
+                self.refresh_sparse_view();
+                self.notify_info("Refreshed");
+            }
+            View::Stack => {
+                self.refresh_stack_view();
+                if self.error_message.is_none() {
+                    self.notify_info("Refreshed");
+                }
+            }
+            View::Diagnostics => {
+                self.refresh_diagnostics_view();
+                self.notify_info("Refreshed");
+            }
+            View::CommandHistory | View::CommandLog => {
+                // Command history and log are in-memory data, no external refresh needed
+            }
+            View::OpHeads => {
+                if self.op_heads_view.is_some() {
+                    self.open_op_heads_view();
+                    if self.error_message.is_none() && self.current_view == View::OpHeads {
+                        self.notify_info("Refreshed");
+                    }
+                }
+            }
+            View::Merge => {
+                // Reloading would discard the choices made so far
             }
             View::Help => {
                 // Help is static content, no refresh needed, no notification
@@ -1,5 +1,7 @@
 //! Rendering logic for the application
 
+use std::collections::HashMap;
+
 use ratatui::{
     Frame,
     prelude::*,
@@ -8,12 +10,17 @@ use ratatui::{
 
 use super::state::{App, View};
 use crate::app::helpers::revision::short_id;
-use crate::keys::{self, BookmarkKind, DialogHintKind, HintContext};
-use crate::model::{DiffContent, DiffLineKind, FileOperation};
+use crate::keys::{self, BookmarkKind, DialogHintKind, HintContext, KeyHint};
+use crate::model::{
+    Change, DiffContent, DiffLine, DiffLineKind, FileOperation, FileState, TimestampFormat,
+    TrackingCounts,
+};
+use crate::ui::components::compact;
 use crate::ui::components::dialog::DialogKind;
 use crate::ui::widgets::{
-    render_blame_status_bar, render_diff_status_bar, render_error_banner, render_help_panel,
-    render_placeholder, render_status_hints, status_hints_height,
+    fit_hints, render_blame_status_bar, render_diff_status_bar, render_error_banner,
+    render_help_overlay, render_placeholder, render_status_hints, render_suggestion_banner,
+    status_hints_height,
 };
 
 impl App {
@@ -26,26 +33,26 @@ impl App {
             .filter(|n| !n.is_expired())
             .cloned();
 
+        // Log/Status/Bookmarks become tabs of one pane on small terminals
+        self.compact = self
+            .compact_layout
+            .is_some_and(|layout| layout.applies(frame.area()));
+
         // Render main view (notification is passed to views for title bar display)
-        match self.current_view {
-            View::Log => self.render_log_view(frame, notification.as_ref()),
-            View::Diff => self.render_diff_view(frame, notification.as_ref()),
-            View::Status => self.render_status_view(frame, notification.as_ref()),
-            View::Operation => self.render_operation_view(frame, notification.as_ref()),
-            View::Blame => self.render_blame_view(frame, notification.as_ref()),
-            View::Resolve => self.render_resolve_view(frame, notification.as_ref()),
-            View::Bookmark => self.render_bookmark_view(frame, notification.as_ref()),
-            View::Tag => self.render_tag_view(frame, notification.as_ref()),
-            View::Workspace => self.render_workspace_view(frame, notification.as_ref()),
-            View::Evolog => self.render_evolog_view(frame, notification.as_ref()),
-            View::CommandHistory => self.render_command_history_view(frame, notification.as_ref()),
-            View::Help => self.render_help_view(frame),
-        }
+        self.render_view(frame, self.current_view, notification.as_ref());
 
-        // Render error banner above status bar (errors are always shown prominently)
-        if let Some(ref error) = self.error_message {
+        // Render error banner above status bar (hidden with the bars while fullscreen)
+        if let Some(ref error) = self.error_message
+            && !self.fullscreen
+        {
             let status_bar_height = self.get_current_status_bar_height(frame.area().width);
             render_error_banner(frame, error, status_bar_height);
+        } else if self.current_view == View::Log
+            && !self.fullscreen
+            && let Some(suggestion) = self.rebase_suggestion_text()
+        {
+            let status_bar_height = self.get_current_status_bar_height(frame.area().width);
+            render_suggestion_banner(frame, &suggestion, status_bar_height);
         }
 
         // Render dialog on top of everything
@@ -54,8 +61,116 @@ impl App {
         }
     }
 
+    /// Render `view` full screen
+    ///
+    /// Help is an overlay: the view it was opened from is drawn beneath.
+    fn render_view(
+        &mut self,
+        frame: &mut Frame,
+        view: View,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        match view {
+            View::Log => self.render_log_view(frame, notification),
+            View::Diff => self.render_diff_view(frame, notification),
+            View::Status => self.render_status_view(frame, notification),
+            View::Operation => self.render_operation_view(frame, notification),
+            View::Blame => self.render_blame_view(frame, notification),
+            View::Resolve => self.render_resolve_view(frame, notification),
+            View::Bookmark => self.render_bookmark_view(frame, notification),
+            View::Tag => self.render_tag_view(frame, notification),
+            View::Workspace => self.render_workspace_view(frame, notification),
+            View::Remote => self.render_remote_view(frame, notification),
+            View::Sparse => self.render_sparse_view(frame, notification),
+            View::Pager => self.render_pager_view(frame, notification),
+            View::Stack => self.render_stack_view(frame, notification),
+            View::Evolog => self.render_evolog_view(frame, notification),
+            View::FileLog => self.render_file_log_view(frame, notification),
+            View::Merge => self.render_merge_view(frame, notification),
+            View::OpHeads => self.render_op_heads_view(frame, notification),
+            View::CommandHistory => self.render_command_history_view(frame, notification),
+            View::CommandLog => self.render_command_log_view(frame, notification),
+            View::Diagnostics => self.render_diagnostics_view(frame, notification),
+            View::Help => {
+                let context = self.help_context();
+                if let Some(context) = context {
+                    self.render_view(frame, context, None);
+                }
+                self.render_help_view(frame, context);
+            }
+        }
+    }
+
+    /// Area left for the view above a status bar of `sb_height` rows
+    ///
+    /// While fullscreen the bar is hidden and the view gets the whole area.
+    fn view_area(&self, area: Rect, sb_height: u16) -> Rect {
+        let sb_height = if self.fullscreen { 0 } else { sb_height };
+        Rect {
+            x: area.x,
+            y: area.y,
+            width: area.width,
+            height: area.height.saturating_sub(sb_height),
+        }
+    }
+
+    /// Render the status bar hints unless fullscreen
+    fn render_hints(&self, frame: &mut Frame, hints: &[KeyHint]) {
+        if !self.fullscreen {
+            render_status_hints(frame, hints, &self.status_text());
+        }
+    }
+
+    /// Whether `view` is drawn as a tab of the compact layout
+    fn is_compact_tab(&self, view: View) -> bool {
+        self.compact && compact::is_tab(view)
+    }
+
+    /// Status bar hints of `view`: one row of them in the compact layout
+    fn layout_hints<'a>(&self, view: View, hints: &'a [KeyHint], width: u16) -> &'a [KeyHint] {
+        if self.is_compact_tab(view) {
+            fit_hints(hints, width)
+        } else {
+            hints
+        }
+    }
+
+    /// Area of `view` below the tab bar in the compact layout
+    fn tab_area(&self, frame: &mut Frame, area: Rect, view: View) -> Rect {
+        if self.is_compact_tab(view) && !self.fullscreen {
+            compact::render_tab_bar(frame, area, view)
+        } else {
+            area
+        }
+    }
+
+    /// Split `main_area` into the list and the preview pane (if shown)
+    ///
+    /// Where the split doesn't fit the preview is turned off. The compact
+    /// layout shows the list, or the preview in its place after `p`.
+    fn preview_panes(&mut self, view: View, main_area: Rect) -> (Option<Rect>, Option<Rect>) {
+        let compact = self.is_compact_tab(view);
+        let fits = if compact {
+            self.compact_preview
+        } else {
+            self.preview_layout.fits(main_area)
+        };
+        self.preview_auto_disabled = self.fullscreen || !fits;
+        if !self.preview_enabled || self.preview_auto_disabled {
+            (Some(main_area), None)
+        } else if compact {
+            (None, Some(main_area))
+        } else {
+            let (list_area, preview_area) = self.preview_layout.split(main_area);
+            (Some(list_area), Some(preview_area))
+        }
+    }
+
     /// Get the status bar height for the current view
     fn get_current_status_bar_height(&self, width: u16) -> u16 {
+        if self.is_compact_tab(self.current_view) {
+            return 1;
+        }
         match self.current_view {
             View::Log | View::Status | View::Operation => {
                 let ctx = self.build_hint_context();
@@ -67,7 +182,15 @@ impl App {
                 let hints = keys::current_hints(View::Bookmark, self.log_view.input_mode, &ctx);
                 status_hints_height(&hints, width)
             }
-            View::Tag | View::Workspace => {
+            View::Tag
+            | View::Workspace
+            | View::Remote
+            | View::Sparse
+            | View::Pager
+            | View::Diagnostics
+            | View::Stack
+            | View::Merge
+            | View::OpHeads => {
                 let ctx = keys::HintContext::default();
                 let hints = keys::current_hints(self.current_view, self.log_view.input_mode, &ctx);
                 status_hints_height(&hints, width)
@@ -77,13 +200,12 @@ impl App {
                 let hints = keys::current_hints(View::Resolve, self.log_view.input_mode, &ctx);
                 status_hints_height(&hints, width)
             }
-            View::CommandHistory => {
+            View::CommandHistory | View::CommandLog => {
                 let ctx = keys::HintContext::default();
-                let hints =
-                    keys::current_hints(View::CommandHistory, self.log_view.input_mode, &ctx);
+                let hints = keys::current_hints(self.current_view, self.log_view.input_mode, &ctx);
                 status_hints_height(&hints, width)
             }
-            View::Evolog | View::Diff => 1,
+            View::Evolog | View::FileLog | View::Diff => 1,
             View::Blame => status_hints_height(keys::BLAME_VIEW_HINTS, width),
             View::Help => 0,
         }
@@ -99,6 +221,7 @@ impl App {
             skip_emptied: self.log_view.skip_emptied,
             simplify_parents: self.log_view.simplify_parents,
             rebase_mode: self.log_view.rebase_mode,
+            keymap: self.keymap,
             dialog: self.dialog_hint_kind(),
             ..HintContext::default()
         }
@@ -126,6 +249,7 @@ impl App {
             } => DialogHintKind::SingleSelect,
             DialogKind::Select { .. } => DialogHintKind::Select,
             DialogKind::Input { .. } => DialogHintKind::Confirm,
+            DialogKind::Results { .. } => DialogHintKind::Results,
         })
     }
 
@@ -137,33 +261,24 @@ impl App {
         let area = frame.area();
         let ctx = self.build_hint_context();
         let hints = keys::current_hints(View::Log, self.log_view.input_mode, &ctx);
-        let sb_height = status_hints_height(&hints, area.width);
+        let hints = self.layout_hints(View::Log, &hints, area.width);
+        let sb_height = status_hints_height(hints, area.width);
 
         // Reserve space for status bar at bottom
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
-        };
-
-        // Auto-disable preview for small terminals (does not modify preview_enabled)
-        self.preview_auto_disabled = main_area.height < 20;
-
-        let preview_active = self.preview_enabled && !self.preview_auto_disabled;
-
-        if preview_active {
-            // Split: log (top 50%) / preview (bottom 50%)
-            let chunks = Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .split(main_area);
-
-            self.log_view.render(frame, chunks[0], notification);
-            self.render_preview_pane(frame, chunks[1]);
-        } else {
-            self.log_view.render(frame, main_area, notification);
+        let main_area = self.view_area(area, sb_height);
+        let main_area = self.tab_area(frame, main_area, View::Log);
+
+        // Split: log / preview (bottom or right, sized by `<`/`>`); auto-disabled
+        // for small terminals and fullscreen (does not modify preview_enabled)
+        let (log_area, preview_area) = self.preview_panes(View::Log, main_area);
+        if let Some(log_area) = log_area {
+            self.log_view.render(frame, log_area, notification);
+        }
+        if let Some(preview_area) = preview_area {
+            self.render_preview_pane(frame, preview_area);
         }
 
-        render_status_hints(frame, &hints);
+        self.render_hints(frame, hints);
     }
 
     fn render_preview_pane(&self, frame: &mut Frame, area: Rect) {
@@ -176,7 +291,7 @@ impl App {
 
         let title = match cached {
             Some(entry) => {
-                let commit_short = short_id(entry.content.commit_id.as_str());
+                let commit_short = short_id(entry.content.commit_id.as_str(), self.id_length);
                 format!(" Preview: {} ({}) ", &entry.change_id, commit_short)
             }
             None => " Preview ".to_string(),
@@ -189,9 +304,18 @@ impl App {
         match cached {
             Some(entry) => {
                 let inner = block.inner(area);
+                // Badges come from the log row, which matches the cached entry
+                let change = self
+                    .log_view
+                    .selected_change()
+                    .filter(|c| c.commit_id.as_str() == entry.content.commit_id.as_str());
                 let lines = build_preview_lines(
                     &entry.content,
+                    &self.timestamp_format,
                     &entry.bookmarks,
+                    change,
+                    self.log_view.notes.get(&entry.change_id),
+                    &self.log_view.bookmark_tracking,
                     inner.height as usize,
                     inner.width as usize,
                 );
@@ -210,17 +334,9 @@ impl App {
         frame: &mut Frame,
         notification: Option<&crate::model::Notification>,
     ) {
+        // Reserve space for status bar at bottom
+        let main_area = self.view_area(frame.area(), 1);
         if let Some(ref mut diff_view) = self.diff_view {
-            let area = frame.area();
-
-            // Reserve space for status bar at bottom
-            let main_area = Rect {
-                x: area.x,
-                y: area.y,
-                width: area.width,
-                height: area.height.saturating_sub(1),
-            };
-
             // Mirror DiffView's layout so scroll bounds stay accurate as the
             // header grows/shrinks with description length or the expand
             // toggle. Re-clamp scroll_offset *before* render so a previously
@@ -231,7 +347,9 @@ impl App {
             self.last_frame_height.set(diff_content_height);
 
             diff_view.render(frame, main_area, notification);
-            render_diff_status_bar(frame, diff_view);
+            if !self.fullscreen {
+                render_diff_status_bar(frame, diff_view);
+            }
         } else {
             render_placeholder(
                 frame,
@@ -243,34 +361,75 @@ impl App {
     }
 
     fn render_status_view(
-        &self,
+        &mut self,
         frame: &mut Frame,
         notification: Option<&crate::model::Notification>,
     ) {
         let area = frame.area();
         let ctx = self.build_hint_context();
         let hints = keys::current_hints(View::Status, self.log_view.input_mode, &ctx);
-        let sb_height = status_hints_height(&hints, area.width);
+        let hints = self.layout_hints(View::Status, &hints, area.width);
+        let sb_height = status_hints_height(hints, area.width);
 
         // Reserve space for status bar at bottom
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
-        };
+        let main_area = self.view_area(area, sb_height);
+        let main_area = self.tab_area(frame, main_area, View::Status);
+
+        // File diff preview shares the Log View toggle and split layout
+        let (list_area, preview_area) = self.preview_panes(View::Status, main_area);
 
         // Store visible height for file list (2 borders + 3 header lines)
         // This is used by key handling for accurate scroll bounds
-        let file_list_height = main_area.height.saturating_sub(5);
+        let file_list_height = list_area.unwrap_or(main_area).height.saturating_sub(5);
         self.last_frame_height.set(file_list_height);
 
-        self.status_view.render(frame, main_area, notification);
-        render_status_hints(frame, &hints);
+        if let Some(list_area) = list_area {
+            self.status_view.render(frame, list_area, notification);
+        }
+        if let Some(preview_area) = preview_area {
+            self.render_status_preview_pane(frame, preview_area);
+        }
+        self.render_hints(frame, hints);
+    }
+
+    fn render_status_preview_pane(&self, frame: &mut Frame, area: Rect) {
+        let selected_path = self.status_view.selected_file_path();
+        let cached = selected_path.and_then(|path| self.status_preview_cache.peek(path));
+
+        let title = match selected_path {
+            Some(path) => format!(" Preview: {} ", path),
+            None => " Preview ".to_string(),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Line::from(title).bold().cyan());
+
+        let untracked_state = self
+            .status_view
+            .selected_file()
+            .map(|f| &f.state)
+            .filter(|state| !state.is_tracked());
+        let paragraph = match cached {
+            _ if untracked_state == Some(&FileState::Untracked) => {
+                Paragraph::new("  Untracked: not part of @ (i to ignore)").block(block)
+            }
+            _ if untracked_state == Some(&FileState::Ignored) => {
+                Paragraph::new("  Ignored by .gitignore").block(block)
+            }
+            Some(entry) if entry.content.lines.is_empty() => {
+                Paragraph::new("  No textual changes").block(block)
+            }
+            Some(entry) => {
+                let max_lines = block.inner(area).height as usize;
+                Paragraph::new(build_file_preview_lines(&entry.content, max_lines)).block(block)
+            }
+            None => Paragraph::new("  No preview available").block(block),
+        };
+        frame.render_widget(paragraph, area);
     }
 
     fn render_operation_view(
-        &self,
+        &mut self,
         frame: &mut Frame,
         notification: Option<&crate::model::Notification>,
     ) {
@@ -280,15 +439,44 @@ impl App {
         let sb_height = status_hints_height(&hints, area.width);
 
         // Reserve space for status bar at bottom
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
+        let main_area = self.view_area(area, sb_height);
+
+        // `jj op show` preview shares the Log View toggle and split layout
+        self.preview_auto_disabled = self.fullscreen || !self.preview_layout.fits(main_area);
+        let (list_area, preview_area) = if self.preview_enabled && !self.preview_auto_disabled {
+            let (list_area, preview_area) = self.preview_layout.split(main_area);
+            (list_area, Some(preview_area))
+        } else {
+            (main_area, None)
+        };
+
+        self.operation_view.render(frame, list_area, notification);
+        if let Some(preview_area) = preview_area {
+            self.render_op_preview_pane(frame, preview_area);
+        }
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_op_preview_pane(&self, frame: &mut Frame, area: Rect) {
+        let selected_id = self.operation_view.selected_operation().map(|op| &op.id);
+        let cached = selected_id.and_then(|id| self.op_preview_cache.peek(id));
+
+        let title = match selected_id {
+            Some(id) => format!(" Preview: op {} ", short_id(id, self.id_length)),
+            None => " Preview ".to_string(),
         };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Line::from(title).bold().cyan());
 
-        self.operation_view.render(frame, main_area, notification);
-        render_status_hints(frame, &hints);
+        let paragraph = match cached {
+            Some(entry) => {
+                let max_lines = block.inner(area).height as usize;
+                Paragraph::new(build_op_preview_lines(&entry.content, max_lines)).block(block)
+            }
+            None => Paragraph::new("  No preview available").block(block),
+        };
+        frame.render_widget(paragraph, area);
     }
 
     /// Build HintContext for Bookmark View (uses selected bookmark kind)
@@ -321,17 +509,14 @@ impl App {
         let area = frame.area();
         let ctx = self.build_bookmark_hint_context();
         let hints = keys::current_hints(View::Bookmark, self.log_view.input_mode, &ctx);
-        let sb_height = status_hints_height(&hints, area.width);
+        let hints = self.layout_hints(View::Bookmark, &hints, area.width);
+        let sb_height = status_hints_height(hints, area.width);
 
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
-        };
+        let main_area = self.view_area(area, sb_height);
+        let main_area = self.tab_area(frame, main_area, View::Bookmark);
 
         self.bookmark_view.render(frame, main_area, notification);
-        render_status_hints(frame, &hints);
+        self.render_hints(frame, hints);
     }
 
     fn render_tag_view(
@@ -344,15 +529,10 @@ impl App {
         let hints = keys::current_hints(View::Tag, self.log_view.input_mode, &ctx);
         let sb_height = status_hints_height(&hints, area.width);
 
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
-        };
+        let main_area = self.view_area(area, sb_height);
 
         self.tag_view.render(frame, main_area, notification);
-        render_status_hints(frame, &hints);
+        self.render_hints(frame, &hints);
     }
 
     fn render_workspace_view(
@@ -365,15 +545,94 @@ impl App {
         let hints = keys::current_hints(View::Workspace, self.log_view.input_mode, &ctx);
         let sb_height = status_hints_height(&hints, area.width);
 
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
-        };
+        let main_area = self.view_area(area, sb_height);
 
         self.workspace_view.render(frame, main_area, notification);
-        render_status_hints(frame, &hints);
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_remote_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        let area = frame.area();
+        let ctx = keys::HintContext::default();
+        let hints = keys::current_hints(View::Remote, self.log_view.input_mode, &ctx);
+        let sb_height = status_hints_height(&hints, area.width);
+
+        let main_area = self.view_area(area, sb_height);
+
+        self.remote_view.render(frame, main_area, notification);
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_pager_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        let area = frame.area();
+        let ctx = keys::HintContext::default();
+        let hints = keys::current_hints(View::Pager, self.log_view.input_mode, &ctx);
+        let sb_height = status_hints_height(&hints, area.width);
+
+        let main_area = self.view_area(area, sb_height);
+        self.last_frame_height
+            .set(main_area.height.saturating_sub(2));
+
+        self.pager_view.render(frame, main_area, notification);
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_diagnostics_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        let area = frame.area();
+        let ctx = keys::HintContext::default();
+        let hints = keys::current_hints(View::Diagnostics, self.log_view.input_mode, &ctx);
+        let sb_height = status_hints_height(&hints, area.width);
+
+        let main_area = self.view_area(area, sb_height);
+        self.last_frame_height
+            .set(main_area.height.saturating_sub(2));
+
+        self.diagnostics_view.render(frame, main_area, notification);
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_sparse_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        let area = frame.area();
+        let ctx = keys::HintContext::default();
+        let hints = keys::current_hints(View::Sparse, self.log_view.input_mode, &ctx);
+        let sb_height = status_hints_height(&hints, area.width);
+
+        let main_area = self.view_area(area, sb_height);
+
+        self.sparse_view.render(frame, main_area, notification);
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_stack_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        let area = frame.area();
+        let ctx = keys::HintContext::default();
+        let hints = keys::current_hints(View::Stack, self.log_view.input_mode, &ctx);
+        let sb_height = status_hints_height(&hints, area.width);
+
+        let main_area = self.view_area(area, sb_height);
+
+        self.stack_view.render(frame, main_area, notification);
+        self.render_hints(frame, &hints);
     }
 
     fn render_evolog_view(
@@ -393,6 +652,79 @@ impl App {
         }
     }
 
+    fn render_file_log_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        if let Some(ref file_log_view) = self.file_log_view {
+            file_log_view.render(frame, frame.area(), notification);
+        } else {
+            render_placeholder(
+                frame,
+                " Tij - File History ",
+                Color::Cyan,
+                "No file history loaded - Press q to go back",
+            );
+        }
+    }
+
+    fn render_merge_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        if let Some(ref merge_view) = self.merge_view {
+            let area = frame.area();
+            let hints = keys::current_hints(
+                View::Merge,
+                self.log_view.input_mode,
+                &HintContext::default(),
+            );
+            let sb_height = status_hints_height(&hints, area.width);
+
+            let main_area = self.view_area(area, sb_height);
+
+            merge_view.render(frame, main_area, notification);
+            self.render_hints(frame, &hints);
+        } else {
+            render_placeholder(
+                frame,
+                " Tij - Merge Editor ",
+                Color::Magenta,
+                "No file loaded - Press q to go back",
+            );
+        }
+    }
+
+    fn render_op_heads_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        if let Some(ref op_heads_view) = self.op_heads_view {
+            let area = frame.area();
+            let hints = keys::current_hints(
+                View::OpHeads,
+                self.log_view.input_mode,
+                &HintContext::default(),
+            );
+            let sb_height = status_hints_height(&hints, area.width);
+
+            let main_area = self.view_area(area, sb_height);
+
+            op_heads_view.render(frame, main_area, notification);
+            self.render_hints(frame, &hints);
+        } else {
+            render_placeholder(
+                frame,
+                " Tij - Divergent Operations ",
+                Color::Cyan,
+                "No divergence loaded - Press q to go back",
+            );
+        }
+    }
+
     fn render_command_history_view(
         &self,
         frame: &mut Frame,
@@ -403,28 +735,43 @@ impl App {
         let hints = keys::current_hints(View::CommandHistory, self.log_view.input_mode, &ctx);
         let sb_height = status_hints_height(&hints, area.width);
 
-        let main_area = Rect {
-            x: area.x,
-            y: area.y,
-            width: area.width,
-            height: area.height.saturating_sub(sb_height),
-        };
+        let main_area = self.view_area(area, sb_height);
 
         self.command_history_view
             .render(frame, main_area, &self.command_history, notification);
-        render_status_hints(frame, &hints);
+        self.render_hints(frame, &hints);
+    }
+
+    fn render_command_log_view(
+        &self,
+        frame: &mut Frame,
+        notification: Option<&crate::model::Notification>,
+    ) {
+        let area = frame.area();
+        let ctx = keys::HintContext::default();
+        let hints = keys::current_hints(View::CommandLog, self.log_view.input_mode, &ctx);
+        let sb_height = status_hints_height(&hints, area.width);
+
+        let main_area = self.view_area(area, sb_height);
+
+        let entries = self.jj.command_log().entries();
+        self.command_log_view
+            .render(frame, main_area, &entries, notification);
+        self.render_hints(frame, &hints);
     }
 
-    fn render_help_view(&self, frame: &mut Frame) {
+    fn render_help_view(&self, frame: &mut Frame, context: Option<View>) {
         let search_query = self.help_search_query.as_deref();
         let search_input = if self.help_search_input {
-            Some(self.help_input_buffer.as_str())
+            Some(&self.help_input_buffer)
         } else {
             None
         };
-        render_help_panel(
+        render_help_overlay(
             frame,
             frame.area(),
+            context,
+            self.keymap,
             self.help_scroll,
             search_query,
             search_input,
@@ -443,15 +790,10 @@ impl App {
             let sb_height = status_hints_height(&hints, area.width);
 
             // Reserve space for status bar
-            let main_area = Rect {
-                x: area.x,
-                y: area.y,
-                width: area.width,
-                height: area.height.saturating_sub(sb_height),
-            };
+            let main_area = self.view_area(area, sb_height);
 
             resolve_view.render(frame, main_area, notification);
-            render_status_hints(frame, &hints);
+            self.render_hints(frame, &hints);
         } else {
             render_placeholder(
                 frame,
@@ -472,19 +814,16 @@ impl App {
             let sb_height = status_hints_height(keys::BLAME_VIEW_HINTS, area.width);
 
             // Reserve space for status bar at bottom
-            let main_area = Rect {
-                x: area.x,
-                y: area.y,
-                width: area.width,
-                height: area.height.saturating_sub(sb_height),
-            };
+            let main_area = self.view_area(area, sb_height);
 
             // Store visible height for blame content
             let blame_content_height = main_area.height.saturating_sub(2);
             self.last_frame_height.set(blame_content_height);
 
             blame_view.render(frame, main_area, notification);
-            render_blame_status_bar(frame, blame_view);
+            if !self.fullscreen {
+                render_blame_status_bar(frame, blame_view);
+            }
         } else {
             render_placeholder(
                 frame,
@@ -568,31 +907,118 @@ fn infer_file_op(insertions: usize, deletions: usize) -> FileOperation {
     }
 }
 
+/// Status badges for the previewed change (conflict, immutable, empty, divergent)
+fn preview_badges(change: &Change) -> Vec<Span<'static>> {
+    use crate::ui::theme::preview;
+
+    [
+        (change.has_conflict, "conflict", preview::CONFLICT),
+        (change.is_immutable, "immutable", preview::IMMUTABLE),
+        (change.is_empty, "empty", preview::EMPTY),
+        (change.is_divergent, "divergent", preview::DIVERGENT),
+    ]
+    .into_iter()
+    .filter(|(set, _, _)| *set)
+    .enumerate()
+    .flat_map(|(i, (_, label, color))| {
+        let separator = (i > 0).then(|| Span::raw(" "));
+        separator.into_iter().chain(std::iter::once(Span::styled(
+            format!(" {} ", label),
+            Style::default().fg(Color::Black).bg(color),
+        )))
+    })
+    .collect()
+}
+
+/// Bookmark names with their remote sync state: `✓` when in sync with the
+/// tracked remote, `↑N ↓M` when diverged, nothing when untracked/unknown
+fn preview_bookmark_spans(
+    bookmarks: &[String],
+    tracking: &HashMap<String, TrackingCounts>,
+) -> Vec<Span<'static>> {
+    use crate::ui::theme::{bookmark_view, log_view};
+
+    let mut spans = Vec::new();
+    for (i, name) in bookmarks.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(", "));
+        }
+        spans.push(Span::styled(
+            name.clone(),
+            Style::default().fg(Color::Magenta),
+        ));
+        match tracking.get(name) {
+            Some(counts) if counts.is_synced() => spans.push(Span::styled(
+                " ✓",
+                Style::default().fg(bookmark_view::SYNCED),
+            )),
+            Some(counts) => spans.push(Span::styled(
+                format!(" {}", counts.indicator()),
+                Style::default().fg(log_view::BOOKMARK_TRACKING),
+            )),
+            None => {}
+        }
+    }
+    spans
+}
+
 /// Build preview lines from DiffContent, limited to max_lines.
 ///
-/// Shows: Author, Bookmarks (if any), Description, file stats summary,
+/// Shows: status badges of the selected change (if any), Author, Bookmarks
+/// with remote sync state (if any), local note (if any), Description, file
+/// stats summary,
 /// then file change list (M/A/D + path + per-file stats).
+#[allow(clippy::too_many_arguments)]
 fn build_preview_lines(
     content: &DiffContent,
+    timestamp_format: &TimestampFormat,
     bookmarks: &[String],
+    change: Option<&Change>,
+    note: Option<&str>,
+    tracking: &HashMap<String, TrackingCounts>,
     max_lines: usize,
     max_width: usize,
 ) -> Vec<Line<'static>> {
     let mut lines: Vec<Line<'static>> = Vec::new();
 
+    // Badges
+    let badges = change.map(preview_badges).unwrap_or_default();
+    if !badges.is_empty() {
+        lines.push(Line::from(badges));
+    }
+
     // Author + timestamp
     if !content.author.is_empty() {
         lines.push(Line::from(vec![
             Span::styled("Author: ", Style::default().fg(Color::DarkGray)),
-            Span::raw(format!("{}  {}", content.author, content.timestamp)),
+            Span::raw(format!(
+                "{}  {}",
+                content.author,
+                crate::model::format_timestamp(&content.timestamp, timestamp_format)
+            )),
         ]));
     }
 
     // Bookmarks
     if !bookmarks.is_empty() {
+        let mut spans = vec![Span::styled(
+            "Bookmarks: ",
+            Style::default().fg(Color::DarkGray),
+        )];
+        spans.extend(preview_bookmark_spans(bookmarks, tracking));
+        lines.push(Line::from(spans));
+    }
+
+    // Local note
+    if let Some(note) = note {
         lines.push(Line::from(vec![
-            Span::styled("Bookmarks: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(bookmarks.join(", "), Style::default().fg(Color::Magenta)),
+            Span::styled("Note: ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                note.to_string(),
+                Style::default()
+                    .fg(crate::ui::theme::log_view::NOTE)
+                    .italic(),
+            ),
         ]));
     }
 
@@ -678,6 +1104,86 @@ fn build_preview_lines(
 }
 
 /// Format a single file summary line with path truncation and right-aligned stats.
+/// Diff lines for the Status View preview of a single file
+///
+/// The file header is dropped (the pane title names the file); when the diff
+/// doesn't fit, the last line reports how many lines are hidden.
+fn build_file_preview_lines(content: &DiffContent, max_lines: usize) -> Vec<Line<'static>> {
+    use crate::ui::theme::diff_view;
+
+    let body: Vec<&DiffLine> = content
+        .lines
+        .iter()
+        .filter(|line| line.kind != DiffLineKind::FileHeader)
+        .collect();
+    let fits = body.len() <= max_lines;
+    let shown = if fits {
+        body.len()
+    } else {
+        max_lines.saturating_sub(1)
+    };
+
+    let mut lines: Vec<Line<'static>> = body[..shown]
+        .iter()
+        .map(|line| match line.kind {
+            DiffLineKind::Added => Line::from(Span::styled(
+                format!("+{}", line.content),
+                Style::default().fg(diff_view::ADDED),
+            )),
+            DiffLineKind::Deleted => Line::from(Span::styled(
+                format!("-{}", line.content),
+                Style::default().fg(diff_view::DELETED),
+            )),
+            DiffLineKind::Context => Line::from(format!(" {}", line.content)),
+            DiffLineKind::FileHeader | DiffLineKind::Separator => Line::from(""),
+        })
+        .collect();
+    if !fits && max_lines > 0 {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  … {} more lines (Enter for full diff)",
+                body.len() - shown
+            ),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines
+}
+
+/// Build Operation View preview lines from `jj op show` output
+///
+/// Section titles (`Changed commits:`) are bold and `+`/`-` lines colored;
+/// when the output doesn't fit, the last line reports how many are hidden.
+fn build_op_preview_lines(content: &DiffContent, max_lines: usize) -> Vec<Line<'static>> {
+    use crate::ui::theme::diff_view;
+
+    let total = content.lines.len();
+    let shown = if total <= max_lines {
+        total
+    } else {
+        max_lines.saturating_sub(1)
+    };
+    let mut lines: Vec<Line<'static>> = content.lines[..shown]
+        .iter()
+        .map(|line| {
+            let style = match line.kind {
+                DiffLineKind::Added => Style::default().fg(diff_view::ADDED),
+                DiffLineKind::Deleted => Style::default().fg(diff_view::DELETED),
+                _ if line.content.starts_with("Changed ") => Style::default().bold(),
+                _ => Style::default(),
+            };
+            Line::from(Span::styled(format!(" {}", line.content), style))
+        })
+        .collect();
+    if shown < total && max_lines > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("  … {} more lines", total - shown),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines
+}
+
 fn format_file_summary_line(entry: &FileSummaryEntry, max_width: usize) -> Line<'static> {
     let (op_color, op_char) = match entry.op {
         FileOperation::Added => (Color::Green, 'A'),
@@ -752,14 +1258,107 @@ fn truncate_path(path: &str, budget: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::{DiffContent, DiffLine};
+    use crate::model::{Change, ChangeId, CommitId, DiffContent, DiffLine};
+    use crate::ui::testing::render_to_text;
 
     const TEST_WIDTH: usize = 40;
 
+    #[test]
+    fn test_compact_layout_shows_the_list_until_p() {
+        let mut app = App::new_for_test();
+        app.log_view.set_changes(vec![Change {
+            change_id: ChangeId::new("kkmpptxz".to_string()),
+            commit_id: CommitId::new("abc12345".to_string()),
+            description: "Fix login bug".to_string(),
+            ..Default::default()
+        }]);
+        let screen = render_to_text(60, 20, |frame| app.render(frame));
+        assert!(app.compact);
+        assert!(screen.lines().next().unwrap().contains("Alt+1 Log"));
+        assert!(screen.contains("Fix login bug"), "{}", screen);
+
+        app.compact_preview = true;
+        let screen = render_to_text(60, 20, |frame| app.render(frame));
+        assert!(!screen.contains("Fix login bug"), "{}", screen);
+
+        // Large enough for the regular layout: list and preview side by side
+        let screen = render_to_text(120, 40, |frame| app.render(frame));
+        assert!(!app.compact);
+        assert!(!screen.contains("Alt+1 Log"));
+        assert!(screen.contains("Fix login bug"), "{}", screen);
+    }
+
+    #[test]
+    fn test_build_file_preview_lines_drops_header_and_reports_overflow() {
+        use crate::jj::parser::Parser;
+
+        let content = Parser::parse_diff_body(
+            "\
+Modified regular file src/main.rs:
+   10   10:     fn main() {
+   11     : -       old();
+        11: +       new();
+   12   12:     }
+",
+        );
+        let texts = |lines: Vec<Line>| lines.iter().map(line_text).collect::<Vec<_>>();
+
+        let all = texts(build_file_preview_lines(&content, 10));
+        assert_eq!(all.len(), 4);
+        assert!(all[0].starts_with(' '));
+        assert!(all.iter().any(|l| l.starts_with('+')));
+        assert!(all.iter().any(|l| l.starts_with('-')));
+
+        let clipped = texts(build_file_preview_lines(&content, 3));
+        assert_eq!(clipped.len(), 3);
+        assert!(clipped[2].contains("2 more lines"));
+    }
+
+    #[test]
+    fn test_build_op_preview_lines_colors_changes() {
+        use crate::jj::parser::Parser;
+
+        let content = Parser::parse_op_show(
+            "\
+7c1f7c8a2d1e user@host 5 minutes ago, lasted 12 milliseconds
+describe commit 1234abcd
+args: jj describe -m fix
+
+Changed commits:
++ kxqyzprt 5e6f7a8b fix
+- kxqyzprt 1234abcd (hidden) wip
+",
+        );
+        let lines = build_op_preview_lines(&content, 10);
+        assert_eq!(lines.len(), 7);
+        assert_eq!(line_text(&lines[4]), " Changed commits:");
+        assert_eq!(
+            lines[5].spans[0].style.fg,
+            Some(crate::ui::theme::diff_view::ADDED)
+        );
+        assert_eq!(
+            lines[6].spans[0].style.fg,
+            Some(crate::ui::theme::diff_view::DELETED)
+        );
+
+        let clipped = build_op_preview_lines(&content, 4);
+        assert_eq!(clipped.len(), 4);
+        assert!(line_text(&clipped[3]).contains("4 more lines"));
+    }
+
     #[test]
     fn test_build_preview_lines_empty_content() {
         let content = DiffContent::default();
-        let lines = build_preview_lines(&content, &[], 10, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            10,
+            TEST_WIDTH,
+        );
         assert!(lines.is_empty());
     }
 
@@ -771,7 +1370,16 @@ mod tests {
             description: "Fix login bug".to_string(),
             ..DiffContent::default()
         };
-        let lines = build_preview_lines(&content, &[], 10, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            10,
+            TEST_WIDTH,
+        );
         // Author + description + blank + (no changes) = 4 lines
         assert_eq!(lines.len(), 4);
     }
@@ -785,11 +1393,111 @@ mod tests {
             ..DiffContent::default()
         };
         let bookmarks = vec!["main".to_string(), "feature/login".to_string()];
-        let lines = build_preview_lines(&content, &bookmarks, 10, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &bookmarks,
+            None,
+            None,
+            &HashMap::new(),
+            10,
+            TEST_WIDTH,
+        );
         // Author + bookmarks + description + blank + (no changes) = 5 lines
         assert_eq!(lines.len(), 5);
     }
 
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_build_preview_lines_badges_and_bookmark_sync() {
+        let content = DiffContent {
+            author: "alice@example.com".to_string(),
+            description: "Release".to_string(),
+            ..DiffContent::default()
+        };
+        let change = Change {
+            has_conflict: true,
+            is_immutable: true,
+            is_divergent: true,
+            is_hidden: false,
+            ..Change::default()
+        };
+        let bookmarks = vec![
+            "main".to_string(),
+            "feature".to_string(),
+            "local".to_string(),
+        ];
+        let tracking = HashMap::from([
+            ("main".to_string(), TrackingCounts::default()),
+            (
+                "feature".to_string(),
+                TrackingCounts {
+                    ahead: 2,
+                    behind: 1,
+                },
+            ),
+        ]);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &bookmarks,
+            Some(&change),
+            None,
+            &tracking,
+            10,
+            TEST_WIDTH,
+        );
+        assert_eq!(line_text(&lines[0]), " conflict   immutable   divergent ");
+        assert_eq!(
+            line_text(&lines[2]),
+            "Bookmarks: main ✓, feature ↑2 ↓1, local"
+        );
+    }
+
+    #[test]
+    fn test_build_preview_lines_shows_note_after_author() {
+        let content = DiffContent {
+            author: "alice@example.com".to_string(),
+            description: "Add retry".to_string(),
+            ..DiffContent::default()
+        };
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            Some("check the retry limit"),
+            &HashMap::new(),
+            10,
+            TEST_WIDTH,
+        );
+        assert_eq!(line_text(&lines[1]), "Note: check the retry limit");
+        assert_eq!(line_text(&lines[2]), "Add retry");
+    }
+
+    #[test]
+    fn test_build_preview_lines_no_badges_for_plain_change() {
+        let content = DiffContent {
+            author: "alice@example.com".to_string(),
+            ..DiffContent::default()
+        };
+        let change = Change::default();
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            Some(&change),
+            None,
+            &HashMap::new(),
+            10,
+            TEST_WIDTH,
+        );
+        assert!(line_text(&lines[0]).starts_with("Author: "));
+    }
+
     #[test]
     fn test_build_preview_lines_file_summary() {
         let content = DiffContent {
@@ -807,7 +1515,16 @@ mod tests {
             ],
             ..DiffContent::default()
         };
-        let lines = build_preview_lines(&content, &[], 20, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            20,
+            TEST_WIDTH,
+        );
         // Author + desc + stats("1 file changed, +1, -0") + blank + "A src/main.rs" = 5
         assert_eq!(lines.len(), 5);
     }
@@ -837,7 +1554,16 @@ mod tests {
         };
         // max_lines=8: header uses 4 (author + desc + stats + blank), leaving 4 for files
         // 10 files > 4 → show 3 files + "… and 7 more files"
-        let lines = build_preview_lines(&content, &[], 8, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            8,
+            TEST_WIDTH,
+        );
         assert_eq!(lines.len(), 8);
         // Last line should be the overflow indicator
         let last_line_text: String = lines
@@ -874,7 +1600,16 @@ mod tests {
         };
         // max_lines=4: author + desc + stats = 3 header lines, blank = 4th → remaining = 0
         // Fix: blank is sacrificed, file summary shown in its place
-        let lines = build_preview_lines(&content, &[], 4, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            4,
+            TEST_WIDTH,
+        );
         assert_eq!(lines.len(), 4);
         // Last line should be the file summary (not blank, not missing)
         let last_line_text: String = lines
@@ -919,7 +1654,16 @@ mod tests {
         };
         // max_lines=4: header=3, blank=4th → remaining=0 → sacrifice blank → remaining=1
         // 2 files > 1 remaining → overflow: 0 files shown + "… and 2 more files"
-        let lines = build_preview_lines(&content, &[], 4, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            4,
+            TEST_WIDTH,
+        );
         assert_eq!(lines.len(), 4);
         let last_line_text: String = lines
             .last()
@@ -943,7 +1687,16 @@ mod tests {
             description: "Empty commit".to_string(),
             ..DiffContent::default()
         };
-        let lines = build_preview_lines(&content, &[], 10, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            10,
+            TEST_WIDTH,
+        );
         // Author + desc + blank + "(no changes)" = 4
         assert_eq!(lines.len(), 4);
         let last_line_text: String = lines
@@ -984,7 +1737,16 @@ mod tests {
             ..DiffContent::default()
         };
         // Max 5 lines total
-        let lines = build_preview_lines(&content, &[], 5, TEST_WIDTH);
+        let lines = build_preview_lines(
+            &content,
+            &TimestampFormat::default(),
+            &[],
+            None,
+            None,
+            &HashMap::new(),
+            5,
+            TEST_WIDTH,
+        );
         assert_eq!(lines.len(), 5);
     }
 
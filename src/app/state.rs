@@ -1,30 +1,51 @@
 //! Application state and view management
 
 use std::cell::Cell;
-use std::collections::VecDeque;
-
-use crate::jj::JjExecutor;
-use crate::model::{Change, CommandHistory, DiffContent, Notification};
-use crate::ui::components::Dialog;
+use std::collections::{HashMap, VecDeque};
+use std::sync::mpsc::Receiver;
+use std::time::SystemTime;
+
+use super::auto_fetch::AutoFetch;
+use super::diff_stream::DiffStream;
+use super::event::{CreateRequestResult, OpenRequestsResult};
+use super::instance_lock::InstanceLock;
+use super::watcher::FsWatcher;
+use crate::jj::constants::DEFAULT_LOG_REVSET;
+use crate::jj::{CancelHook, JjExecutor, TerminalHandoff};
+use crate::keys::{KeyRemap, KeymapPreset};
+use crate::model::{
+    BookmarkRetarget, Change, CommandHistory, DEFAULT_ID_LENGTH, DiffContent, DiffOptions,
+    DiffStat, Notification, TimestampFormat,
+};
+use crate::ui::components::{CompactLayout, Dialog, LineInput, SplitLayout};
 use crate::ui::views::{
-    BlameView, BookmarkView, CommandHistoryView, DiffView, EvologView, LogView, OperationView,
-    ResolveView, StatusView, TagView, WorkspaceView,
+    BlameView, BookmarkView, CommandHistoryView, CommandLogView, DiagnosticsView, DiffView,
+    EvologView, FileLogView, LogView, MergeView, OpHeadsView, OperationView, PagerView, RemoteView,
+    ResolveView, SparseView, StackView, StatusView, TagView, WorkspaceView,
 };
+use crate::ui::widgets::StatusTemplate;
 
 /// Tracks which data needs refreshing after a jj operation.
 ///
 /// All write operations set `op_log: true` since they create a new jj operation.
-/// Use the convenience constructors to create flags for specific operations.
+/// Use the convenience constructors to create flags for specific operations,
+/// picking the narrowest one that covers what the operation invalidates.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub(crate) struct DirtyFlags {
     pub log: bool,
     pub status: bool,
     pub op_log: bool,
+    /// Bookmark View (also shows each target's commit ID and description)
     pub bookmarks: bool,
+    /// Conflicted files listed in an open Resolve View
+    pub resolve_list: bool,
+    /// Whole Log preview cache (normally validated by commit ID on reload)
+    pub preview: bool,
 }
 
 impl DirtyFlags {
-    /// Log and operation log (metadata-only changes like describe)
+    /// Log and operation log (new commits, tags, workspaces: nothing existing
+    /// is rewritten)
     pub fn log() -> Self {
         Self {
             log: true,
@@ -33,17 +54,41 @@ impl DirtyFlags {
         }
     }
 
-    /// Log and status (most write operations)
+    /// Metadata-only rewrites (describe, metaedit): bookmarks pointing at the
+    /// rewritten commit show its new commit ID and description
+    pub fn rewrite_metadata() -> Self {
+        Self {
+            log: true,
+            op_log: true,
+            bookmarks: true,
+            ..Default::default()
+        }
+    }
+
+    /// Working copy moved without rewriting commits (new, edit, next/prev)
+    pub fn working_copy() -> Self {
+        Self {
+            log: true,
+            status: true,
+            op_log: true,
+            resolve_list: true,
+            ..Default::default()
+        }
+    }
+
+    /// Content rewrites (most write operations: squash, rebase, abandon, ...)
     pub fn log_and_status() -> Self {
         Self {
             log: true,
             status: true,
             op_log: true,
+            bookmarks: true,
+            resolve_list: true,
             ..Default::default()
         }
     }
 
-    /// Log and bookmarks (bookmark create/delete/move)
+    /// Log and bookmarks (bookmark create/delete/move, track, push)
     pub fn log_and_bookmarks() -> Self {
         Self {
             log: true,
@@ -53,6 +98,14 @@ impl DirtyFlags {
         }
     }
 
+    /// Operation log only (op abandon)
+    pub fn op_log() -> Self {
+        Self {
+            op_log: true,
+            ..Default::default()
+        }
+    }
+
     /// All flags dirty (fetch, undo, redo, op_restore)
     pub fn all() -> Self {
         Self {
@@ -60,12 +113,22 @@ impl DirtyFlags {
             status: true,
             op_log: true,
             bookmarks: true,
+            resolve_list: true,
+            preview: true,
         }
     }
 }
 
 const PREVIEW_CACHE_CAPACITY: usize = 8;
 
+/// A fetch/push cancelled with Esc/Ctrl+C, rechecked once the key is handled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CancelledNetwork {
+    pub is_push: bool,
+    /// Operation current when the command started
+    pub before_op: Option<String>,
+}
+
 /// Single preview cache entry
 #[derive(Debug)]
 pub(crate) struct PreviewCacheEntry {
@@ -171,8 +234,17 @@ pub enum View {
     Bookmark,
     Tag,
     Workspace,
+    Remote,
+    Sparse,
+    Pager,
+    Stack,
     Evolog,
+    FileLog,
+    Merge,
+    OpHeads,
     CommandHistory,
+    CommandLog,
+    Diagnostics,
     Help,
 }
 
@@ -189,20 +261,42 @@ pub struct App {
     pub log_view: LogView,
     /// Diff view state (created on demand)
     pub diff_view: Option<DiffView>,
+    /// `jj show` still streaming into the Diff View
+    pub(crate) diff_stream: Option<DiffStream>,
+    /// Diff View whitespace/context/mode options, kept for the session
+    pub(crate) diff_options: DiffOptions,
     /// Blame view state (created on demand)
     pub blame_view: Option<BlameView>,
     /// Resolve view state (created on demand)
     pub resolve_view: Option<ResolveView>,
     /// Evolog view state (created on demand)
     pub evolog_view: Option<EvologView>,
+    /// File Log View state (created on demand)
+    pub file_log_view: Option<FileLogView>,
+    /// Merge editor state (created on demand)
+    pub merge_view: Option<MergeView>,
+    /// Divergent Operations View state (created on demand)
+    pub op_heads_view: Option<OpHeadsView>,
     /// Bookmark view state
     pub bookmark_view: BookmarkView,
     /// Tag view state
     pub tag_view: TagView,
     /// Workspace view state
     pub workspace_view: WorkspaceView,
+    /// Remote view state
+    pub remote_view: RemoteView,
+    /// Sparse view state
+    pub sparse_view: SparseView,
+    /// Pager view state (output of a typed jj command)
+    pub pager_view: PagerView,
+    /// Stack view state
+    pub stack_view: StackView,
     /// Command history view state
     pub command_history_view: CommandHistoryView,
+    /// Command log view state (every jj process run)
+    pub command_log_view: CommandLogView,
+    /// Diagnostics view state (environment report)
+    pub diagnostics_view: DiagnosticsView,
     /// Status view state
     pub status_view: StatusView,
     /// Operation history view state
@@ -211,6 +305,8 @@ pub struct App {
     pub jj: JjExecutor,
     /// Error message to display
     pub error_message: Option<String>,
+    /// Errors shown this session, oldest first (for bug reports)
+    pub(crate) recent_errors: VecDeque<(SystemTime, String)>,
     /// Notification to display (success/info/warning messages)
     pub notification: Option<Notification>,
     /// Last known frame height (updated during render, uses Cell for interior mutability)
@@ -219,6 +315,20 @@ pub struct App {
     pub active_dialog: Option<Dialog>,
     /// Bookmark names pending for push (Confirm dialog only; Select dialog uses DialogResult names)
     pub(crate) pending_push_bookmarks: Vec<String>,
+    /// Bookmarks queued with `Q` for one batched push (`Ctrl+P`), in queue order
+    pub(crate) push_queue: Vec<String>,
+    /// Background listing of open pull/merge requests (Bookmark View badges)
+    pub(crate) open_request_query: Option<Receiver<OpenRequestsResult>>,
+    /// The listing runs once per session
+    pub(crate) open_request_query_started: bool,
+    /// Request being created with `gh`/`glab` in the background
+    pub(crate) create_request_job: Option<Receiver<CreateRequestResult>>,
+    /// Background search for bookmarks left on rewritten commits
+    pub(crate) bookmark_retarget_job: Option<Receiver<Vec<BookmarkRetarget>>>,
+    /// Operations abandoned since the last `jj util gc --expire=now`
+    pub(crate) abandoned_operations: usize,
+    /// Fetch/push cancelled during the current key's handling
+    pub(crate) cancelled_network: Option<CancelledNetwork>,
     /// Pending bookmark forget name (Confirm dialog)
     pub(crate) pending_forget_bookmark: Option<String>,
     /// Pending jump target from Blame View (for 2-step J: first shows hint, second expands revset)
@@ -227,10 +337,43 @@ pub struct App {
     pub preview_enabled: bool,
     /// Preview auto-disabled due to small terminal (render-time flag, does not override user intent)
     pub(crate) preview_auto_disabled: bool,
+    /// Current view maximized: preview, status bar and error banner hidden (Ctrl+F)
+    pub(crate) fullscreen: bool,
+    /// Terminal size below which Log/Status/Bookmarks become tabs (None = never)
+    pub(crate) compact_layout: Option<CompactLayout>,
+    /// Revset of the log without a filter (jj's `revsets.log`)
+    pub(crate) default_log_revset: String,
+    /// Minimum change/commit ID display length (`tij.id-length`)
+    pub(crate) id_length: usize,
+    /// Timestamp display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub(crate) timestamp_format: TimestampFormat,
+    /// Keymap preset (`tij.keymap`), applied before every key dispatch
+    pub(crate) keymap: KeymapPreset,
+    /// `[tij.keys]` remaps, applied on top of the preset
+    pub(crate) key_remaps: Vec<KeyRemap>,
+    /// The compact layout is in use (render-time flag)
+    pub(crate) compact: bool,
+    /// Compact layout shows the preview in place of the list (`p`)
+    pub(crate) compact_preview: bool,
+    /// Log/preview split placement and size (`\`, `<`, `>`)
+    pub(crate) preview_layout: SplitLayout,
+    /// Split as loaded from config; differences are written back on quit
+    pub(crate) saved_preview_layout: SplitLayout,
     /// LRU preview cache (change_id → DiffContent + commit_id + bookmarks)
     pub(crate) preview_cache: PreviewCache,
     /// Pending preview fetch (deferred to idle tick)
     pub(crate) preview_pending_id: Option<String>,
+    /// Status View file diffs (`change_id` holds the file path; cleared on
+    /// every status refresh since the working copy may have changed)
+    pub(crate) status_preview_cache: PreviewCache,
+    /// Pending Status View preview fetch (file path, deferred to idle tick)
+    pub(crate) status_preview_pending: Option<String>,
+    /// Operation View `jj op show` output (`change_id` holds the operation ID)
+    pub(crate) op_preview_cache: PreviewCache,
+    /// Pending Operation View preview fetch (operation ID, deferred to idle tick)
+    pub(crate) op_preview_pending: Option<String>,
+    /// Log View `diff_stats` column by commit ID (`None`: jj returned nothing)
+    pub(crate) diff_stat_cache: HashMap<String, Option<DiffStat>>,
     /// Selected remote for push (None = default remote)
     ///
     /// Cleared on all exit paths: push success/error (via `take()` at top of
@@ -243,11 +386,30 @@ pub struct App {
     /// Help view: search input mode active
     pub(crate) help_search_input: bool,
     /// Help view: search input buffer
-    pub(crate) help_input_buffer: String,
+    pub(crate) help_input_buffer: LineInput,
     /// Dirty flags for lazy refresh
     pub(crate) dirty: DirtyFlags,
     /// Command execution history (for Command History View)
     pub(crate) command_history: CommandHistory,
+    /// Working-copy watcher (`tij.auto-refresh`), None when disabled
+    pub(crate) fs_watcher: Option<FsWatcher>,
+    /// Background fetch timer (`tij.auto-fetch-minutes`), None when disabled
+    pub(crate) auto_fetch: Option<AutoFetch>,
+    /// `.jj/tij.pid` marker, held while no other instance runs on the workspace
+    pub(crate) instance_lock: Option<InstanceLock>,
+    /// `.git` sits next to `.jj` (git tools share the refs)
+    pub(crate) colocated: bool,
+    /// Create backup bookmarks before destructive operations (`tij.backup-bookmarks`)
+    pub(crate) backup_bookmarks: bool,
+    /// Parsed `tij.status-bar` template, None when unset or invalid
+    pub(crate) status_template: Option<StatusTemplate>,
+    /// Current operation ID, kept up to date only while the template shows it
+    pub(crate) status_op_id: String,
+    /// Commits `trunk()` moved by in the last fetch, while a rebase of the
+    /// stack under `@` is suggested
+    pub(crate) rebase_suggestion: Option<usize>,
+    /// Operation before the background fetch in flight (to see what it moved)
+    pub(crate) auto_fetch_pre_op: Option<String>,
 }
 
 impl Default for App {
@@ -267,39 +429,85 @@ impl App {
             previous_view: None,
             log_view: LogView::new(),
             diff_view: None,
+            diff_stream: None,
+            diff_options: DiffOptions::default(),
             blame_view: None,
             resolve_view: None,
             evolog_view: None,
+            file_log_view: None,
+            merge_view: None,
+            op_heads_view: None,
             bookmark_view: BookmarkView::new(),
             tag_view: TagView::new(),
             workspace_view: WorkspaceView::new(),
+            remote_view: RemoteView::new(),
+            sparse_view: SparseView::new(),
+            pager_view: PagerView::new(),
+            stack_view: StackView::new(),
             command_history_view: CommandHistoryView::new(),
+            command_log_view: CommandLogView::new(),
+            diagnostics_view: DiagnosticsView::new(),
             status_view: StatusView::new(),
             operation_view: OperationView::new(),
             jj: JjExecutor::new(),
             error_message: None,
+            recent_errors: VecDeque::new(),
             notification: None,
             last_frame_height: Cell::new(24), // Default terminal height
             active_dialog: None,
             pending_push_bookmarks: Vec::new(),
+            push_queue: Vec::new(),
+            open_request_query: None,
+            open_request_query_started: false,
+            create_request_job: None,
+            bookmark_retarget_job: None,
+            abandoned_operations: 0,
+            cancelled_network: None,
             pending_forget_bookmark: None,
             pending_jump_change_id: None,
             preview_enabled: true,
             preview_auto_disabled: false,
+            fullscreen: false,
+            compact_layout: Some(CompactLayout::default()),
+            default_log_revset: DEFAULT_LOG_REVSET.to_string(),
+            id_length: DEFAULT_ID_LENGTH,
+            timestamp_format: TimestampFormat::default(),
+            keymap: KeymapPreset::default(),
+            key_remaps: Vec::new(),
+            compact: false,
+            compact_preview: false,
+            preview_layout: SplitLayout::default(),
+            saved_preview_layout: SplitLayout::default(),
             preview_cache: PreviewCache::new(),
             preview_pending_id: None,
+            status_preview_cache: PreviewCache::new(),
+            status_preview_pending: None,
+            op_preview_cache: PreviewCache::new(),
+            op_preview_pending: None,
+            diff_stat_cache: HashMap::new(),
             push_target_remote: None,
             help_scroll: 0,
             help_search_query: None,
             help_search_input: false,
-            help_input_buffer: String::new(),
+            help_input_buffer: LineInput::default(),
             dirty: DirtyFlags {
                 log: false, // Log is loaded in new()
                 status: true,
                 op_log: true,
                 bookmarks: true,
+                resolve_list: false,
+                preview: false,
             },
             command_history: CommandHistory::new(),
+            fs_watcher: None,
+            auto_fetch: None,
+            instance_lock: None,
+            colocated: false,
+            backup_bookmarks: false,
+            status_template: None,
+            status_op_id: String::new(),
+            rebase_suggestion: None,
+            auto_fetch_pre_op: None,
         }
     }
 
@@ -309,10 +517,39 @@ impl App {
     /// from jj. Production entry point.
     pub fn new() -> Self {
         let mut app = Self::init();
+        app.load_command_log_file();
+        // Templates read the ID length, so it must be set before any query
+        app.load_id_length();
+        app.load_timestamp_settings();
+        app.load_scroll_settings();
+        app.load_commit_id_column();
+        app.load_log_row();
+        app.load_graph_style();
+        app.load_default_log_revset();
+        app.load_pins();
+        app.load_notes();
+        app.load_preview_layout();
+        app.load_compact_layout();
+        app.load_backup_setting();
+        app.load_revset_presets();
+        app.load_status_template();
+        app.load_keymap_preset();
+        app.load_key_remaps();
+        app.acquire_instance_lock();
         app.refresh_log(None);
+        app.load_git_colocation();
+        app.run_config_check();
+        app.run_keymap_check();
         // Load preview for the initially selected revision (avoid "No preview available" flash)
         app.update_preview_if_needed();
         app.resolve_pending_preview();
+        app.start_fs_watcher();
+        app.start_auto_fetch();
+        app.jj
+            .set_cancel_hook(CancelHook::new(super::event::cancel_requested));
+        app.jj.set_terminal_handoff(TerminalHandoff::new(|| {
+            Box::new(super::actions::suspend_tui())
+        }));
         app
     }
 
@@ -336,9 +573,18 @@ impl App {
             View::Resolve => View::Log,
             View::Bookmark => View::Log,
             View::Evolog => View::Log,
+            View::FileLog => View::Log,
+            View::Merge => View::Log,
+            View::OpHeads => View::Log,
             View::Tag => View::Log,
             View::Workspace => View::Log,
+            View::Remote => View::Log,
+            View::Sparse => View::Log,
+            View::Pager => View::Log,
+            View::Stack => View::Log,
             View::CommandHistory => View::Log,
+            View::CommandLog => View::Log,
+            View::Diagnostics => View::Log,
             View::Help => View::Log,
         };
         self.go_to_view(next);
@@ -354,6 +600,9 @@ impl App {
             if self.current_view == View::Log {
                 self.preview_pending_id = None;
             }
+            if self.current_view == View::Status {
+                self.status_preview_pending = None;
+            }
 
             self.previous_view = Some(self.current_view);
             self.current_view = view;
@@ -369,6 +618,8 @@ impl App {
                     self.refresh_status();
                     self.dirty.status = false;
                 }
+                // Data unchanged: the cached file diff may still be there
+                View::Status => self.update_status_preview_if_needed(),
                 View::Operation if self.dirty.op_log => {
                     self.refresh_operation_log();
                     self.dirty.op_log = false;
@@ -377,6 +628,11 @@ impl App {
                     self.refresh_bookmark_view();
                     self.dirty.bookmarks = false;
                 }
+                // e.g. back from the Merge or Diff view after an operation
+                View::Resolve if self.dirty.resolve_list => {
+                    self.dirty.resolve_list = false;
+                    self.refresh_open_resolve_list();
+                }
                 View::Help => {
                     self.help_scroll = 0;
                     self.help_search_query = None;
@@ -388,6 +644,11 @@ impl App {
         }
     }
 
+    /// View Help was opened from (its keys are listed first)
+    pub(crate) fn help_context(&self) -> Option<View> {
+        self.previous_view.filter(|view| *view != View::Help)
+    }
+
     /// Go back to previous view
     ///
     /// Routes through `go_to_view()` to ensure dirty flags are checked.
@@ -398,6 +659,7 @@ impl App {
 
     /// Set running to false to quit the application.
     pub(crate) fn quit(&mut self) {
+        self.save_preview_layout();
         self.running = false;
     }
 
@@ -434,9 +696,42 @@ mod tests {
         assert!(flags.log);
         assert!(flags.status);
         assert!(flags.op_log);
+        // Rewritten commits change what bookmarks point at
+        assert!(flags.bookmarks);
+        assert!(flags.resolve_list);
+        assert!(!flags.preview);
+    }
+
+    #[test]
+    fn dirty_flags_working_copy_leaves_bookmarks() {
+        let flags = DirtyFlags::working_copy();
+        assert!(flags.log);
+        assert!(flags.status);
+        assert!(flags.op_log);
+        assert!(flags.resolve_list);
         assert!(!flags.bookmarks);
     }
 
+    #[test]
+    fn dirty_flags_rewrite_metadata_leaves_status() {
+        let flags = DirtyFlags::rewrite_metadata();
+        assert!(flags.log);
+        assert!(flags.bookmarks);
+        assert!(!flags.status);
+        assert!(!flags.resolve_list);
+    }
+
+    #[test]
+    fn dirty_flags_op_log_only() {
+        assert_eq!(
+            DirtyFlags::op_log(),
+            DirtyFlags {
+                op_log: true,
+                ..Default::default()
+            }
+        );
+    }
+
     #[test]
     fn dirty_flags_log_and_bookmarks_includes_op_log() {
         let flags = DirtyFlags::log_and_bookmarks();
@@ -453,6 +748,8 @@ mod tests {
         assert!(flags.status);
         assert!(flags.op_log);
         assert!(flags.bookmarks);
+        assert!(flags.resolve_list);
+        assert!(flags.preview);
     }
 
     #[test]
@@ -462,6 +759,8 @@ mod tests {
         assert!(!flags.status);
         assert!(!flags.op_log);
         assert!(!flags.bookmarks);
+        assert!(!flags.resolve_list);
+        assert!(!flags.preview);
     }
 
     // =========================================================================
@@ -485,6 +784,26 @@ mod tests {
         assert_eq!(app.current_view, View::Operation);
     }
 
+    #[test]
+    fn mark_dirty_keeps_unaffected_views_clean() {
+        let mut app = App::new_for_test();
+        app.dirty = DirtyFlags::default();
+        app.current_view = View::Help;
+        app.mark_dirty_and_refresh_current(DirtyFlags::log_and_bookmarks());
+        assert!(app.dirty.bookmarks);
+        assert!(!app.dirty.status);
+        assert!(!app.dirty.resolve_list);
+    }
+
+    #[test]
+    fn go_to_view_resolve_clears_resolve_list_flag() {
+        let mut app = App::new_for_test();
+        app.dirty.resolve_list = true;
+        // No resolve view is open: nothing to reload
+        app.go_to_view(View::Resolve);
+        assert!(!app.dirty.resolve_list);
+    }
+
     // =========================================================================
     // go_back routes through go_to_view
     // =========================================================================
@@ -745,4 +1064,30 @@ mod tests {
         app.update_preview_if_needed();
         assert!(app.preview_pending_id.is_none());
     }
+
+    #[test]
+    fn status_preview_schedules_selected_file_until_cached() {
+        use crate::model::{FileState, FileStatus, Status};
+
+        let mut app = App::new_for_test();
+        app.status_view.set_status(Status {
+            files: vec![FileStatus {
+                path: "src/main.rs".to_string(),
+                state: FileState::Modified,
+            }],
+            has_conflicts: false,
+            working_copy_change_id: crate::model::ChangeId::new("aaa".to_string()),
+            parent_change_id: crate::model::ChangeId::new("bbb".to_string()),
+            warnings: vec![],
+        });
+
+        app.update_status_preview_if_needed();
+        assert_eq!(app.status_preview_pending.as_deref(), Some("src/main.rs"));
+
+        app.status_preview_pending = None;
+        app.status_preview_cache
+            .insert(make_entry("src/main.rs", ""));
+        app.update_status_preview_if_needed();
+        assert!(app.status_preview_pending.is_none());
+    }
 }
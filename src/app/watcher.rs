@@ -0,0 +1,490 @@
+//! Filesystem watcher for automatic working-copy refresh
+//!
+//! Opt-in via `tij.auto-refresh = true` in jj config. Events under `.jj/`
+//! and `.git/` are jj's own bookkeeping and are ignored, and so are paths
+//! matched by a `.gitignore` (at the root or in any directory below it) or
+//! `.git/info/exclude` (`target/`, `node_modules/`), which jj doesn't
+//! snapshot. Everything else is debounced
+//! so a burst of writes (editor save) results in a single refresh once the
+//! working copy has been quiet for a while.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use regex::Regex;
+
+/// Quiet period before a refresh fires (`tij.auto-refresh-debounce-ms`)
+pub(crate) const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Top-level directories whose changes never affect `jj status`
+const INTERNAL_DIRS: &[&str] = &[".jj", ".git"];
+
+/// Ignore files read from the workspace root, in increasing precedence
+const IGNORE_FILES: &[&str] = &[".git/info/exclude", GITIGNORE];
+
+/// Per-directory ignore file, read in every directory that isn't ignored
+const GITIGNORE: &str = ".gitignore";
+
+/// One `.gitignore` pattern
+#[derive(Debug)]
+struct IgnoreRule {
+    regex: Regex,
+    /// `!pattern`: re-include what an earlier rule ignored
+    negated: bool,
+    /// `pattern/`: matches directories only
+    dir_only: bool,
+    /// Contains a `/`: matched against the path below `base`, not a name
+    anchored: bool,
+    /// Directory of the ignore file (root-relative, empty for the root)
+    base: String,
+}
+
+impl IgnoreRule {
+    /// `path` relative to the rule's directory (None outside of it)
+    fn relative<'a>(&self, path: &'a str) -> Option<&'a str> {
+        if self.base.is_empty() {
+            return Some(path);
+        }
+        path.strip_prefix(self.base.as_str())?
+            .strip_prefix('/')
+            .filter(|rest| !rest.is_empty())
+    }
+}
+
+/// Patterns of the workspace's ignore files
+///
+/// Covers the common syntax (`*`, `**`, `?`, `[...]`, `!`, leading and
+/// trailing `/`). A nested `.gitignore` applies below its directory and
+/// wins over the ones above it, as in git.
+#[derive(Debug, Default)]
+pub(crate) struct IgnoreRules {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreRules {
+    /// Read [`IGNORE_FILES`] under `root`, then the `.gitignore` of every
+    /// directory below it (missing files are skipped)
+    ///
+    /// Ignored directories are not entered, so their `.gitignore` files
+    /// (and their size) don't matter.
+    pub fn load(root: &Path) -> Self {
+        let mut ignore = Self::default();
+        for file in IGNORE_FILES {
+            if let Ok(text) = std::fs::read_to_string(root.join(file)) {
+                ignore.add(&text, "");
+            }
+        }
+        ignore.load_nested(root, "");
+        ignore
+    }
+
+    /// Parse root `.gitignore` syntax; later lines take precedence
+    #[cfg(test)]
+    pub fn parse(text: &str) -> Self {
+        let mut ignore = Self::default();
+        ignore.add(text, "");
+        ignore
+    }
+
+    /// Add the rules of an ignore file in root-relative directory `base`
+    fn add(&mut self, text: &str, base: &str) {
+        self.rules
+            .extend(text.lines().filter_map(|line| parse_rule(line, base)));
+    }
+
+    /// Read the `.gitignore` files below root-relative directory `dir`
+    ///
+    /// Parents are read before their subdirectories, so deeper rules come
+    /// later and take precedence.
+    fn load_nested(&mut self, root: &Path, dir: &str) {
+        let Ok(entries) = std::fs::read_dir(root.join(dir)) else {
+            return;
+        };
+        // Symlinked directories are not followed (file_type doesn't)
+        let mut subdirs: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| !(dir.is_empty() && INTERNAL_DIRS.contains(&name.as_str())))
+            .map(|name| match dir {
+                "" => name,
+                _ => format!("{}/{}", dir, name),
+            })
+            .collect();
+        subdirs.sort();
+        for subdir in subdirs {
+            if self.is_ignored(&subdir, true) {
+                continue;
+            }
+            if let Ok(text) = std::fs::read_to_string(root.join(&subdir).join(GITIGNORE)) {
+                self.add(&text, &subdir);
+            }
+            self.load_nested(root, &subdir);
+        }
+    }
+
+    /// Whether root-relative `path` (`/`-separated) is ignored
+    ///
+    /// A path inside an ignored directory is ignored too, as in git.
+    pub fn is_ignored(&self, path: &str, is_dir: bool) -> bool {
+        let components: Vec<&str> = path.split('/').filter(|c| !c.is_empty()).collect();
+        (0..components.len()).any(|end| {
+            let prefix = components[..=end].join("/");
+            let prefix_is_dir = end + 1 < components.len() || is_dir;
+            self.matches(&prefix, components[end], prefix_is_dir)
+        })
+    }
+
+    /// Outcome of the last rule matching `path` (whose last component is `name`)
+    fn matches(&self, path: &str, name: &str, is_dir: bool) -> bool {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                let Some(relative) = rule.relative(path) else {
+                    return false;
+                };
+                (is_dir || !rule.dir_only)
+                    && rule
+                        .regex
+                        .is_match(if rule.anchored { relative } else { name })
+            })
+            .is_some_and(|rule| !rule.negated)
+    }
+}
+
+fn parse_rule(line: &str, base: &str) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    let (negated, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    let anchored = pattern.contains('/');
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    if pattern.is_empty() {
+        return None;
+    }
+    let regex = Regex::new(&format!("^{}$", glob_to_regex(pattern))).ok()?;
+    Some(IgnoreRule {
+        regex,
+        negated,
+        dir_only,
+        anchored,
+        base: base.to_string(),
+    })
+}
+
+/// Regex source for a gitignore glob (`*` stays within one component)
+fn glob_to_regex(glob: &str) -> String {
+    let mut out = String::new();
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `**/` also matches no directory at all
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    out.push_str("(?:.*/)?");
+                } else {
+                    out.push_str(".*");
+                }
+            }
+            '*' => out.push_str("[^/]*"),
+            '?' => out.push_str("[^/]"),
+            '[' => {
+                let class: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                out.push('[');
+                // `[!a-z]` is regex's `[^a-z]`
+                match class.strip_prefix('!') {
+                    Some(rest) => out.push_str(&format!("^{}", rest.replace('\\', "\\\\"))),
+                    None => out.push_str(&class.replace('\\', "\\\\")),
+                }
+                out.push(']');
+            }
+            c => out.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    out
+}
+
+/// Trailing-edge debouncer: fires once after events stop for `delay`
+#[derive(Debug)]
+pub(crate) struct Debouncer {
+    delay: Duration,
+    last_event: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            last_event: None,
+        }
+    }
+
+    /// Record an event at `now`, restarting the quiet period
+    pub fn record(&mut self, now: Instant) {
+        self.last_event = Some(now);
+    }
+
+    /// Whether the quiet period has elapsed (consumes the pending event)
+    pub fn fire(&mut self, now: Instant) -> bool {
+        match self.last_event {
+            Some(last) if now.duration_since(last) >= self.delay => {
+                self.last_event = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Recursive watcher over the workspace root
+#[derive(Debug)]
+pub(crate) struct FsWatcher {
+    /// Kept alive for the lifetime of the watch; dropping it stops events
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    root: PathBuf,
+    ignore: IgnoreRules,
+    debouncer: Debouncer,
+}
+
+impl FsWatcher {
+    /// Start watching `root` recursively
+    pub fn start(root: PathBuf, debounce: Duration) -> notify::Result<Self> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        watcher.watch(&root, RecursiveMode::Recursive)?;
+        Ok(Self {
+            _watcher: watcher,
+            events: rx,
+            ignore: IgnoreRules::load(&root),
+            root,
+            debouncer: Debouncer::new(debounce),
+        })
+    }
+
+    /// Drain queued events; `true` once relevant changes have settled
+    pub fn poll(&mut self, now: Instant) -> bool {
+        while let Ok(event) = self.events.try_recv() {
+            let Ok(event) = event else {
+                continue;
+            };
+            // Pick up edited ignore rules for the following events
+            if event
+                .paths
+                .iter()
+                .any(|path| is_ignore_file(&self.root, path))
+            {
+                self.ignore = IgnoreRules::load(&self.root);
+            }
+            if is_relevant(&self.root, &self.ignore, &event) {
+                self.debouncer.record(now);
+            }
+        }
+        self.debouncer.fire(now)
+    }
+}
+
+/// Whether an event can change what `jj status` reports
+///
+/// Access events are dropped: jj itself opens and reads files while
+/// snapshotting, which would otherwise retrigger a refresh forever.
+fn is_relevant(root: &Path, ignore: &IgnoreRules, event: &Event) -> bool {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return false;
+    }
+    event
+        .paths
+        .iter()
+        .any(|path| !is_internal_path(root, path) && !is_ignored_path(root, ignore, path))
+}
+
+/// Whether `path` under `root` is matched by the ignore rules
+fn is_ignored_path(root: &Path, ignore: &IgnoreRules, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let relative: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    ignore.is_ignored(&relative.join("/"), path.is_dir())
+}
+
+/// Whether `path` is one of the [`IGNORE_FILES`] of `root` or a nested
+/// `.gitignore`
+fn is_ignore_file(root: &Path, path: &Path) -> bool {
+    IGNORE_FILES.iter().any(|file| path == root.join(file))
+        || (path.starts_with(root)
+            && !is_internal_path(root, path)
+            && path.file_name().is_some_and(|name| name == GITIGNORE))
+}
+
+/// Whether `path` lives under one of [`INTERNAL_DIRS`] of `root`
+fn is_internal_path(root: &Path, path: &Path) -> bool {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .next()
+        .is_some_and(|first| INTERNAL_DIRS.iter().any(|dir| first.as_os_str() == *dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+
+    fn event(kind: EventKind, path: &str) -> Event {
+        Event::new(kind).add_path(PathBuf::from(path))
+    }
+
+    #[test]
+    fn test_debouncer_waits_for_quiet_period() {
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(Duration::from_millis(500));
+        assert!(!debouncer.fire(start));
+
+        debouncer.record(start);
+        assert!(!debouncer.fire(start + Duration::from_millis(200)));
+        debouncer.record(start + Duration::from_millis(300));
+        assert!(!debouncer.fire(start + Duration::from_millis(600)));
+        assert!(debouncer.fire(start + Duration::from_millis(800)));
+        // Consumed: no second refresh without new events
+        assert!(!debouncer.fire(start + Duration::from_millis(2000)));
+    }
+
+    #[test]
+    fn test_internal_paths_ignored() {
+        let root = Path::new("/repo");
+        let none = IgnoreRules::default();
+        let modify = EventKind::Modify(ModifyKind::Any);
+        assert!(!is_relevant(
+            root,
+            &none,
+            &event(modify, "/repo/.jj/repo/op_heads")
+        ));
+        assert!(!is_relevant(
+            root,
+            &none,
+            &event(modify, "/repo/.git/index")
+        ));
+        assert!(is_relevant(
+            root,
+            &none,
+            &event(modify, "/repo/src/main.rs")
+        ));
+        // Only the top-level directory counts
+        assert!(is_relevant(
+            root,
+            &none,
+            &event(modify, "/repo/docs/.git/x")
+        ));
+    }
+
+    #[test]
+    fn test_access_events_ignored() {
+        let root = Path::new("/repo");
+        let none = IgnoreRules::default();
+        let access = EventKind::Access(AccessKind::Any);
+        assert!(!is_relevant(
+            root,
+            &none,
+            &event(access, "/repo/src/main.rs")
+        ));
+    }
+
+    #[test]
+    fn test_gitignored_paths_ignored() {
+        let root = Path::new("/repo");
+        let ignore =
+            IgnoreRules::parse("# build output\ntarget/\nnode_modules\n*.log\n!keep.log\n");
+        let modify = EventKind::Modify(ModifyKind::Any);
+        assert!(!is_relevant(
+            root,
+            &ignore,
+            &event(modify, "/repo/target/debug/tij")
+        ));
+        assert!(!is_relevant(
+            root,
+            &ignore,
+            &event(modify, "/repo/web/node_modules/x/index.js")
+        ));
+        assert!(!is_relevant(
+            root,
+            &ignore,
+            &event(modify, "/repo/build.log")
+        ));
+        assert!(is_relevant(root, &ignore, &event(modify, "/repo/keep.log")));
+        assert!(is_relevant(
+            root,
+            &ignore,
+            &event(modify, "/repo/src/main.rs")
+        ));
+    }
+
+    #[test]
+    fn test_nested_gitignore_applies_below_its_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let write = |path: &str, text: &str| {
+            let path = root.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        };
+        write(".gitignore", "*.log\n");
+        write("frontend/.gitignore", "node_modules/\n/dist\n!keep.log\n");
+        write("frontend/node_modules/.gitignore", "!*\n");
+        write("frontend/node_modules/x/index.js", "");
+        write("frontend/dist/app.js", "");
+
+        let ignore = IgnoreRules::load(root.path());
+        assert!(ignore.is_ignored("frontend/node_modules/x/index.js", false));
+        assert!(ignore.is_ignored("frontend/dist/app.js", false));
+        assert!(ignore.is_ignored("frontend/build.log", false));
+        // The nested file wins over the root one, but only below it
+        assert!(!ignore.is_ignored("frontend/keep.log", false));
+        assert!(ignore.is_ignored("keep.log", false));
+        // Patterns are relative to the `.gitignore`'s directory
+        assert!(!ignore.is_ignored("node_modules/x/index.js", false));
+        assert!(!ignore.is_ignored("backend/dist/app.js", false));
+    }
+
+    #[test]
+    fn test_nested_gitignore_edit_reloads_rules() {
+        let root = Path::new("/repo");
+        assert!(is_ignore_file(root, Path::new("/repo/.gitignore")));
+        assert!(is_ignore_file(root, Path::new("/repo/web/.gitignore")));
+        assert!(is_ignore_file(root, Path::new("/repo/.git/info/exclude")));
+        assert!(!is_ignore_file(root, Path::new("/repo/web/.gitignore.bak")));
+        assert!(!is_ignore_file(root, Path::new("/other/.gitignore")));
+    }
+
+    #[test]
+    fn test_ignore_rule_syntax() {
+        let ignore = IgnoreRules::parse("/dist\ndocs/*.html\n**/gen/**\ntmp/\nfile[0-9].txt\n");
+        // Anchored to the root
+        assert!(ignore.is_ignored("dist/app.js", false));
+        assert!(!ignore.is_ignored("web/dist/app.js", false));
+        // `*` stays within one directory
+        assert!(ignore.is_ignored("docs/index.html", false));
+        assert!(!ignore.is_ignored("docs/api/index.html", false));
+        assert!(ignore.is_ignored("a/gen/b.rs", false));
+        assert!(ignore.is_ignored("gen/b.rs", false));
+        // Directory-only patterns skip files of that name
+        assert!(ignore.is_ignored("tmp/x", false));
+        assert!(!ignore.is_ignored("tmp", false));
+        assert!(ignore.is_ignored("file1.txt", false));
+        assert!(!ignore.is_ignored("fileA.txt", false));
+    }
+}
@@ -0,0 +1,148 @@
+//! Cancellable `jj git fetch` / `jj git push`
+//!
+//! Network commands run in their own process group so that cancelling
+//! kills jj together with the `git`/`ssh` children it spawned. While the
+//! command runs, the [`CancelHook`] set by the app is polled; it reports
+//! whether the user asked to cancel (Esc or Ctrl+C).
+
+use std::fmt;
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::constants::commands;
+
+/// How often the hook is polled while a network command runs
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Check whether the user asked to cancel the running network command
+#[derive(Clone)]
+pub struct CancelHook(Arc<dyn Fn() -> bool + Send + Sync>);
+
+impl CancelHook {
+    /// Wrap `check`, which must not block
+    pub fn new(check: impl Fn() -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(check))
+    }
+
+    fn is_cancelled(&self) -> bool {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for CancelHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CancelHook")
+    }
+}
+
+/// Whether `args` (after global flags) is `git fetch` or `git push`
+pub(crate) fn is_network_command(args: &[&str]) -> bool {
+    let mut words = args.iter().skip_while(|arg| arg.starts_with('-'));
+    words.next() == Some(&commands::GIT)
+        && matches!(
+            words.next(),
+            Some(&(commands::GIT_FETCH | commands::GIT_PUSH))
+        )
+}
+
+/// Run `cmd` to completion, or kill it when `hook` reports a cancel
+///
+/// Returns `Ok(None)` when cancelled.
+pub(crate) fn output_cancellable(
+    cmd: &mut Command,
+    hook: &CancelHook,
+) -> io::Result<Option<Output>> {
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+    let mut child = cmd.spawn()?;
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if hook.is_cancelled() {
+            kill_process_group(&mut child);
+            return Ok(None);
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+/// Drain a pipe on a thread so a chatty child can't block on a full pipe
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+/// Kill `child` and everything in its process group, then reap it
+pub(crate) fn kill_process_group(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        // The group id is the child's pid (process_group(0) above)
+        let _ = Command::new("kill")
+            .args(["-TERM", "--", &format!("-{}", child.id())])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+    let _: io::Result<ExitStatus> = child.wait();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_network_command() {
+        assert!(is_network_command(&["git", "fetch"]));
+        assert!(is_network_command(&["git", "push", "--bookmark", "main"]));
+        assert!(is_network_command(&[
+            "--no-integrate-operation",
+            "git",
+            "push"
+        ]));
+        assert!(!is_network_command(&["git", "remote", "list"]));
+        assert!(!is_network_command(&["log", "-r", "git"]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cancel_kills_running_command() {
+        let hook = CancelHook::new(|| true);
+        let started = std::time::Instant::now();
+        let result = output_cancellable(Command::new("sleep").arg("10"), &hook).unwrap();
+        assert!(result.is_none());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_uncancelled_command_returns_output() {
+        let hook = CancelHook::new(|| false);
+        let output = output_cancellable(Command::new("echo").arg("done"), &hook)
+            .unwrap()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"done\n");
+    }
+}
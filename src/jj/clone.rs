@@ -0,0 +1,217 @@
+//! `jj git clone` for `tij clone <url> [dir]`
+//!
+//! The clone runs in the background with its output streamed line by line,
+//! so that the minimal progress screen can show what git is doing (git
+//! rewrites its progress lines with `\r`; each rewrite counts as a line).
+//! Prompts are disabled as for fetch/push; when the clone fails for want
+//! of credentials it can be re-run on the plain terminal
+//! ([`CloneJob::interactive_command`]).
+
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use super::cancel::kill_process_group;
+use super::constants::{self, commands, flags};
+use super::prompt::{disable_prompts, needs_credentials};
+
+/// Directory `jj git clone <url>` would create: the last path segment
+/// without `.git`
+pub fn clone_destination(url: &str) -> Option<String> {
+    let name = url
+        .trim_end_matches('/')
+        .rsplit(['/', ':', '\\'])
+        .next()?
+        .trim_end_matches(".git");
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Percentage of the latest progress line (`Receiving objects:  45% (..)`)
+pub fn progress_percent(line: &str) -> Option<u16> {
+    line.split_whitespace()
+        .rev()
+        .filter_map(|word| word.strip_suffix('%')?.parse::<u16>().ok())
+        .find(|percent| *percent <= 100)
+}
+
+/// Split a chunk of output at `\r`/`\n`, keeping an unfinished line in `pending`
+fn split_output(pending: &mut Vec<u8>, chunk: &[u8]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for &byte in chunk {
+        if byte == b'\r' || byte == b'\n' {
+            let line = String::from_utf8_lossy(pending).trim().to_string();
+            if !line.is_empty() {
+                lines.push(line);
+            }
+            pending.clear();
+        } else {
+            pending.push(byte);
+        }
+    }
+    lines
+}
+
+/// Forward the lines of a pipe to `tx` until it closes
+fn stream_lines(pipe: Option<impl Read + Send + 'static>, tx: Sender<String>) {
+    thread::spawn(move || {
+        let Some(mut pipe) = pipe else {
+            return;
+        };
+        let mut pending = Vec::new();
+        let mut buf = [0u8; 4096];
+        while let Ok(n) = pipe.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            for line in split_output(&mut pending, &buf[..n]) {
+                if tx.send(line).is_err() {
+                    return;
+                }
+            }
+        }
+        let _ = split_output(&mut pending, b"\n")
+            .into_iter()
+            .try_for_each(|line| tx.send(line));
+    });
+}
+
+/// A running `jj git clone`
+pub struct CloneJob {
+    url: String,
+    destination: PathBuf,
+    child: Child,
+    output: Receiver<String>,
+    /// Every line read so far, for the error report
+    transcript: Vec<String>,
+}
+
+impl CloneJob {
+    /// Start cloning `url` into `destination`
+    pub fn spawn(url: &str, destination: &Path) -> io::Result<Self> {
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+        cmd.arg(flags::NO_COLOR)
+            .args([commands::GIT, commands::GIT_CLONE, url])
+            .arg(destination)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        disable_prompts(&mut cmd);
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
+        let mut child = cmd.spawn()?;
+        let (tx, output) = mpsc::channel();
+        stream_lines(child.stdout.take(), tx.clone());
+        stream_lines(child.stderr.take(), tx);
+        Ok(Self {
+            url: url.to_string(),
+            destination: destination.to_path_buf(),
+            child,
+            output,
+            transcript: Vec::new(),
+        })
+    }
+
+    /// Lines printed since the last call
+    pub fn read_lines(&mut self) -> Vec<String> {
+        let lines: Vec<String> = self.output.try_iter().collect();
+        self.transcript.extend(lines.iter().cloned());
+        lines
+    }
+
+    /// Collect what is still in the pipes after jj exited
+    ///
+    /// Gives the reader threads a moment, in case a leftover git process
+    /// keeps a pipe open.
+    pub fn read_remaining_lines(&mut self) {
+        while let Ok(line) = self.output.recv_timeout(Duration::from_millis(500)) {
+            self.transcript.push(line);
+        }
+    }
+
+    /// Everything printed so far
+    pub fn transcript(&self) -> &[String] {
+        &self.transcript
+    }
+
+    /// Exit status once jj has finished (non-blocking)
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        self.child.try_wait()
+    }
+
+    /// Kill jj together with the git/ssh processes it started
+    pub fn cancel(&mut self) {
+        kill_process_group(&mut self.child);
+    }
+
+    /// Whether the clone failed because git or ssh could not prompt
+    pub fn needs_credentials(&self) -> bool {
+        needs_credentials(&self.transcript.join("\n"))
+    }
+
+    /// The same clone with the terminal's stdin/stdout, so ssh/git can prompt
+    pub fn interactive_command(&self) -> Command {
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+        cmd.args([commands::GIT, commands::GIT_CLONE, &self.url])
+            .arg(&self.destination);
+        cmd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_destination() {
+        assert_eq!(
+            clone_destination("https://github.com/jj-vcs/jj.git").as_deref(),
+            Some("jj")
+        );
+        assert_eq!(
+            clone_destination("https://github.com/nakamura-shuta/tij/").as_deref(),
+            Some("tij")
+        );
+        assert_eq!(
+            clone_destination("git@github.com:jj-vcs/jj.git").as_deref(),
+            Some("jj")
+        );
+        assert_eq!(clone_destination("host:repo").as_deref(), Some("repo"));
+        assert_eq!(clone_destination("https://example.com/.git"), None);
+    }
+
+    #[test]
+    fn test_progress_percent() {
+        assert_eq!(
+            progress_percent("remote: Receiving objects:  45% (450/1000), 1.2 MiB"),
+            Some(45)
+        );
+        assert_eq!(
+            progress_percent("Resolving deltas: 100% (12/12), done."),
+            Some(100)
+        );
+        assert_eq!(
+            progress_percent("Fetching into new repo in \"/tmp/x\""),
+            None
+        );
+    }
+
+    #[test]
+    fn test_split_output_keeps_partial_lines() {
+        let mut pending = Vec::new();
+        assert_eq!(
+            split_output(&mut pending, b"Receiving objects:  10%\rReceiving obj"),
+            vec!["Receiving objects:  10%"]
+        );
+        assert_eq!(
+            split_output(&mut pending, b"ects:  20%\r\n\nDone\n"),
+            vec!["Receiving objects:  20%", "Done"]
+        );
+        assert!(pending.is_empty());
+    }
+}
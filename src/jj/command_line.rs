@@ -0,0 +1,201 @@
+//! User-typed jj command lines (`:` prompt in Log View)
+//!
+//! The prompt is an escape hatch for read-only commands tij doesn't wrap.
+//! A line is split like a shell would (quotes and backslashes, no
+//! expansion), then checked against an allowlist of subcommands that only
+//! observe the repository. The subcommand must come first so that global
+//! options taking a value (`-R <path>`) can't hide it.
+
+use super::constants::commands;
+
+/// Subcommands that are read-only with any arguments
+const READ_ONLY_COMMANDS: &[&str] = &[
+    commands::LOG,
+    commands::SHOW,
+    commands::DIFF,
+    commands::EVOLOG,
+    commands::INTERDIFF,
+    commands::STATUS,
+    "st",
+    "root",
+];
+
+/// Groups whose read-only subcommands are listed
+const READ_ONLY_SUBCOMMANDS: &[(&str, &[&str])] = &[
+    ("op", &["log", "show", "diff"]),
+    ("operation", &["log", "show", "diff"]),
+    ("file", &["list", "show", "annotate"]),
+    ("bookmark", &["list", "l"]),
+    ("b", &["list", "l"]),
+    ("tag", &["list", "l"]),
+    ("workspace", &["list"]),
+    ("config", &["list", "get", "path"]),
+    ("sparse", &["list"]),
+];
+
+/// Options that hand control to an external program
+const REJECTED_OPTIONS: &[&str] = &["--tool", "-i", "--interactive"];
+
+/// Config overrides: `ui.pager`, `ui.diff-formatter` and the like name
+/// programs jj would run
+const CONFIG_OVERRIDE_OPTIONS: &[&str] = &["--config", "--config-toml", "--config-file"];
+
+/// Whether `arg` is `option` or `option=value`
+fn is_option(arg: &str, option: &str) -> bool {
+    arg.strip_prefix(option)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('='))
+}
+
+/// Split `line` into arguments: whitespace separates, quotes group
+///
+/// `'…'` is taken literally, `"…"` honors `\"` and `\\`, and a backslash
+/// outside quotes escapes the next character. A leading `jj` is dropped.
+pub fn split_command_line(line: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    args.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err("unterminated ' quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => return Err("unterminated \" quote".to_string()),
+                        },
+                        Some(c) => current.push(c),
+                        None => return Err("unterminated \" quote".to_string()),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                if let Some(c) = chars.next() {
+                    current.push(c);
+                }
+            }
+            c => {
+                in_word = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_word {
+        args.push(current);
+    }
+    if args.first().is_some_and(|first| first == "jj") {
+        args.remove(0);
+    }
+    Ok(args)
+}
+
+/// `Ok` when `args` is an allowlisted read-only invocation, else why not
+pub fn check_read_only(args: &[String]) -> Result<(), String> {
+    let Some(command) = args.first() else {
+        return Err("empty command".to_string());
+    };
+    if command.starts_with('-') {
+        return Err("put the subcommand first (e.g. log -r 'mine()')".to_string());
+    }
+    if !READ_ONLY_COMMANDS.contains(&command.as_str()) {
+        let Some((_, allowed)) = READ_ONLY_SUBCOMMANDS
+            .iter()
+            .find(|(group, _)| group == command)
+        else {
+            return Err(format!("'{}' is not a read-only command", command));
+        };
+        let sub = args.get(1).map(String::as_str).unwrap_or_default();
+        if !allowed.contains(&sub) {
+            return Err(format!(
+                "'{} {}' is not read-only (allowed: {})",
+                command,
+                sub,
+                allowed.join(", ")
+            ));
+        }
+    }
+    let find = |options: &[&str]| {
+        args.iter()
+            .find(|arg| options.iter().any(|option| is_option(arg, option)))
+    };
+    if let Some(option) = find(REJECTED_OPTIONS) {
+        return Err(format!("{} needs a terminal and can't run here", option));
+    }
+    if let Some(option) = find(CONFIG_OVERRIDE_OPTIONS) {
+        return Err(format!(
+            "{} can't be used here (it can run other programs)",
+            option
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        split_command_line(line).unwrap()
+    }
+
+    #[test]
+    fn test_split_command_line_quotes() {
+        assert_eq!(
+            args(r#"jj log -r 'author("a b")' --limit 5"#),
+            ["log", "-r", r#"author("a b")"#, "--limit", "5"]
+        );
+        assert_eq!(args(r#"show "x \"y\"" a\ b"#), ["show", r#"x "y""#, "a b"]);
+        assert_eq!(args("diff ''"), ["diff", ""]);
+        assert!(split_command_line("log -r 'mine()").is_err());
+    }
+
+    #[test]
+    fn test_check_read_only_allows_listed_commands() {
+        assert!(check_read_only(&args("log -r mine() --stat")).is_ok());
+        assert!(check_read_only(&args("op log --limit 3")).is_ok());
+        assert!(check_read_only(&args("bookmark list --all")).is_ok());
+    }
+
+    #[test]
+    fn test_check_read_only_rejects_writes() {
+        assert!(check_read_only(&args("describe -m x")).is_err());
+        assert!(check_read_only(&args("op restore abc")).is_err());
+        assert!(check_read_only(&args("bookmark set main")).is_err());
+        assert!(check_read_only(&args("-R /tmp log")).is_err());
+        assert!(check_read_only(&args("diff --tool=meld")).is_err());
+        assert!(check_read_only(&[]).is_err());
+    }
+
+    #[test]
+    fn test_check_read_only_rejects_config_overrides() {
+        assert!(check_read_only(&args("log --config ui.pager=sh")).is_err());
+        assert!(check_read_only(&args("diff --config=ui.diff-formatter=['sh']")).is_err());
+        assert!(check_read_only(&args("show --config-toml x")).is_err());
+        assert!(check_read_only(&args("log --config-file /tmp/c.toml")).is_err());
+        // Listing the config stays allowed
+        assert!(check_read_only(&args("config list ui")).is_ok());
+    }
+}
@@ -0,0 +1,246 @@
+//! Audit trail of every jj invocation
+//!
+//! Unlike the app's command history (user actions only), the executor logs
+//! every process it starts, read-only queries included, with its duration
+//! and exit code. The log is shared by executor clones (worker threads
+//! record into the same log) and can also be appended to a file
+//! (`tij.command-log-file`).
+
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::constants::{self, flags};
+use crate::model::civil_from_days;
+
+/// Maximum number of entries kept in memory
+const CAPACITY: usize = 500;
+
+/// One jj process run by the executor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandLogEntry {
+    /// Sequence number, unique for the lifetime of the log
+    pub id: u64,
+    /// Arguments after `jj` (`--color=never` left out)
+    pub args: Vec<String>,
+    /// When the process was started
+    pub started: SystemTime,
+    /// Wall-clock time until it exited
+    pub duration: Duration,
+    /// Exit code; `None` when it was cancelled, killed by a signal or
+    /// could not be started
+    pub exit_code: Option<i32>,
+}
+
+impl CommandLogEntry {
+    /// Whether jj exited with status 0
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+
+    /// `exit 0`, `exit 1`, or `no exit` (cancelled, killed, not started)
+    pub fn status_label(&self) -> String {
+        match self.exit_code {
+            Some(code) => format!("exit {}", code),
+            None => "no exit".to_string(),
+        }
+    }
+
+    /// The command as it can be pasted into a POSIX shell
+    pub fn shell_command(&self) -> String {
+        std::iter::once(constants::JJ_COMMAND.to_string())
+            .chain(self.args.iter().map(|arg| shell_quote(arg)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// One line for the log file: UTC time, duration, status, command
+    fn file_line(&self) -> String {
+        format!(
+            "{} {:>6}ms {:<7} {}",
+            format_utc(self.started),
+            self.duration.as_millis(),
+            self.status_label(),
+            self.shell_command()
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: VecDeque<CommandLogEntry>,
+    next_id: u64,
+    file: Option<PathBuf>,
+}
+
+/// Bounded in-memory log of jj invocations, shared between clones
+#[derive(Debug, Clone, Default)]
+pub struct CommandLog {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl CommandLog {
+    /// Also append every new entry to `path` (`None` stops writing)
+    pub fn set_file(&self, path: Option<PathBuf>) {
+        self.lock().file = path;
+    }
+
+    /// All entries, newest first
+    pub fn entries(&self) -> Vec<CommandLogEntry> {
+        self.lock().entries.iter().rev().cloned().collect()
+    }
+
+    /// The entry with this id, if it is still kept
+    pub fn entry(&self, id: u64) -> Option<CommandLogEntry> {
+        self.lock().entries.iter().find(|e| e.id == id).cloned()
+    }
+
+    /// Record that `cmd` ran from `started` until now
+    pub(crate) fn record(&self, cmd: &Command, started: Instant, exit_code: Option<i32>) {
+        let args = cmd
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .filter(|arg| arg != flags::NO_COLOR)
+            .collect();
+        let duration = started.elapsed();
+        self.push(CommandLogEntry {
+            id: 0,
+            args,
+            started: SystemTime::now() - duration,
+            duration,
+            exit_code,
+        });
+    }
+
+    /// Append `entry` under the next id
+    fn push(&self, mut entry: CommandLogEntry) {
+        let mut inner = self.lock();
+        entry.id = inner.next_id;
+        inner.next_id += 1;
+        if let Some(path) = &inner.file
+            && let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path)
+        {
+            // Best effort: a broken log file must not break jj commands
+            let _ = writeln!(file, "{}", entry.file_line());
+        }
+        if inner.entries.len() >= CAPACITY {
+            inner.entries.pop_front();
+        }
+        inner.entries.push_back(entry);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Quote `arg` for a POSIX shell when it contains anything but safe characters
+fn shell_quote(arg: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./:=@,+%^".contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// `2026-01-31T12:34:56Z`
+fn format_utc(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let day_secs = secs % 86_400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        day_secs / 3600,
+        day_secs % 3600 / 60,
+        day_secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(args: &[&str], exit_code: Option<i32>) -> CommandLogEntry {
+        CommandLogEntry {
+            id: 0,
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            started: UNIX_EPOCH + Duration::from_secs(86_400 + 3_723),
+            duration: Duration::from_millis(42),
+            exit_code,
+        }
+    }
+
+    #[test]
+    fn test_shell_command_quotes_special_args() {
+        let entry = entry(&["log", "-r", "author(\"it's me\")", "--limit=5"], Some(0));
+        assert_eq!(
+            entry.shell_command(),
+            r#"jj log -r 'author("it'\''s me")' --limit=5"#
+        );
+    }
+
+    #[test]
+    fn test_file_line() {
+        assert_eq!(
+            entry(&["status"], Some(1)).file_line(),
+            "1970-01-02T01:02:03Z     42ms exit 1  jj status"
+        );
+        assert!(
+            entry(&["git", "fetch"], None)
+                .file_line()
+                .contains("no exit")
+        );
+    }
+
+    #[test]
+    fn test_record_drops_color_flag_and_shares_clones() {
+        let log = CommandLog::default();
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+        cmd.arg(flags::NO_COLOR).args(["log", "-r", "@"]);
+        log.clone().record(&cmd, Instant::now(), Some(0));
+        let entries = log.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].args, ["log", "-r", "@"]);
+        assert!(entries[0].succeeded());
+    }
+
+    #[test]
+    fn test_record_appends_to_file() {
+        let path = std::env::temp_dir().join(format!("tij-command-log-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let log = CommandLog::default();
+        log.set_file(Some(path.clone()));
+        log.record(
+            Command::new(constants::JJ_COMMAND).arg("root"),
+            Instant::now(),
+            Some(0),
+        );
+        let written = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(written.ends_with("exit 0  jj root\n"));
+    }
+
+    #[test]
+    fn test_entry_ids_survive_eviction() {
+        let log = CommandLog::default();
+        for _ in 0..=CAPACITY {
+            log.push(entry(&["status"], Some(0)));
+        }
+        let entries = log.entries();
+        assert_eq!(entries.len(), CAPACITY);
+        assert_eq!(entries[0].id, CAPACITY as u64);
+        assert!(log.entry(0).is_none());
+        assert_eq!(log.entry(1).map(|e| e.id), Some(1));
+    }
+}
@@ -21,6 +21,8 @@ pub mod commands {
     pub const OP: &str = "op";
     pub const OP_LOG: &str = "log";
     pub const OP_RESTORE: &str = "restore";
+    pub const OP_DIFF: &str = "diff";
+    pub const OP_SHOW: &str = "show";
     pub const BOOKMARK: &str = "bookmark";
     pub const BOOKMARK_CREATE: &str = "create";
     pub const BOOKMARK_SET: &str = "set";
@@ -37,10 +39,14 @@ pub mod commands {
     pub const ABSORB: &str = "absorb";
     pub const FILE: &str = "file";
     pub const FILE_ANNOTATE: &str = "annotate";
+    pub const FILE_LIST: &str = "list";
+    pub const FILE_SHOW: &str = "show";
     pub const RESOLVE: &str = "resolve";
     pub const GIT: &str = "git";
     pub const GIT_FETCH: &str = "fetch";
     pub const GIT_PUSH: &str = "push";
+    pub const GIT_CLONE: &str = "clone";
+    pub const GIT_INIT: &str = "init";
     pub const DUPLICATE: &str = "duplicate";
     pub const DIFFEDIT: &str = "diffedit";
     pub const RESTORE: &str = "restore";
@@ -66,6 +72,16 @@ pub mod commands {
     pub const WORKSPACE_ADD: &str = "add";
     pub const WORKSPACE_FORGET: &str = "forget";
     pub const WORKSPACE_RENAME: &str = "rename";
+    pub const SPARSE: &str = "sparse";
+    pub const SPARSE_LIST: &str = "list";
+    pub const CONFIG: &str = "config";
+    pub const CONFIG_GET: &str = "get";
+    pub const CONFIG_LIST: &str = "list";
+    pub const CONFIG_SET: &str = "set";
+    pub const CONFIG_UNSET: &str = "unset";
+    pub const UTIL: &str = "util";
+    pub const UTIL_EXEC: &str = "exec";
+    pub const VERSION: &str = "version";
 }
 
 /// jj resolve flags
@@ -80,8 +96,18 @@ pub mod flags {
     pub const NO_COLOR: &str = "--color=never";
     /// Run without writing to the operation log (jj 0.41+, read-only callers)
     pub const NO_INTEGRATE_OPERATION: &str = "--no-integrate-operation";
+    /// Target the user config file (jj config set)
+    pub const USER: &str = "--user";
+    /// List jj's built-in defaults too (jj config list)
+    pub const INCLUDE_DEFAULTS: &str = "--include-defaults";
+    /// Target the repo config file (jj config set)
+    pub const REPO: &str = "--repo";
+    /// External diff tool to show a diff with (jj diff)
+    pub const TOOL: &str = "--tool";
     /// Force `jj fix` to format entire files even when `line-range-arg` is set (jj 0.41+)
     pub const ALL_LINES: &str = "--all-lines";
+    /// Load the repo at an earlier operation (global flag, read-only use)
+    pub const AT_OPERATION: &str = "--at-operation";
     /// Disable graph output for parsing (jj log only, NOT a global flag)
     pub const NO_GRAPH: &str = "--no-graph";
     /// Specify template
@@ -138,6 +164,8 @@ pub mod flags {
     pub const GIT_FORMAT: &str = "--git";
     /// Histogram diff summary
     pub const STAT: &str = "--stat";
+    /// One `M path` / `A path` / ... line per changed file
+    pub const SUMMARY: &str = "--summary";
     /// Allow pushing private commits
     pub const ALLOW_PRIVATE: &str = "--allow-private";
     /// Allow pushing commits with no description
@@ -155,6 +183,16 @@ pub mod flags {
 /// Default limit for log output (no revset)
 pub const DEFAULT_LOG_LIMIT: &str = "200";
 
+/// Revisions shown in Stack View: the current stack on top of trunk
+pub const STACK_REVSET: &str = "trunk()..@";
+
+/// jj's built-in `revsets.log` default (used when the config can't be read)
+pub const DEFAULT_LOG_REVSET: &str =
+    "present(@) | ancestors(immutable_heads().., 2) | present(trunk())";
+
+/// Recent operations searched for hidden commits (Log View `h`)
+pub const HIDDEN_COMMITS_OP_DEPTH: usize = 20;
+
 /// Special jj values
 pub mod special {
     /// The root change ID (all 'z' characters)
@@ -164,6 +202,81 @@ pub mod special {
     pub const ROOT_CHANGE_ID: &str = "zzzzzzzz";
 }
 
+/// tij-specific keys read from jj config (`[tij]` table)
+pub mod config_keys {
+    /// Configured identity, used by `jj metaedit --update-author`
+    pub const USER_NAME: &str = "user.name";
+    pub const USER_EMAIL: &str = "user.email";
+    /// Shell command run before every push; a non-zero exit aborts the push
+    pub const PRE_PUSH: &str = "tij.pre-push";
+    /// Minimum change/commit ID display length (extended when ambiguous)
+    pub const ID_LENGTH: &str = "tij.id-length";
+    /// Watch the working copy and refresh Status/Log on file changes (bool)
+    pub const AUTO_REFRESH: &str = "tij.auto-refresh";
+    /// Quiet period in milliseconds before an auto-refresh fires
+    pub const AUTO_REFRESH_DEBOUNCE_MS: &str = "tij.auto-refresh-debounce-ms";
+    /// Minutes between background `jj git fetch` runs (0 or unset disables)
+    pub const AUTO_FETCH_MINUTES: &str = "tij.auto-fetch-minutes";
+    /// Table of named Log View revsets (`tij.revset-presets.<name> = "<revset>"`)
+    pub const REVSET_PRESETS: &str = "tij.revset-presets";
+    /// Table of named compares (`tij.compare-sessions.<name>.from` / `.to`)
+    pub const COMPARE_SESSIONS: &str = "tij.compare-sessions";
+    /// jj merge tool (`merge-tools.<name>`) used to open diffs in a GUI
+    pub const DIFF_TOOL: &str = "tij.diff-tool";
+    /// Rows of context kept above/below the Log View selection
+    pub const SCROLL_OFF: &str = "tij.scroll-off";
+    /// Keep the Log View selection centered while scrolling (bool)
+    pub const CENTER_CURSOR: &str = "tij.center-cursor";
+    /// Show the commit ID column in the Log View by default (bool)
+    pub const SHOW_COMMIT_IDS: &str = "tij.show-commit-ids";
+    /// Log View row columns in order (`change_id[:N]`, `commit_id`, `author`,
+    /// `timestamp[:relative]`, `bookmarks`, `diff_stats`, `description`)
+    pub const LOG_ROW: &str = "tij.log-row";
+    /// Create `tij-backup/...` bookmarks before destructive operations (bool)
+    pub const BACKUP_BOOKMARKS: &str = "tij.backup-bookmarks";
+    /// strftime pattern for commit timestamps in all views
+    pub const TIMESTAMP_FORMAT: &str = "tij.timestamp-format";
+    /// Show commit timestamps in UTC instead of local time (bool)
+    pub const TIMESTAMP_UTC: &str = "tij.timestamp-utc";
+    /// Log preview placement (`"bottom"` or `"right"`), saved on quit
+    pub const PREVIEW_LAYOUT: &str = "tij.preview-layout";
+    /// Log pane share of the preview split in percent (20-80), saved on quit
+    pub const PREVIEW_RATIO: &str = "tij.preview-ratio";
+    /// Terminal size below which Log/Status/Bookmarks become tabs of one
+    /// pane (`"WIDTHxHEIGHT"`, default `"80x24"`, or `"off"`)
+    pub const COMPACT_LAYOUT: &str = "tij.compact-layout";
+    /// What to do when another tij runs on the workspace: `"warn"` (default)
+    /// or `"read-only"`
+    pub const SECOND_INSTANCE: &str = "tij.second-instance";
+    /// Web URL template for commits opened with Ctrl+O (`{host}`, `{repo}`,
+    /// `{commit}`); overrides the GitHub/GitLab/Bitbucket layout
+    pub const BROWSE_COMMIT_URL: &str = "tij.browse-commit-url";
+    /// Web URL template for files opened with Ctrl+O (also `{path}`, `{line}`)
+    pub const BROWSE_FILE_URL: &str = "tij.browse-file-url";
+    /// List jj config problems at startup (bool, default on)
+    pub const CONFIG_CHECK: &str = "tij.config-check";
+    /// File every jj invocation is appended to (time, duration, exit, command)
+    pub const COMMAND_LOG_FILE: &str = "tij.command-log-file";
+    /// Table of pinned revisions (`tij.pins.<1-9> = "<revision>"`, repo config)
+    pub const PINS: &str = "tij.pins";
+    /// Table of local notes (`tij.notes.<change_id> = "<text>"`, repo config)
+    pub const NOTES: &str = "tij.notes";
+    /// Status bar template (`{view}`, `{revset}`, `{bookmark}`, `{change_id}`,
+    /// `{op_id}`, `{ahead_behind}`, `{time}`; `[...]` drops with an empty value)
+    pub const STATUS_BAR: &str = "tij.status-bar";
+    /// Keymap preset: `"vim"` (default) or `"arrows"` (Home/End, PgDn/PgUp,
+    /// mnemonic Log View letters)
+    pub const KEYMAP: &str = "tij.keymap";
+    /// Table of key remaps (`tij.keys.<key>`, `tij.keys.<view>.<key>`)
+    pub const KEYS: &str = "tij.keys";
+    /// jj's own graph style (`curved`, `square`, `ascii`, `ascii-large`)
+    pub const GRAPH_STYLE: &str = "ui.graph.style";
+    /// jj's editor (a command line string or an array of arguments)
+    pub const UI_EDITOR: &str = "ui.editor";
+    /// Revset of `jj log` without `-r`
+    pub const LOG_REVSET: &str = "revsets.log";
+}
+
 /// Error detection patterns in jj output
 pub mod errors {
     /// Pattern indicating not a jj repository
@@ -10,18 +10,25 @@
 //! - **Result consistency**: When parallel reads complete, apply all results to App state
 //!   atomically to avoid partial/inconsistent UI state.
 
+use std::io;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, ExitStatus, Output};
+use std::time::Instant;
 
 use crate::model::{
-    AnnotationContent, Bookmark, BookmarkInfo, Change, ChangeId, CommitId, ConflictFile,
-    DiffContent, Operation, RebaseMode, Status, TagInfo, WorkspaceInfo,
+    AnnotationContent, Bookmark, BookmarkInfo, BookmarkTarget, Change, ChangeId, CommitId,
+    CompareSession, ConflictFile, DEFAULT_ID_LENGTH, DiffContent, DiffStat, FileStatus,
+    LogParseIssue, LogRow, Notes, OpDiff, OpDivergence, Operation, Pin, RebaseMode, RemoteInfo,
+    RevsetPreset, StackEntry, Status, TagInfo, WorkspaceInfo, clamp_id_length,
 };
 
 use super::JjError;
-use super::constants::{self, commands, errors, flags, resolve_flags};
+use super::cancel::{CancelHook, is_network_command, output_cancellable};
+use super::command_log::CommandLog;
+use super::constants::{self, commands, config_keys, errors, flags, resolve_flags};
 use super::parser::Parser;
-use super::template::Templates;
+use super::prompt::{TerminalHandoff, disable_prompts, needs_credentials};
+use super::template::{Templates, shortest_id};
 
 /// Bulk push mode (repository-wide push operations)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -73,6 +80,20 @@ pub struct RunResult {
 pub struct JjExecutor {
     /// Path to the repository (None = current directory)
     repo_path: Option<PathBuf>,
+    /// Refuse commands that may modify the repository
+    read_only: bool,
+    /// Polled while `jj git fetch/push` runs (None = not cancellable)
+    cancel_hook: Option<CancelHook>,
+    /// Hands the terminal to a fetch/push that needs credentials (None = fail)
+    terminal_handoff: Option<TerminalHandoff>,
+    /// Every jj process started, shared with clones
+    command_log: CommandLog,
+    /// Extra flags for `show`/`diff`/`interdiff` (Diff View options)
+    diff_flags: Vec<String>,
+    /// Minimum ID length requested from templates (`tij.id-length`)
+    id_length: usize,
+    /// Log View columns the log template fetches fields for (`tij.log-row`)
+    log_row: LogRow,
 }
 
 // Compile-time assertion: JjExecutor must be Sync for thread::scope sharing.
@@ -91,7 +112,16 @@ impl Default for JjExecutor {
 impl JjExecutor {
     /// Create a new executor for the current directory
     pub fn new() -> Self {
-        Self { repo_path: None }
+        Self {
+            repo_path: None,
+            read_only: false,
+            cancel_hook: None,
+            terminal_handoff: None,
+            command_log: CommandLog::default(),
+            diff_flags: Vec::new(),
+            id_length: DEFAULT_ID_LENGTH,
+            log_row: LogRow::default(),
+        }
     }
 
     /// Create a new executor for a specific repository path
@@ -99,6 +129,13 @@ impl JjExecutor {
     pub fn with_repo_path(path: PathBuf) -> Self {
         Self {
             repo_path: Some(path),
+            read_only: false,
+            cancel_hook: None,
+            terminal_handoff: None,
+            command_log: CommandLog::default(),
+            diff_flags: Vec::new(),
+            id_length: DEFAULT_ID_LENGTH,
+            log_row: LogRow::default(),
         }
     }
 
@@ -107,27 +144,123 @@ impl JjExecutor {
         self.repo_path.as_ref()
     }
 
+    /// Minimum change/commit ID length the templates request
+    pub fn id_length(&self) -> usize {
+        self.id_length
+    }
+
+    /// Request IDs of at least `len` chars (clamped to
+    /// [`ID_LENGTH_RANGE`](crate::model::ID_LENGTH_RANGE))
+    ///
+    /// Must be called before the first jj query so that every view sees
+    /// the same IDs.
+    pub fn set_id_length(&mut self, len: usize) {
+        self.id_length = clamp_id_length(len);
+    }
+
+    /// Fetch the extra fields `row` needs with every `jj log`
+    ///
+    /// Must be called before the first `jj log`.
+    pub fn set_log_row(&mut self, row: LogRow) {
+        self.log_row = row;
+    }
+
+    /// Refuse repository-modifying commands from now on (second instance)
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Let `jj git fetch/push` be cancelled when `hook` says so
+    pub fn set_cancel_hook(&mut self, hook: CancelHook) {
+        self.cancel_hook = Some(hook);
+    }
+
+    /// Let a fetch/push that failed for want of credentials be rerun with
+    /// the terminal handed over, so that ssh/git can prompt
+    pub fn set_terminal_handoff(&mut self, handoff: TerminalHandoff) {
+        self.terminal_handoff = Some(handoff);
+    }
+
+    /// Copy for worker threads: the hooks use the terminal, which only
+    /// the UI thread may do
+    pub fn without_cancel_hook(&self) -> Self {
+        Self {
+            cancel_hook: None,
+            terminal_handoff: None,
+            ..self.clone()
+        }
+    }
+
+    /// Copy that passes `flags` to every `show`, `diff` and `interdiff`
+    ///
+    /// Used for the Diff View only: patches that get applied or exported
+    /// must not lose whitespace changes.
+    pub fn with_diff_flags(&self, flags: Vec<String>) -> Self {
+        Self {
+            diff_flags: flags,
+            ..self.clone()
+        }
+    }
+
+    /// Log of every jj process this executor (and its clones) started
+    pub fn command_log(&self) -> &CommandLog {
+        &self.command_log
+    }
+
+    /// Run `cmd` to completion, recording it in the command log
+    pub(crate) fn output_logged(&self, cmd: &mut Command) -> io::Result<Output> {
+        let started = Instant::now();
+        let result = cmd.output();
+        let exit_code = result.as_ref().ok().and_then(|output| output.status.code());
+        self.command_log.record(cmd, started, exit_code);
+        result
+    }
+
+    /// Run `cmd` with inherited stdio, recording it in the command log
+    pub(crate) fn status_logged(&self, cmd: &mut Command) -> io::Result<ExitStatus> {
+        let started = Instant::now();
+        let result = cmd.status();
+        let exit_code = result.as_ref().ok().and_then(ExitStatus::code);
+        self.command_log.record(cmd, started, exit_code);
+        result
+    }
+
+    /// Whether repository-modifying commands are refused
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Fail with [`JjError::ReadOnly`] in read-only mode
+    ///
+    /// Called by every method that may modify the repository without going
+    /// through [`Self::run`] (interactive commands, `jj duplicate`).
+    pub(crate) fn ensure_writable(&self) -> Result<(), JjError> {
+        if self.read_only {
+            Err(JjError::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Run a jj command with the given arguments
     ///
     /// Automatically adds `--color=never` to ensure parseable output.
     /// Returns `RunResult` containing both the output and the captured args.
+    /// In read-only mode only read-only invocations (see
+    /// [`Self::run_readonly_str`]) and `jj config` are allowed.
     pub fn run(&self, args: &[&str]) -> Result<RunResult, JjError> {
         use std::process::Stdio;
 
-        let args_vec: Vec<String> = args.iter().map(|s| s.to_string()).collect();
-
-        let mut cmd = Command::new(constants::JJ_COMMAND);
-
-        // Add repository path if specified
-        if let Some(ref path) = self.repo_path {
-            cmd.arg(flags::REPO_PATH).arg(path);
+        if !matches!(
+            args.first(),
+            Some(&flags::NO_INTEGRATE_OPERATION | &commands::CONFIG)
+        ) {
+            self.ensure_writable()?;
         }
 
-        // Always disable color for parsing
-        cmd.arg(flags::NO_COLOR);
+        let args_vec: Vec<String> = args.iter().map(|s| s.to_string()).collect();
 
-        // Add user-specified arguments
-        cmd.args(args);
+        let mut cmd = self.command(args);
 
         // Explicitly close stdin to prevent jj from waiting for input
         // (e.g., during snapshot warnings or interactive prompts).
@@ -135,18 +268,59 @@ impl JjExecutor {
         // which may not signal EOF properly under raw-mode terminals.
         cmd.stdin(Stdio::null());
 
-        // Defensively suppress any pager. jj usually skips the pager when stdout
-        // is not a tty, but JJ_PAGER (jj 0.41+, mirrors JJ_EDITOR) lets us pin
-        // it explicitly so terminal misdetection cannot blow up the TUI.
-        cmd.env("JJ_PAGER", "cat");
+        // A passphrase/password prompt would hang on the TUI's terminal
+        let is_network = is_network_command(args);
+        if is_network {
+            disable_prompts(&mut cmd);
+        }
 
-        let output = cmd.output().map_err(|e| {
+        let to_jj_error = |e: std::io::Error| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
                 JjError::IoError(e)
             }
-        })?;
+        };
+        let output = match &self.cancel_hook {
+            Some(hook) if is_network => {
+                // Taken before the run: after a cancel, newer operations
+                // tell whether jj still recorded the fetch/push
+                let before_op = self
+                    .op_log(Some(1))
+                    .ok()
+                    .and_then(|ops| ops.into_iter().next())
+                    .map(|op| op.id);
+                let started = Instant::now();
+                let result = output_cancellable(&mut cmd, hook);
+                let exit_code = match &result {
+                    Ok(Some(output)) => output.status.code(),
+                    _ => None,
+                };
+                self.command_log.record(&cmd, started, exit_code);
+                match result.map_err(to_jj_error)? {
+                    Some(output) => output,
+                    None => return Err(JjError::Cancelled { before_op }),
+                }
+            }
+            _ => self.output_logged(&mut cmd).map_err(to_jj_error)?,
+        };
+
+        // Asked for credentials: once more, with the terminal to answer on
+        let output = match &self.terminal_handoff {
+            Some(handoff)
+                if is_network
+                    && !output.status.success()
+                    && needs_credentials(&String::from_utf8_lossy(&output.stderr)) =>
+            {
+                let mut cmd = self.command(args);
+                cmd.stdin(Stdio::inherit());
+                let command_line = format!("jj {}", args.join(" "));
+                handoff
+                    .run(&command_line, || self.output_logged(&mut cmd))
+                    .map_err(to_jj_error)?
+            }
+            _ => output,
+        };
 
         if output.status.success() {
             Ok(RunResult {
@@ -184,6 +358,40 @@ impl JjExecutor {
         }
     }
 
+    /// `jj` with the repository path, `--color=never`, no pager and `args`
+    fn command(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+
+        // Add repository path if specified
+        if let Some(ref path) = self.repo_path {
+            cmd.arg(flags::REPO_PATH).arg(path);
+        }
+
+        // Always disable color for parsing
+        cmd.arg(flags::NO_COLOR);
+
+        // Add user-specified arguments, with the diff flags after the subcommand
+        match args.iter().position(|arg| !arg.starts_with('-')) {
+            Some(i)
+                if !self.diff_flags.is_empty()
+                    && [commands::SHOW, commands::DIFF, commands::INTERDIFF].contains(&args[i]) =>
+            {
+                cmd.args(&args[..=i]);
+                cmd.args(&self.diff_flags);
+                cmd.args(&args[i + 1..]);
+            }
+            _ => {
+                cmd.args(args);
+            }
+        }
+
+        // Defensively suppress any pager. jj usually skips the pager when stdout
+        // is not a tty, but JJ_PAGER (jj 0.41+, mirrors JJ_EDITOR) lets us pin
+        // it explicitly so terminal misdetection cannot blow up the TUI.
+        cmd.env("JJ_PAGER", "cat");
+        cmd
+    }
+
     /// Run a jj command and return only the output string.
     ///
     /// Convenience wrapper around `run()` for callers that don't need `RunResult.args`.
@@ -199,10 +407,15 @@ impl JjExecutor {
     /// only for invocations that observe state (log/status/diff/op log/...);
     /// never for commands that mutate the repo.
     fn run_readonly_str(&self, args: &[&str]) -> Result<String, JjError> {
+        self.run_readonly(args).map(|r| r.output)
+    }
+
+    /// Run a read-only jj command, keeping stderr (see [`Self::run_readonly_str`])
+    fn run_readonly(&self, args: &[&str]) -> Result<RunResult, JjError> {
         let mut all_args: Vec<&str> = Vec::with_capacity(args.len() + 1);
         all_args.push(flags::NO_INTEGRATE_OPERATION);
         all_args.extend_from_slice(args);
-        self.run_str(&all_args)
+        self.run(&all_args)
     }
 
     /// Run `jj log` with optional revset filter (raw output)
@@ -210,8 +423,8 @@ impl JjExecutor {
     /// Note: Graph output is enabled to show DAG structure.
     /// The parser handles graph prefixes in the output.
     pub fn log_raw(&self, revset: Option<&str>, reversed: bool) -> Result<String, JjError> {
-        let template = Templates::log();
-        let mut args = vec![commands::LOG, flags::TEMPLATE, template];
+        let template = Templates::log(&self.log_row, self.id_length);
+        let mut args = vec![commands::LOG, flags::TEMPLATE, &template];
 
         if let Some(rev) = revset {
             args.push(flags::REVISION);
@@ -245,15 +458,66 @@ impl JjExecutor {
         self.log(revset, reversed)
     }
 
+    /// Like `log_changes()`, but a malformed line doesn't discard the whole log
+    ///
+    /// Returns the rows parsed before the first unparsable line, plus the
+    /// issue (with the raw output) when parsing stopped early.
+    pub fn log_changes_partial(
+        &self,
+        revset: Option<&str>,
+        reversed: bool,
+    ) -> Result<(Vec<Change>, Option<LogParseIssue>), JjError> {
+        let output = self.log_raw(revset, reversed)?;
+        Ok(Parser::parse_log_partial(&output))
+    }
+
+    /// Run `jj log` limited to revisions that touched `path`
+    ///
+    /// Searches the ancestors of `revision` (`::<revision>`), newest first,
+    /// so the history matches the version of the file being looked at.
+    pub fn file_log(&self, path: &str, revision: &str) -> Result<Vec<Change>, JjError> {
+        let template = Templates::log(&self.log_row, self.id_length);
+        let revset = format!("::{}", revision);
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::TEMPLATE,
+            &template,
+            flags::REVISION,
+            &revset,
+            flags::LIMIT,
+            constants::DEFAULT_LOG_LIMIT,
+            path,
+        ])?;
+        Parser::parse_log(&output)
+    }
+
+    /// Tracked files of the working copy (`jj file list`)
+    pub fn file_list(&self) -> Result<Vec<String>, JjError> {
+        let output = self.run_readonly_str(&[commands::FILE, commands::FILE_LIST])?;
+        Ok(output
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
     /// Run `jj status`
     pub fn status_raw(&self) -> Result<String, JjError> {
         self.run_readonly_str(&[commands::STATUS])
     }
 
     /// Run `jj status` and parse the output into Status
+    ///
+    /// Warnings and hints jj writes to stderr (e.g. files refused by the
+    /// snapshot) are appended to those found in stdout.
     pub fn status(&self) -> Result<Status, JjError> {
-        let output = self.status_raw()?;
-        Parser::parse_status(&output)
+        let result = self.run_readonly(&[commands::STATUS])?;
+        let mut status = Parser::parse_status(&result.output)?;
+        status
+            .warnings
+            .extend(Parser::parse_status_warnings(&result.stderr));
+        Ok(status)
     }
 
     /// Run `jj show` for a specific change
@@ -269,6 +533,89 @@ impl JjExecutor {
         Parser::parse_show(&output)
     }
 
+    /// Run `jj show` for a specific change, handing each output line to `on_line`
+    ///
+    /// Lines are passed on as soon as jj writes them, for diffs too large to
+    /// wait for in one piece. Returning `false` from `on_line` stops jj.
+    pub fn show_streamed(
+        &self,
+        revision: &str,
+        mut on_line: impl FnMut(&str) -> bool,
+    ) -> Result<(), JjError> {
+        use std::io::{BufRead, BufReader, Read};
+        use std::process::Stdio;
+
+        let mut cmd = self.command(&[
+            flags::NO_INTEGRATE_OPERATION,
+            commands::SHOW,
+            flags::REVISION,
+            revision,
+        ]);
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let started = Instant::now();
+        let mut child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                self.command_log.record(&cmd, started, None);
+                return Err(if e.kind() == io::ErrorKind::NotFound {
+                    JjError::JjNotFound
+                } else {
+                    JjError::IoError(e)
+                });
+            }
+        };
+
+        // Drained on its own thread so a full stderr pipe cannot stall jj
+        let mut stderr = child.stderr.take().expect("stderr is piped");
+        let stderr_reader = std::thread::spawn(move || {
+            let mut text = String::new();
+            let _ = stderr.read_to_string(&mut text);
+            text
+        });
+
+        let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        let mut buf = Vec::new();
+        let mut stopped = false;
+        loop {
+            buf.clear();
+            match stdout.read_until(b'\n', &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    self.command_log.record(&cmd, started, None);
+                    return Err(JjError::IoError(e));
+                }
+            }
+            let line = String::from_utf8_lossy(&buf);
+            let line = line.strip_suffix('\n').unwrap_or(&line);
+            if !on_line(line.strip_suffix('\r').unwrap_or(line)) {
+                stopped = true;
+                let _ = child.kill();
+                break;
+            }
+        }
+
+        let status = child.wait().map_err(JjError::IoError);
+        let exit_code = status.as_ref().ok().and_then(ExitStatus::code);
+        self.command_log.record(&cmd, started, exit_code);
+        let status = status?;
+        let stderr = stderr_reader.join().unwrap_or_default();
+        if stopped || status.success() || stderr.contains("Refused to snapshot") {
+            Ok(())
+        } else if stderr.contains(errors::NOT_A_REPO) {
+            Err(JjError::NotARepository)
+        } else {
+            Err(JjError::CommandFailed {
+                stderr,
+                exit_code: status.code().unwrap_or(-1),
+            })
+        }
+    }
+
     /// Run `jj show --stat` for a specific change (histogram overview)
     pub fn show_stat(&self, revision: &str) -> Result<String, JjError> {
         self.run_readonly_str(&[commands::SHOW, flags::STAT, flags::REVISION, revision])
@@ -304,6 +651,120 @@ impl JjExecutor {
         Ok(output)
     }
 
+    /// Get a revision's author as `Name <email>` (the form `--author` takes)
+    pub fn author_signature(&self, revision: &str) -> Result<String, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revision,
+            flags::TEMPLATE,
+            r#"author.name() ++ " <" ++ author.email() ++ ">""#,
+        ])?;
+        Ok(output.trim().to_string())
+    }
+
+    /// Get the full (unabbreviated) change ID and commit ID of a revision
+    pub fn full_ids(&self, revision: &str) -> Result<(String, String), JjError> {
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revision,
+            flags::TEMPLATE,
+            r#"change_id ++ "\t" ++ commit_id"#,
+        ])?;
+        output
+            .trim()
+            .split_once('\t')
+            .map(|(change_id, commit_id)| (change_id.to_string(), commit_id.to_string()))
+            .ok_or_else(|| JjError::ParseError(format!("unexpected output: {}", output)))
+    }
+
+    /// Count the revisions in a revset
+    pub fn count_revisions(&self, revset: &str) -> Result<usize, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revset,
+            flags::TEMPLATE,
+            r#"commit_id.short() ++ "\n""#,
+        ])?;
+        Ok(output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count())
+    }
+
+    /// Change IDs (shortest unique prefix) of the revisions in a revset
+    pub fn change_ids(&self, revset: &str) -> Result<Vec<String>, JjError> {
+        let template = format!("{} ++ \"\\n\"", shortest_id("change_id", self.id_length));
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revset,
+            flags::TEMPLATE,
+            &template,
+        ])?;
+        Ok(output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Full commit IDs of the revisions in a revset
+    pub fn commit_ids(&self, revset: &str) -> Result<Vec<String>, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revset,
+            flags::TEMPLATE,
+            r#"commit_id ++ "\n""#,
+        ])?;
+        Ok(output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Full change ID and commit ID of the divergent revisions in a revset
+    pub fn divergent_ids(&self, revset: &str) -> Result<Vec<(String, String)>, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revset,
+            flags::TEMPLATE,
+            r#"if(divergent, change_id ++ "\t" ++ commit_id ++ "\n")"#,
+        ])?;
+        Ok(output
+            .lines()
+            .filter_map(|line| line.trim().split_once('\t'))
+            .map(|(change_id, commit_id)| (change_id.to_string(), commit_id.to_string()))
+            .collect())
+    }
+
+    /// Lines added/removed per revision of a revset, keyed by the commit ID
+    /// prefix the log shows
+    pub fn log_diff_stats(&self, revset: &str) -> Result<Vec<(String, DiffStat)>, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            revset,
+            flags::TEMPLATE,
+            &Templates::diff_stats(self.id_length),
+        ])?;
+        Ok(Parser::parse_diff_stats(&output))
+    }
+
     /// Check if a revision is immutable
     pub fn is_immutable(&self, revision: &str) -> bool {
         self.run_readonly_str(&[
@@ -394,7 +855,7 @@ impl JjExecutor {
             "  commit.commit_id().short(),",
             "  commit.change_id().short(),",
             "  commit.author().email(),",
-            "  commit.committer().timestamp().format(\"%Y-%m-%d %H:%M:%S\"),",
+            "  commit.committer().timestamp().local().format(\"%Y-%m-%dT%H:%M:%S%z\"),",
             "  if(commit.empty(), \"[empty]\", \"\"),",
             "  if(commit.description(), commit.description().first_line(), \"(no description set)\")",
             ") ++ \"\\n\""
@@ -503,17 +964,12 @@ impl JjExecutor {
 
     /// Run `jj op log` and parse the output into Operations
     ///
-    /// Returns a list of operations, most recent first.
+    /// Returns a list of operations, most recent first, including graph-only
+    /// rows for the operation graph (concurrent operations branch and merge).
     /// The first operation in the list is the current operation.
     pub fn op_log(&self, limit: Option<usize>) -> Result<Vec<Operation>, JjError> {
         let template = Templates::op_log();
-        let mut args = vec![
-            commands::OP,
-            commands::OP_LOG,
-            flags::NO_GRAPH,
-            flags::TEMPLATE,
-            template,
-        ];
+        let mut args = vec![commands::OP, commands::OP_LOG, flags::TEMPLATE, template];
 
         // Convert limit to String and store it
         let limit_str;
@@ -527,6 +983,51 @@ impl JjExecutor {
         Parser::parse_op_log(&output)
     }
 
+    /// Find the most recent divergent operation heads in the op log
+    ///
+    /// Returns `None` when no merge operation exists within the last
+    /// `DEFAULT_LOG_LIMIT` operations.
+    pub fn op_divergence(&self) -> Result<Option<OpDivergence>, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::OP,
+            commands::OP_LOG,
+            flags::NO_GRAPH,
+            flags::TEMPLATE,
+            Templates::op_log_with_parents(),
+            flags::LIMIT,
+            constants::DEFAULT_LOG_LIMIT,
+        ])?;
+        let nodes = Parser::parse_op_nodes(&output)?;
+        Ok(OpDivergence::find_latest(&nodes))
+    }
+
+    /// Run `jj op diff --from <from> --to <to>` and parse the changes
+    ///
+    /// `jj op diff --from @ --to <op>` previews what restoring `<op>` does.
+    pub fn op_diff(&self, from: &str, to: &str) -> Result<OpDiff, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::OP,
+            commands::OP_DIFF,
+            flags::FROM,
+            from,
+            flags::TO,
+            to,
+            flags::NO_GRAPH,
+        ])?;
+        Ok(Parser::parse_op_diff(&output))
+    }
+
+    /// Run `jj op show <operation_id>` (operation header plus the changes it made)
+    pub fn op_show(&self, operation_id: &str) -> Result<DiffContent, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::OP,
+            commands::OP_SHOW,
+            operation_id,
+            flags::NO_GRAPH,
+        ])?;
+        Ok(Parser::parse_op_show(&output))
+    }
+
     /// Run `jj op restore <operation_id>` to restore a previous state
     ///
     /// This restores the repository state to what it was after the specified operation.
@@ -576,11 +1077,14 @@ impl JjExecutor {
     /// Run `jj bookmark list --all-remotes` to get all bookmarks
     ///
     /// Returns both local and remote bookmarks with their tracking status.
-    /// Uses a template to output: name, remote, tracked (tab-separated).
+    /// Uses a template to output: name, remote, tracked (tab-separated),
+    /// plus `ahead,behind` counts for tracked remotes with a local bookmark.
     ///
     /// Note: Uses the `tracked` template field (jj 0.37+, guaranteed by startup check).
+    /// `tracking_ahead_count` counts commits on the *remote* that the local
+    /// bookmark lacks, so it is the local "behind" count (and vice versa).
     pub fn bookmark_list_all(&self) -> Result<Vec<Bookmark>, JjError> {
-        const BOOKMARK_LIST_TEMPLATE: &str = r#"separate("\t", name, remote, tracked) ++ "\n""#;
+        const BOOKMARK_LIST_TEMPLATE: &str = r#"separate("\t", name, remote, tracked, if(remote, if(tracking_present, tracking_behind_count().lower() ++ "," ++ tracking_ahead_count().lower()))) ++ "\n""#;
 
         let output = self.run_readonly_str(&[
             commands::BOOKMARK,
@@ -592,6 +1096,31 @@ impl JjExecutor {
         Ok(super::parser::parse_bookmark_list(&output))
     }
 
+    /// Targets of all local and remote bookmarks, at `at_operation` if given
+    ///
+    /// Compared before/after a fetch to spot remote bookmarks that were
+    /// renamed on the remote.
+    pub fn bookmark_targets(
+        &self,
+        at_operation: Option<&str>,
+    ) -> Result<Vec<BookmarkTarget>, JjError> {
+        const BOOKMARK_TARGET_TEMPLATE: &str = r#"name ++ "\t" ++ remote ++ "\t" ++ tracked ++ "\t" ++ if(normal_target, normal_target.commit_id()) ++ "\n""#;
+
+        let mut args = Vec::new();
+        if let Some(op) = at_operation {
+            args.extend([flags::AT_OPERATION, op]);
+        }
+        args.extend([
+            commands::BOOKMARK,
+            commands::BOOKMARK_LIST,
+            flags::ALL_REMOTES,
+            flags::TEMPLATE,
+            BOOKMARK_TARGET_TEMPLATE,
+        ]);
+        let output = self.run_readonly_str(&args)?;
+        Ok(super::parser::parse_bookmark_targets(&output))
+    }
+
     /// Get extended bookmark information for Bookmark Jump/View
     ///
     /// Two-stage approach:
@@ -610,8 +1139,12 @@ impl JjExecutor {
         // Step 2: Get revision info for local bookmarks
         // Template: explicitly format bookmarks as space-separated names
         // Using bookmarks.map(|x| x.name()).join(" ") for stable parsing
-        // Use short(8) to match LogView's change_id length for exact matching
-        const BOOKMARK_INFO_TEMPLATE: &str = r#"bookmarks.map(|x| x.name()).join(" ") ++ "\t" ++ change_id.short(8) ++ "\t" ++ commit_id.short(8) ++ "\t" ++ description.first_line() ++ "\n""#;
+        // Use the log template's shortest IDs so change_ids match LogView exactly
+        let bookmark_info_template = format!(
+            r#"bookmarks.map(|x| x.name()).join(" ") ++ "\t" ++ {} ++ "\t" ++ {} ++ "\t" ++ committer.timestamp().local().format("%Y-%m-%d %H:%M:%S") ++ "\t" ++ description.first_line() ++ "\n""#,
+            shortest_id("change_id", self.id_length),
+            shortest_id("commit_id", self.id_length),
+        );
 
         let log_output = self.run_readonly_str(&[
             commands::LOG,
@@ -619,26 +1152,28 @@ impl JjExecutor {
             flags::REVISION,
             "bookmarks()",
             flags::TEMPLATE,
-            BOOKMARK_INFO_TEMPLATE,
+            &bookmark_info_template,
         ])?;
 
-        // Parse log output into a map: bookmark_name -> (change_id, commit_id, description)
+        // Parse log output into a map:
+        // bookmark_name -> (change_id, commit_id, timestamp, description)
         // Note: This only includes LOCAL bookmarks (from `jj log -r 'bookmarks()'`)
-        let mut info_map: HashMap<String, (String, String, String)> = HashMap::new();
+        let mut info_map: HashMap<String, (String, String, String, String)> = HashMap::new();
         for line in log_output.lines() {
-            let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() >= 4 {
+            // Description is last so tabs inside it stay in the description
+            let parts: Vec<&str> = line.splitn(5, '\t').collect();
+            if parts.len() >= 5 {
                 let bookmark_names = parts[0]; // Space-separated bookmark names
-                let change_id = parts[1].to_string();
-                let commit_id = parts[2].to_string();
-                let description = parts[3].to_string();
+                let info = (
+                    parts[1].to_string(),
+                    parts[2].to_string(),
+                    parts[3].to_string(),
+                    parts[4].to_string(),
+                );
 
                 // Multiple bookmarks may point to the same commit
                 for name in bookmark_names.split_whitespace() {
-                    info_map.insert(
-                        name.to_string(),
-                        (change_id.clone(), commit_id.clone(), description.clone()),
-                    );
+                    info_map.insert(name.to_string(), info.clone());
                 }
             }
         }
@@ -657,9 +1192,10 @@ impl JjExecutor {
                     None
                 };
                 BookmarkInfo {
-                    change_id: info.map(|(c, _, _)| ChangeId::new(c.clone())),
-                    commit_id: info.map(|(_, c, _)| CommitId::new(c.clone())),
-                    description: info.map(|(_, _, d)| d.clone()),
+                    change_id: info.map(|(c, _, _, _)| ChangeId::new(c.clone())),
+                    commit_id: info.map(|(_, c, _, _)| CommitId::new(c.clone())),
+                    description: info.map(|(_, _, _, d)| d.clone()),
+                    timestamp: info.map(|(_, _, t, _)| t.clone()),
                     bookmark,
                 }
             })
@@ -747,6 +1283,25 @@ impl JjExecutor {
         Ok(output.trim() == "true")
     }
 
+    /// Number of conflicted changes in `revset`
+    ///
+    /// Uses `jj log -r '(<revset>) & conflicts()'`, one line per change.
+    pub fn count_conflicts(&self, revset: &str) -> Result<usize, JjError> {
+        let filter = format!("({}) & conflicts()", revset);
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            &filter,
+            flags::TEMPLATE,
+            r#"change_id ++ "\n""#,
+        ])?;
+        Ok(output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count())
+    }
+
     /// Run `jj absorb` to move changes into ancestor commits
     ///
     /// Each hunk in the working copy (@) is moved to the closest mutable
@@ -809,6 +1364,21 @@ impl JjExecutor {
         Ok(Parser::parse_resolve_list(&output))
     }
 
+    /// Show file contents at a revision (`jj file show`)
+    ///
+    /// For conflicted files the output contains jj's conflict markers.
+    pub fn file_show(&self, file_path: &str, revision: Option<&str>) -> Result<String, JjError> {
+        let mut args = vec![commands::FILE, commands::FILE_SHOW];
+
+        if let Some(rev) = revision {
+            args.push(flags::REVISION);
+            args.push(rev);
+        }
+
+        args.push(file_path);
+        self.run_readonly_str(&args)
+    }
+
     /// Resolve a conflict using a built-in tool (:ours or :theirs)
     ///
     /// Works for any change (not just @).
@@ -868,6 +1438,7 @@ impl JjExecutor {
     /// Note: `jj duplicate` writes its result to stderr, not stdout.
     /// Output format: "Duplicated <commit_id> as <new_change_id> <new_commit_id> <description>"
     pub fn duplicate(&self, revision: &str) -> Result<String, JjError> {
+        self.ensure_writable()?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
         if let Some(ref path) = self.repo_path {
             cmd.arg(flags::REPO_PATH).arg(path);
@@ -875,7 +1446,7 @@ impl JjExecutor {
         cmd.arg(flags::NO_COLOR);
         cmd.args([commands::DUPLICATE, revision]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -942,6 +1513,227 @@ impl JjExecutor {
             .collect())
     }
 
+    /// Run `jj git remote list` to get remote names with their URLs
+    pub fn git_remote_list_with_urls(&self) -> Result<Vec<RemoteInfo>, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::GIT,
+            commands::GIT_REMOTE,
+            commands::GIT_REMOTE_LIST,
+        ])?;
+        Ok(super::parser::parse_remote_list(&output))
+    }
+
+    /// Run `jj sparse list` (`.` alone means a full checkout)
+    pub fn sparse_list(&self) -> Result<Vec<String>, JjError> {
+        let output = self.run_readonly_str(&[commands::SPARSE, commands::SPARSE_LIST])?;
+        Ok(super::parser::parse_sparse_list(&output))
+    }
+
+    /// Run a user-typed command after checking it only reads the repo
+    ///
+    /// Used by the `:` prompt; see [`super::check_read_only`].
+    pub fn run_user_command(&self, args: &[String]) -> Result<String, JjError> {
+        super::check_read_only(args).map_err(JjError::NotReadOnly)?;
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.run_readonly_str(&args)
+    }
+
+    /// Run `jj config get <key>`
+    ///
+    /// Returns `None` when the key is unset (jj exits non-zero) or empty.
+    pub fn config_get(&self, key: &str) -> Result<Option<String>, JjError> {
+        match self.run_readonly_str(&[commands::CONFIG, commands::CONFIG_GET, key]) {
+            Ok(value) => {
+                let value = value.trim();
+                Ok((!value.is_empty()).then(|| value.to_string()))
+            }
+            Err(JjError::CommandFailed { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Set a key in the user config (`jj config set --user`)
+    ///
+    /// `value` is TOML; callers quote strings themselves.
+    pub fn config_set_user(&self, key: &str, value: &str) -> Result<String, JjError> {
+        self.run_str(&[
+            commands::CONFIG,
+            commands::CONFIG_SET,
+            flags::USER,
+            key,
+            value,
+        ])
+    }
+
+    /// Effective config as `key = value` lines (`jj config list`)
+    pub fn config_list(&self) -> Result<String, JjError> {
+        self.run_readonly_str(&[commands::CONFIG, commands::CONFIG_LIST])
+    }
+
+    /// Effective config including jj's built-in defaults (merge tools,
+    /// aliases) as `key = value` lines
+    pub fn config_list_with_defaults(&self) -> Result<String, JjError> {
+        self.run_readonly_str(&[
+            commands::CONFIG,
+            commands::CONFIG_LIST,
+            flags::INCLUDE_DEFAULTS,
+        ])
+    }
+
+    /// `jj version` output, e.g. `jj 0.41.0`
+    pub fn version(&self) -> Result<String, JjError> {
+        Ok(self
+            .run_readonly_str(&[commands::VERSION])?
+            .trim()
+            .to_string())
+    }
+
+    /// Named revset presets from `[tij.revset-presets]`, in config order
+    pub fn revset_presets(&self) -> Result<Vec<RevsetPreset>, JjError> {
+        match self.run_readonly_str(&[
+            commands::CONFIG,
+            commands::CONFIG_LIST,
+            config_keys::REVSET_PRESETS,
+        ]) {
+            Ok(output) => Ok(super::parser::parse_config_strings(
+                &output,
+                config_keys::REVSET_PRESETS,
+            )
+            .into_iter()
+            .map(|(name, revset)| RevsetPreset { name, revset })
+            .collect()),
+            Err(JjError::CommandFailed { .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Save a revset preset to the user config
+    ///
+    /// The revset is passed as a quoted TOML string so jj never reads it
+    /// as a number or boolean.
+    pub fn save_revset_preset(&self, name: &str, revset: &str) -> Result<String, JjError> {
+        let key = format!("{}.{}", config_keys::REVSET_PRESETS, name);
+        let value = format!("\"{}\"", revset.replace('\\', "\\\\").replace('"', "\\\""));
+        self.run_str(&[
+            commands::CONFIG,
+            commands::CONFIG_SET,
+            flags::USER,
+            &key,
+            &value,
+        ])
+    }
+
+    /// String entries below a config table, in config order
+    ///
+    /// Keys are relative to the table (`log.x` for `tij.keys.log.x`).
+    pub fn config_strings(&self, table: &str) -> Result<Vec<(String, String)>, JjError> {
+        match self.run_readonly_str(&[commands::CONFIG, commands::CONFIG_LIST, table]) {
+            Ok(output) => Ok(super::parser::parse_config_strings(&output, table)),
+            Err(JjError::CommandFailed { .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Named compare sessions from `[tij.compare-sessions]`, in config order
+    pub fn compare_sessions(&self) -> Result<Vec<CompareSession>, JjError> {
+        match self.run_readonly_str(&[
+            commands::CONFIG,
+            commands::CONFIG_LIST,
+            config_keys::COMPARE_SESSIONS,
+        ]) {
+            Ok(output) => Ok(CompareSession::from_config_pairs(
+                super::parser::parse_config_strings(&output, config_keys::COMPARE_SESSIONS),
+            )),
+            Err(JjError::CommandFailed { .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Save a compare session (`from`/`to` as quoted TOML strings) to the user config
+    pub fn save_compare_session(&self, name: &str, from: &str, to: &str) -> Result<(), JjError> {
+        for (field, revision) in [("from", from), ("to", to)] {
+            let key = format!("{}.{}.{}", config_keys::COMPARE_SESSIONS, name, field);
+            let value = format!(
+                "\"{}\"",
+                revision.replace('\\', "\\\\").replace('"', "\\\"")
+            );
+            self.run_str(&[
+                commands::CONFIG,
+                commands::CONFIG_SET,
+                flags::USER,
+                &key,
+                &value,
+            ])?;
+        }
+        Ok(())
+    }
+
+    /// Remove a compare session from the user config
+    pub fn delete_compare_session(&self, name: &str) -> Result<String, JjError> {
+        let key = format!("{}.{}", config_keys::COMPARE_SESSIONS, name);
+        self.run_str(&[commands::CONFIG, commands::CONFIG_UNSET, flags::USER, &key])
+    }
+
+    /// Pinned revisions from `[tij.pins]`, sorted by slot
+    pub fn pins(&self) -> Result<Vec<Pin>, JjError> {
+        match self.run_readonly_str(&[commands::CONFIG, commands::CONFIG_LIST, config_keys::PINS]) {
+            Ok(output) => Ok(Pin::from_config_pairs(super::parser::parse_config_strings(
+                &output,
+                config_keys::PINS,
+            ))),
+            Err(JjError::CommandFailed { .. }) => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Pin `revision` to `slot` in the repo config (quoted TOML string)
+    pub fn set_pin(&self, slot: u8, revision: &str) -> Result<String, JjError> {
+        let key = format!("{}.{}", config_keys::PINS, slot);
+        self.run_str(&[
+            commands::CONFIG,
+            commands::CONFIG_SET,
+            flags::REPO,
+            &key,
+            &toml_string(revision),
+        ])
+    }
+
+    /// Remove the pin in `slot` from the repo config
+    pub fn remove_pin(&self, slot: u8) -> Result<String, JjError> {
+        let key = format!("{}.{}", config_keys::PINS, slot);
+        self.run_str(&[commands::CONFIG, commands::CONFIG_UNSET, flags::REPO, &key])
+    }
+
+    /// Local notes from `[tij.notes]`
+    pub fn notes(&self) -> Result<Notes, JjError> {
+        match self.run_readonly_str(&[commands::CONFIG, commands::CONFIG_LIST, config_keys::NOTES])
+        {
+            Ok(output) => Ok(Notes::from_config_pairs(
+                super::parser::parse_config_strings(&output, config_keys::NOTES),
+            )),
+            Err(JjError::CommandFailed { .. }) => Ok(Notes::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Attach a note to a change (full change ID) in the repo config
+    pub fn set_note(&self, change_id: &str, text: &str) -> Result<String, JjError> {
+        let key = format!("{}.{}", config_keys::NOTES, change_id);
+        self.run_str(&[
+            commands::CONFIG,
+            commands::CONFIG_SET,
+            flags::REPO,
+            &key,
+            &toml_string(text),
+        ])
+    }
+
+    /// Remove the note on a change (full change ID)
+    pub fn remove_note(&self, change_id: &str) -> Result<String, JjError> {
+        let key = format!("{}.{}", config_keys::NOTES, change_id);
+        self.run_str(&[commands::CONFIG, commands::CONFIG_UNSET, flags::REPO, &key])
+    }
+
     /// Run `jj git push --bookmark <name>` to push a bookmark to remote
     ///
     /// Pushes the specified bookmark to the default remote (origin).
@@ -994,7 +1786,7 @@ impl JjExecutor {
             bookmark_name,
         ]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1084,7 +1876,7 @@ impl JjExecutor {
             remote,
         ]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1138,7 +1930,7 @@ impl JjExecutor {
             remote,
         ]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1173,7 +1965,7 @@ impl JjExecutor {
             change_id,
         ]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1230,7 +2022,7 @@ impl JjExecutor {
         }
         cmd.args(&args);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1397,7 +2189,7 @@ impl JjExecutor {
             revision,
         ]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1435,7 +2227,7 @@ impl JjExecutor {
             remote,
         ]);
 
-        let output = cmd.output().map_err(|e| {
+        let output = self.output_logged(&mut cmd).map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
                 JjError::JjNotFound
             } else {
@@ -1487,6 +2279,54 @@ impl JjExecutor {
         self.run_readonly_str(&[commands::DIFF, flags::REVISION, revision])
     }
 
+    /// Run `jj diff -r <revision> <file_path>` and parse it (a single file's diff)
+    pub fn diff_file(&self, revision: &str, file_path: &str) -> Result<DiffContent, JjError> {
+        let output =
+            self.run_readonly_str(&[commands::DIFF, flags::REVISION, revision, file_path])?;
+        Ok(Parser::parse_diff_body(&output))
+    }
+
+    /// Run `jj diff --stat -r <revision>` (raw; per-file histogram plus a
+    /// `N files changed, ...` summary line)
+    pub fn diff_stat(&self, revision: &str) -> Result<String, JjError> {
+        self.run_readonly_str(&[commands::DIFF, flags::STAT, flags::REVISION, revision])
+    }
+
+    /// Run `jj diff --summary -r <revision>`: the files the revision changes
+    pub fn diff_summary(&self, revision: &str) -> Result<Vec<FileStatus>, JjError> {
+        let output =
+            self.run_readonly_str(&[commands::DIFF, flags::SUMMARY, flags::REVISION, revision])?;
+        Ok(Parser::parse_diff_summary(&output))
+    }
+
+    /// Run `jj diff --summary -r <revision> <file_path>`: the file's status
+    /// without a full `jj status` (empty when it is unchanged)
+    pub fn diff_summary_file(
+        &self,
+        revision: &str,
+        file_path: &str,
+    ) -> Result<Vec<FileStatus>, JjError> {
+        let output = self.run_readonly_str(&[
+            commands::DIFF,
+            flags::SUMMARY,
+            flags::REVISION,
+            revision,
+            file_path,
+        ])?;
+        Ok(Parser::parse_diff_summary(&output))
+    }
+
+    /// Run `jj diff --git -r <revision> <file_path>` (raw; one file's unified diff)
+    pub fn diff_file_git(&self, revision: &str, file_path: &str) -> Result<String, JjError> {
+        self.run_readonly_str(&[
+            commands::DIFF,
+            flags::GIT_FORMAT,
+            flags::REVISION,
+            revision,
+            file_path,
+        ])
+    }
+
     /// Run `jj diff --git -r <change_id>` for git-compatible unified patch output
     ///
     /// Produces output suitable for `git apply`.
@@ -1561,14 +2401,14 @@ impl JjExecutor {
         &self,
         change_id: &str,
     ) -> Result<(String, Vec<String>, String, String, String), JjError> {
-        let template = Templates::change_info();
+        let template = Templates::change_info(self.id_length);
         let output = self.run_readonly_str(&[
             commands::LOG,
             flags::NO_GRAPH,
             flags::REVISION,
             change_id,
             flags::TEMPLATE,
-            template,
+            &template,
         ])?;
 
         let line = output.lines().next().unwrap_or("");
@@ -1601,14 +2441,14 @@ impl JjExecutor {
     ///
     /// Returns AnnotationContent containing line-by-line blame information.
     ///
-    /// Uses a custom template with `change_id.short(8)` to ensure change_id
-    /// length matches the log template, enabling reliable cross-view ID matching.
+    /// Uses the same `shortest(<len>)` change_id as the log template, enabling
+    /// reliable cross-view ID matching.
     pub fn file_annotate(
         &self,
         file_path: &str,
         revision: Option<&str>,
     ) -> Result<AnnotationContent, JjError> {
-        let template = Templates::file_annotate();
+        let template = Templates::file_annotate(self.id_length);
         let mut args = vec![commands::FILE, commands::FILE_ANNOTATE];
 
         if let Some(rev) = revision {
@@ -1617,7 +2457,7 @@ impl JjExecutor {
         }
 
         args.push(flags::TEMPLATE);
-        args.push(template);
+        args.push(&template);
         args.push(file_path);
 
         let output = self.run_readonly_str(&args)?;
@@ -1631,13 +2471,17 @@ impl JjExecutor {
     /// Uses a single-stage query (unlike bookmarks which need 2 stages)
     /// because `jj tag list -T` can access `normal_target` directly.
     pub fn tag_list(&self) -> Result<Vec<TagInfo>, JjError> {
-        const TAG_LIST_TEMPLATE: &str = r#"separate("\t", name, if(remote, remote, ""), if(present, "true", "false"), if(tracked, "true", "false"), normal_target.change_id().short(8), normal_target.commit_id().short(8), normal_target.description().first_line()) ++ "\n""#;
+        let tag_list_template = format!(
+            r#"separate("\t", name, if(remote, remote, ""), if(present, "true", "false"), if(tracked, "true", "false"), {}, {}, normal_target.description().first_line()) ++ "\n""#,
+            shortest_id("normal_target.change_id()", self.id_length),
+            shortest_id("normal_target.commit_id()", self.id_length),
+        );
 
         let output = self.run_readonly_str(&[
             commands::TAG,
             commands::TAG_LIST,
             flags::TEMPLATE,
-            TAG_LIST_TEMPLATE,
+            &tag_list_template,
         ])?;
         Ok(super::parser::parse_tag_list(&output))
     }
@@ -1670,23 +2514,56 @@ impl JjExecutor {
         Ok(output.trim().to_string())
     }
 
+    /// List the current stack (`trunk()..@`), top of the stack first
+    pub fn stack(&self) -> Result<Vec<StackEntry>, JjError> {
+        let template = Templates::stack(self.id_length);
+        let output = self.run_readonly_str(&[
+            commands::LOG,
+            flags::NO_GRAPH,
+            flags::REVISION,
+            constants::STACK_REVSET,
+            flags::TEMPLATE,
+            &template,
+        ])?;
+        Ok(super::parser::parse_stack(&output))
+    }
+
     /// List all workspaces
     pub fn workspace_list(&self) -> Result<Vec<WorkspaceInfo>, JjError> {
-        let template = Templates::workspace_list();
+        let template = Templates::workspace_list(self.id_length);
         let output = self.run_readonly_str(&[
             commands::WORKSPACE,
             commands::WORKSPACE_LIST,
             flags::TEMPLATE,
-            template,
+            &template,
         ])?;
         Ok(super::parser::parse_workspace_list(&output))
     }
 }
 
+/// Quote a value for `jj config set` as a TOML basic string
+fn toml_string(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_toml_string_escapes_quotes_and_newlines() {
+        assert_eq!(
+            toml_string("say \"hi\"\nC:\\tmp"),
+            r#""say \"hi\"\nC:\\tmp""#
+        );
+    }
+
     #[test]
     fn test_executor_default() {
         let executor = JjExecutor::default();
@@ -1699,6 +2576,50 @@ mod tests {
         assert_eq!(executor.repo_path(), Some(&PathBuf::from("/tmp/test")));
     }
 
+    #[test]
+    fn test_read_only_refuses_writes_but_not_reads() {
+        let mut executor = JjExecutor::new();
+        executor.set_read_only(true);
+        assert!(matches!(executor.run(&["new"]), Err(JjError::ReadOnly)));
+        assert!(matches!(executor.duplicate("@"), Err(JjError::ReadOnly)));
+        // Reads and config are let through (whatever jj itself then returns)
+        let read = executor.run(&[flags::NO_INTEGRATE_OPERATION, commands::LOG]);
+        assert!(!matches!(read, Err(JjError::ReadOnly)));
+        let config = executor.run(&[commands::CONFIG, commands::CONFIG_GET, "user.name"]);
+        assert!(!matches!(config, Err(JjError::ReadOnly)));
+    }
+
+    #[test]
+    fn test_diff_flags_follow_the_diff_subcommand() {
+        let args = |executor: &JjExecutor, args: &[&str]| -> Vec<String> {
+            executor
+                .command(args)
+                .get_args()
+                .skip(1) // --color=never
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect()
+        };
+        let executor = JjExecutor::new().with_diff_flags(vec!["--context".into(), "1".into()]);
+        assert_eq!(
+            args(
+                &executor,
+                &[flags::NO_INTEGRATE_OPERATION, "show", "-r", "@"]
+            ),
+            [
+                flags::NO_INTEGRATE_OPERATION,
+                "show",
+                "--context",
+                "1",
+                "-r",
+                "@"
+            ]
+        );
+        // Other commands, including `op diff` and `file show`, are left alone
+        assert_eq!(args(&executor, &["op", "diff"]), ["op", "diff"]);
+        assert_eq!(args(&executor, &["file", "show"]), ["file", "show"]);
+        assert_eq!(args(&JjExecutor::new(), &["diff"]), ["diff"]);
+    }
+
     #[test]
     fn test_push_bulk_mode_flag() {
         assert_eq!(PushBulkMode::All.flag(), "--all");
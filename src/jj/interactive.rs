@@ -6,12 +6,27 @@
 //! I/O patterns (Stdio::inherit vs captured output).
 
 use std::io;
-use std::process::{Command, ExitStatus, Stdio};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::time::Instant;
 
 use super::constants::{self, commands, flags};
 use super::executor::JjExecutor;
 
 impl JjExecutor {
+    /// Run `jj git init <dir>` with inherited stdio (`tij init`)
+    ///
+    /// Runs before the TUI starts, so jj's output goes straight to the
+    /// terminal. The repository path is not passed: the repo doesn't
+    /// exist yet.
+    pub fn git_init_interactive(&self, dir: &str) -> io::Result<ExitStatus> {
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+        cmd.args([commands::GIT, commands::GIT_INIT, dir])
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
+    }
+
     /// Run `jj squash --from <source> --into <destination>` interactively
     ///
     /// Moves changes from the source revision into the destination.
@@ -25,6 +40,7 @@ impl JjExecutor {
         source: &str,
         destination: &str,
     ) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -34,8 +50,8 @@ impl JjExecutor {
         cmd.args([commands::SQUASH, "--from", source, "--into", destination])
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Run `jj describe -r <change-id> --edit` interactively
@@ -47,6 +63,7 @@ impl JjExecutor {
     /// Note: Unlike `run()`, this method does NOT use `--color=never`
     /// because interactive mode benefits from the editor's native behavior.
     pub fn describe_edit_interactive(&self, revision: &str) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -56,8 +73,8 @@ impl JjExecutor {
         cmd.args([commands::DESCRIBE, "-r", revision, flags::EDITOR_FLAG])
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Run `jj split -r <change-id>` interactively
@@ -69,6 +86,7 @@ impl JjExecutor {
     /// Note: Unlike `run()`, this method does NOT use `--color=never`
     /// because interactive mode benefits from color output in the diff editor.
     pub fn split_interactive(&self, revision: &str) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         // repo_path がある場合は -R を付与（tij /path/to/repo 対応）
@@ -79,8 +97,8 @@ impl JjExecutor {
         cmd.args([commands::SPLIT, "-r", revision])
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Run `jj diffedit -r <revision>` interactively
@@ -88,6 +106,7 @@ impl JjExecutor {
     /// Opens the configured diff editor to edit the changes in a revision.
     /// The caller must disable raw mode before calling this method.
     pub fn diffedit_interactive(&self, revision: &str) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -97,8 +116,8 @@ impl JjExecutor {
         cmd.args([commands::DIFFEDIT, flags::REVISION, revision])
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Run `jj diffedit -r <revision> <file>` interactively
@@ -106,6 +125,7 @@ impl JjExecutor {
     /// Opens the configured diff editor for a specific file in a revision.
     /// The caller must disable raw mode before calling this method.
     pub fn diffedit_file_interactive(&self, revision: &str, file: &str) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -115,8 +135,32 @@ impl JjExecutor {
         cmd.args([commands::DIFFEDIT, flags::REVISION, revision, file])
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
+    }
+
+    /// Run `jj diff --tool <tool>` interactively
+    ///
+    /// `revisions` is `["-r", rev]` or `["--from", a, "--to", b]`; `file`
+    /// narrows the diff to one path. Blocks until the tool exits, so the
+    /// caller must disable raw mode before calling this method.
+    pub fn diff_tool_interactive(
+        &self,
+        tool: &str,
+        revisions: &[&str],
+        file: Option<&str>,
+    ) -> io::Result<ExitStatus> {
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+
+        if let Some(repo_path) = self.repo_path() {
+            cmd.arg(flags::REPO_PATH).arg(repo_path);
+        }
+
+        cmd.args(diff_tool_args(tool, revisions, file))
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Resolve a conflict interactively using an external merge tool
@@ -129,6 +173,7 @@ impl JjExecutor {
         file_path: &str,
         revision: Option<&str>,
     ) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -145,8 +190,8 @@ impl JjExecutor {
         cmd.args(args)
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Run `jj arrange` interactively
@@ -155,6 +200,7 @@ impl JjExecutor {
     /// The caller must call suspend_tui() before invoking this method.
     /// Available since jj 0.40.0.
     pub fn arrange_interactive(&self, revset: Option<&str>) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -170,8 +216,8 @@ impl JjExecutor {
         cmd.args(args)
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+            .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
     }
 
     /// Run `jj bisect run --range <good>..<bad> -- bash -c <command>` interactively
@@ -184,6 +230,7 @@ impl JjExecutor {
         bad: &str,
         command: &str,
     ) -> io::Result<ExitStatus> {
+        self.ensure_writable().map_err(io::Error::other)?;
         let mut cmd = Command::new(constants::JJ_COMMAND);
 
         if let Some(repo_path) = self.repo_path() {
@@ -203,7 +250,101 @@ impl JjExecutor {
         ])
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
+        .stderr(Stdio::inherit());
+        self.status_logged(&mut cmd)
+    }
+
+    /// Run `jj util exec -- sh -c <script>` for a user-configured hook
+    ///
+    /// stderr is folded into stdout by the shell so `consume` can echo and
+    /// capture a single stream from the child's piped stdout; the child is
+    /// reaped afterwards. stdin stays inherited for hooks that prompt. The
+    /// hook runs from the repository root when `-R` was given.
+    /// The caller must call suspend_tui() before invoking this method.
+    pub fn util_exec_hook<T>(
+        &self,
+        command: &str,
+        consume: impl FnOnce(&mut Child) -> T,
+    ) -> io::Result<(ExitStatus, T)> {
+        self.ensure_writable().map_err(io::Error::other)?;
+        let mut cmd = Command::new(constants::JJ_COMMAND);
+
+        if let Some(repo_path) = self.repo_path() {
+            cmd.arg(flags::REPO_PATH).arg(repo_path);
+            cmd.current_dir(repo_path);
+        }
+
+        let script = hook_script(command);
+        cmd.args([
+            commands::UTIL,
+            commands::UTIL_EXEC,
+            "--",
+            "sh",
+            "-c",
+            &script,
+        ])
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit());
+
+        let started = Instant::now();
+        let mut child = cmd.spawn()?;
+        let value = consume(&mut child);
+        let status = child.wait();
+        let exit_code = status.as_ref().ok().and_then(ExitStatus::code);
+        self.command_log().record(&cmd, started, exit_code);
+        Ok((status?, value))
+    }
+}
+
+/// Arguments of `jj diff --tool` (file paths go after `--`)
+fn diff_tool_args<'a>(tool: &'a str, revisions: &[&'a str], file: Option<&'a str>) -> Vec<&'a str> {
+    let mut args = vec![commands::DIFF, flags::TOOL, tool];
+    args.extend_from_slice(revisions);
+    if let Some(file) = file {
+        args.extend(["--", file]);
+    }
+    args
+}
+
+/// Wrap a hook command so its stderr is merged into stdout
+///
+/// The closing brace sits on its own line so a trailing `# comment` in the
+/// configured command cannot swallow it.
+fn hook_script(command: &str) -> String {
+    format!("{{ {}\n}} 2>&1", command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_tool_args_revision() {
+        assert_eq!(
+            diff_tool_args("meld", &["-r", "abc"], None),
+            vec!["diff", "--tool", "meld", "-r", "abc"]
+        );
+    }
+
+    #[test]
+    fn test_diff_tool_args_range_and_file() {
+        assert_eq!(
+            diff_tool_args("meld", &["--from", "a", "--to", "b"], Some("-odd.rs")),
+            vec![
+                "diff", "--tool", "meld", "--from", "a", "--to", "b", "--", "-odd.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hook_script_merges_stderr() {
+        assert_eq!(hook_script("cargo test"), "{ cargo test\n} 2>&1");
+    }
+
+    #[test]
+    fn test_hook_script_survives_trailing_comment() {
+        let script = hook_script("make lint # fast");
+        assert!(script.ends_with("\n} 2>&1"));
     }
 }
@@ -2,17 +2,31 @@
 //!
 //! This module handles executing jj commands and parsing their output.
 
+mod cancel;
+mod clone;
+mod command_line;
+mod command_log;
 pub mod constants;
 mod executor;
 mod interactive;
 /// Parser module (public for integration testing)
 pub mod parser;
+mod prompt;
 mod template;
+mod version;
 
+pub use cancel::CancelHook;
+pub use clone::{CloneJob, clone_destination, progress_percent};
+pub use command_line::{check_read_only, split_command_line};
+pub use command_log::{CommandLog, CommandLogEntry};
 pub use executor::{JjExecutor, PushBulkMode, RunResult};
 pub use parser::{
     PushPreviewAction, PushPreviewResult, SkippedRef, parse_push_dry_run, parse_push_skipped,
 };
+pub use prompt::TerminalHandoff;
+/// Templates (public for golden-file tests)
+pub use template::Templates;
+pub use version::{MIN_JJ_VERSION, feature_gates, parse_jj_version};
 
 use std::io;
 use thiserror::Error;
@@ -34,4 +48,15 @@ pub enum JjError {
 
     #[error("jj is not installed or not in PATH")]
     JjNotFound,
+
+    #[error("Read-only mode: another tij instance is running on this workspace")]
+    ReadOnly,
+
+    /// A fetch/push was killed on request; `before_op` is the operation
+    /// that was current when it started, to find out what got recorded
+    #[error("cancelled by user")]
+    Cancelled { before_op: Option<String> },
+
+    #[error("Not allowed: {0}")]
+    NotReadOnly(String),
 }
@@ -38,7 +38,7 @@ impl Parser {
     /// Parse a single line of `jj file annotate` output using regex
     ///
     /// Format: `<change_id>\t<commit_id> <author> <timestamp>  <line_number>: <content>`
-    /// Example: `twzksoxt\tabcd1234 nakamura 2026-01-30 10:43:19    1: //! Tij`
+    /// Example: `twzksoxt\tabcd1234 nakamura 2026-01-30T10:43:19+0900    1: //! Tij`
     pub(super) fn parse_annotate_line(
         line: &str,
         prev_change_id: &Option<ChangeId>,
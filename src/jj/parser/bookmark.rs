@@ -1,21 +1,28 @@
 //! Parser for `jj bookmark list --all-remotes` output
 
-use crate::model::Bookmark;
+use crate::model::{Bookmark, BookmarkTarget, TrackingCounts};
 
 /// Parse `jj bookmark list --all-remotes -T ...` output
 ///
-/// Template format: `separate("\t", name, remote, tracked) ++ "\n"`
+/// Template format:
+/// `separate("\t", name, remote, tracked, <counts if tracking_present>) ++ "\n"`
 ///
 /// Note: jj's `separate()` skips empty fields, so output varies:
 /// - Local bookmark: `name\ttracked` (2 fields, remote is empty/skipped)
 /// - Remote bookmark: `name\tremote\ttracked` (3 fields)
+/// - Tracked remote with a local counterpart: `name\tremote\ttracked\tahead,behind`
+///   (4 fields, counts from the local bookmark's point of view)
 ///
 /// Output examples:
 /// - `main\tfalse` (local bookmark, 2 fields)
 /// - `feature-x\torigin\tfalse` (untracked remote bookmark, 3 fields)
 /// - `main\torigin\ttrue` (tracked remote bookmark, 3 fields)
+/// - `main\torigin\ttrue\t0,5` (tracked remote, local is 5 commits behind)
+///
+/// Local bookmarks inherit the counts of their first tracked remote so
+/// callers that only look at local names (Log badges) can show them.
 pub fn parse_bookmark_list(output: &str) -> Vec<Bookmark> {
-    output
+    let mut bookmarks: Vec<Bookmark> = output
         .lines()
         .filter(|line| !line.is_empty())
         .filter_map(|line| {
@@ -29,29 +36,100 @@ pub fn parse_bookmark_list(output: &str) -> Vec<Bookmark> {
                         name,
                         remote: None,
                         is_tracked,
+                        tracking: None,
                     })
                 }
-                3 => {
-                    // Remote bookmark: name\tremote\ttracked
+                3 | 4 => {
+                    // Remote bookmark: name\tremote\ttracked[\tahead,behind]
                     let name = parts[0].to_string();
                     let remote = Some(parts[1].to_string());
                     let is_tracked = parts[2] == "true";
+                    let tracking = match parts.get(3) {
+                        Some(counts) => Some(parse_tracking_counts(counts)?),
+                        None => None,
+                    };
                     Some(Bookmark {
                         name,
                         remote,
                         is_tracked,
+                        tracking,
                     })
                 }
                 _ => None, // Malformed line
             }
         })
+        .collect();
+
+    // Copy remote counts onto the local bookmark of the same name
+    let remote_counts: Vec<(String, TrackingCounts)> = bookmarks
+        .iter()
+        .filter(|b| b.remote.as_deref().is_some_and(|r| r != "git"))
+        .filter_map(|b| b.tracking.map(|t| (b.name.clone(), t)))
+        .collect();
+    for bookmark in bookmarks.iter_mut().filter(|b| b.remote.is_none()) {
+        bookmark.tracking = remote_counts
+            .iter()
+            .find(|(name, _)| *name == bookmark.name)
+            .map(|(_, counts)| *counts);
+    }
+
+    bookmarks
+}
+
+/// Parse `jj bookmark list --all-remotes` with the target template
+///
+/// Template format (no `separate()`, so every line has 4 fields):
+/// `name ++ "\t" ++ remote ++ "\t" ++ tracked ++ "\t" ++ <commit_id if normal>`
+///
+/// Bookmarks of the `git` pseudo-remote are left out.
+pub fn parse_bookmark_targets(output: &str) -> Vec<BookmarkTarget> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let name = parts.next().filter(|name| !name.is_empty())?;
+            let remote = parts.next()?;
+            let is_tracked = parts.next()? == "true";
+            let commit_id = parts.next().unwrap_or_default();
+            if remote == "git" {
+                return None;
+            }
+            Some(BookmarkTarget {
+                name: name.to_string(),
+                remote: (!remote.is_empty()).then(|| remote.to_string()),
+                is_tracked,
+                commit_id: (!commit_id.is_empty()).then(|| commit_id.to_string()),
+            })
+        })
         .collect()
 }
 
+/// Parse `ahead,behind` (e.g. `2,5`)
+fn parse_tracking_counts(field: &str) -> Option<TrackingCounts> {
+    let (ahead, behind) = field.split_once(',')?;
+    Some(TrackingCounts {
+        ahead: ahead.trim().parse().ok()?,
+        behind: behind.trim().parse().ok()?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_bookmark_targets() {
+        let output = "main\t\tfalse\tabc123\nmain\torigin\ttrue\tabc123\nmain\tgit\tfalse\tabc123\nwip\t\tfalse\t\n";
+        let targets = parse_bookmark_targets(output);
+        assert_eq!(targets.len(), 3);
+        assert_eq!(targets[0].remote, None);
+        assert_eq!(targets[1].remote.as_deref(), Some("origin"));
+        assert!(targets[1].is_tracked);
+        assert_eq!(targets[1].commit_id.as_deref(), Some("abc123"));
+        // Conflicted bookmark: no single target
+        assert_eq!(targets[2].commit_id, None);
+    }
+
     #[test]
     fn test_parse_bookmark_list() {
         // jj's separate() skips empty fields, so:
@@ -92,7 +170,7 @@ mod tests {
         let bookmarks = parse_bookmark_list(output);
         assert!(bookmarks.is_empty());
 
-        // Line with 4+ fields should also be skipped
+        // Line with a non-count 4th field (or 5+ fields) should also be skipped
         let output2 = "name\tremote\ttrue\textra\n";
         let bookmarks2 = parse_bookmark_list(output2);
         assert!(bookmarks2.is_empty());
@@ -114,4 +192,31 @@ mod tests {
         assert_eq!(untracked.len(), 1);
         assert_eq!(untracked[0].name, "feature");
     }
+
+    #[test]
+    fn test_parse_tracking_counts() {
+        let output = "main\ttrue\nmain\torigin\ttrue\t2,5\n";
+        let bookmarks = parse_bookmark_list(output);
+        assert_eq!(bookmarks.len(), 2);
+        let expected = Some(TrackingCounts {
+            ahead: 2,
+            behind: 5,
+        });
+        assert_eq!(bookmarks[1].tracking, expected);
+        // Local bookmark inherits its tracked remote's counts
+        assert_eq!(bookmarks[0].tracking, expected);
+    }
+
+    #[test]
+    fn test_parse_tracking_counts_ignores_git_remote() {
+        let output = "main\tfalse\nmain\tgit\ttrue\t0,0\n";
+        let bookmarks = parse_bookmark_list(output);
+        assert!(bookmarks[0].tracking.is_none());
+    }
+
+    #[test]
+    fn test_local_without_remote_has_no_tracking() {
+        let bookmarks = parse_bookmark_list("topic\tfalse\n");
+        assert!(bookmarks[0].tracking.is_none());
+    }
 }
@@ -0,0 +1,78 @@
+//! Parser for `jj config list <table>` output
+
+/// Parse `jj config list <table>` output into (key, string value) pairs
+///
+/// Each line is `<table>.<key> = <TOML value>`. Keys are returned relative
+/// to `table` with TOML quoting removed; non-string values are skipped.
+pub fn parse_config_strings(output: &str, table: &str) -> Vec<(String, String)> {
+    let prefix = format!("{}.", table);
+    output
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(" = ")?;
+            let key = key.trim().strip_prefix(&prefix)?;
+            let key = unquote_toml_string(key).unwrap_or_else(|| key.to_string());
+            Some((key, unquote_toml_string(value.trim())?))
+        })
+        .collect()
+}
+
+/// Decode a TOML basic (`"..."`) or literal (`'...'`) string
+fn unquote_toml_string(s: &str) -> Option<String> {
+    if let Some(literal) = s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        return Some(literal.to_string());
+    }
+    let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'r' => out.push('\r'),
+            'u' => {
+                let hex: String = chars.by_ref().take(4).collect();
+                out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            }
+            other => out.push(other),
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config_strings() {
+        let output = "tij.revset-presets.mine = \"mine() & ~empty()\"\n\
+                      tij.revset-presets.stack = 'trunk()..@'\n";
+        assert_eq!(
+            parse_config_strings(output, "tij.revset-presets"),
+            vec![
+                ("mine".to_string(), "mine() & ~empty()".to_string()),
+                ("stack".to_string(), "trunk()..@".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_config_strings_quoted_key_and_escapes() {
+        let output = r#"tij.revset-presets."my stack" = "description(\"wip\")""#;
+        assert_eq!(
+            parse_config_strings(output, "tij.revset-presets"),
+            vec![("my stack".to_string(), r#"description("wip")"#.to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_config_strings_skips_non_strings_and_other_tables() {
+        let output = "tij.revset-presets.limit = 10\ntij.pre-push = \"make\"\n";
+        assert!(parse_config_strings(output, "tij.revset-presets").is_empty());
+    }
+}
@@ -2,120 +2,188 @@
 
 use super::super::JjError;
 use super::Parser;
-use crate::model::{CommitId, DiffContent, DiffLine, DiffLineKind, FileOperation};
+use crate::model::{
+    CommitId, DiffContent, DiffLine, DiffLineKind, FileOperation, Hunk, HunkLine, HunkLineKind,
+};
+
+/// Line-by-line parser behind [`Parser::parse_show`] and [`Parser::parse_diff_body`]
+///
+/// Keeps its state between lines, so a huge diff can be parsed while jj is
+/// still writing it: [`Self::take`] hands out the lines parsed so far.
+#[derive(Debug)]
+pub struct DiffStreamParser {
+    content: DiffContent,
+    description_lines: Vec<String>,
+    file_count: usize,
+    header_done: bool,
+    current_file_op: FileOperation,
+}
 
-impl Parser {
-    /// Parse `jj show` output into DiffContent
-    ///
-    /// Format:
-    /// ```text
-    /// Commit ID: <hash>
-    /// Change ID: <hash>
-    /// Author   : Name <email> (timestamp)
-    /// Committer: Name <email> (timestamp)
-    ///
-    ///     Description text
-    ///
-    /// Modified regular file src/main.rs:
-    ///    10   10:     fn main() {
-    /// ```
-    pub fn parse_show(output: &str) -> Result<DiffContent, JjError> {
-        let mut content = DiffContent::default();
-        let mut description_lines = Vec::new();
-        let mut file_count = 0;
-        let mut header_done = false;
-        let mut in_diff_section = false;
-        let mut current_file_op = FileOperation::Modified;
+impl DiffStreamParser {
+    /// Parser for `jj show` output (header, description, then files)
+    pub fn show() -> Self {
+        Self {
+            content: DiffContent::default(),
+            description_lines: Vec::new(),
+            file_count: 0,
+            header_done: false,
+            current_file_op: FileOperation::Modified,
+        }
+    }
 
-        for line in output.lines() {
-            // Parse header fields (before diff section)
-            if !header_done {
-                if let Some(commit_id) = line.strip_prefix("Commit ID: ") {
-                    content.commit_id = CommitId::new(commit_id.trim().to_string());
-                    continue;
-                }
+    /// Parser for `jj diff` output (files only)
+    pub fn body() -> Self {
+        Self {
+            header_done: true,
+            ..Self::show()
+        }
+    }
 
-                if let Some(author_line) = line.strip_prefix("Author   : ") {
-                    if let Some((author, timestamp)) = Self::parse_author_line(author_line) {
-                        content.author = author;
-                        content.timestamp = timestamp;
-                    }
-                    continue;
-                }
+    /// Parse one output line (without its line ending)
+    pub fn push_line(&mut self, line: &str) {
+        // Parse header fields (before diff section)
+        if !self.header_done {
+            if let Some(commit_id) = line.strip_prefix("Commit ID: ") {
+                self.content.commit_id = CommitId::new(commit_id.trim().to_string());
+                return;
+            }
 
-                // Skip Change ID and Committer lines
-                if line.starts_with("Change ID: ") || line.starts_with("Committer: ") {
-                    continue;
+            if let Some(author_line) = line.strip_prefix("Author   : ") {
+                if let Some((author, timestamp)) = Parser::parse_author_line(author_line) {
+                    self.content.author = author;
+                    self.content.timestamp = timestamp;
                 }
+                return;
+            }
 
-                // Empty line in header section
-                if line.is_empty() {
-                    if !description_lines.is_empty() {
-                        // Could be paragraph break within multi-line description
-                        description_lines.push(String::new());
-                    }
-                    // If no description yet, skip (gap between header fields and description)
-                    continue;
-                }
+            // Skip Change ID and Committer lines
+            if line.starts_with("Change ID: ") || line.starts_with("Committer: ") {
+                return;
+            }
 
-                // Description lines are indented with 4 spaces
-                if line.starts_with("    ") {
-                    description_lines.push(line.trim_start().to_string());
-                    continue;
+            // Empty line in header section
+            if line.is_empty() {
+                if !self.description_lines.is_empty() {
+                    // Could be paragraph break within multi-line description
+                    self.description_lines.push(String::new());
                 }
+                // If no description yet, skip (gap between header fields and description)
+                return;
+            }
 
-                // Non-empty, non-indented, non-header line = end of header
-                // (e.g. "Modified regular file ...")
-                // Trim trailing empty lines and save description
-                while description_lines.last().is_some_and(|l| l.is_empty()) {
-                    description_lines.pop();
-                }
-                if !description_lines.is_empty() {
-                    content.description = description_lines.join("\n");
-                    description_lines.clear();
-                }
-                header_done = true;
-                // Fall through to file header detection below
+            // Description lines are indented with 4 spaces
+            if line.starts_with("    ") {
+                self.description_lines.push(line.trim_start().to_string());
+                return;
             }
 
-            // File header detection - marks start of diff section
-            if let Some((path, file_op)) = Self::extract_file_info(line) {
-                // If we haven't saved description yet, do it now
-                if !description_lines.is_empty() {
-                    content.description = description_lines.join("\n");
-                    description_lines.clear();
-                }
-                header_done = true;
-                in_diff_section = true;
-                current_file_op = file_op;
+            // Non-empty, non-indented, non-header line = end of header
+            // (e.g. "Modified regular file ...")
+            self.end_header();
+            // Fall through to file header detection below
+        }
 
-                // Add separator before file (except first file)
-                if file_count > 0 {
-                    content.lines.push(DiffLine::separator());
-                }
-                content
-                    .lines
-                    .push(DiffLine::file_header_with_op(path, file_op));
-                file_count += 1;
-                continue;
-            }
+        // File header detection - marks start of diff section
+        if let Some((path, file_op)) = Parser::extract_file_info(line) {
+            self.current_file_op = file_op;
 
-            // Diff line parsing (only after we're in diff section)
-            if in_diff_section && let Some(diff_line) = Self::parse_diff_line(line, current_file_op)
-            {
-                content.lines.push(diff_line);
+            // Add separator before file (except first file)
+            if self.file_count > 0 {
+                self.content.lines.push(DiffLine::separator());
             }
+            self.content
+                .lines
+                .push(DiffLine::file_header_with_op(path, file_op));
+            self.file_count += 1;
+            return;
         }
+        // A type change (e.g. a file becoming executable) keeps its
+        // description as the first line of the file
+        if let Some((path, description)) = Parser::extract_type_change(line) {
+            self.current_file_op = FileOperation::Modified;
+            if self.file_count > 0 {
+                self.content.lines.push(DiffLine::separator());
+            }
+            self.content.lines.extend([
+                DiffLine::file_header_with_op(path, FileOperation::Modified),
+                DiffLine {
+                    kind: DiffLineKind::Context,
+                    line_numbers: None,
+                    content: description,
+                    file_op: None,
+                },
+            ]);
+            self.file_count += 1;
+            return;
+        }
+
+        // Diff line parsing (only after we've seen at least one file header)
+        if self.file_count > 0
+            && let Some(diff_line) = Parser::parse_diff_line(line, self.current_file_op)
+        {
+            self.content.lines.push(diff_line);
+        }
+    }
+
+    /// Save the description, trimming trailing empty lines
+    fn end_header(&mut self) {
+        while self.description_lines.last().is_some_and(|l| l.is_empty()) {
+            self.description_lines.pop();
+        }
+        if !self.description_lines.is_empty() {
+            self.content.description = self.description_lines.join("\n");
+            self.description_lines.clear();
+        }
+        self.header_done = true;
+    }
+
+    /// Header fields plus the lines parsed since the last call
+    ///
+    /// `None` until the header is complete, so the first piece always
+    /// carries the full description.
+    pub fn take(&mut self) -> Option<DiffContent> {
+        if !self.header_done {
+            return None;
+        }
+        let lines = std::mem::take(&mut self.content.lines);
+        Some(DiffContent {
+            commit_id: self.content.commit_id.clone(),
+            author: self.content.author.clone(),
+            timestamp: self.content.timestamp.clone(),
+            description: self.content.description.clone(),
+            lines,
+        })
+    }
 
+    /// Header fields plus the remaining lines, once the output has ended
+    pub fn finish(mut self) -> DiffContent {
         // Handle description if no file headers followed it
-        if !description_lines.is_empty() {
-            while description_lines.last().is_some_and(|l| l.is_empty()) {
-                description_lines.pop();
-            }
-            content.description = description_lines.join("\n");
+        if !self.header_done {
+            self.end_header();
         }
+        self.content
+    }
+}
 
-        Ok(content)
+impl Parser {
+    /// Parse `jj show` output into DiffContent
+    ///
+    /// Format:
+    /// ```text
+    /// Commit ID: <hash>
+    /// Change ID: <hash>
+    /// Author   : Name <email> (timestamp)
+    /// Committer: Name <email> (timestamp)
+    ///
+    ///     Description text
+    ///
+    /// Modified regular file src/main.rs:
+    ///    10   10:     fn main() {
+    /// ```
+    pub fn parse_show(output: &str) -> Result<DiffContent, JjError> {
+        let mut parser = DiffStreamParser::show();
+        output.lines().for_each(|line| parser.push_line(line));
+        Ok(parser.finish())
     }
 
     /// Parse `jj diff --from --to` output into DiffContent
@@ -123,35 +191,9 @@ impl Parser {
     /// Unlike `parse_show()`, this output has no header (no Commit ID, Author, etc.)
     /// It starts directly with file headers like "Modified regular file src/main.rs:"
     pub fn parse_diff_body(output: &str) -> DiffContent {
-        let mut content = DiffContent::default();
-        let mut file_count = 0;
-        let mut current_file_op = FileOperation::Modified;
-
-        for line in output.lines() {
-            // File header detection
-            if let Some((path, file_op)) = Self::extract_file_info(line) {
-                current_file_op = file_op;
-
-                // Add separator before file (except first file)
-                if file_count > 0 {
-                    content.lines.push(DiffLine::separator());
-                }
-                content
-                    .lines
-                    .push(DiffLine::file_header_with_op(path, file_op));
-                file_count += 1;
-                continue;
-            }
-
-            // Diff line parsing (only after we've seen at least one file header)
-            if file_count > 0
-                && let Some(diff_line) = Self::parse_diff_line(line, current_file_op)
-            {
-                content.lines.push(diff_line);
-            }
-        }
-
-        content
+        let mut parser = DiffStreamParser::body();
+        output.lines().for_each(|line| parser.push_line(line));
+        parser.finish()
     }
 
     /// Parse author line "Name <email> (timestamp)" into (author, timestamp)
@@ -181,6 +223,9 @@ impl Parser {
             ("Removed regular file ", FileOperation::Deleted),
             ("Deleted regular file ", FileOperation::Deleted),
             ("Modified regular file ", FileOperation::Modified),
+            ("Added executable file ", FileOperation::Added),
+            ("Removed executable file ", FileOperation::Deleted),
+            ("Modified executable file ", FileOperation::Modified),
             ("Renamed regular file ", FileOperation::Modified),
             ("Copied regular file ", FileOperation::Added),
             ("Created conflict in ", FileOperation::Modified),
@@ -198,6 +243,26 @@ impl Parser {
         None
     }
 
+    /// Extract file path and description from a file type change header
+    ///
+    /// - "Regular file became executable file at run.sh:" ->
+    ///   ("run.sh", "Regular file became executable file")
+    pub(super) fn extract_type_change(line: &str) -> Option<(String, String)> {
+        // Diff lines start with line numbers, headers with a capital letter
+        if !line.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return None;
+        }
+        let (description, path) = line.strip_suffix(':')?.split_once(" became ")?;
+        let (became, path) = path.split_once(" at ")?;
+        if description.contains(':') || became.contains(':') {
+            return None;
+        }
+        Some((
+            path.to_string(),
+            format!("{} became {}", description, became),
+        ))
+    }
+
     /// Parse a diff line with line numbers
     ///
     /// Format examples:
@@ -400,6 +465,105 @@ impl Parser {
         }
     }
 
+    /// Parse the hunks of a single file's `jj diff --git` output
+    ///
+    /// Line endings inside the file are preserved (lines are split on `\n`
+    /// only). Binary files and mode-only changes have no hunks.
+    pub fn parse_git_hunks(output: &str) -> Vec<Hunk> {
+        /// Which side(s) the previous line went to (for `\ No newline`)
+        #[derive(Clone, Copy)]
+        enum Side {
+            Old,
+            New,
+            Both,
+        }
+        fn strip_newline(line: Option<&mut String>) {
+            if let Some(line) = line
+                && line.ends_with('\n')
+            {
+                line.pop();
+            }
+        }
+
+        let mut hunks: Vec<Hunk> = Vec::new();
+        let mut last_side = Side::Both;
+        for raw in output.split_inclusive('\n') {
+            let text = raw.strip_suffix('\n').unwrap_or(raw);
+            if let Some(range) = text.strip_prefix("@@ -") {
+                let Some((old_start, new_start)) = Self::parse_hunk_range(range) else {
+                    continue;
+                };
+                hunks.push(Hunk {
+                    header: text.trim_end_matches('\r').to_string(),
+                    old_start,
+                    new_start,
+                    old_lines: Vec::new(),
+                    new_lines: Vec::new(),
+                    added: 0,
+                    removed: 0,
+                    first_change: String::new(),
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+            if text.starts_with("diff --git ") && !hunks.is_empty() {
+                // A following file's hunks must not be appended to this one
+                break;
+            }
+            let Some(hunk) = hunks.last_mut() else {
+                continue;
+            };
+            let (marker, body) = text.split_at(text.len().min(1));
+            let line = format!("{}\n", body);
+            if matches!(marker, "+" | "-") && hunk.first_change.is_empty() {
+                hunk.first_change = body.trim_end_matches('\r').to_string();
+            }
+            let kind = match marker {
+                "+" => {
+                    hunk.new_lines.push(line.clone());
+                    hunk.added += 1;
+                    last_side = Side::New;
+                    HunkLineKind::Added
+                }
+                "-" => {
+                    hunk.old_lines.push(line.clone());
+                    hunk.removed += 1;
+                    last_side = Side::Old;
+                    HunkLineKind::Removed
+                }
+                "\\" => {
+                    match last_side {
+                        Side::Old => strip_newline(hunk.old_lines.last_mut()),
+                        Side::New => strip_newline(hunk.new_lines.last_mut()),
+                        Side::Both => {
+                            strip_newline(hunk.old_lines.last_mut());
+                            strip_newline(hunk.new_lines.last_mut());
+                        }
+                    }
+                    strip_newline(hunk.lines.last_mut().map(|last| &mut last.text));
+                    continue;
+                }
+                // Context (an empty line is a context line whose space was trimmed)
+                _ => {
+                    hunk.old_lines.push(line.clone());
+                    hunk.new_lines.push(line.clone());
+                    last_side = Side::Both;
+                    HunkLineKind::Context
+                }
+            };
+            hunk.lines.push(HunkLine { kind, text: line });
+        }
+        hunks
+    }
+
+    /// Start lines from the `a[,b] +c[,d] @@` part of a hunk header
+    fn parse_hunk_range(range: &str) -> Option<(usize, usize)> {
+        let (old, rest) = range.split_once(" +")?;
+        let (new, _) = rest.split_once(" @@")?;
+        let start = |spec: &str| spec.split(',').next()?.parse().ok();
+        Some((start(old)?, start(new)?))
+    }
+
     /// Extract the header section (Commit ID, Author, Description) from `jj show` output.
     ///
     /// Returns (DiffContent with header fields populated, byte offset where body starts).
@@ -2,7 +2,7 @@
 
 use super::super::JjError;
 use super::super::template::FIELD_SEPARATOR;
-use crate::model::{Change, ChangeId, CommitId};
+use crate::model::{Change, ChangeId, CommitId, DiffStat, LogParseIssue, LogRowExtras};
 
 use super::Parser;
 
@@ -13,35 +13,66 @@ impl Parser {
     /// - Lines with TAB: Change lines (graph prefix + TAB-separated fields)
     /// - Lines without TAB: Graph-only lines (branch/merge lines)
     pub fn parse_log(output: &str) -> Result<Vec<Change>, JjError> {
+        match Self::parse_log_partial(output) {
+            (changes, None) => Ok(changes),
+            (_, Some(issue)) => Err(JjError::ParseError(issue.message)),
+        }
+    }
+
+    /// Parse `jj log` output, keeping the rows before the first malformed line
+    ///
+    /// Parsing stops at the first line that cannot be understood; the rows
+    /// parsed so far are returned together with a description of the problem.
+    pub fn parse_log_partial(output: &str) -> (Vec<Change>, Option<LogParseIssue>) {
         let mut changes = Vec::new();
 
-        for line in output.lines() {
+        for (index, line) in output.lines().enumerate() {
             if line.is_empty() {
                 continue;
             }
 
-            // TAB presence determines line type
-            if let Some(tab_pos) = line.find(FIELD_SEPARATOR) {
-                // Change line: extract graph prefix and parse fields
-                let graph_and_id = &line[..tab_pos];
-                let data_fields = &line[tab_pos + 1..];
-
-                let (graph_prefix, change_id) = Self::split_graph_prefix(graph_and_id)?;
-                let mut change = Self::parse_log_fields(change_id, data_fields)?;
-                change.graph_prefix = graph_prefix;
-                change.is_graph_only = false;
-                changes.push(change);
-            } else {
-                // Graph-only line (no TAB = no data fields)
-                changes.push(Change {
-                    graph_prefix: line.to_string(),
-                    is_graph_only: true,
-                    ..Default::default()
-                });
+            match Self::parse_log_line(line) {
+                Ok(change) => changes.push(change),
+                Err(e) => {
+                    let message = match e {
+                        JjError::ParseError(message) => message,
+                        other => other.to_string(),
+                    };
+                    let issue = LogParseIssue {
+                        line_number: index + 1,
+                        line: line.to_string(),
+                        message,
+                        raw_output: output.to_string(),
+                    };
+                    return (changes, Some(issue));
+                }
             }
         }
 
-        Ok(changes)
+        (changes, None)
+    }
+
+    /// Parse a single non-empty `jj log` line
+    ///
+    /// TAB presence determines the line type: change lines carry a graph
+    /// prefix plus TAB-separated fields, graph-only lines have no TAB.
+    fn parse_log_line(line: &str) -> Result<Change, JjError> {
+        if let Some(tab_pos) = line.find(FIELD_SEPARATOR) {
+            let graph_and_id = &line[..tab_pos];
+            let data_fields = &line[tab_pos + 1..];
+
+            let (graph_prefix, change_id) = Self::split_graph_prefix(graph_and_id)?;
+            let mut change = Self::parse_log_fields(change_id, data_fields)?;
+            change.graph_prefix = graph_prefix;
+            change.is_graph_only = false;
+            Ok(change)
+        } else {
+            Ok(Change {
+                graph_prefix: line.to_string(),
+                is_graph_only: true,
+                ..Default::default()
+            })
+        }
     }
 
     /// Split graph prefix and change_id from the part before TAB
@@ -50,7 +81,7 @@ impl Parser {
     /// Output: Ok(("│ │ ○  ", "oqwroxvu"))
     ///
     /// jj's change_id uses "reversed hex" encoding with lowercase letters only.
-    /// The template uses `.short(8)` which outputs `[a-z]{8}`.
+    /// The template uses `.shortest(<len>)` which outputs `[a-z]{len,}`.
     pub(super) fn split_graph_prefix(graph_and_id: &str) -> Result<(String, &str), JjError> {
         let bytes = graph_and_id.as_bytes();
         let mut id_start = bytes.len();
@@ -113,9 +144,46 @@ impl Parser {
             } else {
                 Vec::new()
             },
+            is_immutable: fields.get(9).is_some_and(|v| *v == "true"),
+            is_divergent: fields.get(10).is_some_and(|v| *v == "true"),
+            is_hidden: fields.get(11).is_some_and(|v| *v == "true"),
+            extras: Self::parse_log_extras(fields.get(12..).unwrap_or_default()),
         })
     }
 
+    /// Parse the tagged `tij.log-row` fields (`id:`, `ago:`)
+    ///
+    /// Unknown tags and malformed values are skipped.
+    fn parse_log_extras(fields: &[&str]) -> LogRowExtras {
+        let mut extras = LogRowExtras::default();
+        for field in fields {
+            let Some((tag, value)) = field.split_once(':') else {
+                continue;
+            };
+            match tag {
+                "id" => extras.change_id = Some(value.to_string()),
+                "ago" => extras.relative_time = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        extras
+    }
+
+    /// Parse [`Templates::diff_stats`](crate::jj::template::Templates::diff_stats)
+    /// output into `(commit_id, (added, removed))`, skipping malformed lines
+    pub fn parse_diff_stats(output: &str) -> Vec<(String, DiffStat)> {
+        output
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.trim_end().split(FIELD_SEPARATOR);
+                let commit_id = fields.next().filter(|id| !id.is_empty())?;
+                let added = fields.next()?.parse().ok()?;
+                let removed = fields.next()?.parse().ok()?;
+                Some((commit_id.to_string(), (added, removed)))
+            })
+            .collect()
+    }
+
     // Legacy function for tests - kept for backwards compatibility
     #[cfg(test)]
     pub(super) fn parse_log_record(record: &str) -> Result<Change, JjError> {
@@ -150,6 +218,10 @@ impl Parser {
             } else {
                 Vec::new()
             },
+            is_immutable: fields.get(10).is_some_and(|v| *v == "true"),
+            is_divergent: fields.get(11).is_some_and(|v| *v == "true"),
+            is_hidden: fields.get(12).is_some_and(|v| *v == "true"),
+            extras: LogRowExtras::default(),
         })
     }
 }
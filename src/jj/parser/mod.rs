@@ -4,21 +4,30 @@
 
 mod annotation;
 mod bookmark;
+mod config;
 mod diff;
 mod evolog;
 mod log;
 mod operation;
 mod push;
+mod remote;
 mod resolve;
+mod sparse;
+mod stack;
 mod status;
 mod tag;
 mod workspace;
 
-pub use bookmark::parse_bookmark_list;
+pub use bookmark::{parse_bookmark_list, parse_bookmark_targets};
+pub use config::parse_config_strings;
+pub use diff::DiffStreamParser;
 pub use evolog::parse_evolog;
 pub use push::{
     PushPreviewAction, PushPreviewResult, SkippedRef, parse_push_dry_run, parse_push_skipped,
 };
+pub use remote::parse_remote_list;
+pub use sparse::parse_sparse_list;
+pub use stack::parse_stack;
 pub use tag::parse_tag_list;
 pub use workspace::parse_workspace_list;
 
@@ -30,17 +39,18 @@ use std::sync::LazyLock;
 
 /// Regex for parsing jj file annotate output with commit_id
 /// Format: `<change_id>\t<commit_id> <author> <timestamp>  <line_number>: <content>`
-/// Example: `twzksoxt\tabcd1234 nakamura 2026-01-30 10:43:19    1: //! Tij`
+/// Example: `twzksoxt\tabcd1234 nakamura 2026-01-30T10:43:19+0900    1: //! Tij`
 ///
 /// Groups:
 /// 1. change_id (first token before tab)
 /// 2. commit_id (token after tab, before space)
 /// 3. author (between commit_id and timestamp)
-/// 4. timestamp (YYYY-MM-DD HH:MM:SS)
+/// 4. timestamp (YYYY-MM-DDTHH:MM:SS+ZZZZ; the space-separated form
+///    without offset is accepted too)
 /// 5. line_number (digits after timestamp, before colon)
 /// 6. content (everything after `: ` or `:`)
 static ANNOTATE_LINE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"^(\S+)\t(\S+)\s+(.+?)\s+(\d{4}-\d{2}-\d{2}\s+\d{2}:\d{2}:\d{2})\s+(\d+):\s?(.*)$")
+    Regex::new(r"^(\S+)\t(\S+)\s+(.+?)\s+(\d{4}-\d{2}-\d{2}[T\s]\d{2}:\d{2}:\d{2}(?:[+-]\d{4})?)\s+(\d+):\s?(.*)$")
         .expect("Invalid annotate line regex")
 });
 
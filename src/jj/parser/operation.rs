@@ -2,37 +2,181 @@
 
 use super::super::JjError;
 use super::super::template::FIELD_SEPARATOR;
-use crate::model::Operation;
+use crate::model::{DiffContent, DiffLine, DiffLineKind, OpDiff, OpNode, Operation};
 
 use super::Parser;
 
+/// Characters that can appear in a graph-only line of `jj op log`
+fn is_graph_char(c: char) -> bool {
+    c.is_whitespace()
+        || ('\u{2500}'..='\u{257F}').contains(&c)
+        || matches!(c, '|' | '/' | '\\' | '-' | '~')
+}
+
 impl Parser {
     /// Parse `jj op log` output into a list of Operations
     ///
-    /// Expected format (tab-separated):
-    /// `<id>\t<user>\t<timestamp>\t<description>`
+    /// Expected format (tab-separated, optional graph prefix before the id):
+    /// `<graph><id>\t<user>\t<timestamp>\t<description>[\t<started_at>[\t<tag>...]]`
+    ///
+    /// Lines consisting only of graph characters become graph-only entries;
+    /// other lines without enough fields are skipped.
     pub fn parse_op_log(output: &str) -> Result<Vec<Operation>, JjError> {
         let mut operations = Vec::new();
+        let mut seen_op = false;
 
-        for (index, line) in output.lines().enumerate() {
-            if line.is_empty() {
+        for line in output.lines() {
+            if line.trim().is_empty() {
                 continue;
             }
 
             let parts: Vec<&str> = line.split(FIELD_SEPARATOR).collect();
             if parts.len() < 4 {
+                if line.chars().all(is_graph_char) {
+                    operations.push(Operation {
+                        graph_prefix: line.to_string(),
+                        is_graph_only: true,
+                        ..Default::default()
+                    });
+                }
                 continue; // Skip malformed lines
             }
 
+            // The id is the last word; everything before it is graph
+            let id_start = parts[0].rfind(' ').map_or(0, |i| i + 1);
+
             operations.push(Operation {
-                id: parts[0].to_string(),
+                id: parts[0][id_start..].to_string(),
                 user: parts[1].to_string(),
                 timestamp: parts[2].to_string(),
                 description: parts[3].to_string(),
-                is_current: index == 0,
+                is_current: !seen_op,
+                started_at: parts.get(4).map(|s| s.to_string()).unwrap_or_default(),
+                tags: parts
+                    .iter()
+                    .skip(5)
+                    .filter(|t| !t.is_empty())
+                    .map(|t| t.to_string())
+                    .collect(),
+                graph_prefix: parts[0][..id_start].to_string(),
+                is_graph_only: false,
             });
+            seen_op = true;
         }
 
         Ok(operations)
     }
+
+    /// Parse `jj op log --no-graph` output with parent links
+    ///
+    /// Expected format (tab-separated):
+    /// `<id>\t<parent_ids>\t<user>\t<timestamp>\t<description>`
+    ///
+    /// `parent_ids` is comma-separated and empty for the root operation.
+    /// Lines without enough fields are skipped.
+    pub fn parse_op_nodes(output: &str) -> Result<Vec<OpNode>, JjError> {
+        let nodes = output
+            .lines()
+            .map(|line| line.split(FIELD_SEPARATOR).collect::<Vec<_>>())
+            .filter(|parts| parts.len() >= 5)
+            .enumerate()
+            .map(|(idx, parts)| OpNode {
+                op: Operation {
+                    id: parts[0].to_string(),
+                    user: parts[2].to_string(),
+                    timestamp: parts[3].to_string(),
+                    description: parts[4].to_string(),
+                    is_current: idx == 0,
+                    ..Default::default()
+                },
+                parents: parts[1]
+                    .split(',')
+                    .filter(|p| !p.is_empty())
+                    .map(|p| p.to_string())
+                    .collect(),
+            })
+            .collect();
+        Ok(nodes)
+    }
+
+    /// Parse `jj op diff --no-graph` output
+    ///
+    /// The output is a list of sections ("Changed commits:", "Changed local
+    /// bookmarks:", ...). Commit sections hold `+`/`-` commit lines; ref
+    /// sections hold a `<name>:` line per ref followed by its `+`/`-` targets.
+    /// The "From/To operation" header and unknown sections are ignored.
+    pub fn parse_op_diff(output: &str) -> OpDiff {
+        #[derive(PartialEq)]
+        enum Section {
+            Other,
+            Commits,
+            Refs,
+        }
+
+        let mut diff = OpDiff::default();
+        let mut section = Section::Other;
+        for line in output.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(title) = line.strip_prefix("Changed ") {
+                section = if title.starts_with("commits") {
+                    Section::Commits
+                } else if title.contains("bookmarks") || title.starts_with("tags") {
+                    Section::Refs
+                } else {
+                    Section::Other
+                };
+                continue;
+            }
+            match section {
+                Section::Commits => {
+                    if let Some(commit) = line.strip_prefix("+ ") {
+                        diff.added_commits.push(commit.to_string());
+                    } else if let Some(commit) = line.strip_prefix("- ") {
+                        diff.removed_commits.push(commit.to_string());
+                    }
+                }
+                Section::Refs => {
+                    if !line.starts_with(['+', '-', ' '])
+                        && let Some(name) = line.strip_suffix(':')
+                    {
+                        diff.changed_refs.push(name.to_string());
+                    }
+                }
+                Section::Other => {}
+            }
+        }
+        diff
+    }
+
+    /// Parse `jj op show --no-graph` output into plain preview lines
+    ///
+    /// The text is kept as jj prints it; only `+`/`-` lines (commits and
+    /// bookmark targets coming and going) get an added/deleted kind for color.
+    pub fn parse_op_show(output: &str) -> DiffContent {
+        let lines = output
+            .lines()
+            .map(|line| {
+                let trimmed = line.trim_start();
+                let kind = if trimmed.starts_with("+ ") {
+                    DiffLineKind::Added
+                } else if trimmed.starts_with("- ") {
+                    DiffLineKind::Deleted
+                } else {
+                    DiffLineKind::Context
+                };
+                DiffLine {
+                    kind,
+                    line_numbers: None,
+                    content: line.to_string(),
+                    file_op: None,
+                }
+            })
+            .collect();
+        DiffContent {
+            lines,
+            ..Default::default()
+        }
+    }
 }
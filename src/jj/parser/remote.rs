@@ -0,0 +1,54 @@
+//! Parser for `jj git remote list` output
+
+use crate::model::RemoteInfo;
+
+/// Parse `jj git remote list` output: `<name> <url>` per line
+///
+/// The URL is everything after the first whitespace run, so a separate push
+/// URL annotation stays part of it.
+pub fn parse_remote_list(output: &str) -> Vec<RemoteInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() {
+                return None;
+            }
+            let (name, url) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            Some(RemoteInfo {
+                name: name.to_string(),
+                url: url.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_remote_list() {
+        let output = "origin https://github.com/user/repo.git\n\
+                      upstream git@github.com:org/repo.git\n";
+        let remotes = parse_remote_list(output);
+        assert_eq!(remotes.len(), 2);
+        assert_eq!(remotes[0].name, "origin");
+        assert_eq!(remotes[0].url, "https://github.com/user/repo.git");
+        assert_eq!(remotes[1].name, "upstream");
+        assert_eq!(remotes[1].url, "git@github.com:org/repo.git");
+    }
+
+    #[test]
+    fn test_parse_remote_without_url() {
+        let remotes = parse_remote_list("local\n\n");
+        assert_eq!(remotes.len(), 1);
+        assert_eq!(remotes[0].name, "local");
+        assert!(remotes[0].url.is_empty());
+    }
+
+    #[test]
+    fn test_parse_empty_output() {
+        assert!(parse_remote_list("").is_empty());
+    }
+}
@@ -1,7 +1,27 @@
 //! Resolve list parser (jj resolve --list)
 
+use super::super::JjError;
 use super::{Parser, RESOLVE_LIST_REGEX};
-use crate::model::ConflictFile;
+use crate::model::{ConflictFile, ConflictLine, ConflictRegion, ConflictSide, MergeSegment};
+
+/// Minimum length of a jj/git conflict marker (jj may use longer ones)
+const MIN_MARKER_LEN: usize = 7;
+
+/// Return the marker character and its run length if `line` starts with a
+/// conflict marker
+///
+/// jj lengthens the markers of a region when the file itself contains
+/// marker-like lines, so inside a region only markers as long as the
+/// opening `<<<<<<<` count.
+fn conflict_marker(line: &str) -> Option<(char, usize)> {
+    let first = line.chars().next()?;
+    if !matches!(first, '<' | '>' | '%' | '+' | '-' | '|' | '=' | '\\') {
+        return None;
+    }
+    let run = line.chars().take_while(|&c| c == first).count();
+    let rest = &line[run * first.len_utf8()..];
+    (run >= MIN_MARKER_LEN && (rest.is_empty() || rest.starts_with(' '))).then_some((first, run))
+}
 
 impl Parser {
     /// Parse `jj resolve --list` output into conflict file list
@@ -39,4 +59,207 @@ impl Parser {
             })
             .collect()
     }
+
+    /// Parse file contents with conflict markers (`jj file show`) into
+    /// side-tagged lines
+    ///
+    /// Supports jj's "diff" and "snapshot" marker styles as well as git-style
+    /// markers. Inside a `%%%%%%%` diff section, `-` lines belong to the base,
+    /// `+` lines to the side being diffed and ` ` lines to both (shown as context).
+    pub fn parse_conflict_markers(content: &str) -> Vec<ConflictLine> {
+        // Current section inside a conflict region (None = outside)
+        let mut section: Option<ConflictSide> = None;
+        let mut in_diff = false;
+        let mut seen_side = false;
+        // Length of the opening marker; other lengths are content
+        let mut marker_len = 0;
+
+        content
+            .lines()
+            .map(|line| {
+                let found = conflict_marker(line)
+                    .filter(|&(_, len)| section.is_none() || len == marker_len);
+                let marker = found.map(|(c, _)| c);
+                let side = match (marker, section) {
+                    (Some('<'), None) => {
+                        // git style starts with side #1; jj style announces the section next
+                        marker_len = found.map_or(0, |(_, len)| len);
+                        section = Some(ConflictSide::Ours);
+                        in_diff = false;
+                        seen_side = false;
+                        ConflictSide::Marker
+                    }
+                    (Some('>'), Some(_)) => {
+                        section = None;
+                        in_diff = false;
+                        ConflictSide::Marker
+                    }
+                    (Some('%'), Some(_)) => {
+                        in_diff = true;
+                        section = Some(if seen_side {
+                            ConflictSide::Theirs
+                        } else {
+                            ConflictSide::Ours
+                        });
+                        seen_side = true;
+                        ConflictSide::Marker
+                    }
+                    (Some('\\'), Some(_)) => ConflictSide::Marker,
+                    (Some('+'), Some(_)) => {
+                        in_diff = false;
+                        section = Some(if seen_side {
+                            ConflictSide::Theirs
+                        } else {
+                            ConflictSide::Ours
+                        });
+                        seen_side = true;
+                        ConflictSide::Marker
+                    }
+                    (Some('-') | Some('|'), Some(_)) => {
+                        in_diff = false;
+                        section = Some(ConflictSide::Base);
+                        ConflictSide::Marker
+                    }
+                    (Some('='), Some(_)) => {
+                        in_diff = false;
+                        seen_side = true;
+                        section = Some(ConflictSide::Theirs);
+                        ConflictSide::Marker
+                    }
+                    (_, None) => ConflictSide::Context,
+                    (_, Some(s)) if in_diff => match line.chars().next() {
+                        Some('-') => ConflictSide::Base,
+                        Some('+') => s,
+                        _ => ConflictSide::Context,
+                    },
+                    (_, Some(s)) => s,
+                };
+                ConflictLine {
+                    side,
+                    content: line.to_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// Parse file contents with conflict markers into merge segments
+    ///
+    /// Reconstructs base and both sides for every conflict region (diff
+    /// sections are applied to recover the base and the diffed side).
+    /// Only 2-sided conflicts are supported; anything else is an error.
+    pub fn parse_merge_segments(content: &str) -> Result<Vec<MergeSegment>, JjError> {
+        /// Section of the conflict region currently being read
+        enum Section {
+            Side(usize),
+            Diff(usize),
+            Base,
+        }
+
+        /// Conflict region under construction
+        struct Region {
+            base: Vec<String>,
+            sides: Vec<Vec<String>>,
+            section: Section,
+            /// Implicit first side (git style) not yet claimed by a marker
+            fresh: bool,
+            /// Length of the opening marker; other lengths are content
+            marker_len: usize,
+        }
+
+        impl Region {
+            /// Start a new side, reusing the implicit one if nothing was read into it
+            fn next_side(&mut self) -> usize {
+                if std::mem::take(&mut self.fresh) && self.sides[0].is_empty() {
+                    0
+                } else {
+                    self.sides.push(Vec::new());
+                    self.sides.len() - 1
+                }
+            }
+        }
+
+        let mut segments = Vec::new();
+        let mut region: Option<Region> = None;
+
+        for line in content.lines() {
+            let found = conflict_marker(line)
+                .filter(|&(_, len)| region.as_ref().is_none_or(|r| r.marker_len == len));
+            let marker = found.map(|(c, _)| c);
+            if marker == Some('>')
+                && let Some(Region {
+                    base, mut sides, ..
+                }) = region.take()
+            {
+                if sides.len() != 2 {
+                    return Err(JjError::ParseError(format!(
+                        "{}-sided conflicts are not supported",
+                        sides.len()
+                    )));
+                }
+                let theirs = sides.pop().unwrap_or_default();
+                let ours = sides.pop().unwrap_or_default();
+                segments.push(MergeSegment::Conflict(ConflictRegion {
+                    base,
+                    ours,
+                    theirs,
+                    choice: None,
+                }));
+                continue;
+            }
+
+            let Some(r) = region.as_mut() else {
+                if let Some(('<', marker_len)) = found {
+                    // git style puts side #1 right after `<<<<<<<`
+                    region = Some(Region {
+                        base: Vec::new(),
+                        sides: vec![Vec::new()],
+                        section: Section::Side(0),
+                        fresh: true,
+                        marker_len,
+                    });
+                } else {
+                    segments.push(MergeSegment::Context(line.to_string()));
+                }
+                continue;
+            };
+
+            match marker {
+                Some('%') => r.section = Section::Diff(r.next_side()),
+                Some('+') => r.section = Section::Side(r.next_side()),
+                // `=======` always follows the implicit side, even an empty one
+                Some('=') => {
+                    r.fresh = false;
+                    r.section = Section::Side(r.next_side());
+                }
+                Some('-') | Some('|') => {
+                    r.fresh = false;
+                    r.section = Section::Base;
+                }
+                Some('\\') => {}
+                _ => match r.section {
+                    Section::Side(idx) => r.sides[idx].push(line.to_string()),
+                    Section::Base => r.base.push(line.to_string()),
+                    Section::Diff(idx) => {
+                        let (tag, rest) =
+                            line.split_at(line.chars().next().map_or(0, char::len_utf8));
+                        match tag {
+                            "-" => r.base.push(rest.to_string()),
+                            "+" => r.sides[idx].push(rest.to_string()),
+                            _ => {
+                                r.base.push(rest.to_string());
+                                r.sides[idx].push(rest.to_string());
+                            }
+                        }
+                    }
+                },
+            }
+        }
+
+        if region.is_some() {
+            return Err(JjError::ParseError(
+                "Unterminated conflict region".to_string(),
+            ));
+        }
+        Ok(segments)
+    }
 }
@@ -0,0 +1,25 @@
+//! Parser for `jj sparse list` output
+
+/// Parse `jj sparse list` output: one repo-relative path pattern per line
+///
+/// A full checkout is listed as `.`.
+pub fn parse_sparse_list(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sparse_list() {
+        assert_eq!(parse_sparse_list("src\ndocs/api\n\n"), ["src", "docs/api"]);
+        assert_eq!(parse_sparse_list(".\n"), ["."]);
+        assert!(parse_sparse_list("").is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+//! Parser for the Stack View `jj log` output
+
+use crate::model::{ChangeId, CommitId, StackEntry, assign_push_states};
+
+/// Parse `jj log --no-graph -T <Templates::stack()>` output
+///
+/// Fields: change_id, commit_id, is_working_copy, is_empty, has_conflict,
+/// on_remote, local bookmarks (comma-separated), description. The
+/// description comes last and may contain tabs, hence `splitn(8, ..)`.
+/// Malformed lines are skipped. Push states are derived afterwards.
+pub fn parse_stack(output: &str) -> Vec<StackEntry> {
+    let mut entries: Vec<StackEntry> = output
+        .lines()
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.splitn(8, '\t').collect();
+            if parts.len() < 7 {
+                return None;
+            }
+            Some(StackEntry {
+                change_id: ChangeId::new(parts[0].to_string()),
+                commit_id: CommitId::new(parts[1].to_string()),
+                is_working_copy: parts[2] == "true",
+                is_empty: parts[3] == "true",
+                has_conflict: parts[4] == "true",
+                on_remote: parts[5] == "true",
+                bookmarks: parts[6]
+                    .split(',')
+                    .filter(|b| !b.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                description: parts.get(7).unwrap_or(&"").to_string(),
+                push_state: Default::default(),
+            })
+        })
+        .collect();
+    assign_push_states(&mut entries);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::StackPushState;
+
+    #[test]
+    fn test_parse_stack() {
+        let output = "wqnwkozp\t1a2b3c4d\ttrue\ttrue\tfalse\tfalse\t\t\n\
+                      zsuskuln\t5e6f7a8b\tfalse\tfalse\ttrue\tfalse\tfeature\tAdd\tparser\n\
+                      kkmpptxz\t9c0d1e2f\tfalse\tfalse\tfalse\ttrue\t\tBase work\n";
+        let stack = parse_stack(output);
+        assert_eq!(stack.len(), 3);
+
+        assert!(stack[0].is_working_copy);
+        assert!(stack[0].is_empty);
+        assert_eq!(stack[0].push_state, StackPushState::Local);
+
+        assert_eq!(stack[1].change_id.as_str(), "zsuskuln");
+        assert_eq!(stack[1].bookmarks, vec!["feature"]);
+        assert!(stack[1].has_conflict);
+        assert_eq!(stack[1].description, "Add\tparser");
+        assert_eq!(stack[1].push_state, StackPushState::NeedsPush);
+
+        assert_eq!(stack[2].push_state, StackPushState::Pushed);
+    }
+
+    #[test]
+    fn test_parse_stack_skips_malformed_lines() {
+        let stack = parse_stack("garbage\n\nwqnwkozp\t1a2b3c4d\ttrue\n");
+        assert!(stack.is_empty());
+    }
+}
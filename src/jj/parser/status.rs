@@ -1,7 +1,7 @@
 //! Status output parser (jj status)
 
 use super::super::JjError;
-use crate::model::{ChangeId, FileState, FileStatus, Status};
+use crate::model::{ChangeId, FileState, FileStatus, Status, StatusWarning, StatusWarningKind};
 
 use super::Parser;
 
@@ -52,9 +52,59 @@ impl Parser {
             has_conflicts,
             working_copy_change_id,
             parent_change_id,
+            warnings: Self::parse_status_warnings(output),
         })
     }
 
+    /// Parse the warning/hint blocks of `jj status` stdout or stderr
+    ///
+    /// A block starts at `Warning:`, `Hint:`, "There are unresolved
+    /// conflicts ..." and collects the following lines (paths, resolution
+    /// steps) until a blank line, another block or one of the regular status
+    /// sections ("Working copy", "Parent commit", "Untracked paths:", ...).
+    /// Untracked paths are parsed as files instead.
+    pub fn parse_status_warnings(output: &str) -> Vec<StatusWarning> {
+        let mut warnings = Vec::new();
+        let mut current: Option<StatusWarning> = None;
+
+        for line in output.lines().map(str::trim_end) {
+            let kind = if line.starts_with("Warning:") {
+                Some(StatusWarningKind::Warning)
+            } else if line.starts_with("Hint:") {
+                Some(StatusWarningKind::Hint)
+            } else if line.starts_with("There are unresolved conflicts") {
+                Some(StatusWarningKind::Warning)
+            } else {
+                None
+            };
+
+            let ends_block = line.trim().is_empty()
+                || line.starts_with("Working copy")
+                || line.starts_with("Parent commit")
+                || line.starts_with("The working copy")
+                || line == "Untracked paths:";
+            if kind.is_some() || ends_block {
+                warnings.extend(current.take());
+            }
+            if let Some(kind) = kind {
+                current = Some(StatusWarning {
+                    kind,
+                    lines: vec![line.to_string()],
+                });
+            } else if !ends_block && let Some(ref mut warning) = current {
+                warning.lines.push(line.to_string());
+            }
+        }
+        warnings.extend(current);
+        warnings
+    }
+
+    /// Parse `jj diff --summary` output (same line format as the file list
+    /// of `jj status`)
+    pub fn parse_diff_summary(output: &str) -> Vec<FileStatus> {
+        output.lines().filter_map(Self::parse_status_line).collect()
+    }
+
     /// Parse a single status line into FileStatus
     ///
     /// Formats:
@@ -63,6 +113,7 @@ impl Parser {
     /// - "D path" (deleted)
     /// - "R prefix{old => new}" (renamed, jj format)
     /// - "C path" (conflicted)
+    /// - "? path" (untracked, listed under "Untracked paths:")
     pub(super) fn parse_status_line(line: &str) -> Option<FileStatus> {
         if line.len() < 2 {
             return None;
@@ -99,6 +150,7 @@ impl Parser {
                 return None;
             }
             'C' => FileState::Conflicted,
+            '?' => FileState::Untracked,
             _ => return None,
         };
 
@@ -5,8 +5,8 @@ use crate::model::{ChangeId, CommitId, TagInfo};
 /// Parse `jj tag list` output with template:
 ///
 /// Template: `separate("\t", name, if(remote, remote, ""), if(present, "true", "false"),
-///            if(tracked, "true", "false"), normal_target.change_id().short(8),
-///            normal_target.commit_id().short(8), normal_target.description().first_line()) ++ "\n"`
+///            if(tracked, "true", "false"), normal_target.change_id().shortest(<len>),
+///            normal_target.commit_id().shortest(<len>), normal_target.description().first_line()) ++ "\n"`
 ///
 /// Note: jj's `separate()` skips empty fields, so field count varies:
 /// - Local tag: `name\tpresent\ttracked\tchange_id\tcommit_id\tdescription` (6 fields)
@@ -1,5 +1,8 @@
 use super::*;
-use crate::model::{DiffLineKind, FileOperation, FileState};
+use crate::model::{
+    ConflictRegion, ConflictSide, DiffLine, DiffLineKind, FileOperation, FileState, HunkLineKind,
+    MergeSegment, StatusWarningKind,
+};
 
 #[test]
 fn test_parse_log_record() {
@@ -51,6 +54,15 @@ fn test_parse_log_multiple_records() {
     assert_eq!(changes[1].description, "Second");
 }
 
+#[test]
+fn test_parse_diff_summary() {
+    let files = Parser::parse_diff_summary("M src/main.rs\nR src/{a.rs => b.rs}\n");
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].state, FileState::Modified);
+    assert_eq!(files[1].path, "src/b.rs");
+    assert!(Parser::parse_diff_summary("").is_empty());
+}
+
 #[test]
 fn test_parse_status_line_added() {
     let file = Parser::parse_status_line("A new_file.rs").unwrap();
@@ -122,6 +134,62 @@ Parent commit (@-): xyz98765 uvw43210 Initial commit"#;
     assert_eq!(status.parent_change_id, "xyz98765");
 }
 
+#[test]
+fn test_parse_status_warnings_conflicts_and_untracked() {
+    let output = r#"Working copy changes:
+C conflicted.rs
+Working copy  (@) : abc12345 def67890 description
+Parent commit (@-): xyz98765 uvw43210 parent
+Warning: There are unresolved conflicts at these paths:
+conflicted.rs    2-sided conflict
+Hint: Use `jj resolve` to resolve the conflicts.
+Untracked paths:
+? notes.txt"#;
+
+    let status = Parser::parse_status(output).unwrap();
+    assert_eq!(status.files.len(), 2);
+    assert_eq!(status.files[1].path, "notes.txt");
+    assert_eq!(status.files[1].state, FileState::Untracked);
+    assert_eq!(status.warnings.len(), 2);
+    assert_eq!(status.warnings[0].kind, StatusWarningKind::Warning);
+    assert_eq!(
+        status.warnings[0].lines,
+        [
+            "Warning: There are unresolved conflicts at these paths:",
+            "conflicted.rs    2-sided conflict"
+        ]
+    );
+    assert_eq!(status.warnings[1].kind, StatusWarningKind::Hint);
+    assert_eq!(
+        status.warnings[1].lines,
+        ["Hint: Use `jj resolve` to resolve the conflicts."]
+    );
+}
+
+#[test]
+fn test_parse_status_warnings_from_stderr() {
+    let stderr = "Warning: Refused to snapshot some files:\n  \
+                  big.bin: 12.0MiB (12582912 bytes); the maximum size allowed is 1.0MiB\n\
+                  Hint: This is to prevent large files from being added by accident.\n";
+
+    let warnings = Parser::parse_status_warnings(stderr);
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+        warnings[0].headline(),
+        "Warning: Refused to snapshot some files:"
+    );
+    assert!(warnings[0].lines[1].trim_start().starts_with("big.bin"));
+    assert_eq!(warnings[1].kind, StatusWarningKind::Hint);
+}
+
+#[test]
+fn test_parse_status_without_warnings() {
+    let output = "The working copy has no changes.\n\
+                  Working copy  (@) : abc12345 def67890 (empty) (no description set)\n\
+                  Parent commit (@-): xyz98765 uvw43210 parent";
+    assert!(Parser::parse_status(output).unwrap().warnings.is_empty());
+}
+
 #[test]
 fn test_parse_status_line_renamed() {
     // jj format: "R prefix{old => new}"
@@ -317,9 +385,46 @@ fn test_extract_file_info() {
     assert_eq!(path, "test.txt");
     assert_eq!(op, FileOperation::Modified);
 
+    let (path, op) = Parser::extract_file_info("Modified executable file run.sh:").unwrap();
+    assert_eq!(path, "run.sh");
+    assert_eq!(op, FileOperation::Modified);
+
     assert!(Parser::extract_file_info("Some other line").is_none());
 }
 
+#[test]
+fn test_extract_type_change() {
+    let (path, description) =
+        Parser::extract_type_change("Regular file became executable file at bin/run.sh:").unwrap();
+    assert_eq!(path, "bin/run.sh");
+    assert_eq!(description, "Regular file became executable file");
+
+    // Diff lines mentioning the words are not headers
+    assert!(Parser::extract_type_change("    3    3: it became slow at night:").is_none());
+    assert!(Parser::extract_type_change("Modified regular file src/main.rs:").is_none());
+}
+
+#[test]
+fn test_parse_show_type_change_keeps_description() {
+    let output = "Commit ID: abc\nChange ID: xyz\nAuthor   : A <a@b> (2024-01-30 12:00:00)\nCommitter: A <a@b> (2024-01-30 12:00:00)\n\n    msg\n\nRegular file became executable file at run.sh:\nModified regular file a.txt:\n   1    1: same\n";
+    let content = Parser::parse_show(output).unwrap();
+    let kinds: Vec<(DiffLineKind, &str)> = content
+        .lines
+        .iter()
+        .map(|line| (line.kind, line.content.as_str()))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            (DiffLineKind::FileHeader, "run.sh"),
+            (DiffLineKind::Context, "Regular file became executable file"),
+            (DiffLineKind::Separator, ""),
+            (DiffLineKind::FileHeader, "a.txt"),
+            (DiffLineKind::Context, " same"),
+        ]
+    );
+}
+
 #[test]
 fn test_parse_author_line() {
     let (author, ts) =
@@ -460,6 +565,37 @@ fn test_parse_log_with_graph_branch() {
     assert!(!changes[3].is_graph_only);
 }
 
+#[test]
+fn test_parse_log_partial_keeps_rows_before_bad_line() {
+    let output = "@  oqwroxvu\t1f7a8c00\tuser@example.com\t2026-01-30T16:17:51+0900\tfeature\ttrue\tfalse\t\n\
+                  │\n\
+                  ○  nuzyqrpm\tunexpected\n\
+                  ○  basecommit\tbase1234\tuser@example.com\t2026-01-30T16:15:24+0900\tbase\tfalse\tfalse\t";
+
+    let (changes, issue) = Parser::parse_log_partial(output);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].change_id, "oqwroxvu");
+    assert!(changes[1].is_graph_only);
+
+    let issue = issue.expect("third line is malformed");
+    assert_eq!(issue.line_number, 3);
+    assert!(issue.line.contains("nuzyqrpm"));
+    assert!(issue.message.contains("Expected at least 6 fields"));
+    assert_eq!(issue.raw_output, output);
+
+    // The strict parser still rejects the whole output
+    assert!(Parser::parse_log(output).is_err());
+}
+
+#[test]
+fn test_parse_log_partial_clean_output_has_no_issue() {
+    let output =
+        "@  oqwroxvu\t1f7a8c00\tuser@example.com\t2026-01-30T16:17:51+0900\tfeature\ttrue\tfalse\t";
+    let (changes, issue) = Parser::parse_log_partial(output);
+    assert_eq!(changes.len(), 1);
+    assert!(issue.is_none());
+}
+
 #[test]
 fn test_parse_log_graph_only_lines() {
     // Various graph-only patterns
@@ -540,6 +676,101 @@ fn test_parse_op_log_malformed_lines_skipped() {
     assert_eq!(operations.len(), 2);
 }
 
+#[test]
+fn test_parse_op_log_graph_and_metadata() {
+    let output = "@  abc123def456\talice@host1\t5 minutes ago\tdescribe commit\t2026-02-02 11:25:54\targs: jj describe -m x\n\
+                  ├─╮\n\
+                  │ ○  xyz789uvw012\tbob@host2\t1 hour ago\tnew empty commit\t2026-02-02 10:00:00\n\
+                  ○ │  def456ghi789\talice@host1\t2 hours ago\tsnapshot working copy\t2026-02-02 09:00:00";
+
+    let operations = Parser::parse_op_log(output).unwrap();
+    assert_eq!(operations.len(), 4);
+
+    assert_eq!(operations[0].graph_prefix, "@  ");
+    assert_eq!(operations[0].id, "abc123def456");
+    assert!(operations[0].is_current);
+    assert_eq!(operations[0].started_at, "2026-02-02 11:25:54");
+    assert_eq!(operations[0].args(), Some("jj describe -m x"));
+
+    assert!(operations[1].is_graph_only);
+    assert_eq!(operations[1].graph_prefix, "├─╮");
+
+    assert_eq!(operations[2].graph_prefix, "│ ○  ");
+    assert_eq!(operations[2].id, "xyz789uvw012");
+    assert!(!operations[2].is_current);
+    assert!(operations[2].tags.is_empty());
+    assert_eq!(operations[3].graph_prefix, "○ │  ");
+}
+
+// =========================================================================
+// parse_op_nodes tests
+// =========================================================================
+
+#[test]
+fn test_parse_op_nodes_with_parents() {
+    let output = "aaaaaaaaaaaa\tbbbbbbbbbbbb,cccccccccccc\talice@host1\t1 minute ago\treconcile divergent operations\n\
+                  bbbbbbbbbbbb\tdddddddddddd\talice@host1\t2 minutes ago\tdescribe commit\n\
+                  malformed line\n\
+                  000000000000\t\troot()\t3 years ago\t";
+
+    let nodes = Parser::parse_op_nodes(output).unwrap();
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(nodes[0].op.id, "aaaaaaaaaaaa");
+    assert_eq!(nodes[0].parents, ["bbbbbbbbbbbb", "cccccccccccc"]);
+    assert_eq!(nodes[0].op.description, "reconcile divergent operations");
+    assert!(nodes[0].op.is_current);
+    assert_eq!(nodes[1].parents, ["dddddddddddd"]);
+    assert!(!nodes[1].op.is_current);
+    assert!(nodes[2].parents.is_empty());
+}
+
+// =========================================================================
+// parse_op_diff tests
+// =========================================================================
+
+#[test]
+fn test_parse_op_diff_sections() {
+    let output = "From operation: 8ac5a1b2c3d4 (2026-10-15 10:00:00) snapshot working copy\n\
+                  \x20\x20To operation: 7c8d9e0f1a2b (2026-10-14 09:00:00) new empty commit\n\
+                  \n\
+                  Changed commits:\n\
+                  + nzqlqyxx 5d1a2c4b (empty) (no description set)\n\
+                  - kvxvqvxn 9f8e7d6c Add parser\n\
+                  - zsuskuln 1a2b3c4d Fix tests\n\
+                  \n\
+                  Changed working copy default@:\n\
+                  + nzqlqyxx 5d1a2c4b (empty) (no description set)\n\
+                  - kvxvqvxn 9f8e7d6c Add parser\n\
+                  \n\
+                  Changed local bookmarks:\n\
+                  feature:\n\
+                  + (absent)\n\
+                  - kvxvqvxn 9f8e7d6c Add parser\n\
+                  \n\
+                  Changed remote bookmarks:\n\
+                  feature@origin:\n\
+                  + untracked (absent)\n\
+                  - tracked kvxvqvxn 9f8e7d6c Add parser\n";
+
+    let diff = Parser::parse_op_diff(output);
+    assert_eq!(
+        diff.added_commits,
+        ["nzqlqyxx 5d1a2c4b (empty) (no description set)"]
+    );
+    assert_eq!(diff.removed_commits.len(), 2);
+    assert_eq!(diff.removed_commits[1], "zsuskuln 1a2b3c4d Fix tests");
+    assert_eq!(diff.changed_refs, ["feature", "feature@origin"]);
+    assert_eq!(diff.summary(), "+1 -2 commits, 2 bookmarks changed");
+}
+
+#[test]
+fn test_parse_op_diff_empty() {
+    let output = "From operation: 8ac5a1b2c3d4 (now) snapshot\n  To operation: 8ac5a1b2c3d4 (now) snapshot\n";
+    let diff = Parser::parse_op_diff(output);
+    assert!(diff.is_empty());
+    assert_eq!(diff.summary(), "no visible changes");
+}
+
 // =========================================================================
 // parse_file_annotate tests
 // =========================================================================
@@ -661,6 +892,16 @@ fn test_parse_file_annotate_complex_author_name() {
     assert_eq!(content.lines[0].author, "John Doe");
 }
 
+#[test]
+fn test_parse_file_annotate_timestamp_with_offset() {
+    let output = "twzksoxt\taaa11111 John Doe 2026-01-30T10:43:19+0900    1: content";
+    let result = Parser::parse_file_annotate(output, "test.rs").unwrap();
+    let line = &result.lines[0];
+    assert_eq!(line.author, "John Doe");
+    assert_eq!(line.timestamp, "2026-01-30T10:43:19+0900");
+    assert_eq!(line.line_number, 1);
+}
+
 // =========================================================================
 // parse_resolve_list tests (Phase 9)
 // =========================================================================
@@ -711,6 +952,241 @@ fn test_parse_resolve_list_empty() {
     assert!(files.is_empty());
 }
 
+// =========================================================================
+// parse_conflict_markers tests
+// =========================================================================
+
+fn sides(output: &str) -> Vec<ConflictSide> {
+    Parser::parse_conflict_markers(output)
+        .into_iter()
+        .map(|l| l.side)
+        .collect()
+}
+
+#[test]
+fn test_parse_conflict_markers_jj_diff_style() {
+    let output = "\
+fn main() {
+<<<<<<< conflict 1 of 1
+%%%%%%% diff from base to side #1
+-    old();
++    ours();
+ shared();
++++++++ side #2
+    theirs();
+>>>>>>> conflict 1 of 1 ends
+}
+";
+    use ConflictSide::*;
+    assert_eq!(
+        sides(output),
+        vec![
+            Context, Marker, Marker, Base, Ours, Context, Marker, Theirs, Marker, Context
+        ]
+    );
+}
+
+#[test]
+fn test_parse_conflict_markers_jj_snapshot_style() {
+    let output = "\
+<<<<<<< conflict 1 of 1
++++++++ side #1
+ours
+------- base
+base
++++++++ side #2
+theirs
+>>>>>>> conflict 1 of 1 ends
+";
+    use ConflictSide::*;
+    assert_eq!(
+        sides(output),
+        vec![Marker, Marker, Ours, Marker, Base, Marker, Theirs, Marker]
+    );
+}
+
+#[test]
+fn test_parse_conflict_markers_longer_markers_keep_marker_like_content() {
+    let output = "\
+<<<<<<<< conflict 1 of 1
+++++++++ side #1
+=======
+-------- base
++++++++
+++++++++ side #2
+-------
+>>>>>>>> conflict 1 of 1 ends
+";
+    use ConflictSide::*;
+    assert_eq!(
+        sides(output),
+        vec![Marker, Marker, Ours, Marker, Base, Marker, Theirs, Marker]
+    );
+}
+
+#[test]
+fn test_parse_conflict_markers_git_style() {
+    let output = "\
+<<<<<<< side #1
+ours
+||||||| base
+base
+=======
+theirs
+>>>>>>> side #2
+";
+    use ConflictSide::*;
+    assert_eq!(
+        sides(output),
+        vec![Marker, Ours, Marker, Base, Marker, Theirs, Marker]
+    );
+}
+
+#[test]
+fn test_parse_conflict_markers_diff_for_second_side() {
+    let output = "\
+<<<<<<< conflict 1 of 1
++++++++ side #1
+ours
+%%%%%%% diff from base to side #2
+-base
++theirs
+>>>>>>> conflict 1 of 1 ends
+";
+    use ConflictSide::*;
+    assert_eq!(
+        sides(output),
+        vec![Marker, Marker, Ours, Marker, Base, Theirs, Marker]
+    );
+}
+
+// =========================================================================
+// parse_merge_segments tests
+// =========================================================================
+
+fn only_region(segments: &[MergeSegment]) -> &ConflictRegion {
+    let regions: Vec<&ConflictRegion> = segments
+        .iter()
+        .filter_map(|s| match s {
+            MergeSegment::Conflict(r) => Some(r),
+            MergeSegment::Context(_) => None,
+        })
+        .collect();
+    assert_eq!(regions.len(), 1);
+    regions[0]
+}
+
+#[test]
+fn test_parse_merge_segments_jj_diff_style() {
+    let output = "\
+before
+<<<<<<< conflict 1 of 1
+%%%%%%% diff from base to side #1
+-old
++ours
+ shared
++++++++ side #2
+theirs
+>>>>>>> conflict 1 of 1 ends
+after
+";
+    let segments = Parser::parse_merge_segments(output).unwrap();
+    assert_eq!(segments.len(), 3);
+    assert_eq!(segments[0], MergeSegment::Context("before".to_string()));
+    let region = only_region(&segments);
+    assert_eq!(region.base, vec!["old", "shared"]);
+    assert_eq!(region.ours, vec!["ours", "shared"]);
+    assert_eq!(region.theirs, vec!["theirs"]);
+}
+
+#[test]
+fn test_parse_merge_segments_snapshot_style() {
+    let output = "\
+<<<<<<< conflict 1 of 1
++++++++ side #1
+ours
+------- base
+base
++++++++ side #2
+theirs
+>>>>>>> conflict 1 of 1 ends
+";
+    let segments = Parser::parse_merge_segments(output).unwrap();
+    let region = only_region(&segments);
+    assert_eq!(region.base, vec!["base"]);
+    assert_eq!(region.ours, vec!["ours"]);
+    assert_eq!(region.theirs, vec!["theirs"]);
+}
+
+#[test]
+fn test_parse_merge_segments_git_style() {
+    let output = "<<<<<<< a\nours\n||||||| b\nbase\n=======\ntheirs\n>>>>>>> c\n";
+    let segments = Parser::parse_merge_segments(output).unwrap();
+    let region = only_region(&segments);
+    assert_eq!(region.base, vec!["base"]);
+    assert_eq!(region.ours, vec!["ours"]);
+    assert_eq!(region.theirs, vec!["theirs"]);
+}
+
+#[test]
+fn test_parse_merge_segments_git_style_empty_first_side() {
+    let output = "<<<<<<< a\n=======\ntheirs\n>>>>>>> c\n";
+    let segments = Parser::parse_merge_segments(output).unwrap();
+    let region = only_region(&segments);
+    assert!(region.base.is_empty());
+    assert!(region.ours.is_empty());
+    assert_eq!(region.theirs, vec!["theirs"]);
+}
+
+#[test]
+fn test_parse_merge_segments_longer_markers_keep_marker_like_content() {
+    let output = "\
+<<<<<<<< conflict 1 of 1
+++++++++ side #1
+=======
+ours
+-------- base
++++++++
+base
+++++++++ side #2
+-------
+theirs
+>>>>>>>> conflict 1 of 1 ends
+";
+    let segments = Parser::parse_merge_segments(output).unwrap();
+    let region = only_region(&segments);
+    assert_eq!(region.ours, vec!["=======", "ours"]);
+    assert_eq!(region.base, vec!["+++++++", "base"]);
+    assert_eq!(region.theirs, vec!["-------", "theirs"]);
+}
+
+#[test]
+fn test_parse_merge_segments_rejects_three_sided() {
+    let output = "\
+<<<<<<< conflict 1 of 1
++++++++ side #1
+a
++++++++ side #2
+b
++++++++ side #3
+c
+>>>>>>> conflict 1 of 1 ends
+";
+    assert!(Parser::parse_merge_segments(output).is_err());
+}
+
+#[test]
+fn test_parse_merge_segments_unterminated() {
+    assert!(Parser::parse_merge_segments("<<<<<<< x\nours\n").is_err());
+}
+
+#[test]
+fn test_parse_conflict_markers_ignores_short_runs() {
+    // "<<<" is ordinary content, not a marker
+    let output = "a <<< b\n<<< c\n";
+    assert_eq!(sides(output), vec![ConflictSide::Context; 2]);
+}
+
 // =========================================================================
 // conflict field in log parser tests (Phase 9)
 // =========================================================================
@@ -737,6 +1213,57 @@ fn test_parse_log_missing_conflict_field() {
     assert!(!change.has_conflict); // defaults to false
 }
 
+#[test]
+fn test_parse_log_immutable_and_divergent_fields() {
+    let output = "◆  abc12345\tdef67890\tuser@example.com\t2026-01-01T00:00:00+0900\tdescription\tfalse\tfalse\tmain\tfalse\t\ttrue\tfalse\n\
+                  ○  xyz98765\t1234abcd\tuser@example.com\t2026-01-01T00:00:00+0900\tdescription\tfalse\tfalse\t\tfalse\t\tfalse\ttrue\n";
+    let changes = Parser::parse_log(output).unwrap();
+    assert!(changes[0].is_immutable);
+    assert!(!changes[0].is_divergent);
+    assert!(!changes[1].is_immutable);
+    assert!(changes[1].is_divergent);
+}
+
+#[test]
+fn test_parse_log_missing_immutable_and_divergent_fields() {
+    // Older output without the trailing fields
+    let output = "○  abc12345\tdef67890\tuser@example.com\t2026-01-01T00:00:00+0900\tdescription\tfalse\tfalse\tmain\tfalse\t\n";
+    let changes = Parser::parse_log(output).unwrap();
+    assert!(!changes[0].is_immutable);
+    assert!(!changes[0].is_divergent);
+    assert!(!changes[0].is_hidden);
+}
+
+#[test]
+fn test_parse_log_hidden_field() {
+    let output = "○  abc12345\tdef67890\tuser@example.com\t2026-01-01T00:00:00+0900\tdescription\tfalse\tfalse\t\tfalse\t\tfalse\tfalse\ttrue\n";
+    let changes = Parser::parse_log(output).unwrap();
+    assert!(changes[0].is_hidden);
+}
+
+#[test]
+fn test_parse_log_row_extras() {
+    let output = "○  abc12345\tdef67890\tuser@example.com\t2026-01-01T00:00:00+0900\tdescription\tfalse\tfalse\t\tfalse\t\tfalse\tfalse\tfalse\tid:abc12345678\tago:2 hours ago\n";
+    let changes = Parser::parse_log(output).unwrap();
+    let extras = &changes[0].extras;
+    assert_eq!(extras.change_id.as_deref(), Some("abc12345678"));
+    assert_eq!(extras.relative_time.as_deref(), Some("2 hours ago"));
+    assert_eq!(extras.diff_stat, None);
+    assert!(!changes[0].is_hidden);
+}
+
+#[test]
+fn test_parse_diff_stats() {
+    let output = "def67890\t12\t3\nabc12345\t0\t0\nbroken\tx\t1\n";
+    assert_eq!(
+        Parser::parse_diff_stats(output),
+        vec![
+            ("def67890".to_string(), (12, 3)),
+            ("abc12345".to_string(), (0, 0)),
+        ]
+    );
+}
+
 // =========================================================================
 // Multi-line description tests (parse_show)
 // =========================================================================
@@ -849,6 +1376,48 @@ fn test_parse_diff_body_empty() {
     assert_eq!(content.file_count(), 0);
 }
 
+#[test]
+fn test_diff_stream_parser_pieces_match_parse_show() {
+    let output = "Commit ID: abc123
+Change ID: xyz789
+Author   : Test <test@example.com> (2024-01-30 12:00:00)
+Committer: Test <test@example.com> (2024-01-30 12:00:00)
+
+    Summary line
+
+Modified regular file src/main.rs:
+   10   10:     fn main() {
+   11     : -       println!(\"old\");
+        11: +       println!(\"new\");
+Added regular file src/new.rs:
+        1: pub fn hello() {}
+";
+    let whole = Parser::parse_show(output).unwrap();
+
+    let mut parser = DiffStreamParser::show();
+    let mut lines = Vec::new();
+    for (i, line) in output.lines().enumerate() {
+        parser.push_line(line);
+        if i < 5 {
+            // Still inside the header/description
+            assert!(parser.take().is_none());
+        } else if let Some(piece) = parser.take() {
+            assert_eq!(piece.description, "Summary line");
+            assert_eq!(piece.author, "Test <test@example.com>");
+            lines.extend(piece.lines);
+        }
+    }
+    lines.extend(parser.finish().lines);
+
+    let summary = |lines: &[DiffLine]| -> Vec<(DiffLineKind, String)> {
+        lines
+            .iter()
+            .map(|line| (line.kind, line.content.clone()))
+            .collect()
+    };
+    assert_eq!(summary(&lines), summary(&whole.lines));
+}
+
 // =========================================================================
 // Stat format parser tests
 // =========================================================================
@@ -1180,3 +1749,72 @@ fn test_parse_diff_line_file_op_is_none() {
         Parser::parse_diff_line("        11: +       new line", FileOperation::Modified).unwrap();
     assert_eq!(line.file_op, None);
 }
+
+#[test]
+fn test_parse_git_hunks() {
+    let output = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1234567..89abcde 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@
+ fn a() {}
+-fn b() {}
++fn b2() {}
+ fn c() {}
+@@ -10,2 +10,0 @@ impl Foo {
+-    old();
+-    older();
+@@ -20 +18,2 @@
+-last
+\\ No newline at end of file
++last
++appended
+";
+    let hunks = Parser::parse_git_hunks(output);
+    assert_eq!(hunks.len(), 3);
+
+    assert_eq!(hunks[0].header, "@@ -1,3 +1,3 @@");
+    assert_eq!((hunks[0].old_start, hunks[0].new_start), (1, 1));
+    assert_eq!(
+        hunks[0].old_lines,
+        ["fn a() {}\n", "fn b() {}\n", "fn c() {}\n"]
+    );
+    assert_eq!(
+        hunks[0].new_lines,
+        ["fn a() {}\n", "fn b2() {}\n", "fn c() {}\n"]
+    );
+    assert_eq!(hunks[0].first_change, "fn b() {}");
+
+    assert_eq!((hunks[1].added, hunks[1].removed), (0, 2));
+    assert!(hunks[1].new_lines.is_empty());
+    assert_eq!(hunks[1].summary(), "-2 +0  old();");
+
+    // `\ No newline` applies to the removed line only
+    assert_eq!((hunks[2].old_start, hunks[2].new_start), (20, 18));
+    assert_eq!(hunks[2].old_lines, ["last"]);
+    assert_eq!(hunks[2].new_lines, ["last\n", "appended\n"]);
+    // Body lines keep diff order for line-level picks
+    let kinds: Vec<HunkLineKind> = hunks[0].lines.iter().map(|l| l.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            HunkLineKind::Context,
+            HunkLineKind::Removed,
+            HunkLineKind::Added,
+            HunkLineKind::Context
+        ]
+    );
+    assert_eq!(hunks[2].lines[0].text, "last");
+    assert_eq!(hunks[2].lines[1].text, "last\n");
+}
+
+#[test]
+fn test_parse_git_hunks_binary_has_none() {
+    let output = "\
+diff --git a/logo.png b/logo.png
+index 1234567..89abcde 100644
+Binary files a/logo.png and b/logo.png differ
+";
+    assert!(Parser::parse_git_hunks(output).is_empty());
+}
@@ -4,7 +4,7 @@ use crate::model::{ChangeId, WorkspaceInfo};
 
 /// Parse `jj workspace list` output with template:
 ///
-/// Template: `name ++ "\t" ++ self.root() ++ "\t" ++ self.target().change_id().short(8)
+/// Template: `name ++ "\t" ++ self.root() ++ "\t" ++ self.target().change_id().shortest(<len>)
 ///            ++ "\t" ++ self.target().description().first_line() ++ "\n"`
 ///
 /// Uses splitn(4, '\t') so that description (4th field) can safely contain tabs.
@@ -0,0 +1,131 @@
+//! Credential prompts during `jj git fetch` / `jj git push`
+//!
+//! Network commands run with git's and ssh's terminal prompts turned off,
+//! so that a passphrase or password request fails at once instead of
+//! hanging on a terminal the TUI owns. When the failure shows that
+//! credentials were asked for, the command is run once more with the
+//! terminal handed over ([`TerminalHandoff`]) so that ssh/git can prompt
+//! the user directly.
+
+use std::any::Any;
+use std::fmt;
+use std::process::Command;
+use std::sync::Arc;
+
+/// stderr of a fetch/push that failed because it could not prompt
+///
+/// Only password and passphrase requests count. `Permission denied
+/// (publickey)` means no key was accepted at all, which a retry on the
+/// terminal would not fix.
+const PROMPT_FAILURES: &[&str] = &[
+    // git over HTTPS (GIT_TERMINAL_PROMPT=0)
+    "terminal prompts disabled",
+    "could not read Username",
+    "could not read Password",
+    // ssh (askpass refused the key passphrase)
+    "read_passphrase",
+];
+
+/// Give the terminal to a child process for the duration of a call
+///
+/// The app's `suspend` leaves the alternate screen and raw mode; dropping
+/// the value it returns restores them.
+#[derive(Clone)]
+pub struct TerminalHandoff(Arc<dyn Fn() -> Box<dyn Any> + Send + Sync>);
+
+impl TerminalHandoff {
+    /// Wrap `suspend`, called on the UI thread only
+    pub fn new(suspend: impl Fn() -> Box<dyn Any> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(suspend))
+    }
+
+    /// Run `f` with the terminal handed over, after telling the user why
+    pub(crate) fn run<T>(&self, command: &str, f: impl FnOnce() -> T) -> T {
+        let _restore = (self.0)();
+        println!(
+            "tij: `{}` needs your credentials; answer the prompt below.",
+            command
+        );
+        f()
+    }
+}
+
+impl fmt::Debug for TerminalHandoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TerminalHandoff")
+    }
+}
+
+/// Make git and ssh fail instead of prompting on the terminal
+///
+/// Credential helpers and an ssh agent still work. A user-configured
+/// `SSH_ASKPASS` (e.g. a GUI dialog) is kept; otherwise `false` refuses
+/// the request.
+pub(crate) fn disable_prompts(cmd: &mut Command) {
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+    if std::env::var_os("SSH_ASKPASS").is_none() {
+        cmd.env("SSH_ASKPASS", "false");
+    }
+    cmd.env("SSH_ASKPASS_REQUIRE", "force");
+}
+
+/// Whether a failed fetch/push wanted a passphrase or password
+pub(crate) fn needs_credentials(stderr: &str) -> bool {
+    PROMPT_FAILURES
+        .iter()
+        .any(|pattern| stderr.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_credentials() {
+        assert!(needs_credentials(
+            "fatal: could not read Username for 'https://github.com': terminal prompts disabled"
+        ));
+        assert!(needs_credentials(
+            "ssh_askpass: exec(false): No such file or directory\nread_passphrase: can't open /dev/tty"
+        ));
+        assert!(!needs_credentials(
+            "git@github.com: Permission denied (publickey).\nfatal: Could not read from remote repository."
+        ));
+        assert!(!needs_credentials("Host key verification failed."));
+        assert!(!needs_credentials(
+            "Error: Refusing to push a bookmark that unexpectedly moved on the remote"
+        ));
+    }
+
+    #[test]
+    fn test_disable_prompts_sets_git_and_ssh_env() {
+        let mut cmd = Command::new("true");
+        disable_prompts(&mut cmd);
+        let envs: Vec<_> = cmd
+            .get_envs()
+            .map(|(key, value)| (key.to_owned(), value.map(|v| v.to_owned())))
+            .collect();
+        assert!(envs.contains(&("GIT_TERMINAL_PROMPT".into(), Some("0".into()))));
+        assert!(envs.contains(&("SSH_ASKPASS_REQUIRE".into(), Some("force".into()))));
+    }
+
+    #[test]
+    fn test_handoff_restores_after_run() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static RESTORED: AtomicUsize = AtomicUsize::new(0);
+        struct Restore;
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                RESTORED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        let handoff = TerminalHandoff::new(|| Box::new(Restore));
+        let value = handoff.run("jj git fetch", || {
+            assert_eq!(RESTORED.load(Ordering::SeqCst), 0);
+            42
+        });
+        assert_eq!(value, 42);
+        assert_eq!(RESTORED.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,10 +2,22 @@
 //!
 //! These templates ensure consistent, parseable output from jj commands
 //! regardless of user configuration.
+//!
+//! Change/commit IDs use jj's `shortest(<len>)` with the configured display
+//! length (`tij.id-length`, held by [`super::JjExecutor`]), so every template
+//! emits the same string for the same commit and ambiguous prefixes are
+//! extended by jj.
+
+use crate::model::LogRow;
 
 /// Separator used between fields in template output (tab character)
 pub const FIELD_SEPARATOR: char = '\t';
 
+/// `<expr>.shortest(<len>)`: unique ID prefix of at least the display length
+pub fn shortest_id(expr: &str, id_length: usize) -> String {
+    format!("{}.shortest({})", expr, id_length)
+}
+
 /// Templates for jj commands
 pub struct Templates;
 
@@ -13,16 +25,28 @@ impl Templates {
     /// Template for `jj log` output
     ///
     /// Fields (separated by tab):
-    /// 1. change_id (short)
-    /// 2. commit_id (short)
+    /// 1. change_id (shortest unique prefix)
+    /// 2. commit_id (shortest unique prefix)
     /// 3. author email
-    /// 4. timestamp (ISO 8601)
+    /// 4. timestamp (ISO 8601, local time; see [`crate::model::format_timestamp`])
     /// 5. description (first line)
     /// 6. is_working_copy ("true" or "false")
     /// 7. is_empty ("true" or "false")
     /// 8. bookmarks (comma-separated)
     /// 9. has_conflict ("true" or "false")
     /// 10. working_copies (comma-separated workspace names)
+    /// 11. is_immutable ("true" or "false")
+    /// 12. is_divergent ("true" or "false")
+    /// 13. is_hidden ("true" or "false"; abandoned or rewritten commits)
+    ///
+    /// followed by the extra fields `row` (`tij.log-row`) needs, each tagged
+    /// with its name so that the parser doesn't depend on which are present:
+    /// - `id:<change_id.shortest(N)>` for `change_id:N`
+    /// - `ago:<relative author time>` for `timestamp:relative`
+    ///
+    /// The `diff_stats` column is not part of it: computing every diff of
+    /// the log is slow, so [`Templates::diff_stats`] is run for the rows on
+    /// screen instead.
     ///
     /// Notes:
     /// - jj doesn't interpret `\x1f` escape sequences in templates,
@@ -30,15 +54,13 @@ impl Templates {
     /// - `current_working_copy` is available in jj 0.20.0+.
     ///   (Earlier versions used `self.working_copy()` which no longer exists)
     /// - `conflict` keyword is available in jj 0.12.0+.
-    pub fn log() -> &'static str {
-        concat!(
-            "change_id.short(8)",
-            " ++ \"\\t\" ++ ",
-            "commit_id.short(8)",
+    pub fn log(row: &LogRow, id_length: usize) -> String {
+        // Everything after the two ID fields
+        let rest = concat!(
             " ++ \"\\t\" ++ ",
             "author.email()",
             " ++ \"\\t\" ++ ",
-            "author.timestamp().format('%Y-%m-%dT%H:%M:%S%z')",
+            "author.timestamp().local().format('%Y-%m-%dT%H:%M:%S%z')",
             " ++ \"\\t\" ++ ",
             "description.first_line()",
             " ++ \"\\t\" ++ ",
@@ -51,7 +73,42 @@ impl Templates {
             "if(conflict, 'true', 'false')",
             " ++ \"\\t\" ++ ",
             "self.working_copies().map(|w| w.name()).join(',')",
-            " ++ \"\\n\""
+            " ++ \"\\t\" ++ ",
+            "if(immutable, 'true', 'false')",
+            " ++ \"\\t\" ++ ",
+            "if(divergent, 'true', 'false')",
+            " ++ \"\\t\" ++ ",
+            "if(hidden, 'true', 'false')"
+        );
+        let mut extras = String::new();
+        if let Some(len) = row.change_id_length() {
+            extras.push_str(&format!(" ++ \"\\tid:\" ++ change_id.shortest({})", len));
+        }
+        if row.relative_timestamps() {
+            extras.push_str(" ++ \"\\tago:\" ++ author.timestamp().ago()");
+        }
+        format!(
+            "{} ++ \"\\t\" ++ {}{}{} ++ \"\\n\"",
+            shortest_id("change_id", id_length),
+            shortest_id("commit_id", id_length),
+            rest,
+            extras
+        )
+    }
+
+    /// Template for the Log View `diff_stats` column
+    ///
+    /// Fields (separated by tab): commit_id (same prefix as [`Templates::log`]),
+    /// lines added, lines removed
+    pub fn diff_stats(id_length: usize) -> String {
+        format!(
+            concat!(
+                "{}",
+                " ++ \"\\t\" ++ self.diff().stat().total_added()",
+                " ++ \"\\t\" ++ self.diff().stat().total_removed()",
+                " ++ \"\\n\""
+            ),
+            shortest_id("commit_id", id_length)
         )
     }
 
@@ -59,9 +116,11 @@ impl Templates {
     ///
     /// Fields (separated by tab):
     /// 1. operation_id (short, 12 chars)
-    /// 2. user
-    /// 3. timestamp
+    /// 2. user (`user@hostname`)
+    /// 3. timestamp (relative)
     /// 4. description
+    /// 5. start time (absolute, for date filtering)
+    /// 6. tags (`key: value`, one field per tag, may repeat)
     pub fn op_log() -> &'static str {
         concat!(
             "self.id().short(12)",
@@ -71,6 +130,35 @@ impl Templates {
             "self.time().start().ago()",
             " ++ \"\\t\" ++ ",
             "self.description().first_line()",
+            " ++ \"\\t\" ++ ",
+            "self.time().start().format('%Y-%m-%d %H:%M:%S')",
+            " ++ \"\\t\" ++ ",
+            "self.tags().lines().join(\"\\t\")",
+            " ++ \"\\n\""
+        )
+    }
+
+    /// Template for `jj op log --no-graph` with parent links
+    ///
+    /// Used to locate divergent operation heads.
+    ///
+    /// Fields (separated by tab):
+    /// 1. operation_id (short, 12 chars)
+    /// 2. parent operation IDs (short, comma-separated)
+    /// 3. user (`user@hostname`)
+    /// 4. timestamp (relative)
+    /// 5. description
+    pub fn op_log_with_parents() -> &'static str {
+        concat!(
+            "self.id().short(12)",
+            " ++ \"\\t\" ++ ",
+            "self.parents().map(|p| p.id().short(12)).join(',')",
+            " ++ \"\\t\" ++ ",
+            "self.user()",
+            " ++ \"\\t\" ++ ",
+            "self.time().start().ago()",
+            " ++ \"\\t\" ++ ",
+            "self.description().first_line()",
             " ++ \"\\n\""
         )
     }
@@ -78,70 +166,111 @@ impl Templates {
     /// Template for getting change metadata (for compare info)
     ///
     /// Fields (separated by tab):
-    /// 1. change_id (short, 8 chars)
+    /// 1. change_id (shortest unique prefix)
     /// 2. bookmarks (comma-separated)
     /// 3. author email
     /// 4. timestamp
     /// 5. description (first line)
-    pub fn change_info() -> &'static str {
-        concat!(
-            "change_id.short(8)",
+    pub fn change_info(id_length: usize) -> String {
+        let rest = concat!(
             " ++ \"\\t\" ++ ",
             "bookmarks.map(|b| b.name()).join(',')",
             " ++ \"\\t\" ++ ",
             "author.email()",
             " ++ \"\\t\" ++ ",
-            "author.timestamp().format('%Y-%m-%dT%H:%M:%S%z')",
+            "author.timestamp().local().format('%Y-%m-%dT%H:%M:%S%z')",
             " ++ \"\\t\" ++ ",
             "description.first_line()",
             " ++ \"\\n\""
-        )
+        );
+        format!("{}{}", shortest_id("change_id", id_length), rest)
     }
 
-    /// Template for `jj file annotate` output
-    ///
-    /// Uses `commit.change_id().short(8)` and `commit.commit_id().short(8)` to ensure
-    /// ID lengths match the log template (8 chars), enabling reliable cross-view ID matching.
+    /// Template for Stack View (`jj log --no-graph -r trunk()..@`)
     ///
-    /// Output format:
-    /// `<change_id>\t<commit_id> <author> <timestamp>  <line_number>: <content>`
-    ///
-    /// Note: Uses AnnotationLine template methods available in jj 0.38+.
-    /// The tab separator between change_id and commit_id distinguishes them
-    /// from the space-separated remaining fields.
+    /// Fields (separated by tab):
+    /// 1. change_id (shortest unique prefix)
+    /// 2. commit_id (shortest unique prefix)
+    /// 3. is_working_copy ("true" or "false")
+    /// 4. is_empty ("true" or "false")
+    /// 5. has_conflict ("true" or "false")
+    /// 6. on_remote: reachable from a remote bookmark ("true" or "false")
+    /// 7. local bookmarks (comma-separated)
+    /// 8. description (first line)
+    pub fn stack(id_length: usize) -> String {
+        let rest = concat!(
+            " ++ \"\\t\" ++ ",
+            "if(current_working_copy, 'true', 'false')",
+            " ++ \"\\t\" ++ ",
+            "if(empty, 'true', 'false')",
+            " ++ \"\\t\" ++ ",
+            "if(conflict, 'true', 'false')",
+            " ++ \"\\t\" ++ ",
+            "if(self.contained_in('::remote_bookmarks()'), 'true', 'false')",
+            " ++ \"\\t\" ++ ",
+            "local_bookmarks.map(|b| b.name()).join(',')",
+            " ++ \"\\t\" ++ ",
+            "description.first_line()",
+            " ++ \"\\n\""
+        );
+        format!(
+            "{} ++ \"\\t\" ++ {}{}",
+            shortest_id("change_id", id_length),
+            shortest_id("commit_id", id_length),
+            rest
+        )
+    }
+
     /// Template for `jj workspace list` output
     ///
     /// Fields (separated by tab):
     /// 1. name (workspace name)
     /// 2. root path (self.root(), may be error string if not recorded)
-    /// 3. change_id (working copy, short 8 chars)
+    /// 3. change_id (working copy, shortest unique prefix)
     /// 4. description (working copy, first line)
-    pub fn workspace_list() -> &'static str {
-        concat!(
-            "name",
-            " ++ \"\\t\" ++ ",
-            "self.root()",
-            " ++ \"\\t\" ++ ",
-            "self.target().change_id().short(8)",
-            " ++ \"\\t\" ++ ",
-            "self.target().description().first_line()",
-            " ++ \"\\n\""
+    pub fn workspace_list(id_length: usize) -> String {
+        format!(
+            concat!(
+                "name",
+                " ++ \"\\t\" ++ ",
+                "self.root()",
+                " ++ \"\\t\" ++ ",
+                "{}",
+                " ++ \"\\t\" ++ ",
+                "self.target().description().first_line()",
+                " ++ \"\\n\""
+            ),
+            shortest_id("self.target().change_id()", id_length)
         )
     }
 
-    pub fn file_annotate() -> &'static str {
-        concat!(
-            "commit.change_id().short(8)",
-            " ++ \"\\t\" ++ ",
-            "commit.commit_id().short(8)",
+    /// Template for `jj file annotate` output
+    ///
+    /// Uses the same `shortest(<len>)` IDs as the log template, enabling
+    /// reliable cross-view ID matching.
+    ///
+    /// Output format:
+    /// `<change_id>\t<commit_id> <author> <timestamp>  <line_number>: <content>`
+    ///
+    /// Note: Uses AnnotationLine template methods available in jj 0.38+.
+    /// The tab separator between change_id and commit_id distinguishes them
+    /// from the space-separated remaining fields.
+    pub fn file_annotate(id_length: usize) -> String {
+        let rest = concat!(
             " ++ \" \" ++ ",
             "commit.author().name()",
             " ++ \" \" ++ ",
-            "commit.committer().timestamp().format(\"%Y-%m-%d %H:%M:%S\")",
+            "commit.committer().timestamp().local().format(\"%Y-%m-%dT%H:%M:%S%z\")",
             " ++ \"    \" ++ ",
             "self.line_number()",
             " ++ \": \" ++ ",
             "self.content()",
+        );
+        format!(
+            "{} ++ \"\\t\" ++ {}{}",
+            shortest_id("commit.change_id()", id_length),
+            shortest_id("commit.commit_id()", id_length),
+            rest
         )
     }
 }
@@ -149,34 +278,116 @@ impl Templates {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::DEFAULT_ID_LENGTH;
 
     #[test]
     fn test_log_template_is_valid() {
-        let template = Templates::log();
+        let template = Templates::log(&LogRow::default(), DEFAULT_ID_LENGTH);
         assert!(template.contains("change_id"));
         assert!(template.contains("commit_id"));
         assert!(template.contains("\\t")); // tab separator
         assert!(template.contains("\\n")); // newline at end
     }
 
+    #[test]
+    fn test_op_log_with_parents_template_field_order() {
+        let template = Templates::op_log_with_parents();
+        assert!(template.starts_with(
+            "self.id().short(12) ++ \"\\t\" ++ self.parents().map(|p| p.id().short(12))"
+        ));
+        assert!(template.ends_with("first_line() ++ \"\\n\""));
+    }
+
+    #[test]
+    fn test_stack_template_field_order() {
+        let template = Templates::stack(DEFAULT_ID_LENGTH);
+        assert!(template.starts_with(
+            "change_id.shortest(8) ++ \"\\t\" ++ commit_id.shortest(8) ++ \"\\t\" ++ if(current_working_copy"
+        ));
+        assert!(template.contains("contained_in('::remote_bookmarks()')"));
+        assert!(template.ends_with("description.first_line() ++ \"\\n\""));
+    }
+
+    #[test]
+    fn test_templates_use_given_id_length() {
+        assert!(
+            Templates::log(&LogRow::default(), 12)
+                .starts_with("change_id.shortest(12) ++ \"\\t\" ++ commit_id.shortest(12)")
+        );
+        assert!(Templates::diff_stats(4).starts_with("commit_id.shortest(4)"));
+    }
+
     #[test]
     fn test_field_separator_is_tab() {
         assert_eq!(FIELD_SEPARATOR, '\t');
     }
 
     #[test]
-    fn test_file_annotate_template_uses_short_8() {
-        let template = Templates::file_annotate();
-        assert!(template.contains("change_id().short(8)"));
+    fn test_file_annotate_template_uses_shortest_ids() {
+        let template = Templates::file_annotate(DEFAULT_ID_LENGTH);
+        assert!(template.contains("change_id().shortest(8)"));
+        assert!(template.contains("commit_id().shortest(8)"));
+    }
+
+    #[test]
+    fn test_log_template_field_order() {
+        let template = Templates::log(&LogRow::default(), DEFAULT_ID_LENGTH);
+        assert!(template.starts_with(
+            "change_id.shortest(8) ++ \"\\t\" ++ commit_id.shortest(8) ++ \"\\t\" ++ author.email()"
+        ));
+        assert!(template.ends_with(
+            "if(divergent, 'true', 'false') ++ \"\\t\" ++ if(hidden, 'true', 'false') ++ \"\\n\""
+        ));
+    }
+
+    #[test]
+    fn test_log_template_extras_follow_row_layout() {
+        let row = LogRow::parse("change_id:12 timestamp:relative diff_stats").unwrap();
+        let template = Templates::log(&row, DEFAULT_ID_LENGTH);
+        assert!(template.ends_with(concat!(
+            "if(hidden, 'true', 'false')",
+            " ++ \"\\tid:\" ++ change_id.shortest(12)",
+            " ++ \"\\tago:\" ++ author.timestamp().ago()",
+            " ++ \"\\n\""
+        )));
+        assert!(
+            Templates::log(&LogRow::default(), DEFAULT_ID_LENGTH)
+                .ends_with("if(hidden, 'true', 'false') ++ \"\\n\"")
+        );
+    }
+
+    #[test]
+    fn test_diff_stats_template_keys_by_log_commit_id() {
+        let template = Templates::diff_stats(DEFAULT_ID_LENGTH);
+        assert!(template.starts_with("commit_id.shortest(8) ++ \"\\t\""));
+        assert!(template.contains("self.diff().stat().total_removed()"));
+    }
+
+    #[test]
+    fn test_timestamps_are_local_iso() {
+        for template in [
+            Templates::log(&LogRow::default(), DEFAULT_ID_LENGTH),
+            Templates::change_info(DEFAULT_ID_LENGTH),
+        ] {
+            assert!(template.contains("timestamp().local().format('%Y-%m-%dT%H:%M:%S%z')"));
+        }
+    }
+
+    #[test]
+    fn test_workspace_list_template_uses_shortest_id() {
+        let template = Templates::workspace_list(DEFAULT_ID_LENGTH);
+        assert!(template.contains("self.target().change_id().shortest(8)"));
     }
 
     #[test]
     fn test_file_annotate_template_has_required_fields() {
-        let template = Templates::file_annotate();
+        let template = Templates::file_annotate(DEFAULT_ID_LENGTH);
         assert!(template.contains("change_id"));
         assert!(template.contains("commit_id"));
         assert!(template.contains("author"));
         assert!(template.contains("line_number"));
         assert!(template.contains("content"));
+        // Machine-readable with offset, formatted by `format_timestamp`
+        assert!(template.contains("%Y-%m-%dT%H:%M:%S%z"));
     }
 }
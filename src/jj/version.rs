@@ -0,0 +1,133 @@
+//! jj version probe
+//!
+//! tij checks the jj version at startup; the features that need a newer jj
+//! than the oldest one it knows about are listed with their minimum
+//! version, for Diagnostics.
+
+/// Minimum required jj version (major, minor)
+///
+/// Bumped to 0.41 because read-only invocations rely on `--no-integrate-operation`
+/// (introduced in jj 0.41) to avoid polluting the operation log with snapshot ops.
+pub const MIN_JJ_VERSION: (u32, u32) = (0, 41);
+
+/// jj features tij relies on, with the version that introduced them
+pub const VERSION_GATES: &[(&str, (u32, u32))] = &[
+    ("Workspace roots in Workspace View", (0, 40)),
+    ("Arrange (interactive graph editing)", (0, 40)),
+    ("Read-only queries without op log entries", (0, 41)),
+    ("Fix entire files (jj fix --all-lines)", (0, 41)),
+];
+
+/// Each feature gate with whether `version` passes it
+pub fn feature_gates(version: (u32, u32)) -> Vec<(&'static str, (u32, u32), bool)> {
+    VERSION_GATES
+        .iter()
+        .map(|&(feature, needed)| (feature, needed, version >= needed))
+        .collect()
+}
+
+/// Parse jj version string into (major, minor).
+///
+/// Handles formats like "jj 0.40.0", "jj 0.40.0-rc1", "jj 1.0.0.dev1234".
+pub fn parse_jj_version(output: &str) -> Option<(u32, u32)> {
+    let version_str = output.trim().strip_prefix("jj ")?;
+    let parts: Vec<&str> = version_str.split('.').collect();
+    if parts.len() >= 2 {
+        let major = parse_leading_digits(parts[0])?;
+        let minor = parse_leading_digits(parts[1])?;
+        Some((major, minor))
+    } else {
+        None
+    }
+}
+
+/// Extract leading digits from a string (e.g., "40" -> 40, "40-rc1" -> 40).
+fn parse_leading_digits(s: &str) -> Option<u32> {
+    let digits: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        None
+    } else {
+        digits.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jj_version_normal() {
+        assert_eq!(parse_jj_version("jj 0.40.0"), Some((0, 40)));
+    }
+
+    #[test]
+    fn test_parse_jj_version_older() {
+        assert_eq!(parse_jj_version("jj 0.39.1"), Some((0, 39)));
+    }
+
+    #[test]
+    fn test_parse_jj_version_major() {
+        assert_eq!(parse_jj_version("jj 1.0.0"), Some((1, 0)));
+    }
+
+    #[test]
+    fn test_parse_jj_version_rc_suffix() {
+        assert_eq!(parse_jj_version("jj 0.40.0-rc1"), Some((0, 40)));
+    }
+
+    #[test]
+    fn test_parse_jj_version_dev_suffix() {
+        assert_eq!(parse_jj_version("jj 0.40.0.dev1234"), Some((0, 40)));
+    }
+
+    #[test]
+    fn test_parse_jj_version_with_trailing_newline() {
+        assert_eq!(parse_jj_version("jj 0.40.0\n"), Some((0, 40)));
+    }
+
+    #[test]
+    fn test_parse_jj_version_invalid() {
+        assert_eq!(parse_jj_version("invalid"), None);
+    }
+
+    #[test]
+    fn test_parse_jj_version_empty() {
+        assert_eq!(parse_jj_version(""), None);
+    }
+
+    #[test]
+    fn test_feature_gates() {
+        let gates = feature_gates((0, 40));
+        assert!(
+            gates
+                .iter()
+                .any(|(_, needed, ok)| *needed == (0, 40) && *ok)
+        );
+        assert!(
+            gates
+                .iter()
+                .any(|(_, needed, ok)| *needed == (0, 41) && !*ok)
+        );
+        assert!(feature_gates(MIN_JJ_VERSION).iter().all(|(_, _, ok)| *ok));
+    }
+
+    #[test]
+    fn test_parse_leading_digits_normal() {
+        assert_eq!(parse_leading_digits("40"), Some(40));
+    }
+
+    #[test]
+    fn test_parse_leading_digits_with_suffix() {
+        assert_eq!(parse_leading_digits("40-rc1"), Some(40));
+    }
+
+    #[test]
+    fn test_parse_leading_digits_empty() {
+        assert_eq!(parse_leading_digits(""), None);
+    }
+
+    #[test]
+    fn test_parse_leading_digits_no_digits() {
+        assert_eq!(parse_leading_digits("abc"), None);
+    }
+}
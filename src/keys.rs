@@ -12,6 +12,42 @@ use crate::ui::views::InputMode;
 // Key detection helpers (for modifier keys)
 // =============================================================================
 
+/// Check if key is Ctrl+P (review and push the push queue)
+pub fn is_push_queue_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('p') | KeyCode::Char('P'))
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Check if key is Ctrl+N (merge helper: new merge change, Log View)
+pub fn is_merge_helper_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('n') | KeyCode::Char('N'))
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Check if key is Ctrl+X (split by picking files, Log View)
+pub fn is_split_files_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('x') | KeyCode::Char('X'))
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Check if key is Ctrl+Z (revert a range of changes, Log View)
+pub fn is_revert_range_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('z') | KeyCode::Char('Z'))
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Check if key is Ctrl+F (toggle fullscreen for the current view)
+pub fn is_fullscreen_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('f') | KeyCode::Char('F'))
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Check if key is Ctrl+O (open the selection on the forge in a browser)
+pub fn is_browse_key(key: &KeyEvent) -> bool {
+    matches!(key.code, KeyCode::Char('o') | KeyCode::Char('O'))
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
 /// Check if key is Ctrl+L (refresh)
 /// Note: Accept both 'l' and 'L' for terminal compatibility
 pub fn is_refresh_key(key: &KeyEvent) -> bool {
@@ -96,6 +132,9 @@ pub const COMMIT: KeyCode = KeyCode::Char('C');
 /// Squash change (select destination, Log View, uppercase)
 pub const SQUASH: KeyCode = KeyCode::Char('S');
 
+/// Squash @ into the selected revision without the select mode (Log View)
+pub const QUICK_AMEND: KeyCode = KeyCode::Char('a');
+
 /// Abandon change (Log View, uppercase)
 pub const ABANDON: KeyCode = KeyCode::Char('A');
 
@@ -130,13 +169,26 @@ pub const FETCH: KeyCode = KeyCode::Char('F');
 /// Push to remote (Log View, uppercase for remote ops)
 pub const PUSH: KeyCode = KeyCode::Char('P');
 
+/// Open, copy or create the bookmark's pull request (Bookmark View)
+pub const PULL_REQUEST: KeyCode = KeyCode::Char('O');
+
+/// Add/remove bookmarks in the push queue (Log View, Bookmark View)
+pub const PUSH_QUEUE_TOGGLE: KeyCode = KeyCode::Char('Q');
+
 /// Track remote bookmarks (Log View, uppercase for remote ops)
 pub const TRACK: KeyCode = KeyCode::Char('T');
 
 /// Jump to bookmark (Log View)
 pub const BOOKMARK_JUMP: KeyCode = KeyCode::Char('\'');
 
-/// Compare two revisions (Log View)
+/// Pin/unpin the selected change to the favorites strip (Log View);
+/// 1-9 jump to the pins
+pub const PIN: KeyCode = KeyCode::Char('"');
+
+/// Add/edit the local note on the selected change (Log View)
+pub const NOTE: KeyCode = KeyCode::Char(';');
+
+/// Compare two revisions (Log View) or two versions of a change (Evolog View)
 pub const COMPARE: KeyCode = KeyCode::Char('=');
 
 /// Interdiff two revisions (Log View)
@@ -157,12 +209,48 @@ pub const TAG_VIEW: KeyCode = KeyCode::Char('t');
 /// Open Workspace View (Log View)
 pub const WORKSPACE_VIEW: KeyCode = KeyCode::Char('w');
 
+/// Open Remote View (Log View)
+pub const REMOTE_VIEW: KeyCode = KeyCode::Char('m');
+
+/// Open Stack View: `trunk()..@` as a linear stack (Log View)
+pub const STACK_VIEW: KeyCode = KeyCode::Char('^');
+
+/// Enter reorder mode: move changes with K/J (Log View)
+pub const REORDER: KeyCode = KeyCode::Char('&');
+
+/// Move commit up, towards its descendants (Stack View, Log View reorder mode)
+pub const MOVE_COMMIT_UP: KeyCode = KeyCode::Char('K');
+
+/// Move commit down, towards its ancestors (Stack View, Log View reorder mode)
+pub const MOVE_COMMIT_DOWN: KeyCode = KeyCode::Char('J');
+
 /// Open Command History View (Log View)
 pub const COMMAND_HISTORY: KeyCode = KeyCode::Char('H');
 
+/// Run a read-only jj command, output in the Pager View (Log View)
+pub const JJ_COMMAND: KeyCode = KeyCode::Char(':');
+
+/// Write a bug report bundle (Command History View)
+pub const BUG_REPORT: KeyCode = KeyCode::Char('B');
+
+/// Open the log of every jj process run (Command History View)
+pub const COMMAND_LOG: KeyCode = KeyCode::Char('L');
+
+/// Open the environment diagnostics (Command History View)
+pub const DIAGNOSTICS: KeyCode = KeyCode::Char('D');
+
 /// Toggle preview pane (Log View)
 pub const PREVIEW: KeyCode = KeyCode::Char('p');
 
+/// Move the preview pane between bottom and right (Log View)
+pub const PREVIEW_LAYOUT: KeyCode = KeyCode::Char('\\');
+
+/// Shrink the log pane / grow the preview (Log View)
+pub const PREVIEW_SHRINK: KeyCode = KeyCode::Char('<');
+
+/// Grow the log pane / shrink the preview (Log View)
+pub const PREVIEW_GROW: KeyCode = KeyCode::Char('>');
+
 /// Untrack remote bookmark (Bookmark View)
 pub const BOOKMARK_UNTRACK: KeyCode = KeyCode::Char('U');
 
@@ -175,6 +263,29 @@ pub const BOOKMARK_FORGET: KeyCode = KeyCode::Char('f');
 /// Move bookmark to @ (Bookmark View)
 pub const BOOKMARK_MOVE: KeyCode = KeyCode::Char('m');
 
+/// Move bookmarks left on rewritten commits to the rewrites (Bookmark View)
+pub const BOOKMARK_RETARGET: KeyCode = KeyCode::Char('E');
+
+/// Cycle the sort mode (Bookmark View: name/date/ahead, Operation View:
+/// chronological/by type)
+pub const SORT_CYCLE: KeyCode = KeyCode::Char('S');
+
+/// Open Sparse View (Status View: sparse checkout patterns)
+pub const SPARSE_VIEW: KeyCode = KeyCode::Char('S');
+
+/// Mark the selected change as a merge parent (Log View)
+pub const MARK: KeyCode = KeyCode::Char(' ');
+
+/// Mark the other end of an abandon range (Operation View)
+pub const RANGE_MARK: KeyCode = KeyCode::Char(' ');
+
+/// Garbage-collect abandoned operations (Operation View: `jj util gc`)
+pub const OP_GC: KeyCode = KeyCode::Char('C');
+
+/// Show only the next/previous remote's bookmarks (Bookmark View)
+pub const REMOTE_FILTER_NEXT: KeyCode = KeyCode::Char(']');
+pub const REMOTE_FILTER_PREV: KeyCode = KeyCode::Char('[');
+
 /// Move @ to next child (Log View)
 pub const NEXT_CHANGE: KeyCode = KeyCode::Char(']');
 
@@ -184,9 +295,42 @@ pub const PREV_CHANGE: KeyCode = KeyCode::Char('[');
 /// Toggle reversed display order (Log View)
 pub const LOG_REVERSE: KeyCode = KeyCode::Char('V');
 
+/// Expand/collapse elided revisions in the graph (Log View)
+pub const TOGGLE_ELIDED: KeyCode = KeyCode::Char('~');
+
+/// Toggle listing hidden (abandoned/rewritten) commits (Log View)
+pub const TOGGLE_HIDDEN: KeyCode = KeyCode::Char('h');
+
+/// Restore a hidden commit's content into @ (Log View, hidden rows)
+pub const RESTORE_HIDDEN: KeyCode = KeyCode::Char('U');
+
+/// Switch to the next revset preset (Log View)
+pub const REVSET_PRESET_CYCLE: KeyCode = KeyCode::Char('*');
+
+/// Open the revset preset menu: apply or save presets (Log View)
+pub const REVSET_PRESET_MENU: KeyCode = KeyCode::Char('#');
+
+/// Open saved compare sessions: re-open, export or delete (Log View)
+pub const COMPARE_SESSIONS: KeyCode = KeyCode::Char('+');
+
+/// Save the current compare as a named session (Diff View, compare mode)
+pub const COMPARE_SAVE: KeyCode = KeyCode::Char('S');
+
+/// Save the raw `jj log` output after a parse failure (Log View)
+pub const REPORT_PARSE_ISSUE: KeyCode = KeyCode::Char('!');
+
+/// Toggle keeping the selection centered while scrolling (Log View)
+pub const CENTER_CURSOR: KeyCode = KeyCode::Char('z');
+
+/// Toggle the commit ID column (Log View)
+pub const COMMIT_ID_COLUMN: KeyCode = KeyCode::Char('%');
+
 /// Duplicate change (Log View)
 pub const DUPLICATE: KeyCode = KeyCode::Char('Y');
 
+/// Cherry-pick: duplicate the selected change onto @ (Log View)
+pub const CHERRY_PICK: KeyCode = KeyCode::Char('@');
+
 /// Open external diff editor (Log/Status View)
 pub const DIFFEDIT: KeyCode = KeyCode::Char('E');
 
@@ -196,9 +340,36 @@ pub const RESTORE_FILE: KeyCode = KeyCode::Char('r');
 /// Restore all files (Status View, uppercase)
 pub const RESTORE_ALL: KeyCode = KeyCode::Char('R');
 
+/// Pick hunks or lines of the selected file to discard (Status View)
+pub const RESTORE_HUNKS: KeyCode = KeyCode::Char('x');
+
+/// Pick hunks or lines of the selected file to move into @- (Status View)
+pub const SQUASH_LINES: KeyCode = KeyCode::Char('m');
+
+/// Add the selected file (or its extension/directory) to .gitignore (Status View)
+pub const GITIGNORE: KeyCode = KeyCode::Char('i');
+
+/// Show/hide untracked paths (Status View)
+pub const TOGGLE_UNTRACKED: KeyCode = KeyCode::Char('U');
+
+/// Show/hide ignored paths (Status View)
+pub const TOGGLE_IGNORED: KeyCode = KeyCode::Char('I');
+
+/// Expand/collapse the jj warnings section (Status View)
+pub const TOGGLE_WARNINGS: KeyCode = KeyCode::Char('!');
+
 /// Open evolution log (Log View)
 pub const EVOLOG: KeyCode = KeyCode::Char('L');
 
+/// Open the history of the selected file (Status, Diff and Blame View)
+pub const FILE_LOG: KeyCode = KeyCode::Char('L');
+
+/// Re-blame at the parent of the selected line's change (Blame View)
+pub const BLAME_PARENT: KeyCode = KeyCode::Char(',');
+
+/// Return to the previous annotation on the breadcrumb (Blame View)
+pub const BLAME_BACK: KeyCode = KeyCode::Char('.');
+
 /// Revert a change (Log View, creates reverse-diff commit)
 pub const REVERT: KeyCode = KeyCode::Char('Z');
 
@@ -248,7 +419,7 @@ pub const HALF_PAGE_DOWN: KeyCode = KeyCode::Char('d');
 /// Half page up
 pub const HALF_PAGE_UP: KeyCode = KeyCode::Char('u');
 
-/// Copy diff to clipboard (full: jj show output)
+/// Copy to clipboard (Diff: full jj show output; Log/Evolog/Operation: yank menu)
 pub const YANK: KeyCode = KeyCode::Char('y');
 
 /// Copy diff to clipboard (diff only: jj diff output)
@@ -263,6 +434,67 @@ pub const DIFF_FORMAT_CYCLE: KeyCode = KeyCode::Char('m');
 /// Toggle full description in DiffView header (compact ↔ full)
 pub const DIFF_DESC_TOGGLE: KeyCode = KeyCode::Char('t');
 
+/// Open the diff in the configured GUI difftool (Diff/Status View)
+pub const DIFF_TOOL: KeyCode = KeyCode::Char('D');
+
+/// Toggle showing only added lines in DiffView
+pub const DIFF_ADDED_ONLY: KeyCode = KeyCode::Char('+');
+
+/// Toggle showing only deleted lines in DiffView
+pub const DIFF_DELETED_ONLY: KeyCode = KeyCode::Char('-');
+
+/// Toggle collapsing renamed/copied files in DiffView
+pub const DIFF_COLLAPSE_MOVES: KeyCode = KeyCode::Char('M');
+
+/// Toggle ignoring whitespace changes in DiffView
+pub const DIFF_IGNORE_WHITESPACE: KeyCode = KeyCode::Char('i');
+
+/// Show fewer context lines around changes in DiffView
+pub const DIFF_LESS_CONTEXT: KeyCode = KeyCode::Char('{');
+
+/// Show more context lines around changes in DiffView
+pub const DIFF_MORE_CONTEXT: KeyCode = KeyCode::Char('}');
+
+/// Toggle hiding file mode changes in DiffView
+pub const DIFF_HIDE_MODE_CHANGES: KeyCode = KeyCode::Char('E');
+
+/// Prefix of `za`, fold/unfold the current file in DiffView
+pub const DIFF_FOLD_PREFIX: KeyCode = KeyCode::Char('z');
+
+/// Second key of `za`
+pub const DIFF_FOLD_TOGGLE: KeyCode = KeyCode::Char('a');
+
+/// Fold all files to an outline of file headers, or unfold all (DiffView)
+pub const DIFF_FOLD_ALL: KeyCode = KeyCode::Char('Z');
+
+/// Open the action menu for the current file in DiffView (Space also works)
+pub const DIFF_FILE_MENU: KeyCode = KeyCode::Enter;
+
+/// Jump to the next conflict region in DiffView
+pub const DIFF_NEXT_CONFLICT: KeyCode = KeyCode::Char('c');
+
+/// Jump to the previous conflict region in DiffView
+pub const DIFF_PREV_CONFLICT: KeyCode = KeyCode::Char('C');
+
+/// Scroll long lines left (Diff/Blame View)
+pub const SCROLL_LEFT: KeyCode = KeyCode::Char('h');
+
+/// Scroll long lines right (Diff/Blame View)
+pub const SCROLL_RIGHT: KeyCode = KeyCode::Char('l');
+
+/// Toggle wrapping long lines instead of cutting them off (Diff/Blame View)
+pub const WRAP_TOGGLE: KeyCode = KeyCode::Char('W');
+
+/// Check if key scrolls long lines left (h or ←)
+pub fn is_scroll_left(code: KeyCode) -> bool {
+    matches!(code, SCROLL_LEFT | KeyCode::Left)
+}
+
+/// Check if key scrolls long lines right (l or →)
+pub fn is_scroll_right(code: KeyCode) -> bool {
+    matches!(code, SCROLL_RIGHT | KeyCode::Right)
+}
+
 // =============================================================================
 // Undo/Redo keys
 // =============================================================================
@@ -282,6 +514,208 @@ pub const STATUS_VIEW: KeyCode = KeyCode::Char('s');
 /// Open operation history view
 pub const OPERATION_HISTORY: KeyCode = KeyCode::Char('o');
 
+// =============================================================================
+// Keymap presets (`tij.keymap`)
+// =============================================================================
+
+/// Built-in keymap preset
+///
+/// `Arrows` is a translation layer over the vim bindings: its keys are
+/// mapped to the vim keys they stand for before dispatch, so every view
+/// handles a single set of keys and the vim keys keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeymapPreset {
+    /// j/k, g/G, d/u and the default letters
+    #[default]
+    Vim,
+    /// Home/End, PgDn/PgUp and mnemonic Log View letters
+    /// (d=diff, p=push, f=fetch, l=describe; P=preview, F=fix)
+    Arrows,
+}
+
+impl KeymapPreset {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "vim" => Ok(KeymapPreset::Vim),
+            "arrows" => Ok(KeymapPreset::Arrows),
+            _ => Err(format!("unknown keymap '{}' (vim, arrows)", name)),
+        }
+    }
+}
+
+/// Log View keys of the arrows preset and the vim keys they stand for
+const ARROWS_LOG_KEYS: &[(KeyCode, KeyCode)] = &[
+    (KeyCode::Char('d'), OPEN_DIFF),
+    (KeyCode::Char('l'), DESCRIBE),
+    (KeyCode::Char('p'), PUSH),
+    (KeyCode::Char('P'), PREVIEW),
+    (KeyCode::Char('f'), FETCH),
+    (KeyCode::Char('F'), FIX),
+];
+
+/// Views where `d`/`u` scroll half a page
+const HALF_PAGE_VIEWS: &[View] = &[View::Diff, View::Pager, View::Diagnostics];
+
+/// Log View help labels of the arrows preset, by vim label
+const ARROWS_LOG_LABELS: &[(&str, &str)] = &[
+    ("Enter", "d/Enter"),
+    ("d", "l"),
+    ("P", "p"),
+    ("p", "P"),
+    ("F", "f"),
+    ("f", "F"),
+];
+
+/// The vim key a preset key stands for in `view` (normal mode only)
+pub fn translate_key(preset: KeymapPreset, view: View, code: KeyCode) -> KeyCode {
+    if preset == KeymapPreset::Vim {
+        return code;
+    }
+    match code {
+        KeyCode::Home => GO_TOP,
+        KeyCode::End => GO_BOTTOM,
+        KeyCode::PageDown if HALF_PAGE_VIEWS.contains(&view) => HALF_PAGE_DOWN,
+        KeyCode::PageUp if HALF_PAGE_VIEWS.contains(&view) => HALF_PAGE_UP,
+        _ if view == View::Log => ARROWS_LOG_KEYS
+            .iter()
+            .find(|(key, _)| *key == code)
+            .map_or(code, |(_, vim)| *vim),
+        _ => code,
+    }
+}
+
+/// The key label a preset shows for a vim binding (`view` is `None` for the
+/// Global and Navigation sections)
+pub fn preset_label(preset: KeymapPreset, view: Option<View>, key: &'static str) -> &'static str {
+    if preset == KeymapPreset::Vim {
+        return key;
+    }
+    match key {
+        "g/G" => "Home/End",
+        "d/u" if view.is_some_and(|v| HALF_PAGE_VIEWS.contains(&v)) => "PgDn/PgUp",
+        _ if view == Some(View::Log) => ARROWS_LOG_LABELS
+            .iter()
+            .find(|(vim, _)| *vim == key)
+            .map_or(key, |(_, label)| label),
+        _ => key,
+    }
+}
+
+// =============================================================================
+// Key remaps (`[tij.keys]`)
+// =============================================================================
+
+/// A `[tij.keys]` entry: pressing `key` acts as the built-in `target` key
+///
+/// Remaps sit on top of the keymap preset: `[tij.keys]` applies to every
+/// view, `[tij.keys.<view>]` to one view and wins over the global table.
+/// Only keys without Ctrl/Alt can be remapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRemap {
+    /// View the remap applies to (`None` for all views)
+    pub view: Option<View>,
+    pub key: KeyCode,
+    pub target: KeyCode,
+}
+
+impl KeyRemap {
+    /// Parse an entry as `jj config list` shows it below `tij.keys`
+    /// (`x = "d"` or `log.x = "d"`)
+    pub fn parse(name: &str, target: &str) -> Result<Self, String> {
+        let (view, key) = match name.split_once('.') {
+            Some((scope, key)) if !scope.is_empty() => {
+                let view = HELP_VIEW_ORDER
+                    .iter()
+                    .copied()
+                    .find(|view| view_config_name(*view).as_deref() == Some(scope))
+                    .ok_or_else(|| format!("unknown view '{}'", scope))?;
+                (Some(view), key.trim_matches('"'))
+            }
+            _ => (None, name),
+        };
+        let parse =
+            |name: &str| parse_key_name(name).ok_or_else(|| format!("unknown key '{}'", name));
+        Ok(Self {
+            view,
+            key: parse(key)?,
+            target: parse(target)?,
+        })
+    }
+}
+
+/// Name of `view` in `[tij.keys.<view>]` (`log`, `file-history`, ...)
+pub fn view_config_name(view: View) -> Option<String> {
+    let (title, _) = view_keymap(view)?;
+    Some(
+        title
+            .trim_end_matches(" View")
+            .to_lowercase()
+            .replace(' ', "-"),
+    )
+}
+
+/// Key names as the help panel writes them
+pub const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("Enter", KeyCode::Enter),
+    ("Esc", KeyCode::Esc),
+    ("Tab", KeyCode::Tab),
+    ("Space", KeyCode::Char(' ')),
+    ("Backspace", KeyCode::Backspace),
+    ("Del", KeyCode::Delete),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PgUp", KeyCode::PageUp),
+    ("PgDn", KeyCode::PageDown),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    // Short form for labels that must fit the help key column
+    ("Spc", KeyCode::Char(' ')),
+];
+
+/// Key for a single character or a name like `Enter` or `PgDn`
+pub fn parse_key_name(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(KeyCode::Char(c)),
+        _ => KEY_NAMES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|(_, code)| *code),
+    }
+}
+
+/// Display name of a key (inverse of [`parse_key_name`])
+pub fn key_name(code: KeyCode) -> String {
+    match KEY_NAMES.iter().find(|(_, known)| *known == code) {
+        Some((name, _)) => name.to_string(),
+        None => match code {
+            KeyCode::Char(c) => c.to_string(),
+            other => format!("{:?}", other),
+        },
+    }
+}
+
+/// The built-in key `code` stands for in `view`: a view remap, else a
+/// global remap, else the preset's translation
+pub fn resolve_key(
+    preset: KeymapPreset,
+    remaps: &[KeyRemap],
+    view: View,
+    code: KeyCode,
+) -> KeyCode {
+    let remap = |scope: Option<View>| {
+        remaps
+            .iter()
+            .find(|remap| remap.view == scope && remap.key == code)
+    };
+    match remap(Some(view)).or_else(|| remap(None)) {
+        Some(remap) => remap.target,
+        None => translate_key(preset, view, code),
+    }
+}
+
 // =============================================================================
 // Help text generation
 // =============================================================================
@@ -306,6 +740,10 @@ pub const GLOBAL_KEYS: &[KeyBindEntry] = &[
         key: "Tab",
         description: "Switch view",
     },
+    KeyBindEntry {
+        key: "Alt+1/2/3",
+        description: "Log/Status/Bookmarks tab (compact layout; 1-3 stay pin jumps)",
+    },
     KeyBindEntry {
         key: "Esc",
         description: "Back to previous",
@@ -314,6 +752,14 @@ pub const GLOBAL_KEYS: &[KeyBindEntry] = &[
         key: "Ctrl+l",
         description: "Refresh",
     },
+    KeyBindEntry {
+        key: "Ctrl+f",
+        description: "Fullscreen: hide preview, status bar and banners",
+    },
+    KeyBindEntry {
+        key: "Esc/^C",
+        description: "Cancel a running fetch/push",
+    },
 ];
 
 /// Navigation key bindings for help display
@@ -336,7 +782,7 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
     },
     KeyBindEntry {
         key: "d",
-        description: "Describe (1-line quick edit; opens editor for multi-line)",
+        description: "Describe (multi-line editor, Ctrl+S save)",
     },
     KeyBindEntry {
         key: "Ctrl+e",
@@ -354,6 +800,14 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "C",
         description: "New from selected (Log)",
     },
+    KeyBindEntry {
+        key: "Space",
+        description: "Mark/unmark parent (C: new merge of marked, Esc: clear)",
+    },
+    KeyBindEntry {
+        key: "Ctrl+n",
+        description: "Merge helper: new merge change from bookmarks",
+    },
     KeyBindEntry {
         key: "/",
         description: "Search in list",
@@ -384,7 +838,7 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
     },
     KeyBindEntry {
         key: "S",
-        description: "Squash (select target)",
+        description: "Squash (select target; Enter squashes all, f picks files)",
     },
     KeyBindEntry {
         key: "A",
@@ -394,6 +848,10 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "x",
         description: "Split change",
     },
+    KeyBindEntry {
+        key: "Ctrl+x",
+        description: "Split by picking files (no diff editor)",
+    },
     KeyBindEntry {
         key: "b",
         description: "Create bookmark",
@@ -404,7 +862,11 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
     },
     KeyBindEntry {
         key: "R",
-        description: "Rebase (r/s/b/A/B)",
+        description: "Rebase (r/s/b/A/B; then Enter on a row or t to type the target)",
+    },
+    KeyBindEntry {
+        key: "a",
+        description: "Amend @ into selected (squash --from @)",
     },
     KeyBindEntry {
         key: "B",
@@ -422,6 +884,18 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "P",
         description: "Git push",
     },
+    KeyBindEntry {
+        key: "Q",
+        description: "Add/remove change's bookmarks in push queue",
+    },
+    KeyBindEntry {
+        key: "Ctrl+p",
+        description: "Review and push the push queue",
+    },
+    KeyBindEntry {
+        key: "Ctrl+o",
+        description: "Open commit on GitHub/GitLab/Bitbucket",
+    },
     KeyBindEntry {
         key: "T",
         description: "Track remote bookmarks",
@@ -430,6 +904,18 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "'",
         description: "Jump to bookmark",
     },
+    KeyBindEntry {
+        key: "\"",
+        description: "Pin/unpin change to the favorites strip (per repo)",
+    },
+    KeyBindEntry {
+        key: ";",
+        description: "Add/edit local note on change (not in history)",
+    },
+    KeyBindEntry {
+        key: "1-9",
+        description: "Jump to pinned revision",
+    },
     KeyBindEntry {
         key: "=",
         description: "Compare revisions",
@@ -438,6 +924,10 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "I",
         description: "Interdiff revisions",
     },
+    KeyBindEntry {
+        key: "+",
+        description: "Saved compare sessions",
+    },
     KeyBindEntry {
         key: "M",
         description: "Bookmark view",
@@ -450,9 +940,25 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "w",
         description: "Workspace view",
     },
+    KeyBindEntry {
+        key: "m",
+        description: "Remote view",
+    },
+    KeyBindEntry {
+        key: "^",
+        description: "Stack view (trunk()..@)",
+    },
     KeyBindEntry {
         key: "p",
-        description: "Toggle preview pane",
+        description: "Toggle preview pane (compact layout: list ↔ preview)",
+    },
+    KeyBindEntry {
+        key: "\\",
+        description: "Preview at bottom/right",
+    },
+    KeyBindEntry {
+        key: "</>",
+        description: "Resize preview split",
     },
     KeyBindEntry {
         key: "]/[",
@@ -462,10 +968,50 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "V",
         description: "Toggle reversed order",
     },
+    KeyBindEntry {
+        key: "~",
+        description: "Expand/collapse elided revisions",
+    },
+    KeyBindEntry {
+        key: "h",
+        description: "Show/hide hidden (abandoned/rewritten) commits",
+    },
+    KeyBindEntry {
+        key: "U",
+        description: "Restore hidden commit's content into @ (Y duplicates it)",
+    },
+    KeyBindEntry {
+        key: "z",
+        description: "Toggle centered cursorline",
+    },
+    KeyBindEntry {
+        key: "%",
+        description: "Toggle commit ID column (y copies the full ID)",
+    },
+    KeyBindEntry {
+        key: "*",
+        description: "Next revset preset (tij.revset-presets)",
+    },
+    KeyBindEntry {
+        key: "#",
+        description: "Revset presets menu (apply / save current)",
+    },
+    KeyBindEntry {
+        key: "!",
+        description: "Save parse issue report (after a log parse warning)",
+    },
     KeyBindEntry {
         key: "Y",
         description: "Duplicate change",
     },
+    KeyBindEntry {
+        key: "@",
+        description: "Cherry-pick onto @ (duplicate below or on top of @)",
+    },
+    KeyBindEntry {
+        key: "y",
+        description: "Yank menu (copy change/commit ID, description, bookmarks, jj show)",
+    },
     KeyBindEntry {
         key: "E",
         description: "Diffedit (external diff editor)",
@@ -478,6 +1024,10 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "Z",
         description: "Revert change (create reverse-diff commit)",
     },
+    KeyBindEntry {
+        key: "Ctrl+z",
+        description: "Revert a range (select the other end, Enter)",
+    },
     KeyBindEntry {
         key: "i",
         description: "Simplify parents (remove redundant parent edges)",
@@ -486,6 +1036,10 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "|",
         description: "Parallelize commits (convert linear chain to siblings)",
     },
+    KeyBindEntry {
+        key: "&",
+        description: "Reorder mode (K/J move change past child/parent)",
+    },
     KeyBindEntry {
         key: "f",
         description: "Fix (apply configured code formatters to revision and descendants)",
@@ -498,6 +1052,10 @@ pub const LOG_KEYS: &[KeyBindEntry] = &[
         key: "H",
         description: "Command history",
     },
+    KeyBindEntry {
+        key: ":",
+        description: "Run read-only jj command (log/show/diff/op log...)",
+    },
     KeyBindEntry {
         key: "W",
         description: "Bisect (find bad revision)",
@@ -519,13 +1077,49 @@ pub const INPUT_KEYS: &[KeyBindEntry] = &[
         description: "Cancel input",
     },
     KeyBindEntry {
-        key: "Backspace",
-        description: "Delete character",
+        key: "BS/Del",
+        description: "Delete character before/under the cursor",
+    },
+    KeyBindEntry {
+        key: "Ctrl+A/E",
+        description: "Move to start/end of line (also Home/End)",
+    },
+    KeyBindEntry {
+        key: "Alt+B/F",
+        description: "Move one word back/forward (Left/Right: one char)",
+    },
+    KeyBindEntry {
+        key: "Ctrl+W",
+        description: "Delete word before the cursor",
+    },
+    KeyBindEntry {
+        key: "Ctrl+U/K",
+        description: "Delete to start/end of line",
+    },
+    KeyBindEntry {
+        key: "Ctrl+Y",
+        description: "Paste the last deleted or copied text",
+    },
+    KeyBindEntry {
+        key: "Alt+Y",
+        description: "Swap the pasted text for the one before it",
     },
 ];
 
 /// Diff view key bindings for help display
 pub const DIFF_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "Spc/Enter",
+        description: "File actions (jump, blame, restore, squash, edit, copy path)",
+    },
+    KeyBindEntry {
+        key: "za",
+        description: "Fold/unfold the current file (also Enter on a file header)",
+    },
+    KeyBindEntry {
+        key: "Z",
+        description: "Fold all files to an outline / unfold all",
+    },
     KeyBindEntry {
         key: "m",
         description: "Cycle diff display mode (color-words/stat/git)",
@@ -534,6 +1128,34 @@ pub const DIFF_KEYS: &[KeyBindEntry] = &[
         key: "t",
         description: "Toggle full description (expand/collapse header)",
     },
+    KeyBindEntry {
+        key: "+/-",
+        description: "Show only added/deleted lines (toggle)",
+    },
+    KeyBindEntry {
+        key: "M",
+        description: "Collapse renamed/copied files to one line (toggle)",
+    },
+    KeyBindEntry {
+        key: "i",
+        description: "Ignore whitespace changes (toggle, kept for the session)",
+    },
+    KeyBindEntry {
+        key: "{/}",
+        description: "Fewer/more context lines (kept for the session)",
+    },
+    KeyBindEntry {
+        key: "E",
+        description: "Hide file mode changes (toggle, kept for the session)",
+    },
+    KeyBindEntry {
+        key: "D",
+        description: "Open in difftool (tij.diff-tool)",
+    },
+    KeyBindEntry {
+        key: "Ctrl+o",
+        description: "Open file at current line on the forge",
+    },
     KeyBindEntry {
         key: "j/k",
         description: "Scroll down/up",
@@ -550,10 +1172,30 @@ pub const DIFF_KEYS: &[KeyBindEntry] = &[
         key: "]/[",
         description: "Next/prev file",
     },
+    KeyBindEntry {
+        key: "c/C",
+        description: "Next/prev conflict region",
+    },
+    KeyBindEntry {
+        key: "h/l",
+        description: "Scroll long lines left/right",
+    },
+    KeyBindEntry {
+        key: "W",
+        description: "Wrap long lines / cut off (toggle)",
+    },
+    KeyBindEntry {
+        key: "X",
+        description: "Resolve the conflicted file under the cursor",
+    },
     KeyBindEntry {
         key: "a",
         description: "Show file blame",
     },
+    KeyBindEntry {
+        key: "L",
+        description: "File history (jj log <path>)",
+    },
     KeyBindEntry {
         key: "y",
         description: "Copy to clipboard (full: jj show)",
@@ -566,6 +1208,10 @@ pub const DIFF_KEYS: &[KeyBindEntry] = &[
         key: "w",
         description: "Export to .patch file",
     },
+    KeyBindEntry {
+        key: "S",
+        description: "Save compare as named session",
+    },
     KeyBindEntry {
         key: "q",
         description: "Back to log",
@@ -721,6 +1367,11 @@ pub const HINT_SQUASH_CONFIRM: KeyHint = KeyHint {
     label: "Squash",
     color: Color::Green,
 };
+pub const HINT_SQUASH_FILES: KeyHint = KeyHint {
+    key: "f",
+    label: "Pick files",
+    color: Color::Cyan,
+};
 pub const HINT_CANCEL: KeyHint = KeyHint {
     key: "Esc",
     label: "Cancel",
@@ -731,6 +1382,11 @@ pub const HINT_SUBMIT: KeyHint = KeyHint {
     label: "Confirm",
     color: Color::Green,
 };
+pub const HINT_EDITOR_SAVE: KeyHint = KeyHint {
+    key: "Ctrl+S",
+    label: "Save",
+    color: Color::Green,
+};
 pub const HINT_CANCEL_ESC: KeyHint = KeyHint {
     key: "Esc",
     label: "Cancel",
@@ -762,6 +1418,11 @@ pub const HINT_DIALOG_CANCEL: KeyHint = KeyHint {
     label: "Cancel",
     color: Color::Red,
 };
+pub const HINT_DIALOG_CLOSE: KeyHint = KeyHint {
+    key: "Enter/Esc",
+    label: "Close",
+    color: Color::Green,
+};
 pub const HINT_SELECT: KeyHint = KeyHint {
     key: "Enter",
     label: "Select",
@@ -773,6 +1434,11 @@ pub const HINT_RESOLVE_ENTER: KeyHint = KeyHint {
     label: "Resolve",
     color: Color::Green,
 };
+pub const HINT_MERGE: KeyHint = KeyHint {
+    key: "m",
+    label: "Merge",
+    color: Color::Green,
+};
 pub const HINT_OURS: KeyHint = KeyHint {
     key: "o",
     label: "Ours",
@@ -922,6 +1588,8 @@ pub struct HintContext {
     pub simplify_parents: bool,
     /// Current rebase mode (for revset hint visibility)
     pub rebase_mode: crate::model::RebaseMode,
+    /// Keymap preset (`tij.keymap`) the Log View hint labels follow
+    pub keymap: KeymapPreset,
 }
 
 /// Dialog kind for hint selection
@@ -932,6 +1600,8 @@ pub enum DialogHintKind {
     Select,
     /// Single-select (Enter immediately confirms)
     SingleSelect,
+    /// Read-only batch results table
+    Results,
 }
 
 // =============================================================================
@@ -954,9 +1624,17 @@ pub fn current_hints(view: View, input_mode: InputMode, ctx: &HintContext) -> Ve
         View::Bookmark => bookmark_view_hints(ctx),
         View::Tag => tag_view_hints(),
         View::Workspace => workspace_view_hints(),
+        View::Remote => remote_view_hints(),
+        View::Sparse => sparse_view_hints(),
+        View::Pager => PAGER_VIEW_HINTS.to_vec(),
+        View::Stack => stack_view_hints(),
         View::CommandHistory => command_history_hints(),
+        View::CommandLog => COMMAND_LOG_VIEW_HINTS.to_vec(),
+        View::Diagnostics => DIAGNOSTICS_VIEW_HINTS.to_vec(),
         View::Status => STATUS_VIEW_HINTS.to_vec(),
         View::Operation => OPERATION_VIEW_HINTS.to_vec(),
+        View::Merge => MERGE_VIEW_HINTS.to_vec(),
+        View::OpHeads => OP_HEADS_VIEW_HINTS.to_vec(),
         // Diff, Blame use prefix-based rendering; Help has no status bar.
         // Return empty as a safety fallback.
         _ => vec![],
@@ -968,13 +1646,19 @@ fn dialog_hints(kind: &DialogHintKind) -> Vec<KeyHint> {
         DialogHintKind::Confirm => vec![HINT_YES, HINT_NO],
         DialogHintKind::Select => vec![HINT_NAV, HINT_TOGGLE, HINT_CONFIRM, HINT_DIALOG_CANCEL],
         DialogHintKind::SingleSelect => vec![HINT_NAV, HINT_SELECT, HINT_DIALOG_CANCEL],
+        DialogHintKind::Results => vec![HINT_NAV, HINT_DIALOG_CLOSE],
     }
 }
 
 fn log_hints(input_mode: InputMode, ctx: &HintContext) -> Vec<KeyHint> {
     match input_mode {
         InputMode::Normal => log_normal_hints(ctx),
-        InputMode::SquashSelect => vec![HINT_NAV, HINT_SQUASH_CONFIRM, HINT_CANCEL],
+        InputMode::SquashSelect => vec![
+            HINT_NAV,
+            HINT_SQUASH_CONFIRM,
+            HINT_SQUASH_FILES,
+            HINT_CANCEL,
+        ],
         InputMode::RebaseModeSelect => REBASE_MODE_SELECT_HINTS.to_vec(),
         InputMode::RebaseSelect => {
             rebase_select_hints(ctx.skip_emptied, ctx.simplify_parents, ctx.rebase_mode)
@@ -983,11 +1667,13 @@ fn log_hints(input_mode: InputMode, ctx: &HintContext) -> Vec<KeyHint> {
         InputMode::InterdiffSelect => INTERDIFF_SELECT_HINTS.to_vec(),
         InputMode::BisectSelect => BISECT_SELECT_HINTS.to_vec(),
         InputMode::ParallelizeSelect => PARALLELIZE_SELECT_HINTS.to_vec(),
+        InputMode::RevertSelect => REVERT_SELECT_HINTS.to_vec(),
+        InputMode::Reorder => REORDER_HINTS.to_vec(),
         InputMode::RebaseRevsetInput => vec![HINT_SUBMIT, HINT_CANCEL_ESC],
-        InputMode::SearchInput
-        | InputMode::RevsetInput
-        | InputMode::DescribeInput
-        | InputMode::BookmarkInput => vec![HINT_SUBMIT, HINT_CANCEL_ESC],
+        InputMode::DescribeInput => vec![HINT_EDITOR_SAVE, HINT_CANCEL_ESC],
+        InputMode::SearchInput | InputMode::RevsetInput | InputMode::BookmarkInput => {
+            vec![HINT_SUBMIT, HINT_CANCEL_ESC]
+        }
     }
 }
 
@@ -1013,6 +1699,9 @@ fn log_normal_hints(ctx: &HintContext) -> Vec<KeyHint> {
         h.push(HINT_PUSH);
     }
     h.extend([HINT_UNDO, HINT_QUIT]);
+    for hint in &mut h {
+        hint.key = preset_label(ctx.keymap, Some(View::Log), hint.key);
+    }
     h
 }
 
@@ -1020,6 +1709,7 @@ fn resolve_hints(ctx: &HintContext) -> Vec<KeyHint> {
     let mut h = Vec::new();
     if ctx.is_working_copy {
         h.push(HINT_RESOLVE_ENTER);
+        h.push(HINT_MERGE);
     }
     h.extend([HINT_OURS, HINT_THEIRS, HINT_DIFF, HINT_BACK]);
     h
@@ -1065,6 +1755,21 @@ fn tag_view_hints() -> Vec<KeyHint> {
     ]
 }
 
+fn stack_view_hints() -> Vec<KeyHint> {
+    vec![
+        HINT_NAV,
+        HINT_JUMP_ENTER,
+        KeyHint {
+            key: "K/J",
+            label: "Move up/down",
+            color: Color::Yellow,
+        },
+        HINT_UNDO,
+        HINT_REFRESH,
+        HINT_BACK,
+    ]
+}
+
 fn workspace_view_hints() -> Vec<KeyHint> {
     vec![
         HINT_NAV,
@@ -1085,10 +1790,115 @@ fn workspace_view_hints() -> Vec<KeyHint> {
     ]
 }
 
+fn remote_view_hints() -> Vec<KeyHint> {
+    vec![
+        HINT_NAV,
+        KeyHint {
+            key: "a",
+            label: "Add",
+            color: Color::Green,
+        },
+        KeyHint {
+            key: "D",
+            label: "Remove",
+            color: Color::Red,
+        },
+        HINT_RENAME,
+        KeyHint {
+            key: "e",
+            label: "URL",
+            color: Color::Yellow,
+        },
+        HINT_FETCH,
+        HINT_PUSH,
+        KeyHint {
+            key: "I/E",
+            label: "Import/Export",
+            color: Color::Cyan,
+        },
+        HINT_REFRESH,
+        HINT_BACK,
+    ]
+}
+
+fn sparse_view_hints() -> Vec<KeyHint> {
+    vec![
+        HINT_NAV,
+        KeyHint {
+            key: "a",
+            label: "Add",
+            color: Color::Green,
+        },
+        KeyHint {
+            key: "D",
+            label: "Remove",
+            color: Color::Red,
+        },
+        KeyHint {
+            key: "R",
+            label: "Full",
+            color: Color::Yellow,
+        },
+        KeyHint {
+            key: "Enter",
+            label: "Apply",
+            color: Color::Cyan,
+        },
+        HINT_REFRESH,
+        HINT_BACK,
+    ]
+}
+
 fn command_history_hints() -> Vec<KeyHint> {
-    vec![HINT_NAV, HINT_DETAIL, HINT_BACK]
+    vec![
+        HINT_NAV,
+        HINT_DETAIL,
+        HINT_COMMAND_LOG,
+        HINT_DIAGNOSTICS,
+        HINT_BACK,
+    ]
 }
 
+const HINT_DIAGNOSTICS: KeyHint = KeyHint {
+    key: "D",
+    label: "Diagnostics",
+    color: Color::Magenta,
+};
+
+const HINT_COMMAND_LOG: KeyHint = KeyHint {
+    key: "L",
+    label: "All jj cmds",
+    color: Color::Cyan,
+};
+
+/// Command log view status bar hints
+pub const COMMAND_LOG_VIEW_HINTS: &[KeyHint] = &[
+    HINT_NAV,
+    HINT_DETAIL,
+    KeyHint {
+        key: "y",
+        label: "Copy cmd",
+        color: Color::Green,
+    },
+    HINT_BACK,
+];
+
+/// Diagnostics view status bar hints
+pub const DIAGNOSTICS_VIEW_HINTS: &[KeyHint] = &[
+    KeyHint {
+        key: "j/k",
+        label: "Scroll",
+        color: Color::Cyan,
+    },
+    KeyHint {
+        key: "y",
+        label: "Copy report",
+        color: Color::Green,
+    },
+    HINT_REFRESH,
+    HINT_BACK,
+];
+
 /// ParallelizeSelect mode status bar hints
 pub const PARALLELIZE_SELECT_HINTS: &[KeyHint] = &[
     KeyHint {
@@ -1108,6 +1918,49 @@ pub const PARALLELIZE_SELECT_HINTS: &[KeyHint] = &[
     },
 ];
 
+/// RevertSelect mode status bar hints
+pub const REVERT_SELECT_HINTS: &[KeyHint] = &[
+    KeyHint {
+        key: "j/k",
+        label: "Navigate",
+        color: Color::Blue,
+    },
+    KeyHint {
+        key: "Enter",
+        label: "Revert range",
+        color: Color::Green,
+    },
+    KeyHint {
+        key: "Esc",
+        label: "Cancel",
+        color: Color::Red,
+    },
+];
+
+/// Reorder mode status bar hints
+pub const REORDER_HINTS: &[KeyHint] = &[
+    KeyHint {
+        key: "j/k",
+        label: "Navigate",
+        color: Color::Blue,
+    },
+    KeyHint {
+        key: "K",
+        label: "Move up",
+        color: Color::Yellow,
+    },
+    KeyHint {
+        key: "J",
+        label: "Move down",
+        color: Color::Yellow,
+    },
+    KeyHint {
+        key: "Esc/Enter",
+        label: "Done",
+        color: Color::Green,
+    },
+];
+
 /// CompareSelect mode status bar hints
 pub const COMPARE_SELECT_HINTS: &[KeyHint] = &[
     KeyHint {
@@ -1255,6 +2108,11 @@ fn rebase_select_hints(
             color: Color::Magenta,
         });
     }
+    hints.push(KeyHint {
+        key: "t",
+        label: "Type target",
+        color: Color::Cyan,
+    });
     hints.push(KeyHint {
         key: "Enter",
         label: "Rebase",
@@ -1389,6 +2247,10 @@ pub const STATUS_KEYS: &[KeyBindEntry] = &[
         key: "a",
         description: "Show file blame",
     },
+    KeyBindEntry {
+        key: "L",
+        description: "File history (jj log <path>)",
+    },
     KeyBindEntry {
         key: "C",
         description: "Commit changes",
@@ -1405,10 +2267,54 @@ pub const STATUS_KEYS: &[KeyBindEntry] = &[
         key: "R",
         description: "Restore all files",
     },
+    KeyBindEntry {
+        key: "x",
+        description: "Discard selected hunks/lines of file",
+    },
+    KeyBindEntry {
+        key: "m",
+        description: "Squash selected hunks/lines of file into @-",
+    },
+    KeyBindEntry {
+        key: "i",
+        description: "Add to .gitignore (path/extension/directory)",
+    },
+    KeyBindEntry {
+        key: "U",
+        description: "Show/hide untracked paths",
+    },
+    KeyBindEntry {
+        key: "I",
+        description: "Show/hide ignored paths",
+    },
     KeyBindEntry {
         key: "E",
         description: "Diffedit (external diff editor)",
     },
+    KeyBindEntry {
+        key: "D",
+        description: "Open file in difftool (tij.diff-tool)",
+    },
+    KeyBindEntry {
+        key: "!",
+        description: "Expand/collapse jj warnings",
+    },
+    KeyBindEntry {
+        key: "S",
+        description: "Sparse patterns (jj sparse)",
+    },
+    KeyBindEntry {
+        key: "p",
+        description: "Toggle file diff preview (compact layout: list ↔ preview)",
+    },
+    KeyBindEntry {
+        key: "\\",
+        description: "Preview at bottom/right",
+    },
+    KeyBindEntry {
+        key: "</>",
+        description: "Resize preview split",
+    },
     KeyBindEntry {
         key: "Tab",
         description: "Switch to log",
@@ -1431,7 +2337,51 @@ pub const OPERATION_KEYS: &[KeyBindEntry] = &[
     },
     KeyBindEntry {
         key: "Enter",
-        description: "Restore operation",
+        description: "Restore operation (previews impact first)",
+    },
+    KeyBindEntry {
+        key: "/",
+        description: "Filter (user: after: before: keyword)",
+    },
+    KeyBindEntry {
+        key: "f",
+        description: "Follow mode (live tail of new ops)",
+    },
+    KeyBindEntry {
+        key: "D",
+        description: "Divergent operations (op heads browser)",
+    },
+    KeyBindEntry {
+        key: "y",
+        description: "Yank menu (copy operation ID, description, command)",
+    },
+    KeyBindEntry {
+        key: "S",
+        description: "Toggle chronological / grouped by type",
+    },
+    KeyBindEntry {
+        key: "Space",
+        description: "Mark operation as range end for abandon",
+    },
+    KeyBindEntry {
+        key: "A",
+        description: "Abandon operation / older operations / marked range",
+    },
+    KeyBindEntry {
+        key: "C",
+        description: "Garbage-collect abandoned operations (jj util gc)",
+    },
+    KeyBindEntry {
+        key: "p",
+        description: "Toggle operation preview (jj op show)",
+    },
+    KeyBindEntry {
+        key: "\\",
+        description: "Preview at bottom/right",
+    },
+    KeyBindEntry {
+        key: "</>",
+        description: "Resize preview split",
     },
     KeyBindEntry {
         key: "q",
@@ -1439,6 +2389,30 @@ pub const OPERATION_KEYS: &[KeyBindEntry] = &[
     },
 ];
 
+/// Divergent Operations view key bindings for help display
+pub const OP_HEADS_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up within a side",
+    },
+    KeyBindEntry {
+        key: "h/l",
+        description: "Focus previous/next side",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Keep focused side (op restore to its head)",
+    },
+    KeyBindEntry {
+        key: "m",
+        description: "Keep reconciled (merged) state",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
 /// Bookmark view key bindings for help display
 pub const BOOKMARK_KEYS: &[KeyBindEntry] = &[
     KeyBindEntry {
@@ -1449,6 +2423,10 @@ pub const BOOKMARK_KEYS: &[KeyBindEntry] = &[
         key: "g/G",
         description: "Go to top/bottom",
     },
+    KeyBindEntry {
+        key: "unbound",
+        description: "Unbound letters: type-ahead jump to bookmark by name",
+    },
     KeyBindEntry {
         key: "Enter",
         description: "Jump to bookmark in log",
@@ -1477,6 +2455,30 @@ pub const BOOKMARK_KEYS: &[KeyBindEntry] = &[
         key: "m",
         description: "Move bookmark to @",
     },
+    KeyBindEntry {
+        key: "E",
+        description: "Re-place bookmarks left on rewritten commits",
+    },
+    KeyBindEntry {
+        key: "]/[",
+        description: "Show next/prev remote only (cycles back to all)",
+    },
+    KeyBindEntry {
+        key: "S",
+        description: "Sort by name / target date / ahead count",
+    },
+    KeyBindEntry {
+        key: "Q",
+        description: "Add/remove bookmark in push queue",
+    },
+    KeyBindEntry {
+        key: "Ctrl+p",
+        description: "Review and push the push queue",
+    },
+    KeyBindEntry {
+        key: "O",
+        description: "Open/copy/create pull request (gh/glab)",
+    },
     KeyBindEntry {
         key: "u",
         description: "Undo",
@@ -1551,19 +2553,23 @@ pub const WORKSPACE_KEYS: &[KeyBindEntry] = &[
     },
 ];
 
-/// Command history view key bindings for help display
-pub const COMMAND_HISTORY_KEYS: &[KeyBindEntry] = &[
+/// Remote view key bindings for help display
+pub const PAGER_KEYS: &[KeyBindEntry] = &[
     KeyBindEntry {
         key: "j/k",
-        description: "Move down/up",
+        description: "Scroll down/up",
+    },
+    KeyBindEntry {
+        key: "d/u",
+        description: "Half page down/up",
     },
     KeyBindEntry {
         key: "g/G",
         description: "Go to top/bottom",
     },
     KeyBindEntry {
-        key: "Enter",
-        description: "Toggle detail",
+        key: "Ctrl+L",
+        description: "Run the command again",
     },
     KeyBindEntry {
         key: "q",
@@ -1571,9 +2577,7 @@ pub const COMMAND_HISTORY_KEYS: &[KeyBindEntry] = &[
     },
 ];
 
-/// Blame view key bindings for help display
-#[allow(dead_code)] // planned for Help View integration
-pub const BLAME_KEYS: &[KeyBindEntry] = &[
+pub const SPARSE_KEYS: &[KeyBindEntry] = &[
     KeyBindEntry {
         key: "j/k",
         description: "Move down/up",
@@ -1582,13 +2586,25 @@ pub const BLAME_KEYS: &[KeyBindEntry] = &[
         key: "g/G",
         description: "Go to top/bottom",
     },
+    KeyBindEntry {
+        key: "a",
+        description: "Add pattern (path prefix)",
+    },
+    KeyBindEntry {
+        key: "D",
+        description: "Remove pattern / undo removal",
+    },
+    KeyBindEntry {
+        key: "R",
+        description: "Reset to full checkout",
+    },
     KeyBindEntry {
         key: "Enter",
-        description: "Show diff",
+        description: "Apply (preview file counts, then jj sparse set)",
     },
     KeyBindEntry {
-        key: "J",
-        description: "Jump to change in log",
+        key: "Ctrl+L",
+        description: "Reload patterns (drops pending edits)",
     },
     KeyBindEntry {
         key: "q",
@@ -1596,23 +2612,240 @@ pub const BLAME_KEYS: &[KeyBindEntry] = &[
     },
 ];
 
-/// Resolve view key bindings for help display
-#[allow(dead_code)] // planned for Help View integration
-pub const RESOLVE_KEYS: &[KeyBindEntry] = &[
+pub const REMOTE_KEYS: &[KeyBindEntry] = &[
     KeyBindEntry {
         key: "j/k",
         description: "Move down/up",
     },
     KeyBindEntry {
-        key: "Enter",
-        description: "Resolve (external tool, @ only)",
+        key: "g/G",
+        description: "Go to top/bottom",
     },
     KeyBindEntry {
-        key: "o",
-        description: "Resolve with :ours",
+        key: "a",
+        description: "Add remote (name and URL)",
     },
     KeyBindEntry {
-        key: "t",
+        key: "D",
+        description: "Remove remote",
+    },
+    KeyBindEntry {
+        key: "r",
+        description: "Rename remote",
+    },
+    KeyBindEntry {
+        key: "e",
+        description: "Set remote URL",
+    },
+    KeyBindEntry {
+        key: "F",
+        description: "Fetch from remote",
+    },
+    KeyBindEntry {
+        key: "P",
+        description: "Push tracked bookmarks to remote",
+    },
+    KeyBindEntry {
+        key: "I",
+        description: "jj git import (git refs into jj)",
+    },
+    KeyBindEntry {
+        key: "E",
+        description: "jj git export (jj bookmarks to git refs)",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back to log",
+    },
+];
+
+/// Stack view key bindings for help display
+pub const STACK_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to top/bottom",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Jump to change in log",
+    },
+    KeyBindEntry {
+        key: "K",
+        description: "Move commit up the stack (rebase --insert-after)",
+    },
+    KeyBindEntry {
+        key: "J",
+        description: "Move commit down the stack (rebase --insert-before)",
+    },
+    KeyBindEntry {
+        key: "u",
+        description: "Undo",
+    },
+    KeyBindEntry {
+        key: "Ctrl+l",
+        description: "Refresh",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back to log",
+    },
+];
+
+/// Command history view key bindings for help display
+pub const COMMAND_HISTORY_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to top/bottom",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Toggle detail",
+    },
+    KeyBindEntry {
+        key: "B",
+        description: "Bug report bundle (versions, commands, errors, config)",
+    },
+    KeyBindEntry {
+        key: "L",
+        description: "Command log (every jj process, with duration/exit)",
+    },
+    KeyBindEntry {
+        key: "D",
+        description: "Diagnostics (versions, editors, remotes, feature checks)",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
+/// Command log view key bindings for help display
+pub const COMMAND_LOG_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to newest/oldest",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Toggle detail",
+    },
+    KeyBindEntry {
+        key: "y",
+        description: "Copy command (shell-quoted) to clipboard",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
+pub const DIAGNOSTICS_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Scroll down/up",
+    },
+    KeyBindEntry {
+        key: "d/u",
+        description: "Half page down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to top/bottom",
+    },
+    KeyBindEntry {
+        key: "y",
+        description: "Copy the report as text",
+    },
+    KeyBindEntry {
+        key: "Ctrl+L",
+        description: "Collect again",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
+/// Blame view key bindings for help display
+pub const BLAME_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to top/bottom",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Show diff",
+    },
+    KeyBindEntry {
+        key: "J",
+        description: "Jump to change in log",
+    },
+    KeyBindEntry {
+        key: "L",
+        description: "File history",
+    },
+    KeyBindEntry {
+        key: "Ctrl+o",
+        description: "Open file at selected line on the forge",
+    },
+    KeyBindEntry {
+        key: ",",
+        description: "Re-blame at parent of line's change",
+    },
+    KeyBindEntry {
+        key: ".",
+        description: "Back to previous blame (breadcrumb)",
+    },
+    KeyBindEntry {
+        key: "h/l",
+        description: "Scroll long lines left/right",
+    },
+    KeyBindEntry {
+        key: "W",
+        description: "Wrap long lines / cut off (toggle)",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
+/// Resolve view key bindings for help display
+pub const RESOLVE_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Resolve (external tool, @ only)",
+    },
+    KeyBindEntry {
+        key: "m",
+        description: "Resolve in merge editor (@ only)",
+    },
+    KeyBindEntry {
+        key: "o",
+        description: "Resolve with :ours",
+    },
+    KeyBindEntry {
+        key: "t",
         description: "Resolve with :theirs",
     },
     KeyBindEntry {
@@ -1625,6 +2858,144 @@ pub const RESOLVE_KEYS: &[KeyBindEntry] = &[
     },
 ];
 
+/// Evolog view key bindings for help display
+pub const EVOLOG_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to top/bottom",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Show diff of this version",
+    },
+    KeyBindEntry {
+        key: "=",
+        description: "Compare two versions (Enter picks the second)",
+    },
+    KeyBindEntry {
+        key: "y",
+        description: "Yank menu",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
+/// File history view key bindings for help display
+pub const FILE_LOG_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Move down/up",
+    },
+    KeyBindEntry {
+        key: "g/G",
+        description: "Go to top/bottom",
+    },
+    KeyBindEntry {
+        key: "Enter",
+        description: "Show diff jumped to the file",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back",
+    },
+];
+
+/// Merge editor key bindings for help display
+pub const MERGE_KEYS: &[KeyBindEntry] = &[
+    KeyBindEntry {
+        key: "j/k",
+        description: "Next/previous region",
+    },
+    KeyBindEntry {
+        key: "n",
+        description: "Next unresolved region",
+    },
+    KeyBindEntry {
+        key: "1/2",
+        description: "Take side #1/#2",
+    },
+    KeyBindEntry {
+        key: "b",
+        description: "Take both sides",
+    },
+    KeyBindEntry {
+        key: "x",
+        description: "Clear the choice",
+    },
+    KeyBindEntry {
+        key: "e",
+        description: "Edit the region in $EDITOR",
+    },
+    KeyBindEntry {
+        key: "w",
+        description: "Write the result to the working copy",
+    },
+    KeyBindEntry {
+        key: "q",
+        description: "Back (discards choices)",
+    },
+];
+
+/// Help section title and key bindings of `view` (`None` for Help itself)
+///
+/// The Help overlay lists the section of the view it was opened from
+/// first, then the rest in [`HELP_VIEW_ORDER`].
+pub fn view_keymap(view: View) -> Option<(&'static str, &'static [KeyBindEntry])> {
+    Some(match view {
+        View::Log => ("Log View", LOG_KEYS),
+        View::Diff => ("Diff View", DIFF_KEYS),
+        View::Status => ("Status View", STATUS_KEYS),
+        View::Operation => ("Operation View", OPERATION_KEYS),
+        View::Blame => ("Blame View", BLAME_KEYS),
+        View::Resolve => ("Resolve View", RESOLVE_KEYS),
+        View::Bookmark => ("Bookmark View", BOOKMARK_KEYS),
+        View::Tag => ("Tag View", TAG_KEYS),
+        View::Workspace => ("Workspace View", WORKSPACE_KEYS),
+        View::Remote => ("Remote View", REMOTE_KEYS),
+        View::Sparse => ("Sparse View", SPARSE_KEYS),
+        View::Pager => ("Pager View", PAGER_KEYS),
+        View::Stack => ("Stack View", STACK_KEYS),
+        View::Evolog => ("Evolog View", EVOLOG_KEYS),
+        View::FileLog => ("File History View", FILE_LOG_KEYS),
+        View::Merge => ("Merge Editor", MERGE_KEYS),
+        View::OpHeads => ("Divergent Operations View", OP_HEADS_KEYS),
+        View::CommandHistory => ("Command History View", COMMAND_HISTORY_KEYS),
+        View::CommandLog => ("Command Log View", COMMAND_LOG_KEYS),
+        View::Diagnostics => ("Diagnostics View", DIAGNOSTICS_KEYS),
+        View::Help => return None,
+    })
+}
+
+/// Order of the view sections in Help
+pub const HELP_VIEW_ORDER: &[View] = &[
+    View::Log,
+    View::Diff,
+    View::Status,
+    View::Bookmark,
+    View::Tag,
+    View::Workspace,
+    View::Remote,
+    View::Pager,
+    View::Sparse,
+    View::Stack,
+    View::CommandHistory,
+    View::CommandLog,
+    View::Diagnostics,
+    View::Operation,
+    View::OpHeads,
+    View::Blame,
+    View::Evolog,
+    View::FileLog,
+    View::Resolve,
+    View::Merge,
+];
+
 /// Operation history view status bar hints
 pub const OPERATION_VIEW_HINTS: &[KeyHint] = &[
     KeyHint {
@@ -1637,6 +3008,22 @@ pub const OPERATION_VIEW_HINTS: &[KeyHint] = &[
         label: "Restore",
         color: Color::Green,
     },
+    KeyHint {
+        key: "/",
+        label: "Filter",
+        color: Color::Yellow,
+    },
+    KeyHint {
+        key: "f",
+        label: "Follow",
+        color: Color::Green,
+    },
+    KeyHint {
+        key: "D",
+        label: "Divergence",
+        color: Color::Yellow,
+    },
+    HINT_PREVIEW,
     KeyHint {
         key: "^L",
         label: "Refresh",
@@ -1649,6 +3036,95 @@ pub const OPERATION_VIEW_HINTS: &[KeyHint] = &[
     },
 ];
 
+/// Divergent Operations status bar hints
+pub const OP_HEADS_VIEW_HINTS: &[KeyHint] = &[
+    KeyHint {
+        key: "j/k",
+        label: "Move",
+        color: Color::Cyan,
+    },
+    KeyHint {
+        key: "h/l",
+        label: "Side",
+        color: Color::Cyan,
+    },
+    KeyHint {
+        key: "Enter",
+        label: "Keep side",
+        color: Color::Green,
+    },
+    KeyHint {
+        key: "m",
+        label: "Keep merged",
+        color: Color::Green,
+    },
+    KeyHint {
+        key: "q",
+        label: "Back",
+        color: Color::Red,
+    },
+];
+
+/// Pager View status bar hints
+pub const PAGER_VIEW_HINTS: &[KeyHint] = &[
+    KeyHint {
+        key: "j/k",
+        label: "Scroll",
+        color: Color::Cyan,
+    },
+    KeyHint {
+        key: "d/u",
+        label: "Page",
+        color: Color::Cyan,
+    },
+    HINT_REFRESH,
+    HINT_BACK,
+];
+
+/// Merge editor status bar hints
+pub const MERGE_VIEW_HINTS: &[KeyHint] = &[
+    KeyHint {
+        key: "j/k",
+        label: "Region",
+        color: Color::Cyan,
+    },
+    KeyHint {
+        key: "1",
+        label: "Side #1",
+        color: Color::Green,
+    },
+    KeyHint {
+        key: "2",
+        label: "Side #2",
+        color: Color::Blue,
+    },
+    KeyHint {
+        key: "b",
+        label: "Both",
+        color: Color::Yellow,
+    },
+    KeyHint {
+        key: "e",
+        label: "Edit",
+        color: Color::Magenta,
+    },
+    KeyHint {
+        key: "n",
+        label: "Next Unresolved",
+        color: Color::Cyan,
+    },
+    KeyHint {
+        key: "w",
+        label: "Write",
+        color: Color::Green,
+    },
+    KeyHint {
+        key: "q",
+        label: "Back",
+        color: Color::Red,
+    },
+];
+
 /// Blame view status bar hints
 pub const BLAME_VIEW_HINTS: &[KeyHint] = &[
     KeyHint {
@@ -1666,6 +3142,16 @@ pub const BLAME_VIEW_HINTS: &[KeyHint] = &[
         label: "Log Jump",
         color: Color::Yellow,
     },
+    KeyHint {
+        key: "L",
+        label: "History",
+        color: Color::Magenta,
+    },
+    KeyHint {
+        key: ",/.",
+        label: "Parent/Back",
+        color: Color::Cyan,
+    },
     KeyHint {
         key: "^L",
         label: "Refresh",
@@ -1752,9 +3238,10 @@ mod tests {
     fn log_squash_select_hints() {
         let ctx = HintContext::default();
         let hints = current_hints(View::Log, InputMode::SquashSelect, &ctx);
-        assert_eq!(hints.len(), 3);
+        assert_eq!(hints.len(), 4);
         assert!(hints.iter().any(|h| h.label == "Navigate"));
         assert!(hints.iter().any(|h| h.label == "Squash"));
+        assert!(hints.iter().any(|h| h.label == "Pick files"));
         assert!(hints.iter().any(|h| h.label == "Cancel"));
     }
 
@@ -1899,6 +3386,10 @@ mod tests {
             !hints.iter().any(|h| h.key == "Enter"),
             "Enter hint should not appear for non-working-copy"
         );
+        assert!(
+            !hints.iter().any(|h| h.key == "m"),
+            "Merge hint should not appear for non-working-copy"
+        );
     }
 
     #[test]
@@ -1926,4 +3417,133 @@ mod tests {
         let hints = current_hints(View::Help, InputMode::Normal, &ctx);
         assert!(hints.is_empty());
     }
+
+    // --- Keymap presets ---
+
+    #[test]
+    fn vim_preset_keeps_keys_and_labels() {
+        let preset = KeymapPreset::Vim;
+        assert_eq!(
+            translate_key(preset, View::Log, KeyCode::Home),
+            KeyCode::Home
+        );
+        assert_eq!(translate_key(preset, View::Log, DESCRIBE), DESCRIBE);
+        assert_eq!(preset_label(preset, Some(View::Log), "d"), "d");
+    }
+
+    #[test]
+    fn arrows_preset_maps_to_vim_keys() {
+        let preset = KeymapPreset::Arrows;
+        assert_eq!(translate_key(preset, View::Status, KeyCode::End), GO_BOTTOM);
+        assert_eq!(
+            translate_key(preset, View::Diff, KeyCode::PageDown),
+            HALF_PAGE_DOWN
+        );
+        // PgUp has no half-page meaning in the Log View
+        assert_eq!(
+            translate_key(preset, View::Log, KeyCode::PageUp),
+            KeyCode::PageUp
+        );
+        assert_eq!(
+            translate_key(preset, View::Log, KeyCode::Char('d')),
+            OPEN_DIFF
+        );
+        assert_eq!(translate_key(preset, View::Log, KeyCode::Char('p')), PUSH);
+        assert_eq!(
+            translate_key(preset, View::Log, KeyCode::Char('l')),
+            DESCRIBE
+        );
+        // Diff View keeps d as half page down
+        assert_eq!(
+            translate_key(preset, View::Diff, KeyCode::Char('d')),
+            HALF_PAGE_DOWN
+        );
+    }
+
+    #[test]
+    fn arrows_preset_labels_follow_translation() {
+        let preset = KeymapPreset::Arrows;
+        assert_eq!(preset_label(preset, Some(View::Log), "d"), "l");
+        assert_eq!(preset_label(preset, Some(View::Log), "P"), "p");
+        assert_eq!(preset_label(preset, Some(View::Log), "Enter"), "d/Enter");
+        // Outside the Log View the letters keep their meaning
+        assert_eq!(preset_label(preset, Some(View::Status), "P"), "P");
+        assert_eq!(preset_label(preset, None, "g/G"), "Home/End");
+        assert_eq!(preset_label(preset, Some(View::Pager), "d/u"), "PgDn/PgUp");
+    }
+
+    #[test]
+    fn arrows_preset_relabels_log_hints() {
+        let ctx = HintContext {
+            keymap: KeymapPreset::Arrows,
+            ..HintContext::default()
+        };
+        let fetch_key = |ctx: &HintContext| {
+            current_hints(View::Log, InputMode::Normal, ctx)
+                .into_iter()
+                .find(|h| h.label == "Fetch")
+                .map(|h| h.key)
+        };
+        assert_eq!(fetch_key(&ctx), Some("f"));
+        assert_eq!(fetch_key(&HintContext::default()), Some("F"));
+    }
+
+    #[test]
+    fn keymap_preset_parse() {
+        assert_eq!(KeymapPreset::parse("arrows"), Ok(KeymapPreset::Arrows));
+        assert_eq!(KeymapPreset::parse("vim"), Ok(KeymapPreset::Vim));
+        assert!(KeymapPreset::parse("emacs").is_err());
+    }
+
+    #[test]
+    fn key_remap_parse() {
+        assert_eq!(
+            KeyRemap::parse("x", "q"),
+            Ok(KeyRemap {
+                view: None,
+                key: KeyCode::Char('x'),
+                target: QUIT,
+            })
+        );
+        let remap = KeyRemap::parse("file-history.\"?\"", "enter").unwrap();
+        assert_eq!(remap.view, Some(View::FileLog));
+        assert_eq!(remap.key, KeyCode::Char('?'));
+        assert_eq!(remap.target, KeyCode::Enter);
+        assert_eq!(KeyRemap::parse(".", "j").unwrap().key, KeyCode::Char('.'));
+        assert!(KeyRemap::parse("lgo.x", "d").is_err());
+        assert!(KeyRemap::parse("x", "Ctrl+d").is_err());
+    }
+
+    #[test]
+    fn view_remap_wins_over_global_remap_and_preset() {
+        let remaps = [
+            KeyRemap::parse("x", "q").unwrap(),
+            KeyRemap::parse("log.x", "Enter").unwrap(),
+            KeyRemap::parse("log.d", "d").unwrap(),
+        ];
+        let preset = KeymapPreset::Arrows;
+        let x = KeyCode::Char('x');
+        assert_eq!(resolve_key(preset, &remaps, View::Log, x), OPEN_DIFF);
+        assert_eq!(resolve_key(preset, &remaps, View::Status, x), QUIT);
+        // The remap keeps d as describe despite the arrows preset
+        assert_eq!(resolve_key(preset, &remaps, View::Log, DESCRIBE), DESCRIBE);
+        assert_eq!(
+            resolve_key(preset, &remaps, View::Log, KeyCode::End),
+            GO_BOTTOM
+        );
+        assert_eq!(key_name(KeyCode::Char(' ')), "Space");
+        assert_eq!(parse_key_name("Spc"), Some(KeyCode::Char(' ')));
+        assert_eq!(key_name(KeyCode::PageDown), "PgDn");
+    }
+
+    // --- Help keymap registry ---
+
+    #[test]
+    fn every_view_in_help_order_has_a_keymap() {
+        for view in HELP_VIEW_ORDER {
+            let (_, entries) = view_keymap(*view).expect("keymap");
+            assert!(!entries.is_empty(), "{:?} has no key bindings", view);
+        }
+        assert!(view_keymap(View::Help).is_none());
+    }
 }
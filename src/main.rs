@@ -2,68 +2,90 @@
 //!
 //! Binary entry point for the TUI application.
 
+use std::path::Path;
+use std::process::ExitStatus;
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::event;
 use ratatui::DefaultTerminal;
 
-use tij::app::App;
+use tij::app::{App, AppEvent, COPY_FILE_ARG};
+use tij::jj::{CloneJob, JjExecutor, MIN_JJ_VERSION, parse_jj_version};
+use tij::ui::widgets::CloneProgress;
 
 fn main() -> color_eyre::Result<()> {
     // Handle --version / --help before any TUI/terminal init so they behave
     // like normal CLI commands (and don't drop the user into the alt screen).
-    if let Some(arg) = std::env::args().nth(1) {
-        match arg.as_str() {
-            "-V" | "--version" => {
-                println!("tij {}", env!("CARGO_PKG_VERSION"));
-                return Ok(());
-            }
-            "-h" | "--help" => {
-                print_help();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match args.first().map(String::as_str) {
+        None => None,
+        Some("-V" | "--version") => {
+            println!("tij {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        Some("-h" | "--help") => {
+            print_help();
+            return Ok(());
+        }
+        Some(sub @ ("clone" | "init")) => Some((sub, &args[1..])),
+        // Diff editor of a line squash (no TUI, no jj check)
+        Some(COPY_FILE_ARG) => match &args[1..] {
+            [from, to] => {
+                std::fs::copy(from, to)?;
                 return Ok(());
             }
-            _ => {
-                eprintln!("tij: unknown argument: {arg}");
-                eprintln!("Try `tij --help` for a list of options.");
-                std::process::exit(2);
-            }
-        }
-    }
+            _ => usage_error("usage: tij --copy-file <from> <to>"),
+        },
+        Some(arg) => usage_error(&format!("unknown argument: {arg}")),
+    };
 
     color_eyre::install()?;
 
     // jj version check (before TUI init so errors print to normal terminal)
     check_jj_version()?;
 
+    match command {
+        Some(("clone", [url])) => run_clone(url, None)?,
+        Some(("clone", [url, dir])) => run_clone(url, Some(dir))?,
+        Some(("clone", _)) => usage_error("usage: tij clone <url> [dir]"),
+        Some(("init", [])) => run_init(".")?,
+        Some(("init", [dir])) => run_init(dir)?,
+        Some(_) => usage_error("usage: tij init [dir]"),
+        None => {}
+    }
+
     let terminal = ratatui::init();
     let result = run(terminal);
     ratatui::restore();
     result
 }
 
+/// Report a command-line mistake and exit with status 2
+fn usage_error(message: &str) -> ! {
+    eprintln!("tij: {message}");
+    eprintln!("Try `tij --help` for a list of options.");
+    std::process::exit(2);
+}
+
 /// Print the CLI help message.
 fn print_help() {
     println!(
         "tij {} — Text-mode interface for Jujutsu (jj) version control\n\
          \n\
          USAGE:\n    \
-             tij             Launch the TUI in the current jj repository\n\
+             tij                     Launch the TUI in the current jj repository\n    \
+             tij clone <url> [dir]   Clone with `jj git clone`, then open the TUI in it\n    \
+             tij init [dir]          Create a repo with `jj git init`, then open the TUI\n\
          \n\
          OPTIONS:\n    \
-             -V, --version   Print version and exit\n    \
-             -h, --help      Print this help and exit\n\
+             -V, --version           Print version and exit\n    \
+             -h, --help              Print this help and exit\n\
          \n\
          Once running, press `?` for keybindings.",
         env!("CARGO_PKG_VERSION")
     );
 }
 
-/// Minimum required jj version (major, minor)
-///
-/// Bumped to 0.41 because read-only invocations rely on `--no-integrate-operation`
-/// (introduced in jj 0.41) to avoid polluting the operation log with snapshot ops.
-const MIN_JJ_VERSION: (u32, u32) = (0, 41);
-
 /// Check that jj is installed and meets the minimum version requirement.
 fn check_jj_version() -> color_eyre::Result<()> {
     use color_eyre::eyre::eyre;
@@ -106,123 +128,126 @@ fn check_jj_version() -> color_eyre::Result<()> {
     Ok(())
 }
 
-/// Parse jj version string into (major, minor).
+/// `tij clone`: clone with a progress screen, then enter the new repo
 ///
-/// Handles formats like "jj 0.40.0", "jj 0.40.0-rc1", "jj 1.0.0.dev1234".
-fn parse_jj_version(output: &str) -> Option<(u32, u32)> {
-    let version_str = output.trim().strip_prefix("jj ")?;
-    let parts: Vec<&str> = version_str.split('.').collect();
-    if parts.len() >= 2 {
-        let major = parse_leading_digits(parts[0])?;
-        let minor = parse_leading_digits(parts[1])?;
-        Some((major, minor))
-    } else {
-        None
+/// A failure that needs credentials is retried on the plain terminal so
+/// that git/ssh can prompt. Exits on failure or cancel.
+fn run_clone(url: &str, dir: Option<&str>) -> color_eyre::Result<()> {
+    use color_eyre::eyre::eyre;
+    use tij::jj::clone_destination;
+
+    let destination = match dir {
+        Some(dir) => dir.to_string(),
+        None => clone_destination(url).ok_or_else(|| {
+            eyre!("Cannot name a directory after {url}; run `tij clone <url> <dir>`")
+        })?,
+    };
+    let destination = Path::new(&destination);
+    let mut job = CloneJob::spawn(url, destination)?;
+    let mut progress = CloneProgress::new(url, destination.display().to_string());
+
+    let status = {
+        let mut terminal = ratatui::init();
+        // Restore the terminal on every way out, errors included
+        let _restore = scopeguard::guard((), |_| ratatui::restore());
+        let result = watch_clone(&mut terminal, &mut job, &mut progress);
+        // Stop jj unless it exited by itself (cancelled or failed to watch)
+        if !matches!(result, Ok(Some(_))) {
+            job.cancel();
+        }
+        result?
+    };
+    job.read_remaining_lines();
+
+    match status {
+        Some(status) if status.success() => {}
+        None => {
+            eprintln!(
+                "tij: clone cancelled; {} may be left behind",
+                destination.display()
+            );
+            std::process::exit(130);
+        }
+        Some(_) if job.needs_credentials() => {
+            println!("tij: `jj git clone` needs your credentials; answer the prompt below.");
+            if !job.interactive_command().status()?.success() {
+                std::process::exit(1);
+            }
+        }
+        Some(status) => {
+            for line in job.transcript() {
+                eprintln!("{line}");
+            }
+            eprintln!("tij: jj git clone failed ({status})");
+            std::process::exit(status.code().unwrap_or(1));
+        }
     }
+    std::env::set_current_dir(destination)?;
+    Ok(())
 }
 
-/// Extract leading digits from a string (e.g., "40" -> 40, "40-rc1" -> 40).
-fn parse_leading_digits(s: &str) -> Option<u32> {
-    let digits: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
-    if digits.is_empty() {
-        None
-    } else {
-        digits.parse().ok()
+/// Show clone progress until jj exits (`Some`) or the user cancels (`None`)
+fn watch_clone(
+    terminal: &mut DefaultTerminal,
+    job: &mut CloneJob,
+    progress: &mut CloneProgress,
+) -> color_eyre::Result<Option<ExitStatus>> {
+    use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+    use tij::ui::widgets::render_clone_progress;
+
+    loop {
+        for line in job.read_lines() {
+            progress.push_line(line);
+        }
+        terminal.draw(|frame| render_clone_progress(frame, progress))?;
+        if let Some(status) = job.try_wait()? {
+            return Ok(Some(status));
+        }
+        if event::poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+            && (key.code == KeyCode::Esc
+                || (key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(KeyModifiers::CONTROL)))
+        {
+            return Ok(None);
+        }
     }
 }
 
+/// `tij init`: run `jj git init` in the foreground, then enter the repo
+fn run_init(dir: &str) -> color_eyre::Result<()> {
+    let status = JjExecutor::new().git_init_interactive(dir)?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    std::env::set_current_dir(dir)?;
+    Ok(())
+}
+
 /// Run the application's main loop.
 fn run(mut terminal: DefaultTerminal) -> color_eyre::Result<()> {
     let mut app = App::new();
 
     while app.running {
         terminal.draw(|frame| app.render(frame))?;
-        handle_events(&mut app)?;
+        if let Some(event) = next_event()? {
+            app.handle_event(event);
+        }
     }
 
     Ok(())
 }
 
-/// Handle crossterm events.
+/// Wait for the next terminal event.
 ///
-/// Uses poll with 200ms timeout to support idle processing (e.g., debounced preview fetch).
-/// When no key event arrives within the timeout, pending preview fetches are resolved.
-fn handle_events(app: &mut App) -> color_eyre::Result<()> {
+/// Uses poll with 200ms timeout to support idle processing (e.g., debounced preview fetch):
+/// when no terminal event arrives within the timeout, an [`AppEvent::Tick`] is returned.
+/// Terminal events tij doesn't handle yield `None`.
+fn next_event() -> color_eyre::Result<Option<AppEvent>> {
     if event::poll(Duration::from_millis(200))? {
-        match event::read()? {
-            Event::Key(key) if key.kind == KeyEventKind::Press => {
-                app.on_key_event(key);
-            }
-            _ => {}
-        }
+        Ok(AppEvent::from_terminal(event::read()?))
     } else {
-        // Idle: resolve any pending preview fetch
-        app.resolve_pending_preview();
-    }
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_jj_version_normal() {
-        assert_eq!(parse_jj_version("jj 0.40.0"), Some((0, 40)));
-    }
-
-    #[test]
-    fn test_parse_jj_version_older() {
-        assert_eq!(parse_jj_version("jj 0.39.1"), Some((0, 39)));
-    }
-
-    #[test]
-    fn test_parse_jj_version_major() {
-        assert_eq!(parse_jj_version("jj 1.0.0"), Some((1, 0)));
-    }
-
-    #[test]
-    fn test_parse_jj_version_rc_suffix() {
-        assert_eq!(parse_jj_version("jj 0.40.0-rc1"), Some((0, 40)));
-    }
-
-    #[test]
-    fn test_parse_jj_version_dev_suffix() {
-        assert_eq!(parse_jj_version("jj 0.40.0.dev1234"), Some((0, 40)));
-    }
-
-    #[test]
-    fn test_parse_jj_version_with_trailing_newline() {
-        assert_eq!(parse_jj_version("jj 0.40.0\n"), Some((0, 40)));
-    }
-
-    #[test]
-    fn test_parse_jj_version_invalid() {
-        assert_eq!(parse_jj_version("invalid"), None);
-    }
-
-    #[test]
-    fn test_parse_jj_version_empty() {
-        assert_eq!(parse_jj_version(""), None);
-    }
-
-    #[test]
-    fn test_parse_leading_digits_normal() {
-        assert_eq!(parse_leading_digits("40"), Some(40));
-    }
-
-    #[test]
-    fn test_parse_leading_digits_with_suffix() {
-        assert_eq!(parse_leading_digits("40-rc1"), Some(40));
-    }
-
-    #[test]
-    fn test_parse_leading_digits_empty() {
-        assert_eq!(parse_leading_digits(""), None);
-    }
-
-    #[test]
-    fn test_parse_leading_digits_no_digits() {
-        assert_eq!(parse_leading_digits("abc"), None);
+        Ok(Some(AppEvent::Tick))
     }
 }
@@ -1,6 +1,7 @@
 //! Annotation (blame) data model
 
 use super::id::{ChangeId, CommitId};
+use super::timestamp::{TimestampFormat, format_timestamp, timestamp_to_epoch};
 
 /// Blame information for a single line
 #[derive(Debug, Clone)]
@@ -11,7 +12,7 @@ pub struct AnnotationLine {
     pub commit_id: CommitId,
     /// Author name
     pub author: String,
-    /// Timestamp (YYYY-MM-DD HH:MM format)
+    /// Timestamp as jj printed it (`YYYY-MM-DDTHH:MM:SS+ZZZZ`, local time)
     pub timestamp: String,
     /// 1-based line number
     pub line_number: usize,
@@ -48,20 +49,40 @@ impl AnnotationContent {
     pub fn len(&self) -> usize {
         self.lines.len()
     }
+
+    /// Widest displayed timestamp (the Blame View's timestamp column)
+    pub fn timestamp_width(&self, format: &TimestampFormat) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.first_in_hunk)
+            .map(|line| line.display_timestamp(format).chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Oldest and newest line timestamps (seconds since epoch)
+    ///
+    /// None when no line has a parsable timestamp.
+    pub fn time_range(&self) -> Option<(i64, i64)> {
+        self.lines
+            .iter()
+            .filter_map(AnnotationLine::epoch_seconds)
+            .fold(None, |range, t| match range {
+                None => Some((t, t)),
+                Some((oldest, newest)) => Some((oldest.min(t), newest.max(t))),
+            })
+    }
 }
 
 impl AnnotationLine {
-    /// Get short timestamp for display (MM-DD or YY-MM-DD)
-    pub fn short_timestamp(&self) -> String {
-        // Input format: "YYYY-MM-DD HH:MM"
-        if self.timestamp.len() >= 10 {
-            let date_part = &self.timestamp[..10]; // "YYYY-MM-DD"
-            // For simplicity, always show MM-DD (could compare with current year)
-            if date_part.len() >= 10 {
-                return date_part[5..10].to_string(); // "MM-DD"
-            }
-        }
-        self.timestamp.clone()
+    /// Timestamp for display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub fn display_timestamp(&self, format: &TimestampFormat) -> String {
+        format_timestamp(&self.timestamp, format)
+    }
+
+    /// Timestamp as seconds since epoch, for age comparisons
+    pub fn epoch_seconds(&self) -> Option<i64> {
+        timestamp_to_epoch(&self.timestamp)
     }
 
     /// Get truncated author name for display
@@ -86,17 +107,26 @@ mod tests {
     }
 
     #[test]
-    fn test_annotation_line_short_timestamp() {
+    fn test_annotation_line_display_timestamp() {
         let line = AnnotationLine {
             change_id: ChangeId::new("twzksoxt".to_string()),
             commit_id: CommitId::new("abcd1234".to_string()),
             author: "nakamura".to_string(),
-            timestamp: "2026-01-30 10:43".to_string(),
+            timestamp: "2026-01-30T10:43:19+0900".to_string(),
             line_number: 1,
             content: "test".to_string(),
             first_in_hunk: true,
         };
-        assert_eq!(line.short_timestamp(), "01-30");
+        let format = TimestampFormat::default();
+        assert_eq!(line.display_timestamp(&format), "2026-01-30 10:43:19");
+        assert_ne!(line.display_timestamp(&format), line.timestamp);
+
+        let mut content = AnnotationContent::new("a.rs".to_string());
+        content.lines.push(line);
+        assert_eq!(
+            content.timestamp_width(&format),
+            content.lines[0].display_timestamp(&format).chars().count()
+        );
     }
 
     #[test]
@@ -113,4 +143,24 @@ mod tests {
         assert_eq!(line.short_author(8), "nakamur…");
         assert_eq!(line.short_author(20), "nakamura.shuta");
     }
+
+    #[test]
+    fn test_annotation_content_time_range() {
+        let mut content = AnnotationContent::new("src/main.rs".to_string());
+        assert_eq!(content.time_range(), None);
+        for timestamp in ["2026-01-30 10:43:00", "not a date", "2025-12-01 08:00:00"] {
+            content.lines.push(AnnotationLine {
+                change_id: ChangeId::new("twzksoxt".to_string()),
+                commit_id: CommitId::new("abcd1234".to_string()),
+                author: "nakamura".to_string(),
+                timestamp: timestamp.to_string(),
+                line_number: 1,
+                content: "test".to_string(),
+                first_in_hunk: true,
+            });
+        }
+        let (oldest, newest) = content.time_range().unwrap();
+        assert_eq!(Some(oldest), content.lines[2].epoch_seconds());
+        assert_eq!(Some(newest), content.lines[0].epoch_seconds());
+    }
 }
@@ -1,6 +1,6 @@
 //! Bookmark model for `jj bookmark list --all`
 
-use super::id::{ChangeId, CommitId};
+use super::id::{ChangeId, CommitId, short_id};
 
 /// Bookmark information from `jj bookmark list --all`
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,6 +11,78 @@ pub struct Bookmark {
     pub remote: Option<String>,
     /// Whether this is tracked locally
     pub is_tracked: bool,
+    /// Ahead/behind counts against the tracked remote
+    ///
+    /// Set on tracked remote bookmarks and copied onto their local
+    /// counterpart. None for untracked remotes and local-only bookmarks.
+    pub tracking: Option<TrackingCounts>,
+}
+
+/// Commit counts between a local bookmark and its tracked remote
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrackingCounts {
+    /// Local commits not on the remote (need pushing)
+    pub ahead: usize,
+    /// Remote commits not in the local bookmark (remote moved)
+    pub behind: usize,
+}
+
+impl TrackingCounts {
+    /// Whether local and remote point to the same history
+    pub fn is_synced(&self) -> bool {
+        self.ahead == 0 && self.behind == 0
+    }
+
+    /// Compact indicator, e.g. "↑2 ↓5" (empty when in sync)
+    pub fn indicator(&self) -> String {
+        let mut parts = Vec::new();
+        if self.ahead > 0 {
+            parts.push(format!("↑{}", self.ahead));
+        }
+        if self.behind > 0 {
+            parts.push(format!("↓{}", self.behind));
+        }
+        parts.join(" ")
+    }
+
+    /// Column form used by Bookmark View, e.g. "+2/-5"
+    pub fn column(&self) -> String {
+        format!("+{}/-{}", self.ahead, self.behind)
+    }
+}
+
+/// What deleting a local bookmark would affect
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkDeleteImpact {
+    /// Local bookmark name
+    pub name: String,
+    /// Remotes with a tracked counterpart (deletion propagates on push)
+    pub tracked_remotes: Vec<String>,
+    /// Unmerged commits no other bookmark, tag or working copy points to
+    pub orphaned_commits: usize,
+}
+
+impl BookmarkDeleteImpact {
+    /// One-line summary for the delete dialog, e.g.
+    /// "feature: tracked @origin, 3 commits lose their name"
+    pub fn summary(&self) -> String {
+        let remotes = if self.tracked_remotes.is_empty() {
+            "local only".to_string()
+        } else {
+            let names: Vec<String> = self
+                .tracked_remotes
+                .iter()
+                .map(|r| format!("@{}", r))
+                .collect();
+            format!("tracked {}", names.join(" "))
+        };
+        let commits = match self.orphaned_commits {
+            0 => "no commits lose their name".to_string(),
+            1 => "1 commit loses its name".to_string(),
+            n => format!("{} commits lose their name", n),
+        };
+        format!("{}: {}, {}", self.name, remotes, commits)
+    }
 }
 
 impl Bookmark {
@@ -43,6 +115,8 @@ pub struct BookmarkInfo {
     pub commit_id: Option<CommitId>,
     /// Commit description (first line)
     pub description: Option<String>,
+    /// Committer timestamp of the target (`%Y-%m-%d %H:%M:%S`, local bookmarks only)
+    pub timestamp: Option<String>,
 }
 
 impl BookmarkInfo {
@@ -70,16 +144,313 @@ impl BookmarkInfo {
     }
 }
 
+/// Where a bookmark pointed in one operation (to compare a fetch's before and after)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkTarget {
+    pub name: String,
+    /// Remote name, `None` for a local bookmark
+    pub remote: Option<String>,
+    pub is_tracked: bool,
+    /// Full commit ID, `None` while the bookmark is conflicted
+    pub commit_id: Option<String>,
+}
+
+/// A tracked remote bookmark that a fetch deleted while an untracked one
+/// appeared on the same remote at the same commit: most likely renamed on
+/// the forge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkRename {
+    pub remote: String,
+    pub old_name: String,
+    pub new_name: String,
+    /// Target of `old@remote` before the fetch
+    pub old_commit: String,
+    /// Target of `new@remote`
+    pub new_commit: String,
+    /// Target of the local `old` bookmark, if it survived the fetch
+    pub local_commit: Option<String>,
+}
+
+impl BookmarkRename {
+    /// `old@remote → new@remote`
+    pub fn label(&self) -> String {
+        format!(
+            "{}@{} → {}@{}",
+            self.old_name, self.remote, self.new_name, self.remote
+        )
+    }
+
+    /// Pair every deleted tracked remote bookmark with every new untracked
+    /// one on the same remote and commit
+    ///
+    /// A new bookmark on a descendant is not a rename: a merged and deleted
+    /// branch and a colleague's new branch off it would look like one.
+    pub fn candidates(before: &[BookmarkTarget], after: &[BookmarkTarget]) -> Vec<BookmarkRename> {
+        let has_remote = |targets: &[BookmarkTarget], name: &str, remote: &str| {
+            targets
+                .iter()
+                .any(|t| t.name == name && t.remote.as_deref() == Some(remote))
+        };
+        let mut renames = Vec::new();
+        for old in before.iter().filter(|t| t.is_tracked) {
+            let (Some(remote), Some(old_commit)) = (&old.remote, &old.commit_id) else {
+                continue;
+            };
+            if has_remote(after, &old.name, remote) {
+                continue;
+            }
+            let local_commit = after
+                .iter()
+                .find(|t| t.remote.is_none() && t.name == old.name)
+                .and_then(|t| t.commit_id.clone());
+            for new in after.iter().filter(|t| !t.is_tracked) {
+                let Some(new_commit) = &new.commit_id else {
+                    continue;
+                };
+                // A local `new` already exists: nothing to retarget it to
+                let has_local = after
+                    .iter()
+                    .any(|t| t.remote.is_none() && t.name == new.name);
+                if new.remote.as_ref() != Some(remote)
+                    || new_commit != old_commit
+                    || has_remote(before, &new.name, remote)
+                    || has_local
+                {
+                    continue;
+                }
+                renames.push(BookmarkRename {
+                    remote: remote.clone(),
+                    old_name: old.name.clone(),
+                    new_name: new.name.clone(),
+                    old_commit: old_commit.clone(),
+                    new_commit: new_commit.clone(),
+                    local_commit: local_commit.clone(),
+                });
+            }
+        }
+        renames
+    }
+
+    /// Keep the pairs whose old and new bookmark appear in no other pair
+    pub fn unambiguous(renames: Vec<BookmarkRename>) -> Vec<BookmarkRename> {
+        let count = |f: &dyn Fn(&BookmarkRename) -> bool| renames.iter().filter(|r| f(r)).count();
+        renames
+            .iter()
+            .filter(|r| {
+                count(&|o| o.remote == r.remote && o.old_name == r.old_name) == 1
+                    && count(&|o| o.remote == r.remote && o.new_name == r.new_name) == 1
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// A local bookmark left on a commit that has since been rewritten
+///
+/// jj moves bookmarks along with a rewrite, but an undo, a concurrent
+/// operation or a git import can leave one on the old version, which then
+/// stays visible as a divergent copy of its change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkRetarget {
+    pub name: String,
+    /// Full commit ID the bookmark points at
+    pub commit: String,
+    /// Full commit ID of the latest visible rewrite of `commit`
+    pub successor: String,
+}
+
+impl BookmarkRetarget {
+    /// `name: old → new` with commit IDs cut to `id_length`
+    pub fn label(&self, id_length: usize) -> String {
+        format!(
+            "{}: {} → {}",
+            self.name,
+            short_id(&self.commit, id_length),
+            short_id(&self.successor, id_length)
+        )
+    }
+
+    /// The latest rewrite of `commit` among `candidates`
+    ///
+    /// Each candidate is a commit ID with the (possibly short) commit IDs
+    /// of its evolution log. Successors are the candidates that evolved
+    /// from `commit`; the latest is the one no other successor evolved
+    /// from. `None` unless exactly one is found.
+    pub fn latest_successor(commit: &str, candidates: &[(String, Vec<String>)]) -> Option<String> {
+        let evolved_from = |history: &[String], id: &str| {
+            history
+                .iter()
+                .any(|entry| !entry.is_empty() && id.starts_with(entry.as_str()))
+        };
+        let successors: Vec<&(String, Vec<String>)> = candidates
+            .iter()
+            .filter(|(id, history)| id != commit && evolved_from(history, commit))
+            .collect();
+        let mut latest = successors.iter().filter(|(id, _)| {
+            !successors
+                .iter()
+                .any(|(other, history)| other != id && evolved_from(history, id))
+        });
+        match (latest.next(), latest.next()) {
+            (Some((id, _)), None) => Some(id.clone()),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tracking_indicator() {
+        let counts = TrackingCounts {
+            ahead: 2,
+            behind: 5,
+        };
+        assert_eq!(counts.indicator(), "↑2 ↓5");
+        assert!(!counts.is_synced());
+
+        let behind_only = TrackingCounts {
+            ahead: 0,
+            behind: 3,
+        };
+        assert_eq!(behind_only.indicator(), "↓3");
+
+        assert!(TrackingCounts::default().is_synced());
+        assert_eq!(TrackingCounts::default().indicator(), "");
+        assert_eq!(counts.column(), "+2/-5");
+        assert_eq!(TrackingCounts::default().column(), "+0/-0");
+    }
+
+    fn target(name: &str, remote: Option<&str>, tracked: bool, commit: &str) -> BookmarkTarget {
+        BookmarkTarget {
+            name: name.into(),
+            remote: remote.map(Into::into),
+            is_tracked: tracked,
+            commit_id: Some(commit.into()),
+        }
+    }
+
+    #[test]
+    fn test_rename_candidates_pair_deleted_with_new() {
+        let before = vec![
+            target("feat", None, true, "c2"),
+            target("feat", Some("origin"), true, "c1"),
+            target("main", Some("origin"), true, "c0"),
+        ];
+        let after = vec![
+            target("feat", None, true, "c2"),
+            target("feature/login", Some("origin"), false, "c1"),
+            target("main", Some("origin"), true, "c0"),
+        ];
+        let renames = BookmarkRename::candidates(&before, &after);
+        assert_eq!(renames.len(), 1);
+        assert_eq!(renames[0].label(), "feat@origin → feature/login@origin");
+        assert_eq!(renames[0].old_commit, "c1");
+        assert_eq!(renames[0].local_commit.as_deref(), Some("c2"));
+    }
+
+    #[test]
+    fn test_rename_candidates_skip_other_remote_and_existing_local() {
+        let before = vec![target("feat", Some("origin"), true, "c1")];
+        let after = vec![
+            target("other", Some("upstream"), false, "c1"),
+            target("taken", Some("origin"), false, "c1"),
+            target("taken", None, false, "c9"),
+        ];
+        assert!(BookmarkRename::candidates(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_rename_candidates_skip_other_commits() {
+        let before = vec![target("merged", Some("origin"), true, "c1")];
+        let after = vec![target("colleague", Some("origin"), false, "c2")];
+        assert!(BookmarkRename::candidates(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_unambiguous_drops_shared_bookmarks() {
+        let before = vec![
+            target("a", Some("origin"), true, "c1"),
+            target("b", Some("origin"), true, "c1"),
+        ];
+        let after = vec![target("c", Some("origin"), false, "c1")];
+        let renames = BookmarkRename::candidates(&before, &after);
+        assert_eq!(renames.len(), 2);
+        assert!(BookmarkRename::unambiguous(renames).is_empty());
+    }
+
+    fn evolved(id: &str, history: &[&str]) -> (String, Vec<String>) {
+        (id.into(), history.iter().map(|h| h.to_string()).collect())
+    }
+
+    #[test]
+    fn test_latest_successor_follows_the_evolog() {
+        let candidates = vec![
+            evolved("bbbb2222", &["bbbb2222", "aaaa"]),
+            evolved("cccc3333", &["cccc3333", "bbbb", "aaaa"]),
+            evolved("dddd4444", &["dddd4444"]),
+        ];
+        assert_eq!(
+            BookmarkRetarget::latest_successor("aaaa1111", &candidates),
+            Some("cccc3333".to_string())
+        );
+        assert_eq!(
+            BookmarkRetarget::latest_successor("eeee5555", &candidates),
+            None
+        );
+    }
+
+    #[test]
+    fn test_latest_successor_rejects_split_histories() {
+        let candidates = vec![
+            evolved("bbbb2222", &["bbbb2222", "aaaa"]),
+            evolved("cccc3333", &["cccc3333", "aaaa"]),
+        ];
+        assert_eq!(
+            BookmarkRetarget::latest_successor("aaaa1111", &candidates),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retarget_label() {
+        let retarget = BookmarkRetarget {
+            name: "feat".into(),
+            commit: format!("aaaa1111{}", "f".repeat(32)),
+            successor: format!("cccc3333{}", "f".repeat(32)),
+        };
+        assert_eq!(retarget.label(8), "feat: aaaa1111 → cccc3333");
+    }
+
+    #[test]
+    fn test_delete_impact_summary() {
+        let impact = BookmarkDeleteImpact {
+            name: "feature".into(),
+            tracked_remotes: vec!["origin".into(), "upstream".into()],
+            orphaned_commits: 3,
+        };
+        assert_eq!(
+            impact.summary(),
+            "feature: tracked @origin @upstream, 3 commits lose their name"
+        );
+
+        let local = BookmarkDeleteImpact {
+            name: "wip".into(),
+            tracked_remotes: vec![],
+            orphaned_commits: 1,
+        };
+        assert_eq!(local.summary(), "wip: local only, 1 commit loses its name");
+    }
+
     #[test]
     fn test_full_name_local() {
         let bookmark = Bookmark {
             name: "main".into(),
             remote: None,
             is_tracked: true,
+            tracking: None,
         };
         assert_eq!(bookmark.full_name(), "main");
     }
@@ -90,6 +461,7 @@ mod tests {
             name: "feature-x".into(),
             remote: Some("origin".into()),
             is_tracked: false,
+            tracking: None,
         };
         assert_eq!(bookmark.full_name(), "feature-x@origin");
     }
@@ -101,6 +473,7 @@ mod tests {
             name: "main".into(),
             remote: None,
             is_tracked: true,
+            tracking: None,
         };
         assert!(!local.is_untracked_remote());
 
@@ -109,6 +482,7 @@ mod tests {
             name: "main".into(),
             remote: Some("origin".into()),
             is_tracked: true,
+            tracking: None,
         };
         assert!(!tracked_remote.is_untracked_remote());
 
@@ -117,6 +491,7 @@ mod tests {
             name: "feature".into(),
             remote: Some("origin".into()),
             is_tracked: false,
+            tracking: None,
         };
         assert!(untracked_remote.is_untracked_remote());
     }
@@ -128,10 +503,12 @@ mod tests {
                 name: "main".into(),
                 remote: None,
                 is_tracked: true,
+                tracking: None,
             },
             change_id: Some(ChangeId::new("abc12345".to_string())),
             commit_id: Some(CommitId::new("def67890".to_string())),
             description: Some("Test commit".into()),
+            timestamp: None,
         };
         assert!(jumpable.is_jumpable());
 
@@ -140,10 +517,12 @@ mod tests {
                 name: "remote-only".into(),
                 remote: Some("origin".into()),
                 is_tracked: false,
+                tracking: None,
             },
             change_id: None,
             commit_id: None,
             description: None,
+            timestamp: None,
         };
         assert!(!not_jumpable.is_jumpable());
     }
@@ -155,10 +534,12 @@ mod tests {
                 name: "main".into(),
                 remote: None,
                 is_tracked: true,
+                tracking: None,
             },
             change_id: Some(ChangeId::new("abc12345".to_string())),
             commit_id: Some(CommitId::new("def67890".to_string())),
             description: Some("Fix critical bug".into()),
+            timestamp: None,
         };
 
         let label = info.display_label(40);
@@ -176,10 +557,12 @@ mod tests {
                 name: "orphan".into(),
                 remote: None,
                 is_tracked: true,
+                tracking: None,
             },
             change_id: Some(ChangeId::new("abc12345".to_string())),
             commit_id: Some(CommitId::new("def67890".to_string())),
             description: None,
+            timestamp: None,
         };
 
         let label = info.display_label(40);
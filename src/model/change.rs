@@ -1,6 +1,7 @@
 //! Change (commit) data model
 
 use super::id::{ChangeId, CommitId};
+use super::log_row::LogRowExtras;
 
 /// Represents a jj change (similar to a Git commit)
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -47,6 +48,19 @@ pub struct Change {
     /// Workspace names that have this commit as working copy
     /// Empty if not a working copy for any workspace.
     pub working_copy_names: Vec<String>,
+
+    /// True if this change is in `immutable_heads()` ancestry
+    pub is_immutable: bool,
+
+    /// True if this change ID has more than one visible commit
+    pub is_divergent: bool,
+
+    /// True if this commit is hidden (abandoned or rewritten); only listed
+    /// while the Log View shows hidden commits
+    pub is_hidden: bool,
+
+    /// Values requested by a custom `tij.log-row` layout
+    pub extras: LogRowExtras,
 }
 
 impl Change {
@@ -65,6 +79,22 @@ impl Change {
     }
 }
 
+/// A `jj log` line the parser could not understand
+///
+/// Rows before it are still shown; the full raw output is kept so it can be
+/// saved for a bug report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogParseIssue {
+    /// 1-based line number of the offending line
+    pub line_number: usize,
+    /// The offending line as printed by jj
+    pub line: String,
+    /// Why the line was rejected
+    pub message: String,
+    /// Complete `jj log` output
+    pub raw_output: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,6 +120,10 @@ mod tests {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         }
     }
 
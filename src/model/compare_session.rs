@@ -0,0 +1,107 @@
+//! Named compare sessions from `[tij.compare-sessions]` in jj config
+
+use super::RevsetPreset;
+
+/// A saved `jj diff --from --to` pair, e.g.
+///
+/// ```toml
+/// [tij.compare-sessions.release]
+/// from = "v1.0"
+/// to = "release-1.x"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareSession {
+    /// Session name (the table key under `tij.compare-sessions`)
+    pub name: String,
+    /// "From" revision
+    pub from: String,
+    /// "To" revision
+    pub to: String,
+}
+
+impl CompareSession {
+    /// Whether `name` can be saved as a bare TOML key (same rules as revset presets)
+    pub fn is_valid_name(name: &str) -> bool {
+        RevsetPreset::is_valid_name(name)
+    }
+
+    /// Build sessions from `<name>.from` / `<name>.to` string pairs, in
+    /// first-seen order; entries missing either side are skipped
+    pub fn from_config_pairs(pairs: Vec<(String, String)>) -> Vec<Self> {
+        let mut sessions: Vec<(String, Option<String>, Option<String>)> = Vec::new();
+        for (key, value) in pairs {
+            let Some((name, field)) = key.split_once('.') else {
+                continue;
+            };
+            let index = match sessions.iter().position(|(n, _, _)| n == name) {
+                Some(index) => index,
+                None => {
+                    sessions.push((name.to_string(), None, None));
+                    sessions.len() - 1
+                }
+            };
+            match field {
+                "from" => sessions[index].1 = Some(value),
+                "to" => sessions[index].2 = Some(value),
+                _ => {}
+            }
+        }
+        sessions
+            .into_iter()
+            .filter_map(|(name, from, to)| {
+                Some(Self {
+                    name,
+                    from: from?,
+                    to: to?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_from_config_pairs_groups_by_name() {
+        let sessions = CompareSession::from_config_pairs(vec![
+            pair("release.from", "v1.0"),
+            pair("release.to", "release-1.x"),
+            pair("hotfix.to", "hotfix"),
+            pair("hotfix.from", "main"),
+        ]);
+        assert_eq!(
+            sessions,
+            vec![
+                CompareSession {
+                    name: "release".to_string(),
+                    from: "v1.0".to_string(),
+                    to: "release-1.x".to_string(),
+                },
+                CompareSession {
+                    name: "hotfix".to_string(),
+                    from: "main".to_string(),
+                    to: "hotfix".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_config_pairs_skips_incomplete_entries() {
+        let sessions = CompareSession::from_config_pairs(vec![
+            pair("half.from", "main"),
+            pair("flat", "main"),
+            pair("extra.from", "a"),
+            pair("extra.to", "b"),
+            pair("extra.note", "ignored"),
+        ]);
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].name, "extra");
+    }
+}
@@ -10,6 +10,70 @@ pub struct ConflictFile {
     pub description: String,
 }
 
+/// Which side of a conflict a materialized line belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictSide {
+    /// Outside any conflict region
+    Context,
+    /// Conflict marker line (`<<<<<<<`, `%%%%%%%`, `>>>>>>>`, ...)
+    Marker,
+    /// Base (common ancestor) content
+    Base,
+    /// First side (`side #1`, the rebase destination)
+    Ours,
+    /// Other sides (`side #2` and later)
+    Theirs,
+}
+
+/// A single line of a file with conflict markers (from `jj file show`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConflictLine {
+    pub side: ConflictSide,
+    pub content: String,
+}
+
+/// Resolution picked for a conflict region in the merge editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeChoice {
+    /// Take side #1
+    Ours,
+    /// Take side #2
+    Theirs,
+    /// Take side #1 followed by side #2
+    Both,
+    /// Hand-edited content
+    Edited(Vec<String>),
+}
+
+/// A 2-sided conflict region with its (optional) resolution
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConflictRegion {
+    pub base: Vec<String>,
+    pub ours: Vec<String>,
+    pub theirs: Vec<String>,
+    pub choice: Option<MergeChoice>,
+}
+
+impl ConflictRegion {
+    /// Lines produced by the current choice (None if unresolved)
+    pub fn resolved_lines(&self) -> Option<Vec<String>> {
+        let lines = match self.choice.as_ref()? {
+            MergeChoice::Ours => self.ours.clone(),
+            MergeChoice::Theirs => self.theirs.clone(),
+            MergeChoice::Both => self.ours.iter().chain(&self.theirs).cloned().collect(),
+            MergeChoice::Edited(lines) => lines.clone(),
+        };
+        Some(lines)
+    }
+}
+
+/// Part of a conflicted file: plain line or conflict region
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeSegment {
+    Context(String),
+    Conflict(ConflictRegion),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,4 +87,24 @@ mod tests {
         assert_eq!(file.path, "src/main.rs");
         assert_eq!(file.description, "2-sided conflict");
     }
+
+    #[test]
+    fn test_conflict_region_resolved_lines() {
+        let mut region = ConflictRegion {
+            base: vec!["base".to_string()],
+            ours: vec!["a".to_string()],
+            theirs: vec!["b".to_string()],
+            choice: None,
+        };
+        assert_eq!(region.resolved_lines(), None);
+
+        region.choice = Some(MergeChoice::Both);
+        assert_eq!(
+            region.resolved_lines(),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+
+        region.choice = Some(MergeChoice::Edited(vec![]));
+        assert_eq!(region.resolved_lines(), Some(vec![]));
+    }
 }
@@ -0,0 +1,91 @@
+//! Environment diagnostics shown in the Diagnostics View
+
+/// Outcome of one diagnostic check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticStatus {
+    /// Plain information (versions, URLs)
+    Info,
+    /// The feature or program is available
+    Available,
+    /// The feature or program is missing or unusable
+    Missing,
+}
+
+/// One `label: value` line of a diagnostics section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticItem {
+    pub label: String,
+    pub value: String,
+    pub status: DiagnosticStatus,
+}
+
+impl DiagnosticItem {
+    pub fn info(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::new(label, value, DiagnosticStatus::Info)
+    }
+
+    pub fn new(
+        label: impl Into<String>,
+        value: impl Into<String>,
+        status: DiagnosticStatus,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            status,
+        }
+    }
+}
+
+/// A titled group of diagnostic items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticSection {
+    pub title: String,
+    pub items: Vec<DiagnosticItem>,
+}
+
+/// Plain-text report of `sections`, for pasting into an issue or chat
+pub fn format_diagnostics(sections: &[DiagnosticSection]) -> String {
+    let mut out = String::new();
+    for (i, section) in sections.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!("## {}\n", section.title));
+        for item in &section.items {
+            let marker = match item.status {
+                DiagnosticStatus::Info => "",
+                DiagnosticStatus::Available => " [ok]",
+                DiagnosticStatus::Missing => " [missing]",
+            };
+            out.push_str(&format!("{}: {}{}\n", item.label, item.value, marker));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_diagnostics() {
+        let sections = vec![
+            DiagnosticSection {
+                title: "Versions".to_string(),
+                items: vec![DiagnosticItem::info("jj", "jj 0.41.0")],
+            },
+            DiagnosticSection {
+                title: "Features".to_string(),
+                items: vec![
+                    DiagnosticItem::new("Clipboard", "xclip", DiagnosticStatus::Available),
+                    DiagnosticItem::new("GitHub PRs", "gh not found", DiagnosticStatus::Missing),
+                ],
+            },
+        ];
+        assert_eq!(
+            format_diagnostics(&sections),
+            "## Versions\njj: jj 0.41.0\n\n## Features\nClipboard: xclip [ok]\nGitHub PRs: gh not found [missing]\n"
+        );
+    }
+}
@@ -182,10 +182,79 @@ impl DiffDisplayFormat {
         }
     }
 
+    /// Whether the `+`/`-` line filters apply
+    ///
+    /// Only the git format marks every changed line. Stat has no lines to
+    /// filter, and color-words (read without color) prints a modified line
+    /// once with both line numbers, just like an unchanged one.
+    pub fn filters_lines(self) -> bool {
+        self == Self::Git
+    }
+
     /// Total number of formats
     pub const COUNT: usize = 3;
 }
 
+/// Diff View options, kept for the session
+///
+/// Whitespace and context are passed to jj, so changing them re-fetches
+/// the diff; mode changes are hidden by the Diff View itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DiffOptions {
+    /// Ignore whitespace changes (`--ignore-all-space`)
+    pub ignore_whitespace: bool,
+    /// Context lines around changes (None = jj's default or configured value)
+    pub context: Option<usize>,
+    /// Hide file mode changes (e.g. a file becoming executable)
+    pub hide_mode_changes: bool,
+}
+
+impl DiffOptions {
+    /// jj's built-in context line count, the starting point of `{`/`}`
+    pub const DEFAULT_CONTEXT: usize = 3;
+
+    /// Context line counts `{`/`}` step through
+    const CONTEXT_STEPS: [usize; 9] = [0, 1, 2, 3, 5, 10, 20, 50, 100];
+
+    /// Flags for `jj show`/`diff`/`interdiff`
+    pub fn jj_flags(&self) -> Vec<String> {
+        let mut flags = Vec::new();
+        if self.ignore_whitespace {
+            flags.push("--ignore-all-space".to_string());
+        }
+        if let Some(context) = self.context {
+            flags.push("--context".to_string());
+            flags.push(context.to_string());
+        }
+        flags
+    }
+
+    /// Next context step up (`more`) or down, None at either end
+    pub fn step_context(&self, more: bool) -> Option<usize> {
+        let current = self.context.unwrap_or(Self::DEFAULT_CONTEXT);
+        if more {
+            Self::CONTEXT_STEPS.into_iter().find(|&n| n > current)
+        } else {
+            Self::CONTEXT_STEPS.into_iter().rev().find(|&n| n < current)
+        }
+    }
+
+    /// Context bar labels of the options that differ from the defaults
+    pub fn labels(&self) -> Vec<String> {
+        let mut labels = Vec::new();
+        if self.ignore_whitespace {
+            labels.push("whitespace ignored".to_string());
+        }
+        if let Some(context) = self.context {
+            labels.push(format!("{} context line(s)", context));
+        }
+        if self.hide_mode_changes {
+            labels.push("mode changes hidden".to_string());
+        }
+        labels
+    }
+}
+
 /// Display mode for DiffView (determines executor routing for derived operations)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DiffMode {
@@ -359,4 +428,42 @@ mod tests {
         assert_eq!(line.content, "src/main.rs");
         assert_eq!(line.file_op, None);
     }
+
+    // =========================================================================
+    // DiffOptions tests
+    // =========================================================================
+
+    #[test]
+    fn test_diff_options_flags() {
+        assert!(DiffOptions::default().jj_flags().is_empty());
+        let options = DiffOptions {
+            ignore_whitespace: true,
+            context: Some(0),
+            hide_mode_changes: true,
+        };
+        assert_eq!(options.jj_flags(), ["--ignore-all-space", "--context", "0"]);
+        assert_eq!(
+            options.labels(),
+            [
+                "whitespace ignored",
+                "0 context line(s)",
+                "mode changes hidden"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_options_context_steps() {
+        let mut options = DiffOptions::default();
+        assert_eq!(options.step_context(true), Some(5));
+        assert_eq!(options.step_context(false), Some(2));
+        options.context = Some(0);
+        assert_eq!(options.step_context(false), None);
+        options.context = Some(100);
+        assert_eq!(options.step_context(true), None);
+        // A configured count between steps moves to the neighbouring step
+        options.context = Some(7);
+        assert_eq!(options.step_context(true), Some(10));
+        assert_eq!(options.step_context(false), Some(5));
+    }
 }
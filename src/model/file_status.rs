@@ -5,7 +5,7 @@ use super::id::ChangeId;
 /// Overall repository status
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Status {
-    /// List of changed files
+    /// List of changed files, followed by untracked paths
     pub files: Vec<FileStatus>,
 
     /// Are there any conflicted files?
@@ -16,12 +16,16 @@ pub struct Status {
 
     /// Parent change ID
     pub parent_change_id: ChangeId,
+
+    /// Warnings and hints jj printed alongside the file list
+    pub warnings: Vec<StatusWarning>,
 }
 
 impl Status {
-    /// Check if the working copy is clean (no changes)
+    /// Check if the working copy is clean (no changes; untracked and
+    /// ignored paths don't count)
     pub fn is_clean(&self) -> bool {
-        self.files.is_empty()
+        !self.files.iter().any(|f| f.state.is_tracked())
     }
 
     /// Get count of files by state (test-only helper)
@@ -34,6 +38,32 @@ impl Status {
     }
 }
 
+/// Kind of a `jj status` message block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusWarningKind {
+    /// `Warning: ...` (e.g. files refused by the snapshot, unresolved conflicts)
+    Warning,
+    /// `Hint: ...` follow-up advice
+    Hint,
+    /// Informational section such as "Untracked paths:"
+    Info,
+}
+
+/// A message block from `jj status`: headline plus continuation lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusWarning {
+    pub kind: StatusWarningKind,
+    /// First line is the headline; the rest are its details (paths, steps)
+    pub lines: Vec<String>,
+}
+
+impl StatusWarning {
+    /// Headline of the block
+    pub fn headline(&self) -> &str {
+        self.lines.first().map_or("", String::as_str)
+    }
+}
+
 /// Status of a single file
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileStatus {
@@ -53,6 +83,8 @@ impl FileStatus {
             FileState::Deleted => 'D',
             FileState::Renamed { .. } => 'R',
             FileState::Conflicted => 'C',
+            FileState::Untracked => '?',
+            FileState::Ignored => '!',
         }
     }
 }
@@ -77,6 +109,19 @@ pub enum FileState {
 
     /// File has conflicts
     Conflicted,
+
+    /// Path is not tracked (`snapshot.auto-track` excludes it)
+    Untracked,
+
+    /// Path is excluded by `.gitignore` (directories end with `/`)
+    Ignored,
+}
+
+impl FileState {
+    /// A change recorded in the working copy commit (not untracked/ignored)
+    pub fn is_tracked(&self) -> bool {
+        !matches!(self, FileState::Untracked | FileState::Ignored)
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +135,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         assert!(clean.is_clean());
 
@@ -101,8 +147,18 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         assert!(!dirty.is_clean());
+
+        let untracked_only = Status {
+            files: vec![FileStatus {
+                path: "notes.txt".to_string(),
+                state: FileState::Untracked,
+            }],
+            ..clean
+        };
+        assert!(untracked_only.is_clean());
     }
 
     #[test]
@@ -125,6 +181,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
 
         assert_eq!(status.count_by_state(&FileState::Added), 2);
@@ -0,0 +1,310 @@
+//! Hunks of a single file's unified diff (hunk- and line-level discard and squash)
+//!
+//! Picks are single added/removed lines ([`LinePick`]); picking a whole hunk
+//! picks all of its changed lines. A discard reverts the picked lines in the
+//! working copy file, a squash applies only them to the parent's file, like
+//! the edit mode of `git add -p`.
+
+/// One `@@ -a,b +c,d @@` hunk of a git-format diff
+///
+/// Both sides keep their lines newline-terminated exactly as in the file
+/// (`\r\n` included), except a last line marked `\ No newline at end of file`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// Header line (`@@ -10,3 +10,4 @@ fn main()`)
+    pub header: String,
+    /// First line of the old (parent) side, 1-based
+    pub old_start: usize,
+    /// First line of the new (working copy) side, 1-based; for a hunk with
+    /// no new-side lines, the line after which the old lines belong
+    pub new_start: usize,
+    /// Context and removed lines
+    pub old_lines: Vec<String>,
+    /// Context and added lines
+    pub new_lines: Vec<String>,
+    /// Number of `+` lines
+    pub added: usize,
+    /// Number of `-` lines
+    pub removed: usize,
+    /// First added or removed line, for labelling the hunk
+    pub first_change: String,
+    /// Context, added and removed lines in diff order
+    pub lines: Vec<HunkLine>,
+}
+
+/// Kind of a hunk body line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HunkLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// A body line of a [`Hunk`], newline-terminated like its sides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HunkLine {
+    pub kind: HunkLineKind,
+    pub text: String,
+}
+
+impl HunkLine {
+    /// `+ text` / `- text` for pickers
+    pub fn label(&self) -> String {
+        let marker = match self.kind {
+            HunkLineKind::Context => ' ',
+            HunkLineKind::Added => '+',
+            HunkLineKind::Removed => '-',
+        };
+        format!("{} {}", marker, self.text.trim_end_matches(['\n', '\r']))
+    }
+}
+
+/// A picked line: index of the hunk and index into its `lines`
+pub type LinePick = (usize, usize);
+
+/// Which side of the diff picked lines are spliced into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    /// The working copy file, reverting the picked lines
+    Discard,
+    /// The parent's file, applying the picked lines
+    Squash,
+}
+
+impl Hunk {
+    /// `-1 +2  first changed line`
+    pub fn summary(&self) -> String {
+        format!(
+            "-{} +{}  {}",
+            self.removed,
+            self.added,
+            self.first_change.trim()
+        )
+    }
+
+    /// 0-based index of the new side in the working copy file
+    fn new_index(&self) -> usize {
+        if self.new_lines.is_empty() {
+            self.new_start
+        } else {
+            self.new_start.saturating_sub(1)
+        }
+    }
+
+    /// 0-based index of the old side in the parent's file
+    fn old_index(&self) -> usize {
+        if self.old_lines.is_empty() {
+            self.old_start
+        } else {
+            self.old_start.saturating_sub(1)
+        }
+    }
+
+    /// Indices into `lines` of the added and removed lines
+    pub fn changed_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.kind != HunkLineKind::Context)
+            .map(|(i, _)| i)
+    }
+
+    /// Span of `target`'s file the hunk covers, and what replaces it
+    fn splice(&self, picked: &[usize], target: Target) -> (usize, &[String], Vec<String>) {
+        let squash = target == Target::Squash;
+        let replacement = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(i, line)| {
+                let picked = picked.contains(i);
+                match line.kind {
+                    HunkLineKind::Context => true,
+                    HunkLineKind::Added => picked == squash,
+                    HunkLineKind::Removed => picked != squash,
+                }
+            })
+            .map(|(_, line)| line.text.clone())
+            .collect();
+        match target {
+            Target::Discard => (self.new_index(), &self.new_lines, replacement),
+            Target::Squash => (self.old_index(), &self.old_lines, replacement),
+        }
+    }
+}
+
+/// Every changed line of the hunks at `selected`
+pub fn hunk_picks(hunks: &[Hunk], selected: &[usize]) -> Vec<LinePick> {
+    selected
+        .iter()
+        .flat_map(|&index| {
+            let lines: Vec<usize> = hunks
+                .get(index)
+                .map(|hunk| hunk.changed_lines().collect())
+                .unwrap_or_default();
+            lines.into_iter().map(move |line| (index, line))
+        })
+        .collect()
+}
+
+/// `current` (the working copy file) with the picked lines reverted
+///
+/// Returns `None` if the file no longer matches the diff the hunks came from
+/// (it was edited in between), so nothing is written from a stale diff.
+pub fn discard_lines(current: &str, hunks: &[Hunk], picked: &[LinePick]) -> Option<String> {
+    splice_picks(current, hunks, picked, Target::Discard)
+}
+
+/// `parent` (the file in `@-`) with only the picked lines applied
+///
+/// This is the parent's new content when the picked lines are squashed out
+/// of the working copy; `None` if `parent` doesn't match the diff.
+pub fn squash_lines(parent: &str, hunks: &[Hunk], picked: &[LinePick]) -> Option<String> {
+    splice_picks(parent, hunks, picked, Target::Squash)
+}
+
+fn splice_picks(text: &str, hunks: &[Hunk], picked: &[LinePick], target: Target) -> Option<String> {
+    let mut lines: Vec<String> = text.split_inclusive('\n').map(String::from).collect();
+
+    let mut order: Vec<usize> = picked.iter().map(|&(hunk, _)| hunk).collect();
+    order.sort_unstable();
+    order.dedup();
+    // Bottom-up, so earlier hunks keep their line numbers
+    for &index in order.iter().rev() {
+        let hunk = hunks.get(index)?;
+        let hunk_picked: Vec<usize> = picked
+            .iter()
+            .filter(|(h, _)| *h == index)
+            .map(|&(_, line)| line)
+            .collect();
+        let (start, expected, replacement) = hunk.splice(&hunk_picked, target);
+        let end = start + expected.len();
+        if end > lines.len() || lines[start..end] != expected[..] {
+            return None;
+        }
+        lines.splice(start..end, replacement);
+    }
+    Some(lines.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A hunk from body lines with their `+`/`-`/` ` markers
+    fn hunk(old_start: usize, new_start: usize, body: &[&str]) -> Hunk {
+        let mut hunk = Hunk {
+            header: String::new(),
+            old_start,
+            new_start,
+            old_lines: Vec::new(),
+            new_lines: Vec::new(),
+            added: 0,
+            removed: 0,
+            first_change: String::new(),
+            lines: Vec::new(),
+        };
+        for line in body {
+            let (marker, text) = line.split_at(1);
+            let kind = match marker {
+                "+" => HunkLineKind::Added,
+                "-" => HunkLineKind::Removed,
+                _ => HunkLineKind::Context,
+            };
+            if kind != HunkLineKind::Added {
+                hunk.old_lines.push(text.to_string());
+            }
+            if kind != HunkLineKind::Removed {
+                hunk.new_lines.push(text.to_string());
+            }
+            hunk.lines.push(HunkLine {
+                kind,
+                text: text.to_string(),
+            });
+        }
+        hunk
+    }
+
+    #[test]
+    fn test_discard_selected_hunks_only() {
+        let current = "a\nB\nc\nd\ne\nF\nnew\n";
+        let hunks = [
+            hunk(1, 1, &[" a\n", "-b\n", "+B\n", " c\n"]),
+            hunk(5, 5, &[" e\n", "-f\n", "+F\n", "+new\n"]),
+        ];
+        assert_eq!(
+            discard_lines(current, &hunks, &hunk_picks(&hunks, &[1])).unwrap(),
+            "a\nB\nc\nd\ne\nf\n"
+        );
+        assert_eq!(
+            discard_lines(current, &hunks, &hunk_picks(&hunks, &[1, 0])).unwrap(),
+            "a\nb\nc\nd\ne\nf\n"
+        );
+    }
+
+    #[test]
+    fn test_discard_pure_deletion_reinserts_lines() {
+        // `@@ -2,1 +1,0 @@`: line 2 was removed after new line 1
+        let hunks = [hunk(2, 1, &["-gone\n"])];
+        assert_eq!(
+            discard_lines("a\nb\n", &hunks, &hunk_picks(&hunks, &[0])).unwrap(),
+            "a\ngone\nb\n"
+        );
+    }
+
+    #[test]
+    fn test_discard_keeps_crlf_and_rejects_stale_diff() {
+        let hunks = [hunk(1, 1, &["-x\r\n", "+y\r\n"])];
+        let picks = hunk_picks(&hunks, &[0]);
+        assert_eq!(discard_lines("y\r\nz", &hunks, &picks).unwrap(), "x\r\nz");
+        assert!(discard_lines("edited\r\nz", &hunks, &picks).is_none());
+        assert!(discard_lines("y\r\n", &hunks, &[(3, 0)]).is_none());
+    }
+
+    #[test]
+    fn test_discard_single_lines() {
+        // b -> B, plus an inserted line; only the insertion is discarded
+        let hunks = [hunk(1, 1, &[" a\n", "-b\n", "+B\n", "+new\n", " c\n"])];
+        assert_eq!(
+            discard_lines("a\nB\nnew\nc\n", &hunks, &[(0, 3)]).unwrap(),
+            "a\nB\nc\n"
+        );
+        // Reverting just the removal keeps both versions of the line
+        assert_eq!(
+            discard_lines("a\nB\nnew\nc\n", &hunks, &[(0, 1)]).unwrap(),
+            "a\nb\nB\nnew\nc\n"
+        );
+    }
+
+    #[test]
+    fn test_squash_applies_only_picked_lines_to_parent() {
+        let parent = "a\nb\nc\nd\n";
+        let hunks = [
+            hunk(1, 1, &[" a\n", "-b\n", "+B\n", "+new\n", " c\n"]),
+            // `@@ -4,0 +5,1 @@`: appended after old line 4
+            hunk(4, 5, &["+end\n"]),
+        ];
+        assert_eq!(
+            squash_lines(parent, &hunks, &[(0, 1), (0, 2)]).unwrap(),
+            "a\nB\nc\nd\n"
+        );
+        assert_eq!(
+            squash_lines(parent, &hunks, &[(0, 3), (1, 0)]).unwrap(),
+            "a\nb\nnew\nc\nd\nend\n"
+        );
+        // Everything picked: the parent becomes the working copy file
+        assert_eq!(
+            squash_lines(parent, &hunks, &hunk_picks(&hunks, &[0, 1])).unwrap(),
+            "a\nB\nnew\nc\nd\nend\n"
+        );
+        assert!(squash_lines("a\nx\nc\nd\n", &hunks, &[(0, 1)]).is_none());
+    }
+
+    #[test]
+    fn test_line_labels_and_changed_lines() {
+        let hunk = hunk(1, 1, &[" a\n", "-b\r\n", "+B\n"]);
+        assert_eq!(hunk.changed_lines().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(hunk.lines[1].label(), "- b");
+        assert_eq!(hunk.lines[2].label(), "+ B");
+    }
+}
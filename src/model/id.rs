@@ -5,10 +5,27 @@
 //! mix-ups that cause bugs with divergent changes.
 
 use std::fmt;
+use std::ops::RangeInclusive;
 
-/// Truncate an ID to 8 characters for display.
-fn short_id(id: &str) -> &str {
-    &id[..8.min(id.len())]
+/// Default minimum number of ID characters shown (`tij.id-length`)
+pub const DEFAULT_ID_LENGTH: usize = 8;
+
+/// Accepted range for the configured ID length
+pub const ID_LENGTH_RANGE: RangeInclusive<usize> = 4..=16;
+
+/// Clamp a configured ID length (`tij.id-length`) to [`ID_LENGTH_RANGE`]
+pub fn clamp_id_length(len: usize) -> usize {
+    len.clamp(*ID_LENGTH_RANGE.start(), *ID_LENGTH_RANGE.end())
+}
+
+/// Display form of a jj ID (shared by notifications, dialogs and views)
+///
+/// Cut to `len` chars; shorter IDs are returned unchanged.
+pub fn short_id(id: &str, len: usize) -> &str {
+    match id.char_indices().nth(len) {
+        Some((end, _)) => &id[..end],
+        None => id,
+    }
 }
 
 /// jj change ID — UI identifier used for highlighting, search, cache keys, `--change` flag.
@@ -19,6 +36,14 @@ pub struct ChangeId(String);
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub struct CommitId(String);
 
+/// Whether `id` is a prefix of the root change ID (`zzzz…`)
+///
+/// With a configurable ID length the root is no longer exactly
+/// `ROOT_CHANGE_ID`, but it is always the only all-`z` prefix.
+pub fn is_root_change_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c == 'z')
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // ChangeId
 // ─────────────────────────────────────────────────────────────────────────────
@@ -32,8 +57,9 @@ impl ChangeId {
         &self.0
     }
 
-    pub fn short(&self) -> &str {
-        short_id(&self.0)
+    /// Display form at ID length `len` (see [`short_id`])
+    pub fn short(&self, len: usize) -> &str {
+        short_id(&self.0, len)
     }
 
     pub fn is_empty(&self) -> bool {
@@ -59,6 +85,11 @@ impl ChangeId {
     pub fn to_lowercase(&self) -> String {
         self.0.to_lowercase()
     }
+
+    /// Whether this is the root change (all `z`, at any display length)
+    pub fn is_root(&self) -> bool {
+        is_root_change_id(&self.0)
+    }
 }
 
 impl fmt::Display for ChangeId {
@@ -116,8 +147,9 @@ impl CommitId {
         &self.0
     }
 
-    pub fn short(&self) -> &str {
-        short_id(&self.0)
+    /// Display form at ID length `len` (see [`short_id`])
+    pub fn short(&self, len: usize) -> &str {
+        short_id(&self.0, len)
     }
 
     pub fn is_empty(&self) -> bool {
@@ -215,14 +247,45 @@ mod tests {
 
     #[test]
     fn test_change_id_short() {
-        let id = ChangeId::new("abcdef1234567890".to_string());
-        assert_eq!(id.short(), "abcdef12");
+        let id = ChangeId::new("kxryzmorwvtuqnslpkxryzmorwvtuqns".to_string());
+        assert_eq!(id.short(DEFAULT_ID_LENGTH), "kxryzmor");
     }
 
     #[test]
     fn test_commit_id_short() {
-        let id = CommitId::new("abcdef1234567890".to_string());
-        assert_eq!(id.short(), "abcdef12");
+        let id = CommitId::new("abcdef1234567890abcdef1234567890abcdef12".to_string());
+        assert_eq!(id.short(DEFAULT_ID_LENGTH), "abcdef12");
+    }
+
+    #[test]
+    fn test_short_id_cuts_to_length() {
+        let hash = "abcdef1234567890abcdef1234567890abcdef12";
+        assert_eq!(short_id(hash, 8), "abcdef12");
+        assert_eq!(short_id(hash, 12), "abcdef123456");
+        assert_eq!(short_id("kxryzmorw", 8), "kxryzmor");
+    }
+
+    #[test]
+    fn test_short_id_keeps_shorter_ids() {
+        assert_eq!(short_id("kxryzmor", 8), "kxryzmor");
+        assert_eq!(short_id("abc", 8), "abc");
+        assert_eq!(short_id("", 8), "");
+    }
+
+    #[test]
+    fn test_is_root_change_id_any_length() {
+        assert!(is_root_change_id("zzzzzzzz"));
+        assert!(is_root_change_id("zzzzzzzzzzzz"));
+        assert!(!is_root_change_id("zzzzzzzk"));
+        assert!(!is_root_change_id(""));
+        assert!(ChangeId::from("zzzzzzzzzz").is_root());
+    }
+
+    #[test]
+    fn test_clamp_id_length() {
+        assert_eq!(clamp_id_length(1), 4);
+        assert_eq!(clamp_id_length(12), 12);
+        assert_eq!(clamp_id_length(64), 16);
     }
 
     #[test]
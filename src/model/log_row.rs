@@ -0,0 +1,212 @@
+//! Log View row layout from `tij.log-row`
+//!
+//! The layout lists the columns of a Log View row in order, e.g.
+//!
+//! ```toml
+//! [tij]
+//! log-row = "change_id:12 author timestamp:relative bookmarks diff_stats description"
+//! ```
+//!
+//! Columns that need more than the fixed `jj log` fields (a longer change
+//! ID, relative time) make the log template ask for them as tagged extra
+//! fields, parsed into [`LogRowExtras`]. Diff stats are fetched separately
+//! for the rows on screen. Markers such as `[CONFLICT]` or `[note]` always
+//! sit right before the description.
+
+use super::id::ID_LENGTH_RANGE;
+
+/// How the timestamp column is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle {
+    /// `tij.timestamp-format` (default `%Y-%m-%d %H:%M:%S`)
+    Absolute,
+    /// jj's `ago()` (`2 hours ago`)
+    Relative,
+}
+
+/// A column of a Log View row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogColumn {
+    /// Change ID, with an optional display length (`change_id:12`)
+    ChangeId(Option<usize>),
+    /// Commit ID (also toggled with `%`)
+    CommitId,
+    /// Author email
+    Author,
+    /// Author timestamp (`timestamp`, `timestamp:relative`)
+    Timestamp(TimestampStyle),
+    /// Bookmarks with their ahead/behind counts
+    Bookmarks,
+    /// Lines added/removed (`+12 -3`), fetched lazily for the visible rows
+    DiffStats,
+    /// First line of the description
+    Description,
+}
+
+impl LogColumn {
+    fn parse(spec: &str) -> Result<Self, String> {
+        let (name, option) = match spec.split_once(':') {
+            Some((name, option)) => (name, Some(option)),
+            None => (spec, None),
+        };
+        let column = match (name, option) {
+            ("change_id", None) => LogColumn::ChangeId(None),
+            ("change_id", Some(len)) => {
+                let len: usize = len
+                    .parse()
+                    .map_err(|_| format!("invalid change_id length '{}'", len))?;
+                if !ID_LENGTH_RANGE.contains(&len) {
+                    return Err(format!(
+                        "change_id length must be {}-{}",
+                        ID_LENGTH_RANGE.start(),
+                        ID_LENGTH_RANGE.end()
+                    ));
+                }
+                LogColumn::ChangeId(Some(len))
+            }
+            ("commit_id", None) => LogColumn::CommitId,
+            ("author", None) => LogColumn::Author,
+            ("timestamp", None | Some("absolute")) => {
+                LogColumn::Timestamp(TimestampStyle::Absolute)
+            }
+            ("timestamp", Some("relative")) => LogColumn::Timestamp(TimestampStyle::Relative),
+            ("bookmarks", None) => LogColumn::Bookmarks,
+            ("diff_stats", None) => LogColumn::DiffStats,
+            ("description", None) => LogColumn::Description,
+            (_, Some(option)) => return Err(format!("unknown option '{}' for {}", option, name)),
+            _ => return Err(format!("unknown column '{}'", name)),
+        };
+        Ok(column)
+    }
+
+    /// Columns compare by kind (`timestamp` and `timestamp:relative` clash)
+    fn same_kind(self, other: LogColumn) -> bool {
+        std::mem::discriminant(&self) == std::mem::discriminant(&other)
+    }
+}
+
+/// Ordered columns of a Log View row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRow {
+    columns: Vec<LogColumn>,
+}
+
+impl Default for LogRow {
+    /// The built-in row; the commit ID only shows while toggled on with `%`
+    fn default() -> Self {
+        Self {
+            columns: vec![
+                LogColumn::ChangeId(None),
+                LogColumn::CommitId,
+                LogColumn::Author,
+                LogColumn::Timestamp(TimestampStyle::Absolute),
+                LogColumn::Bookmarks,
+                LogColumn::Description,
+            ],
+        }
+    }
+}
+
+impl LogRow {
+    /// Parse a space or comma separated column list
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut columns: Vec<LogColumn> = Vec::new();
+        for word in spec.split([' ', ',']).filter(|word| !word.is_empty()) {
+            let column = LogColumn::parse(word)?;
+            if columns.iter().any(|c| c.same_kind(column)) {
+                return Err(format!("column '{}' listed twice", word));
+            }
+            columns.push(column);
+        }
+        if !columns.iter().any(|c| matches!(c, LogColumn::ChangeId(_))) {
+            return Err("change_id is required".to_string());
+        }
+        Ok(Self { columns })
+    }
+
+    pub fn columns(&self) -> &[LogColumn] {
+        &self.columns
+    }
+
+    pub fn has_commit_id(&self) -> bool {
+        self.columns.contains(&LogColumn::CommitId)
+    }
+
+    /// Display length of the change ID column, if set
+    pub fn change_id_length(&self) -> Option<usize> {
+        self.columns.iter().find_map(|c| match c {
+            LogColumn::ChangeId(len) => *len,
+            _ => None,
+        })
+    }
+
+    pub fn relative_timestamps(&self) -> bool {
+        self.columns
+            .contains(&LogColumn::Timestamp(TimestampStyle::Relative))
+    }
+
+    pub fn diff_stats(&self) -> bool {
+        self.columns.contains(&LogColumn::DiffStats)
+    }
+}
+
+/// Lines added and removed by a change
+pub type DiffStat = (usize, usize);
+
+/// Values only fetched for a custom [`LogRow`] (all `None` by default)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LogRowExtras {
+    /// Change ID at the layout's display length
+    pub change_id: Option<String>,
+    /// Relative author time (`2 hours ago`)
+    pub relative_time: Option<String>,
+    /// Lines added and removed, filled in once fetched
+    pub diff_stat: Option<DiffStat>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keeps_order_and_options() {
+        let row = LogRow::parse("change_id:12, author timestamp:relative diff_stats").unwrap();
+        assert_eq!(
+            row.columns(),
+            [
+                LogColumn::ChangeId(Some(12)),
+                LogColumn::Author,
+                LogColumn::Timestamp(TimestampStyle::Relative),
+                LogColumn::DiffStats,
+            ]
+        );
+        assert_eq!(row.change_id_length(), Some(12));
+        assert!(row.relative_timestamps());
+        assert!(row.diff_stats());
+        assert!(!row.has_commit_id());
+    }
+
+    #[test]
+    fn test_default_row_needs_no_extras() {
+        let row = LogRow::default();
+        assert_eq!(row.change_id_length(), None);
+        assert!(!row.relative_timestamps());
+        assert!(!row.diff_stats());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            LogRow::parse("change_id branch"),
+            Err("unknown column 'branch'".to_string())
+        );
+        assert!(LogRow::parse("change_id:abc").is_err());
+        assert!(LogRow::parse("change_id:200").is_err());
+        assert!(LogRow::parse("change_id author:name").is_err());
+        assert!(LogRow::parse("change_id timestamp timestamp:relative").is_err());
+        assert_eq!(
+            LogRow::parse("author description"),
+            Err("change_id is required".to_string())
+        );
+    }
+}
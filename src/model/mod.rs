@@ -7,31 +7,64 @@ mod annotation;
 mod bookmark;
 mod change;
 mod command_record;
+mod compare_session;
 mod conflict;
+mod diagnostics;
 mod diff;
 mod evolog;
 mod file_status;
+mod hunk;
 mod id;
+mod log_row;
+mod note;
 mod notification;
 mod operation;
+mod pin;
 mod rebase;
+mod remote;
+mod revset_preset;
+mod sparse;
+mod stack;
 mod tag;
+mod timestamp;
 mod workspace;
 
 pub use annotation::{AnnotationContent, AnnotationLine};
-pub use bookmark::{Bookmark, BookmarkInfo};
-pub use change::Change;
+pub use bookmark::{
+    Bookmark, BookmarkDeleteImpact, BookmarkInfo, BookmarkRename, BookmarkRetarget, BookmarkTarget,
+    TrackingCounts,
+};
+pub use change::{Change, LogParseIssue};
 pub use command_record::{CommandHistory, CommandRecord, CommandStatus};
-pub use conflict::ConflictFile;
+pub use compare_session::CompareSession;
+pub use conflict::{
+    ConflictFile, ConflictLine, ConflictRegion, ConflictSide, MergeChoice, MergeSegment,
+};
+pub use diagnostics::{DiagnosticItem, DiagnosticSection, DiagnosticStatus, format_diagnostics};
 pub use diff::{
     CompareInfo, CompareRevisionInfo, DiffContent, DiffDisplayFormat, DiffLine, DiffLineKind,
-    DiffMode, FileOperation,
+    DiffMode, DiffOptions, FileOperation,
 };
 pub use evolog::EvologEntry;
-pub use file_status::{FileState, FileStatus, Status};
-pub use id::{ChangeId, CommitId};
+pub use file_status::{FileState, FileStatus, Status, StatusWarning, StatusWarningKind};
+pub use hunk::{Hunk, HunkLine, HunkLineKind, LinePick, discard_lines, hunk_picks, squash_lines};
+pub use id::{
+    ChangeId, CommitId, DEFAULT_ID_LENGTH, ID_LENGTH_RANGE, clamp_id_length, is_root_change_id,
+    short_id,
+};
+pub use log_row::{DiffStat, LogColumn, LogRow, LogRowExtras, TimestampStyle};
+pub use note::Notes;
 pub use notification::{Notification, NotificationKind};
-pub use operation::Operation;
+pub use operation::{OpDiff, OpDivergence, OpNode, Operation};
+pub use pin::{MAX_PINS, Pin};
 pub use rebase::RebaseMode;
+pub use remote::RemoteInfo;
+pub use revset_preset::RevsetPreset;
+pub use sparse::{FULL_CHECKOUT, SparseImpact, sparse_pattern_matches};
+pub use stack::{StackEntry, StackPushState, assign_push_states};
 pub use tag::TagInfo;
+pub use timestamp::{
+    DEFAULT_TIMESTAMP_FORMAT, TimestampFormat, civil_from_days, clock_time, format_timestamp,
+    timestamp_to_epoch,
+};
 pub use workspace::WorkspaceInfo;
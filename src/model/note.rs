@@ -0,0 +1,65 @@
+//! Local notes on changes from `[tij.notes]` in the repo config
+
+/// Free-text notes keyed by full change ID, e.g.
+///
+/// ```toml
+/// [tij.notes]
+/// kxqyzprtwmnvlsookxqyzprtwmnvlsoo = "ask about the retry limit in review"
+/// ```
+///
+/// Notes live in the repo config, not in jj history: they follow the change
+/// through rewrites but are never pushed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Notes(Vec<(String, String)>);
+
+impl Notes {
+    /// Build notes from `<change_id> = "<text>"` pairs, skipping empty ones
+    pub fn from_config_pairs(pairs: Vec<(String, String)>) -> Self {
+        Self(
+            pairs
+                .into_iter()
+                .filter(|(change_id, text)| !change_id.is_empty() && !text.trim().is_empty())
+                .collect(),
+        )
+    }
+
+    /// The note on a change; `change_id` may be a (shortest unique) prefix
+    pub fn get(&self, change_id: &str) -> Option<&str> {
+        if change_id.is_empty() {
+            return None;
+        }
+        self.0
+            .iter()
+            .find(|(id, _)| id.starts_with(change_id))
+            .map(|(_, text)| text.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_get_matches_short_change_id() {
+        let notes = Notes::from_config_pairs(vec![
+            pair("kxqyzprtwmnvlsoo", "review the retry limit"),
+            pair("zzzzzzzzzzzzzzzz", "  "),
+        ]);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes.get("kxqy"), Some("review the retry limit"));
+        assert_eq!(notes.get("zzzz"), None);
+        assert_eq!(notes.get(""), None);
+    }
+}
@@ -1,12 +1,13 @@
 //! Operation model for jj operation history
 
+use std::collections::{HashMap, HashSet};
+
 /// Represents a jj operation from `jj op log`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Operation {
     /// Operation ID (e.g., "75ea3c2331bf")
     pub id: String,
-    /// User who performed the operation
-    #[allow(dead_code)]
+    /// User who performed the operation (`user@hostname`)
     pub user: String,
     /// Timestamp (e.g., "2026-02-02 11:25:54 +09:00")
     pub timestamp: String,
@@ -14,6 +15,14 @@ pub struct Operation {
     pub description: String,
     /// Is this the current operation? (first in list)
     pub is_current: bool,
+    /// Absolute start time (`%Y-%m-%d %H:%M:%S`, empty if unknown)
+    pub started_at: String,
+    /// Operation tags as `key: value` (e.g., "args: jj describe -m foo")
+    pub tags: Vec<String>,
+    /// Graph prefix from `jj op log` (e.g., "│ ○  ")
+    pub graph_prefix: String,
+    /// Graph-only line (no operation data)
+    pub is_graph_only: bool,
 }
 
 impl Operation {
@@ -21,6 +30,144 @@ impl Operation {
     pub fn short_id(&self) -> &str {
         &self.id[..12.min(self.id.len())]
     }
+
+    /// User name without the hostname
+    pub fn username(&self) -> &str {
+        self.user
+            .rsplit_once('@')
+            .map_or(self.user.as_str(), |(name, _)| name)
+    }
+
+    /// Hostname the operation was run on (empty if unknown)
+    pub fn hostname(&self) -> &str {
+        self.user.rsplit_once('@').map_or("", |(_, host)| host)
+    }
+
+    /// Command line that created the operation (from the `args` tag)
+    pub fn args(&self) -> Option<&str> {
+        self.tags.iter().find_map(|t| t.strip_prefix("args: "))
+    }
+}
+
+/// Operation with the IDs of its parent operations
+#[derive(Debug, Clone, Default)]
+pub struct OpNode {
+    pub op: Operation,
+    /// Parent operation IDs (short, 12 chars); two or more for a merge
+    pub parents: Vec<String>,
+}
+
+/// Divergent operation heads and the operation that reconciled them
+///
+/// When two jj processes write concurrently, the op log gets several heads.
+/// jj merges them the next time the repo is loaded ("reconcile divergent
+/// operations"), so the divergence is found as a merge operation.
+#[derive(Debug, Clone)]
+pub struct OpDivergence {
+    /// Operation that merged the heads
+    pub merge: Operation,
+    /// Operations done after the merge (undone by keeping one side)
+    pub later_ops: usize,
+    /// One chain per head, newest first, without the shared history
+    pub sides: Vec<Vec<Operation>>,
+    /// Newest operation shared by all heads (None if beyond the loaded log)
+    pub fork_point: Option<Operation>,
+}
+
+impl OpDivergence {
+    /// Find the most recent divergence in an op log listing (newest first)
+    pub fn find_latest(nodes: &[OpNode]) -> Option<Self> {
+        let (merge_idx, merge) = nodes
+            .iter()
+            .enumerate()
+            .find(|(_, n)| n.parents.len() > 1)?;
+        let by_id: HashMap<&str, &OpNode> = nodes.iter().map(|n| (n.op.id.as_str(), n)).collect();
+        let ancestry: Vec<HashSet<&str>> = merge
+            .parents
+            .iter()
+            .map(|head| ancestors(&by_id, head))
+            .collect();
+        let is_shared = |id: &str| ancestry.iter().all(|set| set.contains(id));
+
+        let sides = ancestry
+            .iter()
+            .map(|set| {
+                nodes
+                    .iter()
+                    .filter(|n| set.contains(n.op.id.as_str()) && !is_shared(&n.op.id))
+                    .map(|n| n.op.clone())
+                    .collect()
+            })
+            .collect();
+        let fork_point = nodes
+            .iter()
+            .find(|n| is_shared(&n.op.id))
+            .map(|n| n.op.clone());
+
+        Some(Self {
+            merge: merge.op.clone(),
+            later_ops: merge_idx,
+            sides,
+            fork_point,
+        })
+    }
+
+    /// Head operation of a side (the state "keep this side" restores)
+    pub fn head(&self, side: usize) -> Option<&Operation> {
+        self.sides.get(side)?.first()
+    }
+}
+
+/// Repository changes between two operations (`jj op diff`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpDiff {
+    /// Commits that become visible, as printed by jj (`+` lines)
+    pub added_commits: Vec<String>,
+    /// Commits that become hidden (`-` lines)
+    pub removed_commits: Vec<String>,
+    /// Bookmarks and tags whose target changes (`name` or `name@remote`)
+    pub changed_refs: Vec<String>,
+}
+
+impl OpDiff {
+    /// Whether the two operations have the same visible state
+    pub fn is_empty(&self) -> bool {
+        self.added_commits.is_empty()
+            && self.removed_commits.is_empty()
+            && self.changed_refs.is_empty()
+    }
+
+    /// Diffstat-style summary, e.g. "+2 -1 commits, 3 bookmarks changed"
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "no visible changes".to_string();
+        }
+        let refs = match self.changed_refs.len() {
+            1 => "1 bookmark changed".to_string(),
+            n => format!("{} bookmarks changed", n),
+        };
+        format!(
+            "+{} -{} commits, {}",
+            self.added_commits.len(),
+            self.removed_commits.len(),
+            refs
+        )
+    }
+}
+
+/// IDs of `start` and all its ancestors present in `by_id`
+fn ancestors<'a>(by_id: &HashMap<&'a str, &'a OpNode>, start: &'a str) -> HashSet<&'a str> {
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(id) = stack.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        if let Some(node) = by_id.get(id) {
+            stack.extend(node.parents.iter().map(String::as_str));
+        }
+    }
+    seen
 }
 
 #[cfg(test)]
@@ -35,6 +182,7 @@ mod tests {
             timestamp: "2026-02-02 11:25:54".to_string(),
             description: "snapshot working copy".to_string(),
             is_current: true,
+            ..Default::default()
         };
         assert_eq!(op.short_id(), "75ea3c2331bf");
     }
@@ -47,7 +195,93 @@ mod tests {
             timestamp: "2026-02-02 11:25:54".to_string(),
             description: "test".to_string(),
             is_current: false,
+            ..Default::default()
         };
         assert_eq!(op.short_id(), "abc");
     }
+
+    #[test]
+    fn test_user_and_host() {
+        let op = Operation {
+            user: "alice@build-01".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(op.username(), "alice");
+        assert_eq!(op.hostname(), "build-01");
+
+        let op = Operation {
+            user: "alice".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(op.username(), "alice");
+        assert_eq!(op.hostname(), "");
+    }
+
+    #[test]
+    fn test_args_tag() {
+        let op = Operation {
+            tags: vec![
+                "args: jj describe -m 'fix'".to_string(),
+                "other: value".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(op.args(), Some("jj describe -m 'fix'"));
+        assert_eq!(Operation::default().args(), None);
+    }
+
+    fn node(id: &str, parents: &[&str]) -> OpNode {
+        OpNode {
+            op: Operation {
+                id: id.to_string(),
+                ..Default::default()
+            },
+            parents: parents.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn ids(ops: &[Operation]) -> Vec<&str> {
+        ops.iter().map(|op| op.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_find_latest_divergence() {
+        // later -> merge -> {a2 -> a1, b1} -> fork -> root
+        let nodes = vec![
+            node("later", &["merge"]),
+            node("merge", &["a2", "b1"]),
+            node("a2", &["a1"]),
+            node("b1", &["fork"]),
+            node("a1", &["fork"]),
+            node("fork", &["root"]),
+            node("root", &[]),
+        ];
+        let divergence = OpDivergence::find_latest(&nodes).unwrap();
+        assert_eq!(divergence.merge.id, "merge");
+        assert_eq!(divergence.later_ops, 1);
+        assert_eq!(ids(&divergence.sides[0]), ["a2", "a1"]);
+        assert_eq!(ids(&divergence.sides[1]), ["b1"]);
+        assert_eq!(divergence.fork_point.as_ref().unwrap().id, "fork");
+        assert_eq!(divergence.head(1).unwrap().id, "b1");
+    }
+
+    #[test]
+    fn test_find_latest_without_merge() {
+        let nodes = vec![node("b", &["a"]), node("a", &[])];
+        assert!(OpDivergence::find_latest(&nodes).is_none());
+    }
+
+    #[test]
+    fn test_fork_point_beyond_loaded_log() {
+        // Both chains are cut off by --limit before they meet
+        let nodes = vec![
+            node("merge", &["a1", "b1"]),
+            node("a1", &["a0"]),
+            node("b1", &["b0"]),
+        ];
+        let divergence = OpDivergence::find_latest(&nodes).unwrap();
+        assert!(divergence.fork_point.is_none());
+        assert_eq!(ids(&divergence.sides[0]), ["a1"]);
+        assert_eq!(ids(&divergence.sides[1]), ["b1"]);
+    }
 }
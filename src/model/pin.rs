@@ -0,0 +1,130 @@
+//! Pinned revisions from `[tij.pins]` in the repo config
+
+use super::id::short_id;
+
+/// Highest pin slot; slots are jumped to with the keys 1-9
+pub const MAX_PINS: u8 = 9;
+
+/// A revision pinned to the Log View's favorites strip, e.g.
+///
+/// ```toml
+/// [tij.pins]
+/// 1 = "release-1.x"
+/// 2 = "kxqyzprtwmnvlsoo"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pin {
+    /// Slot number (1-9), also the jump key
+    pub slot: u8,
+    /// Bookmark name or change ID
+    pub revision: String,
+}
+
+impl Pin {
+    /// Build pins from `<slot> = "<revision>"` pairs, sorted by slot;
+    /// keys that are not a slot number are skipped
+    pub fn from_config_pairs(pairs: Vec<(String, String)>) -> Vec<Self> {
+        let mut pins: Vec<Self> = pairs
+            .into_iter()
+            .filter_map(|(key, revision)| {
+                let slot = key.parse().ok().filter(|s| (1..=MAX_PINS).contains(s))?;
+                Some(Self { slot, revision })
+            })
+            .collect();
+        pins.sort_by_key(|pin| pin.slot);
+        pins.dedup_by_key(|pin| pin.slot);
+        pins
+    }
+
+    /// Whether the pin is `change_id` (a short prefix, as the log shows it)
+    ///
+    /// Change IDs are pinned in full; only they are matched by prefix, so a
+    /// bookmark that happens to start with the same letters is not.
+    pub fn is_change(&self, change_id: &str) -> bool {
+        self.revision == change_id
+            || (!change_id.is_empty()
+                && self.revision.starts_with(change_id)
+                && self.is_change_id())
+    }
+
+    /// Strip label: a change ID cut to `id_length`, a bookmark name in full
+    pub fn label(&self, id_length: usize) -> &str {
+        if self.is_change_id() {
+            short_id(&self.revision, id_length)
+        } else {
+            &self.revision
+        }
+    }
+
+    /// Whether the revision is spelled in change ID letters (`k`-`z`)
+    fn is_change_id(&self) -> bool {
+        self.revision.chars().all(|c| ('k'..='z').contains(&c))
+    }
+
+    /// Lowest unused slot, if any is left
+    pub fn free_slot(pins: &[Self]) -> Option<u8> {
+        (1..=MAX_PINS).find(|slot| pins.iter().all(|pin| pin.slot != *slot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_is_change_matches_prefix_of_full_change_id() {
+        let full = Pin {
+            slot: 1,
+            revision: "kxqyzprtwmnvlsookxqyzprtwmnvlsoo".to_string(),
+        };
+        assert!(full.is_change("kxqyzprt"));
+        assert!(!full.is_change("kxqyzprz"));
+        assert!(!full.is_change(""));
+        let bookmark = Pin {
+            slot: 2,
+            revision: "kxqyzprt-fix".to_string(),
+        };
+        assert!(!bookmark.is_change("kxqyzprt"));
+    }
+
+    #[test]
+    fn test_from_config_pairs_sorts_and_skips_invalid_slots() {
+        let pins = Pin::from_config_pairs(vec![
+            pair("3", "main"),
+            pair("1", "release-1.x"),
+            pair("0", "ignored"),
+            pair("10", "ignored"),
+            pair("epic", "ignored"),
+        ]);
+        assert_eq!(
+            pins,
+            vec![
+                Pin {
+                    slot: 1,
+                    revision: "release-1.x".to_string(),
+                },
+                Pin {
+                    slot: 3,
+                    revision: "main".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_free_slot_fills_gaps() {
+        let pins = Pin::from_config_pairs(vec![pair("1", "a"), pair("3", "b")]);
+        assert_eq!(Pin::free_slot(&pins), Some(2));
+        let full: Vec<Pin> = (1..=MAX_PINS)
+            .map(|slot| Pin {
+                slot,
+                revision: slot.to_string(),
+            })
+            .collect();
+        assert_eq!(Pin::free_slot(&full), None);
+    }
+}
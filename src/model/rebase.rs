@@ -13,3 +13,16 @@ pub enum RebaseMode {
     /// `-B`: Insert revision before target in history
     InsertBefore,
 }
+
+impl RebaseMode {
+    /// jj flag selecting this mode (`-r`, `-s`, `-b`, `-A`, `-B`)
+    pub fn flag(self) -> &'static str {
+        match self {
+            Self::Revision => "-r",
+            Self::Source => "-s",
+            Self::Branch => "-b",
+            Self::InsertAfter => "-A",
+            Self::InsertBefore => "-B",
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Git remote model for `jj git remote list`
+
+/// Git remote from `jj git remote list`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteInfo {
+    /// Remote name (e.g., "origin", "upstream")
+    pub name: String,
+    /// Fetch URL (empty if the remote has none)
+    pub url: String,
+}
@@ -0,0 +1,34 @@
+//! Named revset presets from `[tij.revset-presets]` in jj config
+
+/// A saved Log View filter, e.g. `mine = "mine() & ~empty()"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevsetPreset {
+    /// Preset name (the config key under `tij.revset-presets`)
+    pub name: String,
+    /// Revset expression
+    pub revset: String,
+}
+
+impl RevsetPreset {
+    /// Whether `name` can be saved as a bare TOML key (letters, digits, `-`, `_`)
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_name() {
+        assert!(RevsetPreset::is_valid_name("mine"));
+        assert!(RevsetPreset::is_valid_name("my-stack_2"));
+        assert!(!RevsetPreset::is_valid_name(""));
+        assert!(!RevsetPreset::is_valid_name("my stack"));
+        assert!(!RevsetPreset::is_valid_name("a.b"));
+    }
+}
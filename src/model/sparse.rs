@@ -0,0 +1,95 @@
+//! Sparse checkout patterns from `jj sparse list`
+
+/// Pattern `jj sparse` uses for "everything" (a full checkout)
+pub const FULL_CHECKOUT: &str = ".";
+
+/// Whether `path` is materialized by `pattern`
+///
+/// Patterns are repo-relative path prefixes matched at component
+/// boundaries: `src` covers `src/lib.rs` but not `src2/lib.rs`.
+pub fn sparse_pattern_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_end_matches('/');
+    if pattern.is_empty() || pattern == FULL_CHECKOUT {
+        return true;
+    }
+    path.strip_prefix(pattern)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Files gained and lost when switching sparse patterns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SparseImpact {
+    /// Files written to the working copy
+    pub materialized: usize,
+    /// Files removed from the working copy
+    pub removed: usize,
+}
+
+impl SparseImpact {
+    /// Compare `files` (the tree of `@`) under the old and new patterns
+    pub fn between(files: &[String], old: &[String], new: &[String]) -> Self {
+        let covered = |patterns: &[String], file: &str| {
+            patterns.iter().any(|p| sparse_pattern_matches(p, file))
+        };
+        let mut impact = Self::default();
+        for file in files {
+            match (covered(old, file), covered(new, file)) {
+                (false, true) => impact.materialized += 1,
+                (true, false) => impact.removed += 1,
+                _ => {}
+            }
+        }
+        impact
+    }
+
+    /// `+3 files, -12 files` style summary
+    pub fn summary(&self) -> String {
+        if self.materialized == 0 && self.removed == 0 {
+            return "no files change".to_string();
+        }
+        format!(
+            "{} file(s) materialize, {} file(s) disappear",
+            self.materialized, self.removed
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_pattern_matches_at_component_boundary() {
+        assert!(sparse_pattern_matches(".", "any/file.rs"));
+        assert!(sparse_pattern_matches("src", "src/lib.rs"));
+        assert!(sparse_pattern_matches("src/", "src/lib.rs"));
+        assert!(sparse_pattern_matches("README.md", "README.md"));
+        assert!(!sparse_pattern_matches("src", "src2/lib.rs"));
+        assert!(!sparse_pattern_matches("src/ui", "src/lib.rs"));
+    }
+
+    #[test]
+    fn test_impact_between_patterns() {
+        let files = strings(&["src/lib.rs", "src/ui/mod.rs", "docs/a.md", "README.md"]);
+        let impact = SparseImpact::between(&files, &strings(&["."]), &strings(&["src"]));
+        assert_eq!(
+            impact,
+            SparseImpact {
+                materialized: 0,
+                removed: 2
+            }
+        );
+        let impact =
+            SparseImpact::between(&files, &strings(&["src/ui"]), &strings(&["docs", "src"]));
+        assert_eq!(impact.materialized, 2);
+        assert_eq!(impact.removed, 0);
+        assert_eq!(
+            impact.summary(),
+            "2 file(s) materialize, 0 file(s) disappear"
+        );
+    }
+}
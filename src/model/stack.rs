@@ -0,0 +1,96 @@
+//! Stack model for the `trunk()..@` Stack View
+
+use super::id::{ChangeId, CommitId};
+
+/// Push state of a commit in the current stack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StackPushState {
+    /// Reachable from a remote bookmark: already on the remote
+    Pushed,
+    /// Not on the remote, but a local bookmark at or above it would push it
+    NeedsPush,
+    /// Not on the remote and no bookmark covers it yet
+    #[default]
+    Local,
+}
+
+impl StackPushState {
+    /// Short label for the status column
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Pushed => "pushed",
+            Self::NeedsPush => "needs push",
+            Self::Local => "local",
+        }
+    }
+}
+
+/// One commit of the current stack (top of the stack first)
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StackEntry {
+    pub change_id: ChangeId,
+    pub commit_id: CommitId,
+    /// Description (first line)
+    pub description: String,
+    /// Local bookmarks pointing at this commit
+    pub bookmarks: Vec<String>,
+    pub is_working_copy: bool,
+    pub is_empty: bool,
+    pub has_conflict: bool,
+    /// Whether a remote bookmark already contains this commit
+    pub on_remote: bool,
+    /// Derived by [`assign_push_states`]
+    pub push_state: StackPushState,
+}
+
+/// Derive push states for a stack listed top (`@`) first
+///
+/// A commit needs pushing when it isn't on the remote yet but a local
+/// bookmark on it or on a commit above it would carry it along.
+pub fn assign_push_states(entries: &mut [StackEntry]) {
+    let mut covered = false;
+    for entry in entries.iter_mut() {
+        covered |= !entry.bookmarks.is_empty();
+        entry.push_state = if entry.on_remote {
+            StackPushState::Pushed
+        } else if covered {
+            StackPushState::NeedsPush
+        } else {
+            StackPushState::Local
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(bookmarks: &[&str], on_remote: bool) -> StackEntry {
+        StackEntry {
+            bookmarks: bookmarks.iter().map(|b| b.to_string()).collect(),
+            on_remote,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_push_states_follow_bookmarks_down_the_stack() {
+        let mut stack = vec![
+            entry(&[], false),
+            entry(&["feature"], false),
+            entry(&[], false),
+            entry(&["base"], true),
+        ];
+        assign_push_states(&mut stack);
+        let states: Vec<_> = stack.iter().map(|e| e.push_state).collect();
+        assert_eq!(
+            states,
+            vec![
+                StackPushState::Local,
+                StackPushState::NeedsPush,
+                StackPushState::NeedsPush,
+                StackPushState::Pushed,
+            ]
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Timestamp display formatting shared by all views
+//!
+//! Templates ask jj for local-time timestamps in one machine-readable form
+//! (`YYYY-MM-DDTHH:MM:SS+ZZZZ`). Views render them with
+//! [`format_timestamp`], which applies `tij.timestamp-format` (a strftime
+//! subset) and, with `tij.timestamp-utc`, converts to UTC first. Values that
+//! don't parse (e.g. relative operation times) are shown unchanged.
+
+/// Pattern used when `tij.timestamp-format` is unset
+pub const DEFAULT_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Display settings from `tij.timestamp-format` and `tij.timestamp-utc`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TimestampFormat {
+    /// strftime subset (`None` = [`DEFAULT_TIMESTAMP_FORMAT`])
+    pattern: Option<String>,
+    /// Convert to UTC before formatting
+    utc: bool,
+}
+
+impl TimestampFormat {
+    /// An empty pattern falls back to the default
+    pub fn new(pattern: Option<String>, utc: bool) -> Self {
+        Self {
+            pattern: pattern.filter(|p| !p.is_empty()),
+            utc,
+        }
+    }
+}
+
+/// Format a timestamp from jj output for display
+pub fn format_timestamp(raw: &str, format: &TimestampFormat) -> String {
+    let pattern = format
+        .pattern
+        .as_deref()
+        .unwrap_or(DEFAULT_TIMESTAMP_FORMAT);
+    format_with(raw, pattern, format.utc)
+}
+
+/// Seconds since 1970-01-01 UTC, for ordering timestamps from jj output
+///
+/// Values without an offset are taken as-is (they compare correctly against
+/// each other as long as they come from the same zone).
+pub fn timestamp_to_epoch(raw: &str) -> Option<i64> {
+    let ts = Timestamp::parse(raw)?.to_utc();
+    let days = days_from_civil(ts.year, ts.month, ts.day);
+    Some(days * 86_400 + i64::from(ts.hour * 3600 + ts.minute * 60 + ts.second))
+}
+
+/// Current time as `HH:MM`
+///
+/// std has no time zone database, so the local offset is taken from
+/// `reference`, a timestamp from jj output (e.g. the working copy's, which
+/// jj keeps rewriting in local time). UTC with `tij.timestamp-utc` or when
+/// `reference` carries no offset.
+pub fn clock_time(reference: Option<&str>, format: &TimestampFormat) -> String {
+    let offset = reference
+        .filter(|_| !format.utc)
+        .and_then(Timestamp::parse)
+        .and_then(|ts| ts.offset)
+        .unwrap_or(0);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    clock_time_at(now, offset)
+}
+
+/// `HH:MM` of `epoch` seconds at a UTC offset in minutes
+fn clock_time_at(epoch: i64, offset: i32) -> String {
+    let minute_of_day = (epoch.div_euclid(60) + i64::from(offset)).rem_euclid(1440);
+    format!("{:02}:{:02}", minute_of_day / 60, minute_of_day % 60)
+}
+
+fn format_with(raw: &str, pattern: &str, utc: bool) -> String {
+    match Timestamp::parse(raw) {
+        Some(ts) if utc => ts.to_utc().format(pattern),
+        Some(ts) => ts.format(pattern),
+        None => raw.to_string(),
+    }
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) civil date
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Howard Hinnant's days_from_civil inverse
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Days since 1970-01-01 for a civil date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Broken-down timestamp with an optional UTC offset in minutes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Timestamp {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    offset: Option<i32>,
+}
+
+impl Timestamp {
+    /// Parse `YYYY-MM-DD[T ]HH:MM[:SS[.fff]][ ][Z|±HHMM|±HH:MM]`
+    fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim();
+        let num = |range: std::ops::Range<usize>| -> Option<u32> {
+            let part = raw.get(range)?;
+            part.bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| part.parse().ok())?
+        };
+        let sep = |idx: usize, allowed: &[u8]| {
+            raw.as_bytes().get(idx).is_some_and(|b| allowed.contains(b))
+        };
+
+        if !(sep(4, b"-") && sep(7, b"-") && sep(10, b"T ") && sep(13, b":")) {
+            return None;
+        }
+        let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+        let (hour, minute) = (num(11..13)?, num(14..16)?);
+        let mut rest = &raw[16..];
+        let mut second = 0;
+        if let Some(after) = rest.strip_prefix(':') {
+            second = after.get(..2)?.parse().ok()?;
+            rest = &after[2..];
+            if let Some(frac) = rest.strip_prefix('.') {
+                rest = frac.trim_start_matches(|c: char| c.is_ascii_digit());
+            }
+        }
+        let offset = parse_offset(rest.trim_start())?;
+
+        let valid = (1..=12).contains(&month)
+            && (1..=31).contains(&day)
+            && hour < 24
+            && minute < 60
+            && second < 61;
+        valid.then_some(Self {
+            year: i64::from(year),
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            offset,
+        })
+    }
+
+    /// Same instant in UTC (unchanged when the offset is unknown)
+    fn to_utc(self) -> Self {
+        let Some(offset) = self.offset else {
+            return self;
+        };
+        let minutes = days_from_civil(self.year, self.month, self.day) * 1440
+            + i64::from(self.hour * 60 + self.minute)
+            - i64::from(offset);
+        let (year, month, day) = civil_from_days(minutes.div_euclid(1440));
+        let minute_of_day = minutes.rem_euclid(1440) as u32;
+        Self {
+            year,
+            month,
+            day,
+            hour: minute_of_day / 60,
+            minute: minute_of_day % 60,
+            second: self.second,
+            offset: Some(0),
+        }
+    }
+
+    fn weekday(self) -> usize {
+        // 1970-01-01 was a Thursday
+        (days_from_civil(self.year, self.month, self.day) + 4).rem_euclid(7) as usize
+    }
+
+    /// Render with a strftime subset; unknown specifiers are kept verbatim
+    fn format(self, pattern: &str) -> String {
+        let mut out = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            let Some(spec) = chars.next() else {
+                out.push('%');
+                break;
+            };
+            match spec {
+                'Y' => out.push_str(&format!("{:04}", self.year)),
+                'y' => out.push_str(&format!("{:02}", self.year.rem_euclid(100))),
+                'm' => out.push_str(&format!("{:02}", self.month)),
+                'd' => out.push_str(&format!("{:02}", self.day)),
+                'e' => out.push_str(&format!("{:>2}", self.day)),
+                'H' => out.push_str(&format!("{:02}", self.hour)),
+                'I' => out.push_str(&format!("{:02}", (self.hour + 11) % 12 + 1)),
+                'p' => out.push_str(if self.hour < 12 { "AM" } else { "PM" }),
+                'M' => out.push_str(&format!("{:02}", self.minute)),
+                'S' => out.push_str(&format!("{:02}", self.second)),
+                'b' => out.push_str(MONTHS[self.month as usize - 1]),
+                'a' => out.push_str(WEEKDAYS[self.weekday()]),
+                'F' => out.push_str(&self.format("%Y-%m-%d")),
+                'T' => out.push_str(&self.format("%H:%M:%S")),
+                'R' => out.push_str(&self.format("%H:%M")),
+                'z' => {
+                    if let Some(offset) = self.offset {
+                        let sign = if offset < 0 { '-' } else { '+' };
+                        let abs = offset.unsigned_abs();
+                        out.push_str(&format!("{}{:02}{:02}", sign, abs / 60, abs % 60));
+                    }
+                }
+                '%' => out.push('%'),
+                other => {
+                    out.push('%');
+                    out.push(other);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Parse a trailing UTC offset; `Some(None)` when there is none
+fn parse_offset(rest: &str) -> Option<Option<i32>> {
+    if rest.is_empty() {
+        return Some(None);
+    }
+    if rest == "Z" {
+        return Some(Some(0));
+    }
+    let sign = match rest.as_bytes()[0] {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits: String = rest[1..].chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[..2].parse().ok()?;
+    let minutes: i32 = digits[2..].parse().ok()?;
+    Some(Some(sign * (hours * 60 + minutes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_time_at_offset() {
+        // 2026-10-15T05:25:30Z
+        let epoch = 1_792_041_930;
+        assert_eq!(clock_time_at(epoch, 0), "05:25");
+        assert_eq!(clock_time_at(epoch, 540), "14:25");
+        assert_eq!(clock_time_at(epoch, -420), "22:25");
+    }
+
+    #[test]
+    fn test_default_pattern_from_template_output() {
+        assert_eq!(
+            format_with("2026-10-15T14:25:30+0900", DEFAULT_TIMESTAMP_FORMAT, false),
+            "2026-10-15 14:25:30"
+        );
+    }
+
+    #[test]
+    fn test_format_timestamp_applies_settings() {
+        let raw = "2026-01-01T05:10:00+0900";
+        assert_eq!(
+            format_timestamp(raw, &TimestampFormat::default()),
+            "2026-01-01 05:10:00"
+        );
+        assert_eq!(
+            format_timestamp(raw, &TimestampFormat::new(Some(String::new()), false)),
+            "2026-01-01 05:10:00"
+        );
+        assert_eq!(
+            format_timestamp(raw, &TimestampFormat::new(Some("%F %R".to_string()), true)),
+            "2025-12-31 20:10"
+        );
+    }
+
+    #[test]
+    fn test_utc_conversion_crosses_midnight() {
+        assert_eq!(
+            format_with("2026-01-01T05:10:00+0900", "%F %T %z", true),
+            "2025-12-31 20:10:00 +0000"
+        );
+        assert_eq!(
+            format_with("2026-02-28T22:30:00-03:00", "%F %R", true),
+            "2026-03-01 01:30"
+        );
+    }
+
+    #[test]
+    fn test_pattern_specifiers() {
+        let raw = "2026-10-15 09:05:07";
+        assert_eq!(
+            format_with(raw, "%a %d %b %y %I:%M %p %%", false),
+            "Thu 15 Oct 26 09:05 AM %"
+        );
+        // No offset: %z is empty and UTC conversion is skipped
+        assert_eq!(format_with(raw, "%H:%M%z", true), "09:05");
+        assert_eq!(format_with(raw, "%Q", false), "%Q");
+    }
+
+    #[test]
+    fn test_unparsable_values_are_kept() {
+        assert_eq!(format_with("3 minutes ago", "%F", false), "3 minutes ago");
+        assert_eq!(
+            format_with("2026-13-01 00:00", "%F", false),
+            "2026-13-01 00:00"
+        );
+        assert_eq!(format_with("", "%F", false), "");
+    }
+
+    #[test]
+    fn test_fractional_seconds_and_colon_offset() {
+        assert_eq!(
+            format_with("2026-10-15 14:25:30.123 +09:00", "%T %z", false),
+            "14:25:30 +0900"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_epoch() {
+        assert_eq!(timestamp_to_epoch("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(timestamp_to_epoch("1970-01-01T09:00:01+0900"), Some(1));
+        assert_eq!(
+            timestamp_to_epoch("2026-01-30 10:43"),
+            Some(timestamp_to_epoch("2026-01-30 10:42:59").unwrap() + 1)
+        );
+        assert_eq!(timestamp_to_epoch("yesterday"), None);
+    }
+
+    #[test]
+    fn test_civil_from_days_handles_leap_years() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+    }
+}
@@ -0,0 +1,157 @@
+//! Compact layout for small terminals
+//!
+//! Below a configurable size (`tij.compact-layout`, default `80x24`) the
+//! Log, Status and Bookmark views become tabs of a single pane: a one-row
+//! tab bar on top, `Alt+1`/`2`/`3` to switch, a one-row status bar, and
+//! `p` showing the preview in place of the list instead of beside it.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::View;
+
+/// Terminal size below which the compact layout is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactLayout {
+    pub min_width: u16,
+    pub min_height: u16,
+}
+
+impl Default for CompactLayout {
+    fn default() -> Self {
+        Self {
+            min_width: 80,
+            min_height: 24,
+        }
+    }
+}
+
+impl CompactLayout {
+    /// Parse a config value: `"WIDTHxHEIGHT"`, or `"off"` for never
+    pub fn parse(value: &str) -> Result<Option<Self>, String> {
+        let value = value.trim();
+        if value == "off" {
+            return Ok(None);
+        }
+        let size = value.split_once('x').and_then(|(width, height)| {
+            Some(Self {
+                min_width: width.trim().parse().ok()?,
+                min_height: height.trim().parse().ok()?,
+            })
+        });
+        match size {
+            Some(size) => Ok(Some(size)),
+            None => Err(format!(
+                "expected \"WIDTHxHEIGHT\" (e.g. \"80x24\") or \"off\", got \"{}\"",
+                value
+            )),
+        }
+    }
+
+    /// Whether `area` is small enough for the compact layout
+    pub fn applies(&self, area: Rect) -> bool {
+        area.width < self.min_width || area.height < self.min_height
+    }
+}
+
+/// Views shown as tabs, with their number keys (pressed with Alt)
+pub const TABS: [(char, View, &str); 3] = [
+    ('1', View::Log, "Log"),
+    ('2', View::Status, "Status"),
+    ('3', View::Bookmark, "Bookmarks"),
+];
+
+/// Whether `view` is one of the tabs
+pub fn is_tab(view: View) -> bool {
+    TABS.iter().any(|(_, tab, _)| *tab == view)
+}
+
+/// Tab selected by Alt and a number key
+pub fn tab_for_key(key: KeyEvent) -> Option<View> {
+    if !key.modifiers.contains(KeyModifiers::ALT) {
+        return None;
+    }
+    TABS.iter()
+        .find(|(number, _, _)| key.code == KeyCode::Char(*number))
+        .map(|(_, view, _)| *view)
+}
+
+/// Tab after `view` (Tab key), wrapping around
+pub fn next_tab(view: View) -> View {
+    let index = TABS.iter().position(|(_, tab, _)| *tab == view);
+    TABS[index.map_or(0, |i| (i + 1) % TABS.len())].1
+}
+
+/// Render the tab bar in the first row of `area`; returns the rest
+pub fn render_tab_bar(frame: &mut Frame, area: Rect, active: View) -> Rect {
+    if area.height < 2 {
+        return area;
+    }
+    let mut spans = Vec::with_capacity(TABS.len() * 2);
+    for (i, (key, view, label)) in TABS.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled("│", Style::default().fg(Color::DarkGray)));
+        }
+        let style = if *view == active {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(format!(" Alt+{} {} ", key, label), style));
+    }
+    let bar = Rect { height: 1, ..area };
+    frame.render_widget(Paragraph::new(Line::from(spans)), bar);
+    Rect {
+        y: area.y + 1,
+        height: area.height - 1,
+        ..area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            CompactLayout::parse("100x30"),
+            Ok(Some(CompactLayout {
+                min_width: 100,
+                min_height: 30
+            }))
+        );
+        assert_eq!(CompactLayout::parse(" off "), Ok(None));
+        assert!(CompactLayout::parse("100").is_err());
+        assert!(CompactLayout::parse("wide x tall").is_err());
+    }
+
+    #[test]
+    fn test_applies_below_either_dimension() {
+        let layout = CompactLayout::default();
+        assert!(!layout.applies(Rect::new(0, 0, 80, 24)));
+        assert!(layout.applies(Rect::new(0, 0, 79, 50)));
+        assert!(layout.applies(Rect::new(0, 0, 200, 23)));
+    }
+
+    #[test]
+    fn test_tab_keys_and_cycle() {
+        let alt = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT);
+        assert_eq!(tab_for_key(alt('2')), Some(View::Status));
+        assert_eq!(tab_for_key(alt('4')), None);
+        assert_eq!(tab_for_key(KeyEvent::from(KeyCode::Char('2'))), None);
+        assert_eq!(next_tab(View::Log), View::Status);
+        assert_eq!(next_tab(View::Bookmark), View::Log);
+        assert!(is_tab(View::Bookmark) && !is_tab(View::Diff));
+    }
+}
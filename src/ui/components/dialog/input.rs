@@ -10,31 +10,82 @@ use ratatui::{
 };
 
 use super::{Dialog, DialogKind, DialogResult, centered_rect};
+use crate::ui::components::{LineInput, cursor_spans};
+
+/// Completion candidates listed under the input before `+N` is shown
+const MAX_SHOWN_COMPLETIONS: usize = 4;
+
+/// Byte offset where the last word of `text` starts
+///
+/// Words are made of characters that can appear in bookmark, tag and
+/// remote names (`main@origin`, `v1.0`), so the operators of a revset like
+/// `trunk()..feat` separate them. Leading dots belong to the `..` operator.
+fn last_word_start(text: &str) -> usize {
+    let start = text
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '@')))
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    start + text[start..].len() - text[start..].trim_start_matches('.').len()
+}
+
+/// Candidates that start with the last word of `text`
+pub(super) fn completion_matches<'a>(text: &str, candidates: &'a [String]) -> Vec<&'a str> {
+    let word = &text[last_word_start(text)..];
+    candidates
+        .iter()
+        .map(String::as_str)
+        .filter(|c| c.starts_with(word))
+        .collect()
+}
+
+/// `text` with its last word extended to the longest prefix shared by the
+/// matching candidates (None when that adds nothing)
+pub(super) fn complete(text: &str, candidates: &[String]) -> Option<String> {
+    let start = last_word_start(text);
+    let matches = completion_matches(text, candidates);
+    let (first, rest) = matches.split_first()?;
+    let common = rest.iter().fold(*first, |common, candidate| {
+        let len = common
+            .char_indices()
+            .zip(candidate.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((i, a), _)| i + a.len_utf8());
+        &common[..len]
+    });
+    (common.len() > text.len() - start).then(|| format!("{}{}", &text[..start], common))
+}
 
 impl Dialog {
     pub(super) fn handle_input_key(&mut self, key: KeyEvent) -> Option<DialogResult> {
         match key.code {
             KeyCode::Enter => {
                 if let DialogKind::Input { ref buffer, .. } = self.kind {
-                    Some(DialogResult::Confirmed(vec![buffer.clone()]))
+                    Some(DialogResult::Confirmed(vec![buffer.text().to_string()]))
                 } else {
                     None
                 }
             }
             KeyCode::Esc => Some(DialogResult::Cancelled),
-            KeyCode::Backspace => {
-                if let DialogKind::Input { ref mut buffer, .. } = self.kind {
-                    buffer.pop();
+            KeyCode::Tab => {
+                if let DialogKind::Input {
+                    ref mut buffer,
+                    ref completions,
+                    ..
+                } = self.kind
+                    && let Some(completed) = complete(buffer.text(), completions)
+                {
+                    *buffer = LineInput::new(completed);
                 }
                 None
             }
-            KeyCode::Char(c) => {
+            _ => {
                 if let DialogKind::Input { ref mut buffer, .. } = self.kind {
-                    buffer.push(c);
+                    buffer.handle_key(key);
                 }
                 None
             }
-            _ => None,
         }
     }
 
@@ -44,51 +95,65 @@ impl Dialog {
         area: Rect,
         title: &str,
         message: &str,
-        buffer: &str,
+        buffer: &LineInput,
+        completions: &[String],
     ) {
-        let width = 50.min(area.width.saturating_sub(4));
-        let height = 8u16.min(area.height.saturating_sub(4));
+        // One more line for the matches, and room for the [Tab] hint
+        let extra = u16::from(!completions.is_empty());
+        let width = (50 + 10 * extra).min(area.width.saturating_sub(4));
+        let height = (8 + extra).min(area.height.saturating_sub(4));
 
         let dialog_area = centered_rect(width, height, area);
 
         frame.render_widget(Clear, dialog_area);
 
         let inner_width = width.saturating_sub(4) as usize;
-        let display_buffer = if buffer.chars().count() > inner_width && inner_width > 0 {
-            let skip = buffer
-                .chars()
-                .count()
-                .saturating_sub(inner_width.saturating_sub(1));
-            format!("…{}", buffer.chars().skip(skip).collect::<String>())
-        } else {
-            buffer.to_string()
-        };
+        let (display_buffer, cursor) = buffer.display("", inner_width);
+        let mut input_spans = vec![Span::styled("> ", Style::default().fg(Color::Cyan))];
+        input_spans.extend(cursor_spans(
+            &display_buffer,
+            cursor,
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+            Span::styled("_", Style::default().fg(Color::DarkGray)),
+        ));
 
-        let lines = vec![
+        let mut lines = vec![
             Line::from(""),
             Line::from(Span::styled(
                 message,
                 Style::default().add_modifier(Modifier::BOLD),
             )),
             Line::from(""),
-            Line::from(vec![
-                Span::styled("> ", Style::default().fg(Color::Cyan)),
-                Span::styled(
-                    display_buffer,
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled("_", Style::default().fg(Color::DarkGray)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("[Enter]", Style::default().fg(Color::Green)),
-                Span::raw(" Confirm   "),
-                Span::styled("[Esc]", Style::default().fg(Color::Red)),
-                Span::raw(" Cancel"),
-            ]),
+            Line::from(input_spans),
         ];
+        let mut footer = vec![
+            Span::styled("[Enter]", Style::default().fg(Color::Green)),
+            Span::raw(" Confirm   "),
+        ];
+        if !completions.is_empty() {
+            let matches = completion_matches(buffer.text(), completions);
+            let mut shown = matches
+                .iter()
+                .take(MAX_SHOWN_COMPLETIONS)
+                .copied()
+                .collect::<Vec<_>>()
+                .join("  ");
+            if matches.len() > MAX_SHOWN_COMPLETIONS {
+                shown.push_str(&format!("  +{}", matches.len() - MAX_SHOWN_COMPLETIONS));
+            }
+            lines.push(Line::from(Span::styled(
+                shown,
+                Style::default().fg(Color::DarkGray),
+            )));
+            footer.push(Span::styled("[Tab]", Style::default().fg(Color::Yellow)));
+            footer.push(Span::raw(" Complete   "));
+        }
+        footer.push(Span::styled("[Esc]", Style::default().fg(Color::Red)));
+        footer.push(Span::raw(" Cancel"));
+        lines.push(Line::from(""));
+        lines.push(Line::from(footer));
 
         let paragraph = Paragraph::new(lines)
             .block(
@@ -3,9 +3,11 @@
 //! Provides reusable dialog components:
 //! - Confirm dialog: Yes/No confirmation
 //! - Select dialog: Checkbox selection for multiple items
+//! - Results dialog: Per-item outcome of a batch operation
 
 mod confirm;
 mod input;
+mod results;
 mod select;
 #[cfg(test)]
 mod tests;
@@ -18,6 +20,8 @@ use ratatui::{
 
 use crate::jj::PushBulkMode;
 use crate::keys;
+use crate::model::{BookmarkRename, BookmarkRetarget, LinePick, RebaseMode};
+use crate::ui::components::{LineInput, TypeAhead};
 
 /// Callback identifier for dialog results
 ///
@@ -27,6 +31,8 @@ use crate::keys;
 pub enum DialogCallback {
     /// Bookmark deletion (Select dialog)
     DeleteBookmarks,
+    /// Per-item results of a batch operation (Results dialog, no action)
+    BatchResults,
     /// Bookmark move confirmation (Confirm dialog)
     MoveBookmark {
         /// Bookmark name to move
@@ -34,9 +40,18 @@ pub enum DialogCallback {
         /// Target revision (commit_id)
         revision: String,
     },
-    /// Operation restore (future use)
-    #[allow(dead_code)]
-    OpRestore,
+    /// Operation restore after the `jj op diff` impact preview (Confirm dialog)
+    OpRestore { operation_id: String },
+    /// Abandon scope for the selected operation (Select dialog, single_select)
+    OpAbandonScope {
+        operation_id: String,
+        /// Other end of the range marked with Space
+        mark: Option<String>,
+    },
+    /// `jj op abandon <expression>` (Confirm dialog; `count` is the estimate)
+    OpAbandon { expression: String, count: usize },
+    /// `jj util gc`; the value is the `--expire` argument, empty for the default
+    OpGc,
     /// Git push confirmation
     GitPush,
     /// Track remote bookmarks (Select dialog)
@@ -47,8 +62,16 @@ pub enum DialogCallback {
     BookmarkForget,
     /// Git fetch remote selection (Select dialog, single_select)
     GitFetch,
-    /// Git fetch specific branch (Select dialog, single_select)
+    /// Git fetch specific branches (Select dialog, multi-select)
     GitFetchBranch,
+    /// Git fetch by branch name/glob patterns (Input dialog)
+    GitFetchBranchPattern,
+    /// Review revisions that arrived with a fetch (Confirm dialog)
+    GitFetchReviewIncoming { revset: String },
+    /// Remote bookmarks a fetch renamed (Select dialog; values are indices)
+    ReconcileBookmarks { renames: Vec<BookmarkRename> },
+    /// Bookmarks left on rewritten commits (Select dialog; values are indices)
+    RetargetBookmarks { retargets: Vec<BookmarkRetarget> },
     /// Git push by change ID (creates auto bookmark)
     GitPushChange {
         /// Change ID to push
@@ -70,10 +93,86 @@ pub enum DialogCallback {
     BookmarkMoveBackwards { name: String },
     /// Restore a single file (Confirm dialog)
     RestoreFile { file_path: String },
-    /// Restore all files (Confirm dialog)
+    /// Restore a file's changes in a revision from the Diff View file menu (Confirm dialog)
+    DiffRestoreFile { revision: String, file_path: String },
+    /// Diff View file menu (Select dialog, single_select)
+    DiffFileMenu {
+        file_path: String,
+        /// Set for single-revision diffs; enables blame/restore/squash
+        revision: Option<String>,
+    },
+    /// Diff View file picker (Select dialog, single_select)
+    DiffJumpToFile,
+    /// Restore all files (Confirm dialog with a preview of what is discarded)
     RestoreAll,
+    /// Where to put a cherry-picked copy of a revision (Select dialog, single_select)
+    CherryPick { revision: String },
+    /// How descriptions combine in `jj squash --from --into` (Select dialog,
+    /// single_select); the descriptions are the ones the choices were built from
+    SquashMessage {
+        source: String,
+        destination: String,
+        source_desc: String,
+        destination_desc: String,
+    },
+    /// Files of `source` to squash into `destination` (Select dialog);
+    /// `renamed_from` maps renamed paths to their old path
+    SquashFiles {
+        source: String,
+        destination: String,
+        total: usize,
+        renamed_from: Vec<(String, String)>,
+    },
+    /// Files of `revision` kept in the first half of a split (Select
+    /// dialog); `renamed_from` maps renamed paths to their old path
+    SplitFiles {
+        revision: String,
+        total: usize,
+        renamed_from: Vec<(String, String)>,
+    },
+    /// Local note on a change (Input dialog), by full change ID
+    EditNote { change_id: String },
+    /// Abandon a change (Confirm dialog with a preview)
+    Abandon { revision: String },
+    /// Rebase from the Log View (Confirm dialog with a preview)
+    Rebase {
+        source: String,
+        destination: String,
+        mode: RebaseMode,
+        skip_emptied: bool,
+        simplify_parents: bool,
+        use_revset: bool,
+    },
+    /// Typed rebase destination (Input dialog with bookmark/tag completion)
+    RebaseDestination {
+        source: String,
+        mode: RebaseMode,
+        skip_emptied: bool,
+        simplify_parents: bool,
+        use_revset: bool,
+    },
+    /// Config handling for the bug report bundle (Select dialog, single_select)
+    BugReport,
+    /// Startup config problems; the value is the fix command (single select)
+    ConfigCheck,
+    /// How to add a file to .gitignore (Select dialog, single_select; path
+    /// relative to the workspace root)
+    GitignoreAdd { file_path: String },
+    /// Pick hunks/lines of a file to discard (Select dialog)
+    HunkRestoreSelect { file_path: String },
+    /// Discard the picked lines (Confirm dialog; picks into the file's hunks)
+    HunkRestore {
+        file_path: String,
+        lines: Vec<LinePick>,
+    },
+    /// Pick hunks/lines of a file to move into @- (Select dialog)
+    HunkSquashSelect { file_path: String },
+    /// Restore a hidden commit's content into @ (Confirm dialog)
+    RestoreHidden { commit_id: String },
     /// Revert a change (Confirm dialog, creates reverse-diff commit)
     Revert { revision: String },
+    /// Revert every revision in `revset` in one operation (Confirm dialog)
+    RevertRange { revset: String, count: usize },
     /// Simplify parents (Confirm dialog, removes redundant parent edges)
     SimplifyParents { revision: String },
     /// Parallelize commits (Confirm dialog, converts linear chain to siblings)
@@ -86,6 +185,8 @@ pub enum DialogCallback {
         /// Bookmarks associated with the change
         bookmarks: Vec<String>,
     },
+    /// Push queue review: checked bookmarks are pushed together (Select dialog)
+    PushQueue,
     /// Mode selection for multi-bookmark push (Single Select)
     /// User chooses between --revisions (all) or individual bookmark selection
     GitPushMultiBookmarkMode {
@@ -102,6 +203,36 @@ pub enum DialogCallback {
     WorkspaceForget { name: String },
     /// Workspace rename (Input dialog for new name)
     WorkspaceRename { old_name: String },
+    /// Pull request menu for a pushed bookmark (single select)
+    PullRequest { bookmark: String, web_url: String },
+    /// Merge helper: first parent (single select)
+    MergeTarget { change_id: String },
+    /// Merge helper: parents merged into `target` (Select dialog)
+    MergeSources { change_id: String, target: String },
+    /// Merge helper: description (Input dialog, empty keeps the default)
+    MergeDescribe {
+        parents: Vec<String>,
+        default_message: String,
+    },
+    /// New change from the Log View's marked parents (single select:
+    /// create, or create and describe)
+    MergeMarked { parents: Vec<String> },
+    /// Remote add (Input dialog for `<name> <url>`)
+    RemoteAdd,
+    /// Remote remove (Confirm dialog)
+    RemoteRemove { name: String },
+    /// Remote rename (Input dialog for new name)
+    RemoteRename { old_name: String },
+    /// Remote URL change (Input dialog for new URL)
+    RemoteSetUrl { name: String },
+    /// Fetch after a push was cancelled mid-way (Confirm dialog)
+    FetchAfterCancelledPush,
+    /// Read-only jj command line (Input dialog, `:` in Log View)
+    JjCommand,
+    /// Sparse pattern to stage (Input dialog)
+    SparseAdd,
+    /// Apply sparse patterns after the file count preview (Confirm dialog)
+    SparseApply { patterns: Vec<String> },
     /// Bisect run (Input dialog for command entry)
     BisectRun { good: String, bad: String },
     /// Metaedit operation selection (Select dialog, single_select)
@@ -119,6 +250,29 @@ pub enum DialogCallback {
         commit_id: String,
         change_id: String,
     },
+    /// Copy to clipboard (Select dialog, single_select)
+    Yank {
+        /// `(what, text)` pairs offered in the menu; item value is the index
+        entries: Vec<(String, String)>,
+        /// Revision whose full description / `jj show` output is offered
+        revision: Option<String>,
+    },
+    /// Revset preset menu: apply a preset or save the current revset (Select dialog, single_select)
+    RevsetPresetMenu,
+    /// Name for a new revset preset (Input dialog)
+    RevsetPresetSave { revset: String },
+    /// Saved compare sessions (Select dialog, single_select; value is the name)
+    CompareSessionMenu,
+    /// Open / export / delete a compare session (Select dialog, single_select)
+    CompareSessionAction {
+        name: String,
+        from: String,
+        to: String,
+    },
+    /// Name for a new compare session (Input dialog)
+    CompareSessionSave { from: String, to: String },
+    /// Delete a compare session (Confirm dialog)
+    CompareSessionDelete { name: String },
 }
 
 /// Selection item for Select dialog
@@ -132,6 +286,31 @@ pub struct SelectItem {
     pub selected: bool,
 }
 
+/// Outcome of one item of a batch operation (Results dialog row)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResult {
+    /// Item name (bookmark, revision, ...)
+    pub item: String,
+    /// Error text if the item failed
+    pub error: Option<String>,
+}
+
+impl BatchResult {
+    pub fn ok(item: impl Into<String>) -> Self {
+        Self {
+            item: item.into(),
+            error: None,
+        }
+    }
+
+    pub fn failed(item: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            item: item.into(),
+            error: Some(error.into()),
+        }
+    }
+}
+
 /// Dialog kind and content
 #[derive(Debug, Clone)]
 pub enum DialogKind {
@@ -156,7 +335,15 @@ pub enum DialogKind {
     Input {
         title: String,
         message: String,
-        buffer: String,
+        buffer: LineInput,
+        /// Tab-completion candidates for the word under the cursor (may be empty)
+        completions: Vec<String>,
+    },
+    /// Read-only table of batch results (closed with Enter/Esc)
+    Results {
+        title: String,
+        message: String,
+        rows: Vec<BatchResult>,
     },
 }
 
@@ -178,6 +365,8 @@ pub struct Dialog {
     pub cursor: usize,
     /// Callback identifier
     pub callback_id: DialogCallback,
+    /// Type-ahead prefix for jumping to Select items by label
+    type_ahead: TypeAhead,
 }
 
 impl Dialog {
@@ -196,6 +385,7 @@ impl Dialog {
             },
             cursor: 0,
             callback_id,
+            type_ahead: TypeAhead::default(),
         }
     }
 
@@ -217,6 +407,7 @@ impl Dialog {
             },
             cursor: 0,
             callback_id,
+            type_ahead: TypeAhead::default(),
         }
     }
 
@@ -238,6 +429,7 @@ impl Dialog {
             },
             cursor: 0,
             callback_id,
+            type_ahead: TypeAhead::default(),
         }
     }
 
@@ -246,15 +438,59 @@ impl Dialog {
         title: impl Into<String>,
         message: impl Into<String>,
         callback_id: DialogCallback,
+    ) -> Self {
+        Self::input_with_completions(title, message, Vec::new(), callback_id)
+    }
+
+    /// Create an Input dialog whose last word Tab-completes from `completions`
+    pub fn input_with_completions(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        completions: Vec<String>,
+        callback_id: DialogCallback,
     ) -> Self {
         Self {
             kind: DialogKind::Input {
                 title: title.into(),
                 message: message.into(),
-                buffer: String::new(),
+                buffer: LineInput::default(),
+                completions,
             },
             cursor: 0,
             callback_id,
+            type_ahead: TypeAhead::default(),
+        }
+    }
+
+    /// Create an Input dialog pre-filled with `text` (cursor at the end)
+    pub fn input_with_text(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        text: &str,
+        callback_id: DialogCallback,
+    ) -> Self {
+        let mut dialog = Self::input(title, message, callback_id);
+        if let DialogKind::Input { buffer, .. } = &mut dialog.kind {
+            *buffer = LineInput::new(text);
+        }
+        dialog
+    }
+
+    /// Create a Results dialog listing each item's success or failure
+    pub fn results(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        rows: Vec<BatchResult>,
+    ) -> Self {
+        Self {
+            kind: DialogKind::Results {
+                title: title.into(),
+                message: message.into(),
+                rows,
+            },
+            cursor: 0,
+            callback_id: DialogCallback::BatchResults,
+            type_ahead: TypeAhead::default(),
         }
     }
 
@@ -264,6 +500,7 @@ impl Dialog {
             DialogKind::Confirm { .. } => self.handle_confirm_key(key),
             DialogKind::Select { .. } => self.handle_select_key(key),
             DialogKind::Input { .. } => self.handle_input_key(key),
+            DialogKind::Results { .. } => self.handle_results_key(key),
         }
     }
 
@@ -294,7 +531,13 @@ impl Dialog {
                 title,
                 message,
                 buffer,
-            } => self.render_input(frame, area, title, message, buffer),
+                completions,
+            } => self.render_input(frame, area, title, message, buffer, completions),
+            DialogKind::Results {
+                title,
+                message,
+                rows,
+            } => self.render_results(frame, area, title, message, rows),
         }
     }
 }
@@ -0,0 +1,97 @@
+//! Results dialog: per-item outcome of a batch operation
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use super::{BatchResult, Dialog, DialogKind, DialogResult, centered_rect, keys};
+
+/// "2 of 3 succeeded" line above the table
+pub(super) fn results_summary(rows: &[BatchResult]) -> String {
+    let ok = rows.iter().filter(|row| row.error.is_none()).count();
+    format!("{} of {} succeeded", ok, rows.len())
+}
+
+impl Dialog {
+    pub(super) fn handle_results_key(&mut self, key: KeyEvent) -> Option<DialogResult> {
+        let DialogKind::Results { rows, .. } = &self.kind else {
+            return None;
+        };
+        match key.code {
+            k if keys::is_move_down(k) => {
+                if self.cursor < rows.len().saturating_sub(1) {
+                    self.cursor += 1;
+                }
+                None
+            }
+            k if keys::is_move_up(k) => {
+                self.cursor = self.cursor.saturating_sub(1);
+                None
+            }
+            KeyCode::Enter => Some(DialogResult::Confirmed(vec![])),
+            KeyCode::Esc | KeyCode::Char('q') => Some(DialogResult::Cancelled),
+            _ => None,
+        }
+    }
+
+    pub(super) fn render_results(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        title: &str,
+        message: &str,
+        rows: &[BatchResult],
+    ) {
+        let width = 76.min(area.width.saturating_sub(4));
+        let height = (rows.len() as u16 + 7).min(area.height.saturating_sub(4));
+        let dialog_area = centered_rect(width, height, area);
+        frame.render_widget(Clear, dialog_area);
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                message,
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(
+                results_summary(rows),
+                Style::default().fg(Color::Cyan),
+            )),
+            Line::from(""),
+        ];
+        // Rows above the cursor scroll away; errors wrap instead of truncating
+        for (i, row) in rows.iter().enumerate().skip(self.cursor) {
+            let marker = if i == self.cursor { "> " } else { "  " };
+            let line = match &row.error {
+                None => Line::from(vec![
+                    Span::raw(marker),
+                    Span::styled("ok     ", Style::default().fg(Color::Green)),
+                    Span::raw(row.item.clone()),
+                ]),
+                Some(error) => Line::from(vec![
+                    Span::raw(marker),
+                    Span::styled(
+                        "failed ",
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(format!("{}: ", row.item)),
+                    Span::styled(error.clone(), Style::default().fg(Color::Yellow)),
+                ]),
+            };
+            lines.push(line);
+        }
+
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .title(format!(" {} ", title))
+                .title_bottom(" j/k Scroll  Enter/Esc Close ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        );
+        frame.render_widget(paragraph, dialog_area);
+    }
+}
@@ -1,6 +1,8 @@
 //! Select dialog input handling and rendering
 
-use crossterm::event::KeyCode;
+use std::time::Instant;
+
+use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     Frame,
     layout::Rect,
@@ -10,6 +12,7 @@ use ratatui::{
 };
 
 use super::{Dialog, DialogKind, DialogResult, SelectItem, centered_rect, keys};
+use crate::ui::components::type_ahead;
 
 impl Dialog {
     pub(super) fn handle_select_key(
@@ -74,6 +77,19 @@ impl Dialog {
             }
             // Cancel
             KeyCode::Esc | KeyCode::Char('q') => Some(DialogResult::Cancelled),
+            // Type-ahead: jump to the next item whose label starts with the typed letters
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                let query = self.type_ahead.push(c, Instant::now());
+                let labels = items.iter().map(|item| item.label.as_str());
+                if let Some(index) = type_ahead::find_match(labels, self.cursor, query) {
+                    self.cursor = index;
+                }
+                None
+            }
             _ => None,
         }
     }
@@ -89,9 +105,15 @@ impl Dialog {
         detail: Option<&str>,
         single_select: bool,
     ) {
-        // Calculate dialog size (add 2 lines if detail is present)
-        let width = 50.min(area.width.saturating_sub(4));
-        let detail_lines = if detail.is_some() { 2 } else { 0 };
+        // Calculate dialog size (detail lines plus a blank line; widened to fit them)
+        let detail_width = detail
+            .into_iter()
+            .flat_map(|d| d.lines())
+            .map(|line| line.chars().count() as u16 + 4)
+            .max()
+            .unwrap_or(0);
+        let width = 50.max(detail_width).min(area.width.saturating_sub(4));
+        let detail_lines = detail.map_or(0, |d| d.lines().count() as u16 + 1);
         let height = (items.len() as u16 + 6 + detail_lines).min(area.height.saturating_sub(4));
 
         let dialog_area = centered_rect(width, height, area);
@@ -134,10 +156,12 @@ impl Dialog {
 
         // Add optional detail text
         if let Some(detail_text) = detail {
-            lines.push(Line::from(Span::styled(
-                detail_text,
-                Style::default().fg(Color::Yellow),
-            )));
+            for detail_line in detail_text.lines() {
+                lines.push(Line::from(Span::styled(
+                    detail_line,
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
             lines.push(Line::from(""));
         }
 
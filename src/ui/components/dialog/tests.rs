@@ -379,3 +379,155 @@ fn test_single_select_dialog_cancel() {
         Some(DialogResult::Cancelled)
     );
 }
+
+// ─────────────────────────────────────────────────────────────────────────
+// Type-ahead in select dialogs
+// ─────────────────────────────────────────────────────────────────────────
+
+fn labelled_items(labels: &[&str]) -> Vec<SelectItem> {
+    labels
+        .iter()
+        .map(|label| SelectItem {
+            label: label.to_string(),
+            value: label.to_string(),
+            selected: false,
+        })
+        .collect()
+}
+
+#[test]
+fn test_select_dialog_type_ahead_moves_cursor() {
+    let mut dialog = Dialog::select_single(
+        "Test",
+        "Select one",
+        labelled_items(&["feature", "main", "release", "master"]),
+        None,
+        DialogCallback::BookmarkJump,
+    );
+
+    assert!(dialog.handle_key(key(KeyCode::Char('r'))).is_none());
+    assert_eq!(dialog.cursor, 2);
+    // Extending the prefix within the timeout narrows the match
+    dialog.handle_key(key(KeyCode::Char('m')));
+    assert_eq!(dialog.cursor, 2, "\"rm\" matches nothing");
+}
+
+#[test]
+fn test_select_dialog_type_ahead_keeps_bound_keys() {
+    let mut dialog = Dialog::select(
+        "Test",
+        "Select items",
+        labelled_items(&["jump", "keep", "space"]),
+        None,
+        DialogCallback::DeleteBookmarks,
+    );
+
+    // j/k still navigate and q still cancels
+    dialog.handle_key(key(KeyCode::Char('j')));
+    assert_eq!(dialog.cursor, 1);
+    assert_eq!(
+        dialog.handle_key(key(KeyCode::Char('q'))),
+        Some(DialogResult::Cancelled)
+    );
+}
+
+#[test]
+fn test_select_dialog_type_ahead_ignores_ctrl() {
+    let mut dialog = Dialog::select_single(
+        "Test",
+        "Select one",
+        labelled_items(&["alpha", "beta"]),
+        None,
+        DialogCallback::BookmarkJump,
+    );
+
+    dialog.handle_key(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL));
+    assert_eq!(dialog.cursor, 0);
+    dialog.handle_key(key(KeyCode::Char('b')));
+    assert_eq!(dialog.cursor, 1);
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Input dialog completion
+// ─────────────────────────────────────────────────────────────────────────
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn test_complete_extends_to_common_prefix() {
+    let candidates = names(&["feature-a", "feature-b", "main"]);
+    assert_eq!(
+        input::complete("fea", &candidates),
+        Some("feature-".to_string())
+    );
+    assert_eq!(input::complete("feature-", &candidates), None);
+    assert_eq!(input::complete("ma", &candidates), Some("main".to_string()));
+    assert_eq!(input::complete("xyz", &candidates), None);
+}
+
+#[test]
+fn test_complete_only_touches_last_word_of_revset() {
+    let candidates = names(&["main@origin", "release"]);
+    assert_eq!(
+        input::complete("trunk()..rel", &candidates),
+        Some("trunk()..release".to_string())
+    );
+    assert_eq!(
+        input::complete("main@o", &candidates),
+        Some("main@origin".to_string())
+    );
+}
+
+#[test]
+fn test_input_dialog_tab_completes() {
+    let mut dialog = Dialog::input_with_completions(
+        "Test",
+        "Destination",
+        names(&["main", "maint-1"]),
+        DialogCallback::JjCommand,
+    );
+    dialog.handle_key(key(KeyCode::Char('m')));
+    assert!(dialog.handle_key(key(KeyCode::Tab)).is_none());
+    assert_eq!(
+        dialog.handle_key(key(KeyCode::Enter)),
+        Some(DialogResult::Confirmed(vec!["main".to_string()]))
+    );
+}
+
+#[test]
+fn test_results_dialog_scrolls_and_closes() {
+    let rows = vec![
+        BatchResult::ok("main"),
+        BatchResult::failed("feature", "rejected"),
+    ];
+    let mut dialog = Dialog::results("Push Results", "Push", rows);
+    assert_eq!(dialog.callback_id, DialogCallback::BatchResults);
+
+    assert_eq!(dialog.handle_key(key(KeyCode::Char('j'))), None);
+    assert_eq!(dialog.cursor, 1);
+    assert_eq!(dialog.handle_key(key(KeyCode::Char('j'))), None);
+    assert_eq!(dialog.cursor, 1);
+    assert_eq!(dialog.handle_key(key(KeyCode::Char('y'))), None);
+
+    let mut d = dialog.clone();
+    assert_eq!(
+        d.handle_key(key(KeyCode::Enter)),
+        Some(DialogResult::Confirmed(vec![]))
+    );
+    assert_eq!(
+        dialog.handle_key(key(KeyCode::Esc)),
+        Some(DialogResult::Cancelled)
+    );
+}
+
+#[test]
+fn test_results_summary_counts_successes() {
+    let rows = vec![
+        BatchResult::ok("main"),
+        BatchResult::failed("feature", "rejected"),
+        BatchResult::ok("docs"),
+    ];
+    assert_eq!(results::results_summary(&rows), "2 of 3 succeeded");
+}
@@ -0,0 +1,357 @@
+//! Single-line text input with readline-style editing
+//!
+//! Used by every one-line prompt (search, revset, bookmark name, dialog
+//! input, filters). Besides typing and Backspace it supports:
+//!
+//! - Left/Right, Home/End, Ctrl+A/Ctrl+E: move the cursor
+//! - Alt+B/Alt+F: move a word back/forward
+//! - Ctrl+W: kill the word before the cursor
+//! - Ctrl+U/Ctrl+K: kill to the start/end of the line
+//! - Ctrl+Y: yank the latest kill
+//! - Alt+Y: right after a yank, replace it with the next older kill
+//!
+//! Killed text goes to a kill ring shared by all inputs. Text copied with
+//! the clipboard helper (`y` menus) is added to the same ring, so it can be
+//! yanked into any prompt.
+
+use std::cell::RefCell;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    style::{Modifier, Style},
+    text::Span,
+};
+
+/// Entries kept in the kill ring
+const KILL_RING_SIZE: usize = 16;
+
+thread_local! {
+    /// Killed or copied text, newest last
+    ///
+    /// Per thread: the UI runs on one thread, and parallel tests don't see
+    /// each other's kills.
+    static KILL_RING: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Add `text` to the kill ring (empty text is ignored)
+pub fn remember_kill(text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    KILL_RING.with_borrow_mut(|ring| {
+        ring.retain(|entry| entry != text);
+        ring.push(text.to_string());
+        if ring.len() > KILL_RING_SIZE {
+            ring.remove(0);
+        }
+    });
+}
+
+/// Kill ring entry `depth` steps back from the newest, wrapping around
+fn kill_at(depth: usize) -> Option<String> {
+    KILL_RING.with_borrow(|ring| {
+        (!ring.is_empty()).then(|| ring[ring.len() - 1 - depth % ring.len()].clone())
+    })
+}
+
+/// Text inserted by the last yank, which Alt+Y may replace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Yank {
+    /// Char range of the inserted text
+    start: usize,
+    end: usize,
+    /// Kill ring entries back from the newest
+    depth: usize,
+}
+
+/// Editable single-line text with a cursor
+///
+/// The cursor is a character index (not a byte offset).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineInput {
+    text: String,
+    cursor: usize,
+    /// Set only while the previous key was Ctrl+Y or Alt+Y
+    last_yank: Option<Yank>,
+}
+
+impl LineInput {
+    /// Create an input holding `text`, with the cursor at the end
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        let cursor = text.chars().count();
+        Self {
+            text,
+            cursor,
+            last_yank: None,
+        }
+    }
+
+    /// Current text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Cursor position in characters
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Clear the text
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    /// Take the text, leaving the input empty
+    pub fn take(&mut self) -> String {
+        self.cursor = 0;
+        std::mem::take(&mut self.text)
+    }
+
+    /// Handle an editing key; returns false for keys it doesn't use
+    ///
+    /// Enter and Esc are never consumed, so callers handle them first or
+    /// after as they like.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let last_yank = self.last_yank.take();
+        match key.code {
+            KeyCode::Char(c) if !ctrl && !alt => self.insert_str(&c.to_string()),
+            KeyCode::Char('a') if ctrl => self.cursor = 0,
+            KeyCode::Char('e') if ctrl => self.cursor = self.len(),
+            KeyCode::Char('b') if alt => self.cursor = self.word_start_before(),
+            KeyCode::Char('f') if alt => self.cursor = self.word_end_after(),
+            KeyCode::Char('w') if ctrl => self.kill(self.word_start_before(), self.cursor),
+            KeyCode::Char('u') if ctrl => self.kill(0, self.cursor),
+            KeyCode::Char('k') if ctrl => self.kill(self.cursor, self.len()),
+            KeyCode::Char('y') if ctrl => self.yank(0),
+            KeyCode::Char('y') if alt => {
+                if let Some(yank) = last_yank {
+                    self.remove(yank.start, yank.end);
+                    self.yank(yank.depth + 1);
+                }
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.len(),
+            KeyCode::Backspace => {
+                if self.cursor > 0 {
+                    self.remove(self.cursor - 1, self.cursor);
+                }
+            }
+            KeyCode::Delete => {
+                if self.cursor < self.len() {
+                    self.remove(self.cursor, self.cursor + 1);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// `prompt` followed by the text, fitted to `width` columns
+    ///
+    /// Returns the text to draw and the cursor column within it. When the
+    /// line is too long it scrolls so the cursor stays visible, with `…`
+    /// marking the hidden start.
+    pub fn display(&self, prompt: &str, width: usize) -> (String, usize) {
+        let chars: Vec<char> = prompt.chars().chain(self.text.chars()).collect();
+        let cursor = prompt.chars().count() + self.cursor;
+        if width == 0 {
+            return (String::new(), 0);
+        }
+        if cursor < width {
+            return (chars.iter().take(width).collect(), cursor);
+        }
+        let start = cursor + 2 - width;
+        let shown = std::iter::once('…')
+            .chain(chars.iter().skip(start).take(width - 1).copied())
+            .collect();
+        (shown, cursor + 1 - start)
+    }
+
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_offset(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.text.len(), |(i, _)| i)
+    }
+
+    fn insert_str(&mut self, s: &str) {
+        let at = self.byte_offset(self.cursor);
+        self.text.insert_str(at, s);
+        self.cursor += s.chars().count();
+    }
+
+    /// Remove chars `from..to` and put the cursor at `from`
+    fn remove(&mut self, from: usize, to: usize) -> String {
+        let range = self.byte_offset(from)..self.byte_offset(to);
+        self.cursor = from;
+        self.text.drain(range).collect()
+    }
+
+    fn kill(&mut self, from: usize, to: usize) {
+        let killed = self.remove(from, to);
+        remember_kill(&killed);
+    }
+
+    /// Insert the kill ring entry `depth` back from the newest
+    fn yank(&mut self, depth: usize) {
+        if let Some(text) = kill_at(depth) {
+            let start = self.cursor;
+            self.insert_str(&text);
+            self.last_yank = Some(Yank {
+                start,
+                end: self.cursor,
+                depth,
+            });
+        }
+    }
+
+    /// Start of the word before the cursor (skipping separators first)
+    fn word_start_before(&self) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = self.cursor;
+        while i > 0 && !is_word_char(chars[i - 1]) {
+            i -= 1;
+        }
+        while i > 0 && is_word_char(chars[i - 1]) {
+            i -= 1;
+        }
+        i
+    }
+
+    /// End of the word after the cursor (skipping separators first)
+    fn word_end_after(&self) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = self.cursor;
+        while i < chars.len() && !is_word_char(chars[i]) {
+            i += 1;
+        }
+        while i < chars.len() && is_word_char(chars[i]) {
+            i += 1;
+        }
+        i
+    }
+}
+
+impl PartialEq<&str> for LineInput {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Spans for `text` with the cursor at char column `col`
+///
+/// The character under the cursor is drawn reversed; past the end of the
+/// text `end_marker` is drawn instead.
+pub fn cursor_spans(
+    text: &str,
+    col: usize,
+    style: Style,
+    end_marker: Span<'static>,
+) -> Vec<Span<'static>> {
+    let before: String = text.chars().take(col).collect();
+    let mut rest = text.chars().skip(col);
+    match rest.next() {
+        Some(under) => vec![
+            Span::styled(before, style),
+            Span::styled(under.to_string(), style.add_modifier(Modifier::REVERSED)),
+            Span::styled(rest.collect::<String>(), style),
+        ],
+        None => vec![Span::styled(before, style), end_marker],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(input: &mut LineInput, code: KeyCode, modifiers: KeyModifiers) {
+        input.handle_key(KeyEvent::new(code, modifiers));
+    }
+
+    #[test]
+    fn test_insert_and_move() {
+        let mut input = LineInput::new("ac");
+        press(&mut input, KeyCode::Left, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Char('b'), KeyModifiers::NONE);
+        assert_eq!(input, "abc");
+        press(&mut input, KeyCode::Char('a'), KeyModifiers::CONTROL);
+        press(&mut input, KeyCode::Char('ü'), KeyModifiers::NONE);
+        assert_eq!(input, "üabc");
+        press(&mut input, KeyCode::Char('e'), KeyModifiers::CONTROL);
+        press(&mut input, KeyCode::Backspace, KeyModifiers::NONE);
+        assert_eq!(input, "üab");
+        assert_eq!(input.cursor(), 3);
+    }
+
+    #[test]
+    fn test_word_movement() {
+        let mut input = LineInput::new("author(alice) & mine()");
+        press(&mut input, KeyCode::Char('b'), KeyModifiers::ALT);
+        assert_eq!(input.cursor(), 16);
+        press(&mut input, KeyCode::Char('b'), KeyModifiers::ALT);
+        assert_eq!(input.cursor(), 7);
+        press(&mut input, KeyCode::Char('f'), KeyModifiers::ALT);
+        assert_eq!(input.cursor(), 12);
+    }
+
+    #[test]
+    fn test_kill_and_yank() {
+        let mut input = LineInput::new("log -r main");
+        press(&mut input, KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(input, "log -r ");
+        press(&mut input, KeyCode::Char('y'), KeyModifiers::CONTROL);
+        assert_eq!(input, "log -r main");
+        press(&mut input, KeyCode::Char('a'), KeyModifiers::CONTROL);
+        press(&mut input, KeyCode::Char('k'), KeyModifiers::CONTROL);
+        assert!(input.is_empty());
+        press(&mut input, KeyCode::Char('y'), KeyModifiers::CONTROL);
+        press(&mut input, KeyCode::Char('u'), KeyModifiers::CONTROL);
+        assert!(input.is_empty());
+        assert_eq!(kill_at(0).as_deref(), Some("log -r main"));
+    }
+
+    #[test]
+    fn test_yank_pop_cycles_older_kills() {
+        remember_kill("first");
+        remember_kill("second");
+        let mut input = LineInput::new("x ");
+        press(&mut input, KeyCode::Char('y'), KeyModifiers::CONTROL);
+        assert_eq!(input, "x second");
+        press(&mut input, KeyCode::Char('y'), KeyModifiers::ALT);
+        assert_eq!(input, "x first");
+        assert_eq!(input.cursor(), 7);
+
+        // Only right after a yank
+        press(&mut input, KeyCode::Left, KeyModifiers::NONE);
+        press(&mut input, KeyCode::Char('y'), KeyModifiers::ALT);
+        assert_eq!(input, "x first");
+    }
+
+    #[test]
+    fn test_display_scrolls_to_cursor() {
+        let input = LineInput::new("abcdefgh");
+        assert_eq!(input.display("> ", 20), ("> abcdefgh".to_string(), 10));
+        assert_eq!(input.display("> ", 6), ("…efgh".to_string(), 5));
+        let mut input = input;
+        press(&mut input, KeyCode::Home, KeyModifiers::NONE);
+        assert_eq!(input.display("> ", 6), ("> abcd".to_string(), 2));
+    }
+}
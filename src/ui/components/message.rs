@@ -21,6 +21,19 @@ pub fn build_error_line(error: &str) -> Line<'static> {
     ])
 }
 
+/// Build a suggestion line for the banner above the status bar
+///
+/// Format: `[cyan bg] Tip: [/cyan bg][cyan text] message [/cyan text]`
+pub fn build_suggestion_line(suggestion: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(" Tip: ", Style::default().fg(Color::Black).bg(Color::Cyan)),
+        Span::styled(
+            format!(" {} ", suggestion),
+            Style::default().fg(Color::Cyan),
+        ),
+    ])
+}
+
 /// Build a notification line for title bar display
 ///
 /// If `max_width` is provided and the notification is too long,
@@ -3,11 +3,21 @@
 //! Common building blocks for views.
 
 pub mod blocks;
+pub mod compact;
 pub mod dialog;
 pub mod empty_state;
+pub mod line_input;
 pub mod message;
+pub mod split;
+pub mod text_area;
+pub mod type_ahead;
 
 pub use blocks::*;
+pub use compact::CompactLayout;
 pub use dialog::*;
 pub use empty_state::*;
+pub use line_input::{LineInput, cursor_spans};
 pub use message::*;
+pub use split::{SplitDirection, SplitLayout};
+pub use text_area::{TextArea, TextAreaEvent};
+pub use type_ahead::TypeAhead;
@@ -0,0 +1,174 @@
+//! Resizable two-pane split
+//!
+//! Shared by views with a main pane and a secondary (preview) pane: the
+//! secondary pane sits either below or to the right of the main one, and
+//! the main pane's share of the area is adjustable in fixed steps.
+
+use ratatui::layout::{Constraint, Layout, Rect};
+
+/// Where the secondary pane sits relative to the main pane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitDirection {
+    /// Secondary pane below the main pane
+    #[default]
+    Bottom,
+    /// Secondary pane to the right of the main pane
+    Right,
+}
+
+impl SplitDirection {
+    /// Config value (`"bottom"` / `"right"`)
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Bottom => "bottom",
+            Self::Right => "right",
+        }
+    }
+
+    /// Parse a config value; `None` for anything else
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "bottom" => Some(Self::Bottom),
+            "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Direction and size of a two-pane split
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitLayout {
+    pub direction: SplitDirection,
+    /// Main pane share of the area, in percent
+    ratio: u16,
+}
+
+impl Default for SplitLayout {
+    fn default() -> Self {
+        Self {
+            direction: SplitDirection::Bottom,
+            ratio: Self::DEFAULT_RATIO,
+        }
+    }
+}
+
+impl SplitLayout {
+    pub const DEFAULT_RATIO: u16 = 50;
+    pub const MIN_RATIO: u16 = 20;
+    pub const MAX_RATIO: u16 = 80;
+    /// Percent moved by one resize key press
+    pub const STEP: u16 = 5;
+    /// Below this height a bottom split leaves too little room for either pane
+    pub const MIN_HEIGHT_BOTTOM: u16 = 20;
+    /// Below this width a right split leaves too little room for either pane
+    pub const MIN_WIDTH_RIGHT: u16 = 100;
+
+    /// Create a layout; the ratio is clamped to the allowed range
+    pub fn new(direction: SplitDirection, ratio: u16) -> Self {
+        Self {
+            direction,
+            ratio: ratio.clamp(Self::MIN_RATIO, Self::MAX_RATIO),
+        }
+    }
+
+    /// Main pane share of the area, in percent
+    pub fn ratio(&self) -> u16 {
+        self.ratio
+    }
+
+    /// Switch between bottom and right placement
+    pub fn toggle_direction(&mut self) {
+        self.direction = match self.direction {
+            SplitDirection::Bottom => SplitDirection::Right,
+            SplitDirection::Right => SplitDirection::Bottom,
+        };
+    }
+
+    /// Give the main pane one step more room (returns false at the limit)
+    pub fn grow(&mut self) -> bool {
+        self.resize(self.ratio.saturating_add(Self::STEP))
+    }
+
+    /// Give the main pane one step less room (returns false at the limit)
+    pub fn shrink(&mut self) -> bool {
+        self.resize(self.ratio.saturating_sub(Self::STEP))
+    }
+
+    fn resize(&mut self, ratio: u16) -> bool {
+        let ratio = ratio.clamp(Self::MIN_RATIO, Self::MAX_RATIO);
+        let changed = ratio != self.ratio;
+        self.ratio = ratio;
+        changed
+    }
+
+    /// Whether `area` is large enough to show both panes
+    pub fn fits(&self, area: Rect) -> bool {
+        match self.direction {
+            SplitDirection::Bottom => area.height >= Self::MIN_HEIGHT_BOTTOM,
+            SplitDirection::Right => area.width >= Self::MIN_WIDTH_RIGHT,
+        }
+    }
+
+    /// Split `area` into (main, secondary)
+    pub fn split(&self, area: Rect) -> (Rect, Rect) {
+        let constraints = [
+            Constraint::Percentage(self.ratio),
+            Constraint::Percentage(100 - self.ratio),
+        ];
+        let chunks = match self.direction {
+            SplitDirection::Bottom => Layout::vertical(constraints).split(area),
+            SplitDirection::Right => Layout::horizontal(constraints).split(area),
+        };
+        (chunks[0], chunks[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_is_clamped() {
+        let mut layout = SplitLayout::new(SplitDirection::Bottom, 75);
+        assert!(layout.grow());
+        assert_eq!(layout.ratio(), 80);
+        assert!(!layout.grow());
+        assert_eq!(SplitLayout::new(SplitDirection::Right, 5).ratio(), 20);
+        let mut layout = SplitLayout::new(SplitDirection::Right, 20);
+        assert!(!layout.shrink());
+    }
+
+    #[test]
+    fn test_split_follows_direction_and_ratio() {
+        let area = Rect::new(0, 0, 100, 40);
+        let mut layout = SplitLayout::new(SplitDirection::Bottom, 75);
+        let (main, secondary) = layout.split(area);
+        assert_eq!((main.height, secondary.height), (30, 10));
+        assert_eq!(main.width, 100);
+
+        layout.toggle_direction();
+        let (main, secondary) = layout.split(area);
+        assert_eq!((main.width, secondary.width), (75, 25));
+        assert_eq!(secondary.x, 75);
+    }
+
+    #[test]
+    fn test_fits_depends_on_direction() {
+        let bottom = SplitLayout::default();
+        let right = SplitLayout::new(SplitDirection::Right, 50);
+        let tall_narrow = Rect::new(0, 0, 80, 40);
+        let short_wide = Rect::new(0, 0, 160, 15);
+        assert!(bottom.fits(tall_narrow));
+        assert!(!right.fits(tall_narrow));
+        assert!(!bottom.fits(short_wide));
+        assert!(right.fits(short_wide));
+    }
+
+    #[test]
+    fn test_direction_config_round_trip() {
+        for direction in [SplitDirection::Bottom, SplitDirection::Right] {
+            assert_eq!(SplitDirection::parse(direction.as_str()), Some(direction));
+        }
+        assert_eq!(SplitDirection::parse("left"), None);
+    }
+}
@@ -0,0 +1,319 @@
+//! Multi-line text editor
+//!
+//! Used for descriptions and commit messages so short multi-line messages
+//! can be written without suspending to an external editor. Long lines are
+//! soft-wrapped at the character level; Enter inserts a newline, Ctrl+S
+//! saves and Esc cancels.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::Paragraph,
+};
+
+use super::bordered_block;
+
+/// Result of feeding a key to the editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextAreaEvent {
+    /// Text or cursor changed (or key ignored)
+    None,
+    /// Ctrl+S: caller should take the text
+    Save,
+    /// Esc: caller should discard the editor
+    Cancel,
+}
+
+/// Editable multi-line text with a cursor
+///
+/// The cursor column is a character index into the current line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextArea {
+    lines: Vec<String>,
+    row: usize,
+    col: usize,
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl TextArea {
+    /// Create an editor holding `text`, with the cursor at the end
+    pub fn new(text: &str) -> Self {
+        let mut lines: Vec<String> = text.split('\n').map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let row = lines.len() - 1;
+        let col = lines[row].chars().count();
+        Self { lines, row, col }
+    }
+
+    /// Current text, lines joined with `\n`
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Cursor position as (line, character column)
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Handle a key press
+    pub fn handle_key(&mut self, key: KeyEvent) -> TextAreaEvent {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return TextAreaEvent::Cancel,
+            KeyCode::Char('s') if ctrl => return TextAreaEvent::Save,
+            KeyCode::Char('a') if ctrl => self.col = 0,
+            KeyCode::Char('e') if ctrl => self.col = self.line_len(),
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert_char(c)
+            }
+            KeyCode::Enter => self.insert_newline(),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left => self.move_left(),
+            KeyCode::Right => self.move_right(),
+            KeyCode::Up if self.row > 0 => self.move_to_row(self.row - 1),
+            KeyCode::Down if self.row + 1 < self.lines.len() => self.move_to_row(self.row + 1),
+            KeyCode::Home => self.col = 0,
+            KeyCode::End => self.col = self.line_len(),
+            _ => {}
+        }
+        TextAreaEvent::None
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    /// Byte offset of the cursor within the current line
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map_or(line.len(), |(i, _)| i)
+    }
+
+    fn insert_char(&mut self, c: char) {
+        let at = self.byte_offset();
+        self.lines[self.row].insert(at, c);
+        self.col += 1;
+    }
+
+    fn insert_newline(&mut self) {
+        let at = self.byte_offset();
+        let rest = self.lines[self.row].split_off(at);
+        self.row += 1;
+        self.lines.insert(self.row, rest);
+        self.col = 0;
+    }
+
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+        } else if self.row > 0 {
+            // Join with the previous line
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.line_len();
+            self.lines[self.row].push_str(&line);
+        }
+    }
+
+    fn delete(&mut self) {
+        if self.col < self.line_len() {
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+        } else if self.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&next);
+        }
+    }
+
+    fn move_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.line_len();
+        }
+    }
+
+    fn move_right(&mut self) {
+        if self.col < self.line_len() {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    fn move_to_row(&mut self, row: usize) {
+        self.row = row;
+        self.col = self.col.min(self.line_len());
+    }
+
+    /// Soft-wrap lines to `width` columns, returning the visual rows and
+    /// the cursor's (row, column) among them
+    ///
+    /// A line gets `len / width + 1` rows, so a cursor sitting after a
+    /// line that exactly fills the width still has a row to live on.
+    fn wrap(&self, width: usize) -> (Vec<String>, (usize, usize)) {
+        let width = width.max(1);
+        let mut rows = Vec::new();
+        let mut cursor = (0, 0);
+        for (i, line) in self.lines.iter().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            if i == self.row {
+                cursor = (rows.len() + self.col / width, self.col % width);
+            }
+            for r in 0..=chars.len() / width {
+                let end = ((r + 1) * width).min(chars.len());
+                rows.push(chars[r * width..end].iter().collect());
+            }
+        }
+        (rows, cursor)
+    }
+
+    /// Render as a bordered box with save/cancel hints, placing the cursor
+    ///
+    /// Scrolls just enough to keep the cursor row visible.
+    pub fn render(&self, frame: &mut Frame, area: Rect, title: &str) {
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let inner_height = area.height.saturating_sub(2) as usize;
+        if inner_width == 0 || inner_height == 0 {
+            return;
+        }
+
+        let (rows, (cursor_row, cursor_col)) = self.wrap(inner_width);
+        let scroll = (cursor_row + 1).saturating_sub(inner_height);
+        let lines: Vec<Line> = rows
+            .into_iter()
+            .skip(scroll)
+            .take(inner_height)
+            .map(Line::from)
+            .collect();
+
+        let block = bordered_block(Line::from(format!(" {} ", title))).title_bottom(
+            Line::from(" Ctrl+S: Save  Esc: Cancel ")
+                .style(Style::default().fg(Color::DarkGray))
+                .right_aligned(),
+        );
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+        frame.set_cursor_position((
+            area.x + 1 + cursor_col as u16,
+            area.y + 1 + (cursor_row - scroll) as u16,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(area: &mut TextArea, code: KeyCode) -> TextAreaEvent {
+        area.handle_key(KeyEvent::from(code))
+    }
+
+    fn type_text(area: &mut TextArea, text: &str) {
+        for c in text.chars() {
+            press(area, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn test_new_places_cursor_at_end() {
+        let area = TextArea::new("first\nsecond");
+        assert_eq!(area.cursor(), (1, 6));
+        assert_eq!(area.text(), "first\nsecond");
+    }
+
+    #[test]
+    fn test_enter_splits_line() {
+        let mut area = TextArea::new("subjectbody");
+        for _ in 0..4 {
+            press(&mut area, KeyCode::Left);
+        }
+        press(&mut area, KeyCode::Enter);
+        press(&mut area, KeyCode::Enter);
+        assert_eq!(area.text(), "subject\n\nbody");
+        assert_eq!(area.cursor(), (2, 0));
+    }
+
+    #[test]
+    fn test_backspace_joins_lines() {
+        let mut area = TextArea::new("a\nb");
+        press(&mut area, KeyCode::Home);
+        press(&mut area, KeyCode::Backspace);
+        assert_eq!(area.text(), "ab");
+        assert_eq!(area.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn test_delete_joins_next_line() {
+        let mut area = TextArea::new("a\nb");
+        press(&mut area, KeyCode::Up);
+        press(&mut area, KeyCode::Delete);
+        assert_eq!(area.text(), "ab");
+    }
+
+    #[test]
+    fn test_vertical_move_clamps_column() {
+        let mut area = TextArea::new("long line\nab");
+        press(&mut area, KeyCode::Up);
+        press(&mut area, KeyCode::End);
+        press(&mut area, KeyCode::Down);
+        assert_eq!(area.cursor(), (1, 2));
+    }
+
+    #[test]
+    fn test_multibyte_editing() {
+        let mut area = TextArea::new("日本");
+        press(&mut area, KeyCode::Left);
+        type_text(&mut area, "語");
+        assert_eq!(area.text(), "日語本");
+        press(&mut area, KeyCode::Backspace);
+        assert_eq!(area.text(), "日本");
+    }
+
+    #[test]
+    fn test_save_and_cancel_keys() {
+        let mut area = TextArea::default();
+        type_text(&mut area, "x");
+        let ctrl_s = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert_eq!(area.handle_key(ctrl_s), TextAreaEvent::Save);
+        assert_eq!(press(&mut area, KeyCode::Esc), TextAreaEvent::Cancel);
+        // Neither key edits the text
+        assert_eq!(area.text(), "x");
+    }
+
+    #[test]
+    fn test_wrap_tracks_cursor() {
+        let area = TextArea::new("abcdef\ngh");
+        let (rows, cursor) = area.wrap(4);
+        assert_eq!(rows, vec!["abcd", "ef", "gh"]);
+        assert_eq!(cursor, (2, 2));
+    }
+
+    #[test]
+    fn test_wrap_full_line_gets_cursor_row() {
+        let area = TextArea::new("abcd");
+        let (rows, cursor) = area.wrap(4);
+        assert_eq!(rows, vec!["abcd", ""]);
+        assert_eq!(cursor, (1, 0));
+    }
+}
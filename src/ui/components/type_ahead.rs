@@ -0,0 +1,119 @@
+//! Type-ahead jump for long lists
+//!
+//! Typing letters selects the next entry whose label starts with them, like
+//! file managers do. Keys typed within [`TIMEOUT`] of each other extend the
+//! prefix; pressing the same single letter again cycles through matches.
+//! Keys a list already binds to actions keep their meaning, so only unbound
+//! characters reach the type-ahead.
+
+use std::time::{Duration, Instant};
+
+/// Pause after which the next key starts a new prefix
+pub const TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Prefix typed so far and when it was last extended
+#[derive(Debug, Clone, Default)]
+pub struct TypeAhead {
+    query: String,
+    last_key: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Add `c` to the prefix (starting over after a pause) and return it
+    pub fn push(&mut self, c: char, now: Instant) -> &str {
+        let expired = self
+            .last_key
+            .is_none_or(|last| now.duration_since(last) >= TIMEOUT);
+        if expired {
+            self.query.clear();
+        }
+        self.query.push(c);
+        self.last_key = Some(now);
+        &self.query
+    }
+
+    /// Forget the prefix
+    pub fn reset(&mut self) {
+        self.query.clear();
+        self.last_key = None;
+    }
+}
+
+/// Label text used for matching: leading markers/indentation stripped
+fn match_key(label: &str) -> &str {
+    label.trim_start_matches(|c: char| !c.is_alphanumeric())
+}
+
+/// Index of the next label starting with `query` (case-insensitive)
+///
+/// A one-letter query searches after `current` so repeating the letter
+/// cycles; a longer query may stay on `current` while it still matches.
+/// Wraps around the end of the list.
+pub fn find_match<'a>(
+    labels: impl IntoIterator<Item = &'a str>,
+    current: usize,
+    query: &str,
+) -> Option<usize> {
+    let query = query.to_lowercase();
+    let labels: Vec<String> = labels
+        .into_iter()
+        .map(|label| match_key(label).to_lowercase())
+        .collect();
+    if labels.is_empty() || query.is_empty() {
+        return None;
+    }
+    let start = if query.chars().count() == 1 {
+        current + 1
+    } else {
+        current
+    };
+    (0..labels.len())
+        .map(|offset| (start + offset) % labels.len())
+        .find(|&i| labels[i].starts_with(&query))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_extends_within_timeout() {
+        let now = Instant::now();
+        let mut type_ahead = TypeAhead::default();
+        type_ahead.push('m', now);
+        assert_eq!(type_ahead.push('a', now + Duration::from_millis(300)), "ma");
+    }
+
+    #[test]
+    fn test_push_restarts_after_timeout() {
+        let now = Instant::now();
+        let mut type_ahead = TypeAhead::default();
+        type_ahead.push('m', now);
+        assert_eq!(type_ahead.push('f', now + TIMEOUT), "f");
+        type_ahead.reset();
+        assert_eq!(type_ahead.push('x', now + TIMEOUT), "x");
+    }
+
+    #[test]
+    fn test_find_match_single_letter_cycles() {
+        let labels = ["feature-a", "main", "feature-b", "fix"];
+        assert_eq!(find_match(labels, 0, "f"), Some(2));
+        assert_eq!(find_match(labels, 2, "f"), Some(3));
+        assert_eq!(find_match(labels, 3, "f"), Some(0));
+    }
+
+    #[test]
+    fn test_find_match_prefix_stays_on_current() {
+        let labels = ["feature-a", "main", "feature-b"];
+        assert_eq!(find_match(labels, 2, "fe"), Some(2));
+        assert_eq!(find_match(labels, 0, "MA"), Some(1));
+        assert_eq!(find_match(labels, 0, "zz"), None);
+    }
+
+    #[test]
+    fn test_find_match_ignores_leading_markers() {
+        let labels = ["  default view", "* mine: mine()", "  stack: trunk()..@"];
+        assert_eq!(find_match(labels, 0, "mi"), Some(1));
+        assert_eq!(find_match(labels, 0, "s"), Some(2));
+    }
+}
@@ -1,10 +1,12 @@
 //! UI layer
 //!
-//! Contains views, widgets, components, symbols, and theme definitions.
+//! Contains views, widgets, components, symbols, and theme definitions,
+//! plus the golden-file harness for view tests.
 
 pub mod components;
 pub mod navigation;
 pub mod symbols;
+pub mod testing;
 pub mod theme;
 pub mod views;
 pub mod widgets;
@@ -2,6 +2,7 @@
 //!
 //! Pure functions that compute new selection/scroll state without side effects.
 //! Each View calls these with its own state and applies the results.
+//! [`LineOverflow`] is the sideways counterpart for views of long text lines.
 
 /// Move selection down by one, clamped to max_index.
 ///
@@ -34,10 +35,127 @@ pub fn adjust_scroll(selected: usize, scroll_offset: usize, visible_count: usize
     }
 }
 
+/// Columns moved per horizontal scroll step (`h`/`l`)
+pub const HSCROLL_STEP: usize = 8;
+
+/// How lines wider than the view are shown (Diff and Blame View)
+///
+/// Long lines are cut off by default and can be scrolled sideways;
+/// wrapped lines always start at the first column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineOverflow {
+    /// Wrap long lines instead of cutting them off
+    pub wrap: bool,
+    /// First content column shown while cut off (0-based)
+    pub column: usize,
+}
+
+impl LineOverflow {
+    /// Switch between wrapping and cutting off, back at the first column
+    ///
+    /// Returns whether lines now wrap.
+    pub fn toggle_wrap(&mut self) -> bool {
+        self.wrap = !self.wrap;
+        self.column = 0;
+        self.wrap
+    }
+
+    /// Scroll one step right, at most to the last column of the widest line
+    ///
+    /// Returns false (nothing to scroll) while wrapping.
+    pub fn scroll_right(&mut self, widest: usize) -> bool {
+        if self.wrap {
+            return false;
+        }
+        self.column = (self.column + HSCROLL_STEP).min(widest.saturating_sub(1));
+        true
+    }
+
+    /// Scroll one step left; false while wrapping
+    pub fn scroll_left(&mut self) -> bool {
+        if self.wrap {
+            return false;
+        }
+        self.column = self.column.saturating_sub(HSCROLL_STEP);
+        true
+    }
+
+    /// The part of `text` right of the scrolled-off columns
+    pub fn visible<'a>(&self, text: &'a str) -> &'a str {
+        if self.wrap || self.column == 0 {
+            return text;
+        }
+        match text.char_indices().nth(self.column) {
+            Some((start, _)) => &text[start..],
+            None => "",
+        }
+    }
+
+    /// Notification after [`Self::toggle_wrap`]
+    pub fn wrap_notice(&self) -> &'static str {
+        if self.wrap {
+            "Wrapping long lines"
+        } else {
+            "Cutting off long lines (h/l scrolls)"
+        }
+    }
+
+    /// Notification for `h`/`l` while wrapping
+    pub const WRAPPED_SCROLL_NOTICE: &'static str = "Lines wrap: W cuts them off for h/l";
+
+    /// Indicator for the view's title or context bar (`None` at column 1)
+    pub fn label(&self) -> Option<String> {
+        if self.wrap {
+            Some("wrap".to_string())
+        } else if self.column > 0 {
+            Some(format!("col {}", self.column + 1))
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // =========================================================================
+    // LineOverflow tests
+    // =========================================================================
+
+    #[test]
+    fn test_line_overflow_scrolls_within_widest_line() {
+        let mut overflow = LineOverflow::default();
+        assert!(overflow.scroll_right(20));
+        assert_eq!(overflow.column, HSCROLL_STEP);
+        assert_eq!(overflow.visible("0123456789abcdef"), "89abcdef");
+        assert_eq!(overflow.label().as_deref(), Some("col 9"));
+
+        overflow.scroll_right(20);
+        overflow.scroll_right(20);
+        assert_eq!(overflow.column, 19);
+        assert_eq!(overflow.visible("short"), "");
+
+        overflow.scroll_left();
+        assert_eq!(overflow.column, 11);
+    }
+
+    #[test]
+    fn test_line_overflow_wrap_resets_column() {
+        let mut overflow = LineOverflow {
+            wrap: false,
+            column: 16,
+        };
+        assert!(overflow.toggle_wrap());
+        assert_eq!(overflow.column, 0);
+        assert!(!overflow.scroll_right(100));
+        assert!(!overflow.scroll_left());
+        assert_eq!(overflow.visible("héllo"), "héllo");
+        assert_eq!(overflow.label().as_deref(), Some("wrap"));
+        assert!(!overflow.toggle_wrap());
+        assert_eq!(overflow.label(), None);
+    }
+
     // =========================================================================
     // select_next tests
     // =========================================================================
@@ -3,13 +3,8 @@
 //! ## Character Set Policy
 //! - **Unicode adopted**: For consistency with jj default output
 //! - Existing UI (app.rs) already uses Unicode characters
-//! - ASCII fallback (theme feature) to be considered in future
-//!
-//! ASCII alternatives (for reference):
-//! - WORKING_COPY: '@' (same)
-//! - NORMAL: 'o' or '*'
-//! - ROOT: '+' or '#'
-//! - CONNECTOR: '|'
+//! - ASCII markers (`ascii_markers`) when `ui.graph.style` is `ascii` or
+//!   `ascii-large`, matching jj's ASCII node glyphs
 
 /// Change markers in Log View
 pub mod markers {
@@ -19,11 +14,23 @@ pub mod markers {
     pub const NORMAL: char = '○';
     /// Root change marker (◆)
     pub const ROOT: char = '◆';
+    /// Conflicted change marker (×)
+    pub const CONFLICT: char = '×';
     /// Vertical connector (│)
     #[allow(dead_code)]
     pub const CONNECTOR: char = '│';
 }
 
+/// Log View markers for jj's ASCII graph styles (@ stays the same)
+pub mod ascii_markers {
+    /// Normal change marker (o)
+    pub const NORMAL: char = 'o';
+    /// Immutable change marker (+)
+    pub const IMMUTABLE: char = '+';
+    /// Conflicted change marker (x)
+    pub const CONFLICT: char = 'x';
+}
+
 /// Empty state indicators
 pub mod empty {
     /// Label for empty changes
@@ -41,6 +48,7 @@ mod tests {
         assert_eq!(markers::WORKING_COPY.len_utf8(), 1);
         assert!(markers::NORMAL.len_utf8() <= 3); // Unicode char
         assert!(markers::ROOT.len_utf8() <= 3);
+        assert!(markers::CONFLICT.len_utf8() <= 3);
         assert!(markers::CONNECTOR.len_utf8() <= 3);
     }
 
@@ -0,0 +1,144 @@
+//! Golden-file harness for view tests
+//!
+//! Views are rendered into a [`TestBackend`] and compared as plain text with
+//! files under `tests/snapshots/` of the crate being tested. Unlike the insta
+//! snapshots, a golden file is just the screen, so a UI change shows up in
+//! review as a readable diff. Set `TIJ_UPDATE_GOLDEN=1` (or run
+//! `scripts/update-golden.sh`) to rewrite the files instead of comparing.
+//!
+//! The harness is public so views built outside this crate can be tested
+//! the same way:
+//!
+//! ```no_run
+//! use tij::ui::testing::{assert_golden, render_to_text};
+//! use tij::ui::views::OperationView;
+//!
+//! let view = OperationView::new();
+//! let screen = render_to_text(60, 10, |frame| view.render(frame, frame.area(), None));
+//! assert_golden("operation_view_empty", &screen);
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ratatui::{Frame, Terminal, backend::TestBackend, buffer::Buffer};
+
+/// Environment variable that switches to update mode
+pub const UPDATE_ENV: &str = "TIJ_UPDATE_GOLDEN";
+
+/// Directory of golden files, relative to the crate root
+pub const GOLDEN_DIR: &str = "tests/snapshots";
+
+/// Render with `draw` on a `width` x `height` terminal and return the text
+pub fn render_to_text(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> String {
+    let mut terminal =
+        Terminal::new(TestBackend::new(width, height)).expect("TestBackend terminal cannot fail");
+    terminal.draw(draw).expect("TestBackend draw cannot fail");
+    buffer_to_text(terminal.backend().buffer())
+}
+
+/// Buffer symbols line by line, trailing spaces trimmed
+///
+/// Styles are dropped: golden files track layout and text only.
+pub fn buffer_to_text(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    let mut text = String::new();
+    if width == 0 {
+        return text;
+    }
+    for row in buffer.content.chunks(width) {
+        let line: String = row.iter().map(|cell| cell.symbol()).collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Whether golden files are rewritten instead of compared
+pub fn is_update_mode() -> bool {
+    env::var(UPDATE_ENV).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Compare `actual` with `tests/snapshots/<name>.txt` of the tested crate
+pub fn assert_golden(name: &str, actual: &str) {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    assert_golden_in(&PathBuf::from(root).join(GOLDEN_DIR), name, actual);
+}
+
+/// Compare `actual` with `<dir>/<name>.txt` (update mode writes it)
+///
+/// # Panics
+///
+/// When the file is missing or differs, with the first differing line.
+pub fn assert_golden_in(dir: &Path, name: &str, actual: &str) {
+    let path = dir.join(format!("{}.txt", name));
+    if is_update_mode() {
+        fs::create_dir_all(dir).expect("create golden directory");
+        fs::write(&path, actual).expect("write golden file");
+        return;
+    }
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!(
+            "golden file {} is missing: run with {}=1 to create it",
+            path.display(),
+            UPDATE_ENV
+        );
+    };
+    if let Some(mismatch) = first_mismatch(&expected, actual) {
+        panic!(
+            "golden file {} differs ({}); run with {}=1 to update it",
+            path.display(),
+            mismatch,
+            UPDATE_ENV
+        );
+    }
+}
+
+/// `line N: expected "..", got ".."` for the first differing line
+fn first_mismatch(expected: &str, actual: &str) -> Option<String> {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for number in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => return None,
+            (e, a) if e == a => {}
+            (e, a) => {
+                return Some(format!(
+                    "line {}: expected {:?}, got {:?}",
+                    number,
+                    e.unwrap_or("<end>"),
+                    a.unwrap_or("<end>")
+                ));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Paragraph;
+
+    #[test]
+    fn test_render_to_text_trims_trailing_spaces() {
+        let text = render_to_text(6, 2, |frame| {
+            frame.render_widget(Paragraph::new("ab\ncd  e"), frame.area());
+        });
+        assert_eq!(text, "ab\ncd  e\n");
+    }
+
+    #[test]
+    fn test_first_mismatch() {
+        assert_eq!(first_mismatch("a\nb\n", "a\nb\n"), None);
+        assert_eq!(
+            first_mismatch("a\nb\n", "a\nc\n").as_deref(),
+            Some("line 2: expected \"b\", got \"c\"")
+        );
+        assert_eq!(
+            first_mismatch("a\n", "a\nb\n").as_deref(),
+            Some("line 2: expected \"<end>\", got \"b\"")
+        );
+    }
+}
@@ -28,14 +28,42 @@ pub mod log_view {
     pub const ROOT_MARKER: Color = Color::Magenta;
     /// Change ID color
     pub const CHANGE_ID: Color = Color::Yellow;
+    /// Commit ID column color (`%` toggle)
+    pub const COMMIT_ID: Color = Color::Blue;
     /// Bookmark color
     pub const BOOKMARK: Color = Color::Cyan;
+    /// Ahead/behind indicator next to a bookmark (`↑2 ↓5`)
+    pub const BOOKMARK_TRACKING: Color = Color::LightMagenta;
     /// Timestamp color
     pub const TIMESTAMP: Color = Color::DarkGray;
+    /// Added / removed line counts of the `diff_stats` column
+    pub const STAT_ADDED: Color = Color::Green;
+    pub const STAT_REMOVED: Color = Color::Red;
     /// Empty label color
     pub const EMPTY_LABEL: Color = Color::DarkGray;
+    /// `[hidden]` badge on abandoned/rewritten commits
+    pub const HIDDEN: Color = Color::DarkGray;
+    /// `[1]` mark on changes picked as merge parents (Space)
+    pub const MARK: Color = Color::LightGreen;
+    /// `[note]` badge on changes with a local note (`;`)
+    pub const NOTE: Color = Color::LightYellow;
     /// Graph line color (DAG structure)
     pub const GRAPH_LINE: Color = Color::Blue;
+    /// Per-lane edge colors (cycled by graph column)
+    pub const GRAPH_EDGE_PALETTE: [Color; 6] = [
+        GRAPH_LINE,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Green,
+        Color::Yellow,
+        Color::LightBlue,
+    ];
+    /// Conflicted change marker color
+    pub const CONFLICT_MARKER: Color = Color::Red;
+    /// Elided revisions marker / label color
+    pub const ELIDED: Color = Color::DarkGray;
+    /// Another workspace's working copy marker (`feature-ws@`)
+    pub const WORKSPACE_MARKER: Color = Color::Magenta;
 }
 
 /// Colors for Diff View
@@ -66,10 +94,108 @@ pub mod status_view {
     pub const RENAMED: Color = Color::Cyan;
     /// Conflicted file color
     pub const CONFLICTED: Color = Color::Magenta;
+    /// Untracked path color
+    pub const UNTRACKED: Color = Color::Blue;
+    /// Ignored path color
+    pub const IGNORED: Color = Color::DarkGray;
     /// Header text color (change ID, etc.)
     pub const HEADER: Color = Color::Cyan;
 }
 
+/// Colors for Bookmark View
+pub mod bookmark_view {
+    use super::*;
+
+    /// Commits to push (`+N` column)
+    pub const AHEAD: Color = Color::Green;
+    /// Commits the remote has that local lacks (`-N` column)
+    pub const BEHIND: Color = Color::Red;
+    /// Zero counts
+    pub const SYNCED: Color = Color::DarkGray;
+    /// `Q` marker on bookmarks in the push queue
+    pub const QUEUED: Color = Color::Magenta;
+    /// `PR`/`MR` badge on bookmarks with an open request
+    pub const OPEN_REQUEST: Color = Color::Green;
+}
+
+/// Badge colors for the Log preview header (black text on these)
+pub mod preview {
+    use super::*;
+
+    /// Unresolved conflicts
+    pub const CONFLICT: Color = Color::Red;
+    /// In `immutable_heads()` ancestry
+    pub const IMMUTABLE: Color = Color::Blue;
+    /// No file changes
+    pub const EMPTY: Color = Color::DarkGray;
+    /// Change ID with several visible commits
+    pub const DIVERGENT: Color = Color::Magenta;
+}
+
+/// Colors for Operation View
+pub mod operation_view {
+    use super::*;
+
+    /// Operations that arrived in follow mode and were not selected yet
+    pub const NEW_OPERATION: Color = Color::LightGreen;
+    /// Follow mode indicator in the title
+    pub const FOLLOW: Color = Color::Green;
+    /// Last operation shared by divergent heads
+    pub const FORK_POINT: Color = Color::Yellow;
+    /// Warning about operations undone by keeping one head
+    pub const DIVERGENCE_WARNING: Color = Color::LightRed;
+    /// Operation marked as the other end of an abandon range
+    pub const RANGE_MARK: Color = Color::LightMagenta;
+}
+
+/// Colors for Resolve View conflict preview
+pub mod resolve_view {
+    use super::*;
+
+    /// Conflict marker lines
+    pub const MARKER: Color = Color::Magenta;
+    /// Base (common ancestor) content
+    pub const BASE: Color = Color::DarkGray;
+    /// Side #1 content
+    pub const OURS: Color = Color::Green;
+    /// Side #2+ content
+    pub const THEIRS: Color = Color::Blue;
+}
+
+/// Colors for Blame View
+pub mod blame_view {
+    use super::*;
+
+    /// Line age heatmap, oldest first (xterm-256: blue → cyan → green → yellow → orange → red)
+    pub const HEAT: [Color; 6] = [
+        Color::Indexed(61),
+        Color::Indexed(67),
+        Color::Indexed(72),
+        Color::Indexed(143),
+        Color::Indexed(179),
+        Color::Indexed(167),
+    ];
+    /// Per-change colors (picked by change ID hash) to group hunks
+    pub const CHANGE_PALETTE: [Color; 8] = [
+        Color::Cyan,
+        Color::Magenta,
+        Color::Green,
+        Color::Yellow,
+        Color::LightBlue,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightMagenta,
+    ];
+}
+
+/// Colors for the status bar
+pub mod status_bar {
+    use super::*;
+
+    /// `tij.status-bar` template text
+    pub const TEMPLATE: Color = Color::Gray;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,6 +208,11 @@ mod tests {
         let _ = log_view::BOOKMARK;
     }
 
+    #[test]
+    fn test_graph_edge_palette_starts_with_graph_line() {
+        assert_eq!(log_view::GRAPH_EDGE_PALETTE[0], log_view::GRAPH_LINE);
+    }
+
     #[test]
     fn test_diff_view_colors_defined() {
         let _ = diff_view::ADDED;
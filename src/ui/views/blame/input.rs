@@ -3,6 +3,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::keys;
+use crate::ui::navigation::LineOverflow;
 
 use super::{BlameAction, BlameView};
 
@@ -51,6 +52,30 @@ impl BlameView {
                     BlameAction::None
                 }
             }
+            k if k == keys::FILE_LOG => BlameAction::OpenFileLog,
+            // Dig into the parent of the selected line's change / pop back out
+            k if k == keys::BLAME_PARENT => {
+                if let Some(commit_id) = self.selected_commit_id() {
+                    BlameAction::BlameParent(commit_id.to_string())
+                } else {
+                    BlameAction::None
+                }
+            }
+            k if k == keys::BLAME_BACK => {
+                self.pop_content();
+                BlameAction::None
+            }
+            k if k == keys::WRAP_TOGGLE => {
+                self.toggle_wrap();
+                BlameAction::ShowNotification(self.overflow().wrap_notice().to_string())
+            }
+            k if keys::is_scroll_left(k) || keys::is_scroll_right(k) => {
+                if self.scroll_sideways(keys::is_scroll_right(k)) {
+                    BlameAction::None
+                } else {
+                    BlameAction::ShowNotification(LineOverflow::WRAPPED_SCROLL_NOTICE.to_string())
+                }
+            }
             // Back
             k if k == keys::QUIT || k == keys::ESC => BlameAction::Back,
             _ => BlameAction::None,
@@ -100,6 +125,32 @@ mod tests {
         assert_eq!(view.selected_index, 0);
     }
 
+    #[test]
+    fn test_handle_key_horizontal_scroll_and_wrap() {
+        let mut view = BlameView::new();
+        let mut content = make_test_content();
+        content.lines[0].content = "x".repeat(30);
+        view.set_content(content, None);
+
+        assert_eq!(
+            view.handle_key(key_event(KeyCode::Char('l'))),
+            BlameAction::None
+        );
+        assert_eq!(view.overflow().column, 8);
+        assert_eq!(view.overflow().label().as_deref(), Some("col 9"));
+        view.handle_key(key_event(KeyCode::Char('h')));
+        assert_eq!(view.overflow().column, 0);
+
+        assert_eq!(
+            view.handle_key(key_event(KeyCode::Char('W'))),
+            BlameAction::ShowNotification("Wrapping long lines".to_string())
+        );
+        assert!(matches!(
+            view.handle_key(key_event(KeyCode::Right)),
+            BlameAction::ShowNotification(_)
+        ));
+    }
+
     #[test]
     fn test_handle_key_enter() {
         let mut view = BlameView::new();
@@ -135,6 +186,37 @@ mod tests {
         assert_eq!(action, BlameAction::JumpToLog("change02".to_string()));
     }
 
+    #[test]
+    fn test_handle_key_blame_parent() {
+        let mut view = BlameView::new();
+        view.set_content(make_test_content(), None);
+        view.move_down();
+        let action = view.handle_key(key_event(KeyCode::Char(',')));
+        assert_eq!(action, BlameAction::BlameParent("commit02".to_string()));
+
+        let mut empty = BlameView::new();
+        let action = empty.handle_key(key_event(KeyCode::Char(',')));
+        assert_eq!(action, BlameAction::None);
+    }
+
+    #[test]
+    fn test_handle_key_blame_back_pops_breadcrumb() {
+        let mut view = BlameView::new();
+        view.set_content(make_test_content(), None);
+        view.push_content(make_test_content(), "commit01-".to_string());
+        let action = view.handle_key(key_event(KeyCode::Char('.')));
+        assert_eq!(action, BlameAction::None);
+        assert_eq!(view.revision(), None);
+    }
+
+    #[test]
+    fn test_handle_key_file_log() {
+        let mut view = BlameView::new();
+        view.set_content(make_test_content(), None);
+        let action = view.handle_key(key_event(KeyCode::Char('L')));
+        assert_eq!(action, BlameAction::OpenFileLog);
+    }
+
     #[test]
     fn test_handle_key_jump_to_log_shift_j() {
         // Some terminals send Char('j') + SHIFT instead of Char('J')
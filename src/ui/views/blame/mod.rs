@@ -5,8 +5,8 @@
 mod input;
 mod render;
 
-use crate::model::AnnotationContent;
-use crate::ui::navigation;
+use crate::model::{AnnotationContent, TimestampFormat};
+use crate::ui::navigation::{self, LineOverflow};
 
 /// Action returned by BlameView input handling
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +19,21 @@ pub enum BlameAction {
     OpenDiff(String),
     /// Jump to this change in Log View
     JumpToLog(String),
+    /// Show the history of the annotated file
+    OpenFileLog,
+    /// Re-annotate at the parent of the selected line's change (commit_id)
+    BlameParent(String),
+    /// Show a notification message
+    ShowNotification(String),
+}
+
+/// Annotation left behind when digging into a parent revision
+#[derive(Debug, Clone)]
+struct BlameFrame {
+    content: AnnotationContent,
+    selected_index: usize,
+    scroll_offset: usize,
+    revision: Option<String>,
 }
 
 /// View state for blame/annotation display
@@ -32,6 +47,12 @@ pub struct BlameView {
     scroll_offset: usize,
     /// Revision used for annotation (None = working copy)
     revision: Option<String>,
+    /// Breadcrumb of earlier annotations (oldest first), popped with `.`
+    history: Vec<BlameFrame>,
+    /// Wrapping or horizontal scroll of long lines (`W`, `h`/`l`)
+    overflow: LineOverflow,
+    /// Timestamp display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub(crate) timestamp_format: TimestampFormat,
 }
 
 impl Default for BlameView {
@@ -48,15 +69,63 @@ impl BlameView {
             selected_index: 0,
             scroll_offset: 0,
             revision: None,
+            history: Vec::new(),
+            overflow: LineOverflow::default(),
+            timestamp_format: TimestampFormat::default(),
         }
     }
 
     /// Set the annotation content with optional revision
+    ///
+    /// Starts a fresh breadcrumb.
     pub fn set_content(&mut self, content: AnnotationContent, revision: Option<String>) {
         self.content = content;
         self.selected_index = 0;
         self.scroll_offset = 0;
         self.revision = revision;
+        self.history.clear();
+    }
+
+    /// Show an annotation of an older revision, keeping the current one
+    /// on the breadcrumb
+    ///
+    /// The selection stays on the same line number where possible, which
+    /// is usually close to the line being traced.
+    pub fn push_content(&mut self, content: AnnotationContent, revision: String) {
+        let selected_index = self.selected_index.min(content.len().saturating_sub(1));
+        let previous = std::mem::replace(&mut self.content, content);
+        self.history.push(BlameFrame {
+            content: previous,
+            selected_index: self.selected_index,
+            scroll_offset: self.scroll_offset,
+            revision: self.revision.replace(revision),
+        });
+        self.selected_index = selected_index;
+    }
+
+    /// Return to the annotation before the last `push_content`
+    ///
+    /// Returns false when already at the start of the breadcrumb.
+    pub fn pop_content(&mut self) -> bool {
+        let Some(frame) = self.history.pop() else {
+            return false;
+        };
+        self.content = frame.content;
+        self.selected_index = frame.selected_index;
+        self.scroll_offset = frame.scroll_offset;
+        self.revision = frame.revision;
+        true
+    }
+
+    /// Revisions from the first annotation to the current one
+    /// (`@` for the working copy)
+    pub fn breadcrumb(&self) -> Vec<&str> {
+        self.history
+            .iter()
+            .map(|frame| frame.revision.as_deref())
+            .chain(std::iter::once(self.revision.as_deref()))
+            .map(|revision| revision.unwrap_or("@"))
+            .collect()
     }
 
     /// Get the revision used for this blame view
@@ -88,6 +157,14 @@ impl BlameView {
             .map(|line| line.change_id.as_str())
     }
 
+    /// Get the currently selected line's 1-based line number
+    pub fn selected_line_number(&self) -> Option<usize> {
+        self.content
+            .lines
+            .get(self.selected_index)
+            .map(|line| line.line_number)
+    }
+
     /// Get the currently selected line's commit_id (for jj command execution)
     pub fn selected_commit_id(&self) -> Option<&str> {
         self.content
@@ -96,6 +173,32 @@ impl BlameView {
             .map(|line| line.commit_id.as_str())
     }
 
+    /// Wrapping or horizontal scroll of long lines
+    pub fn overflow(&self) -> LineOverflow {
+        self.overflow
+    }
+
+    /// Switch between wrapping and cutting off long lines
+    pub fn toggle_wrap(&mut self) -> bool {
+        self.overflow.toggle_wrap()
+    }
+
+    /// Scroll long lines sideways; false while wrapping
+    pub fn scroll_sideways(&mut self, right: bool) -> bool {
+        if right {
+            let widest = self
+                .content
+                .lines
+                .iter()
+                .map(|line| line.content.trim_end_matches('\n').chars().count())
+                .max()
+                .unwrap_or(0);
+            self.overflow.scroll_right(widest)
+        } else {
+            self.overflow.scroll_left()
+        }
+    }
+
     /// Move selection down
     pub fn move_down(&mut self) {
         let max = self.content.len().saturating_sub(1);
@@ -170,6 +273,38 @@ mod tests {
         assert_eq!(view.revision(), Some("abc12345"));
     }
 
+    #[test]
+    fn test_push_and_pop_content_walks_breadcrumb() {
+        let mut view = BlameView::new();
+        view.set_content(make_test_content(), None);
+        view.move_to_bottom();
+
+        let mut parent = AnnotationContent::new("test.rs".to_string());
+        parent.lines = make_test_content().lines[..4].to_vec();
+        view.push_content(parent, "commit10-".to_string());
+        assert_eq!(view.revision(), Some("commit10-"));
+        assert_eq!(view.line_count(), 4);
+        // Selection clamped to the shorter file
+        assert_eq!(view.selected_index, 3);
+        assert_eq!(view.breadcrumb(), vec!["@", "commit10-"]);
+
+        assert!(view.pop_content());
+        assert_eq!(view.revision(), None);
+        assert_eq!(view.line_count(), 10);
+        assert_eq!(view.selected_index, 9);
+        assert_eq!(view.breadcrumb(), vec!["@"]);
+        assert!(!view.pop_content());
+    }
+
+    #[test]
+    fn test_set_content_clears_breadcrumb() {
+        let mut view = BlameView::new();
+        view.set_content(make_test_content(), None);
+        view.push_content(make_test_content(), "commit01-".to_string());
+        view.set_content(make_test_content(), Some("abc".to_string()));
+        assert_eq!(view.breadcrumb(), vec!["abc"]);
+    }
+
     #[test]
     fn test_blame_view_navigation() {
         let mut view = BlameView::new();
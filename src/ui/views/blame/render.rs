@@ -5,22 +5,22 @@ use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Paragraph, Wrap},
 };
 
 use crate::model::Notification;
-use crate::ui::components;
+use crate::ui::{components, theme};
 
 use super::BlameView;
 
 /// Constants for blame display layout
 mod layout {
+    /// Heat gutter ("▌" + space)
+    pub const GUTTER_WIDTH: usize = 2;
     /// Width for change_id display (8 chars)
     pub const CHANGE_ID_WIDTH: usize = 8;
     /// Width for author display (truncated)
     pub const AUTHOR_WIDTH: usize = 10;
-    /// Width for timestamp display (MM-DD)
-    pub const TIMESTAMP_WIDTH: usize = 5;
     /// Width for line number (dynamic, but max 6 digits)
     pub const LINE_NUMBER_WIDTH: usize = 6;
 }
@@ -30,14 +30,9 @@ mod colors {
     use super::Color;
     use crate::ui::theme;
 
-    pub const CHANGE_ID: Color = Color::Cyan;
     pub const AUTHOR: Color = Color::White;
-    /// Timestamp color - using a lighter gray for better visibility on dark terminals
-    pub const TIMESTAMP: Color = Color::Gray;
-    /// Line number color - same as timestamp for consistency
+    /// Line number color - using a lighter gray for better visibility on dark terminals
     pub const LINE_NUMBER: Color = Color::Gray;
-    /// Continuation marker (↑) - can be darker as it's less important
-    pub const CONTINUATION: Color = Color::DarkGray;
     /// Selected line background (uses common theme)
     pub const SELECTED_BG: Color = theme::selection::BG;
     /// Selected line foreground (uses common theme)
@@ -47,7 +42,19 @@ mod colors {
 impl BlameView {
     /// Render the blame view
     pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
-        let title = format!(" Blame View: {} ", self.file_path());
+        let breadcrumb = self.breadcrumb();
+        let mut title = if breadcrumb.len() > 1 {
+            format!(
+                " Blame View: {} [{}] ",
+                self.file_path(),
+                breadcrumb.join(" › ")
+            )
+        } else {
+            format!(" Blame View: {} ", self.file_path())
+        };
+        if let Some(label) = self.overflow.label() {
+            title.push_str(&format!("[{}] ", label));
+        }
 
         // Build title with optional notification
         let title_width = title.len();
@@ -74,31 +81,72 @@ impl BlameView {
         }
 
         // Calculate scroll offset
-        let scroll_offset = self.calculate_scroll_offset(inner_height);
+        let mut scroll_offset = self.calculate_scroll_offset(inner_height);
+        let time_range = self.content.time_range();
+        let timestamp_width = self.content.timestamp_width(&self.timestamp_format);
+        let build = |idx: usize| {
+            let annotation = &self.content.lines[idx];
+            self.build_annotation_line(
+                annotation,
+                time_range,
+                timestamp_width,
+                idx == self.selected_index,
+            )
+        };
+
+        // Wrapped lines take several rows: scroll further until the
+        // selected line's rows fit
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let rows = |line: &Line| line.width().div_ceil(inner_width.max(1)).max(1);
+        if self.overflow.wrap {
+            let selected = self.selected_index.min(self.content.len() - 1);
+            let mut used: usize = (scroll_offset..=selected)
+                .map(|idx| rows(&build(idx)))
+                .sum();
+            while used > inner_height && scroll_offset < selected {
+                used -= rows(&build(scroll_offset));
+                scroll_offset += 1;
+            }
+        }
 
         // Build lines
         let mut lines: Vec<Line> = Vec::new();
-        for (idx, annotation) in self.content.lines.iter().enumerate().skip(scroll_offset) {
-            if lines.len() >= inner_height {
+        let mut used = 0;
+        for idx in scroll_offset..self.content.len() {
+            if used >= inner_height {
                 break;
             }
-
-            let is_selected = idx == self.selected_index;
-            let line = self.build_annotation_line(annotation, is_selected);
+            let line = build(idx);
+            used += if self.overflow.wrap { rows(&line) } else { 1 };
             lines.push(line);
         }
 
-        let paragraph = Paragraph::new(lines).block(block);
+        let mut paragraph = Paragraph::new(lines).block(block);
+        if self.overflow.wrap {
+            paragraph = paragraph.wrap(Wrap { trim: false });
+        }
         frame.render_widget(paragraph, area);
     }
 
     /// Build a single annotation line
+    ///
+    /// The gutter and timestamp are colored by age within `time_range`; the
+    /// change ID and continuation marker share a per-change color so hunks
+    /// from the same change stand out as a group. The timestamp column is
+    /// `timestamp_width` wide (it follows `tij.timestamp-format`).
     fn build_annotation_line(
         &self,
         annotation: &crate::model::AnnotationLine,
+        time_range: Option<(i64, i64)>,
+        timestamp_width: usize,
         is_selected: bool,
     ) -> Line<'static> {
-        let mut spans = Vec::new();
+        let heat = heat_color(annotation.epoch_seconds(), time_range);
+        let change_color = change_color(annotation.change_id.as_str());
+        let mut spans = vec![Span::styled(
+            format!("{:<width$}", "▌", width = layout::GUTTER_WIDTH),
+            Style::default().fg(heat),
+        )];
 
         if annotation.first_in_hunk {
             // Full display for first line in hunk
@@ -106,10 +154,10 @@ impl BlameView {
             spans.push(Span::styled(
                 format!(
                     "{:<width$}",
-                    annotation.change_id,
+                    annotation.change_id.as_str(),
                     width = layout::CHANGE_ID_WIDTH
                 ),
-                Style::default().fg(colors::CHANGE_ID),
+                Style::default().fg(change_color),
             ));
             spans.push(Span::raw(" "));
 
@@ -122,19 +170,19 @@ impl BlameView {
             spans.push(Span::raw(" "));
 
             // Timestamp
-            let timestamp = annotation.short_timestamp();
+            let timestamp = annotation.display_timestamp(&self.timestamp_format);
             spans.push(Span::styled(
-                format!("{:<width$}", timestamp, width = layout::TIMESTAMP_WIDTH),
-                Style::default().fg(colors::TIMESTAMP),
+                format!("{:<width$}", timestamp, width = timestamp_width),
+                Style::default().fg(heat),
             ));
             spans.push(Span::raw(" "));
         } else {
             // Continuation line - show "↑" indicator
             let continuation_width =
-                layout::CHANGE_ID_WIDTH + 1 + layout::AUTHOR_WIDTH + 1 + layout::TIMESTAMP_WIDTH;
+                layout::CHANGE_ID_WIDTH + 1 + layout::AUTHOR_WIDTH + 1 + timestamp_width;
             spans.push(Span::styled(
                 format!("{:>width$} ", "↑", width = continuation_width),
-                Style::default().fg(colors::CONTINUATION),
+                Style::default().fg(change_color),
             ));
         }
 
@@ -150,7 +198,7 @@ impl BlameView {
 
         // Content (trim trailing newline if present)
         let content = annotation.content.trim_end_matches('\n');
-        spans.push(Span::raw(content.to_string()));
+        spans.push(Span::raw(self.overflow.visible(content).to_string()));
 
         let mut line = Line::from(spans);
 
@@ -167,3 +215,63 @@ impl BlameView {
         line
     }
 }
+
+/// Heatmap color for a line's age: oldest line in the file gets the first
+/// color, newest the last. Lines without a parsable timestamp (or a file
+/// whose lines all share one timestamp) use the newest color.
+fn heat_color(epoch: Option<i64>, time_range: Option<(i64, i64)>) -> Color {
+    let heat = &theme::blame_view::HEAT;
+    let (Some(t), Some((oldest, newest))) = (epoch, time_range) else {
+        return heat[heat.len() - 1];
+    };
+    if newest <= oldest {
+        return heat[heat.len() - 1];
+    }
+    let level = (t - oldest) as i128 * (heat.len() - 1) as i128 / (newest - oldest) as i128;
+    heat[level.clamp(0, heat.len() as i128 - 1) as usize]
+}
+
+/// Stable color for a change ID (FNV-1a hash into the palette), so the same
+/// change keeps its color across lines, scrolls and sessions
+fn change_color(change_id: &str) -> Color {
+    let palette = &theme::blame_view::CHANGE_PALETTE;
+    let hash = change_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+    palette[(hash % palette.len() as u64) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heat_color_spans_oldest_to_newest() {
+        let heat = &theme::blame_view::HEAT;
+        let range = Some((100, 200));
+        assert_eq!(heat_color(Some(100), range), heat[0]);
+        assert_eq!(heat_color(Some(200), range), heat[heat.len() - 1]);
+        assert_eq!(heat_color(Some(150), range), heat[(heat.len() - 1) / 2]);
+    }
+
+    #[test]
+    fn test_heat_color_without_spread_uses_newest() {
+        let newest = theme::blame_view::HEAT[theme::blame_view::HEAT.len() - 1];
+        assert_eq!(heat_color(Some(100), Some((100, 100))), newest);
+        assert_eq!(heat_color(None, Some((100, 200))), newest);
+        assert_eq!(heat_color(Some(100), None), newest);
+    }
+
+    #[test]
+    fn test_change_color_is_stable_per_change() {
+        assert_eq!(change_color("twzksoxt"), change_color("twzksoxt"));
+        let distinct: std::collections::HashSet<_> =
+            ["aaaaaaaa", "bbbbbbbb", "kkmpptxz", "zxsrvopz"]
+                .iter()
+                .map(|id| change_color(id))
+                .collect();
+        assert!(distinct.len() > 1);
+    }
+}
@@ -1,5 +1,7 @@
 //! Bookmark View key handling
 
+use std::time::Instant;
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::{BookmarkAction, BookmarkView};
@@ -13,7 +15,7 @@ impl BookmarkView {
             match key.code {
                 KeyCode::Enter => {
                     let old = state.old_name.clone();
-                    let new = state.input_buffer.clone();
+                    let new = state.input_buffer.text().to_string();
                     self.rename_state = None;
                     return BookmarkAction::ConfirmRename {
                         old_name: old,
@@ -24,19 +26,10 @@ impl BookmarkView {
                     self.rename_state = None;
                     return BookmarkAction::CancelRename;
                 }
-                KeyCode::Backspace => {
-                    state.backspace();
-                    return BookmarkAction::None;
-                }
-                KeyCode::Char(c)
-                    if !key
-                        .modifiers
-                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
-                {
-                    state.insert_char(c);
+                _ => {
+                    state.input_buffer.handle_key(key);
                     return BookmarkAction::None;
                 }
-                _ => return BookmarkAction::None,
             }
         }
 
@@ -137,6 +130,33 @@ impl BookmarkView {
                     BookmarkAction::None
                 }
             }
+            k if k == keys::PUSH_QUEUE_TOGGLE => match self.selected_bookmark() {
+                Some(info) if info.bookmark.remote.is_none() => {
+                    BookmarkAction::TogglePushQueue(info.bookmark.name.clone())
+                }
+                _ => BookmarkAction::None,
+            },
+            k if k == keys::PULL_REQUEST => match self.selected_bookmark() {
+                Some(info) if info.bookmark.remote.is_none() => {
+                    BookmarkAction::PullRequest(info.bookmark.name.clone())
+                }
+                _ => BookmarkAction::None,
+            },
+            k if k == keys::SORT_CYCLE => BookmarkAction::CycleSort,
+            k if k == keys::BOOKMARK_RETARGET => BookmarkAction::Retarget,
+            k if k == keys::REMOTE_FILTER_NEXT || k == keys::REMOTE_FILTER_PREV => {
+                self.cycle_remote_filter(k == keys::REMOTE_FILTER_NEXT);
+                BookmarkAction::None
+            }
+            // Unbound letters jump to bookmarks by name
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.type_ahead_jump(c, Instant::now());
+                BookmarkAction::None
+            }
             _ => BookmarkAction::None,
         }
     }
@@ -3,7 +3,12 @@
 mod input;
 mod render;
 
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::time::Instant;
+
 use crate::model::BookmarkInfo;
+use crate::ui::components::{LineInput, TypeAhead, type_ahead};
 use crate::ui::navigation;
 
 /// Action returned by the Bookmark View after handling input
@@ -31,6 +36,14 @@ pub enum BookmarkAction {
     Move(String),
     /// Move attempted on remote bookmark (show info notification)
     MoveUnavailable,
+    /// Add/remove selected local bookmark in the push queue (name)
+    TogglePushQueue(String),
+    /// Open/copy/create the pull request of the selected local bookmark (name)
+    PullRequest(String),
+    /// Switch to the next sort mode
+    CycleSort,
+    /// Offer to move bookmarks left on rewritten commits
+    Retarget,
 }
 
 /// Bookmark rename inline edit state
@@ -38,51 +51,48 @@ pub enum BookmarkAction {
 pub struct RenameState {
     /// Original bookmark name
     pub old_name: String,
-    /// Current input buffer
-    pub input_buffer: String,
-    /// Cursor position in char count (NOT byte offset)
-    pub cursor: usize,
+    /// New name being edited (starts as the old name)
+    pub input_buffer: LineInput,
 }
 
 impl RenameState {
     pub fn new(old_name: String) -> Self {
-        let cursor = old_name.chars().count();
         Self {
-            input_buffer: old_name.clone(),
+            input_buffer: LineInput::new(old_name.clone()),
             old_name,
-            cursor,
         }
     }
+}
 
-    /// Get byte offset for current cursor position (char-based → byte-based)
-    fn cursor_byte_offset(&self) -> usize {
-        self.input_buffer
-            .char_indices()
-            .nth(self.cursor)
-            .map(|(i, _)| i)
-            .unwrap_or(self.input_buffer.len())
-    }
-
-    /// Remove the character before the cursor (backspace)
-    pub fn backspace(&mut self) {
-        if self.cursor > 0 {
-            // Find the byte range of the char before cursor
-            let byte_offset = self
-                .input_buffer
-                .char_indices()
-                .nth(self.cursor - 1)
-                .map(|(i, _)| i)
-                .unwrap_or(0);
-            self.input_buffer.remove(byte_offset);
-            self.cursor -= 1;
+/// Order of bookmarks within each group (`S` cycles)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BookmarkSort {
+    /// Alphabetical
+    #[default]
+    Name,
+    /// Most recently committed target first
+    Date,
+    /// Most commits ahead of the remote first
+    Ahead,
+}
+
+impl BookmarkSort {
+    /// Next mode in the `S` cycle
+    pub fn next(self) -> Self {
+        match self {
+            Self::Name => Self::Date,
+            Self::Date => Self::Ahead,
+            Self::Ahead => Self::Name,
         }
     }
 
-    /// Insert a character at the cursor position
-    pub fn insert_char(&mut self, c: char) {
-        let byte_offset = self.cursor_byte_offset();
-        self.input_buffer.insert(byte_offset, c);
-        self.cursor += 1;
+    /// Short name shown in the title and notification
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Date => "date",
+            Self::Ahead => "ahead",
+        }
     }
 }
 
@@ -108,6 +118,19 @@ pub struct BookmarkView {
     scroll_offset: usize,
     /// Rename input state (Some = rename mode active)
     pub(crate) rename_state: Option<RenameState>,
+    /// Type-ahead prefix for jumping to bookmarks by name
+    type_ahead: TypeAhead,
+    /// Remote names with bookmarks, sorted (headers split by remote when >1)
+    remotes: Vec<String>,
+    /// Only list this remote's bookmarks (`]`/`[` cycle)
+    remote_filter: Option<String>,
+    /// Local bookmarks in the push queue (marked `Q`)
+    queued: Vec<String>,
+    /// Bookmarks with an open pull/merge request, and the forge's badge
+    open_requests: HashSet<String>,
+    request_label: &'static str,
+    /// Order within each group
+    sort: BookmarkSort,
 }
 
 impl Default for BookmarkView {
@@ -125,6 +148,13 @@ impl BookmarkView {
             selected: 0,
             scroll_offset: 0,
             rename_state: None,
+            type_ahead: TypeAhead::default(),
+            remotes: Vec::new(),
+            remote_filter: None,
+            queued: Vec::new(),
+            open_requests: HashSet::new(),
+            request_label: "PR",
+            sort: BookmarkSort::default(),
         }
     }
 
@@ -134,40 +164,158 @@ impl BookmarkView {
         bookmarks.retain(|b| b.bookmark.remote.as_deref() != Some("git"));
 
         // Sort: local first, then tracked remote, then untracked remote
-        // Within each group, sort alphabetically
-        bookmarks.sort_by(|a, b| {
-            let group_a = bookmark_group_order(&a.bookmark);
-            let group_b = bookmark_group_order(&b.bookmark);
-            group_a
-                .cmp(&group_b)
-                .then(a.bookmark.full_name().cmp(&b.bookmark.full_name()))
-        });
+        // Within each group, sort by remote, then by the sort mode
+        let sort = self.sort;
+        bookmarks.sort_by(|a, b| compare_bookmarks(a, b, sort));
+
+        let mut remotes: Vec<String> = bookmarks
+            .iter()
+            .filter_map(|b| b.bookmark.remote.clone())
+            .collect();
+        remotes.sort();
+        remotes.dedup();
+        if self
+            .remote_filter
+            .as_ref()
+            .is_some_and(|remote| !remotes.contains(remote))
+        {
+            self.remote_filter = None;
+        }
+
+        self.bookmarks = bookmarks;
+        self.remotes = remotes;
+        self.rebuild_rows();
+    }
+
+    /// Build display rows with headers for the bookmarks passing the filter
+    ///
+    /// With several remotes, each remote section gets one header per remote
+    /// with its bookmark count.
+    fn rebuild_rows(&mut self) {
+        let split_by_remote = self.remotes.len() > 1;
+        let section_key = |info: &BookmarkInfo| {
+            let remote = split_by_remote
+                .then(|| info.bookmark.remote.clone())
+                .flatten();
+            (bookmark_group_order(&info.bookmark), remote)
+        };
+        let visible: Vec<usize> = (0..self.bookmarks.len())
+            .filter(|&idx| match self.remote_filter {
+                Some(ref remote) => self.bookmarks[idx].bookmark.remote.as_ref() == Some(remote),
+                None => true,
+            })
+            .collect();
 
-        // Build display rows with headers
         let mut rows = Vec::new();
-        let mut current_group = None;
-
-        for (idx, info) in bookmarks.iter().enumerate() {
-            let group = bookmark_group_order(&info.bookmark);
-            if current_group != Some(group) {
-                current_group = Some(group);
-                let header = match group {
-                    0 => "── Local ──",
-                    1 => "── Remote (tracked) ──",
-                    2 => "── Remote (untracked) ──",
-                    _ => "── Other ──",
+        let mut current_section = None;
+        for &idx in &visible {
+            let section = section_key(&self.bookmarks[idx]);
+            if current_section.as_ref() != Some(&section) {
+                let header = match (&section.0, &section.1) {
+                    (0, _) => "── Local ──".to_string(),
+                    (group, Some(remote)) => {
+                        let count = visible
+                            .iter()
+                            .filter(|&&i| section_key(&self.bookmarks[i]) == section)
+                            .count();
+                        let kind = if *group == 1 { "tracked" } else { "untracked" };
+                        format!("── Remote ({}): {} ({}) ──", kind, remote, count)
+                    }
+                    (1, None) => "── Remote (tracked) ──".to_string(),
+                    (2, None) => "── Remote (untracked) ──".to_string(),
+                    _ => "── Other ──".to_string(),
                 };
-                rows.push(DisplayRow::Header(header.to_string()));
+                rows.push(DisplayRow::Header(header));
+                current_section = Some(section);
             }
             rows.push(DisplayRow::Bookmark(idx));
         }
 
-        self.bookmarks = bookmarks;
         self.display_rows = rows;
         self.selected = self.first_bookmark_row().unwrap_or(0);
         self.scroll_offset = 0;
     }
 
+    /// Current order within each group
+    pub fn sort(&self) -> BookmarkSort {
+        self.sort
+    }
+
+    /// Switch to the next sort mode, keeping the selected bookmark
+    pub fn cycle_sort(&mut self) -> BookmarkSort {
+        self.sort = self.sort.next();
+        let selected = self
+            .selected_bookmark()
+            .map(|info| info.bookmark.full_name());
+        let sort = self.sort;
+        self.bookmarks.sort_by(|a, b| compare_bookmarks(a, b, sort));
+        self.rebuild_rows();
+        if let Some(name) = selected
+            && let Some(row) = self.display_rows.iter().position(|row| {
+                matches!(row, DisplayRow::Bookmark(idx)
+                    if self.bookmarks[*idx].bookmark.full_name() == name)
+            })
+        {
+            self.selected = row;
+        }
+        self.sort
+    }
+
+    /// Mirror the app's push queue for the `Q` markers
+    pub fn set_queued(&mut self, queue: &[String]) {
+        self.queued = queue.to_vec();
+    }
+
+    /// Whether local bookmark `name` is in the push queue
+    pub fn is_queued(&self, name: &str) -> bool {
+        self.queued.iter().any(|queued| queued == name)
+    }
+
+    /// Set the bookmarks with an open request (`label`: "PR" or "MR")
+    pub fn set_open_requests(&mut self, branches: HashSet<String>, label: &'static str) {
+        self.open_requests = branches;
+        self.request_label = label;
+    }
+
+    /// Mark one more bookmark as having an open request
+    pub fn add_open_request(&mut self, name: &str) {
+        self.open_requests.insert(name.to_string());
+    }
+
+    /// Badge for local bookmark `name` if it has an open request
+    pub fn request_badge(&self, name: &str) -> Option<&'static str> {
+        self.open_requests
+            .contains(name)
+            .then_some(self.request_label)
+    }
+
+    /// Remote whose bookmarks are the only ones listed, if filtered
+    pub fn remote_filter(&self) -> Option<&str> {
+        self.remote_filter.as_deref()
+    }
+
+    /// Cycle the remote filter: all → first remote → … → last remote → all
+    /// (`forward = false` goes the other way)
+    pub fn cycle_remote_filter(&mut self, forward: bool) {
+        if self.remotes.is_empty() {
+            return;
+        }
+        let position = self
+            .remote_filter
+            .as_ref()
+            .and_then(|remote| self.remotes.iter().position(|r| r == remote));
+        // Positions 0..len are remotes, len is "all"
+        let len = self.remotes.len();
+        let current = position.unwrap_or(len);
+        let next = if forward {
+            (current + 1) % (len + 1)
+        } else {
+            (current + len) % (len + 1)
+        };
+        self.remote_filter = self.remotes.get(next).cloned();
+        self.rebuild_rows();
+    }
+
     /// Get the currently selected bookmark
     pub fn selected_bookmark(&self) -> Option<&BookmarkInfo> {
         if let Some(DisplayRow::Bookmark(idx)) = self.display_rows.get(self.selected) {
@@ -177,11 +325,24 @@ impl BookmarkView {
         }
     }
 
+    /// Whether any bookmark carries ahead/behind counts (shows the column)
+    pub fn has_tracking_counts(&self) -> bool {
+        self.bookmarks.iter().any(|b| b.bookmark.tracking.is_some())
+    }
+
     /// Total number of bookmarks (excluding headers)
     pub fn bookmark_count(&self) -> usize {
         self.bookmarks.len()
     }
 
+    /// Number of bookmarks passing the remote filter
+    pub fn visible_count(&self) -> usize {
+        self.display_rows
+            .iter()
+            .filter(|row| matches!(row, DisplayRow::Bookmark(_)))
+            .count()
+    }
+
     /// Move selection to next bookmark row (skip headers)
     pub fn select_next(&mut self) {
         let max = self.display_rows.len().saturating_sub(1);
@@ -228,6 +389,25 @@ impl BookmarkView {
         }
     }
 
+    /// Type-ahead: select the next bookmark whose name starts with the typed letters
+    pub fn type_ahead_jump(&mut self, c: char, now: Instant) {
+        let query = self.type_ahead.push(c, now);
+        // Headers get an empty label so they never match
+        let labels: Vec<String> = self
+            .display_rows
+            .iter()
+            .map(|row| match row {
+                DisplayRow::Bookmark(idx) => self.bookmarks[*idx].bookmark.full_name(),
+                DisplayRow::Header(_) => String::new(),
+            })
+            .collect();
+        if let Some(row) =
+            type_ahead::find_match(labels.iter().map(String::as_str), self.selected, query)
+        {
+            self.selected = row;
+        }
+    }
+
     fn first_bookmark_row(&self) -> Option<usize> {
         self.display_rows
             .iter()
@@ -241,6 +421,22 @@ impl BookmarkView {
     }
 }
 
+/// Group, then remote, then `sort`; ties fall back to the name
+///
+/// Bookmarks without a date (remote-only) or tracking counts sort last.
+fn compare_bookmarks(a: &BookmarkInfo, b: &BookmarkInfo, sort: BookmarkSort) -> Ordering {
+    let ahead = |info: &BookmarkInfo| info.bookmark.tracking.map(|t| t.ahead);
+    bookmark_group_order(&a.bookmark)
+        .cmp(&bookmark_group_order(&b.bookmark))
+        .then(a.bookmark.remote.cmp(&b.bookmark.remote))
+        .then(match sort {
+            BookmarkSort::Name => Ordering::Equal,
+            BookmarkSort::Date => b.timestamp.cmp(&a.timestamp),
+            BookmarkSort::Ahead => ahead(b).cmp(&ahead(a)),
+        })
+        .then(a.bookmark.full_name().cmp(&b.bookmark.full_name()))
+}
+
 /// Return sort order: 0=local, 1=tracked remote, 2=untracked remote
 fn bookmark_group_order(bookmark: &crate::model::Bookmark) -> u8 {
     if bookmark.remote.is_none() {
@@ -255,6 +451,7 @@ fn bookmark_group_order(bookmark: &crate::model::Bookmark) -> u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys;
     use crate::model::{Bookmark, ChangeId};
     use crossterm::event::{KeyCode, KeyEvent};
 
@@ -264,10 +461,12 @@ mod tests {
                 name: name.to_string(),
                 remote: None,
                 is_tracked: false,
+                tracking: None,
             },
             change_id: change_id.map(|s| ChangeId::new(s.to_string())),
             commit_id: None,
             description: desc.map(|s| s.to_string()),
+            timestamp: None,
         }
     }
 
@@ -277,10 +476,12 @@ mod tests {
                 name: name.to_string(),
                 remote: Some(remote.to_string()),
                 is_tracked: true,
+                tracking: None,
             },
             change_id: None,
             commit_id: None,
             description: None,
+            timestamp: None,
         }
     }
 
@@ -290,10 +491,12 @@ mod tests {
                 name: name.to_string(),
                 remote: Some(remote.to_string()),
                 is_tracked: false,
+                tracking: None,
             },
             change_id: None,
             commit_id: None,
             description: None,
+            timestamp: None,
         }
     }
 
@@ -303,10 +506,12 @@ mod tests {
                 name: name.to_string(),
                 remote: Some("git".to_string()),
                 is_tracked: true,
+                tracking: None,
             },
             change_id: None,
             commit_id: None,
             description: None,
+            timestamp: None,
         }
     }
 
@@ -471,6 +676,18 @@ mod tests {
         assert!(matches!(action, BookmarkAction::Delete(n) if n == "feature-x"));
     }
 
+    #[test]
+    fn test_handle_key_push_queue_local_only() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_test_bookmarks());
+        let action = view.handle_key(KeyEvent::from(keys::PUSH_QUEUE_TOGGLE));
+        assert!(matches!(action, BookmarkAction::TogglePushQueue(n) if n == "feature-x"));
+
+        view.select_last();
+        let action = view.handle_key(KeyEvent::from(keys::PUSH_QUEUE_TOGGLE));
+        assert!(matches!(action, BookmarkAction::None));
+    }
+
     #[test]
     fn test_handle_key_delete_on_remote_noop() {
         let mut view = BookmarkView::new();
@@ -589,13 +806,13 @@ mod tests {
         view.handle_key(KeyEvent::from(KeyCode::Backspace));
         let state = view.rename_state.as_ref().unwrap();
         assert_eq!(state.input_buffer, "機能ブラン");
-        assert_eq!(state.cursor, 5);
+        assert_eq!(state.input_buffer.cursor(), 5);
 
         // Another backspace
         view.handle_key(KeyEvent::from(KeyCode::Backspace));
         let state = view.rename_state.as_ref().unwrap();
         assert_eq!(state.input_buffer, "機能ブラ");
-        assert_eq!(state.cursor, 4);
+        assert_eq!(state.input_buffer.cursor(), 4);
     }
 
     #[test]
@@ -607,7 +824,7 @@ mod tests {
         view.handle_key(KeyEvent::from(KeyCode::Char('2')));
         let state = view.rename_state.as_ref().unwrap();
         assert_eq!(state.input_buffer, "テスト2");
-        assert_eq!(state.cursor, 4);
+        assert_eq!(state.input_buffer.cursor(), 4);
     }
 
     #[test]
@@ -619,15 +836,15 @@ mod tests {
         view.handle_key(KeyEvent::from(KeyCode::Backspace));
         let state = view.rename_state.as_ref().unwrap();
         assert_eq!(state.input_buffer, "feat-");
-        assert_eq!(state.cursor, 5);
+        assert_eq!(state.input_buffer.cursor(), 5);
     }
 
     #[test]
     fn test_rename_state_cursor_char_count() {
         // Verify cursor is char count, not byte length
         let state = RenameState::new("日本語".to_string());
-        assert_eq!(state.cursor, 3); // 3 chars, not 9 bytes
-        assert_eq!(state.input_buffer.len(), 9); // 9 bytes
+        assert_eq!(state.input_buffer.cursor(), 3); // 3 chars, not 9 bytes
+        assert_eq!(state.input_buffer.text().len(), 9); // 9 bytes
     }
 
     #[test]
@@ -677,4 +894,183 @@ mod tests {
         let action = view.handle_key(KeyEvent::from(KeyCode::Char('m')));
         assert!(matches!(action, BookmarkAction::MoveUnavailable));
     }
+
+    #[test]
+    fn test_type_ahead_jump_cycles_and_skips_headers() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_test_bookmarks());
+        let now = Instant::now();
+        view.type_ahead_jump('m', now);
+        assert_eq!(
+            view.selected_bookmark().unwrap().bookmark.full_name(),
+            "main"
+        );
+        let later = now + type_ahead::TIMEOUT;
+        view.type_ahead_jump('m', later);
+        assert_eq!(
+            view.selected_bookmark().unwrap().bookmark.full_name(),
+            "main@origin"
+        );
+        // Wraps past the untracked header back to the local group
+        view.type_ahead_jump('f', later + type_ahead::TIMEOUT);
+        assert_eq!(view.selected, 1);
+    }
+
+    #[test]
+    fn test_type_ahead_jump_extends_prefix() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_test_bookmarks());
+        let now = Instant::now();
+        view.type_ahead_jump('M', now);
+        view.type_ahead_jump('A', now);
+        assert_eq!(view.selected, 2);
+        view.type_ahead_jump('x', now);
+        assert_eq!(view.selected, 2, "no match keeps the selection");
+    }
+
+    #[test]
+    fn test_handle_key_unbound_letter_jumps() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_test_bookmarks());
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        assert!(matches!(action, BookmarkAction::None));
+        assert_eq!(
+            view.selected_bookmark().unwrap().bookmark.name,
+            "dependabot/cargo"
+        );
+    }
+
+    fn create_multi_remote_bookmarks() -> Vec<BookmarkInfo> {
+        vec![
+            make_local("main", Some("abc"), None),
+            make_tracked_remote("main", "upstream"),
+            make_tracked_remote("main", "origin"),
+            make_tracked_remote("dev", "origin"),
+            make_untracked_remote("wip", "fork"),
+        ]
+    }
+
+    fn headers(view: &BookmarkView) -> Vec<&str> {
+        view.display_rows
+            .iter()
+            .filter_map(|row| match row {
+                DisplayRow::Header(h) => Some(h.as_str()),
+                DisplayRow::Bookmark(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_multiple_remotes_get_own_headers() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_multi_remote_bookmarks());
+        assert_eq!(
+            headers(&view),
+            [
+                "── Local ──",
+                "── Remote (tracked): origin (2) ──",
+                "── Remote (tracked): upstream (1) ──",
+                "── Remote (untracked): fork (1) ──",
+            ]
+        );
+        if let DisplayRow::Bookmark(idx) = &view.display_rows[3] {
+            assert_eq!(view.bookmarks[*idx].bookmark.full_name(), "dev@origin");
+        }
+    }
+
+    #[test]
+    fn test_remote_filter_cycles_through_remotes() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_multi_remote_bookmarks());
+
+        view.handle_key(KeyEvent::from(keys::REMOTE_FILTER_NEXT));
+        assert_eq!(view.remote_filter(), Some("fork"));
+        assert_eq!(view.visible_count(), 1);
+        assert_eq!(
+            view.selected_bookmark().unwrap().bookmark.full_name(),
+            "wip@fork"
+        );
+
+        view.handle_key(KeyEvent::from(keys::REMOTE_FILTER_NEXT));
+        assert_eq!(view.remote_filter(), Some("origin"));
+        assert_eq!(headers(&view), ["── Remote (tracked): origin (2) ──"]);
+
+        view.handle_key(KeyEvent::from(keys::REMOTE_FILTER_NEXT));
+        view.handle_key(KeyEvent::from(keys::REMOTE_FILTER_NEXT));
+        assert_eq!(view.remote_filter(), None);
+        assert_eq!(view.visible_count(), 5);
+
+        view.handle_key(KeyEvent::from(keys::REMOTE_FILTER_PREV));
+        assert_eq!(view.remote_filter(), Some("upstream"));
+    }
+
+    #[test]
+    fn test_remote_filter_cleared_when_remote_disappears() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_multi_remote_bookmarks());
+        view.cycle_remote_filter(true);
+        assert_eq!(view.remote_filter(), Some("fork"));
+
+        view.set_bookmarks(create_test_bookmarks());
+        assert_eq!(view.remote_filter(), None);
+        assert_eq!(view.visible_count(), 5);
+    }
+
+    #[test]
+    fn test_cycle_sort_orders_locals_and_keeps_selection() {
+        use crate::model::TrackingCounts;
+
+        let mut old = make_local("alpha", Some("aaaa"), None);
+        old.timestamp = Some("2026-01-01 10:00:00".to_string());
+        let mut new = make_local("beta", Some("bbbb"), None);
+        new.timestamp = Some("2026-03-01 10:00:00".to_string());
+        new.bookmark.tracking = Some(TrackingCounts {
+            ahead: 1,
+            behind: 0,
+        });
+        let mut far_ahead = make_local("gamma", Some("cccc"), None);
+        far_ahead.bookmark.tracking = Some(TrackingCounts {
+            ahead: 5,
+            behind: 0,
+        });
+
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(vec![far_ahead, new, old]);
+        let names = |view: &BookmarkView| -> Vec<String> {
+            view.bookmarks
+                .iter()
+                .map(|b| b.bookmark.name.clone())
+                .collect()
+        };
+        assert_eq!(names(&view), ["alpha", "beta", "gamma"]);
+        view.select_first();
+        assert_eq!(view.selected_bookmark().unwrap().bookmark.name, "alpha");
+
+        let action = view.handle_key(KeyEvent::from(keys::SORT_CYCLE));
+        assert!(matches!(action, BookmarkAction::CycleSort));
+        assert_eq!(view.cycle_sort(), BookmarkSort::Date);
+        // Undated targets last
+        assert_eq!(names(&view), ["beta", "alpha", "gamma"]);
+        assert_eq!(view.selected_bookmark().unwrap().bookmark.name, "alpha");
+
+        assert_eq!(view.cycle_sort(), BookmarkSort::Ahead);
+        assert_eq!(names(&view), ["gamma", "beta", "alpha"]);
+
+        assert_eq!(view.cycle_sort(), BookmarkSort::Name);
+        assert_eq!(names(&view), ["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn test_sort_keeps_groups() {
+        let mut view = BookmarkView::new();
+        view.set_bookmarks(create_test_bookmarks());
+        view.cycle_sort();
+        view.cycle_sort();
+        let groups: Vec<u8> = view
+            .bookmarks
+            .iter()
+            .map(|b| bookmark_group_order(&b.bookmark))
+            .collect();
+        assert!(groups.is_sorted(), "{:?}", groups);
+    }
 }
@@ -8,18 +8,27 @@ use ratatui::{
     widgets::Paragraph,
 };
 
-use super::{BookmarkView, DisplayRow};
-use crate::model::{BookmarkInfo, Notification};
+use super::{BookmarkSort, BookmarkView, DisplayRow};
+use crate::model::{BookmarkInfo, Notification, TrackingCounts};
 use crate::ui::{components, navigation, theme};
 
 impl BookmarkView {
     /// Render the bookmark view with optional notification in title bar
     pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
         let count = self.bookmark_count();
-        let title = Line::from(format!(" Bookmarks ({}) ", count))
-            .bold()
-            .cyan()
-            .centered();
+        let mut title = match self.remote_filter() {
+            Some(remote) => format!(
+                " Bookmarks ({}/{}) [remote: {}] ",
+                self.visible_count(),
+                count,
+                remote
+            ),
+            None => format!(" Bookmarks ({}) ", count),
+        };
+        if self.sort() != BookmarkSort::Name {
+            title.push_str(&format!("[sort: {}] ", self.sort().label()));
+        }
+        let title = Line::from(title).bold().cyan().centered();
 
         let title_width = title.width();
         let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
@@ -44,6 +53,7 @@ impl BookmarkView {
         let scroll_offset =
             navigation::adjust_scroll(self.selected, self.scroll_offset, inner_height);
 
+        let show_counts = self.has_tracking_counts();
         let mut lines: Vec<Line> = Vec::new();
         for (idx, row) in self.display_rows.iter().enumerate().skip(scroll_offset) {
             if lines.len() >= inner_height {
@@ -53,7 +63,13 @@ impl BookmarkView {
             let line = match row {
                 DisplayRow::Header(text) => build_header_line(text),
                 DisplayRow::Bookmark(bm_idx) => {
-                    build_bookmark_line(&self.bookmarks[*bm_idx], is_selected)
+                    let info = &self.bookmarks[*bm_idx];
+                    let is_local = info.bookmark.remote.is_none();
+                    let queued = is_local && self.is_queued(&info.bookmark.name);
+                    let badge = is_local
+                        .then(|| self.request_badge(&info.bookmark.name))
+                        .flatten();
+                    build_bookmark_line(info, is_selected, show_counts, queued, badge)
                 }
             };
             lines.push(line);
@@ -70,17 +86,19 @@ impl BookmarkView {
                 width: area.width,
                 height: 3.min(area.height),
             };
-            let input_text = format!("Rename bookmark: {}", state.input_buffer);
-            let input_line = Line::from(vec![
-                Span::styled("Rename bookmark: ", Style::default().fg(Color::Cyan)),
-                Span::styled(
-                    state.input_buffer.clone(),
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
-                ),
+            let mut input_spans = vec![Span::styled(
+                "Rename bookmark: ",
+                Style::default().fg(Color::Cyan),
+            )];
+            input_spans.extend(components::cursor_spans(
+                state.input_buffer.text(),
+                state.input_buffer.cursor(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
                 Span::styled("█", Style::default().fg(Color::White)),
-            ]);
+            ));
+            let input_line = Line::from(input_spans);
             let hint_line = Line::from(vec![
                 Span::styled("[Enter]", Style::default().fg(Color::Green)),
                 Span::raw(" Confirm  "),
@@ -90,7 +108,6 @@ impl BookmarkView {
             let input_block = ratatui::widgets::Block::default()
                 .borders(ratatui::widgets::Borders::TOP)
                 .border_style(Style::default().fg(Color::DarkGray));
-            let _ = input_text; // suppress unused warning
             let input_paragraph = Paragraph::new(vec![input_line, hint_line]).block(input_block);
             frame.render_widget(input_paragraph, input_area);
         }
@@ -106,7 +123,43 @@ fn build_header_line(text: &str) -> Line<'static> {
     )])
 }
 
-fn build_bookmark_line(info: &BookmarkInfo, is_selected: bool) -> Line<'static> {
+/// Width of the `+ahead/-behind` column
+const COUNTS_WIDTH: usize = 9;
+
+/// Right-aligned `+3/-1` cell; blank for bookmarks without tracking info
+fn build_counts_spans(tracking: Option<TrackingCounts>) -> Vec<Span<'static>> {
+    let Some(counts) = tracking else {
+        return vec![Span::raw(" ".repeat(COUNTS_WIDTH))];
+    };
+    let color = |n: usize, active: Color| {
+        Style::default().fg(if n > 0 {
+            active
+        } else {
+            theme::bookmark_view::SYNCED
+        })
+    };
+    let padding = COUNTS_WIDTH.saturating_sub(counts.column().chars().count());
+    vec![
+        Span::raw(" ".repeat(padding)),
+        Span::styled(
+            format!("+{}", counts.ahead),
+            color(counts.ahead, theme::bookmark_view::AHEAD),
+        ),
+        Span::styled("/", Style::default().fg(theme::bookmark_view::SYNCED)),
+        Span::styled(
+            format!("-{}", counts.behind),
+            color(counts.behind, theme::bookmark_view::BEHIND),
+        ),
+    ]
+}
+
+fn build_bookmark_line(
+    info: &BookmarkInfo,
+    is_selected: bool,
+    show_counts: bool,
+    queued: bool,
+    request_badge: Option<&'static str>,
+) -> Line<'static> {
     let is_local = info.bookmark.remote.is_none();
     let is_untracked = info.bookmark.is_untracked_remote();
 
@@ -119,18 +172,33 @@ fn build_bookmark_line(info: &BookmarkInfo, is_selected: bool) -> Line<'static>
         Color::DarkGray
     };
 
+    let marker = if queued {
+        Span::styled("Q ", Style::default().fg(theme::bookmark_view::QUEUED))
+    } else {
+        Span::raw("  ")
+    };
     let mut spans = vec![
-        Span::raw("  "),
+        marker,
         Span::styled(
             format!("{:<30}", truncate_str(&name, 30)),
             Style::default().fg(name_color),
         ),
     ];
+    if show_counts {
+        spans.extend(build_counts_spans(info.bookmark.tracking));
+    }
 
     if is_local {
+        spans.push(match request_badge {
+            Some(badge) => Span::styled(
+                format!(" {:<3}", badge),
+                Style::default().fg(theme::bookmark_view::OPEN_REQUEST),
+            ),
+            None => Span::raw("    "),
+        });
         if let Some(ref change_id) = info.change_id {
             spans.push(Span::styled(
-                format!("  {:<10}", change_id),
+                format!("  {:<10}", change_id.as_str()),
                 Style::default().fg(Color::Yellow),
             ));
         } else {
@@ -219,4 +287,98 @@ mod tests {
     fn truncate_str_empty_string() {
         assert_eq!(truncate_str("", 10), "");
     }
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn info(name: &str, tracking: Option<TrackingCounts>) -> BookmarkInfo {
+        BookmarkInfo {
+            bookmark: crate::model::Bookmark {
+                name: name.to_string(),
+                remote: Some("origin".to_string()),
+                is_tracked: true,
+                tracking,
+            },
+            change_id: None,
+            commit_id: None,
+            description: None,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn counts_column_right_aligned() {
+        let counts = TrackingCounts {
+            ahead: 3,
+            behind: 1,
+        };
+        let text = line_text(&build_bookmark_line(
+            &info("main", Some(counts)),
+            false,
+            true,
+            false,
+            None,
+        ));
+        assert!(text.ends_with("     +3/-1"), "{text:?}");
+    }
+
+    #[test]
+    fn counts_column_aligns_rows_without_tracking() {
+        let with = build_bookmark_line(
+            &info("a", Some(TrackingCounts::default())),
+            false,
+            true,
+            false,
+            None,
+        );
+        let without = build_bookmark_line(&info("b", None), false, true, false, None);
+        assert_eq!(
+            line_text(&with).chars().count(),
+            line_text(&without).chars().count()
+        );
+    }
+
+    #[test]
+    fn counts_column_hidden_when_disabled() {
+        let counts = TrackingCounts {
+            ahead: 3,
+            behind: 1,
+        };
+        let text = line_text(&build_bookmark_line(
+            &info("main", Some(counts)),
+            false,
+            false,
+            false,
+            None,
+        ));
+        assert!(!text.contains("+3/-1"));
+    }
+
+    #[test]
+    fn open_request_badge_follows_name() {
+        let mut local = info("main", None);
+        local.bookmark.remote = None;
+        let text = line_text(&build_bookmark_line(
+            &local,
+            false,
+            false,
+            false,
+            Some("PR"),
+        ));
+        assert!(text.starts_with("  main"), "{text:?}");
+        assert!(text.contains(" PR "), "{text:?}");
+    }
+
+    #[test]
+    fn queued_bookmark_gets_marker() {
+        let text = line_text(&build_bookmark_line(
+            &info("main", None),
+            false,
+            false,
+            true,
+            None,
+        ));
+        assert!(text.starts_with("Q main"), "{text:?}");
+    }
 }
@@ -35,6 +35,9 @@ impl CommandHistoryView {
                     CommandHistoryAction::None
                 }
             }
+            k if k == keys::BUG_REPORT => CommandHistoryAction::BugReport,
+            k if k == keys::COMMAND_LOG => CommandHistoryAction::OpenCommandLog,
+            k if k == keys::DIAGNOSTICS => CommandHistoryAction::OpenDiagnostics,
             KeyCode::Char('q') | KeyCode::Esc => CommandHistoryAction::Back,
             _ => CommandHistoryAction::None,
         }
@@ -3,6 +3,8 @@
 mod input;
 mod render;
 
+pub(crate) use render::format_timestamp;
+
 use crate::ui::navigation;
 
 /// Action returned by the Command History View after handling input
@@ -14,6 +16,12 @@ pub enum CommandHistoryAction {
     Back,
     /// Toggle detail expansion for the selected record
     ToggleDetail(usize),
+    /// Write a bug report bundle (versions, commands, errors, config)
+    BugReport,
+    /// Open the Command Log View (every jj process, read-only ones too)
+    OpenCommandLog,
+    /// Open the Diagnostics View (versions, tools, remotes, features)
+    OpenDiagnostics,
 }
 
 /// Command History View state
@@ -185,6 +193,27 @@ mod tests {
         assert_eq!(action, CommandHistoryAction::None);
     }
 
+    #[test]
+    fn test_handle_key_bug_report() {
+        let mut view = CommandHistoryView::new();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('B')), 0);
+        assert_eq!(action, CommandHistoryAction::BugReport);
+    }
+
+    #[test]
+    fn test_handle_key_open_command_log() {
+        let mut view = CommandHistoryView::new();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('L')), 0);
+        assert_eq!(action, CommandHistoryAction::OpenCommandLog);
+    }
+
+    #[test]
+    fn test_handle_key_open_diagnostics() {
+        let mut view = CommandHistoryView::new();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('D')), 0);
+        assert_eq!(action, CommandHistoryAction::OpenDiagnostics);
+    }
+
     #[test]
     fn test_handle_key_navigation_j_k() {
         let mut view = CommandHistoryView::new();
@@ -218,7 +218,7 @@ fn build_detail_lines(record: &CommandRecord, _width: usize) -> Vec<Line<'static
 }
 
 /// Format a SystemTime as HH:MM:SS
-fn format_timestamp(timestamp: &std::time::SystemTime) -> String {
+pub(crate) fn format_timestamp(timestamp: &std::time::SystemTime) -> String {
     use std::time::UNIX_EPOCH;
     let secs = timestamp
         .duration_since(UNIX_EPOCH)
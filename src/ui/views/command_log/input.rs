@@ -0,0 +1,45 @@
+//! Command Log View key handling
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{CommandLogAction, CommandLogView};
+use crate::jj::CommandLogEntry;
+use crate::keys;
+
+impl CommandLogView {
+    /// Handle key input
+    ///
+    /// `entries` are the command log entries as rendered, newest first.
+    pub fn handle_key(&mut self, key: KeyEvent, entries: &[CommandLogEntry]) -> CommandLogAction {
+        let total = entries.len();
+        match key.code {
+            k if keys::is_move_down(k) => {
+                self.select_next(total);
+                CommandLogAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.select_prev();
+                CommandLogAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.select_first();
+                CommandLogAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.select_last(total);
+                CommandLogAction::None
+            }
+            KeyCode::Enter if total > 0 => {
+                self.toggle_detail();
+                CommandLogAction::None
+            }
+            k if k == keys::YANK => entries
+                .get(self.selected)
+                .map_or(CommandLogAction::None, |entry| {
+                    CommandLogAction::CopyCommand(entry.id)
+                }),
+            KeyCode::Char('q') | KeyCode::Esc => CommandLogAction::Back,
+            _ => CommandLogAction::None,
+        }
+    }
+}
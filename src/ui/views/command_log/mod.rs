@@ -0,0 +1,135 @@
+//! Command Log View for inspecting every jj process tij has run
+
+mod input;
+mod render;
+
+use crate::ui::navigation;
+
+/// Action returned by the Command Log View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandLogAction {
+    /// No action needed
+    None,
+    /// Go back to previous view
+    Back,
+    /// Copy the command of the entry with this id to the clipboard
+    CopyCommand(u64),
+}
+
+/// Command Log View state
+///
+/// Entries are shown newest first, as returned by
+/// [`CommandLog::entries`](crate::jj::CommandLog::entries).
+#[derive(Debug, Default)]
+pub struct CommandLogView {
+    /// Selected index
+    selected: usize,
+    /// Scroll offset
+    scroll_offset: usize,
+    /// Index of expanded detail (None if no detail open)
+    expanded_index: Option<usize>,
+}
+
+impl CommandLogView {
+    /// Create a new Command Log View
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move selection to next entry
+    pub fn select_next(&mut self, total: usize) {
+        let max = total.saturating_sub(1);
+        self.selected = navigation::select_next(self.selected, max);
+        self.expanded_index = None;
+    }
+
+    /// Move selection to previous entry
+    pub fn select_prev(&mut self) {
+        self.selected = navigation::select_prev(self.selected);
+        self.expanded_index = None;
+    }
+
+    /// Go to first (newest) entry
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.scroll_offset = 0;
+        self.expanded_index = None;
+    }
+
+    /// Go to last (oldest) entry
+    pub fn select_last(&mut self, total: usize) {
+        if total > 0 {
+            self.selected = total - 1;
+        }
+        self.expanded_index = None;
+    }
+
+    /// Toggle detail for the currently selected entry
+    pub fn toggle_detail(&mut self) {
+        if self.expanded_index == Some(self.selected) {
+            self.expanded_index = None;
+        } else {
+            self.expanded_index = Some(self.selected);
+        }
+    }
+
+    /// Start over at the newest entry (called when the view is opened)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jj::CommandLogEntry;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    /// Entries newest first, with ids counting down from `newest_id`
+    fn entries(newest_id: u64, count: u64) -> Vec<CommandLogEntry> {
+        (0..count)
+            .map(|i| CommandLogEntry {
+                id: newest_id - i,
+                args: vec!["status".to_string()],
+                started: UNIX_EPOCH,
+                duration: Duration::ZERO,
+                exit_code: Some(0),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_navigation_closes_detail() {
+        let mut view = CommandLogView::new();
+        view.toggle_detail();
+        assert_eq!(view.expanded_index, Some(0));
+        view.select_next(3);
+        assert_eq!(view.selected, 1);
+        assert!(view.expanded_index.is_none());
+        view.select_last(3);
+        assert_eq!(view.selected, 2);
+        view.select_first();
+        assert_eq!(view.selected, 0);
+    }
+
+    #[test]
+    fn test_handle_key_copy() {
+        let mut view = CommandLogView::new();
+        let entries = entries(9, 3);
+        view.handle_key(KeyEvent::from(KeyCode::Char('j')), &entries);
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('y')), &entries);
+        assert_eq!(action, CommandLogAction::CopyCommand(8));
+    }
+
+    #[test]
+    fn test_handle_key_empty_log() {
+        let mut view = CommandLogView::new();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('y')), &[]);
+        assert_eq!(action, CommandLogAction::None);
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter), &[]);
+        assert_eq!(action, CommandLogAction::None);
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('q')), &[]);
+        assert_eq!(action, CommandLogAction::Back);
+    }
+}
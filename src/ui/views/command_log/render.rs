@@ -0,0 +1,152 @@
+//! Command Log View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::CommandLogView;
+use crate::jj::CommandLogEntry;
+use crate::model::Notification;
+use crate::ui::{components, navigation, theme, views::format_timestamp};
+
+/// Lines added below an expanded entry (command, duration, separator)
+const DETAIL_LINES: usize = 3;
+
+impl CommandLogView {
+    /// Render the command log view (`entries` newest first)
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        entries: &[CommandLogEntry],
+        notification: Option<&Notification>,
+    ) {
+        let failed = entries.iter().filter(|e| !e.succeeded()).count();
+        let title = if failed > 0 {
+            format!(" Command Log ({}, {} failed) ", entries.len(), failed)
+        } else {
+            format!(" Command Log ({}) ", entries.len())
+        };
+        let title = Line::from(title).bold().cyan().centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        if entries.is_empty() {
+            let paragraph = Paragraph::new("No jj commands run yet").block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let inner_height = area.height.saturating_sub(2) as usize;
+        if inner_height == 0 {
+            return;
+        }
+
+        let mut scroll_offset =
+            navigation::adjust_scroll(self.selected, self.scroll_offset, inner_height);
+        if self.expanded_index == Some(self.selected) {
+            let pos_in_view = self.selected.saturating_sub(scroll_offset);
+            if pos_in_view + 1 + DETAIL_LINES > inner_height {
+                scroll_offset = (self.selected + 1 + DETAIL_LINES).saturating_sub(inner_height);
+            }
+        }
+
+        let mut lines: Vec<Line> = Vec::new();
+        for (idx, entry) in entries.iter().enumerate().skip(scroll_offset) {
+            if lines.len() >= inner_height {
+                break;
+            }
+            lines.push(build_entry_line(entry, idx == self.selected));
+            if self.expanded_index == Some(idx) {
+                lines.extend(build_detail_lines(entry));
+            }
+        }
+        lines.truncate(inner_height);
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// `  HH:MM:SS  exit 0    12ms  jj log -r @`
+fn build_entry_line(entry: &CommandLogEntry, is_selected: bool) -> Line<'static> {
+    let status_color = if entry.succeeded() {
+        Color::Green
+    } else {
+        Color::Red
+    };
+    let spans = vec![
+        Span::raw("  "),
+        Span::styled(
+            format_timestamp(&entry.started),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!("{:<7}", entry.status_label()),
+            Style::default()
+                .fg(status_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!("{:>7}", format_duration(entry)),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw("  "),
+        Span::styled(entry.shell_command(), Style::default().fg(Color::White)),
+    ];
+
+    let mut line = Line::from(spans);
+    if is_selected {
+        line = line.style(
+            Style::default()
+                .fg(theme::selection::FG)
+                .bg(theme::selection::BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    line
+}
+
+fn build_detail_lines(entry: &CommandLogEntry) -> Vec<Line<'static>> {
+    let indent = "      ";
+    let detail_style = Style::default().fg(Color::DarkGray);
+    let label_style = Style::default().fg(Color::Yellow);
+    vec![
+        Line::from(vec![
+            Span::raw(indent),
+            Span::styled("Command: ", label_style),
+            Span::styled(entry.shell_command(), detail_style),
+        ]),
+        Line::from(vec![
+            Span::raw(indent),
+            Span::styled("Duration: ", label_style),
+            Span::styled(
+                format!("{} ({})", format_duration(entry), entry.status_label()),
+                detail_style,
+            ),
+        ]),
+        Line::from(""),
+    ]
+}
+
+fn format_duration(entry: &CommandLogEntry) -> String {
+    let ms = entry.duration.as_millis();
+    if ms >= 1000 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else {
+        format!("{}ms", ms)
+    }
+}
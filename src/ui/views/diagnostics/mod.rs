@@ -0,0 +1,120 @@
+//! Diagnostics View: versions, tools, remotes and feature checks
+//!
+//! Collected once when the view opens (and again on refresh); `y` copies
+//! the plain-text report for bug reports and support conversations.
+
+mod input;
+mod render;
+
+use crate::model::DiagnosticSection;
+
+/// Action returned by the Diagnostics View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticsAction {
+    /// No action needed
+    None,
+    /// Copy the report to the clipboard
+    CopyReport,
+}
+
+/// Diagnostics View state
+#[derive(Debug, Default)]
+pub struct DiagnosticsView {
+    /// Collected diagnostics
+    sections: Vec<DiagnosticSection>,
+    /// First visible line
+    scroll_offset: usize,
+}
+
+impl DiagnosticsView {
+    /// Create a new empty Diagnostics View
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show freshly collected diagnostics, keeping the position
+    pub fn set_sections(&mut self, sections: Vec<DiagnosticSection>) {
+        self.sections = sections;
+        self.scroll_offset = self.scroll_offset.min(self.line_count().saturating_sub(1));
+    }
+
+    /// Collected diagnostics
+    pub fn sections(&self) -> &[DiagnosticSection] {
+        &self.sections
+    }
+
+    /// Rendered lines: a title per section, its items and a blank separator
+    fn line_count(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|section| section.items.len() + 2)
+            .sum::<usize>()
+            .saturating_sub(1)
+    }
+
+    fn max_scroll_offset(&self, visible_height: usize) -> usize {
+        self.line_count().saturating_sub(visible_height)
+    }
+
+    /// Scroll by `delta` lines, clamped to the report
+    pub fn scroll_by(&mut self, delta: isize, visible_height: usize) {
+        let max = self.max_scroll_offset(visible_height);
+        self.scroll_offset = self.scroll_offset.saturating_add_signed(delta).min(max);
+    }
+
+    /// Jump to the top
+    pub fn jump_to_top(&mut self) {
+        self.scroll_offset = 0;
+    }
+
+    /// Jump to the bottom
+    pub fn jump_to_bottom(&mut self, visible_height: usize) {
+        self.scroll_offset = self.max_scroll_offset(visible_height);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::DiagnosticItem;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn view() -> DiagnosticsView {
+        let section = |title: &str, count: usize| DiagnosticSection {
+            title: title.to_string(),
+            items: (0..count)
+                .map(|n| DiagnosticItem::info(format!("item {}", n), "value"))
+                .collect(),
+        };
+        let mut view = DiagnosticsView::new();
+        view.set_sections(vec![section("Versions", 4), section("Remotes", 10)]);
+        view
+    }
+
+    #[test]
+    fn test_line_count_includes_titles_and_separator() {
+        assert_eq!(view().line_count(), 17);
+    }
+
+    #[test]
+    fn test_scroll_is_clamped_to_report() {
+        let mut view = view();
+        view.scroll_by(100, 10);
+        assert_eq!(view.scroll_offset, 7);
+        view.jump_to_top();
+        view.scroll_by(-1, 10);
+        assert_eq!(view.scroll_offset, 0);
+        view.jump_to_bottom(40);
+        assert_eq!(view.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_handle_key_copy() {
+        let mut view = view();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('y')), 10);
+        assert_eq!(action, DiagnosticsAction::CopyReport);
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('j')), 10);
+        assert_eq!(action, DiagnosticsAction::None);
+        assert_eq!(view.scroll_offset, 1);
+    }
+}
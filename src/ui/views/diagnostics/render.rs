@@ -0,0 +1,85 @@
+//! Diagnostics View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::DiagnosticsView;
+use crate::model::{DiagnosticItem, DiagnosticStatus, Notification};
+use crate::ui::components;
+
+/// Width of the label column
+const LABEL_WIDTH: usize = 22;
+
+impl DiagnosticsView {
+    /// Render the report with missing features counted in the title
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let missing = self
+            .sections
+            .iter()
+            .flat_map(|section| &section.items)
+            .filter(|item| item.status == DiagnosticStatus::Missing)
+            .count();
+        let title = if missing > 0 {
+            format!(" Diagnostics ({} missing) ", missing)
+        } else {
+            " Diagnostics ".to_string()
+        };
+        let title = Line::from(title).bold().cyan().centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        let mut lines: Vec<Line> = Vec::new();
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                section.title.clone(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.extend(section.items.iter().map(build_item_line));
+        }
+
+        let inner_height = area.height.saturating_sub(2) as usize;
+        let lines: Vec<Line> = lines
+            .into_iter()
+            .skip(self.scroll_offset)
+            .take(inner_height)
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// `  ✓ label                 value`
+fn build_item_line(item: &DiagnosticItem) -> Line<'static> {
+    let (marker, color) = match item.status {
+        DiagnosticStatus::Info => (" ", Color::White),
+        DiagnosticStatus::Available => ("✓", Color::Green),
+        DiagnosticStatus::Missing => ("✗", Color::Red),
+    };
+    Line::from(vec![
+        Span::raw("  "),
+        Span::styled(marker, Style::default().fg(color)),
+        Span::raw(" "),
+        Span::styled(
+            format!("{:<width$}", item.label, width = LABEL_WIDTH),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw(" "),
+        Span::styled(item.value.clone(), Style::default().fg(color)),
+    ])
+}
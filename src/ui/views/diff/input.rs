@@ -1,11 +1,12 @@
 //! Key handling for DiffView
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent};
 
 use crate::keys;
-use crate::model::DiffMode;
+use crate::model::{DiffDisplayFormat, DiffLineKind, DiffMode};
+use crate::ui::navigation::LineOverflow;
 
-use super::{DiffAction, DiffView};
+use super::{DiffAction, DiffLineFilter, DiffView};
 
 impl DiffView {
     /// Handle key input
@@ -14,11 +15,30 @@ impl DiffView {
         self.handle_key_with_height(key, Self::DEFAULT_VISIBLE_HEIGHT)
     }
 
+    /// Fold or unfold the current file (`za`, Enter on a header)
+    fn fold_current_file(&mut self) -> DiffAction {
+        if self.display_format == DiffDisplayFormat::Stat {
+            return DiffAction::ShowNotification(
+                "Stat format already shows one line per file".to_string(),
+            );
+        }
+        match self.toggle_fold() {
+            Some(true) => DiffAction::ShowNotification("Folded file (za unfolds)".to_string()),
+            Some(false) => DiffAction::ShowNotification("Unfolded file".to_string()),
+            None => DiffAction::None,
+        }
+    }
+
     /// Handle key input with explicit visible height
     pub fn handle_key_with_height(&mut self, key: KeyEvent, visible_height: usize) -> DiffAction {
         // Always update visible_height to ensure accurate scroll bounds
         self.visible_height = visible_height;
 
+        // `za`; after `z` any other key is handled as usual
+        if std::mem::take(&mut self.fold_pending) && key.code == keys::DIFF_FOLD_TOGGLE {
+            return self.fold_current_file();
+        }
+
         match key.code {
             code if keys::is_move_down(code) => {
                 self.scroll_down();
@@ -52,6 +72,31 @@ impl DiffView {
                 self.prev_file();
                 DiffAction::None
             }
+            keys::DIFF_NEXT_CONFLICT | keys::DIFF_PREV_CONFLICT => {
+                let moved = if key.code == keys::DIFF_NEXT_CONFLICT {
+                    self.next_conflict()
+                } else {
+                    self.prev_conflict()
+                };
+                if moved {
+                    DiffAction::None
+                } else {
+                    DiffAction::ShowNotification("No conflict markers in this diff".to_string())
+                }
+            }
+            keys::RESOLVE_LIST => {
+                if self.mode != DiffMode::Single {
+                    DiffAction::ShowNotification(
+                        "Resolve is not available in compare/interdiff mode".to_string(),
+                    )
+                } else if let Some(file_path) = self.current_conflict_file() {
+                    DiffAction::OpenResolve { file_path }
+                } else {
+                    DiffAction::ShowNotification(
+                        "No conflict in this file (c/C jumps to one)".to_string(),
+                    )
+                }
+            }
             keys::ANNOTATE => {
                 // Blame is not available in compare/interdiff mode (no single revision context)
                 if self.mode != DiffMode::Single {
@@ -66,14 +111,137 @@ impl DiffView {
                     DiffAction::None
                 }
             }
+            keys::FILE_LOG => match self.current_file_path() {
+                Some(file_path) => DiffAction::OpenFileLog { file_path },
+                None => DiffAction::None,
+            },
             keys::DIFF_FORMAT_CYCLE => DiffAction::CycleFormat,
             keys::DIFF_DESC_TOGGLE => {
                 self.toggle_description_expanded();
                 DiffAction::None
             }
+            keys::DIFF_ADDED_ONLY | keys::DIFF_DELETED_ONLY => {
+                if self.display_format == DiffDisplayFormat::Stat {
+                    return DiffAction::ShowNotification(
+                        "Line filter is not available in stat format".to_string(),
+                    );
+                }
+                if !self.display_format.filters_lines() {
+                    return DiffAction::ShowNotification(
+                        "Line filter needs the git format (m): color-words can't tell modified lines from context".to_string(),
+                    );
+                }
+                let filter = if key.code == keys::DIFF_ADDED_ONLY {
+                    DiffLineFilter::AddedOnly
+                } else {
+                    DiffLineFilter::DeletedOnly
+                };
+                let filter = self.toggle_line_filter(filter);
+                DiffAction::ShowNotification(format!("Showing {}", filter.label()))
+            }
+            keys::DIFF_COLLAPSE_MOVES => {
+                if self.display_format == DiffDisplayFormat::Stat {
+                    return DiffAction::ShowNotification(
+                        "Stat format already shows one line per file".to_string(),
+                    );
+                }
+                let moved = self.toggle_collapse_moves();
+                let message = if !self.collapse_moves {
+                    "Showing moved files in full".to_string()
+                } else if moved == 0 {
+                    "No renamed/copied files in this diff".to_string()
+                } else {
+                    format!("Collapsed {} renamed/copied file(s)", moved)
+                };
+                DiffAction::ShowNotification(message)
+            }
+            keys::DIFF_IGNORE_WHITESPACE => DiffAction::ToggleIgnoreWhitespace,
+            keys::DIFF_LESS_CONTEXT | keys::DIFF_MORE_CONTEXT => {
+                if self.display_format == DiffDisplayFormat::Stat {
+                    return DiffAction::ShowNotification(
+                        "Context lines are not shown in stat format".to_string(),
+                    );
+                }
+                DiffAction::StepContext {
+                    more: key.code == keys::DIFF_MORE_CONTEXT,
+                }
+            }
+            keys::DIFF_HIDE_MODE_CHANGES => {
+                if self.display_format == DiffDisplayFormat::Stat {
+                    return DiffAction::ShowNotification(
+                        "Mode changes are not shown in stat format".to_string(),
+                    );
+                }
+                DiffAction::ToggleModeChanges
+            }
+            keys::DIFF_TOOL => {
+                if self.mode == DiffMode::Interdiff {
+                    DiffAction::ShowNotification(
+                        "Difftool is not available in interdiff mode".to_string(),
+                    )
+                } else {
+                    DiffAction::OpenDiffTool
+                }
+            }
+            keys::DIFF_FOLD_PREFIX => {
+                self.fold_pending = true;
+                DiffAction::None
+            }
+            keys::DIFF_FOLD_ALL => {
+                if self.display_format == DiffDisplayFormat::Stat {
+                    return DiffAction::ShowNotification(
+                        "Stat format already shows one line per file".to_string(),
+                    );
+                }
+                let message = if self.toggle_fold_all() {
+                    format!("Folded {} file(s) (Z unfolds all)", self.file_count())
+                } else {
+                    "Unfolded all files".to_string()
+                };
+                DiffAction::ShowNotification(message)
+            }
+            // Enter on a file header folds it instead
+            keys::DIFF_FILE_MENU
+                if self
+                    .content
+                    .lines
+                    .get(self.scroll_offset)
+                    .is_some_and(|line| line.kind == DiffLineKind::FileHeader) =>
+            {
+                self.fold_current_file()
+            }
+            keys::DIFF_FILE_MENU | KeyCode::Char(' ') => match self.current_file_path() {
+                Some(file_path) => DiffAction::OpenFileMenu { file_path },
+                None => DiffAction::None,
+            },
             keys::YANK => DiffAction::CopyToClipboard { full: true },
             keys::YANK_DIFF => DiffAction::CopyToClipboard { full: false },
             keys::WRITE_FILE => DiffAction::ExportToFile,
+            keys::COMPARE_SAVE => {
+                if self.mode == DiffMode::Compare {
+                    DiffAction::SaveCompareSession
+                } else {
+                    DiffAction::ShowNotification(
+                        "Only compare diffs (=) can be saved as sessions".to_string(),
+                    )
+                }
+            }
+            keys::WRAP_TOGGLE => {
+                self.overflow.toggle_wrap();
+                DiffAction::ShowNotification(self.overflow.wrap_notice().to_string())
+            }
+            code if keys::is_scroll_left(code) || keys::is_scroll_right(code) => {
+                let scrolled = if keys::is_scroll_left(code) {
+                    self.overflow.scroll_left()
+                } else {
+                    self.overflow.scroll_right(self.widest_line())
+                };
+                if scrolled {
+                    DiffAction::None
+                } else {
+                    DiffAction::ShowNotification(LineOverflow::WRAPPED_SCROLL_NOTICE.to_string())
+                }
+            }
             keys::QUIT | keys::ESC => DiffAction::Back,
             _ => DiffAction::None,
         }
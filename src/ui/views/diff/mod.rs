@@ -5,7 +5,13 @@
 mod input;
 mod render;
 
-use crate::model::{CompareInfo, DiffContent, DiffDisplayFormat, DiffMode};
+use std::collections::HashSet;
+
+use crate::model::{
+    CompareInfo, DEFAULT_ID_LENGTH, DiffContent, DiffDisplayFormat, DiffLine, DiffLineKind,
+    DiffMode, DiffOptions, TimestampFormat,
+};
+use crate::ui::navigation::LineOverflow;
 
 /// Action returned by DiffView key handling
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +25,11 @@ pub enum DiffAction {
         /// File path to annotate
         file_path: String,
     },
+    /// Show the history of the current file
+    OpenFileLog {
+        /// File path (new path for renames)
+        file_path: String,
+    },
     /// Show an info notification (e.g., feature unavailable in current mode)
     ShowNotification(String),
     /// Copy diff to clipboard (full = jj show, !full = jj diff)
@@ -27,6 +38,61 @@ pub enum DiffAction {
     ExportToFile,
     /// Cycle display format (color-words → stat → git → color-words)
     CycleFormat,
+    /// Toggle ignoring whitespace changes (re-fetches the diff)
+    ToggleIgnoreWhitespace,
+    /// Show more or fewer context lines (re-fetches the diff)
+    StepContext { more: bool },
+    /// Toggle hiding file mode changes
+    ToggleModeChanges,
+    /// Open the diff in the configured GUI difftool
+    OpenDiffTool,
+    /// Open the action menu for the current file
+    OpenFileMenu {
+        /// File path (new path for renames)
+        file_path: String,
+    },
+    /// Save the from/to pair of this compare as a named session
+    SaveCompareSession,
+    /// Open the Resolve View on a conflicted file of this revision
+    OpenResolve {
+        /// File path (new path for renames)
+        file_path: String,
+    },
+}
+
+/// Which changed lines DiffView shows (file headers are always kept)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffLineFilter {
+    /// Full diff
+    #[default]
+    All,
+    /// Added lines only
+    AddedOnly,
+    /// Deleted lines only
+    DeletedOnly,
+}
+
+impl DiffLineFilter {
+    /// Label for notifications and the context bar
+    pub fn label(self) -> &'static str {
+        match self {
+            DiffLineFilter::All => "all lines",
+            DiffLineFilter::AddedOnly => "added lines only",
+            DiffLineFilter::DeletedOnly => "deleted lines only",
+        }
+    }
+
+    fn keeps(self, kind: DiffLineKind) -> bool {
+        match self {
+            DiffLineFilter::All => true,
+            DiffLineFilter::AddedOnly => {
+                kind != DiffLineKind::Deleted && kind != DiffLineKind::Context
+            }
+            DiffLineFilter::DeletedOnly => {
+                kind != DiffLineKind::Added && kind != DiffLineKind::Context
+            }
+        }
+    }
 }
 
 /// Diff view state
@@ -55,6 +121,28 @@ pub struct DiffView {
     /// When true, header expands to show the full description even if it
     /// shrinks the diff area. Default false keeps diff visibility prioritized.
     pub description_expanded: bool,
+    /// Added/deleted-only filter (kept across format changes)
+    pub line_filter: DiffLineFilter,
+    /// When true, renamed/copied files show only their header and a summary
+    pub collapse_moves: bool,
+    /// Full diff lines while a filter is active (`content.lines` holds the filtered ones)
+    unfiltered_lines: Option<Vec<DiffLine>>,
+    /// Positions of conflict start markers (`<<<<<<<`) in the lines array
+    pub conflict_positions: Vec<usize>,
+    /// More lines are still arriving from a streamed `jj show`
+    pub loading: bool,
+    /// Wrapping or horizontal scroll of long lines (`W`, `h`/`l`)
+    pub overflow: LineOverflow,
+    /// Whitespace/context/mode options the diff was loaded with
+    pub options: DiffOptions,
+    /// Files whose hunks are folded away (`za`, `Z`), by header
+    pub folded: HashSet<String>,
+    /// Minimum ID length the revision is shown with (`tij.id-length`)
+    pub id_length: usize,
+    /// Timestamp display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub timestamp_format: TimestampFormat,
+    /// `z` was pressed, waiting for `a`
+    fold_pending: bool,
 }
 
 impl Default for DiffView {
@@ -81,6 +169,17 @@ impl DiffView {
             mode: DiffMode::Single,
             display_format: DiffDisplayFormat::default(),
             description_expanded: false,
+            line_filter: DiffLineFilter::All,
+            collapse_moves: false,
+            unfiltered_lines: None,
+            conflict_positions: Vec::new(),
+            loading: false,
+            overflow: LineOverflow::default(),
+            options: DiffOptions::default(),
+            folded: HashSet::new(),
+            id_length: DEFAULT_ID_LENGTH,
+            timestamp_format: TimestampFormat::default(),
+            fold_pending: false,
         }
     }
 
@@ -167,23 +266,188 @@ impl DiffView {
 
     /// Set the content to display
     pub fn set_content(&mut self, revision: String, content: DiffContent) {
-        use crate::model::DiffLineKind;
+        // Folds survive a format switch or reload of the same diff
+        if revision != self.revision {
+            self.folded.clear();
+        }
+        self.revision = revision;
+        self.content = content;
+        self.unfiltered_lines = None;
+        self.apply_line_filter();
+        self.scroll_offset = 0;
+        self.current_file_index = 0;
+        self.loading = false;
+    }
+
+    /// Append lines of a diff that is still loading
+    ///
+    /// Only the new lines are indexed, unless a filter is active and the
+    /// filtered lines have to be rebuilt. The scroll position is kept.
+    pub fn append_lines(&mut self, lines: Vec<DiffLine>) {
+        if let Some(all) = self.unfiltered_lines.as_mut() {
+            all.extend(lines);
+            let offset = self.scroll_offset;
+            self.apply_line_filter();
+            self.scroll_offset = offset.min(self.max_scroll_offset());
+            return;
+        }
+        let start = self.content.lines.len();
+        for (i, line) in lines.iter().enumerate() {
+            if line.kind == DiffLineKind::FileHeader {
+                self.file_header_positions.push(start + i);
+                self.file_names.push(line.content.clone());
+            }
+            if is_conflict_start(line) {
+                self.conflict_positions.push(start + i);
+            }
+        }
+        self.content.lines.extend(lines);
+    }
+
+    /// Toggle `filter` on, or back to the full diff if it is already active
+    ///
+    /// Keeps the current file in view. Returns the filter now in effect.
+    pub fn toggle_line_filter(&mut self, filter: DiffLineFilter) -> DiffLineFilter {
+        self.line_filter = if self.line_filter == filter {
+            DiffLineFilter::All
+        } else {
+            filter
+        };
+        self.refilter_keeping_file();
+        self.line_filter
+    }
+
+    /// Toggle collapsing of renamed/copied files
+    ///
+    /// jj detects renames and copies itself (there is no CLI switch for it);
+    /// collapsing hides the hunks of those files so a large refactor reads
+    /// as a list of moves. Returns the number of moved files in the diff.
+    pub fn toggle_collapse_moves(&mut self) -> usize {
+        self.collapse_moves = !self.collapse_moves;
+        self.refilter_keeping_file();
+        let lines = self
+            .unfiltered_lines
+            .as_ref()
+            .unwrap_or(&self.content.lines);
+        file_sections(lines)
+            .into_iter()
+            .filter(|section| is_moved_file(section))
+            .count()
+    }
+
+    /// Fold or unfold the current file; returns whether it is folded now
+    pub fn toggle_fold(&mut self) -> Option<bool> {
+        let name = self.current_file_name()?.to_string();
+        let folded = self.folded.insert(name.clone());
+        if !folded {
+            self.folded.remove(&name);
+        }
+        self.refilter_keeping_file();
+        Some(folded)
+    }
+
+    /// Fold every file (an outline of file headers), or unfold all if every
+    /// file is folded already; returns whether the files are folded now
+    pub fn toggle_fold_all(&mut self) -> bool {
+        let all_folded = self
+            .file_names
+            .iter()
+            .all(|name| self.folded.contains(name));
+        if all_folded {
+            self.folded.clear();
+        } else {
+            self.folded.extend(self.file_names.iter().cloned());
+        }
+        self.refilter_keeping_file();
+        !all_folded
+    }
+
+    /// Set the options the diff was loaded with
+    ///
+    /// Whitespace and context are already applied by jj; hiding mode
+    /// changes is a filter of this view.
+    pub fn set_options(&mut self, options: DiffOptions) {
+        let refilter = options.hide_mode_changes != self.options.hide_mode_changes;
+        self.options = options;
+        if refilter {
+            self.refilter_keeping_file();
+        }
+    }
+
+    /// Re-apply the filters, keeping the current file in view
+    fn refilter_keeping_file(&mut self) {
+        let file_index = self.current_file_index;
+        self.apply_line_filter();
+        self.scroll_offset = self
+            .file_header_positions
+            .get(file_index)
+            .copied()
+            .unwrap_or(0)
+            .min(self.max_scroll_offset());
+        self.update_current_file_index();
+    }
+
+    /// Rebuild `content.lines` for the current filters and re-index file headers
+    ///
+    /// Only the git format is filtered by line kind (see
+    /// [`DiffDisplayFormat::filters_lines`]). The stat format already shows
+    /// one line per file, so moves are not collapsed, mode changes not
+    /// hidden and files not folded.
+    fn apply_line_filter(&mut self) {
+        let is_stat = self.display_format == DiffDisplayFormat::Stat;
+        let filter = if !self.display_format.filters_lines() {
+            DiffLineFilter::All
+        } else {
+            self.line_filter
+        };
+        let collapse = self.collapse_moves && !is_stat;
+        let hide_modes = self.options.hide_mode_changes && !is_stat;
+        let fold = !self.folded.is_empty() && !is_stat;
+        if filter == DiffLineFilter::All && !collapse && !hide_modes && !fold {
+            if let Some(lines) = self.unfiltered_lines.take() {
+                self.content.lines = lines;
+            }
+        } else {
+            let all = self
+                .unfiltered_lines
+                .get_or_insert_with(|| std::mem::take(&mut self.content.lines));
+            let mut kept: Vec<DiffLine> = all
+                .iter()
+                .filter(|line| filter.keeps(line.kind))
+                .cloned()
+                .collect();
+            if hide_modes {
+                kept = hide_mode_changes(&kept);
+            }
+            if collapse {
+                kept = collapse_moved_files(&kept);
+            }
+            self.content.lines = if fold {
+                fold_files(&kept, &self.folded)
+            } else {
+                kept
+            };
+        }
 
         // Extract file header positions and names
-        let (positions, names): (Vec<_>, Vec<_>) = content
+        let (positions, names): (Vec<_>, Vec<_>) = self
+            .content
             .lines
             .iter()
             .enumerate()
             .filter(|(_, line)| line.kind == DiffLineKind::FileHeader)
             .map(|(i, line)| (i, line.content.clone()))
             .unzip();
-
         self.file_header_positions = positions;
         self.file_names = names;
-        self.revision = revision;
-        self.content = content;
-        self.scroll_offset = 0;
-        self.current_file_index = 0;
+        self.conflict_positions = self
+            .content
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| is_conflict_start(line))
+            .map(|(i, _)| i)
+            .collect();
     }
 
     /// Clear the view (test-only helper)
@@ -199,6 +463,15 @@ impl DiffView {
         self.mode = DiffMode::Single;
         self.display_format = DiffDisplayFormat::default();
         self.description_expanded = false;
+        self.line_filter = DiffLineFilter::All;
+        self.collapse_moves = false;
+        self.unfiltered_lines = None;
+        self.conflict_positions.clear();
+        self.loading = false;
+        self.overflow = LineOverflow::default();
+        self.options = DiffOptions::default();
+        self.folded.clear();
+        self.fold_pending = false;
     }
 
     /// Cycle to the next display format
@@ -214,6 +487,33 @@ impl DiffView {
             .map(|s| s.as_str())
     }
 
+    /// Path of the current file, resolving `prefix{old => new}` renames
+    /// to the new path so it can be passed to jj
+    pub fn current_file_path(&self) -> Option<String> {
+        let name = self.current_file_name()?;
+        Some(Self::extract_new_path_from_rename(name).unwrap_or_else(|| name.to_string()))
+    }
+
+    /// New-side line number at the top of the screen, for linking the file
+    ///
+    /// The first numbered line from the scroll position within the current
+    /// file; `None` for headers-only views (e.g. stat format).
+    pub fn current_line_number(&self) -> Option<usize> {
+        self.content
+            .lines
+            .iter()
+            .skip(self.scroll_offset)
+            .enumerate()
+            .take_while(|(i, line)| {
+                *i == 0
+                    || !matches!(
+                        line.kind,
+                        DiffLineKind::FileHeader | DiffLineKind::Separator
+                    )
+            })
+            .find_map(|(_, line)| line.line_numbers.and_then(|(_, new)| new))
+    }
+
     /// Get total file count
     pub fn file_count(&self) -> usize {
         self.file_names.len()
@@ -238,6 +538,16 @@ impl DiffView {
         self.content.lines.len()
     }
 
+    /// Character count of the longest line (the limit for `l`)
+    pub fn widest_line(&self) -> usize {
+        self.content
+            .lines
+            .iter()
+            .map(|line| line.content.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Get current context string for status bar
     pub fn current_context(&self) -> String {
         if self.file_count() > 0 {
@@ -356,6 +666,77 @@ impl DiffView {
         }
     }
 
+    /// Jump to the next conflict region (wraps around)
+    ///
+    /// Returns false when the diff has no conflict markers.
+    pub fn next_conflict(&mut self) -> bool {
+        let Some(&first) = self.conflict_positions.first() else {
+            return false;
+        };
+        self.scroll_offset = self
+            .conflict_positions
+            .iter()
+            .copied()
+            .find(|&pos| pos > self.scroll_offset)
+            .unwrap_or(first);
+        self.update_current_file_index();
+        true
+    }
+
+    /// Jump to the previous conflict region (wraps around)
+    ///
+    /// Returns false when the diff has no conflict markers.
+    pub fn prev_conflict(&mut self) -> bool {
+        let Some(&last) = self.conflict_positions.last() else {
+            return false;
+        };
+        self.scroll_offset = self
+            .conflict_positions
+            .iter()
+            .copied()
+            .rfind(|&pos| pos < self.scroll_offset)
+            .unwrap_or(last);
+        self.update_current_file_index();
+        true
+    }
+
+    /// `conflict 2/5` for the status bar (`5 conflicts` above the first one)
+    pub fn conflict_context(&self) -> Option<String> {
+        let total = self.conflict_positions.len();
+        if total == 0 {
+            return None;
+        }
+        let passed = self
+            .conflict_positions
+            .iter()
+            .filter(|&&pos| pos <= self.scroll_offset)
+            .count();
+        Some(if passed == 0 {
+            format!("{} conflict{}", total, if total == 1 { "" } else { "s" })
+        } else {
+            format!("conflict {}/{}", passed, total)
+        })
+    }
+
+    /// Path of the file whose conflict is at the top of the screen
+    ///
+    /// The current file, if it has conflict markers.
+    pub fn current_conflict_file(&self) -> Option<String> {
+        let start = self
+            .file_header_positions
+            .get(self.current_file_index)
+            .copied()?;
+        let end = self
+            .file_header_positions
+            .get(self.current_file_index + 1)
+            .copied()
+            .unwrap_or(usize::MAX);
+        self.conflict_positions
+            .iter()
+            .any(|pos| (start..end).contains(pos))
+            .then(|| self.current_file_path())?
+    }
+
     /// Update current_file_index based on scroll position
     fn update_current_file_index(&mut self) {
         self.current_file_index = self
@@ -395,6 +776,51 @@ impl DiffView {
         }
     }
 
+    /// Drop the section of `file_path` (after its changes left the revision)
+    ///
+    /// Returns false when the file isn't shown or the format can't be edited
+    /// locally (the stat summary line counts every file); the caller then
+    /// reloads the whole diff. The view stays on the following file.
+    pub fn remove_file(&mut self, file_path: &str) -> bool {
+        if self.display_format == DiffDisplayFormat::Stat {
+            return false;
+        }
+        let file_index = self.current_file_index;
+        let lines = self
+            .unfiltered_lines
+            .take()
+            .unwrap_or_else(|| std::mem::take(&mut self.content.lines));
+        let sections = file_sections(&lines);
+        let matches = |section: &&[DiffLine]| {
+            section.first().is_some_and(|header| {
+                header.kind == DiffLineKind::FileHeader
+                    && (header.content == file_path
+                        || Self::extract_new_path_from_rename(&header.content).as_deref()
+                            == Some(file_path))
+            })
+        };
+        if !sections.iter().any(matches) {
+            self.content.lines = lines;
+            self.apply_line_filter();
+            return false;
+        }
+        self.content.lines = sections
+            .into_iter()
+            .filter(|section| !matches(section))
+            .flatten()
+            .cloned()
+            .collect();
+        self.apply_line_filter();
+        self.current_file_index = file_index.min(self.file_names.len().saturating_sub(1));
+        self.scroll_offset = self
+            .file_header_positions
+            .get(self.current_file_index)
+            .copied()
+            .unwrap_or(0)
+            .min(self.max_scroll_offset());
+        true
+    }
+
     /// Extract the new path from a rename pattern like "prefix{old => new}"
     ///
     /// Returns the reconstructed new path: "prefix/new"
@@ -408,9 +834,186 @@ impl DiffView {
     }
 }
 
+/// Whether a line added or kept by this revision opens a conflict region
+///
+/// Markers on deleted lines belong to a conflict this revision resolved.
+/// jj may use markers longer than 7 characters.
+fn is_conflict_start(line: &DiffLine) -> bool {
+    if !matches!(line.kind, DiffLineKind::Added | DiffLineKind::Context) {
+        return false;
+    }
+    let content = line.content.trim_start();
+    let rest = content.trim_start_matches('<');
+    content.len() - rest.len() >= 7 && (rest.is_empty() || rest.starts_with(' '))
+}
+
+/// Split `lines` at file headers (lines before the first header form their own section)
+fn file_sections(lines: &[DiffLine]) -> Vec<&[DiffLine]> {
+    let mut starts: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, line)| *i == 0 || line.kind == DiffLineKind::FileHeader)
+        .map(|(i, _)| i)
+        .collect();
+    starts.push(lines.len());
+    starts.windows(2).map(|w| &lines[w[0]..w[1]]).collect()
+}
+
+/// Whether a file section is a rename or copy
+///
+/// color-words headers read `prefix{old => new}`; git format keeps the
+/// `rename from` / `copy from` metadata lines.
+fn is_moved_file(section: &[DiffLine]) -> bool {
+    let Some(header) = section
+        .first()
+        .filter(|line| line.kind == DiffLineKind::FileHeader)
+    else {
+        return false;
+    };
+    header.content.contains(" => ") || move_metadata(section).next().is_some()
+}
+
+/// `rename from` / `copy to` / ... lines of a file section
+///
+/// Only the header part before the first `@@` hunk is searched: a file
+/// whose text contains such a line is not a move.
+fn move_metadata(section: &[DiffLine]) -> impl Iterator<Item = &DiffLine> {
+    section
+        .iter()
+        .take_while(|line| !line.content.starts_with("@@"))
+        .filter(|line| line.kind == DiffLineKind::Context && is_move_metadata(&line.content))
+}
+
+fn is_move_metadata(content: &str) -> bool {
+    [
+        "rename from ",
+        "rename to ",
+        "copy from ",
+        "copy to ",
+        "similarity index ",
+    ]
+    .iter()
+    .any(|prefix| content.starts_with(prefix))
+}
+
+/// Whether a line reports a file mode change
+///
+/// Git format has `old mode`/`new mode` metadata lines (a new file's
+/// `new file mode` is kept); color-words keeps the type change header.
+fn is_mode_change(line: &DiffLine) -> bool {
+    line.kind == DiffLineKind::Context
+        && line.line_numbers.is_none()
+        && (line.content.starts_with("old mode ")
+            || line.content.starts_with("new mode ")
+            || matches!(
+                line.content.as_str(),
+                "Regular file became executable file" | "Executable file became regular file"
+            ))
+}
+
+/// Drop mode change lines, and the files whose only change was the mode
+fn hide_mode_changes(lines: &[DiffLine]) -> Vec<DiffLine> {
+    let mut out = Vec::with_capacity(lines.len());
+    for section in file_sections(lines) {
+        let kept: Vec<&DiffLine> = section
+            .iter()
+            .filter(|line| !is_mode_change(line))
+            .collect();
+        let mode_only = kept.len() < section.len()
+            && kept.iter().all(|line| {
+                matches!(
+                    line.kind,
+                    DiffLineKind::FileHeader | DiffLineKind::Separator
+                )
+            });
+        if !mode_only {
+            out.extend(kept.into_iter().cloned());
+        }
+    }
+    if out
+        .last()
+        .is_some_and(|line| line.kind == DiffLineKind::Separator)
+    {
+        out.pop();
+    }
+    out
+}
+
+/// Replace the lines of folded files with a one-line summary
+fn fold_files(lines: &[DiffLine], folded: &HashSet<String>) -> Vec<DiffLine> {
+    let mut out = Vec::with_capacity(lines.len());
+    for section in file_sections(lines) {
+        let Some(header) = section
+            .first()
+            .filter(|line| line.kind == DiffLineKind::FileHeader && folded.contains(&line.content))
+        else {
+            out.extend_from_slice(section);
+            continue;
+        };
+        let separator = section
+            .last()
+            .filter(|line| line.kind == DiffLineKind::Separator);
+        let body = &section[1..section.len() - usize::from(separator.is_some())];
+        let count = |kind| body.iter().filter(|line| line.kind == kind).count();
+        out.push(header.clone());
+        out.push(DiffLine {
+            kind: DiffLineKind::Context,
+            line_numbers: None,
+            content: format!(
+                "(folded; +{} -{}, {} lines)",
+                count(DiffLineKind::Added),
+                count(DiffLineKind::Deleted),
+                body.len()
+            ),
+            file_op: None,
+        });
+        out.extend(separator.cloned());
+    }
+    out
+}
+
+/// Replace the hunks of renamed/copied files with a one-line summary
+fn collapse_moved_files(lines: &[DiffLine]) -> Vec<DiffLine> {
+    let mut out = Vec::with_capacity(lines.len());
+    for section in file_sections(lines) {
+        if !is_moved_file(section) {
+            out.extend_from_slice(section);
+            continue;
+        }
+        let count = |kind| section.iter().filter(|line| line.kind == kind).count();
+        let (added, deleted) = (count(DiffLineKind::Added), count(DiffLineKind::Deleted));
+        out.extend(
+            section
+                .iter()
+                .take(1)
+                .filter(|line| line.kind == DiffLineKind::FileHeader)
+                .cloned(),
+        );
+        out.extend(move_metadata(section).cloned());
+        out.push(DiffLine {
+            kind: DiffLineKind::Context,
+            line_numbers: None,
+            content: if added + deleted == 0 {
+                "(moved without changes)".to_string()
+            } else {
+                format!("(moved; +{} -{} lines hidden)", added, deleted)
+            },
+            file_op: None,
+        });
+        if section
+            .last()
+            .is_some_and(|line| line.kind == DiffLineKind::Separator)
+        {
+            out.push(DiffLine::separator());
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys;
     use crate::model::{CommitId, DiffContent, DiffLine};
     use crossterm::event::KeyEvent;
 
@@ -540,6 +1143,20 @@ mod tests {
         assert_eq!(view.current_file_name(), Some("src/lib.rs"));
     }
 
+    #[test]
+    fn test_diff_view_current_line_number() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        assert_eq!(view.current_line_number(), Some(10));
+
+        // Deleted line has no new-side number: the next line's is used
+        view.scroll_offset = 2;
+        assert_eq!(view.current_line_number(), Some(11));
+
+        // Never crosses into the next file
+        view.scroll_offset = 5;
+        assert_eq!(view.current_line_number(), None);
+    }
+
     #[test]
     fn test_diff_view_handle_key_scroll() {
         let mut view = DiffView::new("test".to_string(), create_test_content());
@@ -551,6 +1168,29 @@ mod tests {
         assert_eq!(view.scroll_offset, 1);
     }
 
+    #[test]
+    fn test_horizontal_scroll_and_wrap_keys() {
+        use crossterm::event::KeyCode;
+
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Char('l'))),
+            DiffAction::None
+        );
+        assert_eq!(view.overflow.column, 8);
+        view.handle_key(KeyEvent::from(KeyCode::Left));
+        assert_eq!(view.overflow.column, 0);
+
+        view.handle_key(KeyEvent::from(KeyCode::Right));
+        view.handle_key(KeyEvent::from(KeyCode::Char('W')));
+        assert!(view.overflow.wrap);
+        assert_eq!(view.overflow.column, 0);
+        assert!(matches!(
+            view.handle_key(KeyEvent::from(KeyCode::Char('l'))),
+            DiffAction::ShowNotification(_)
+        ));
+    }
+
     #[test]
     fn test_diff_view_handle_key_back() {
         let mut view = DiffView::empty();
@@ -666,6 +1306,25 @@ mod tests {
         assert_eq!(view.scroll_offset, 0);
     }
 
+    #[test]
+    fn test_remove_file_keeps_filters_and_position() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        assert!(view.remove_file("src/main.rs"));
+        assert_eq!(view.file_names, ["src/lib.rs"]);
+        assert_eq!(view.current_file_index, 0);
+
+        // The full diff comes back without the removed file
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        assert_eq!(view.total_lines(), 2);
+        assert!(!view.remove_file("missing.rs"));
+        assert_eq!(view.total_lines(), 2);
+
+        view.display_format = DiffDisplayFormat::Stat;
+        assert!(!view.remove_file("src/lib.rs"));
+    }
+
     #[test]
     fn test_extract_new_path_from_rename() {
         // Standard rename with prefix
@@ -745,6 +1404,106 @@ mod tests {
         view.jump_to_file("src/new.rs");
         assert_eq!(view.current_file_index, 0);
         assert_eq!(view.scroll_offset, 0);
+        assert_eq!(view.current_file_path().as_deref(), Some("src/new.rs"));
+    }
+
+    #[test]
+    fn test_enter_and_space_open_file_menu() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        let expected = DiffAction::OpenFileMenu {
+            file_path: "src/main.rs".to_string(),
+        };
+        let action = view.handle_key(KeyEvent::from(crossterm::event::KeyCode::Char(' ')));
+        assert_eq!(action, expected);
+        // Enter opens the menu below the file header (on it, it folds)
+        view.set_visible_height_and_clamp(2);
+        view.scroll_down();
+        let action = view.handle_key(KeyEvent::from(crossterm::event::KeyCode::Enter));
+        assert_eq!(action, expected);
+    }
+
+    fn line_contents(view: &DiffView) -> Vec<&str> {
+        view.content
+            .lines
+            .iter()
+            .map(|line| line.content.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_za_and_enter_on_header_fold_the_current_file() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        view.handle_key(KeyEvent::from(keys::DIFF_FOLD_PREFIX));
+        view.handle_key(KeyEvent::from(keys::DIFF_FOLD_TOGGLE));
+        assert_eq!(
+            line_contents(&view),
+            [
+                "src/main.rs",
+                "(folded; +1 -1, 4 lines)",
+                "",
+                "src/lib.rs",
+                "pub fn hello() {}"
+            ]
+        );
+        assert_eq!(view.file_header_positions, vec![0, 3]);
+
+        // Enter on the folded header unfolds it
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_FILE_MENU));
+        assert_eq!(
+            action,
+            DiffAction::ShowNotification("Unfolded file".to_string())
+        );
+        assert_eq!(view.total_lines(), 8);
+    }
+
+    #[test]
+    fn test_z_then_other_key_is_handled_as_usual() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        view.handle_key(KeyEvent::from(keys::DIFF_FOLD_PREFIX));
+        view.handle_key(KeyEvent::from(keys::NEXT_FILE));
+        assert_eq!(view.current_file_index, 1);
+        view.handle_key(KeyEvent::from(keys::DIFF_FOLD_TOGGLE));
+        assert!(view.folded.is_empty());
+    }
+
+    #[test]
+    fn test_fold_all_gives_an_outline_and_survives_a_reload() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        view.set_visible_height_and_clamp(2);
+        view.next_file();
+        assert!(view.toggle_fold_all());
+        assert_eq!(view.file_header_positions, vec![0, 3]);
+        // The current file stays in view
+        assert_eq!(view.current_file_name(), Some("src/lib.rs"));
+
+        view.set_content("test".to_string(), create_test_content());
+        assert_eq!(view.total_lines(), 5);
+        // Another revision starts unfolded
+        view.set_content("other".to_string(), create_test_content());
+        assert_eq!(view.total_lines(), 8);
+
+        view.toggle_fold_all();
+        assert!(!view.toggle_fold_all());
+        assert!(view.folded.is_empty());
+    }
+
+    #[test]
+    fn test_file_log_key_uses_current_file() {
+        let mut view = DiffView::new("test".to_string(), create_test_content());
+        let action = view.handle_key(KeyEvent::from(crossterm::event::KeyCode::Char('L')));
+        assert_eq!(
+            action,
+            DiffAction::OpenFileLog {
+                file_path: "src/main.rs".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_file_menu_without_files_does_nothing() {
+        let mut view = DiffView::empty();
+        let action = view.handle_key(KeyEvent::from(crossterm::event::KeyCode::Enter));
+        assert_eq!(action, DiffAction::None);
     }
 
     #[test]
@@ -824,4 +1583,364 @@ mod tests {
         view.display_format = old_format;
         assert_eq!(view.display_format, DiffDisplayFormat::ColorWords);
     }
+
+    // =========================================================================
+    // Line filter tests
+    // =========================================================================
+
+    fn line_kinds(view: &DiffView) -> Vec<DiffLineKind> {
+        view.content.lines.iter().map(|l| l.kind).collect()
+    }
+
+    #[test]
+    fn test_added_only_keeps_headers_and_added_lines() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+
+        assert_eq!(
+            line_kinds(&view),
+            vec![
+                DiffLineKind::FileHeader,
+                DiffLineKind::Added,
+                DiffLineKind::Separator,
+                DiffLineKind::FileHeader,
+                DiffLineKind::Added,
+            ]
+        );
+        assert_eq!(view.file_header_positions, vec![0, 3]);
+        assert_eq!(view.file_count(), 2);
+    }
+
+    #[test]
+    fn test_deleted_only_switches_from_added_only() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        view.toggle_line_filter(DiffLineFilter::DeletedOnly);
+
+        assert_eq!(view.line_filter, DiffLineFilter::DeletedOnly);
+        assert_eq!(
+            line_kinds(&view),
+            vec![
+                DiffLineKind::FileHeader,
+                DiffLineKind::Deleted,
+                DiffLineKind::Separator,
+                DiffLineKind::FileHeader,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toggle_same_filter_restores_full_diff() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        let full = line_kinds(&view);
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        assert_eq!(
+            view.toggle_line_filter(DiffLineFilter::AddedOnly),
+            DiffLineFilter::All
+        );
+        assert_eq!(line_kinds(&view), full);
+        assert_eq!(view.file_header_positions, vec![0, 6]);
+    }
+
+    #[test]
+    fn test_append_lines_indexes_new_files_and_keeps_scroll() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.loading = true;
+        view.scroll_offset = 2;
+        let mut more = vec![DiffLine::separator()];
+        more.extend(create_test_content().lines);
+        view.append_lines(more);
+
+        assert_eq!(view.file_header_positions, vec![0, 6, 9, 15]);
+        assert_eq!(view.file_count(), 4);
+        assert_eq!(view.scroll_offset, 2);
+        assert!(view.loading);
+    }
+
+    #[test]
+    fn test_append_lines_applies_active_filter() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        let mut more = vec![DiffLine::separator()];
+        more.extend(create_test_content().lines);
+        view.append_lines(more);
+
+        assert!(!line_kinds(&view).contains(&DiffLineKind::Deleted));
+        assert_eq!(view.file_count(), 4);
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        assert!(line_kinds(&view).contains(&DiffLineKind::Deleted));
+    }
+
+    #[test]
+    fn test_line_filter_survives_content_reload() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        view.toggle_line_filter(DiffLineFilter::DeletedOnly);
+        view.set_content("testchange".to_string(), create_test_content());
+        assert!(!line_kinds(&view).contains(&DiffLineKind::Added));
+    }
+
+    #[test]
+    fn test_line_filter_keys_blocked_in_stat_format() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Stat;
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_ADDED_ONLY));
+        assert!(matches!(action, DiffAction::ShowNotification(_)));
+        assert_eq!(view.line_filter, DiffLineFilter::All);
+    }
+
+    #[test]
+    fn test_line_filter_keys_blocked_in_color_words_format() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        assert_eq!(view.display_format, DiffDisplayFormat::ColorWords);
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_ADDED_ONLY));
+        assert!(
+            matches!(action, DiffAction::ShowNotification(ref msg) if msg.contains("git format"))
+        );
+        assert_eq!(view.line_filter, DiffLineFilter::All);
+    }
+
+    #[test]
+    fn test_line_filter_pauses_outside_git_format() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        view.toggle_line_filter(DiffLineFilter::AddedOnly);
+        assert!(!line_kinds(&view).contains(&DiffLineKind::Deleted));
+        // Back to color-words: modified lines must not be hidden as context
+        view.display_format = DiffDisplayFormat::ColorWords;
+        view.set_content("testchange".to_string(), create_test_content());
+        assert!(line_kinds(&view).contains(&DiffLineKind::Context));
+        assert_eq!(view.line_filter, DiffLineFilter::AddedOnly);
+    }
+
+    #[test]
+    fn test_line_filter_key_notifies() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        view.display_format = DiffDisplayFormat::Git;
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_DELETED_ONLY));
+        assert_eq!(
+            action,
+            DiffAction::ShowNotification("Showing deleted lines only".to_string())
+        );
+    }
+
+    #[test]
+    fn test_diff_tool_key() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_TOOL));
+        assert_eq!(action, DiffAction::OpenDiffTool);
+
+        view.mode = DiffMode::Interdiff;
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_TOOL));
+        assert!(matches!(action, DiffAction::ShowNotification(_)));
+    }
+
+    fn moved_file_content() -> DiffContent {
+        DiffContent {
+            lines: vec![
+                DiffLine::file_header("src/{old.rs => new.rs}"),
+                DiffLine::context(Some(1), Some(1), "fn a() {}"),
+                DiffLine::deleted(2, "old"),
+                DiffLine::added(2, "new"),
+                DiffLine::separator(),
+                DiffLine::file_header("src/main.rs"),
+                DiffLine::added(1, "fn main() {}"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_collapse_moves_summarizes_renamed_files() {
+        let mut view = DiffView::new("testchange".to_string(), moved_file_content());
+        assert_eq!(view.toggle_collapse_moves(), 1);
+        let contents: Vec<&str> = view
+            .content
+            .lines
+            .iter()
+            .map(|line| line.content.as_str())
+            .collect();
+        assert_eq!(
+            contents,
+            [
+                "src/{old.rs => new.rs}",
+                "(moved; +1 -1 lines hidden)",
+                "",
+                "src/main.rs",
+                "fn main() {}"
+            ]
+        );
+        assert_eq!(view.file_header_positions, vec![0, 3]);
+
+        view.toggle_collapse_moves();
+        assert_eq!(view.content.lines.len(), 7);
+    }
+
+    #[test]
+    fn test_collapse_moves_detects_git_rename_metadata() {
+        let content = DiffContent {
+            lines: vec![
+                DiffLine::file_header("new.rs"),
+                DiffLine {
+                    kind: DiffLineKind::Context,
+                    line_numbers: None,
+                    content: "rename from old.rs".to_string(),
+                    file_op: None,
+                },
+            ],
+            ..Default::default()
+        };
+        let mut view = DiffView::new("testchange".to_string(), content);
+        view.display_format = DiffDisplayFormat::Git;
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_COLLAPSE_MOVES));
+        assert_eq!(
+            action,
+            DiffAction::ShowNotification("Collapsed 1 renamed/copied file(s)".to_string())
+        );
+        assert_eq!(
+            view.content.lines.last().unwrap().content,
+            "(moved without changes)"
+        );
+    }
+
+    #[test]
+    fn test_collapse_moves_ignores_metadata_text_inside_hunks() {
+        let content = DiffContent {
+            lines: vec![
+                DiffLine::file_header("notes.txt"),
+                metadata("@@ -1,2 +1,2 @@"),
+                metadata("rename from old.rs"),
+            ],
+            ..Default::default()
+        };
+        let mut view = DiffView::new("testchange".to_string(), content);
+        view.display_format = DiffDisplayFormat::Git;
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_COLLAPSE_MOVES));
+        assert_eq!(
+            action,
+            DiffAction::ShowNotification("No renamed/copied files in this diff".to_string())
+        );
+    }
+
+    fn metadata(content: &str) -> DiffLine {
+        DiffLine {
+            kind: DiffLineKind::Context,
+            line_numbers: None,
+            content: content.to_string(),
+            file_op: None,
+        }
+    }
+
+    #[test]
+    fn test_hide_mode_changes_drops_mode_only_files() {
+        let content = DiffContent {
+            lines: vec![
+                DiffLine::file_header("run.sh"),
+                metadata("old mode 100644"),
+                metadata("new mode 100755"),
+                DiffLine::separator(),
+                DiffLine::file_header("build.sh"),
+                metadata("Regular file became executable file"),
+                DiffLine::added(1, "set -e"),
+                DiffLine::separator(),
+                DiffLine::file_header("new.sh"),
+                metadata("new file mode 100755"),
+                DiffLine::separator(),
+                DiffLine::file_header("tool.sh"),
+                metadata("Executable file became regular file"),
+            ],
+            ..Default::default()
+        };
+        let mut view = DiffView::new("testchange".to_string(), content);
+        view.set_options(DiffOptions {
+            hide_mode_changes: true,
+            ..Default::default()
+        });
+        let contents: Vec<&str> = view
+            .content
+            .lines
+            .iter()
+            .map(|line| line.content.as_str())
+            .collect();
+        assert_eq!(
+            contents,
+            ["build.sh", "set -e", "", "new.sh", "new file mode 100755"]
+        );
+        assert_eq!(view.file_names, ["build.sh", "new.sh"]);
+
+        view.set_options(DiffOptions::default());
+        assert_eq!(view.file_count(), 4);
+    }
+
+    fn create_conflict_content() -> DiffContent {
+        DiffContent {
+            lines: vec![
+                DiffLine::file_header("src/lib.rs"),
+                DiffLine::added(1, "<<<<<<< Conflict 1 of 2"),
+                DiffLine::added(2, "%%%%%%% Changes from base to side #1"),
+                DiffLine::added(3, ">>>>>>> Conflict 1 of 2 ends"),
+                DiffLine::added(4, "<<<<<<<<<<< Conflict 2 of 2"),
+                DiffLine::added(5, ">>>>>>>>>>> Conflict 2 of 2 ends"),
+                DiffLine::separator(),
+                DiffLine::file_header("src/main.rs"),
+                // Resolved by this revision, not a conflict any more
+                DiffLine::deleted(1, "<<<<<<< Conflict 1 of 1"),
+                DiffLine::added(1, "fn main() {}"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_conflict_positions_skip_deleted_markers() {
+        let view = DiffView::new("testchange".to_string(), create_conflict_content());
+        assert_eq!(view.conflict_positions, vec![1, 4]);
+        assert_eq!(view.conflict_context().as_deref(), Some("2 conflicts"));
+    }
+
+    #[test]
+    fn test_conflict_navigation_wraps() {
+        let mut view = DiffView::new("testchange".to_string(), create_conflict_content());
+        view.handle_key(KeyEvent::from(keys::DIFF_NEXT_CONFLICT));
+        assert_eq!(view.scroll_offset, 1);
+        assert_eq!(view.conflict_context().as_deref(), Some("conflict 1/2"));
+        view.handle_key(KeyEvent::from(keys::DIFF_NEXT_CONFLICT));
+        assert_eq!(view.scroll_offset, 4);
+        view.handle_key(KeyEvent::from(keys::DIFF_NEXT_CONFLICT));
+        assert_eq!(view.scroll_offset, 1);
+        view.handle_key(KeyEvent::from(keys::DIFF_PREV_CONFLICT));
+        assert_eq!(view.scroll_offset, 4);
+        assert_eq!(view.conflict_context().as_deref(), Some("conflict 2/2"));
+    }
+
+    #[test]
+    fn test_conflict_navigation_without_conflicts() {
+        let mut view = DiffView::new("testchange".to_string(), create_test_content());
+        let action = view.handle_key(KeyEvent::from(keys::DIFF_NEXT_CONFLICT));
+        assert_eq!(
+            action,
+            DiffAction::ShowNotification("No conflict markers in this diff".to_string())
+        );
+        assert_eq!(view.conflict_context(), None);
+    }
+
+    #[test]
+    fn test_resolve_key_opens_conflicted_file_only() {
+        let mut view = DiffView::new("testchange".to_string(), create_conflict_content());
+        let action = view.handle_key(KeyEvent::from(keys::RESOLVE_LIST));
+        assert_eq!(
+            action,
+            DiffAction::OpenResolve {
+                file_path: "src/lib.rs".to_string()
+            }
+        );
+
+        view.next_file();
+        let action = view.handle_key(KeyEvent::from(keys::RESOLVE_LIST));
+        assert!(matches!(action, DiffAction::ShowNotification(_)));
+    }
 }
@@ -4,15 +4,16 @@ use ratatui::{
     prelude::*,
     style::Stylize,
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Paragraph, Wrap},
 };
 
 use crate::model::{
     CompareInfo, DiffDisplayFormat, DiffLine, DiffLineKind, DiffMode, Notification,
+    format_timestamp,
 };
 use crate::ui::{components, theme};
 
-use super::DiffView;
+use super::{DiffLineFilter, DiffView};
 
 impl DiffView {
     /// Render the diff view (without status bar - rendered by App)
@@ -75,7 +76,7 @@ impl DiffView {
             Span::raw(" Tij - Diff View ").bold(),
             Span::raw("["),
             Span::styled(
-                self.revision.chars().take(8).collect::<String>(),
+                crate::model::short_id(&self.revision, self.id_length).to_string(),
                 Style::default().fg(theme::log_view::CHANGE_ID),
             ),
             Span::raw("]"),
@@ -111,7 +112,7 @@ impl DiffView {
                 Span::raw(&self.content.author),
                 Span::raw("  "),
                 Span::styled(
-                    &self.content.timestamp,
+                    format_timestamp(&self.content.timestamp, &self.timestamp_format),
                     Style::default().fg(Color::DarkGray),
                 ),
             ]),
@@ -285,11 +286,42 @@ impl DiffView {
             " (no files)".to_string()
         };
 
-        let bar = Paragraph::new(Line::from(vec![Span::styled(
+        let mut spans = vec![Span::styled(
             file_info,
             Style::default().fg(Color::Cyan).bold(),
-        )]))
-        .block(components::side_borders_block());
+        )];
+        if self.line_filter != DiffLineFilter::All && self.display_format.filters_lines() {
+            spans.push(Span::styled(
+                format!("  [{}]", self.line_filter.label()),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if self.collapse_moves && self.display_format != DiffDisplayFormat::Stat {
+            spans.push(Span::styled(
+                "  [moves collapsed]",
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        for label in self.options.labels() {
+            spans.push(Span::styled(
+                format!("  [{}]", label),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if let Some(label) = self.overflow.label() {
+            spans.push(Span::styled(
+                format!("  [{}]", label),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if self.loading {
+            spans.push(Span::styled(
+                format!("  [loading… {} lines]", self.total_lines()),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        let bar = Paragraph::new(Line::from(spans)).block(components::side_borders_block());
 
         frame.render_widget(bar, area);
     }
@@ -316,7 +348,10 @@ impl DiffView {
             .map(|diff_line| self.render_diff_line(diff_line))
             .collect();
 
-        let diff = Paragraph::new(lines).block(components::side_borders_block());
+        let mut diff = Paragraph::new(lines).block(components::side_borders_block());
+        if self.overflow.wrap {
+            diff = diff.wrap(Wrap { trim: false });
+        }
 
         frame.render_widget(diff, area);
     }
@@ -340,10 +375,13 @@ impl DiffView {
                             Style::default().fg(theme::diff_view::LINE_NUMBER),
                         ),
                         Span::raw("  "),
-                        Span::raw(line.content.clone()),
+                        Span::raw(self.overflow.visible(&line.content).to_string()),
                     ])
                 } else {
-                    Line::from(Span::raw(format!(" {}", line.content)))
+                    Line::from(Span::raw(format!(
+                        " {}",
+                        self.overflow.visible(&line.content)
+                    )))
                 }
             }
             DiffLineKind::Added => {
@@ -356,13 +394,13 @@ impl DiffView {
                         ),
                         Span::styled(" +", Style::default().fg(theme::diff_view::ADDED)),
                         Span::styled(
-                            line.content.clone(),
+                            self.overflow.visible(&line.content).to_string(),
                             Style::default().fg(theme::diff_view::ADDED),
                         ),
                     ])
                 } else {
                     Line::from(Span::styled(
-                        format!(" +{}", line.content),
+                        format!(" +{}", self.overflow.visible(&line.content)),
                         Style::default().fg(theme::diff_view::ADDED),
                     ))
                 }
@@ -377,13 +415,13 @@ impl DiffView {
                         ),
                         Span::styled(" -", Style::default().fg(theme::diff_view::DELETED)),
                         Span::styled(
-                            line.content.clone(),
+                            self.overflow.visible(&line.content).to_string(),
                             Style::default().fg(theme::diff_view::DELETED),
                         ),
                     ])
                 } else {
                     Line::from(Span::styled(
-                        format!(" -{}", line.content),
+                        format!(" -{}", self.overflow.visible(&line.content)),
                         Style::default().fg(theme::diff_view::DELETED),
                     ))
                 }
@@ -27,6 +27,14 @@ impl EvologView {
                 EvologAction::None
             }
 
+            // Compare mode: Enter picks the second version, Esc cancels
+            KeyCode::Enter if self.is_comparing() => self.finish_compare(),
+            KeyCode::Esc if self.is_comparing() => {
+                self.compare_from = None;
+                EvologAction::None
+            }
+            k if k == keys::COMPARE => self.start_compare(),
+
             // Actions
             KeyCode::Enter => {
                 if let Some(entry) = self.selected_entry() {
@@ -36,6 +44,14 @@ impl EvologView {
                 }
             }
 
+            k if k == keys::YANK => {
+                if self.selected_entry().is_some() {
+                    EvologAction::Yank
+                } else {
+                    EvologAction::None
+                }
+            }
+
             // Back/Quit
             k if k == keys::QUIT => EvologAction::Back,
             KeyCode::Esc => EvologAction::Back,
@@ -5,7 +5,7 @@
 mod input;
 mod render;
 
-use crate::model::EvologEntry;
+use crate::model::{EvologEntry, TimestampFormat};
 use crate::ui::navigation;
 
 /// Action returned by the Evolog View after handling input
@@ -17,6 +17,14 @@ pub enum EvologAction {
     Back,
     /// Open diff for the selected commit_id
     OpenDiff(String),
+    /// Open the yank (copy to clipboard) menu for the selected entry
+    Yank,
+    /// Compare mode started with the selected entry as one side (commit_id)
+    StartCompare(String),
+    /// Diff two versions of the change: `from` is the older commit_id
+    Compare { from: String, to: String },
+    /// Compare target is the version already marked
+    CompareSameVersion,
 }
 
 /// Evolution Log View state
@@ -30,6 +38,10 @@ pub struct EvologView {
     pub(super) selected: usize,
     /// Scroll offset for long lists
     pub(super) scroll_offset: usize,
+    /// Entry marked as one side of a compare (Some = compare mode active)
+    pub(super) compare_from: Option<usize>,
+    /// Timestamp display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub(crate) timestamp_format: TimestampFormat,
 }
 
 impl EvologView {
@@ -40,6 +52,44 @@ impl EvologView {
             entries,
             selected: 0,
             scroll_offset: 0,
+            compare_from: None,
+            timestamp_format: TimestampFormat::default(),
+        }
+    }
+
+    /// Whether compare mode is waiting for the second version
+    pub fn is_comparing(&self) -> bool {
+        self.compare_from.is_some()
+    }
+
+    /// Mark the selected entry as one side of a compare
+    fn start_compare(&mut self) -> EvologAction {
+        match self.selected_entry() {
+            Some(entry) => {
+                let commit_id = entry.commit_id.to_string();
+                self.compare_from = Some(self.selected);
+                EvologAction::StartCompare(commit_id)
+            }
+            None => EvologAction::None,
+        }
+    }
+
+    /// Finish compare mode with the selected entry as the other side
+    ///
+    /// Entries are newest first, so the one further down is the older
+    /// version and becomes `from`.
+    fn finish_compare(&mut self) -> EvologAction {
+        let Some(marked) = self.compare_from else {
+            return EvologAction::None;
+        };
+        if marked == self.selected {
+            return EvologAction::CompareSameVersion;
+        }
+        self.compare_from = None;
+        let (older, newer) = (marked.max(self.selected), marked.min(self.selected));
+        EvologAction::Compare {
+            from: self.entries[older].commit_id.to_string(),
+            to: self.entries[newer].commit_id.to_string(),
         }
     }
 
@@ -153,6 +203,18 @@ mod tests {
         assert_eq!(action, EvologAction::OpenDiff("43a4bc7d".to_string()));
     }
 
+    #[test]
+    fn test_handle_key_yank() {
+        use crossterm::event::{KeyCode, KeyEvent};
+        let mut view = EvologView::new("zxsrvopz".to_string(), create_test_entries());
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(action, EvologAction::Yank);
+
+        let mut empty = EvologView::new("zxsrvopz".to_string(), vec![]);
+        let action = empty.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(action, EvologAction::None);
+    }
+
     #[test]
     fn test_handle_key_back() {
         use crossterm::event::{KeyCode, KeyEvent};
@@ -166,4 +228,49 @@ mod tests {
         let view = EvologView::new("test".to_string(), vec![]);
         assert!(view.selected_entry().is_none());
     }
+
+    #[test]
+    fn test_compare_orders_older_version_first() {
+        use crossterm::event::{KeyCode, KeyEvent};
+        let mut view = EvologView::new("zxsrvopz".to_string(), create_test_entries());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('=')));
+        assert_eq!(action, EvologAction::StartCompare("43a4bc7d".to_string()));
+        assert!(view.is_comparing());
+
+        view.select_last();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(
+            action,
+            EvologAction::Compare {
+                from: "initial1".to_string(),
+                to: "43a4bc7d".to_string(),
+            }
+        );
+        assert!(!view.is_comparing());
+    }
+
+    #[test]
+    fn test_compare_same_version_stays_in_mode() {
+        use crossterm::event::{KeyCode, KeyEvent};
+        let mut view = EvologView::new("zxsrvopz".to_string(), create_test_entries());
+        view.handle_key(KeyEvent::from(KeyCode::Char('=')));
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, EvologAction::CompareSameVersion);
+        assert!(view.is_comparing());
+    }
+
+    #[test]
+    fn test_compare_esc_cancels() {
+        use crossterm::event::{KeyCode, KeyEvent};
+        let mut view = EvologView::new("zxsrvopz".to_string(), create_test_entries());
+        view.handle_key(KeyEvent::from(KeyCode::Char('=')));
+        let action = view.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(action, EvologAction::None);
+        assert!(!view.is_comparing());
+
+        // Without compare mode, Enter opens the diff again
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, EvologAction::OpenDiff("43a4bc7d".to_string()));
+    }
 }
@@ -9,16 +9,21 @@ use ratatui::{
 };
 
 use super::EvologView;
-use crate::model::{EvologEntry, Notification};
+use crate::model::{EvologEntry, Notification, format_timestamp};
 use crate::ui::{components, navigation, theme};
 
 impl EvologView {
     /// Render the evolog view
     pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
-        let title = Line::from(format!(" Evolution Log: {} ", self.revision))
-            .bold()
-            .cyan()
-            .centered();
+        let title = if self.is_comparing() {
+            format!(
+                " Evolution Log: {} [Compare: select the other version, Enter] ",
+                self.revision
+            )
+        } else {
+            format!(" Evolution Log: {} ", self.revision)
+        };
+        let title = Line::from(title).bold().cyan().centered();
 
         // Build notification line for title bar
         let title_width = title.width();
@@ -53,7 +58,8 @@ impl EvologView {
             }
 
             let is_selected = idx == self.selected;
-            let line = self.build_entry_line(entry, is_selected);
+            let is_marked = self.compare_from == Some(idx);
+            let line = self.build_entry_line(entry, is_selected, is_marked);
             lines.push(line);
         }
 
@@ -62,18 +68,35 @@ impl EvologView {
     }
 
     /// Build a line for an evolog entry
-    fn build_entry_line(&self, entry: &EvologEntry, is_selected: bool) -> Line<'static> {
+    fn build_entry_line(
+        &self,
+        entry: &EvologEntry,
+        is_selected: bool,
+        is_marked: bool,
+    ) -> Line<'static> {
         let id_style = Style::default().fg(Color::Magenta);
         let time_style = Style::default().fg(Color::Yellow);
         let desc_style = Style::default().fg(Color::White);
         let empty_style = Style::default().fg(Color::DarkGray);
 
-        let mut spans = vec![
+        let mut spans = Vec::new();
+        if is_marked {
+            spans.push(Span::styled(
+                "[compare] ",
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        spans.extend([
             Span::styled(entry.commit_id.to_string(), id_style),
             Span::raw("  "),
-            Span::styled(entry.timestamp.clone(), time_style),
+            Span::styled(
+                format_timestamp(&entry.timestamp, &self.timestamp_format),
+                time_style,
+            ),
             Span::raw("  "),
-        ];
+        ]);
 
         if entry.is_empty {
             spans.push(Span::styled("[empty] ", empty_style));
@@ -0,0 +1,46 @@
+//! File Log View key handling
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{FileLogAction, FileLogView};
+use crate::keys;
+
+impl FileLogView {
+    /// Handle key input
+    pub fn handle_key(&mut self, key: KeyEvent) -> FileLogAction {
+        match key.code {
+            // Navigation
+            k if keys::is_move_down(k) => {
+                self.select_next();
+                FileLogAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.select_prev();
+                FileLogAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.select_first();
+                FileLogAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.select_last();
+                FileLogAction::None
+            }
+
+            // Actions
+            KeyCode::Enter => {
+                if let Some(entry) = self.selected_entry() {
+                    FileLogAction::OpenDiff(entry.change_id.to_string())
+                } else {
+                    FileLogAction::None
+                }
+            }
+
+            // Back/Quit
+            k if k == keys::QUIT => FileLogAction::Back,
+            KeyCode::Esc => FileLogAction::Back,
+
+            _ => FileLogAction::None,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! File Log View - history of a single file
+//!
+//! Shows `jj log <path>`: the revisions that touched the file, newest first.
+
+mod input;
+mod render;
+
+use crate::model::{Change, TimestampFormat};
+use crate::ui::navigation;
+
+/// Action returned by the File Log View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileLogAction {
+    /// No action needed
+    None,
+    /// Go back to previous view
+    Back,
+    /// Open the diff of the selected revision (change_id), jumped to the file
+    OpenDiff(String),
+}
+
+/// File Log View state
+#[derive(Debug)]
+pub struct FileLogView {
+    /// File whose history is shown
+    pub file_path: String,
+    /// Revision whose ancestors were searched
+    pub revision: String,
+    /// Revisions that touched the file (newest first)
+    pub(super) entries: Vec<Change>,
+    /// Selected entry index
+    pub(super) selected: usize,
+    /// Scroll offset for long lists
+    pub(super) scroll_offset: usize,
+    /// Timestamp display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub(crate) timestamp_format: TimestampFormat,
+}
+
+impl FileLogView {
+    /// Create a new File Log View
+    pub fn new(file_path: String, revision: String, entries: Vec<Change>) -> Self {
+        Self {
+            file_path,
+            revision,
+            entries,
+            selected: 0,
+            scroll_offset: 0,
+            timestamp_format: TimestampFormat::default(),
+        }
+    }
+
+    /// Get the currently selected entry
+    pub fn selected_entry(&self) -> Option<&Change> {
+        self.entries.get(self.selected)
+    }
+
+    /// Move selection up
+    pub fn select_prev(&mut self) {
+        self.selected = navigation::select_prev(self.selected);
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        let max = self.entries.len().saturating_sub(1);
+        self.selected = navigation::select_next(self.selected, max);
+    }
+
+    /// Go to first entry
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Go to last entry
+    pub fn select_last(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = self.entries.len() - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ChangeId, CommitId};
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn create_test_entries() -> Vec<Change> {
+        vec![
+            Change {
+                change_id: ChangeId::new("zxsrvopz".to_string()),
+                commit_id: CommitId::new("43a4bc7d".to_string()),
+                description: "Fix parser".to_string(),
+                is_working_copy: true,
+                ..Default::default()
+            },
+            Change {
+                change_id: ChangeId::new("kkmpptxz".to_string()),
+                commit_id: CommitId::new("7aa68914".to_string()),
+                description: "Add parser".to_string(),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut view = FileLogView::new(
+            "src/parser.rs".to_string(),
+            "@".to_string(),
+            create_test_entries(),
+        );
+        view.select_next();
+        view.select_next();
+        assert_eq!(view.selected, 1);
+        view.select_prev();
+        assert_eq!(view.selected, 0);
+        view.select_last();
+        assert_eq!(view.selected, 1);
+        view.select_first();
+        assert_eq!(view.selected, 0);
+    }
+
+    #[test]
+    fn test_enter_opens_diff_of_selected_revision() {
+        let mut view = FileLogView::new(
+            "src/parser.rs".to_string(),
+            "@".to_string(),
+            create_test_entries(),
+        );
+        view.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, FileLogAction::OpenDiff("kkmpptxz".to_string()));
+    }
+
+    #[test]
+    fn test_empty_view() {
+        let mut view = FileLogView::new("a.rs".to_string(), "@".to_string(), vec![]);
+        assert!(view.selected_entry().is_none());
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, FileLogAction::None);
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('q')));
+        assert_eq!(action, FileLogAction::Back);
+    }
+}
@@ -0,0 +1,161 @@
+//! File Log View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::FileLogView;
+use crate::model::{Change, Notification, TimestampFormat, format_timestamp};
+use crate::ui::{components, navigation, theme};
+
+impl FileLogView {
+    /// Render the file log view
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let title = Line::from(format!(
+            " File History: {} ({}) ",
+            self.file_path,
+            self.entries.len()
+        ))
+        .bold()
+        .cyan()
+        .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        if self.entries.is_empty() {
+            let paragraph = components::empty_state(
+                "No revisions touched this file",
+                Some(&format!("Searched ::{}", self.revision)),
+            )
+            .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let inner_height = area.height.saturating_sub(2) as usize; // borders
+        if inner_height == 0 {
+            return;
+        }
+
+        let scroll_offset =
+            navigation::adjust_scroll(self.selected, self.scroll_offset, inner_height);
+
+        let lines: Vec<Line> = self
+            .entries
+            .iter()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(inner_height)
+            .map(|(idx, entry)| {
+                build_entry_line(entry, idx == self.selected, &self.timestamp_format)
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// `@ change_id  timestamp  author  [bookmarks] description`
+fn build_entry_line(
+    entry: &Change,
+    is_selected: bool,
+    timestamp_format: &TimestampFormat,
+) -> Line<'static> {
+    let (marker, marker_color) = if entry.is_working_copy {
+        (" @ ", theme::log_view::WORKING_COPY_MARKER)
+    } else {
+        (" ○ ", theme::log_view::NORMAL_MARKER)
+    };
+
+    let mut spans = vec![
+        Span::styled(marker, Style::default().fg(marker_color)),
+        Span::styled(
+            entry.change_id.to_string(),
+            Style::default().fg(theme::log_view::CHANGE_ID),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format_timestamp(&entry.timestamp, timestamp_format),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw("  "),
+        Span::styled(entry.author.clone(), Style::default().fg(Color::DarkGray)),
+        Span::raw("  "),
+    ];
+    if !entry.bookmarks.is_empty() {
+        spans.push(Span::styled(
+            format!("{} ", entry.bookmarks.join(",")),
+            Style::default().fg(theme::log_view::BOOKMARK),
+        ));
+    }
+    spans.push(Span::styled(
+        entry.display_description().to_string(),
+        Style::default().fg(Color::White),
+    ));
+
+    let mut line = Line::from(spans);
+    if is_selected {
+        line = line.style(
+            Style::default()
+                .fg(theme::selection::FG)
+                .bg(theme::selection::BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ChangeId;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_entry_line_shows_date_author_and_bookmarks() {
+        let entry = Change {
+            change_id: ChangeId::new("kkmpptxz".to_string()),
+            author: "user@example.com".to_string(),
+            timestamp: "2026-10-15T14:25:30+0900".to_string(),
+            bookmarks: vec!["main".to_string()],
+            description: "Add parser".to_string(),
+            ..Default::default()
+        };
+        let text = line_text(&build_entry_line(
+            &entry,
+            false,
+            &TimestampFormat::default(),
+        ));
+        assert_eq!(
+            text,
+            " ○ kkmpptxz  2026-10-15 14:25:30  user@example.com  main Add parser"
+        );
+    }
+
+    #[test]
+    fn test_entry_line_working_copy_without_description() {
+        let entry = Change {
+            change_id: ChangeId::new("zxsrvopz".to_string()),
+            is_working_copy: true,
+            ..Default::default()
+        };
+        let text = line_text(&build_entry_line(&entry, true, &TimestampFormat::default()));
+        assert!(text.starts_with(" @ zxsrvopz"));
+        assert!(text.ends_with("(no description set)"));
+    }
+}
@@ -0,0 +1,375 @@
+//! Structured model of jj's graph prefix
+//!
+//! jj draws the DAG as a text prefix in front of each log line. Instead of
+//! passing those characters through verbatim, the prefix is split into cells
+//! with a column index so edges can be colored per branch and node glyphs
+//! can be replaced with themed markers.
+//!
+//! jj already draws the prefix in the user's `ui.graph.style`; the parser
+//! accepts the glyphs of every style and the themed node markers follow the
+//! configured style, so an ASCII graph stays ASCII.
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::Span,
+};
+
+use crate::model::Change;
+use crate::ui::{symbols, theme};
+
+/// Width of a single graph column in jj's output (glyph + padding)
+const COLUMN_WIDTH: usize = 2;
+
+/// jj's `ui.graph.style`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphStyle {
+    /// `curved` (jj's default): `╭─╯`
+    #[default]
+    Curved,
+    /// `square`: `┌─┘`
+    Square,
+    /// `ascii`: `|/\`, nodes `o`/`x`/`+`
+    Ascii,
+    /// `ascii-large`: like `ascii` with wider joins
+    AsciiLarge,
+}
+
+impl GraphStyle {
+    /// Parse a `ui.graph.style` value (None if unknown)
+    pub fn from_config(value: &str) -> Option<Self> {
+        match value.trim().trim_matches('"') {
+            "curved" => Some(Self::Curved),
+            "square" => Some(Self::Square),
+            "ascii" => Some(Self::Ascii),
+            "ascii-large" => Some(Self::AsciiLarge),
+            _ => None,
+        }
+    }
+
+    /// Whether node markers must be ASCII
+    pub fn is_ascii(self) -> bool {
+        matches!(self, Self::Ascii | Self::AsciiLarge)
+    }
+}
+
+/// Kind of a single graph cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CellKind {
+    /// Commit node (`@`, `○`, `◆`, `×`, ...)
+    Node,
+    /// Edge segment (vertical, horizontal or junction)
+    Edge,
+    /// Elided revisions marker (`~`)
+    Elided,
+    /// Padding between columns
+    Blank,
+}
+
+/// A single character of the graph prefix with its lane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct GraphCell {
+    /// Lane index (0 = leftmost)
+    pub column: usize,
+    /// Original character from jj output
+    pub ch: char,
+    pub kind: CellKind,
+}
+
+/// Parsed graph prefix of one log line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct GraphRow {
+    pub cells: Vec<GraphCell>,
+    /// Trailing text on graph-only lines (e.g. "(elided revisions)")
+    pub label: String,
+}
+
+impl GraphRow {
+    /// Whether this row marks an elided section
+    pub fn is_elided(&self) -> bool {
+        self.cells.iter().any(|c| c.kind == CellKind::Elided)
+    }
+}
+
+fn classify(ch: char) -> Option<CellKind> {
+    match ch {
+        '@' | '○' | '◆' | '×' | '◉' | '●' | '◌' | 'o' | 'x' | '+' => {
+            Some(CellKind::Node)
+        }
+        '│' | '├' | '┤' | '┬' | '┴' | '┼' | '─' | '╭' | '╮' | '╯' | '╰' | '┌' | '┐' | '└' | '┘'
+        | '|' | '/' | '\\' | '-' | '.' | '\'' | ':' => Some(CellKind::Edge),
+        '~' => Some(CellKind::Elided),
+        ' ' => Some(CellKind::Blank),
+        _ => None,
+    }
+}
+
+/// Parse a graph prefix into cells
+///
+/// Parsing stops at the first character that is not part of the graph;
+/// the rest of the string becomes the row label.
+pub(crate) fn parse_graph_prefix(prefix: &str) -> GraphRow {
+    let mut row = GraphRow::default();
+
+    for (pos, (byte_idx, ch)) in prefix.char_indices().enumerate() {
+        let Some(kind) = classify(ch) else {
+            row.label = prefix[byte_idx..].to_string();
+            break;
+        };
+        row.cells.push(GraphCell {
+            column: pos / COLUMN_WIDTH,
+            ch,
+            kind,
+        });
+    }
+
+    row
+}
+
+/// Edge color for a lane (cycles through the palette)
+pub(crate) fn edge_color(column: usize) -> ratatui::style::Color {
+    let palette = theme::log_view::GRAPH_EDGE_PALETTE;
+    palette[column % palette.len()]
+}
+
+/// Themed glyph and style for the node of `change`
+///
+/// In the ASCII styles jj's node template may still print `◆` (only
+/// `builtin_log_node_ascii` prints `+`), so both mark an immutable node.
+fn node_glyph(ch: char, change: &Change, style: GraphStyle) -> (char, Style) {
+    let (normal, immutable, conflict) = if style.is_ascii() {
+        (
+            symbols::ascii_markers::NORMAL,
+            symbols::ascii_markers::IMMUTABLE,
+            symbols::ascii_markers::CONFLICT,
+        )
+    } else {
+        (
+            symbols::markers::NORMAL,
+            symbols::markers::ROOT,
+            symbols::markers::CONFLICT,
+        )
+    };
+    if ch == symbols::markers::WORKING_COPY || change.is_working_copy {
+        (
+            symbols::markers::WORKING_COPY,
+            Style::default()
+                .fg(theme::log_view::WORKING_COPY_MARKER)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if change.has_conflict {
+        (
+            conflict,
+            Style::default()
+                .fg(theme::log_view::CONFLICT_MARKER)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if ch == symbols::markers::ROOT || ch == immutable {
+        (immutable, Style::default().fg(theme::log_view::ROOT_MARKER))
+    } else {
+        (normal, Style::default().fg(theme::log_view::NORMAL_MARKER))
+    }
+}
+
+/// Build styled spans for a change's graph prefix
+///
+/// Consecutive cells with the same style are merged into one span.
+pub(crate) fn graph_spans(change: &Change, style: GraphStyle) -> Vec<Span<'static>> {
+    let row = parse_graph_prefix(&change.graph_prefix);
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut buf = String::new();
+    let mut buf_style = Style::default();
+
+    for cell in &row.cells {
+        let (ch, style) = match cell.kind {
+            CellKind::Node if !change.is_graph_only => node_glyph(cell.ch, change, style),
+            CellKind::Node | CellKind::Edge => {
+                (cell.ch, Style::default().fg(edge_color(cell.column)))
+            }
+            CellKind::Elided => (cell.ch, Style::default().fg(theme::log_view::ELIDED)),
+            CellKind::Blank => (cell.ch, buf_style),
+        };
+        if style != buf_style && !buf.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut buf), buf_style));
+        }
+        buf_style = style;
+        buf.push(ch);
+    }
+    if !buf.is_empty() {
+        spans.push(Span::styled(buf, buf_style));
+    }
+
+    if !row.label.is_empty() {
+        let style = if row.is_elided() {
+            Style::default()
+                .fg(theme::log_view::ELIDED)
+                .add_modifier(Modifier::ITALIC)
+        } else {
+            Style::default().fg(theme::log_view::GRAPH_LINE)
+        };
+        spans.push(Span::styled(row.label, style));
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(spans: &[Span]) -> String {
+        spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_parse_simple_node() {
+        let row = parse_graph_prefix("@  ");
+        assert_eq!(row.cells.len(), 3);
+        assert_eq!(row.cells[0].kind, CellKind::Node);
+        assert_eq!(row.cells[0].column, 0);
+        assert!(row.label.is_empty());
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        let row = parse_graph_prefix("│ │ ○  ");
+        let node = row.cells.iter().find(|c| c.kind == CellKind::Node).unwrap();
+        assert_eq!(node.column, 2);
+        let edges: Vec<usize> = row
+            .cells
+            .iter()
+            .filter(|c| c.kind == CellKind::Edge)
+            .map(|c| c.column)
+            .collect();
+        assert_eq!(edges, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_parse_elided_label() {
+        let row = parse_graph_prefix("~  (elided revisions)");
+        assert!(row.is_elided());
+        assert_eq!(row.label, "(elided revisions)");
+        assert_eq!(row.cells.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_merge_line() {
+        let row = parse_graph_prefix("├─╯");
+        assert!(row.cells.iter().all(|c| c.kind == CellKind::Edge));
+        assert_eq!(row.cells[2].column, 1);
+    }
+
+    #[test]
+    fn test_edge_color_cycles() {
+        let len = theme::log_view::GRAPH_EDGE_PALETTE.len();
+        assert_eq!(edge_color(0), edge_color(len));
+        assert_ne!(edge_color(0), edge_color(1));
+    }
+
+    #[test]
+    fn test_graph_spans_preserve_text() {
+        let change = Change {
+            graph_prefix: "│ ○  ".to_string(),
+            ..Change::default()
+        };
+        assert_eq!(text(&graph_spans(&change, GraphStyle::Curved)), "│ ○  ");
+    }
+
+    #[test]
+    fn test_graph_spans_conflict_glyph() {
+        let change = Change {
+            graph_prefix: "○  ".to_string(),
+            has_conflict: true,
+            ..Change::default()
+        };
+        assert_eq!(text(&graph_spans(&change, GraphStyle::Curved)), "×  ");
+    }
+
+    #[test]
+    fn test_graph_spans_working_copy_wins_over_conflict() {
+        let change = Change {
+            graph_prefix: "@  ".to_string(),
+            is_working_copy: true,
+            has_conflict: true,
+            ..Change::default()
+        };
+        let spans = graph_spans(&change, GraphStyle::Curved);
+        assert_eq!(text(&spans), "@  ");
+        assert_eq!(
+            spans[0].style.fg,
+            Some(theme::log_view::WORKING_COPY_MARKER)
+        );
+    }
+
+    #[test]
+    fn test_graph_style_from_config() {
+        assert_eq!(GraphStyle::from_config("square"), Some(GraphStyle::Square));
+        assert_eq!(
+            GraphStyle::from_config("\"ascii\""),
+            Some(GraphStyle::Ascii)
+        );
+        assert_eq!(
+            GraphStyle::from_config("ascii-large"),
+            Some(GraphStyle::AsciiLarge)
+        );
+        assert_eq!(GraphStyle::from_config("fancy"), None);
+    }
+
+    #[test]
+    fn test_parse_square_and_ascii_edges() {
+        let row = parse_graph_prefix("├─┘");
+        assert!(row.cells.iter().all(|c| c.kind == CellKind::Edge));
+        let row = parse_graph_prefix("|/ ");
+        assert_eq!(row.cells[0].kind, CellKind::Edge);
+        assert_eq!(row.cells[1].kind, CellKind::Edge);
+        assert!(row.label.is_empty());
+    }
+
+    #[test]
+    fn test_graph_spans_ascii_markers() {
+        let normal = Change {
+            graph_prefix: "| o  ".to_string(),
+            ..Change::default()
+        };
+        assert_eq!(text(&graph_spans(&normal, GraphStyle::Ascii)), "| o  ");
+        let immutable = Change {
+            graph_prefix: "+  ".to_string(),
+            ..Change::default()
+        };
+        let spans = graph_spans(&immutable, GraphStyle::Ascii);
+        assert_eq!(text(&spans), "+  ");
+        assert_eq!(spans[0].style.fg, Some(theme::log_view::ROOT_MARKER));
+        // The default (non-ASCII) node template prints `◆`
+        let unicode_immutable = Change {
+            graph_prefix: "◆  ".to_string(),
+            ..Change::default()
+        };
+        let spans = graph_spans(&unicode_immutable, GraphStyle::Ascii);
+        assert_eq!(text(&spans), "+  ");
+        assert_eq!(spans[0].style.fg, Some(theme::log_view::ROOT_MARKER));
+        let conflict = Change {
+            graph_prefix: "o  ".to_string(),
+            has_conflict: true,
+            ..Change::default()
+        };
+        assert_eq!(text(&graph_spans(&conflict, GraphStyle::AsciiLarge)), "x  ");
+    }
+
+    #[test]
+    fn test_graph_spans_graph_only_keeps_label() {
+        let change = Change {
+            graph_prefix: "│ ~  (elided revisions)".to_string(),
+            is_graph_only: true,
+            ..Change::default()
+        };
+        let spans = graph_spans(&change, GraphStyle::Curved);
+        assert_eq!(text(&spans), "│ ~  (elided revisions)");
+        assert!(
+            spans
+                .last()
+                .unwrap()
+                .style
+                .add_modifier
+                .contains(Modifier::ITALIC)
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::keys;
 use crate::model::Change;
+use crate::ui::components::TextAreaEvent;
 
 use super::{InputMode, LogAction, LogView, RebaseMode, RebaseSource};
 
@@ -35,7 +36,9 @@ impl LogView {
             InputMode::InterdiffSelect => self.handle_interdiff_select_key(key),
             InputMode::BisectSelect => self.handle_bisect_select_key(key),
             InputMode::ParallelizeSelect => self.handle_parallelize_select_key(key),
+            InputMode::RevertSelect => self.handle_revert_select_key(key),
             InputMode::RebaseRevsetInput => self.handle_rebase_revset_input_key(key),
+            InputMode::Reorder => self.handle_reorder_key(key),
         }
     }
 
@@ -51,6 +54,29 @@ impl LogView {
             };
         }
 
+        if keys::is_revert_range_key(&key) {
+            return if self.start_revert_select() {
+                let from_id = self.revert_from.as_ref().unwrap().0.clone();
+                LogAction::StartRevertRange(from_id)
+            } else {
+                LogAction::None
+            };
+        }
+
+        if keys::is_split_files_key(&key) {
+            return match self.selected_change() {
+                Some(change) => LogAction::SplitFiles(change.commit_id.to_string()),
+                None => LogAction::None,
+            };
+        }
+
+        if keys::is_merge_helper_key(&key) {
+            return match self.selected_change() {
+                Some(change) => LogAction::StartMerge(change.change_id.to_string()),
+                None => LogAction::None,
+            };
+        }
+
         match key.code {
             k if keys::is_move_down(k) => {
                 self.move_down();
@@ -92,17 +118,18 @@ impl LogView {
             }
             k if k == keys::NEW_CHANGE => LogAction::NewChange,
             k if k == keys::NEW_FROM => {
-                if let Some(change) = self.selected_change() {
+                if let Some((parents, labels)) = self.marked_parents() {
+                    LogAction::NewMergeChange { parents, labels }
+                } else if let Some(change) = self.selected_change() {
                     if change.is_working_copy {
                         // @ で C を押した場合は c の使用を案内
                         LogAction::NewChangeFromCurrent
                     } else {
                         // 表示名: 先頭 bookmark があれば優先、なければ short_id
-                        let display_name = change
-                            .bookmarks
-                            .first()
-                            .cloned()
-                            .unwrap_or_else(|| change.change_id.short().to_string());
+                        let display_name =
+                            change.bookmarks.first().cloned().unwrap_or_else(|| {
+                                change.change_id.short(self.id_length).to_string()
+                            });
                         LogAction::NewChangeFrom {
                             revision: change.commit_id.to_string(),
                             display_name,
@@ -112,11 +139,30 @@ impl LogView {
                     LogAction::None
                 }
             }
+            k if k == keys::MARK => {
+                self.toggle_mark();
+                LogAction::None
+            }
+            k if k == keys::ESC => {
+                // Only reached with marks (App handles Esc as back otherwise)
+                self.marked.clear();
+                LogAction::None
+            }
             k if k == keys::SQUASH => {
                 // Enter SquashSelect mode (validation happens in App layer)
                 self.start_squash_select();
                 LogAction::None
             }
+            k if k == keys::QUICK_AMEND => {
+                if let Some(change) = self.selected_change() {
+                    LogAction::QuickAmend {
+                        destination: change.commit_id.to_string(),
+                        change_id: change.change_id.to_string(),
+                    }
+                } else {
+                    LogAction::None
+                }
+            }
             k if k == keys::ABANDON => {
                 if let Some(change) = self.selected_change() {
                     LogAction::Abandon(change.commit_id.to_string())
@@ -177,8 +223,24 @@ impl LogView {
             }
             k if k == keys::FETCH => LogAction::Fetch,
             k if k == keys::PUSH => LogAction::StartPush,
+            k if k == keys::PUSH_QUEUE_TOGGLE => match self.selected_change() {
+                Some(change) => LogAction::TogglePushQueue(change.bookmarks.clone()),
+                None => LogAction::None,
+            },
             k if k == keys::TRACK => LogAction::StartTrack,
             k if k == keys::BOOKMARK_JUMP => LogAction::StartBookmarkJump,
+            k if k == keys::PIN => match self.selected_change() {
+                Some(change) => LogAction::TogglePin {
+                    change_id: change.change_id.to_string(),
+                    bookmark: change.bookmarks.first().cloned(),
+                },
+                None => LogAction::None,
+            },
+            KeyCode::Char(c @ '1'..='9') => LogAction::JumpToPin(c as u8 - b'0'),
+            k if k == keys::NOTE => match self.selected_change() {
+                Some(change) => LogAction::EditNote(change.change_id.to_string()),
+                None => LogAction::None,
+            },
             k if k == keys::COMPARE => {
                 if self.start_compare_select() {
                     let from_id = self.compare_from.as_ref().unwrap().0.to_string();
@@ -206,10 +268,40 @@ impl LogView {
             k if k == keys::BOOKMARK_VIEW => LogAction::OpenBookmarkView,
             k if k == keys::TAG_VIEW => LogAction::OpenTagView,
             k if k == keys::WORKSPACE_VIEW => LogAction::OpenWorkspaceView,
+            k if k == keys::REMOTE_VIEW => LogAction::OpenRemoteView,
+            k if k == keys::STACK_VIEW => LogAction::OpenStackView,
             k if k == keys::COMMAND_HISTORY => LogAction::OpenCommandHistory,
             k if k == keys::NEXT_CHANGE => LogAction::NextChange,
             k if k == keys::PREV_CHANGE => LogAction::PrevChange,
             k if k == keys::LOG_REVERSE => LogAction::ToggleReversed,
+            k if k == keys::TOGGLE_ELIDED => LogAction::ToggleElided,
+            k if k == keys::TOGGLE_HIDDEN => LogAction::ToggleHidden,
+            k if k == keys::RESTORE_HIDDEN => match self.selected_change() {
+                Some(change) if change.is_hidden => {
+                    LogAction::RestoreHidden(change.commit_id.to_string())
+                }
+                _ => LogAction::None,
+            },
+            k if k == keys::CENTER_CURSOR => LogAction::ToggleCenterCursor,
+            k if k == keys::COMMIT_ID_COLUMN => LogAction::ToggleCommitIds,
+            k if k == keys::REVSET_PRESET_CYCLE => LogAction::CycleRevsetPreset,
+            k if k == keys::REVSET_PRESET_MENU => LogAction::OpenRevsetPresets,
+            k if k == keys::COMPARE_SESSIONS => LogAction::OpenCompareSessions,
+            k if k == keys::JJ_COMMAND => LogAction::StartJjCommand,
+            k if k == keys::REPORT_PARSE_ISSUE => {
+                if self.parse_issue.is_some() {
+                    LogAction::ReportParseIssue
+                } else {
+                    LogAction::None
+                }
+            }
+            k if k == keys::YANK => {
+                if self.selected_change().is_some() {
+                    LogAction::StartYank
+                } else {
+                    LogAction::None
+                }
+            }
             k if k == keys::DUPLICATE => {
                 if let Some(change) = self.selected_change() {
                     LogAction::Duplicate(change.commit_id.to_string())
@@ -217,6 +309,13 @@ impl LogView {
                     LogAction::None
                 }
             }
+            k if k == keys::CHERRY_PICK => {
+                if let Some(change) = self.selected_change() {
+                    LogAction::CherryPick(change.commit_id.to_string())
+                } else {
+                    LogAction::None
+                }
+            }
             k if k == keys::DIFFEDIT => {
                 if let Some(change) = self.selected_change() {
                     LogAction::DiffEdit(change.commit_id.to_string())
@@ -279,6 +378,51 @@ impl LogView {
                 }
             }
             k if k == keys::ARRANGE => LogAction::Arrange,
+            k if k == keys::REORDER => {
+                if self.selected_change().is_some() {
+                    self.input_mode = InputMode::Reorder;
+                    LogAction::StartReorder
+                } else {
+                    LogAction::None
+                }
+            }
+            _ => LogAction::None,
+        }
+    }
+
+    /// Handle key events in reorder mode
+    ///
+    /// j/k pick a change, K/J move it; the selection follows the moved change.
+    fn handle_reorder_key(&mut self, key: KeyEvent) -> LogAction {
+        match key.code {
+            k if keys::is_move_down(k) => {
+                self.move_down();
+                LogAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.move_up();
+                LogAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.move_to_top();
+                LogAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.move_to_bottom();
+                LogAction::None
+            }
+            k if k == keys::MOVE_COMMIT_UP => match self.selected_change() {
+                Some(change) => LogAction::MoveCommitUp(change.change_id.to_string()),
+                None => LogAction::None,
+            },
+            k if k == keys::MOVE_COMMIT_DOWN => match self.selected_change() {
+                Some(change) => LogAction::MoveCommitDown(change.change_id.to_string()),
+                None => LogAction::None,
+            },
+            k if k == keys::ESC || k == KeyCode::Enter => {
+                self.input_mode = InputMode::Normal;
+                LogAction::None
+            }
             _ => LogAction::None,
         }
     }
@@ -310,17 +454,25 @@ impl LogView {
     }
 
     fn handle_describe_input_key(&mut self, key: KeyEvent) -> LogAction {
-        self.handle_text_input(key, |view, message| {
-            if let Some(revision) = view.editing_revision.take() {
-                if message.trim().is_empty() {
-                    LogAction::None // Empty = cancel
-                } else {
-                    LogAction::Describe { revision, message }
-                }
-            } else {
+        match self.describe_editor.handle_key(key) {
+            TextAreaEvent::None => LogAction::None,
+            TextAreaEvent::Cancel => {
+                self.cancel_input();
                 LogAction::None
             }
-        })
+            TextAreaEvent::Save => {
+                let message = self.describe_editor.text().trim_end().to_string();
+                let revision = self.editing_revision.take();
+                self.cancel_input();
+                match revision {
+                    // Empty = cancel
+                    Some(revision) if !message.trim().is_empty() => {
+                        LogAction::Describe { revision, message }
+                    }
+                    _ => LogAction::None,
+                }
+            }
+        }
     }
 
     fn handle_bookmark_input_key(&mut self, key: KeyEvent) -> LogAction {
@@ -411,6 +563,25 @@ impl LogView {
                 }
                 LogAction::None
             }
+            // Type the destination instead (for targets outside the revset)
+            KeyCode::Char('t') => {
+                let Some(rebase_src) = self.rebase_source.clone() else {
+                    return LogAction::None;
+                };
+                let (source, use_revset) = match rebase_src {
+                    RebaseSource::Selected { commit_id, .. } => (commit_id, false),
+                    RebaseSource::Revset(revset) => (revset, true),
+                };
+                let action = LogAction::PickRebaseDestination {
+                    source,
+                    mode: self.rebase_mode,
+                    skip_emptied: self.skip_emptied,
+                    use_revset,
+                    simplify_parents: self.simplify_parents,
+                };
+                self.cancel_rebase_select();
+                action
+            }
             // Toggle --skip-emptied
             KeyCode::Char('S') => {
                 self.skip_emptied = !self.skip_emptied;
@@ -497,28 +668,21 @@ impl LogView {
                 LogAction::None
             }
             // Confirm squash
-            KeyCode::Enter => {
-                if let (Some(source_pair), Some(dest_change)) =
-                    (self.squash_source.take(), self.selected_change())
-                {
-                    let destination = dest_change.commit_id.to_string();
-
-                    // Prevent squashing into self (compare by commit_id for divergent support)
-                    if source_pair.1 == destination {
-                        // Restore squash_source and stay in mode
-                        self.squash_source = Some(source_pair);
-                        return LogAction::None;
-                    }
-
-                    self.input_mode = InputMode::Normal;
-                    LogAction::SquashInto {
-                        source: source_pair.1,
-                        destination,
-                    }
-                } else {
-                    LogAction::None
-                }
-            }
+            KeyCode::Enter => match self.confirm_squash_target() {
+                Some((source, destination)) => LogAction::SquashInto {
+                    source,
+                    destination,
+                },
+                None => LogAction::None,
+            },
+            // Confirm, then pick which files to move
+            KeyCode::Char('f') => match self.confirm_squash_target() {
+                Some((source, destination)) => LogAction::SquashFilesInto {
+                    source,
+                    destination,
+                },
+                None => LogAction::None,
+            },
             // Cancel
             k if k == keys::ESC => {
                 self.cancel_squash_select();
@@ -529,6 +693,28 @@ impl LogView {
         }
     }
 
+    /// Leave squash select mode with the (source, destination) commit IDs
+    ///
+    /// Squashing into the source itself is refused and the mode stays on.
+    fn confirm_squash_target(&mut self) -> Option<(String, String)> {
+        let (Some(source_pair), Some(dest_change)) =
+            (self.squash_source.take(), self.selected_change())
+        else {
+            return None;
+        };
+        let destination = dest_change.commit_id.to_string();
+
+        // Prevent squashing into self (compare by commit_id for divergent support)
+        if source_pair.1 == destination {
+            // Restore squash_source and stay in mode
+            self.squash_source = Some(source_pair);
+            return None;
+        }
+
+        self.input_mode = InputMode::Normal;
+        Some((source_pair.1, destination))
+    }
+
     /// Handle key events in compare revision selection mode
     ///
     /// In this mode, j/k navigates to select the "to" revision, Enter confirms,
@@ -745,6 +931,49 @@ impl LogView {
         }
     }
 
+    /// Handle key events in revert range selection mode
+    ///
+    /// Enter on the first end itself reverts just that change.
+    fn handle_revert_select_key(&mut self, key: KeyEvent) -> LogAction {
+        match key.code {
+            k if keys::is_move_down(k) => {
+                self.move_down();
+                LogAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.move_up();
+                LogAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.move_to_top();
+                LogAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.move_to_bottom();
+                LogAction::None
+            }
+            KeyCode::Enter => {
+                let Some(to) = self.selected_change().map(|c| c.commit_id.to_string()) else {
+                    return LogAction::None;
+                };
+                let Some((_, from)) = self.revert_from.take() else {
+                    return LogAction::None;
+                };
+                self.input_mode = InputMode::Normal;
+                if from == to {
+                    LogAction::Revert(to)
+                } else {
+                    LogAction::RevertRange { from, to }
+                }
+            }
+            k if k == keys::ESC => {
+                self.cancel_revert_select();
+                LogAction::None
+            }
+            _ => LogAction::None,
+        }
+    }
+
     /// Handle key events in rebase revset text input mode
     ///
     /// Esc cancels and clears revset mode entirely.
@@ -765,7 +994,7 @@ impl LogView {
                 LogAction::None
             }
             k if k == keys::SUBMIT => {
-                let revset = std::mem::take(&mut self.input_buffer);
+                let revset = self.input_buffer.take();
                 if revset.is_empty() {
                     // Empty Enter: restore single change source
                     if let Some(change) = self.selected_change() {
@@ -780,19 +1009,10 @@ impl LogView {
                 self.input_mode = InputMode::RebaseSelect;
                 LogAction::None
             }
-            KeyCode::Char(c)
-                if !key
-                    .modifiers
-                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
-            {
-                self.input_buffer.push(c);
-                LogAction::None
-            }
-            KeyCode::Backspace => {
-                self.input_buffer.pop();
+            _ => {
+                self.input_buffer.handle_key(key);
                 LogAction::None
             }
-            _ => LogAction::None,
         }
     }
 
@@ -806,23 +1026,14 @@ impl LogView {
                 LogAction::None
             }
             k if k == keys::SUBMIT => {
-                let input = std::mem::take(&mut self.input_buffer);
+                let input = self.input_buffer.take();
                 self.input_mode = InputMode::Normal;
                 on_submit(self, input)
             }
-            KeyCode::Char(c)
-                if !key
-                    .modifiers
-                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
-            {
-                self.input_buffer.push(c);
-                LogAction::None
-            }
-            KeyCode::Backspace => {
-                self.input_buffer.pop();
+            _ => {
+                self.input_buffer.handle_key(key);
                 LogAction::None
             }
-            _ => LogAction::None,
         }
     }
 
@@ -2,13 +2,21 @@
 //!
 //! The main view of Tij, showing the change history.
 
+mod graph;
 mod input;
 mod render;
 
-use crate::model::Change;
+use std::collections::HashMap;
+
+use crate::model::{
+    Change, DEFAULT_ID_LENGTH, LogParseIssue, LogRow, Notes, Pin, RevsetPreset, TimestampFormat,
+    TrackingCounts, short_id,
+};
+use crate::ui::components::{LineInput, TextArea};
 
 // Re-export RebaseMode from model (canonical definition)
 pub use crate::model::RebaseMode;
+pub use graph::GraphStyle;
 
 /// Input mode for Log View
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -20,7 +28,7 @@ pub enum InputMode {
     SearchInput,
     /// Revset input mode (for jj filtering)
     RevsetInput,
-    /// Describe input mode (editing change description)
+    /// Describe input mode (multi-line description editor)
     DescribeInput,
     /// Bookmark input mode (creating bookmark)
     BookmarkInput,
@@ -38,8 +46,12 @@ pub enum InputMode {
     BisectSelect,
     /// Parallelize selection mode (select end of range)
     ParallelizeSelect,
+    /// Revert range selection mode (select other end of range)
+    RevertSelect,
     /// Rebase revset text input mode
     RebaseRevsetInput,
+    /// Reorder mode: K/J move the selected change past its child/parent
+    Reorder,
 }
 
 impl InputMode {
@@ -47,18 +59,20 @@ impl InputMode {
         match self {
             InputMode::SearchInput => Some(("Search: ", " / Search ")),
             InputMode::RevsetInput => Some(("Revset: ", " r Revset ")),
-            InputMode::DescribeInput => Some(("Describe: ", " d Describe ")),
             InputMode::BookmarkInput => Some(("Bookmark: ", " b Bookmark ")),
             InputMode::RebaseRevsetInput => Some(("Revset: ", " Rebase Revset ")),
-            // RebaseModeSelect/RebaseSelect/SquashSelect/CompareSelect/InterdiffSelect/ParallelizeSelect use status bar hints, not input bar
+            // DescribeInput uses the multi-line editor; the select modes use status bar hints
             InputMode::Normal
+            | InputMode::DescribeInput
             | InputMode::RebaseModeSelect
             | InputMode::RebaseSelect
             | InputMode::SquashSelect
             | InputMode::CompareSelect
             | InputMode::InterdiffSelect
             | InputMode::BisectSelect
-            | InputMode::ParallelizeSelect => None,
+            | InputMode::ParallelizeSelect
+            | InputMode::RevertSelect
+            | InputMode::Reorder => None,
         }
     }
 }
@@ -96,19 +110,35 @@ pub enum LogAction {
     Edit(String),
     /// Create a new empty change (jj new)
     NewChange,
+    /// Start the merge helper with the selected change as a candidate parent
+    StartMerge(String),
     /// Create a new change with selected revision as parent (jj new <revision>)
     NewChangeFrom {
         revision: String,
         display_name: String,
     },
+    /// Create a new change with every marked change as a parent (jj new a b c)
+    NewMergeChange {
+        parents: Vec<String>,
+        labels: Vec<String>,
+    },
     /// User pressed C on @ - show info notification suggesting 'c'
     NewChangeFromCurrent,
     /// Squash source change into destination (jj squash --from --into)
     SquashInto { source: String, destination: String },
+    /// Pick files of the source to squash into destination (jj squash --from --into -- <paths>)
+    SquashFilesInto { source: String, destination: String },
+    /// Squash @ into the selected revision (jj squash --from @ --into)
+    QuickAmend {
+        destination: String,
+        change_id: String,
+    },
     /// Abandon a change (jj abandon)
     Abandon(String),
     /// Split a change (jj split, opens external editor)
     Split(String),
+    /// Split a change by picking whole files (jj split <paths>, no editor)
+    SplitFiles(String),
     /// Create a bookmark on a change
     CreateBookmark { revision: String, name: String },
     /// Start bookmark deletion (opens selection dialog)
@@ -122,6 +152,15 @@ pub enum LogAction {
         use_revset: bool,
         simplify_parents: bool,
     },
+    /// Type the rebase destination (bookmark, change-id prefix or revset)
+    /// instead of selecting it in the log
+    PickRebaseDestination {
+        source: String,
+        mode: RebaseMode,
+        skip_emptied: bool,
+        use_revset: bool,
+        simplify_parents: bool,
+    },
     /// Absorb working copy changes into ancestor commits
     Absorb,
     /// Open resolve list view for a change
@@ -133,10 +172,21 @@ pub enum LogAction {
     Fetch,
     /// Start push flow (opens dialog if bookmarks exist)
     StartPush,
+    /// Add/remove the selected change's bookmarks in the push queue
+    TogglePushQueue(Vec<String>),
     /// Start track flow (opens dialog if untracked remotes exist)
     StartTrack,
     /// Start bookmark jump flow (opens selection dialog)
     StartBookmarkJump,
+    /// Pin or unpin a change (its first bookmark if it has one)
+    TogglePin {
+        change_id: String,
+        bookmark: Option<String>,
+    },
+    /// Jump to the pin in a slot (1-9)
+    JumpToPin(u8),
+    /// Add, edit or remove the local note on a change
+    EditNote(String),
     /// Compare two revisions (open diff --from --to)
     Compare { from: String, to: String },
     /// Entered compare mode (notification with from_id)
@@ -155,6 +205,10 @@ pub enum LogAction {
     OpenTagView,
     /// Open Workspace View
     OpenWorkspaceView,
+    /// Open Remote View
+    OpenRemoteView,
+    /// Open Stack View (`trunk()..@`)
+    OpenStackView,
     /// Open Command History View
     OpenCommandHistory,
     /// Move @ to next child (jj next --edit)
@@ -163,20 +217,54 @@ pub enum LogAction {
     PrevChange,
     /// Toggle reversed display order
     ToggleReversed,
+    /// Toggle in-place expansion of elided revisions (`connected()` revset)
+    ToggleElided,
+    /// Toggle listing hidden (abandoned/rewritten) commits next to the revset
+    ToggleHidden,
+    /// Restore a hidden commit's content into @ (commit_id)
+    RestoreHidden(String),
+    /// Toggle keeping the selection centered while scrolling
+    ToggleCenterCursor,
+    /// Toggle the commit ID column
+    ToggleCommitIds,
+    /// Switch to the next revset preset (past the last: default view)
+    CycleRevsetPreset,
+    /// Open the revset preset menu
+    OpenRevsetPresets,
+    /// Open the saved compare sessions menu
+    OpenCompareSessions,
+    /// Prompt for a read-only jj command (output opens in the Pager View)
+    StartJjCommand,
+    /// Save the raw output of a partially parsed log to a file
+    ReportParseIssue,
+    /// Open the yank (copy to clipboard) menu for the selected change
+    StartYank,
     /// Duplicate a change (jj duplicate)
     Duplicate(String),
+    /// Bring a copy of a change onto @ (jj duplicate -B @ / --onto @)
+    CherryPick(String),
     /// Open external diff editor for a change (jj diffedit)
     DiffEdit(String),
     /// Open evolution log for a change (jj evolog)
     OpenEvolog(String),
     /// Revert a change (jj revert, creates reverse-diff commit)
     Revert(String),
+    /// Revert range selection started (from change_id for notification)
+    StartRevertRange(String),
+    /// Revert every change between two ends of a range (commit_ids)
+    RevertRange { from: String, to: String },
     /// Simplify parents (remove redundant parent edges)
     SimplifyParents(String),
     /// Parallelize commits (convert linear chain to siblings)
     Parallelize { from: String, to: String },
     /// Fix (apply configured code formatters to revision and descendants)
     Fix { revision: String, change_id: String },
+    /// Entered reorder mode
+    StartReorder,
+    /// Move a change later in history, past its only child (`rebase -r <id> -A`)
+    MoveCommitUp(String),
+    /// Move a change earlier in history, before its only parent (`rebase -r <id> -B`)
+    MoveCommitDown(String),
     /// Entered parallelize mode (notification with from_id)
     StartParallelize(String),
     /// Parallelize blocked: same revision selected
@@ -207,10 +295,12 @@ pub struct LogView {
     pub selected_index: usize,
     /// Scroll offset for display
     pub scroll_offset: usize,
+    /// Rows shown by the last render (0 before the first frame)
+    pub(crate) visible_rows: usize,
     /// Current input mode
     pub input_mode: InputMode,
     /// Input buffer for revset/search/bookmark (NOT used for describe anymore)
-    pub input_buffer: String,
+    pub input_buffer: LineInput,
     /// Revset input history
     pub revset_history: Vec<String>,
     /// Current revset filter (None = default)
@@ -219,6 +309,8 @@ pub struct LogView {
     pub(crate) last_search_query: Option<String>,
     /// Revision (commit_id) being edited (for DescribeInput/BookmarkInput mode)
     pub editing_revision: Option<String>,
+    /// Description editor (for DescribeInput mode)
+    pub describe_editor: TextArea,
     /// Indices of selectable changes (not graph-only)
     selectable_indices: Vec<usize>,
     /// Current position in selectable_indices
@@ -235,16 +327,56 @@ pub struct LogView {
     pub(crate) interdiff_from: Option<(String, String)>,
     /// "From" change for parallelize (change_id, commit_id)
     pub(crate) parallelize_from: Option<(String, String)>,
+    /// First end of a revert range (change_id, commit_id)
+    pub(crate) revert_from: Option<(String, String)>,
+    /// Changes marked with Space as parents of a new merge (change_id, commit_id),
+    /// in the order they were marked
+    pub(crate) marked: Vec<(String, String)>,
     /// "Bad" revision for bisect (change_id, short_change_id)
     pub(crate) bisect_bad: Option<(String, String)>,
     /// Whether to display log in reversed order (oldest first)
     pub(crate) reversed: bool,
+    /// Whether elided revisions are expanded (wraps the revset in `connected()`)
+    pub(crate) expand_elided: bool,
+    /// Whether hidden commits from recent operations are listed (dimmed)
+    pub(crate) show_hidden: bool,
     /// Whether to pass --skip-emptied on rebase (toggled with S in RebaseSelect)
     pub(crate) skip_emptied: bool,
     /// Whether to pass --simplify-parents on rebase (toggled with P in RebaseSelect)
     pub(crate) simplify_parents: bool,
     /// Whether the log results were truncated by --limit
     pub truncated: bool,
+    /// Ahead/behind counts per local bookmark (shown next to bookmark badges)
+    pub(crate) bookmark_tracking: HashMap<String, TrackingCounts>,
+    /// Rows of context kept above/below the selection (`tij.scroll-off`)
+    pub(crate) scroll_off: usize,
+    /// Minimum change/commit ID display length (`tij.id-length`)
+    pub(crate) id_length: usize,
+    /// Keep the selection in the middle of the list (toggled with z)
+    pub(crate) center_cursor: bool,
+    /// Show each change's commit ID next to its change ID (toggled with %)
+    pub(crate) show_commit_ids: bool,
+    /// jj's `ui.graph.style`, for node markers matching the CLI
+    pub(crate) graph_style: GraphStyle,
+    /// Pinned revisions (`[tij.pins]`), shown in a strip above the log
+    pub(crate) pins: Vec<Pin>,
+    /// Local notes (`[tij.notes]`), shown as a badge on the row
+    pub(crate) notes: Notes,
+    /// Row columns and their order (`tij.log-row`)
+    pub(crate) row_layout: LogRow,
+    /// Timestamp display (`tij.timestamp-format`, `tij.timestamp-utc`)
+    pub(crate) timestamp_format: TimestampFormat,
+    /// Named revsets from `[tij.revset-presets]`, cycled with *
+    pub(crate) revset_presets: Vec<RevsetPreset>,
+    /// Set when `jj log` output could only be parsed partially (warning banner)
+    pub parse_issue: Option<LogParseIssue>,
+    /// More than one workspace has its working copy in the loaded log
+    /// (the current one is then labelled by name, not only by `@`)
+    pub(crate) multiple_workspaces: bool,
+    /// Another instance owns the workspace and this one refuses writes
+    pub(crate) read_only: bool,
+    /// Bookmarks whose git ref differs (colocated repos, title marker)
+    pub(crate) git_diverged: usize,
 }
 
 pub mod empty_text {
@@ -255,7 +387,19 @@ pub mod empty_text {
 impl LogView {
     /// Create a new LogView
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            id_length: DEFAULT_ID_LENGTH,
+            ..Self::default()
+        }
+    }
+
+    /// Changes on screen in the last render (graph-only lines skipped)
+    pub(crate) fn visible_changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .skip(self.scroll_offset)
+            .take(self.visible_rows)
+            .filter(|change| !change.is_graph_only)
     }
 
     /// Set the changes to display
@@ -271,6 +415,22 @@ impl LogView {
             .map(|(i, _)| i)
             .collect();
 
+        let mut workspaces = changes.iter().flat_map(|c| &c.working_copy_names);
+        self.multiple_workspaces = workspaces
+            .next()
+            .is_some_and(|first| workspaces.any(|name| name != first));
+
+        // Keep marks on changes that are still listed (commit IDs may have moved)
+        self.marked.retain_mut(|(change_id, commit_id)| {
+            match changes.iter().find(|c| c.change_id == *change_id) {
+                Some(change) => {
+                    *commit_id = change.commit_id.to_string();
+                    true
+                }
+                None => false,
+            }
+        });
+
         self.changes = changes;
         self.selection_cursor = 0;
         self.selected_index = self.selectable_indices.first().copied().unwrap_or(0);
@@ -311,6 +471,24 @@ impl LogView {
         }
     }
 
+    /// Preset whose revset is the active filter, if any
+    pub(crate) fn active_preset(&self) -> Option<&RevsetPreset> {
+        let revset = self.current_revset.as_deref()?;
+        self.revset_presets.iter().find(|p| p.revset == revset)
+    }
+
+    /// Preset after the active one; `None` past the last (back to the default view)
+    pub(crate) fn next_preset(&self) -> Option<&RevsetPreset> {
+        match self.active_preset() {
+            Some(active) => self
+                .revset_presets
+                .iter()
+                .position(|p| p == active)
+                .and_then(|i| self.revset_presets.get(i + 1)),
+            None => self.revset_presets.first(),
+        }
+    }
+
     /// Start text search input mode
     pub fn start_search_input(&mut self) {
         self.input_mode = InputMode::SearchInput;
@@ -327,16 +505,16 @@ impl LogView {
     pub fn cancel_input(&mut self) {
         self.input_mode = InputMode::Normal;
         self.input_buffer.clear();
+        self.describe_editor = TextArea::default();
         self.editing_revision = None;
     }
 
-    /// Set describe input mode with the description text (single-line only)
+    /// Set describe input mode with the full description in the editor
     ///
-    /// Called by App after verifying the description is single-line.
-    /// Multi-line descriptions are blocked at the App layer (directed to Ctrl+E).
+    /// Called by App after fetching the description from jj.
     pub fn set_describe_input(&mut self, revision: String, description: String) {
         self.editing_revision = Some(revision);
-        self.input_buffer = description;
+        self.describe_editor = TextArea::new(&description);
         self.input_mode = InputMode::DescribeInput;
     }
 
@@ -493,9 +671,12 @@ impl LogView {
     /// The user then selects the "good" revision.
     /// Returns true if mode was entered, false if no change is selected.
     pub fn start_bisect_select(&mut self) -> bool {
-        let source = self
-            .selected_change()
-            .map(|c| (c.change_id.to_string(), c.change_id.short().to_string()));
+        let source = self.selected_change().map(|c| {
+            (
+                c.change_id.to_string(),
+                c.change_id.short(self.id_length).to_string(),
+            )
+        });
 
         if let Some(pair) = source {
             self.bisect_bad = Some(pair);
@@ -537,10 +718,72 @@ impl LogView {
         self.input_mode = InputMode::Normal;
     }
 
+    /// Start revert range selection mode
+    ///
+    /// The currently selected change becomes one end of the range.
+    /// Returns true if mode was entered, false if no change is selected.
+    pub fn start_revert_select(&mut self) -> bool {
+        let Some(change) = self.selected_change() else {
+            return false;
+        };
+        self.revert_from = Some((change.change_id.to_string(), change.commit_id.to_string()));
+        self.input_mode = InputMode::RevertSelect;
+        true
+    }
+
+    /// Cancel revert range selection mode
+    pub fn cancel_revert_select(&mut self) {
+        self.revert_from = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Mark or unmark the selected change as a parent for a new merge change
+    pub fn toggle_mark(&mut self) {
+        let Some(change) = self.selected_change() else {
+            return;
+        };
+        let change_id = change.change_id.to_string();
+        let commit_id = change.commit_id.to_string();
+        if let Some(pos) = self.marked.iter().position(|(cid, _)| *cid == change_id) {
+            self.marked.remove(pos);
+        } else {
+            self.marked.push((change_id, commit_id));
+        }
+    }
+
+    /// Position of a change in the mark order (1-based), if marked
+    pub(crate) fn mark_number(&self, change_id: &str) -> Option<usize> {
+        self.marked
+            .iter()
+            .position(|(cid, _)| cid == change_id)
+            .map(|pos| pos + 1)
+    }
+
+    /// Parents (commit IDs) and display names of the marked changes,
+    /// or None when fewer than two are marked
+    fn marked_parents(&self) -> Option<(Vec<String>, Vec<String>)> {
+        if self.marked.len() < 2 {
+            return None;
+        }
+        let labels = self
+            .marked
+            .iter()
+            .map(|(change_id, _)| {
+                self.changes
+                    .iter()
+                    .find(|c| c.change_id == *change_id)
+                    .and_then(|c| c.bookmarks.first().cloned())
+                    .unwrap_or_else(|| short_id(change_id, self.id_length).to_string())
+            })
+            .collect();
+        let parents = self.marked.iter().map(|(_, cid)| cid.clone()).collect();
+        Some((parents, labels))
+    }
+
     /// Select a change by its change_id (exact match)
     ///
     /// Returns true if the change was found and selected, false otherwise.
-    /// The scroll_offset will be updated during next render via calculate_scroll_offset().
+    /// The scroll_offset will be updated during next render via scroll_offset_for().
     pub fn select_change_by_id(&mut self, change_id: &str) -> bool {
         // Find the change in the selectable indices
         for (cursor, &idx) in self.selectable_indices.iter().enumerate() {
@@ -581,7 +824,7 @@ impl LogView {
     /// Select a change by prefix match on change_id
     ///
     /// Used when the caller has a potentially shorter change_id (e.g., from
-    /// `jj file annotate` which uses `shortest()` format instead of `short(8)`).
+    /// `jj duplicate` output, which uses jj's default ID format).
     pub fn select_change_by_prefix(&mut self, prefix: &str) -> bool {
         for (cursor, &idx) in self.selectable_indices.iter().enumerate() {
             if let Some(change) = self.changes.get(idx)
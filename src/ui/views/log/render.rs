@@ -8,15 +8,79 @@ use ratatui::{
     widgets::Paragraph,
 };
 
-use crate::jj::constants;
-use crate::model::{Change, Notification};
+use crate::model::{Change, LogColumn, Notification, TimestampStyle, format_timestamp};
 use crate::ui::{components, symbols, theme};
 
-use super::{InputMode, LogView, RebaseMode, RebaseSource, empty_text};
+use super::{InputMode, LogView, RebaseMode, RebaseSource, empty_text, graph};
+
+/// Height of the describe editor pane (including borders)
+const DESCRIBE_EDITOR_HEIGHT: u16 = 8;
+
+/// First visible row so that `selected` stays on screen
+///
+/// Scrolls only when the selection comes within `margin` rows of an edge
+/// (the margin shrinks on short screens), or keeps it in the middle when
+/// `center` is set. Never scrolls past the last row.
+fn scroll_offset_for(
+    current: usize,
+    selected: usize,
+    total: usize,
+    height: usize,
+    margin: usize,
+    center: bool,
+) -> usize {
+    if height == 0 {
+        return 0;
+    }
+    let max_offset = total.saturating_sub(height);
+    let offset = if center {
+        selected.saturating_sub(height / 2)
+    } else {
+        let margin = margin.min((height - 1) / 2);
+        if selected < current + margin {
+            selected.saturating_sub(margin)
+        } else if selected + margin >= current + height {
+            selected + margin + 1 - height
+        } else {
+            current
+        }
+    };
+    offset.min(max_offset)
+}
+
+/// Longest pin label in the strip (change IDs are 32 characters)
+const PIN_LABEL_WIDTH: usize = 16;
+
+fn truncate_pin(revision: &str) -> String {
+    if revision.chars().count() <= PIN_LABEL_WIDTH {
+        revision.to_string()
+    } else {
+        let head: String = revision.chars().take(PIN_LABEL_WIDTH - 1).collect();
+        format!("{}…", head)
+    }
+}
 
 impl LogView {
     /// Render the view with optional notification in title bar
     pub fn render(&mut self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        // Partial parse: warning banner above the rows that did parse
+        let area = if self.parse_issue.is_some() && area.height > 3 {
+            let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(area);
+            frame.render_widget(Paragraph::new(self.build_parse_issue_banner()), chunks[0]);
+            chunks[1]
+        } else {
+            area
+        };
+
+        // Favorites strip above the list
+        let area = if !self.pins.is_empty() && area.height > 3 {
+            let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(area);
+            frame.render_widget(Paragraph::new(self.build_pin_strip()), chunks[0]);
+            chunks[1]
+        } else {
+            area
+        };
+
         // Split area for input bar if in input modes
         let (log_area, input_area) = match self.input_mode {
             InputMode::Normal
@@ -26,10 +90,21 @@ impl LogView {
             | InputMode::CompareSelect
             | InputMode::InterdiffSelect
             | InputMode::BisectSelect
-            | InputMode::ParallelizeSelect => (area, None),
+            | InputMode::ParallelizeSelect
+            | InputMode::RevertSelect
+            | InputMode::Reorder => (area, None),
+            InputMode::DescribeInput => {
+                let chunks = Layout::vertical([
+                    Constraint::Min(1),
+                    Constraint::Length(DESCRIBE_EDITOR_HEIGHT),
+                ])
+                .split(area);
+                self.describe_editor.render(frame, chunks[1], "d Describe");
+                self.render_log_list(frame, chunks[0], notification);
+                return;
+            }
             InputMode::SearchInput
             | InputMode::RevsetInput
-            | InputMode::DescribeInput
             | InputMode::BookmarkInput
             | InputMode::RebaseRevsetInput => {
                 let chunks =
@@ -46,7 +121,12 @@ impl LogView {
         }
     }
 
-    fn render_log_list(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+    fn render_log_list(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        notification: Option<&Notification>,
+    ) {
         let title = self.build_title();
 
         // Build notification line for title bar (with truncation if needed)
@@ -65,14 +145,23 @@ impl LogView {
         }
 
         let inner_height = area.height.saturating_sub(2) as usize; // borders
+        self.visible_rows = inner_height;
         if inner_height == 0 {
             return;
         }
 
-        // Calculate scroll offset to keep selection visible
-        let scroll_offset = self.calculate_scroll_offset(inner_height);
+        // Keep selection visible (with scroll-off margin), remembered across frames
+        let scroll_offset = scroll_offset_for(
+            self.scroll_offset,
+            self.selected_index,
+            self.changes.len(),
+            inner_height,
+            self.scroll_off,
+            self.center_cursor,
+        );
+        self.scroll_offset = scroll_offset;
 
-        // Build lines - each change is one line (graph prefix from jj)
+        // Build lines - each change is one line (graph prefix parsed from jj)
         let mut lines: Vec<Line> = Vec::new();
         for (idx, change) in self.changes.iter().enumerate().skip(scroll_offset) {
             if lines.len() >= inner_height {
@@ -213,6 +302,13 @@ impl LogView {
             .centered();
         }
 
+        if self.input_mode == InputMode::Reorder {
+            return Line::from(" Tij - Log View [Reorder: K/J to move, Esc to finish] ")
+                .bold()
+                .yellow()
+                .centered();
+        }
+
         // Special title for ParallelizeSelect mode
         if self.input_mode == InputMode::ParallelizeSelect {
             let from_id = self
@@ -229,6 +325,21 @@ impl LogView {
             .centered();
         }
 
+        if self.input_mode == InputMode::RevertSelect {
+            let from_id = self
+                .revert_from
+                .as_ref()
+                .map(|(cid, _)| cid.as_str())
+                .unwrap_or("?");
+            return Line::from(format!(
+                " Tij - Log View [Revert: From={}, Select other end] ",
+                from_id
+            ))
+            .bold()
+            .yellow()
+            .centered();
+        }
+
         // Build count suffix for revset queries and truncated default view
         let count_suffix = if self.current_revset.is_some() {
             let count = self.changes.iter().filter(|c| !c.is_graph_only).count();
@@ -244,7 +355,16 @@ impl LogView {
             String::new()
         };
 
-        let title_text = match (&self.current_revset, &self.last_search_query) {
+        // Name the preset when the filter came from one
+        let revset_label = self
+            .current_revset
+            .as_ref()
+            .map(|revset| match self.active_preset() {
+                Some(preset) => format!("{}: {}", preset.name, revset),
+                None => revset.clone(),
+            });
+
+        let title_text = match (&revset_label, &self.last_search_query) {
             (Some(revset), Some(query)) => {
                 format!(
                     " Tij - Log View [{}{}] [Search: {}] ",
@@ -265,9 +385,77 @@ impl LogView {
                 }
             }
         };
+        let title_text = if self.show_hidden {
+            format!("{}[+hidden] ", title_text)
+        } else {
+            title_text
+        };
+        let title_text = if self.marked.is_empty() {
+            title_text
+        } else {
+            format!("{}[{} marked] ", title_text, self.marked.len())
+        };
+        let title_text = if self.git_diverged > 0 {
+            format!("{}[git≠jj: {}] ", title_text, self.git_diverged)
+        } else {
+            title_text
+        };
+        if self.read_only {
+            return Line::from(format!("{}[read-only] ", title_text))
+                .bold()
+                .yellow()
+                .centered();
+        }
         Line::from(title_text).bold().cyan().centered()
     }
 
+    /// ` 1 release-1.x  2 main ` with the slot number as the jump key
+    ///
+    /// Change IDs are stored in full and shown shortened.
+    fn build_pin_strip(&self) -> Line<'static> {
+        let mut spans = vec![Span::styled(
+            " Pins ",
+            Style::default().fg(Color::Black).bg(Color::Cyan),
+        )];
+        for pin in &self.pins {
+            spans.push(Span::styled(
+                format!(" {} ", pin.slot),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                truncate_pin(pin.label(self.id_length)),
+                Style::default().fg(theme::log_view::BOOKMARK),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// One-line warning shown while the log is only partially parsed
+    fn build_parse_issue_banner(&self) -> Line<'static> {
+        let Some(ref issue) = self.parse_issue else {
+            return Line::default();
+        };
+        let shown = self.changes.iter().filter(|c| !c.is_graph_only).count();
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    " ⚠ jj log output unparsable at line {} ({}); showing {} changes. ",
+                    issue.line_number, issue.message, shown
+                ),
+                Style::default().fg(Color::Black).bg(Color::Yellow),
+            ),
+            Span::styled(
+                " ! save report ",
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ])
+    }
+
     fn render_empty_state(
         &self,
         frame: &mut Frame,
@@ -279,33 +467,127 @@ impl LogView {
 
         frame.render_widget(paragraph, area);
     }
+    /// Spans of one `tij.log-row` column (nothing when it has no value)
+    fn push_column(&self, spans: &mut Vec<Span<'static>>, change: &Change, column: LogColumn) {
+        let is_root = change.change_id.is_root();
+        match column {
+            LogColumn::ChangeId(_) => {
+                let id = change
+                    .extras
+                    .change_id
+                    .clone()
+                    .unwrap_or_else(|| change.short_id().to_string());
+                spans.push(Span::styled(
+                    format!("{} ", id),
+                    Style::default().fg(theme::log_view::CHANGE_ID),
+                ));
+            }
+            // Commit ID column (shortest unique prefix, as loaded)
+            LogColumn::CommitId => {
+                if self.show_commit_ids {
+                    spans.push(Span::styled(
+                        format!("{} ", change.commit_id),
+                        Style::default().fg(theme::log_view::COMMIT_ID),
+                    ));
+                }
+            }
+            LogColumn::Author if !is_root => {
+                spans.push(Span::raw(format!("{} ", change.author)));
+            }
+            LogColumn::Timestamp(style) if !is_root => {
+                let timestamp = match (style, &change.extras.relative_time) {
+                    (TimestampStyle::Relative, Some(ago)) => ago.clone(),
+                    _ => format_timestamp(&change.timestamp, &self.timestamp_format),
+                };
+                spans.push(Span::styled(
+                    format!("{} ", timestamp),
+                    Style::default().fg(theme::log_view::TIMESTAMP),
+                ));
+            }
+            // Bookmarks (with ↑/↓ when the tracked remote has diverged)
+            LogColumn::Bookmarks if !change.bookmarks.is_empty() => {
+                let bookmark_style = Style::default().fg(theme::log_view::BOOKMARK);
+                for (i, name) in change.bookmarks.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { ", " };
+                    spans.push(Span::styled(
+                        format!("{}{}", separator, name),
+                        bookmark_style,
+                    ));
+                    if let Some(counts) = self.bookmark_tracking.get(name)
+                        && !counts.is_synced()
+                    {
+                        spans.push(Span::styled(
+                            format!(" {}", counts.indicator()),
+                            Style::default().fg(theme::log_view::BOOKMARK_TRACKING),
+                        ));
+                    }
+                }
+                spans.push(Span::raw(" "));
+            }
+            LogColumn::DiffStats => {
+                if let Some((added, removed)) = change.extras.diff_stat {
+                    spans.push(Span::styled(
+                        format!("+{}", added),
+                        Style::default().fg(theme::log_view::STAT_ADDED),
+                    ));
+                    spans.push(Span::styled(
+                        format!(" -{} ", removed),
+                        Style::default().fg(theme::log_view::STAT_REMOVED),
+                    ));
+                }
+            }
+            LogColumn::Description => {
+                let description = change.display_description();
+                if change.is_empty && description == symbols::empty::NO_DESCRIPTION {
+                    spans.push(Span::styled(
+                        format!("{} ", symbols::empty::CHANGE_LABEL),
+                        Style::default().fg(theme::log_view::EMPTY_LABEL),
+                    ));
+                }
+                spans.push(Span::raw(description.to_string()));
+            }
+            LogColumn::Author | LogColumn::Timestamp(_) | LogColumn::Bookmarks => {}
+        }
+    }
 
-    fn calculate_scroll_offset(&self, visible_changes: usize) -> usize {
-        if visible_changes == 0 {
-            return 0;
+    /// Workspace, conflict, hidden and note markers (before the description)
+    fn push_markers(&self, spans: &mut Vec<Span<'static>>, change: &Change) {
+        // Workspace markers (`name@` on each workspace's working copy)
+        if let Some(marker) = workspace_marker(change, self.multiple_workspaces) {
+            spans.push(marker);
         }
 
-        let mut offset = self.scroll_offset;
+        // Conflict indicator
+        if change.has_conflict {
+            spans.push(Span::styled(
+                "[CONFLICT] ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
 
-        // Ensure selected item is visible
-        if self.selected_index < offset {
-            offset = self.selected_index;
-        } else if self.selected_index >= offset + visible_changes {
-            offset = self.selected_index - visible_changes + 1;
+        // Hidden (abandoned/rewritten) commit, listed by the `h` toggle
+        if change.is_hidden {
+            spans.push(Span::styled(
+                "[hidden] ",
+                Style::default().fg(theme::log_view::HIDDEN),
+            ));
         }
 
-        offset
+        // Local note (`;`)
+        if self.notes.get(change.change_id.as_str()).is_some() {
+            spans.push(Span::styled(
+                "[note] ",
+                Style::default().fg(theme::log_view::NOTE),
+            ));
+        }
     }
 
     fn build_change_line(&self, change: &Change, is_selected: bool) -> Line<'static> {
         let mut spans = Vec::new();
 
-        // Graph prefix (from jj output)
+        // Graph prefix (parsed into lanes for per-branch coloring)
         if !change.graph_prefix.is_empty() {
-            spans.push(Span::styled(
-                change.graph_prefix.clone(),
-                Style::default().fg(theme::log_view::GRAPH_LINE),
-            ));
+            spans.extend(graph::graph_spans(change, self.graph_style));
         }
 
         // For graph-only lines, just return the prefix
@@ -313,64 +595,33 @@ impl LogView {
             return Line::from(spans);
         }
 
-        // Change ID
-        spans.push(Span::styled(
-            format!("{} ", change.short_id()),
-            Style::default().fg(theme::log_view::CHANGE_ID),
-        ));
-
-        // Author (if not root)
-        if change.change_id != constants::ROOT_CHANGE_ID {
-            spans.push(Span::raw(format!("{} ", change.author)));
-            spans.push(Span::styled(
-                format!("{} ", change.timestamp),
-                Style::default().fg(theme::log_view::TIMESTAMP),
-            ));
-        }
-
-        // Bookmarks
-        if !change.bookmarks.is_empty() {
+        // Merge parent mark (Space), numbered in parent order
+        if let Some(number) = self.mark_number(change.change_id.as_str()) {
             spans.push(Span::styled(
-                format!("{} ", change.bookmarks.join(", ")),
-                Style::default().fg(theme::log_view::BOOKMARK),
+                format!("[{}] ", number),
+                Style::default()
+                    .fg(theme::log_view::MARK)
+                    .add_modifier(Modifier::BOLD),
             ));
         }
 
-        // Workspace markers (other workspaces' working copies)
-        // Skip if this is the current WC and only 1 workspace name (already shown as @ in graph)
-        let show_ws_markers = !(change.working_copy_names.is_empty()
-            || change.is_working_copy && change.working_copy_names.len() == 1);
-        if show_ws_markers {
-            let marker = change
-                .working_copy_names
-                .iter()
-                .map(|name| format!("{}@", name))
-                .collect::<Vec<_>>()
-                .join(" ");
-            spans.push(Span::styled(
-                format!("{} ", marker),
-                Style::default().fg(Color::Magenta),
-            ));
+        let columns = self.row_layout.columns();
+        let has_description = columns.contains(&LogColumn::Description);
+        for &column in columns {
+            if column == LogColumn::Description {
+                self.push_markers(&mut spans, change);
+            }
+            self.push_column(&mut spans, change, column);
+            // The `%` toggle shows the commit ID after the change ID when
+            // the layout doesn't place it
+            if matches!(column, LogColumn::ChangeId(_)) && !self.row_layout.has_commit_id() {
+                self.push_column(&mut spans, change, LogColumn::CommitId);
+            }
         }
-
-        // Conflict indicator
-        if change.has_conflict {
-            spans.push(Span::styled(
-                "[CONFLICT] ",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ));
+        if !has_description {
+            self.push_markers(&mut spans, change);
         }
 
-        // Description
-        let description = change.display_description();
-        if change.is_empty && description == symbols::empty::NO_DESCRIPTION {
-            spans.push(Span::styled(
-                format!("{} ", symbols::empty::CHANGE_LABEL),
-                Style::default().fg(theme::log_view::EMPTY_LABEL),
-            ));
-        }
-        spans.push(Span::raw(description.to_string()));
-
         let mut line = Line::from(spans);
 
         // Check if this is the rebase source (in RebaseModeSelect, RebaseSelect, or RebaseRevsetInput mode)
@@ -417,6 +668,12 @@ impl LogView {
                 .as_ref()
                 .is_some_and(|(cid, _)| *cid == change.change_id);
 
+        let is_revert_from = self.input_mode == InputMode::RevertSelect
+            && self
+                .revert_from
+                .as_ref()
+                .is_some_and(|(cid, _)| *cid == change.change_id);
+
         // Apply styling
         if is_rebase_source
             || is_squash_source
@@ -424,6 +681,7 @@ impl LogView {
             || is_interdiff_from
             || is_bisect_bad
             || is_parallelize_from
+            || is_revert_from
         {
             // Highlight rebase/squash source with distinct background
             line = line.style(
@@ -439,6 +697,8 @@ impl LogView {
                     .bg(theme::selection::BG)
                     .add_modifier(Modifier::BOLD),
             );
+        } else if change.is_hidden {
+            line = line.style(Style::default().add_modifier(Modifier::DIM));
         }
 
         line
@@ -449,8 +709,6 @@ impl LogView {
             return;
         };
 
-        let input_text = format!("{}{}", prompt, self.input_buffer);
-
         // Calculate available width (area width minus borders)
         let available_width = area.width.saturating_sub(2) as usize;
 
@@ -459,31 +717,49 @@ impl LogView {
             return;
         }
 
-        // Truncate display text if too long (show end of input, UTF-8 safe)
-        let char_count = input_text.chars().count();
-        let display_text = if char_count > available_width {
-            let skip = char_count.saturating_sub(available_width.saturating_sub(1)); // -1 for ellipsis
-            format!("…{}", input_text.chars().skip(skip).collect::<String>())
-        } else {
-            input_text.clone()
-        };
+        // Scrolled so the cursor stays visible (UTF-8 safe)
+        let (display_text, cursor_pos) = self.input_buffer.display(prompt, available_width);
 
         let paragraph =
             Paragraph::new(display_text).block(components::bordered_block(Line::from(title)));
 
         frame.render_widget(paragraph, area);
 
-        // Show cursor (clamped to available width, character-based)
-        let cursor_pos = char_count.min(available_width);
         frame.set_cursor_position((area.x + cursor_pos as u16 + 1, area.y + 1));
     }
 }
 
+/// `name@` labels for the workspaces whose working copy is `change`
+///
+/// With a single workspace the `@` graph marker says it all, so nothing is
+/// shown for it; once several workspaces are in the log every working copy is
+/// labelled, the current one in the working-copy color.
+fn workspace_marker(change: &Change, multiple_workspaces: bool) -> Option<Span<'static>> {
+    if change.working_copy_names.is_empty() || (change.is_working_copy && !multiple_workspaces) {
+        return None;
+    }
+    let marker = change
+        .working_copy_names
+        .iter()
+        .map(|name| format!("{}@", name))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let style = if change.is_working_copy {
+        Style::default()
+            .fg(theme::log_view::WORKING_COPY_MARKER)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme::log_view::WORKSPACE_MARKER)
+    };
+    Some(Span::styled(format!("{} ", marker), style))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::LogView;
+    use super::{LogView, scroll_offset_for, workspace_marker};
     use crate::jj::constants;
-    use crate::model::{Change, ChangeId, CommitId};
+    use crate::model::{Change, ChangeId, CommitId, LogParseIssue, LogRow, LogRowExtras};
+    use crate::ui::theme;
 
     fn create_selectable_changes(count: usize) -> Vec<Change> {
         (0..count)
@@ -504,6 +780,10 @@ mod tests {
                 is_graph_only: false,
                 has_conflict: false,
                 working_copy_names: Vec::new(),
+                is_immutable: false,
+                is_divergent: false,
+                is_hidden: false,
+                extras: Default::default(),
             })
             .collect()
     }
@@ -516,6 +796,55 @@ mod tests {
         text
     }
 
+    #[test]
+    fn test_parse_issue_banner_reports_line_and_count() {
+        let mut view = LogView::new();
+        view.set_changes(create_selectable_changes(3));
+        assert!(view.build_parse_issue_banner().spans.is_empty());
+
+        view.parse_issue = Some(LogParseIssue {
+            line_number: 7,
+            line: "○  xyz\tbroken".to_string(),
+            message: "Expected at least 6 fields after change_id, got 1".to_string(),
+            raw_output: String::new(),
+        });
+        let text: String = view
+            .build_parse_issue_banner()
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert!(text.contains("line 7"));
+        assert!(text.contains("showing 3 changes"));
+        assert!(text.contains("! save report"));
+    }
+
+    fn workspace_change(names: &[&str], is_working_copy: bool) -> Change {
+        Change {
+            is_working_copy,
+            working_copy_names: names.iter().map(|n| n.to_string()).collect(),
+            ..Change::default()
+        }
+    }
+
+    #[test]
+    fn test_workspace_marker_hidden_for_single_workspace() {
+        assert!(workspace_marker(&workspace_change(&["default"], true), false).is_none());
+        assert!(workspace_marker(&workspace_change(&[], false), true).is_none());
+    }
+
+    #[test]
+    fn test_workspace_marker_labels_every_workspace() {
+        let current = workspace_marker(&workspace_change(&["default"], true), true).unwrap();
+        assert_eq!(current.content, "default@ ");
+        assert_eq!(current.style.fg, Some(theme::log_view::WORKING_COPY_MARKER));
+
+        let other =
+            workspace_marker(&workspace_change(&["feature-ws", "docs"], false), true).unwrap();
+        assert_eq!(other.content, "feature-ws@ docs@ ");
+        assert_eq!(other.style.fg, Some(theme::log_view::WORKSPACE_MARKER));
+    }
+
     #[test]
     fn test_build_title_includes_revset_count() {
         let mut view = LogView::new();
@@ -525,6 +854,100 @@ mod tests {
         assert_eq!(title_text(&view), " Tij - Log View [ancestors(@, 5) (5)] ");
     }
 
+    #[test]
+    fn test_build_title_marks_read_only_mode() {
+        let mut view = LogView::new();
+        view.read_only = true;
+        assert_eq!(title_text(&view), " Tij - Log View [read-only] ");
+    }
+
+    #[test]
+    fn test_git_divergence_title_marker() {
+        let mut view = LogView::new();
+        view.git_diverged = 2;
+        assert_eq!(title_text(&view), " Tij - Log View [git≠jj: 2] ");
+    }
+
+    #[test]
+    fn test_hidden_commits_get_badge_and_title_marker() {
+        let mut view = LogView::new();
+        let mut changes = create_selectable_changes(2);
+        changes[1].is_hidden = true;
+        view.set_changes(changes);
+        view.show_hidden = true;
+        assert!(title_text(&view).contains("[+hidden]"));
+
+        let line = view.build_change_line(&view.changes[1], false);
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("[hidden] Commit 1"));
+        assert!(
+            line.style
+                .add_modifier
+                .contains(ratatui::style::Modifier::DIM)
+        );
+    }
+
+    #[test]
+    fn test_commit_id_column_toggle() {
+        let mut view = LogView::new();
+        view.set_changes(create_selectable_changes(2));
+        let text = |view: &LogView| -> String {
+            let line = view.build_change_line(&view.changes[1], false);
+            line.spans.iter().map(|s| s.content.as_ref()).collect()
+        };
+        assert!(!text(&view).contains("commit00001"));
+
+        view.show_commit_ids = true;
+        assert!(text(&view).contains("chg00001 commit00001 user@example.com"));
+    }
+
+    #[test]
+    fn test_row_layout_orders_columns() {
+        let mut view = LogView::new();
+        let mut changes = create_selectable_changes(2);
+        changes[1].bookmarks = vec!["main".to_string()];
+        changes[1].extras = LogRowExtras {
+            change_id: Some("chg00001xyz".to_string()),
+            relative_time: Some("2 hours ago".to_string()),
+            diff_stat: Some((12, 3)),
+        };
+        view.set_changes(changes);
+        view.row_layout =
+            LogRow::parse("change_id:11 bookmarks diff_stats timestamp:relative description")
+                .unwrap();
+        let line = view.build_change_line(&view.changes[1], false);
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.ends_with("chg00001xyz main +12 -3 2 hours ago Commit 1"));
+
+        // `%` still adds the commit ID after the change ID
+        view.show_commit_ids = true;
+        let line = view.build_change_line(&view.changes[1], false);
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.contains("chg00001xyz commit00001 main"));
+    }
+
+    #[test]
+    fn test_pin_strip_lists_slots() {
+        let mut view = LogView::new();
+        view.pins = vec![
+            crate::model::Pin {
+                slot: 1,
+                revision: "release-1.x".to_string(),
+            },
+            crate::model::Pin {
+                slot: 3,
+                revision: "kxqyzprtwmnvlsookxqyzprtwmnvlsoo".to_string(),
+            },
+        ];
+        let text: String = view
+            .build_pin_strip()
+            .spans
+            .iter()
+            .map(|s| s.content.as_ref())
+            .collect();
+        assert_eq!(text, " Pins  1 release-1.x 3 kxqyzprt");
+    }
+
     #[test]
     fn test_build_title_includes_truncated_indicator_for_revset() {
         let mut view = LogView::new();
@@ -548,4 +971,36 @@ mod tests {
 
         assert_eq!(title_text(&view), format!(" Tij - Log View ({}+) ", limit));
     }
+
+    #[test]
+    fn test_scroll_offset_without_margin_scrolls_at_edges() {
+        // 100 rows, 10 visible
+        assert_eq!(scroll_offset_for(0, 9, 100, 10, 0, false), 0);
+        assert_eq!(scroll_offset_for(0, 10, 100, 10, 0, false), 1);
+        assert_eq!(scroll_offset_for(20, 19, 100, 10, 0, false), 19);
+        // Moving inside the window keeps the offset
+        assert_eq!(scroll_offset_for(20, 25, 100, 10, 0, false), 20);
+    }
+
+    #[test]
+    fn test_scroll_offset_keeps_margin() {
+        assert_eq!(scroll_offset_for(0, 7, 100, 10, 3, false), 1);
+        assert_eq!(scroll_offset_for(20, 22, 100, 10, 3, false), 19);
+        // Top and bottom of the list can still be reached
+        assert_eq!(scroll_offset_for(5, 0, 100, 10, 3, false), 0);
+        assert_eq!(scroll_offset_for(85, 99, 100, 10, 3, false), 90);
+    }
+
+    #[test]
+    fn test_scroll_offset_margin_clamped_on_short_screen() {
+        // Margin larger than half the screen behaves like centering
+        assert_eq!(scroll_offset_for(0, 10, 100, 5, 10, false), 8);
+    }
+
+    #[test]
+    fn test_scroll_offset_centered() {
+        assert_eq!(scroll_offset_for(0, 50, 100, 10, 0, true), 45);
+        assert_eq!(scroll_offset_for(0, 2, 100, 10, 0, true), 0);
+        assert_eq!(scroll_offset_for(0, 98, 100, 10, 0, true), 90);
+    }
 }
@@ -1,11 +1,11 @@
 //! Tests for LogView
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::{InputMode, LogAction, LogView, RebaseMode, RebaseSource};
 use crate::jj::constants;
 use crate::keys;
-use crate::model::{Change, ChangeId, CommitId};
+use crate::model::{Change, ChangeId, CommitId, LogParseIssue, RevsetPreset};
 
 fn create_test_changes() -> Vec<Change> {
     vec![
@@ -22,6 +22,10 @@ fn create_test_changes() -> Vec<Change> {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
         Change {
             change_id: ChangeId::new("xyz98765".to_string()),
@@ -36,6 +40,10 @@ fn create_test_changes() -> Vec<Change> {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
         Change {
             change_id: ChangeId::new(constants::ROOT_CHANGE_ID.to_string()),
@@ -50,6 +58,10 @@ fn create_test_changes() -> Vec<Change> {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
     ]
 }
@@ -68,6 +80,10 @@ fn submit(view: &mut LogView) -> LogAction {
     press_key(view, keys::SUBMIT)
 }
 
+fn save_editor(view: &mut LogView) -> LogAction {
+    view.handle_key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+}
+
 fn escape(view: &mut LogView) -> LogAction {
     press_key(view, keys::ESC)
 }
@@ -88,6 +104,19 @@ fn test_set_changes() {
     assert_eq!(view.changes.len(), 3);
 }
 
+#[test]
+fn test_set_changes_detects_multiple_workspaces() {
+    let mut view = LogView::new();
+    let mut changes = create_test_changes();
+    changes[0].working_copy_names = vec!["default".to_string()];
+    view.set_changes(changes.clone());
+    assert!(!view.multiple_workspaces);
+
+    changes[1].working_copy_names = vec!["feature-ws".to_string()];
+    view.set_changes(changes);
+    assert!(view.multiple_workspaces);
+}
+
 #[test]
 fn test_navigation() {
     let mut view = LogView::new();
@@ -248,6 +277,23 @@ fn test_handle_key_backspace() {
     assert_eq!(view.input_buffer, "a");
 }
 
+#[test]
+fn test_revset_input_readline_editing() {
+    let mut view = LogView::new();
+    view.start_revset_input();
+    type_text(&mut view, "mine() & main");
+
+    view.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+    assert_eq!(view.input_buffer, "mine() & ");
+    view.handle_key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    type_text(&mut view, "~");
+    assert_eq!(view.input_buffer, "~mine() & ");
+
+    view.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+    assert_eq!(view.input_buffer, "mine() & ");
+    assert_eq!(view.input_mode, InputMode::RevsetInput);
+}
+
 #[test]
 fn test_set_changes_resets_selection() {
     let mut view = LogView::new();
@@ -509,6 +555,64 @@ fn test_squash_select_confirm() {
     assert_eq!(view.input_mode, InputMode::Normal);
 }
 
+#[test]
+fn test_pin_key_uses_first_bookmark() {
+    let mut view = LogView::new();
+    let mut changes = create_test_changes();
+    changes[0].bookmarks = vec!["main".to_string(), "epic".to_string()];
+    view.set_changes(changes);
+
+    let action = press_key(&mut view, keys::PIN);
+    assert_eq!(
+        action,
+        LogAction::TogglePin {
+            change_id: "abc12345".to_string(),
+            bookmark: Some("main".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_note_key_edits_selected_change() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let action = press_key(&mut view, keys::NOTE);
+    assert_eq!(action, LogAction::EditNote("abc12345".to_string()));
+}
+
+#[test]
+fn test_digit_keys_jump_to_pins() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    assert_eq!(
+        press_key(&mut view, KeyCode::Char('1')),
+        LogAction::JumpToPin(1)
+    );
+    assert_eq!(
+        press_key(&mut view, KeyCode::Char('9')),
+        LogAction::JumpToPin(9)
+    );
+    assert_eq!(press_key(&mut view, KeyCode::Char('0')), LogAction::None);
+}
+
+#[test]
+fn test_squash_select_pick_files() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    press_key(&mut view, keys::SQUASH);
+    press_key(&mut view, keys::MOVE_DOWN);
+
+    let action = press_key(&mut view, KeyCode::Char('f'));
+    assert!(matches!(
+        action,
+        LogAction::SquashFilesInto { source, destination }
+        if source == "def67890" && destination == "uvw43210"
+    ));
+    assert_eq!(view.input_mode, InputMode::Normal);
+}
+
 #[test]
 fn test_squash_into_same_revision_blocked() {
     let mut view = LogView::new();
@@ -640,6 +744,15 @@ fn test_handle_key_split() {
     assert_eq!(action, LogAction::Split("def67890".to_string()));
 }
 
+#[test]
+fn test_handle_key_split_files() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let action = view.handle_key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL));
+    assert_eq!(action, LogAction::SplitFiles("def67890".to_string()));
+}
+
 #[test]
 fn test_handle_key_split_no_selection() {
     let mut view = LogView::new();
@@ -1094,17 +1207,17 @@ fn test_describe_key_returns_start_describe_action() {
 }
 
 #[test]
-fn test_set_describe_input_prefills_first_line() {
+fn test_set_describe_input_prefills_description() {
     let mut view = LogView::new();
     view.set_changes(create_test_changes());
 
-    // Simulate App calling set_describe_input with single-line description
-    view.set_describe_input("abc12345".to_string(), "First commit".to_string());
+    // Simulate App calling set_describe_input with a multi-line description
+    view.set_describe_input("abc12345".to_string(), "First commit\n\nBody".to_string());
 
     assert_eq!(view.input_mode, InputMode::DescribeInput);
     assert_eq!(view.editing_revision, Some("abc12345".to_string()));
-    // input_buffer should be prefilled with the description
-    assert_eq!(view.input_buffer, "First commit");
+    // Editor should be prefilled with the whole description
+    assert_eq!(view.describe_editor.text(), "First commit\n\nBody");
 }
 
 #[test]
@@ -1137,7 +1250,7 @@ fn test_describe_input_escape_cancels() {
     let action = escape(&mut view);
     assert_eq!(action, LogAction::None);
     assert_eq!(view.input_mode, InputMode::Normal);
-    assert!(view.input_buffer.is_empty());
+    assert!(view.describe_editor.text().is_empty());
 }
 
 #[test]
@@ -1152,7 +1265,7 @@ fn test_describe_key_no_selection_returns_none() {
 }
 
 #[test]
-fn test_describe_input_enter_submits() {
+fn test_describe_input_ctrl_s_submits() {
     let mut view = LogView::new();
     view.set_changes(create_test_changes());
 
@@ -1160,8 +1273,8 @@ fn test_describe_input_enter_submits() {
     view.set_describe_input("abc12345".to_string(), "First commit".to_string());
     assert_eq!(view.editing_revision, Some("abc12345".to_string()));
 
-    // Press Enter to submit
-    let action = submit(&mut view);
+    // Press Ctrl+S to submit
+    let action = save_editor(&mut view);
 
     assert!(matches!(
         action,
@@ -1169,7 +1282,26 @@ fn test_describe_input_enter_submits() {
         if revision == "abc12345" && message == "First commit"
     ));
     assert_eq!(view.input_mode, InputMode::Normal);
-    assert!(view.input_buffer.is_empty());
+    assert!(view.describe_editor.text().is_empty());
+}
+
+#[test]
+fn test_describe_input_enter_inserts_newline() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    view.set_describe_input("abc12345".to_string(), "Subject".to_string());
+
+    // Enter stays in the editor and starts a new line
+    assert_eq!(submit(&mut view), LogAction::None);
+    assert_eq!(view.input_mode, InputMode::DescribeInput);
+    submit(&mut view);
+    type_text(&mut view, "Body");
+
+    let action = save_editor(&mut view);
+    assert!(matches!(
+        action,
+        LogAction::Describe { message, .. } if message == "Subject\n\nBody"
+    ));
 }
 
 #[test]
@@ -1181,7 +1313,7 @@ fn test_describe_input_empty_submit_cancels() {
     view.set_describe_input("abc12345".to_string(), String::new());
 
     // Submit empty - should cancel (return None)
-    let action = submit(&mut view);
+    let action = save_editor(&mut view);
     assert_eq!(action, LogAction::None);
     assert_eq!(view.input_mode, InputMode::Normal);
 }
@@ -1196,10 +1328,10 @@ fn test_describe_input_type_and_submit() {
 
     // Type new description
     type_text(&mut view, "new desc");
-    assert_eq!(view.input_buffer, "new desc");
+    assert_eq!(view.describe_editor.text(), "new desc");
 
     // Submit
-    let action = submit(&mut view);
+    let action = save_editor(&mut view);
     assert!(matches!(
         action,
         LogAction::Describe { revision, message }
@@ -1281,14 +1413,15 @@ fn test_ctrl_e_in_describe_input_mode_ignored() {
     assert_eq!(view.input_mode, InputMode::DescribeInput);
 
     // Ctrl+E in describe input mode should NOT trigger DescribeExternal
-    // and should NOT add 'e' to input_buffer
+    // and should NOT add 'e' to the editor
     let key = KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL);
     let action = view.handle_key(key);
     assert_eq!(action, LogAction::None);
     assert_eq!(view.input_mode, InputMode::DescribeInput);
     assert_eq!(
-        view.input_buffer, "test",
-        "Ctrl+E should not modify input_buffer"
+        view.describe_editor.text(),
+        "test",
+        "Ctrl+E should not modify the description"
     );
 }
 
@@ -1299,7 +1432,7 @@ fn test_ctrl_e_in_describe_input_mode_ignored() {
 #[test]
 fn test_new_from_key_returns_action() {
     // create_test_changes() は [working_copy, non_wc, root] を返す
-    let mut view = LogView::default();
+    let mut view = LogView::new();
     view.set_changes(create_test_changes());
     view.selected_index = 1; // non working copy (xyz98765)
 
@@ -1352,6 +1485,85 @@ fn test_new_from_no_selection() {
     assert!(matches!(result, LogAction::None));
 }
 
+// =============================================================================
+// Merge parent marks (Space, then C) tests
+// =============================================================================
+
+#[test]
+fn test_space_toggles_mark() {
+    let mut view = LogView::default();
+    view.set_changes(create_test_changes());
+    view.selected_index = 1;
+
+    view.handle_key(KeyEvent::from(keys::MARK));
+    assert_eq!(view.mark_number("xyz98765"), Some(1));
+
+    view.handle_key(KeyEvent::from(keys::MARK));
+    assert!(view.marked.is_empty());
+}
+
+#[test]
+fn test_new_from_key_with_marks_creates_merge_in_mark_order() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    view.selected_index = 1;
+    view.handle_key(KeyEvent::from(keys::MARK));
+    view.selected_index = 0;
+    view.handle_key(KeyEvent::from(keys::MARK));
+
+    let result = view.handle_key(KeyEvent::from(KeyCode::Char('C')));
+    assert_eq!(
+        result,
+        LogAction::NewMergeChange {
+            parents: vec!["uvw43210".to_string(), "def67890".to_string()],
+            labels: vec!["xyz98765".to_string(), "main".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_new_from_key_with_single_mark_is_plain_new_from() {
+    let mut view = LogView::default();
+    view.set_changes(create_test_changes());
+    view.selected_index = 0;
+    view.handle_key(KeyEvent::from(keys::MARK));
+    view.selected_index = 1;
+
+    let result = view.handle_key(KeyEvent::from(KeyCode::Char('C')));
+    assert!(matches!(result, LogAction::NewChangeFrom { .. }));
+}
+
+#[test]
+fn test_esc_clears_marks() {
+    let mut view = LogView::default();
+    view.set_changes(create_test_changes());
+    view.handle_key(KeyEvent::from(keys::MARK));
+
+    view.handle_key(KeyEvent::from(keys::ESC));
+    assert!(view.marked.is_empty());
+}
+
+#[test]
+fn test_marks_survive_reload_with_new_commit_ids() {
+    let mut view = LogView::default();
+    view.set_changes(create_test_changes());
+    view.selected_index = 1;
+    view.handle_key(KeyEvent::from(keys::MARK));
+    view.selected_index = 0;
+    view.handle_key(KeyEvent::from(keys::MARK));
+
+    // The working copy was rewritten; the other marked change is gone
+    let mut changes = create_test_changes();
+    changes[0].commit_id = CommitId::new("0123abcd".to_string());
+    changes.remove(1);
+    view.set_changes(changes);
+
+    assert_eq!(
+        view.marked,
+        vec![("abc12345".to_string(), "0123abcd".to_string())]
+    );
+}
+
 #[test]
 fn test_track_key_returns_start_track() {
     let mut view = LogView::default();
@@ -1576,6 +1788,10 @@ fn test_select_working_copy_not_found() {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
         Change {
             change_id: ChangeId::new("xyz98765".to_string()),
@@ -1590,6 +1806,10 @@ fn test_select_working_copy_not_found() {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
     ];
     view.set_changes(changes);
@@ -1643,6 +1863,34 @@ fn test_reverse_default_is_false() {
     assert!(!view.reversed);
 }
 
+// =============================================================================
+// Elided expansion toggle tests (~ key)
+// =============================================================================
+
+#[test]
+fn test_toggle_elided_key_returns_action() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let action = press_key(&mut view, keys::TOGGLE_ELIDED);
+    assert_eq!(action, LogAction::ToggleElided);
+}
+
+#[test]
+fn test_expand_elided_default_is_false() {
+    let view = LogView::new();
+    assert!(!view.expand_elided);
+}
+
+#[test]
+fn test_center_cursor_key_returns_action() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let action = press_key(&mut view, keys::CENTER_CURSOR);
+    assert_eq!(action, LogAction::ToggleCenterCursor);
+}
+
 #[test]
 fn test_reverse_preserves_selection() {
     let mut view = LogView::new();
@@ -1695,6 +1943,10 @@ fn test_reverse_falls_back_to_working_copy() {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
         Change {
             change_id: ChangeId::new("abc12345".to_string()),
@@ -1709,6 +1961,10 @@ fn test_reverse_falls_back_to_working_copy() {
             is_graph_only: false,
             has_conflict: false,
             working_copy_names: Vec::new(),
+            is_immutable: false,
+            is_divergent: false,
+            is_hidden: false,
+            extras: Default::default(),
         },
     ];
     view.set_changes(changes);
@@ -1816,6 +2072,26 @@ fn test_evolog_ignored_in_rebase_select_mode() {
     assert_eq!(action, LogAction::None);
 }
 
+// =============================================================================
+// Quick amend tests (a key)
+// =============================================================================
+
+#[test]
+fn test_quick_amend_key_targets_selected_change() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    view.move_down();
+
+    let action = press_key(&mut view, keys::QUICK_AMEND);
+    assert_eq!(
+        action,
+        LogAction::QuickAmend {
+            destination: "uvw43210".to_string(),
+            change_id: "xyz98765".to_string(),
+        }
+    );
+}
+
 // =============================================================================
 // Revert tests (Z key)
 // =============================================================================
@@ -1875,6 +2151,51 @@ fn test_revert_ignored_in_rebase_select_mode() {
     assert_eq!(action, LogAction::None);
 }
 
+#[test]
+fn test_revert_range_select() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let ctrl_z = KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL);
+    let action = view.handle_key(ctrl_z);
+    assert_eq!(action, LogAction::StartRevertRange("abc12345".to_string()));
+    assert_eq!(view.input_mode, InputMode::RevertSelect);
+
+    press_key(&mut view, keys::MOVE_DOWN);
+    let action = press_key(&mut view, KeyCode::Enter);
+    assert_eq!(
+        action,
+        LogAction::RevertRange {
+            from: "def67890".to_string(),
+            to: "uvw43210".to_string(),
+        }
+    );
+    assert_eq!(view.input_mode, InputMode::Normal);
+    assert_eq!(view.revert_from, None);
+}
+
+#[test]
+fn test_revert_range_same_revision_reverts_single() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    view.handle_key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL));
+    let action = press_key(&mut view, KeyCode::Enter);
+    assert_eq!(action, LogAction::Revert("def67890".to_string()));
+}
+
+#[test]
+fn test_revert_range_select_cancel() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    view.handle_key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL));
+    let action = press_key(&mut view, keys::ESC);
+    assert_eq!(action, LogAction::None);
+    assert_eq!(view.input_mode, InputMode::Normal);
+    assert_eq!(view.revert_from, None);
+}
+
 #[test]
 fn test_reverse_ignored_in_special_modes() {
     let mut view = LogView::new();
@@ -1971,6 +2292,30 @@ fn test_skip_emptied_included_in_rebase_action() {
     assert!(!view.skip_emptied);
 }
 
+#[test]
+fn test_rebase_select_t_picks_typed_destination() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    press_key(&mut view, keys::REBASE);
+    press_key(&mut view, KeyCode::Char('s'));
+    press_key(&mut view, KeyCode::Char('S'));
+    let action = press_key(&mut view, KeyCode::Char('t'));
+    assert!(matches!(
+        action,
+        LogAction::PickRebaseDestination {
+            mode: RebaseMode::Source,
+            skip_emptied: true,
+            use_revset: false,
+            ..
+        }
+    ));
+
+    // Rebase select mode is left; the dialog takes over
+    assert_eq!(view.input_mode, InputMode::Normal);
+    assert!(view.rebase_source.is_none());
+}
+
 #[test]
 fn test_skip_emptied_reset_on_rebase_start() {
     let mut view = LogView::new();
@@ -2574,3 +2919,206 @@ fn test_metaedit_key_ignored_in_rebase_select_mode() {
     let action = press_key(&mut view, keys::METAEDIT);
     assert_eq!(action, LogAction::None);
 }
+
+// =============================================================================
+// Revset preset tests
+// =============================================================================
+
+fn presets() -> Vec<RevsetPreset> {
+    vec![
+        RevsetPreset {
+            name: "mine".to_string(),
+            revset: "mine() & ~empty()".to_string(),
+        },
+        RevsetPreset {
+            name: "stack".to_string(),
+            revset: "trunk()..@".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn test_revset_preset_keys_dispatch_actions() {
+    let mut view = LogView::new();
+    assert_eq!(
+        press_key(&mut view, keys::REVSET_PRESET_CYCLE),
+        LogAction::CycleRevsetPreset
+    );
+    assert_eq!(
+        press_key(&mut view, keys::REVSET_PRESET_MENU),
+        LogAction::OpenRevsetPresets
+    );
+}
+
+#[test]
+fn test_active_preset_matches_current_revset() {
+    let mut view = LogView::new();
+    view.revset_presets = presets();
+    assert!(view.active_preset().is_none());
+
+    view.current_revset = Some("trunk()..@".to_string());
+    assert_eq!(view.active_preset().unwrap().name, "stack");
+
+    view.current_revset = Some("all()".to_string());
+    assert!(view.active_preset().is_none());
+}
+
+#[test]
+fn test_next_preset_cycles_then_returns_to_default() {
+    let mut view = LogView::new();
+    view.revset_presets = presets();
+    assert_eq!(view.next_preset().unwrap().name, "mine");
+
+    view.current_revset = Some("mine() & ~empty()".to_string());
+    assert_eq!(view.next_preset().unwrap().name, "stack");
+
+    view.current_revset = Some("trunk()..@".to_string());
+    assert!(view.next_preset().is_none());
+
+    // A hand-typed revset restarts from the first preset
+    view.current_revset = Some("all()".to_string());
+    assert_eq!(view.next_preset().unwrap().name, "mine");
+}
+
+// =============================================================================
+// Parse issue report key tests
+// =============================================================================
+
+#[test]
+fn test_report_parse_issue_key_requires_issue() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    assert_eq!(
+        press_key(&mut view, keys::REPORT_PARSE_ISSUE),
+        LogAction::None
+    );
+
+    view.parse_issue = Some(LogParseIssue {
+        line_number: 2,
+        line: "○  xyz\tbroken".to_string(),
+        message: "Expected at least 6 fields after change_id, got 1".to_string(),
+        raw_output: String::new(),
+    });
+    assert_eq!(
+        press_key(&mut view, keys::REPORT_PARSE_ISSUE),
+        LogAction::ReportParseIssue
+    );
+}
+
+#[test]
+fn test_colon_starts_jj_command_outside_rebase() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    assert_eq!(
+        press_key(&mut view, keys::JJ_COMMAND),
+        LogAction::StartJjCommand
+    );
+    assert_eq!(view.input_mode, InputMode::Normal);
+}
+
+#[test]
+fn test_stack_view_key_dispatches_action() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    assert_eq!(
+        press_key(&mut view, keys::STACK_VIEW),
+        LogAction::OpenStackView
+    );
+}
+
+// =============================================================================
+// Reorder mode tests
+// =============================================================================
+
+#[test]
+fn test_reorder_key_enters_mode() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    assert_eq!(press_key(&mut view, keys::REORDER), LogAction::StartReorder);
+    assert_eq!(view.input_mode, InputMode::Reorder);
+}
+
+#[test]
+fn test_reorder_key_no_selection() {
+    let mut view = LogView::new();
+    assert_eq!(press_key(&mut view, keys::REORDER), LogAction::None);
+    assert_eq!(view.input_mode, InputMode::Normal);
+}
+
+#[test]
+fn test_reorder_mode_move_keys() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    press_key(&mut view, keys::REORDER);
+
+    assert_eq!(
+        press_key(&mut view, keys::MOVE_COMMIT_UP),
+        LogAction::MoveCommitUp("abc12345".to_string())
+    );
+    press_key(&mut view, KeyCode::Char('j'));
+    let selected = view.selected_change().unwrap().change_id.to_string();
+    assert_eq!(
+        press_key(&mut view, keys::MOVE_COMMIT_DOWN),
+        LogAction::MoveCommitDown(selected)
+    );
+    // Still in reorder mode after moves
+    assert_eq!(view.input_mode, InputMode::Reorder);
+}
+
+#[test]
+fn test_reorder_mode_esc_returns_to_normal() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+    press_key(&mut view, keys::REORDER);
+    assert_eq!(press_key(&mut view, KeyCode::Esc), LogAction::None);
+    assert_eq!(view.input_mode, InputMode::Normal);
+}
+
+// =============================================================================
+// Push queue tests (Q key)
+// =============================================================================
+
+#[test]
+fn test_push_queue_key_returns_change_bookmarks() {
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let action = press_key(&mut view, keys::PUSH_QUEUE_TOGGLE);
+    assert_eq!(action, LogAction::TogglePushQueue(vec!["main".to_string()]));
+}
+
+// =============================================================================
+// Hidden commit tests (h / U keys)
+// =============================================================================
+
+#[test]
+fn test_restore_hidden_key_only_on_hidden_rows() {
+    let mut view = LogView::new();
+    let mut changes = create_test_changes();
+    changes[1].is_hidden = true;
+    view.set_changes(changes);
+
+    assert_eq!(press_key(&mut view, keys::RESTORE_HIDDEN), LogAction::None);
+    view.move_down();
+    assert_eq!(
+        press_key(&mut view, keys::RESTORE_HIDDEN),
+        LogAction::RestoreHidden("uvw43210".to_string())
+    );
+    assert_eq!(
+        press_key(&mut view, keys::TOGGLE_HIDDEN),
+        LogAction::ToggleHidden
+    );
+}
+
+#[test]
+fn test_ctrl_n_starts_merge_helper() {
+    use crossterm::event::KeyModifiers;
+
+    let mut view = LogView::new();
+    view.set_changes(create_test_changes());
+
+    let key = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+    let action = view.handle_key(key);
+    let change_id = view.selected_change().unwrap().change_id.to_string();
+    assert_eq!(action, LogAction::StartMerge(change_id));
+}
@@ -0,0 +1,134 @@
+//! Input handling for MergeView
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::keys;
+use crate::model::MergeChoice;
+
+use super::{MergeAction, MergeView};
+
+impl MergeView {
+    /// Handle key event and return action
+    pub fn handle_key(&mut self, key: KeyEvent) -> MergeAction {
+        match key.code {
+            // Region navigation
+            k if keys::is_move_down(k) => {
+                self.next_region();
+                MergeAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.prev_region();
+                MergeAction::None
+            }
+            KeyCode::Char('n') => {
+                self.next_unresolved();
+                MergeAction::None
+            }
+            // Pick a resolution for the current region
+            KeyCode::Char('1') => {
+                self.choose(MergeChoice::Ours);
+                MergeAction::None
+            }
+            KeyCode::Char('2') => {
+                self.choose(MergeChoice::Theirs);
+                MergeAction::None
+            }
+            KeyCode::Char('b') => {
+                self.choose(MergeChoice::Both);
+                MergeAction::None
+            }
+            KeyCode::Char('x') => {
+                self.clear_choice();
+                MergeAction::None
+            }
+            KeyCode::Char('e') => {
+                if self.current_region().is_some() {
+                    MergeAction::EditRegion
+                } else {
+                    MergeAction::None
+                }
+            }
+            // Write result to the working copy
+            KeyCode::Char('w') => match self.unresolved_count() {
+                0 => MergeAction::Write,
+                n => MergeAction::Unresolved(n),
+            },
+            // Back
+            k if k == keys::QUIT || k == keys::ESC => MergeAction::Back,
+            _ => MergeAction::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ConflictRegion, MergeSegment};
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn make_view() -> MergeView {
+        let region = || {
+            MergeSegment::Conflict(ConflictRegion {
+                base: vec![],
+                ours: vec!["ours".to_string()],
+                theirs: vec!["theirs".to_string()],
+                choice: None,
+            })
+        };
+        MergeView::new(
+            "@".to_string(),
+            "a.txt".to_string(),
+            vec![region(), region()],
+            "",
+        )
+    }
+
+    #[test]
+    fn test_pick_keys() {
+        let mut view = make_view();
+        view.handle_key(key(KeyCode::Char('2')));
+        assert_eq!(
+            view.current_region().unwrap().choice,
+            Some(MergeChoice::Theirs)
+        );
+        view.handle_key(key(KeyCode::Char('1')));
+        assert_eq!(
+            view.current_region().unwrap().choice,
+            Some(MergeChoice::Ours)
+        );
+        view.handle_key(key(KeyCode::Char('b')));
+        assert_eq!(
+            view.current_region().unwrap().choice,
+            Some(MergeChoice::Both)
+        );
+    }
+
+    #[test]
+    fn test_write_requires_all_regions() {
+        let mut view = make_view();
+        view.handle_key(key(KeyCode::Char('1')));
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('w'))),
+            MergeAction::Unresolved(1)
+        );
+        view.handle_key(key(KeyCode::Char('j')));
+        view.handle_key(key(KeyCode::Char('2')));
+        assert_eq!(view.handle_key(key(KeyCode::Char('w'))), MergeAction::Write);
+        assert_eq!(view.merged_content().unwrap(), "ours\ntheirs");
+    }
+
+    #[test]
+    fn test_edit_and_back() {
+        let mut view = make_view();
+        assert_eq!(
+            view.handle_key(key(KeyCode::Char('e'))),
+            MergeAction::EditRegion
+        );
+        assert_eq!(view.handle_key(key(KeyCode::Char('q'))), MergeAction::Back);
+        assert_eq!(view.handle_key(key(KeyCode::Esc)), MergeAction::Back);
+    }
+}
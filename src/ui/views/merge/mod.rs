@@ -0,0 +1,283 @@
+//! Merge View - built-in 3-pane conflict resolution editor
+//!
+//! Shows side #1 / base / side #2 of the current conflict region and a
+//! preview of the merged result. Each region is resolved by picking a side,
+//! both sides, or hand-editing, and the result is written back to the
+//! working copy.
+
+mod input;
+mod render;
+
+use crate::model::{ConflictRegion, MergeChoice, MergeSegment};
+use crate::ui::navigation;
+
+/// Action returned by MergeView input handling
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeAction {
+    /// No action needed
+    None,
+    /// Go back to previous view (discarding choices)
+    Back,
+    /// Edit the current region in $EDITOR (App pre-fills and reads back)
+    EditRegion,
+    /// Write the merged file to the working copy
+    Write,
+    /// Write requested while regions are still unresolved
+    Unresolved(usize),
+}
+
+/// Merge editor state for a single conflicted file
+#[derive(Debug, Clone)]
+pub struct MergeView {
+    /// Target revision (always the working copy)
+    pub revision: String,
+    /// File path relative to the workspace root
+    pub file_path: String,
+    segments: Vec<MergeSegment>,
+    /// Indices into `segments` of conflict regions
+    region_indices: Vec<usize>,
+    /// Current position in `region_indices`
+    current: usize,
+    /// Whether the original file ended with a newline
+    trailing_newline: bool,
+    /// Line terminator of the original file (`\r\n` files are kept as CRLF)
+    line_ending: &'static str,
+}
+
+impl MergeView {
+    /// Create a new merge view from parsed segments
+    ///
+    /// `source` is the original file contents; its line endings and trailing
+    /// newline are preserved when the merged file is written back.
+    pub fn new(
+        revision: String,
+        file_path: String,
+        segments: Vec<MergeSegment>,
+        source: &str,
+    ) -> Self {
+        let region_indices = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| matches!(s, MergeSegment::Conflict(_)))
+            .map(|(i, _)| i)
+            .collect();
+        Self {
+            revision,
+            file_path,
+            segments,
+            region_indices,
+            current: 0,
+            trailing_newline: source.ends_with('\n'),
+            line_ending: if source.contains("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            },
+        }
+    }
+
+    /// Number of conflict regions in the file
+    pub fn region_count(&self) -> usize {
+        self.region_indices.len()
+    }
+
+    /// Number of regions without a choice yet
+    pub fn unresolved_count(&self) -> usize {
+        self.regions().filter(|r| r.choice.is_none()).count()
+    }
+
+    /// Index (0-based) of the current region
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// The region currently being resolved
+    pub fn current_region(&self) -> Option<&ConflictRegion> {
+        let idx = *self.region_indices.get(self.current)?;
+        match &self.segments[idx] {
+            MergeSegment::Conflict(region) => Some(region),
+            MergeSegment::Context(_) => None,
+        }
+    }
+
+    fn regions(&self) -> impl Iterator<Item = &ConflictRegion> {
+        self.segments.iter().filter_map(|s| match s {
+            MergeSegment::Conflict(region) => Some(region),
+            MergeSegment::Context(_) => None,
+        })
+    }
+
+    /// Set the resolution of the current region
+    pub fn choose(&mut self, choice: MergeChoice) {
+        let Some(&idx) = self.region_indices.get(self.current) else {
+            return;
+        };
+        if let MergeSegment::Conflict(region) = &mut self.segments[idx] {
+            region.choice = Some(choice);
+        }
+    }
+
+    /// Clear the resolution of the current region
+    pub fn clear_choice(&mut self) {
+        let Some(&idx) = self.region_indices.get(self.current) else {
+            return;
+        };
+        if let MergeSegment::Conflict(region) = &mut self.segments[idx] {
+            region.choice = None;
+        }
+    }
+
+    /// Move to the next region
+    pub fn next_region(&mut self) {
+        let max = self.region_indices.len().saturating_sub(1);
+        self.current = navigation::select_next(self.current, max);
+    }
+
+    /// Move to the previous region
+    pub fn prev_region(&mut self) {
+        self.current = navigation::select_prev(self.current);
+    }
+
+    /// Move to the next unresolved region (wrapping), if any
+    pub fn next_unresolved(&mut self) {
+        let n = self.region_indices.len();
+        for step in 1..=n {
+            let pos = (self.current + step) % n;
+            if let MergeSegment::Conflict(region) = &self.segments[self.region_indices[pos]]
+                && region.choice.is_none()
+            {
+                self.current = pos;
+                return;
+            }
+        }
+    }
+
+    /// Merged file contents (None while any region is unresolved)
+    pub fn merged_content(&self) -> Option<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for segment in &self.segments {
+            match segment {
+                MergeSegment::Context(line) => lines.push(line.clone()),
+                MergeSegment::Conflict(region) => lines.extend(region.resolved_lines()?),
+            }
+        }
+        let mut content = lines.join(self.line_ending);
+        if self.trailing_newline && !content.is_empty() {
+            content.push_str(self.line_ending);
+        }
+        Some(content)
+    }
+
+    /// Result preview lines: (text, belongs to current region, unresolved)
+    pub(crate) fn result_lines(&self) -> Vec<(String, bool, bool)> {
+        let current_idx = self.region_indices.get(self.current).copied();
+        let mut out = Vec::new();
+        for (i, segment) in self.segments.iter().enumerate() {
+            let is_current = Some(i) == current_idx;
+            match segment {
+                MergeSegment::Context(line) => out.push((line.clone(), false, false)),
+                MergeSegment::Conflict(region) => match region.resolved_lines() {
+                    Some(lines) => out.extend(lines.into_iter().map(|l| (l, is_current, false))),
+                    None => out.push(("<<< unresolved >>>".to_string(), is_current, true)),
+                },
+            }
+        }
+        out
+    }
+
+    /// Line index of the current region in `result_lines()` (for scrolling)
+    pub(crate) fn current_result_line(&self) -> usize {
+        self.result_lines()
+            .iter()
+            .position(|(_, is_current, _)| *is_current)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(ours: &str, theirs: &str) -> MergeSegment {
+        MergeSegment::Conflict(ConflictRegion {
+            base: vec!["base".to_string()],
+            ours: vec![ours.to_string()],
+            theirs: vec![theirs.to_string()],
+            choice: None,
+        })
+    }
+
+    fn make_view() -> MergeView {
+        MergeView::new(
+            "@".to_string(),
+            "file.txt".to_string(),
+            vec![
+                MergeSegment::Context("head".to_string()),
+                region("a1", "b1"),
+                MergeSegment::Context("middle".to_string()),
+                region("a2", "b2"),
+            ],
+            "head\n",
+        )
+    }
+
+    #[test]
+    fn test_merge_view_counts() {
+        let view = make_view();
+        assert_eq!(view.region_count(), 2);
+        assert_eq!(view.unresolved_count(), 2);
+        assert_eq!(view.current_region().unwrap().ours, vec!["a1"]);
+    }
+
+    #[test]
+    fn test_merged_content_requires_all_regions() {
+        let mut view = make_view();
+        view.choose(MergeChoice::Ours);
+        assert!(view.merged_content().is_none());
+
+        view.next_region();
+        view.choose(MergeChoice::Both);
+        assert_eq!(view.merged_content().unwrap(), "head\na1\nmiddle\na2\nb2\n");
+    }
+
+    #[test]
+    fn test_merged_content_keeps_crlf() {
+        let source = "head\r\n<<<<<<< a\r\nours\r\n=======\r\ntheirs\r\n>>>>>>> b\r\ntail\r\n";
+        let segments = crate::jj::parser::Parser::parse_merge_segments(source).unwrap();
+        let mut view = MergeView::new("@".to_string(), "f.txt".to_string(), segments, source);
+        view.choose(MergeChoice::Both);
+        assert_eq!(
+            view.merged_content().unwrap(),
+            "head\r\nours\r\ntheirs\r\ntail\r\n"
+        );
+    }
+
+    #[test]
+    fn test_next_unresolved_wraps() {
+        let mut view = make_view();
+        view.next_region();
+        view.choose(MergeChoice::Theirs);
+        view.next_unresolved();
+        assert_eq!(view.current_index(), 0);
+    }
+
+    #[test]
+    fn test_clear_choice() {
+        let mut view = make_view();
+        view.choose(MergeChoice::Theirs);
+        assert_eq!(view.unresolved_count(), 1);
+        view.clear_choice();
+        assert_eq!(view.unresolved_count(), 2);
+    }
+
+    #[test]
+    fn test_result_lines_mark_current_region() {
+        let mut view = make_view();
+        view.next_region();
+        let lines = view.result_lines();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[3].1 && lines[3].2);
+        assert!(!lines[1].1 && lines[1].2);
+        assert_eq!(view.current_result_line(), 3);
+    }
+}
@@ -0,0 +1,197 @@
+//! Rendering for MergeView
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::model::{MergeChoice, Notification};
+use crate::ui::{components, theme};
+
+use super::MergeView;
+
+/// Lines of context shown above the current region in the result pane
+const RESULT_LEADING_CONTEXT: usize = 3;
+
+impl MergeView {
+    /// Render the merge view
+    ///
+    /// Top row: side #1 / base / side #2 of the current region.
+    /// Bottom: merged result with the current region highlighted.
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let rows =
+            Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+        let panes = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(rows[0]);
+
+        let region = self.current_region();
+        let choice = region.and_then(|r| r.choice.as_ref());
+        // Highlight the side(s) taken by the current choice
+        let picked =
+            |side: MergeChoice| choice == Some(&side) || choice == Some(&MergeChoice::Both);
+
+        render_side(
+            frame,
+            panes[0],
+            " [1] Side #1 ",
+            region.map(|r| r.ours.as_slice()).unwrap_or_default(),
+            theme::resolve_view::OURS,
+            picked(MergeChoice::Ours),
+        );
+        render_side(
+            frame,
+            panes[1],
+            " Base ",
+            region.map(|r| r.base.as_slice()).unwrap_or_default(),
+            theme::resolve_view::BASE,
+            false,
+        );
+        render_side(
+            frame,
+            panes[2],
+            " [2] Side #2 ",
+            region.map(|r| r.theirs.as_slice()).unwrap_or_default(),
+            theme::resolve_view::THEIRS,
+            picked(MergeChoice::Theirs),
+        );
+
+        self.render_result(frame, rows[1], notification);
+    }
+
+    fn render_result(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let title = Line::from(format!(
+            " {} - region {}/{} ({} unresolved) ",
+            self.file_path,
+            (self.current_index() + 1).min(self.region_count()),
+            self.region_count(),
+            self.unresolved_count()
+        ))
+        .bold()
+        .cyan()
+        .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        let start = self
+            .current_result_line()
+            .saturating_sub(RESULT_LEADING_CONTEXT);
+        let lines: Vec<Line> = self
+            .result_lines()
+            .into_iter()
+            .skip(start)
+            .take(area.height.saturating_sub(2) as usize)
+            .map(|(text, is_current, unresolved)| {
+                let mut style = if unresolved {
+                    Style::default()
+                        .fg(theme::resolve_view::MARKER)
+                        .add_modifier(Modifier::ITALIC)
+                } else {
+                    Style::default()
+                };
+                if is_current {
+                    style = style.bg(theme::selection::BG).fg(theme::selection::FG);
+                }
+                Line::from(Span::styled(text, style))
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Render one side of the current region
+fn render_side(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    content: &[String],
+    color: Color,
+    picked: bool,
+) {
+    let mut title = Line::from(title.to_string()).bold().fg(color);
+    if picked {
+        title = title.add_modifier(Modifier::REVERSED);
+    }
+    let block = components::bordered_block(title);
+
+    let lines: Vec<Line> = if content.is_empty() {
+        vec![Line::from(Span::styled(
+            "(empty)",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        content
+            .iter()
+            .map(|l| Line::from(Span::styled(l.clone(), Style::default().fg(color))))
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+    frame.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{ConflictRegion, MergeSegment};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn render_to_string(view: &MergeView) -> String {
+        let backend = TestBackend::new(90, 16);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| view.render(frame, frame.area(), None))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|c| c.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_render_shows_sides_and_result() {
+        let mut view = MergeView::new(
+            "@".to_string(),
+            "src/lib.rs".to_string(),
+            vec![
+                MergeSegment::Context("fn main() {".to_string()),
+                MergeSegment::Conflict(ConflictRegion {
+                    base: vec!["base line".to_string()],
+                    ours: vec!["left line".to_string()],
+                    theirs: vec!["right line".to_string()],
+                    choice: None,
+                }),
+            ],
+            "\n",
+        );
+        let output = render_to_string(&view);
+        assert!(output.contains("left line"));
+        assert!(output.contains("base line"));
+        assert!(output.contains("right line"));
+        assert!(output.contains("<<< unresolved >>>"));
+        assert!(output.contains("(1 unresolved)"));
+
+        view.choose(MergeChoice::Theirs);
+        let output = render_to_string(&view);
+        assert!(output.contains("(0 unresolved)"));
+        assert!(!output.contains("<<< unresolved >>>"));
+    }
+}
@@ -5,23 +5,42 @@
 mod blame;
 mod bookmark;
 mod command_history;
+mod command_log;
+mod diagnostics;
 mod diff;
 mod evolog;
+mod file_log;
 mod log;
+mod merge;
+mod op_heads;
 mod operation;
+mod pager;
+mod remote;
 mod resolve;
+mod sparse;
+mod stack;
 mod status;
 mod tag;
 mod workspace;
 
 pub use blame::{BlameAction, BlameView};
 pub use bookmark::{BookmarkAction, BookmarkView, RenameState};
+pub(crate) use command_history::format_timestamp;
 pub use command_history::{CommandHistoryAction, CommandHistoryView};
-pub use diff::{DiffAction, DiffView};
+pub use command_log::{CommandLogAction, CommandLogView};
+pub use diagnostics::{DiagnosticsAction, DiagnosticsView};
+pub use diff::{DiffAction, DiffLineFilter, DiffView};
 pub use evolog::{EvologAction, EvologView};
-pub use log::{InputMode, LogAction, LogView, RebaseMode};
-pub use operation::{OperationAction, OperationView};
+pub use file_log::{FileLogAction, FileLogView};
+pub use log::{GraphStyle, InputMode, LogAction, LogView, RebaseMode};
+pub use merge::{MergeAction, MergeView};
+pub use op_heads::{OpHeadsAction, OpHeadsView};
+pub use operation::{OperationAction, OperationSort, OperationView};
+pub use pager::PagerView;
+pub use remote::{RemoteAction, RemoteView};
 pub use resolve::{ResolveAction, ResolveView};
+pub use sparse::{SparseAction, SparseView};
+pub use stack::{StackAction, StackView};
 pub use status::{StatusAction, StatusInputMode, StatusView};
 pub use tag::{TagAction, TagView};
 pub use workspace::{WorkspaceAction, WorkspaceView};
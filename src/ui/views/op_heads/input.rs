@@ -0,0 +1,52 @@
+//! Divergent Operations View key handling
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{OpHeadsAction, OpHeadsView};
+use crate::keys;
+
+impl OpHeadsView {
+    /// Handle key input
+    pub fn handle_key(&mut self, key: KeyEvent) -> OpHeadsAction {
+        match key.code {
+            // Navigation
+            k if keys::is_move_down(k) => {
+                self.select_next();
+                OpHeadsAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.select_prev();
+                OpHeadsAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.select_first();
+                OpHeadsAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.select_last();
+                OpHeadsAction::None
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                self.focus_next();
+                OpHeadsAction::None
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                self.focus_prev();
+                OpHeadsAction::None
+            }
+
+            // Actions
+            KeyCode::Enter => match self.focused_head() {
+                Some(head) => OpHeadsAction::KeepSide(head.id.clone()),
+                None => OpHeadsAction::None,
+            },
+            KeyCode::Char('m') => OpHeadsAction::KeepMerged,
+
+            // Back/Quit
+            k if k == keys::QUIT => OpHeadsAction::Back,
+            KeyCode::Esc => OpHeadsAction::Back,
+
+            _ => OpHeadsAction::None,
+        }
+    }
+}
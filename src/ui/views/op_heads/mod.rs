@@ -0,0 +1,168 @@
+//! Divergent Operations View - browse operation heads reconciled by jj
+//!
+//! Shows each head's operation chain side by side down to the fork point,
+//! with actions to keep one side (`jj op restore`) or the merged state.
+
+mod input;
+mod render;
+
+use crate::model::{OpDivergence, Operation};
+use crate::ui::navigation;
+
+/// Action returned by the Divergent Operations View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpHeadsAction {
+    /// No action needed
+    None,
+    /// Go back to previous view
+    Back,
+    /// Restore the repo to a head operation, dropping the other sides
+    KeepSide(String),
+    /// Keep the reconciled state jj produced
+    KeepMerged,
+}
+
+/// Divergent Operations View state
+#[derive(Debug)]
+pub struct OpHeadsView {
+    /// Divergence being browsed
+    pub divergence: OpDivergence,
+    /// Focused side (column) index
+    pub(super) focused: usize,
+    /// Selected operation index per side
+    pub(super) selected: Vec<usize>,
+}
+
+impl OpHeadsView {
+    /// Create a new Divergent Operations View
+    pub fn new(divergence: OpDivergence) -> Self {
+        let selected = vec![0; divergence.sides.len()];
+        Self {
+            divergence,
+            focused: 0,
+            selected,
+        }
+    }
+
+    /// Operations of the focused side (newest first)
+    fn focused_side(&self) -> &[Operation] {
+        self.divergence
+            .sides
+            .get(self.focused)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the selected operation in the focused side
+    pub fn selected_operation(&self) -> Option<&Operation> {
+        self.focused_side().get(self.selected[self.focused])
+    }
+
+    /// Head operation of the focused side
+    pub fn focused_head(&self) -> Option<&Operation> {
+        self.divergence.head(self.focused)
+    }
+
+    /// Move selection down within the focused side
+    pub fn select_next(&mut self) {
+        let max = self.focused_side().len().saturating_sub(1);
+        let selected = &mut self.selected[self.focused];
+        *selected = navigation::select_next(*selected, max);
+    }
+
+    /// Move selection up within the focused side
+    pub fn select_prev(&mut self) {
+        let selected = &mut self.selected[self.focused];
+        *selected = navigation::select_prev(*selected);
+    }
+
+    /// Go to the head of the focused side
+    pub fn select_first(&mut self) {
+        self.selected[self.focused] = 0;
+    }
+
+    /// Go to the oldest operation of the focused side
+    pub fn select_last(&mut self) {
+        self.selected[self.focused] = self.focused_side().len().saturating_sub(1);
+    }
+
+    /// Focus the next side (column)
+    pub fn focus_next(&mut self) {
+        let max = self.divergence.sides.len().saturating_sub(1);
+        self.focused = navigation::select_next(self.focused, max);
+    }
+
+    /// Focus the previous side (column)
+    pub fn focus_prev(&mut self) {
+        self.focused = navigation::select_prev(self.focused);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn op(id: &str) -> Operation {
+        Operation {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn create_view() -> OpHeadsView {
+        OpHeadsView::new(OpDivergence {
+            merge: op("merge"),
+            later_ops: 0,
+            sides: vec![vec![op("a2"), op("a1")], vec![op("b1")]],
+            fork_point: Some(op("fork")),
+        })
+    }
+
+    #[test]
+    fn test_navigation_per_side() {
+        let mut view = create_view();
+        view.select_next();
+        view.select_next();
+        assert_eq!(view.selected_operation().unwrap().id, "a1");
+
+        view.focus_next();
+        assert_eq!(view.selected_operation().unwrap().id, "b1");
+        view.focus_next(); // clamped at last side
+        assert_eq!(view.focused, 1);
+
+        // Each side keeps its own selection
+        view.focus_prev();
+        assert_eq!(view.selected_operation().unwrap().id, "a1");
+        view.select_first();
+        assert_eq!(view.selected_operation().unwrap().id, "a2");
+    }
+
+    #[test]
+    fn test_handle_key_keep_focused_side() {
+        let mut view = create_view();
+        view.handle_key(KeyEvent::from(KeyCode::Char('l')));
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, OpHeadsAction::KeepSide("b1".to_string()));
+    }
+
+    #[test]
+    fn test_handle_key_keep_side_uses_head_not_selection() {
+        let mut view = create_view();
+        view.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, OpHeadsAction::KeepSide("a2".to_string()));
+    }
+
+    #[test]
+    fn test_handle_key_keep_merged_and_back() {
+        let mut view = create_view();
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Char('m'))),
+            OpHeadsAction::KeepMerged
+        );
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Char('q'))),
+            OpHeadsAction::Back
+        );
+    }
+}
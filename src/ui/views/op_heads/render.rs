@@ -0,0 +1,195 @@
+//! Divergent Operations View rendering
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::OpHeadsView;
+use crate::model::{Notification, Operation};
+use crate::ui::{components, navigation, theme};
+
+/// Height of the detail pane (including borders)
+const DETAIL_HEIGHT: u16 = 5;
+
+impl OpHeadsView {
+    /// Render the view with optional notification in title bar
+    ///
+    /// Layout: reconcile summary, one column per head, the fork point, and
+    /// details of the selected operation.
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let title = Line::from(format!(
+            " Divergent Operations ({} heads) ",
+            self.divergence.sides.len()
+        ))
+        .bold()
+        .cyan()
+        .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(DETAIL_HEIGHT),
+        ])
+        .split(inner);
+
+        frame.render_widget(Paragraph::new(self.build_summary_line()), chunks[0]);
+        self.render_sides(frame, chunks[1]);
+        frame.render_widget(Paragraph::new(self.build_fork_line()), chunks[2]);
+        self.render_detail(frame, chunks[3]);
+    }
+
+    /// "Reconciled by ..." with a warning when later operations would be undone
+    fn build_summary_line(&self) -> Line<'static> {
+        let merge = &self.divergence.merge;
+        let mut spans = vec![
+            Span::styled("Reconciled by ", Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                merge.short_id().to_string(),
+                Style::default().fg(Color::Magenta),
+            ),
+            Span::styled(
+                format!(" ({})", merge.timestamp),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if self.divergence.later_ops > 0 {
+            spans.push(Span::styled(
+                format!(
+                    "  ⚠ keeping a side also undoes {} later operation(s)",
+                    self.divergence.later_ops
+                ),
+                Style::default().fg(theme::operation_view::DIVERGENCE_WARNING),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// One bordered column per head, focused column highlighted
+    fn render_sides(&self, frame: &mut Frame, area: Rect) {
+        let count = self.divergence.sides.len().max(1) as u32;
+        let columns =
+            Layout::horizontal((0..count).map(|_| Constraint::Ratio(1, count))).split(area);
+
+        for (idx, (side, column)) in self.divergence.sides.iter().zip(columns.iter()).enumerate() {
+            let is_focused = idx == self.focused;
+            let head = side.first().map_or("-", |op| op.short_id());
+            let title_style = if is_focused {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let block = components::bordered_block(Line::from(Span::styled(
+                format!(" Side #{}: {} ", idx + 1, head),
+                title_style,
+            )));
+
+            let height = column.height.saturating_sub(2) as usize;
+            let scroll = navigation::adjust_scroll(self.selected[idx], 0, height);
+            let lines: Vec<Line> = side
+                .iter()
+                .enumerate()
+                .skip(scroll)
+                .take(height)
+                .map(|(op_idx, op)| {
+                    build_operation_line(op, is_focused && op_idx == self.selected[idx])
+                })
+                .collect();
+
+            frame.render_widget(Paragraph::new(lines).block(block), *column);
+        }
+    }
+
+    /// Highlighted row for the operation all heads diverged from
+    fn build_fork_line(&self) -> Line<'static> {
+        let style = Style::default()
+            .fg(theme::operation_view::FORK_POINT)
+            .add_modifier(Modifier::BOLD);
+        match &self.divergence.fork_point {
+            Some(op) => Line::from(vec![
+                Span::styled("◆ Fork point: ", style),
+                Span::styled(
+                    op.short_id().to_string(),
+                    Style::default().fg(Color::Magenta),
+                ),
+                Span::raw("  "),
+                Span::styled(op.timestamp.clone(), Style::default().fg(Color::Yellow)),
+                Span::raw("  "),
+                Span::raw(op.description.clone()),
+            ]),
+            None => Line::from(Span::styled(
+                "◆ Fork point: beyond the loaded op log",
+                style,
+            )),
+        }
+    }
+
+    /// Render metadata for the selected operation
+    fn render_detail(&self, frame: &mut Frame, area: Rect) {
+        let block = components::bordered_block(Line::from(" Details ").bold().cyan());
+        let Some(op) = self.selected_operation() else {
+            frame.render_widget(Paragraph::new("").block(block), area);
+            return;
+        };
+
+        let label = Style::default().fg(Color::DarkGray);
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("Operation: ", label),
+                Span::styled(op.id.clone(), Style::default().fg(Color::Magenta)),
+            ]),
+            Line::from(vec![
+                Span::styled("User:      ", label),
+                Span::raw(op.username().to_string()),
+                Span::styled("  Host: ", label),
+                Span::raw(op.hostname().to_string()),
+                Span::styled(format!("  ({})", op.timestamp), label),
+            ]),
+            Line::from(vec![
+                Span::styled("Summary:   ", label),
+                Span::raw(op.description.clone()),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Build a line for an operation in a side column
+fn build_operation_line(op: &Operation, is_selected: bool) -> Line<'static> {
+    let mut line = Line::from(vec![
+        Span::styled(
+            op.short_id().to_string(),
+            Style::default().fg(Color::Magenta),
+        ),
+        Span::raw("  "),
+        Span::styled(op.timestamp.clone(), Style::default().fg(Color::Yellow)),
+        Span::raw("  "),
+        Span::styled(op.description.clone(), Style::default().fg(Color::White)),
+    ]);
+    if is_selected {
+        line = line.style(
+            Style::default()
+                .fg(theme::selection::FG)
+                .bg(theme::selection::BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    line
+}
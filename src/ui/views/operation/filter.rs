@@ -0,0 +1,165 @@
+//! Operation log filter
+//!
+//! Filter syntax (space separated, all terms must match):
+//! - `user:<name>`   user or hostname contains `<name>`
+//! - `after:<date>`  started at or after `<date>` (`YYYY-MM-DD[ HH:MM]`, `T` also separates)
+//! - `before:<date>` started before `<date>` (same forms)
+//! - anything else   description contains the word (case-insensitive)
+
+use crate::model::Operation;
+
+/// Parsed operation filter
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OperationFilter {
+    /// Original filter text (shown in the title)
+    pub query: String,
+    user: Option<String>,
+    after: Option<String>,
+    before: Option<String>,
+    keywords: Vec<String>,
+}
+
+impl OperationFilter {
+    /// Parse filter text
+    pub fn parse(query: &str) -> Self {
+        let mut filter = Self {
+            query: query.trim().to_string(),
+            ..Self::default()
+        };
+        let mut terms = query.split_whitespace().peekable();
+        while let Some(term) = terms.next() {
+            // `after:2026-02-01 14:30` keeps the time with its date
+            let mut date_term = |date: &str| {
+                let mut date = date.replace('T', " ");
+                if !date.contains(' ')
+                    && let Some(time) = terms.next_if(|next| is_time(next))
+                {
+                    date.push(' ');
+                    date.push_str(time);
+                }
+                date
+            };
+            if let Some(user) = term.strip_prefix("user:") {
+                filter.user = Some(user.to_lowercase());
+            } else if let Some(date) = term.strip_prefix("after:") {
+                filter.after = Some(date_term(date));
+            } else if let Some(date) = term.strip_prefix("before:") {
+                filter.before = Some(date_term(date));
+            } else {
+                filter.keywords.push(term.to_lowercase());
+            }
+        }
+        filter
+    }
+
+    /// Whether the filter has no terms
+    pub fn is_empty(&self) -> bool {
+        self.user.is_none()
+            && self.after.is_none()
+            && self.before.is_none()
+            && self.keywords.is_empty()
+    }
+
+    /// Whether an operation matches all filter terms
+    ///
+    /// Graph-only rows never match. Date terms compare the absolute start
+    /// time as text, so partial dates (`2026-02`) work as prefixes.
+    pub fn matches(&self, op: &Operation) -> bool {
+        if op.is_graph_only {
+            return false;
+        }
+        if let Some(ref user) = self.user
+            && !op.user.to_lowercase().contains(user.as_str())
+        {
+            return false;
+        }
+        if let Some(ref after) = self.after
+            && (op.started_at.is_empty() || op.started_at.as_str() < after.as_str())
+        {
+            return false;
+        }
+        if let Some(ref before) = self.before
+            && (op.started_at.is_empty() || op.started_at.as_str() >= before.as_str())
+        {
+            return false;
+        }
+        let description = op.description.to_lowercase();
+        self.keywords
+            .iter()
+            .all(|k| description.contains(k.as_str()))
+    }
+}
+
+/// `HH:MM` or `HH:MM:SS`, the time part of a date term
+fn is_time(term: &str) -> bool {
+    term.split(':').count() >= 2
+        && term
+            .split(':')
+            .all(|part| part.len() == 2 && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(user: &str, started_at: &str, description: &str) -> Operation {
+        Operation {
+            id: "abc123def456".to_string(),
+            user: user.to_string(),
+            started_at: started_at.to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_terms() {
+        let filter = OperationFilter::parse("user:Alice after:2026-02-01 rebase  commit");
+        assert_eq!(filter.user.as_deref(), Some("alice"));
+        assert_eq!(filter.after.as_deref(), Some("2026-02-01"));
+        assert_eq!(filter.keywords, vec!["rebase", "commit"]);
+        assert!(!filter.is_empty());
+        assert!(OperationFilter::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn test_matches_keywords_and_user() {
+        let filter = OperationFilter::parse("user:host2 REBASE");
+        assert!(filter.matches(&op("bob@host2", "", "rebase commit abc")));
+        assert!(!filter.matches(&op("bob@host1", "", "rebase commit abc")));
+        assert!(!filter.matches(&op("bob@host2", "", "describe commit abc")));
+    }
+
+    #[test]
+    fn test_matches_date_range() {
+        let filter = OperationFilter::parse("after:2026-02-01 before:2026-02-03");
+        assert!(filter.matches(&op("a@h", "2026-02-01 00:00:00", "x")));
+        assert!(filter.matches(&op("a@h", "2026-02-02 23:59:59", "x")));
+        assert!(!filter.matches(&op("a@h", "2026-02-03 00:00:00", "x")));
+        assert!(!filter.matches(&op("a@h", "2026-01-31 12:00:00", "x")));
+        assert!(!filter.matches(&op("a@h", "", "x")));
+    }
+
+    #[test]
+    fn test_date_terms_take_a_separate_time() {
+        let filter = OperationFilter::parse("after:2026-02-01 14:30 rebase");
+        assert_eq!(filter.after.as_deref(), Some("2026-02-01 14:30"));
+        assert_eq!(filter.keywords, vec!["rebase"]);
+        assert!(filter.matches(&op("a@h", "2026-02-01 14:30:00", "rebase")));
+        assert!(!filter.matches(&op("a@h", "2026-02-01 14:29:59", "rebase")));
+
+        let filter = OperationFilter::parse("before:2026-02-01T09:00 12:00");
+        assert_eq!(filter.before.as_deref(), Some("2026-02-01 09:00"));
+        assert_eq!(filter.keywords, vec!["12:00"]);
+    }
+
+    #[test]
+    fn test_graph_only_never_matches() {
+        let row = Operation {
+            graph_prefix: "├─╮".to_string(),
+            is_graph_only: true,
+            ..Default::default()
+        };
+        assert!(!OperationFilter::default().matches(&row));
+    }
+}
@@ -8,6 +8,9 @@ use crate::keys;
 impl OperationView {
     /// Handle key input
     pub fn handle_key(&mut self, key: KeyEvent) -> OperationAction {
+        if self.is_filter_input() {
+            return self.handle_filter_input_key(key);
+        }
         match key.code {
             // Navigation
             k if keys::is_move_down(k) => {
@@ -27,6 +30,21 @@ impl OperationView {
                 OperationAction::None
             }
 
+            KeyCode::Char('f') => OperationAction::ToggleFollow,
+            KeyCode::Char('D') => OperationAction::OpenDivergence,
+            k if k == keys::YANK => OperationAction::Yank,
+            k if k == keys::SORT_CYCLE => OperationAction::ToggleSort,
+            k if k == keys::RANGE_MARK => {
+                self.toggle_range_mark();
+                OperationAction::None
+            }
+            k if k == keys::ABANDON => OperationAction::Abandon,
+            k if k == keys::OP_GC => OperationAction::Gc,
+            k if k == keys::SEARCH_INPUT => {
+                self.start_filter_input();
+                OperationAction::None
+            }
+
             // Actions
             KeyCode::Enter => {
                 if let Some(op) = self.selected_operation() {
@@ -43,4 +61,20 @@ impl OperationView {
             _ => OperationAction::None,
         }
     }
+
+    fn handle_filter_input_key(&mut self, key: KeyEvent) -> OperationAction {
+        match key.code {
+            KeyCode::Esc => self.cancel_filter_input(),
+            KeyCode::Enter => {
+                let query = self.filter_input.take().unwrap_or_default();
+                self.set_filter(query.text());
+            }
+            _ => {
+                if let Some(ref mut input) = self.filter_input {
+                    input.handle_key(key);
+                }
+            }
+        }
+        OperationAction::None
+    }
 }
@@ -1,10 +1,19 @@
 //! Operation History View for displaying jj operation log
 
+mod filter;
 mod input;
 mod render;
 
+pub use filter::OperationFilter;
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
 use crate::model::Operation;
-use crate::ui::navigation;
+use crate::ui::components::LineInput;
+
+/// Interval between op log polls in follow mode
+pub const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(2);
 
 /// Action returned by the Operation View after handling input
 #[derive(Debug, Clone)]
@@ -15,17 +24,63 @@ pub enum OperationAction {
     Back,
     /// Restore to selected operation (returns operation ID)
     Restore(String),
+    /// Toggle follow mode (live tail of the op log)
+    ToggleFollow,
+    /// Open the Divergent Operations View
+    OpenDivergence,
+    /// Open the yank (copy to clipboard) menu for the selected operation
+    Yank,
+    /// Toggle chronological / grouped-by-type order
+    ToggleSort,
+    /// Abandon the selected operation, its ancestors or the marked range
+    Abandon,
+    /// Garbage-collect abandoned operations and unreachable objects
+    Gc,
+}
+
+/// Row order of the Operation View (`S` toggles)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OperationSort {
+    /// The op log graph, newest first
+    #[default]
+    Chronological,
+    /// Operations grouped by type (first word of the description),
+    /// newest first within each group
+    ByType,
+}
+
+/// Type of an operation: first word of its description, lowercased
+fn operation_type(op: &Operation) -> String {
+    op.description
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
 }
 
 /// Operation History View state
 #[derive(Debug)]
 pub struct OperationView {
-    /// List of operations from jj op log
+    /// Rows from jj op log (operations and graph-only lines)
     pub(super) operations: Vec<Operation>,
-    /// Selected operation index
+    /// Selected row index (into `operations`, always a selectable row)
     pub(super) selected: usize,
     /// Scroll offset for long lists
     pub(super) scroll_offset: usize,
+    /// Active filter (empty = show the full graph)
+    pub(super) filter: OperationFilter,
+    /// Filter text being edited (Some while the filter input bar is open)
+    pub(super) filter_input: Option<LineInput>,
+    /// Follow mode: poll the op log and merge in new operations
+    pub follow: bool,
+    /// Operations that arrived while following and have not been selected yet
+    pub(super) unseen: HashSet<String>,
+    /// Time of the last follow-mode poll
+    last_poll: Option<Instant>,
+    /// Row order
+    pub(super) sort: OperationSort,
+    /// Operation marked with Space as the other end of an abandon range
+    pub(super) range_mark: Option<String>,
 }
 
 impl Default for OperationView {
@@ -41,43 +96,271 @@ impl OperationView {
             operations: Vec::new(),
             selected: 0,
             scroll_offset: 0,
+            filter: OperationFilter::default(),
+            filter_input: None,
+            follow: false,
+            unseen: HashSet::new(),
+            last_poll: None,
+            sort: OperationSort::default(),
+            range_mark: None,
         }
     }
 
     /// Set the operations to display
+    ///
+    /// The active filter is kept so a refresh does not reset it.
     pub fn set_operations(&mut self, operations: Vec<Operation>) {
         self.operations = operations;
-        self.selected = 0;
-        self.scroll_offset = 0;
+        self.unseen.clear();
+        // Abandon rewrites the ids of later operations
+        self.range_mark = None;
+        self.select_first();
+    }
+
+    /// Merge a freshly polled op log, keeping the selection
+    ///
+    /// Operations not present before are marked unseen (highlighted until
+    /// selected). Returns the number of new operations.
+    pub fn merge_operations(&mut self, operations: Vec<Operation>) -> usize {
+        if self.operations.is_empty() {
+            self.set_operations(operations);
+            return 0;
+        }
+
+        let known: HashSet<&str> = self.operations.iter().map(|op| op.id.as_str()).collect();
+        let new_ids: Vec<String> = operations
+            .iter()
+            .filter(|op| !op.is_graph_only && !known.contains(op.id.as_str()))
+            .map(|op| op.id.clone())
+            .collect();
+        if new_ids.is_empty() {
+            return 0;
+        }
+
+        let selected_id = self.selected_operation().map(|op| op.id.clone());
+        self.operations = operations;
+        self.unseen.extend(new_ids.iter().cloned());
+        match selected_id.and_then(|id| self.operations.iter().position(|op| op.id == id)) {
+            Some(idx) if self.is_selectable(idx) => self.selected = idx,
+            _ => self.select_first(),
+        }
+        new_ids.len()
+    }
+
+    /// Whether the operation at `idx` arrived while following and is unseen
+    pub(super) fn is_unseen(&self, idx: usize) -> bool {
+        self.operations
+            .get(idx)
+            .is_some_and(|op| self.unseen.contains(&op.id))
+    }
+
+    /// Whether a follow-mode poll is due
+    pub fn follow_poll_due(&self, now: Instant) -> bool {
+        self.follow
+            && self
+                .last_poll
+                .is_none_or(|last| now.duration_since(last) >= FOLLOW_POLL_INTERVAL)
+    }
+
+    /// Record that the op log was just polled
+    pub fn mark_polled(&mut self, now: Instant) {
+        self.last_poll = Some(now);
+    }
+
+    /// Toggle follow mode (returns the new state)
+    pub fn toggle_follow(&mut self) -> bool {
+        self.follow = !self.follow;
+        self.last_poll = None;
+        if !self.follow {
+            self.unseen.clear();
+        }
+        self.follow
+    }
+
+    /// Mark the selected operation as viewed
+    fn mark_selected_seen(&mut self) {
+        if let Some(op) = self.operations.get(self.selected) {
+            self.unseen.remove(&op.id);
+        }
     }
 
     /// Get the currently selected operation
     pub fn selected_operation(&self) -> Option<&Operation> {
-        self.operations.get(self.selected)
+        self.operations
+            .get(self.selected)
+            .filter(|_| self.is_selectable(self.selected))
+    }
+
+    /// Whether the row at `idx` is an operation passing the filter
+    fn is_selectable(&self, idx: usize) -> bool {
+        self.operations.get(idx).is_some_and(|op| {
+            !op.is_graph_only && (self.filter.is_empty() || self.filter.matches(op))
+        })
+    }
+
+    /// Row indices to display, in display order
+    ///
+    /// Without a filter and in chronological order this is every row,
+    /// including graph-only lines. With a filter only matching operations
+    /// are shown. Grouped by type, the graph is dropped and operations are
+    /// stable-sorted by type.
+    pub(super) fn visible_rows(&self) -> Vec<usize> {
+        if self.filter.is_empty() && self.sort == OperationSort::Chronological {
+            return (0..self.operations.len()).collect();
+        }
+        let mut rows: Vec<usize> = (0..self.operations.len())
+            .filter(|&i| self.is_selectable(i))
+            .collect();
+        if self.sort == OperationSort::ByType {
+            rows.sort_by_cached_key(|&i| operation_type(&self.operations[i]));
+        }
+        rows
+    }
+
+    /// Selectable rows in display order
+    fn selectable_rows(&self) -> Vec<usize> {
+        self.visible_rows()
+            .into_iter()
+            .filter(|&i| self.is_selectable(i))
+            .collect()
+    }
+
+    /// Toggle between chronological and grouped-by-type order
+    ///
+    /// The selected operation stays selected.
+    pub fn toggle_sort(&mut self) -> OperationSort {
+        self.sort = match self.sort {
+            OperationSort::Chronological => OperationSort::ByType,
+            OperationSort::ByType => OperationSort::Chronological,
+        };
+        self.scroll_offset = 0;
+        self.sort
+    }
+
+    /// Number of operations passing the filter
+    pub fn match_count(&self) -> usize {
+        (0..self.operations.len())
+            .filter(|&i| self.is_selectable(i))
+            .count()
+    }
+
+    /// Move the selection `delta` selectable rows in display order
+    fn move_selection(&mut self, delta: isize) {
+        let rows = self.selectable_rows();
+        let Some(pos) = rows.iter().position(|&i| i == self.selected) else {
+            return;
+        };
+        if let Some(&idx) = pos
+            .checked_add_signed(delta)
+            .and_then(|target| rows.get(target))
+        {
+            self.selected = idx;
+            self.mark_selected_seen();
+        }
     }
 
     /// Move selection up
     pub fn select_prev(&mut self) {
-        self.selected = navigation::select_prev(self.selected);
+        self.move_selection(-1);
     }
 
     /// Move selection down
     pub fn select_next(&mut self) {
-        let max = self.operations.len().saturating_sub(1);
-        self.selected = navigation::select_next(self.selected, max);
+        self.move_selection(1);
     }
 
     /// Go to first operation
     pub fn select_first(&mut self) {
-        self.selected = 0;
+        self.selected = self.selectable_rows().first().copied().unwrap_or(0);
         self.scroll_offset = 0;
+        self.mark_selected_seen();
     }
 
     /// Go to last operation
     pub fn select_last(&mut self) {
-        if !self.operations.is_empty() {
-            self.selected = self.operations.len() - 1;
+        if let Some(&idx) = self.selectable_rows().last() {
+            self.selected = idx;
+            self.mark_selected_seen();
+        }
+    }
+
+    /// Whether the filter input bar is open
+    pub fn is_filter_input(&self) -> bool {
+        self.filter_input.is_some()
+    }
+
+    /// Open the filter input bar, pre-filled with the current filter
+    pub fn start_filter_input(&mut self) {
+        self.filter_input = Some(LineInput::new(self.filter.query.clone()));
+    }
+
+    /// Close the filter input bar without changing the filter
+    pub fn cancel_filter_input(&mut self) {
+        self.filter_input = None;
+    }
+
+    /// Apply a filter (empty text clears it)
+    pub fn set_filter(&mut self, query: &str) {
+        self.filter = OperationFilter::parse(query);
+        self.select_first();
+    }
+
+    /// Mark the selected operation as a range end, or clear the mark
+    ///
+    /// Returns the marked operation id.
+    pub fn toggle_range_mark(&mut self) -> Option<&str> {
+        let id = self.selected_operation().map(|op| op.id.clone());
+        self.range_mark = if self.range_mark == id { None } else { id };
+        self.range_mark.as_deref()
+    }
+
+    /// Operation marked as a range end
+    pub fn range_mark(&self) -> Option<&Operation> {
+        self.operation(self.range_mark.as_deref()?)
+    }
+
+    /// Whether the row at `idx` is the marked range end
+    pub(super) fn is_range_mark(&self, idx: usize) -> bool {
+        self.operations
+            .get(idx)
+            .is_some_and(|op| self.range_mark.as_deref() == Some(op.id.as_str()))
+    }
+
+    /// Loaded operation with `id`
+    pub fn operation(&self, id: &str) -> Option<&Operation> {
+        self.operations.iter().find(|op| op.id == id)
+    }
+
+    /// Row index of the operation with `id` (smaller is newer)
+    pub fn operation_index(&self, id: &str) -> Option<usize> {
+        self.operations.iter().position(|op| op.id == id)
+    }
+
+    /// Number of loaded operations from `newer` back to `older`, both included
+    ///
+    /// `older = None` counts to the end of the loaded list. The count is an
+    /// estimate for concurrent operations (the list is the flattened graph).
+    pub fn operations_between(&self, newer: &str, older: Option<&str>) -> usize {
+        let ops = self.operations.iter().filter(|op| !op.is_graph_only);
+        let mut count = 0;
+        for op in ops.skip_while(|op| op.id != newer) {
+            count += 1;
+            if Some(op.id.as_str()) == older {
+                break;
+            }
         }
+        count
+    }
+
+    /// Whether older operations exist beyond the loaded list
+    ///
+    /// The oldest operation of every repo is the all-zero root operation.
+    pub fn is_truncated(&self) -> bool {
+        self.operations
+            .iter()
+            .rev()
+            .find(|op| !op.is_graph_only)
+            .is_some_and(|op| op.id.chars().any(|c| c != '0'))
     }
 
     /// Get operation count for status display (test-only helper)
@@ -90,6 +373,7 @@ impl OperationView {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::keys;
     use crossterm::event::{KeyCode, KeyEvent};
 
     fn create_test_operations() -> Vec<Operation> {
@@ -100,6 +384,7 @@ mod tests {
                 timestamp: "5 minutes ago".to_string(),
                 description: "snapshot working copy".to_string(),
                 is_current: true,
+                ..Default::default()
             },
             Operation {
                 id: "xyz789uvw012".to_string(),
@@ -107,6 +392,7 @@ mod tests {
                 timestamp: "10 minutes ago".to_string(),
                 description: "describe commit abc".to_string(),
                 is_current: false,
+                ..Default::default()
             },
             Operation {
                 id: "def456ghi789".to_string(),
@@ -114,6 +400,7 @@ mod tests {
                 timestamp: "1 hour ago".to_string(),
                 description: "new empty commit".to_string(),
                 is_current: false,
+                ..Default::default()
             },
         ]
     }
@@ -196,4 +483,188 @@ mod tests {
         let action = view.handle_key(KeyEvent::from(KeyCode::Char('q')));
         assert!(matches!(action, OperationAction::Back));
     }
+
+    fn create_graph_operations() -> Vec<Operation> {
+        vec![
+            Operation {
+                id: "abc123def456".to_string(),
+                user: "alice@host1".to_string(),
+                description: "describe commit abc".to_string(),
+                started_at: "2026-02-02 11:00:00".to_string(),
+                is_current: true,
+                graph_prefix: "@  ".to_string(),
+                ..Default::default()
+            },
+            Operation {
+                graph_prefix: "├─╮".to_string(),
+                is_graph_only: true,
+                ..Default::default()
+            },
+            Operation {
+                id: "xyz789uvw012".to_string(),
+                user: "bob@host2".to_string(),
+                description: "rebase commit def".to_string(),
+                started_at: "2026-02-01 09:00:00".to_string(),
+                graph_prefix: "│ ○  ".to_string(),
+                ..Default::default()
+            },
+            Operation {
+                id: "def456ghi789".to_string(),
+                user: "alice@host1".to_string(),
+                description: "new empty commit".to_string(),
+                started_at: "2026-01-30 09:00:00".to_string(),
+                graph_prefix: "○ │  ".to_string(),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_navigation_skips_graph_rows() {
+        let mut view = OperationView::new();
+        view.set_operations(create_graph_operations());
+        assert_eq!(view.selected, 0);
+
+        view.select_next();
+        assert_eq!(view.selected, 2);
+        view.select_prev();
+        assert_eq!(view.selected, 0);
+        view.select_last();
+        assert_eq!(view.selected, 3);
+        assert_eq!(view.visible_rows().len(), 4);
+    }
+
+    #[test]
+    fn test_filter_hides_graph_and_non_matching() {
+        let mut view = OperationView::new();
+        view.set_operations(create_graph_operations());
+
+        view.set_filter("user:alice");
+        assert_eq!(view.visible_rows(), vec![0, 3]);
+        assert_eq!(view.match_count(), 2);
+        view.select_next();
+        assert_eq!(view.selected_operation().unwrap().id, "def456ghi789");
+
+        view.set_filter("rebase");
+        assert_eq!(view.selected_operation().unwrap().id, "xyz789uvw012");
+
+        view.set_filter("before:2026-01-01");
+        assert!(view.selected_operation().is_none());
+        assert!(view.visible_rows().is_empty());
+
+        view.set_filter("");
+        assert_eq!(view.visible_rows().len(), 4);
+    }
+
+    #[test]
+    fn test_filter_input_keys() {
+        let mut view = OperationView::new();
+        view.set_operations(create_graph_operations());
+
+        view.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        assert!(view.is_filter_input());
+        for c in "after:2026-02-01".chars() {
+            view.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        // 'q' is text while typing, not Back
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('q')));
+        assert!(matches!(action, OperationAction::None));
+        view.handle_key(KeyEvent::from(KeyCode::Backspace));
+        view.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert!(!view.is_filter_input());
+        assert_eq!(view.filter.query, "after:2026-02-01");
+        assert_eq!(view.match_count(), 2);
+
+        // Esc cancels editing and keeps the applied filter
+        view.handle_key(KeyEvent::from(KeyCode::Char('/')));
+        view.handle_key(KeyEvent::from(KeyCode::Char('x')));
+        view.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(view.filter.query, "after:2026-02-01");
+    }
+
+    #[test]
+    fn test_merge_operations_marks_new_and_keeps_selection() {
+        let mut view = OperationView::new();
+        view.set_operations(create_test_operations());
+        view.select_next();
+        assert_eq!(view.selected_operation().unwrap().id, "xyz789uvw012");
+
+        let mut polled = create_test_operations();
+        polled[0].is_current = false;
+        polled.insert(
+            0,
+            Operation {
+                id: "fff000fff000".to_string(),
+                description: "rebase from another terminal".to_string(),
+                is_current: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(view.merge_operations(polled.clone()), 1);
+        assert_eq!(view.selected_operation().unwrap().id, "xyz789uvw012");
+        assert!(view.is_unseen(0));
+
+        // Same data again: nothing new
+        assert_eq!(view.merge_operations(polled), 0);
+
+        // Viewing the row clears the highlight
+        view.select_first();
+        assert!(!view.is_unseen(0));
+        assert!(view.unseen.is_empty());
+    }
+
+    #[test]
+    fn test_follow_poll_due() {
+        let mut view = OperationView::new();
+        let now = Instant::now();
+        assert!(!view.follow_poll_due(now));
+
+        assert!(view.toggle_follow());
+        assert!(view.follow_poll_due(now));
+        view.mark_polled(now);
+        assert!(!view.follow_poll_due(now));
+        assert!(view.follow_poll_due(now + FOLLOW_POLL_INTERVAL));
+
+        assert!(!view.toggle_follow());
+        assert!(!view.follow_poll_due(now + FOLLOW_POLL_INTERVAL));
+    }
+
+    #[test]
+    fn test_handle_key_follow() {
+        let mut view = OperationView::new();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert!(matches!(action, OperationAction::ToggleFollow));
+    }
+
+    #[test]
+    fn test_toggle_sort_groups_by_type() {
+        let mut ops = create_test_operations();
+        ops.push(Operation {
+            id: "ghi789jkl012".to_string(),
+            description: "describe commit def".to_string(),
+            ..Default::default()
+        });
+        let mut view = OperationView::new();
+        view.set_operations(ops);
+        view.select_next();
+        assert_eq!(view.selected, 1);
+
+        let action = view.handle_key(KeyEvent::from(keys::SORT_CYCLE));
+        assert!(matches!(action, OperationAction::ToggleSort));
+        assert_eq!(view.toggle_sort(), OperationSort::ByType);
+        // describe, describe, new, snapshot; newest first within a type
+        assert_eq!(view.visible_rows(), [1, 3, 2, 0]);
+        assert_eq!(view.selected, 1);
+
+        view.select_next();
+        assert_eq!(view.selected, 3);
+        view.select_last();
+        assert_eq!(view.selected, 0);
+        view.select_first();
+        assert_eq!(view.selected, 1);
+
+        assert_eq!(view.toggle_sort(), OperationSort::Chronological);
+        assert_eq!(view.visible_rows(), [0, 1, 2, 3]);
+    }
 }
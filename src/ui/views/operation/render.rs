@@ -2,20 +2,78 @@
 
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::Paragraph,
 };
 
-use super::OperationView;
+use super::{OperationSort, OperationView};
 use crate::model::{Notification, Operation};
 use crate::ui::{components, navigation, theme};
 
+/// Height of the detail pane (including borders)
+const DETAIL_HEIGHT: u16 = 8;
+
+/// Minimum total height to show the detail pane below the list
+const DETAIL_MIN_AREA_HEIGHT: u16 = 16;
+
 impl OperationView {
     /// Render the operation view with optional notification in title bar
+    ///
+    /// Layout: operation graph, detail pane for the selected operation
+    /// (when tall enough), and the filter input bar while editing.
     pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
-        let title = Line::from(" Operation History ").bold().cyan().centered();
+        let input_height = if self.is_filter_input() { 3 } else { 0 };
+        let detail_height = if area.height >= DETAIL_MIN_AREA_HEIGHT {
+            DETAIL_HEIGHT
+        } else {
+            0
+        };
+        let chunks = Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(detail_height),
+            Constraint::Length(input_height),
+        ])
+        .split(area);
+
+        self.render_list(frame, chunks[0], notification);
+        if detail_height > 0 {
+            self.render_detail(frame, chunks[1]);
+        }
+        if input_height > 0 {
+            self.render_filter_input(frame, chunks[2]);
+        }
+    }
+
+    fn render_list(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let title_text = if self.filter.is_empty() {
+            " Operation History ".to_string()
+        } else {
+            format!(
+                " Operation History [{}] ({} matches) ",
+                self.filter.query,
+                self.match_count()
+            )
+        };
+        let mut title = Line::from(title_text).bold().cyan().centered();
+        if self.sort == OperationSort::ByType {
+            title.push_span(Span::raw("[by type] "));
+        }
+        if self.follow {
+            title.push_span(Span::styled(
+                "[follow] ",
+                Style::default()
+                    .fg(theme::operation_view::FOLLOW)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if !self.unseen.is_empty() {
+            title.push_span(Span::styled(
+                format!("{} new ", self.unseen.len()),
+                Style::default().fg(theme::operation_view::NEW_OPERATION),
+            ));
+        }
 
         // Build notification line for title bar
         let title_width = title.width();
@@ -27,8 +85,14 @@ impl OperationView {
 
         let block = components::bordered_block_with_notification(title, notif_line);
 
-        if self.operations.is_empty() {
-            let paragraph = Paragraph::new("No operations found").block(block);
+        let rows = self.visible_rows();
+        if rows.is_empty() {
+            let message = if self.filter.is_empty() {
+                "No operations found"
+            } else {
+                "No operations match the filter"
+            };
+            let paragraph = Paragraph::new(message).block(block);
             frame.render_widget(paragraph, area);
             return;
         }
@@ -39,57 +103,90 @@ impl OperationView {
         }
 
         // Calculate scroll offset to keep selection visible
-        let scroll_offset = self.calculate_scroll_offset(inner_height);
-
-        // Build lines
-        let mut lines: Vec<Line> = Vec::new();
-        for (idx, op) in self.operations.iter().enumerate().skip(scroll_offset) {
-            if lines.len() >= inner_height {
-                break;
-            }
-
-            let is_selected = idx == self.selected;
-            let line = self.build_operation_line(op, is_selected);
-            lines.push(line);
-        }
+        let selected_row = rows.iter().position(|&i| i == self.selected).unwrap_or(0);
+        let scroll_offset =
+            navigation::adjust_scroll(selected_row, self.scroll_offset, inner_height);
+
+        let lines: Vec<Line> = rows
+            .iter()
+            .skip(scroll_offset)
+            .take(inner_height)
+            .map(|&idx| {
+                self.build_operation_line(
+                    &self.operations[idx],
+                    idx == self.selected,
+                    self.is_unseen(idx),
+                    self.is_range_mark(idx),
+                )
+            })
+            .collect();
 
         let paragraph = Paragraph::new(lines).block(block);
         frame.render_widget(paragraph, area);
     }
 
-    /// Calculate scroll offset to keep selection visible (render time)
-    fn calculate_scroll_offset(&self, visible_height: usize) -> usize {
-        navigation::adjust_scroll(self.selected, self.scroll_offset, visible_height)
-    }
-
-    /// Build a line for an operation
-    fn build_operation_line(&self, op: &Operation, is_selected: bool) -> Line<'static> {
-        let is_current = op.is_current;
-
-        // Build the line with styled spans
-        let marker = if is_current { "@" } else { " " };
-        let marker_style = if is_current {
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD)
+    /// Build a line for an operation (or graph-only row)
+    ///
+    /// Unseen operations (arrived in follow mode) are highlighted; the
+    /// range mark gets a `[mark]` badge.
+    fn build_operation_line(
+        &self,
+        op: &Operation,
+        is_selected: bool,
+        is_unseen: bool,
+        is_marked: bool,
+    ) -> Line<'static> {
+        // Grouped rows are out of graph order, so the graph is dropped
+        let grouped = self.sort == OperationSort::ByType;
+        let mut spans = if grouped {
+            Vec::new()
         } else {
-            Style::default()
+            build_graph_spans(&op.graph_prefix)
         };
+        if op.is_graph_only {
+            return Line::from(spans);
+        }
 
         let id_style = Style::default().fg(Color::Magenta);
         let time_style = Style::default().fg(Color::Yellow);
-        let desc_style = Style::default().fg(Color::White);
+        let user_style = Style::default().fg(Color::DarkGray);
+        let desc_style = if is_unseen {
+            Style::default()
+                .fg(theme::operation_view::NEW_OPERATION)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
 
-        let mut line = Line::from(vec![
-            Span::styled(marker.to_string(), marker_style),
-            Span::raw("  "),
+        // Without a graph prefix (old output), mark the current operation explicitly
+        if op.graph_prefix.is_empty() || grouped {
+            let marker = if op.is_current { "@  " } else { "   " };
+            spans.push(Span::styled(
+                marker,
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if is_marked {
+            spans.push(Span::styled(
+                "[mark] ",
+                Style::default()
+                    .fg(theme::operation_view::RANGE_MARK)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        spans.extend([
             Span::styled(op.short_id().to_string(), id_style),
             Span::raw("  "),
             Span::styled(op.timestamp.clone(), time_style),
             Span::raw("  "),
+            Span::styled(op.username().to_string(), user_style),
+            Span::raw("  "),
             Span::styled(op.description.clone(), desc_style),
         ]);
 
+        let mut line = Line::from(spans);
         if is_selected {
             line = line.style(
                 Style::default()
@@ -101,4 +198,98 @@ impl OperationView {
 
         line
     }
+
+    /// Render metadata for the selected operation
+    fn render_detail(&self, frame: &mut Frame, area: Rect) {
+        let block = components::bordered_block(Line::from(" Details ").bold().cyan());
+        let Some(op) = self.selected_operation() else {
+            frame.render_widget(Paragraph::new("").block(block), area);
+            return;
+        };
+
+        let label = Style::default().fg(Color::DarkGray);
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled("Operation: ", label),
+                Span::styled(op.id.clone(), Style::default().fg(Color::Magenta)),
+                Span::styled(
+                    if op.is_current { "  (current)" } else { "" },
+                    Style::default().fg(Color::Green),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("User:      ", label),
+                Span::raw(op.username().to_string()),
+                Span::styled("  Host: ", label),
+                Span::raw(op.hostname().to_string()),
+            ]),
+            Line::from(vec![
+                Span::styled("Time:      ", label),
+                Span::raw(op.started_at.clone()),
+                Span::styled(format!("  ({})", op.timestamp), label),
+            ]),
+            Line::from(vec![
+                Span::styled("Args:      ", label),
+                Span::styled(
+                    op.args().unwrap_or("-").to_string(),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]),
+        ];
+        for tag in op.tags.iter().filter(|t| !t.starts_with("args: ")) {
+            lines.push(Line::from(vec![
+                Span::styled("Tag:       ", label),
+                Span::raw(tag.clone()),
+            ]));
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    /// Render the filter input bar
+    fn render_filter_input(&self, frame: &mut Frame, area: Rect) {
+        let available_width = area.width.saturating_sub(2) as usize;
+        let (text, cursor_pos) = self
+            .filter_input
+            .as_ref()
+            .map(|input| input.display("Filter: ", available_width))
+            .unwrap_or_default();
+
+        let title = Line::from(vec![
+            Span::raw(" "),
+            Span::styled("[Enter]", Style::default().fg(Color::Green)),
+            Span::raw(" Apply  "),
+            Span::styled("[Esc]", Style::default().fg(Color::Red)),
+            Span::raw(" Cancel  "),
+            Span::styled(
+                "user:<name> after:<date> before:<date> <keyword> ",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]);
+
+        let paragraph = Paragraph::new(text).block(components::bordered_block(title));
+        frame.render_widget(paragraph, area);
+
+        frame.set_cursor_position((area.x + cursor_pos as u16 + 1, area.y + 1));
+    }
+}
+
+/// Style the op log graph prefix (current operation node highlighted)
+fn build_graph_spans(prefix: &str) -> Vec<Span<'static>> {
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    prefix
+        .chars()
+        .map(|c| {
+            let style = if c == '@' {
+                Style::default()
+                    .fg(theme::log_view::WORKING_COPY_MARKER)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::log_view::GRAPH_LINE)
+            };
+            Span::styled(c.to_string(), style)
+        })
+        .collect()
 }
@@ -0,0 +1,22 @@
+//! Pager View key handling
+
+use crossterm::event::KeyEvent;
+
+use super::PagerView;
+use crate::keys;
+
+impl PagerView {
+    /// Handle key input (`q`/Esc are handled globally)
+    pub fn handle_key(&mut self, key: KeyEvent, visible_height: usize) {
+        let half = (visible_height / 2).max(1) as isize;
+        match key.code {
+            k if keys::is_move_down(k) => self.scroll_by(1, visible_height),
+            k if keys::is_move_up(k) => self.scroll_by(-1, visible_height),
+            keys::HALF_PAGE_DOWN => self.scroll_by(half, visible_height),
+            keys::HALF_PAGE_UP => self.scroll_by(-half, visible_height),
+            keys::GO_TOP => self.jump_to_top(),
+            keys::GO_BOTTOM => self.jump_to_bottom(visible_height),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Pager View for the output of a typed jj command
+//!
+//! Plain scrollable text; the command is kept so that a refresh runs it
+//! again.
+
+mod input;
+mod render;
+
+/// Pager View state
+#[derive(Debug, Default)]
+pub struct PagerView {
+    /// Arguments of the command whose output is shown (without `jj`)
+    command: Vec<String>,
+    /// Output lines
+    lines: Vec<String>,
+    /// First visible line
+    scroll_offset: usize,
+}
+
+impl PagerView {
+    /// Create a new empty Pager View
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the output of `command`, scrolled to the top
+    pub fn set_output(&mut self, command: Vec<String>, output: &str) {
+        self.command = command;
+        self.set_text(output);
+        self.scroll_offset = 0;
+    }
+
+    /// Replace the output of the same command, keeping the position
+    pub fn set_text(&mut self, output: &str) {
+        self.lines = output.lines().map(str::to_string).collect();
+        self.scroll_offset = self.scroll_offset.min(self.lines.len().saturating_sub(1));
+    }
+
+    /// Arguments of the shown command
+    pub fn command(&self) -> &[String] {
+        &self.command
+    }
+
+    /// Command line as typed, for the title
+    pub fn command_line(&self) -> String {
+        let mut line = String::from("jj");
+        for arg in &self.command {
+            line.push(' ');
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                line.push_str(&format!("'{}'", arg));
+            } else {
+                line.push_str(arg);
+            }
+        }
+        line
+    }
+
+    /// Output lines
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    fn max_scroll_offset(&self, visible_height: usize) -> usize {
+        self.lines.len().saturating_sub(visible_height)
+    }
+
+    /// Scroll by `delta` lines, clamped to the output
+    pub fn scroll_by(&mut self, delta: isize, visible_height: usize) {
+        let max = self.max_scroll_offset(visible_height);
+        self.scroll_offset = self.scroll_offset.saturating_add_signed(delta).min(max);
+    }
+
+    /// Jump to the top
+    pub fn jump_to_top(&mut self) {
+        self.scroll_offset = 0;
+    }
+
+    /// Jump to the bottom
+    pub fn jump_to_bottom(&mut self, visible_height: usize) {
+        self.scroll_offset = self.max_scroll_offset(visible_height);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view(count: usize) -> PagerView {
+        let output: String = (1..=count).map(|n| format!("line {}\n", n)).collect();
+        let mut view = PagerView::new();
+        view.set_output(
+            vec!["log".to_string(), "-r".to_string(), "a b".to_string()],
+            &output,
+        );
+        view
+    }
+
+    #[test]
+    fn test_scroll_is_clamped_to_output() {
+        let mut view = view(30);
+        view.scroll_by(100, 10);
+        assert_eq!(view.scroll_offset, 20);
+        view.scroll_by(-5, 10);
+        assert_eq!(view.scroll_offset, 15);
+        view.jump_to_top();
+        view.scroll_by(-1, 10);
+        assert_eq!(view.scroll_offset, 0);
+        view.jump_to_bottom(40);
+        assert_eq!(view.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_command_line_quotes_spaces() {
+        assert_eq!(view(1).command_line(), "jj log -r 'a b'");
+    }
+}
@@ -0,0 +1,59 @@
+//! Pager View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::Paragraph,
+};
+
+use super::PagerView;
+use crate::model::Notification;
+use crate::ui::components;
+
+impl PagerView {
+    /// Render the output with the command and position in the title
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let inner_height = area.height.saturating_sub(2) as usize;
+        let position = if self.lines.is_empty() {
+            String::new()
+        } else {
+            let last = (self.scroll_offset + inner_height).min(self.lines.len());
+            format!(
+                "[{}-{}/{}] ",
+                self.scroll_offset + 1,
+                last,
+                self.lines.len()
+            )
+        };
+        let title = Line::from(format!(" {} {}", self.command_line(), position))
+            .bold()
+            .cyan()
+            .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        if self.lines.is_empty() {
+            let paragraph = Paragraph::new("(no output)".fg(Color::DarkGray)).block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .skip(self.scroll_offset)
+            .take(inner_height)
+            .map(|line| Line::raw(line.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
@@ -0,0 +1,46 @@
+//! Remote View key handling
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{RemoteAction, RemoteView};
+use crate::keys;
+
+impl RemoteView {
+    /// Handle key input
+    pub fn handle_key(&mut self, key: KeyEvent) -> RemoteAction {
+        match key.code {
+            k if keys::is_move_down(k) => {
+                self.select_next();
+                RemoteAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.select_prev();
+                RemoteAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.select_first();
+                RemoteAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.select_last();
+                RemoteAction::None
+            }
+            KeyCode::Char('a') => RemoteAction::StartAdd,
+            KeyCode::Char('I') => RemoteAction::GitImport,
+            KeyCode::Char('E') => RemoteAction::GitExport,
+            code => {
+                let Some(name) = self.selected_remote().map(|r| r.name.clone()) else {
+                    return RemoteAction::None;
+                };
+                match code {
+                    k if k == keys::BOOKMARK_DELETE => RemoteAction::Remove(name),
+                    KeyCode::Char('r') => RemoteAction::StartRename(name),
+                    KeyCode::Char('e') => RemoteAction::StartSetUrl(name),
+                    k if k == keys::FETCH => RemoteAction::Fetch(name),
+                    k if k == keys::PUSH => RemoteAction::Push(name),
+                    _ => RemoteAction::None,
+                }
+            }
+        }
+    }
+}
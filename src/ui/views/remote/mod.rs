@@ -0,0 +1,187 @@
+//! Remote View for managing git remotes
+
+mod input;
+mod render;
+
+use crate::model::RemoteInfo;
+use crate::ui::navigation;
+
+/// Action returned by the Remote View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteAction {
+    /// No action needed
+    None,
+    /// Add new remote (open name/URL input dialog)
+    StartAdd,
+    /// Remove selected remote (open confirm dialog)
+    Remove(String),
+    /// Rename selected remote (open input dialog)
+    StartRename(String),
+    /// Change the URL of the selected remote (open input dialog)
+    StartSetUrl(String),
+    /// Fetch from the selected remote
+    Fetch(String),
+    /// Push tracked bookmarks to the selected remote
+    Push(String),
+    /// Import git refs into jj (`jj git import`)
+    GitImport,
+    /// Export jj bookmarks to git refs (`jj git export`)
+    GitExport,
+}
+
+/// Remote View state
+#[derive(Debug, Default)]
+pub struct RemoteView {
+    /// All remotes
+    remotes: Vec<RemoteInfo>,
+    /// Selected index
+    selected: usize,
+    /// Scroll offset
+    scroll_offset: usize,
+    /// Bookmarks differing between jj and git; None unless colocated
+    git_sync: Option<Vec<String>>,
+}
+
+impl RemoteView {
+    /// Create a new Remote View
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the remotes to display, keeping the selection on the same name
+    pub fn set_remotes(&mut self, remotes: Vec<RemoteInfo>) {
+        let selected_name = self.selected_remote().map(|r| r.name.clone());
+        self.remotes = remotes;
+        self.selected = selected_name
+            .and_then(|name| self.remotes.iter().position(|r| r.name == name))
+            .unwrap_or(0);
+        self.scroll_offset = 0;
+    }
+
+    /// Set the jj/git divergence shown in the header (colocated repos)
+    pub fn set_git_sync(&mut self, diverged: Option<Vec<String>>) {
+        self.git_sync = diverged;
+    }
+
+    /// Get the currently selected remote
+    pub fn selected_remote(&self) -> Option<&RemoteInfo> {
+        self.remotes.get(self.selected)
+    }
+
+    /// Total number of remotes
+    pub fn remote_count(&self) -> usize {
+        self.remotes.len()
+    }
+
+    /// Move selection to next remote
+    pub fn select_next(&mut self) {
+        let max = self.remotes.len().saturating_sub(1);
+        self.selected = navigation::select_next(self.selected, max);
+    }
+
+    /// Move selection to previous remote
+    pub fn select_prev(&mut self) {
+        self.selected = navigation::select_prev(self.selected);
+    }
+
+    /// Go to first remote
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Go to last remote
+    pub fn select_last(&mut self) {
+        if !self.remotes.is_empty() {
+            self.selected = self.remotes.len() - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn make_remote(name: &str, url: &str) -> RemoteInfo {
+        RemoteInfo {
+            name: name.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    fn create_test_remotes() -> Vec<RemoteInfo> {
+        vec![
+            make_remote("origin", "git@github.com:user/repo.git"),
+            make_remote("upstream", "https://github.com/org/repo.git"),
+        ]
+    }
+
+    fn press(view: &mut RemoteView, c: char) -> RemoteAction {
+        view.handle_key(KeyEvent::from(KeyCode::Char(c)))
+    }
+
+    #[test]
+    fn test_set_remotes_keeps_selected_name() {
+        let mut view = RemoteView::new();
+        view.set_remotes(create_test_remotes());
+        view.select_next();
+
+        // "upstream" moves to the front after a rename of "origin"
+        view.set_remotes(vec![
+            make_remote("fork", "git@github.com:user/repo.git"),
+            make_remote("upstream", "https://github.com/org/repo.git"),
+            make_remote("zz", ""),
+        ]);
+        assert_eq!(view.selected_remote().unwrap().name, "upstream");
+
+        view.set_remotes(vec![make_remote("fork", "")]);
+        assert_eq!(view.selected_remote().unwrap().name, "fork");
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut view = RemoteView::new();
+        view.set_remotes(create_test_remotes());
+        view.select_last();
+        assert_eq!(view.selected_remote().unwrap().name, "upstream");
+        view.select_next();
+        assert_eq!(view.selected_remote().unwrap().name, "upstream");
+        view.select_first();
+        assert_eq!(view.selected_remote().unwrap().name, "origin");
+    }
+
+    #[test]
+    fn test_handle_key_actions_target_selected_remote() {
+        let mut view = RemoteView::new();
+        view.set_remotes(create_test_remotes());
+        view.select_next();
+        let upstream = "upstream".to_string();
+
+        assert_eq!(press(&mut view, 'a'), RemoteAction::StartAdd);
+        assert_eq!(
+            press(&mut view, 'D'),
+            RemoteAction::Remove(upstream.clone())
+        );
+        assert_eq!(
+            press(&mut view, 'r'),
+            RemoteAction::StartRename(upstream.clone())
+        );
+        assert_eq!(
+            press(&mut view, 'e'),
+            RemoteAction::StartSetUrl(upstream.clone())
+        );
+        assert_eq!(press(&mut view, 'F'), RemoteAction::Fetch(upstream.clone()));
+        assert_eq!(press(&mut view, 'P'), RemoteAction::Push(upstream));
+    }
+
+    #[test]
+    fn test_handle_key_without_remotes_only_adds() {
+        let mut view = RemoteView::new();
+        assert_eq!(press(&mut view, 'D'), RemoteAction::None);
+        assert_eq!(press(&mut view, 'F'), RemoteAction::None);
+        assert_eq!(press(&mut view, 'a'), RemoteAction::StartAdd);
+        assert_eq!(press(&mut view, 'I'), RemoteAction::GitImport);
+        assert_eq!(press(&mut view, 'E'), RemoteAction::GitExport);
+    }
+}
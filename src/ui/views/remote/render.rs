@@ -0,0 +1,110 @@
+//! Remote View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::RemoteView;
+use crate::model::{Notification, RemoteInfo};
+use crate::ui::{components, navigation, theme};
+
+/// Width of the name column
+const NAME_WIDTH: usize = 16;
+
+impl RemoteView {
+    /// Render the remote view with optional notification in title bar
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let sync_marker = match self.git_sync {
+            Some(ref diverged) if diverged.is_empty() => "[colocated: in sync] ".to_string(),
+            Some(ref diverged) => format!("[colocated: {} out of sync] ", diverged.len()),
+            None => String::new(),
+        };
+        let title = Line::from(format!(
+            " Remotes ({}) {}",
+            self.remote_count(),
+            sync_marker
+        ))
+        .bold()
+        .cyan()
+        .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        let mut lines: Vec<Line> = Vec::new();
+        if let Some(diverged) = self.git_sync.as_ref().filter(|d| !d.is_empty()) {
+            lines.push(build_divergence_line(diverged));
+        }
+
+        if self.remotes.is_empty() {
+            lines.push(Line::from("No remotes configured (a: add remote)"));
+            frame.render_widget(Paragraph::new(lines).block(block), area);
+            return;
+        }
+
+        let inner_height = (area.height.saturating_sub(2) as usize).saturating_sub(lines.len());
+        if inner_height == 0 {
+            return;
+        }
+
+        let scroll_offset =
+            navigation::adjust_scroll(self.selected, self.scroll_offset, inner_height);
+
+        lines.extend(
+            self.remotes
+                .iter()
+                .enumerate()
+                .skip(scroll_offset)
+                .take(inner_height)
+                .map(|(idx, remote)| build_remote_line(remote, idx == self.selected)),
+        );
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// `git refs differ: a, b (I: import, E: export)`
+fn build_divergence_line(diverged: &[String]) -> Line<'static> {
+    Line::from(Span::styled(
+        format!(
+            " git refs differ: {} (I: import, E: export)",
+            diverged.join(", ")
+        ),
+        Style::default().fg(Color::Yellow),
+    ))
+}
+
+fn build_remote_line(remote: &RemoteInfo, is_selected: bool) -> Line<'static> {
+    let url = if remote.url.is_empty() {
+        "(no URL)"
+    } else {
+        &remote.url
+    };
+    let mut line = Line::from(vec![
+        Span::styled(
+            format!(" {:<width$}", remote.name, width = NAME_WIDTH),
+            Style::default().fg(Color::Green),
+        ),
+        Span::styled(format!("  {}", url), Style::default().fg(Color::White)),
+    ]);
+    if is_selected {
+        line = line.style(
+            Style::default()
+                .fg(theme::selection::FG)
+                .bg(theme::selection::BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    line
+}
@@ -56,6 +56,11 @@ impl ResolveView {
                     ResolveAction::None
                 }
             }
+            // Resolve with built-in merge editor (@ only)
+            KeyCode::Char('m') => match self.selected_file_path() {
+                Some(path) if self.is_working_copy => ResolveAction::OpenMerge(path.to_string()),
+                _ => ResolveAction::None,
+            },
             // Show diff for selected file
             KeyCode::Char('d') => {
                 if let Some(path) = self.selected_file_path() {
@@ -151,6 +156,17 @@ mod tests {
         assert_eq!(action, ResolveAction::ShowDiff("test.txt".to_string()));
     }
 
+    #[test]
+    fn test_handle_key_merge_working_copy_only() {
+        let mut view = ResolveView::new("abc".to_string(), true, make_test_files());
+        let action = view.handle_key(key_event(KeyCode::Char('m')));
+        assert_eq!(action, ResolveAction::OpenMerge("test.txt".to_string()));
+
+        let mut view = ResolveView::new("abc".to_string(), false, make_test_files());
+        let action = view.handle_key(key_event(KeyCode::Char('m')));
+        assert_eq!(action, ResolveAction::None);
+    }
+
     #[test]
     fn test_handle_key_back() {
         let mut view = ResolveView::new("abc".to_string(), true, make_test_files());
@@ -5,7 +5,7 @@
 mod input;
 mod render;
 
-use crate::model::ConflictFile;
+use crate::model::{ConflictFile, ConflictLine};
 use crate::ui::navigation;
 
 /// Action returned by ResolveView input handling
@@ -21,6 +21,8 @@ pub enum ResolveAction {
     ResolveOurs(String),
     /// Resolve selected file with :theirs
     ResolveTheirs(String),
+    /// Open the built-in merge editor for the selected file (@ only)
+    OpenMerge(String),
     /// Show diff for selected file
     ShowDiff(String),
 }
@@ -38,6 +40,8 @@ pub struct ResolveView {
     selected_index: usize,
     /// Scroll offset for display
     scroll_offset: usize,
+    /// Conflict preview for a file: (path, materialized lines)
+    preview: Option<(String, Vec<ConflictLine>)>,
 }
 
 impl ResolveView {
@@ -49,6 +53,7 @@ impl ResolveView {
             files,
             selected_index: 0,
             scroll_offset: 0,
+            preview: None,
         }
     }
 
@@ -73,9 +78,45 @@ impl ResolveView {
         self.files.get(self.selected_index).map(|f| f.path.as_str())
     }
 
+    /// Select the file at `path`; returns false if it is not in the list
+    pub fn select_path(&mut self, path: &str) -> bool {
+        match self.files.iter().position(|f| f.path == path) {
+            Some(index) => {
+                self.selected_index = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// File path whose preview needs loading (selected file, if not cached)
+    pub fn preview_target(&self) -> Option<&str> {
+        let path = self.selected_file_path()?;
+        match &self.preview {
+            Some((cached, _)) if cached == path => None,
+            _ => Some(path),
+        }
+    }
+
+    /// Set the conflict preview for a file
+    pub fn set_preview(&mut self, path: String, lines: Vec<ConflictLine>) {
+        self.preview = Some((path, lines));
+    }
+
+    /// Preview lines for the selected file (None if not loaded yet)
+    pub fn selected_preview(&self) -> Option<&[ConflictLine]> {
+        let path = self.selected_file_path()?;
+        self.preview
+            .as_ref()
+            .filter(|(cached, _)| cached == path)
+            .map(|(_, lines)| lines.as_slice())
+    }
+
     /// Update the file list (after resolving a conflict)
     pub fn set_files(&mut self, files: Vec<ConflictFile>) {
         self.files = files;
+        // File contents changed on disk/in the repo; reload preview
+        self.preview = None;
         // Clamp selected_index
         if !self.files.is_empty() {
             self.selected_index = self.selected_index.min(self.files.len() - 1);
@@ -201,6 +242,38 @@ mod tests {
         assert_eq!(view.selected_index, 0);
     }
 
+    #[test]
+    fn test_preview_target_tracks_selection() {
+        use crate::model::ConflictSide;
+
+        let mut view = ResolveView::new("abc12345".to_string(), true, make_test_files());
+        assert_eq!(view.preview_target(), Some("test.txt"));
+        assert!(view.selected_preview().is_none());
+
+        view.set_preview(
+            "test.txt".to_string(),
+            vec![ConflictLine {
+                side: ConflictSide::Ours,
+                content: "ours".to_string(),
+            }],
+        );
+        assert_eq!(view.preview_target(), None);
+        assert_eq!(view.selected_preview().map(|l| l.len()), Some(1));
+
+        // Moving selection invalidates the cached preview
+        view.move_down();
+        assert_eq!(view.preview_target(), Some("src/main.rs"));
+        assert!(view.selected_preview().is_none());
+    }
+
+    #[test]
+    fn test_set_files_clears_preview() {
+        let mut view = ResolveView::new("abc12345".to_string(), true, make_test_files());
+        view.set_preview("test.txt".to_string(), vec![]);
+        view.set_files(make_test_files());
+        assert_eq!(view.preview_target(), Some("test.txt"));
+    }
+
     #[test]
     fn test_resolve_view_not_working_copy() {
         let view = ResolveView::new("lqwwsqpm".to_string(), false, make_test_files());
@@ -2,20 +2,41 @@
 
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
     widgets::Paragraph,
 };
 
-use crate::model::Notification;
+use crate::model::{ConflictLine, ConflictSide, Notification};
 use crate::ui::{components, theme};
 
 use super::ResolveView;
 
+/// Minimum width to show the conflict preview pane next to the file list
+const PREVIEW_MIN_WIDTH: u16 = 80;
+
+/// Context lines shown above the first conflict marker in the preview
+const PREVIEW_LEADING_CONTEXT: usize = 2;
+
 impl ResolveView {
     /// Render the resolve view
+    ///
+    /// On wide terminals the selected file's conflict markers are shown in a
+    /// right-hand preview pane.
     pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        if self.is_empty() || area.width < PREVIEW_MIN_WIDTH {
+            self.render_file_list(frame, area, notification);
+            return;
+        }
+
+        let chunks = Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(area);
+        self.render_file_list(frame, chunks[0], notification);
+        self.render_preview(frame, chunks[1]);
+    }
+
+    fn render_file_list(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
         let title = Line::from(format!(" Conflicts ({} files) ", self.file_count()))
             .bold()
             .red()
@@ -146,4 +167,109 @@ impl ResolveView {
         let paragraph = Paragraph::new(lines).block(block);
         frame.render_widget(paragraph, area);
     }
+
+    fn render_preview(&self, frame: &mut Frame, area: Rect) {
+        let path = self.selected_file_path().unwrap_or_default();
+        let block = components::bordered_block(Line::from(format!(" {} ", path)).bold().cyan());
+
+        let Some(preview) = self.selected_preview() else {
+            let paragraph = Paragraph::new("  Loading preview...").block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        };
+
+        // Start just above the first conflict so it is visible without scrolling
+        let start = preview
+            .iter()
+            .position(|l| l.side == ConflictSide::Marker)
+            .unwrap_or(0)
+            .saturating_sub(PREVIEW_LEADING_CONTEXT);
+
+        let lines: Vec<Line> = preview
+            .iter()
+            .skip(start)
+            .take(area.height.saturating_sub(2) as usize)
+            .map(build_preview_line)
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Colorize a materialized conflict line by side
+fn build_preview_line(line: &ConflictLine) -> Line<'static> {
+    let style = match line.side {
+        ConflictSide::Context => Style::default(),
+        ConflictSide::Marker => Style::default()
+            .fg(theme::resolve_view::MARKER)
+            .add_modifier(Modifier::BOLD),
+        ConflictSide::Base => Style::default().fg(theme::resolve_view::BASE),
+        ConflictSide::Ours => Style::default().fg(theme::resolve_view::OURS),
+        ConflictSide::Theirs => Style::default().fg(theme::resolve_view::THEIRS),
+    };
+    Line::from(Span::styled(line.content.clone(), style))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ConflictFile;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn make_view() -> ResolveView {
+        ResolveView::new(
+            "abc12345".to_string(),
+            true,
+            vec![ConflictFile {
+                path: "test.txt".to_string(),
+                description: "2-sided conflict".to_string(),
+            }],
+        )
+    }
+
+    fn render_to_string(view: &ResolveView, width: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, 12)).unwrap();
+        terminal
+            .draw(|frame| view.render(frame, frame.area(), None))
+            .unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer.content().iter().map(|c| c.symbol()).collect()
+    }
+
+    #[test]
+    fn test_preview_pane_shown_on_wide_terminal() {
+        let mut view = make_view();
+        view.set_preview(
+            "test.txt".to_string(),
+            crate::jj::parser::Parser::parse_conflict_markers(
+                "<<<<<<< conflict 1 of 1\n+++++++ side #1\nours_line\n>>>>>>> conflict 1 of 1 ends\n",
+            ),
+        );
+        let text = render_to_string(&view, 100);
+        assert!(text.contains("ours_line"));
+    }
+
+    #[test]
+    fn test_preview_pane_hidden_on_narrow_terminal() {
+        let mut view = make_view();
+        view.set_preview(
+            "test.txt".to_string(),
+            crate::jj::parser::Parser::parse_conflict_markers("ours_line\n"),
+        );
+        let text = render_to_string(&view, 60);
+        assert!(!text.contains("ours_line"));
+    }
+
+    #[test]
+    fn test_preview_line_colors() {
+        let line = ConflictLine {
+            side: ConflictSide::Theirs,
+            content: "x".to_string(),
+        };
+        assert_eq!(
+            build_preview_line(&line).spans[0].style.fg,
+            Some(theme::resolve_view::THEIRS)
+        );
+    }
 }
@@ -0,0 +1,41 @@
+//! Sparse View key handling
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{SparseAction, SparseView};
+use crate::keys;
+
+impl SparseView {
+    /// Handle key input
+    pub fn handle_key(&mut self, key: KeyEvent) -> SparseAction {
+        match key.code {
+            k if keys::is_move_down(k) => {
+                self.select_next();
+                SparseAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.select_prev();
+                SparseAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.select_first();
+                SparseAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.select_last();
+                SparseAction::None
+            }
+            KeyCode::Char('a') => SparseAction::StartAdd,
+            k if k == keys::BOOKMARK_DELETE => {
+                self.toggle_remove_selected();
+                SparseAction::None
+            }
+            KeyCode::Char('R') => {
+                self.reset_to_full();
+                SparseAction::None
+            }
+            KeyCode::Enter if self.is_modified() => SparseAction::Apply,
+            _ => SparseAction::None,
+        }
+    }
+}
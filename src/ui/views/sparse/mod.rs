@@ -0,0 +1,221 @@
+//! Sparse View for editing `jj sparse` patterns
+//!
+//! Edits are staged: added/removed patterns are marked until applied with
+//! Enter, which previews how many files materialize or disappear.
+
+mod input;
+mod render;
+
+use crate::model::FULL_CHECKOUT;
+use crate::ui::navigation;
+
+/// Action returned by the Sparse View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SparseAction {
+    /// No action needed
+    None,
+    /// Add a pattern (open input dialog)
+    StartAdd,
+    /// Apply the staged patterns (preview, then confirm)
+    Apply,
+}
+
+/// How a row differs from the applied patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternState {
+    /// Applied and kept
+    Kept,
+    /// Staged for adding
+    Added,
+    /// Applied, staged for removal
+    Removed,
+}
+
+/// Sparse View state
+#[derive(Debug, Default)]
+pub struct SparseView {
+    /// Patterns of the working copy (`jj sparse list`)
+    applied: Vec<String>,
+    /// Patterns after the staged edits
+    pending: Vec<String>,
+    /// Selected row index
+    selected: usize,
+    /// Scroll offset
+    scroll_offset: usize,
+}
+
+impl SparseView {
+    /// Create a new Sparse View
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the applied patterns, dropping staged edits
+    pub fn set_patterns(&mut self, patterns: Vec<String>) {
+        self.pending = patterns.clone();
+        self.applied = patterns;
+        self.selected = self.selected.min(self.rows().len().saturating_sub(1));
+        self.scroll_offset = 0;
+    }
+
+    /// Patterns of the working copy
+    pub fn applied_patterns(&self) -> &[String] {
+        &self.applied
+    }
+
+    /// Patterns after the staged edits
+    pub fn pending_patterns(&self) -> &[String] {
+        &self.pending
+    }
+
+    /// Whether edits are staged
+    pub fn is_modified(&self) -> bool {
+        self.pending != self.applied
+    }
+
+    /// Rows: staged patterns, then applied patterns staged for removal
+    pub fn rows(&self) -> Vec<(&str, PatternState)> {
+        let mut rows: Vec<(&str, PatternState)> = self
+            .pending
+            .iter()
+            .map(|p| {
+                let state = if self.applied.contains(p) {
+                    PatternState::Kept
+                } else {
+                    PatternState::Added
+                };
+                (p.as_str(), state)
+            })
+            .collect();
+        rows.extend(
+            self.applied
+                .iter()
+                .filter(|p| !self.pending.contains(p))
+                .map(|p| (p.as_str(), PatternState::Removed)),
+        );
+        rows
+    }
+
+    /// Stage a pattern (trailing `/` dropped)
+    ///
+    /// A specific pattern replaces a staged full checkout. Returns `false`
+    /// if the pattern is empty or already staged.
+    pub fn add_pattern(&mut self, pattern: &str) -> bool {
+        let pattern = pattern.trim().trim_end_matches('/');
+        if pattern.is_empty() || self.pending.iter().any(|p| p == pattern) {
+            return false;
+        }
+        if pattern == FULL_CHECKOUT {
+            self.pending = vec![FULL_CHECKOUT.to_string()];
+        } else {
+            self.pending.retain(|p| p != FULL_CHECKOUT);
+            self.pending.push(pattern.to_string());
+        }
+        true
+    }
+
+    /// Stage removal of the selected pattern, or undo a staged removal
+    pub fn toggle_remove_selected(&mut self) {
+        let Some((pattern, state)) = self
+            .rows()
+            .get(self.selected)
+            .map(|(p, s)| (p.to_string(), *s))
+        else {
+            return;
+        };
+        match state {
+            PatternState::Removed => self.pending.push(pattern),
+            PatternState::Kept | PatternState::Added => self.pending.retain(|p| *p != pattern),
+        }
+        self.selected = self.selected.min(self.rows().len().saturating_sub(1));
+    }
+
+    /// Stage a full checkout (`jj sparse reset`)
+    pub fn reset_to_full(&mut self) {
+        self.pending = vec![FULL_CHECKOUT.to_string()];
+        self.selected = 0;
+    }
+
+    /// Move selection down
+    pub fn select_next(&mut self) {
+        let max = self.rows().len().saturating_sub(1);
+        self.selected = navigation::select_next(self.selected, max);
+    }
+
+    /// Move selection up
+    pub fn select_prev(&mut self) {
+        self.selected = navigation::select_prev(self.selected);
+    }
+
+    /// Go to first pattern
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Go to last pattern
+    pub fn select_last(&mut self) {
+        self.selected = self.rows().len().saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn view_with(patterns: &[&str]) -> SparseView {
+        let mut view = SparseView::new();
+        view.set_patterns(patterns.iter().map(|p| p.to_string()).collect());
+        view
+    }
+
+    #[test]
+    fn test_add_replaces_full_checkout() {
+        let mut view = view_with(&["."]);
+        assert!(view.add_pattern("src/"));
+        assert!(!view.add_pattern("src"));
+        assert_eq!(view.pending_patterns(), ["src"]);
+        assert_eq!(
+            view.rows(),
+            [("src", PatternState::Added), (".", PatternState::Removed)]
+        );
+        assert!(view.is_modified());
+    }
+
+    #[test]
+    fn test_toggle_remove_and_restore() {
+        let mut view = view_with(&["docs", "src"]);
+        view.select_next();
+        view.handle_key(KeyEvent::from(KeyCode::Char('D')));
+        assert_eq!(
+            view.rows(),
+            [("docs", PatternState::Kept), ("src", PatternState::Removed)]
+        );
+        view.toggle_remove_selected();
+        assert!(!view.is_modified());
+    }
+
+    #[test]
+    fn test_reset_and_apply_keys() {
+        let mut view = view_with(&["src"]);
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Char('a'))),
+            SparseAction::StartAdd
+        );
+        view.handle_key(KeyEvent::from(KeyCode::Char('R')));
+        assert_eq!(view.pending_patterns(), ["."]);
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Enter)),
+            SparseAction::Apply
+        );
+
+        view.set_patterns(vec!["src".to_string()]);
+        assert!(!view.is_modified());
+        // Nothing staged: Enter does nothing
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Enter)),
+            SparseAction::None
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Sparse View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::{PatternState, SparseView};
+use crate::model::{FULL_CHECKOUT, Notification};
+use crate::ui::{components, navigation, theme};
+
+impl SparseView {
+    /// Render the sparse view with optional notification in title bar
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let modified = if self.is_modified() {
+            "[not applied: Enter] "
+        } else {
+            ""
+        };
+        let title = Line::from(format!(
+            " Sparse Patterns ({}) {}",
+            self.pending_patterns().len(),
+            modified
+        ))
+        .bold()
+        .cyan()
+        .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        let rows = self.rows();
+        if rows.is_empty() {
+            let paragraph =
+                Paragraph::new("No patterns: nothing is checked out (a: add, R: full checkout)")
+                    .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let inner_height = area.height.saturating_sub(2) as usize;
+        if inner_height == 0 {
+            return;
+        }
+
+        let scroll_offset =
+            navigation::adjust_scroll(self.selected, self.scroll_offset, inner_height);
+
+        let lines: Vec<Line> = rows
+            .iter()
+            .enumerate()
+            .skip(scroll_offset)
+            .take(inner_height)
+            .map(|(idx, (pattern, state))| {
+                build_pattern_line(pattern, *state, idx == self.selected)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+fn build_pattern_line(pattern: &str, state: PatternState, is_selected: bool) -> Line<'static> {
+    let (marker, style) = match state {
+        PatternState::Kept => ("  ", Style::default().fg(Color::White)),
+        PatternState::Added => ("+ ", Style::default().fg(Color::Green)),
+        PatternState::Removed => (
+            "- ",
+            Style::default()
+                .fg(Color::Red)
+                .add_modifier(Modifier::CROSSED_OUT),
+        ),
+    };
+    let mut spans = vec![
+        Span::styled(format!(" {}", marker), style),
+        Span::styled(pattern.to_string(), style),
+    ];
+    if pattern == FULL_CHECKOUT {
+        spans.push(Span::styled(
+            "  (full checkout)",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    let mut line = Line::from(spans);
+    if is_selected {
+        line = line.style(
+            Style::default()
+                .fg(theme::selection::FG)
+                .bg(theme::selection::BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    line
+}
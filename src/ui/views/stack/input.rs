@@ -0,0 +1,37 @@
+//! Stack View key handling
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{StackAction, StackView};
+use crate::keys;
+
+impl StackView {
+    /// Handle key input
+    pub fn handle_key(&mut self, key: KeyEvent) -> StackAction {
+        match key.code {
+            k if keys::is_move_down(k) => {
+                self.select_next();
+                StackAction::None
+            }
+            k if keys::is_move_up(k) => {
+                self.select_prev();
+                StackAction::None
+            }
+            k if k == keys::GO_TOP => {
+                self.select_first();
+                StackAction::None
+            }
+            k if k == keys::GO_BOTTOM => {
+                self.select_last();
+                StackAction::None
+            }
+            KeyCode::Enter => match self.selected_entry() {
+                Some(entry) => StackAction::Jump(entry.change_id.to_string()),
+                None => StackAction::None,
+            },
+            k if k == keys::MOVE_COMMIT_UP => self.move_up_action(),
+            k if k == keys::MOVE_COMMIT_DOWN => self.move_down_action(),
+            _ => StackAction::None,
+        }
+    }
+}
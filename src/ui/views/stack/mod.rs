@@ -0,0 +1,233 @@
+//! Stack View for stacked-diff workflows
+//!
+//! Shows `trunk()..@` as a linear list (top of the stack first) with each
+//! commit's bookmarks, push state and empty/conflict flags, and lets commits
+//! be moved up or down the stack.
+
+mod input;
+mod render;
+
+use crate::model::StackEntry;
+use crate::ui::navigation;
+
+/// Action returned by the Stack View after handling input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackAction {
+    /// No action needed
+    None,
+    /// Jump to the change in Log View
+    Jump(String),
+    /// Move `source` above `target` (`jj rebase -r <source> --insert-after <target>`)
+    MoveUp { source: String, target: String },
+    /// Move `source` below `target` (`jj rebase -r <source> --insert-before <target>`)
+    MoveDown { source: String, target: String },
+    /// Selected commit is already at that end of the stack
+    MoveBlocked(&'static str),
+}
+
+/// Stack View state
+#[derive(Debug, Default)]
+pub struct StackView {
+    /// Stack entries, top of the stack (`@`) first
+    entries: Vec<StackEntry>,
+    /// Selected index
+    selected: usize,
+    /// Scroll offset
+    scroll_offset: usize,
+}
+
+impl StackView {
+    /// Create a new Stack View
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the stack, keeping the selection on `keep` (a change ID) if present
+    pub fn set_entries(&mut self, entries: Vec<StackEntry>, keep: Option<&str>) {
+        self.entries = entries;
+        self.selected = keep
+            .and_then(|id| self.entries.iter().position(|e| e.change_id.as_str() == id))
+            .unwrap_or(0);
+        self.scroll_offset = 0;
+    }
+
+    /// Get the currently selected entry
+    pub fn selected_entry(&self) -> Option<&StackEntry> {
+        self.entries.get(self.selected)
+    }
+
+    /// Number of commits in the stack
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the stack is empty (`@` is on trunk)
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Move selection to next entry
+    pub fn select_next(&mut self) {
+        let max = self.entries.len().saturating_sub(1);
+        self.selected = navigation::select_next(self.selected, max);
+    }
+
+    /// Move selection to previous entry
+    pub fn select_prev(&mut self) {
+        self.selected = navigation::select_prev(self.selected);
+    }
+
+    /// Go to first entry (top of the stack)
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Go to last entry (bottom of the stack)
+    pub fn select_last(&mut self) {
+        if !self.entries.is_empty() {
+            self.selected = self.entries.len() - 1;
+        }
+    }
+
+    /// Rebase action moving the selection one step up (towards `@`)
+    fn move_up_action(&self) -> StackAction {
+        let Some(entry) = self.selected_entry() else {
+            return StackAction::None;
+        };
+        match self.selected.checked_sub(1).map(|i| &self.entries[i]) {
+            Some(above) => StackAction::MoveUp {
+                source: entry.change_id.to_string(),
+                target: above.change_id.to_string(),
+            },
+            None => StackAction::MoveBlocked("Already at the top of the stack"),
+        }
+    }
+
+    /// Rebase action moving the selection one step down (towards trunk)
+    fn move_down_action(&self) -> StackAction {
+        let Some(entry) = self.selected_entry() else {
+            return StackAction::None;
+        };
+        match self.entries.get(self.selected + 1) {
+            Some(below) => StackAction::MoveDown {
+                source: entry.change_id.to_string(),
+                target: below.change_id.to_string(),
+            },
+            None => StackAction::MoveBlocked("Already at the bottom of the stack"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys;
+    use crate::model::ChangeId;
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    fn make_entry(change_id: &str) -> StackEntry {
+        StackEntry {
+            change_id: ChangeId::new(change_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn create_test_view() -> StackView {
+        let mut view = StackView::new();
+        view.set_entries(
+            vec![
+                make_entry("topppppp"),
+                make_entry("midddddd"),
+                make_entry("bottommm"),
+            ],
+            None,
+        );
+        view
+    }
+
+    #[test]
+    fn test_set_entries_keeps_selection_on_change() {
+        let mut view = create_test_view();
+        let entries = view.entries.clone();
+        view.set_entries(entries.clone(), Some("bottommm"));
+        assert_eq!(view.selected, 2);
+        view.set_entries(entries, Some("gone"));
+        assert_eq!(view.selected, 0);
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut view = create_test_view();
+        view.select_next();
+        view.select_next();
+        view.select_next();
+        assert_eq!(view.selected, 2);
+        view.select_prev();
+        assert_eq!(view.selected, 1);
+        view.select_first();
+        assert_eq!(view.selected, 0);
+    }
+
+    #[test]
+    fn test_move_up_inserts_after_commit_above() {
+        let mut view = create_test_view();
+        view.select_next();
+        let action = view.handle_key(KeyEvent::from(keys::MOVE_COMMIT_UP));
+        assert_eq!(
+            action,
+            StackAction::MoveUp {
+                source: "midddddd".to_string(),
+                target: "topppppp".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_down_inserts_before_commit_below() {
+        let mut view = create_test_view();
+        view.select_next();
+        let action = view.handle_key(KeyEvent::from(keys::MOVE_COMMIT_DOWN));
+        assert_eq!(
+            action,
+            StackAction::MoveDown {
+                source: "midddddd".to_string(),
+                target: "bottommm".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_blocked_at_ends() {
+        let mut view = create_test_view();
+        assert!(matches!(
+            view.handle_key(KeyEvent::from(keys::MOVE_COMMIT_UP)),
+            StackAction::MoveBlocked(_)
+        ));
+        view.select_last();
+        assert!(matches!(
+            view.handle_key(KeyEvent::from(keys::MOVE_COMMIT_DOWN)),
+            StackAction::MoveBlocked(_)
+        ));
+    }
+
+    #[test]
+    fn test_enter_jumps_to_log() {
+        let mut view = create_test_view();
+        let action = view.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(action, StackAction::Jump("topppppp".to_string()));
+    }
+
+    #[test]
+    fn test_empty_stack_ignores_keys() {
+        let mut view = StackView::new();
+        assert_eq!(
+            view.handle_key(KeyEvent::from(keys::MOVE_COMMIT_UP)),
+            StackAction::None
+        );
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Enter)),
+            StackAction::None
+        );
+    }
+}
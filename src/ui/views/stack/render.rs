@@ -0,0 +1,213 @@
+//! Stack View rendering
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::StackView;
+use crate::jj::constants::STACK_REVSET;
+use crate::model::{Notification, StackEntry, StackPushState};
+use crate::ui::{components, navigation, theme};
+
+/// Width of the bookmark column
+const BOOKMARK_WIDTH: usize = 18;
+
+impl StackView {
+    /// Render the stack view with optional notification in title bar
+    pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
+        let title = Line::from(format!(" Stack: {} ({}) ", STACK_REVSET, self.len()))
+            .bold()
+            .cyan()
+            .centered();
+
+        let title_width = title.width();
+        let available_for_notif = area.width.saturating_sub(title_width as u16 + 4) as usize;
+        let notif_line = notification
+            .filter(|n| !n.is_expired())
+            .map(|n| components::build_notification_title(n, Some(available_for_notif)))
+            .filter(|line| !line.spans.is_empty());
+
+        let block = components::bordered_block_with_notification(title, notif_line);
+
+        if self.entries.is_empty() {
+            let paragraph = components::empty_state(
+                "No commits between trunk() and @",
+                Some("Create a change with 'c' in Log View to start a stack"),
+            )
+            .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        // One row for the column header
+        let inner_height = area.height.saturating_sub(3) as usize;
+        if inner_height == 0 {
+            return;
+        }
+
+        let scroll_offset =
+            navigation::adjust_scroll(self.selected, self.scroll_offset, inner_height);
+
+        let mut lines = vec![build_header_line()];
+        for (idx, entry) in self.entries.iter().enumerate().skip(scroll_offset) {
+            if lines.len() > inner_height {
+                break;
+            }
+            lines.push(build_entry_line(entry, idx == self.selected));
+        }
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+fn build_header_line() -> Line<'static> {
+    Line::from(format!(
+        "   {:<10}  {:<width$}  {:<10}  {:<8}  DESCRIPTION",
+        "CHANGE",
+        "BOOKMARK",
+        "PUSH",
+        "FLAGS",
+        width = BOOKMARK_WIDTH
+    ))
+    .style(Style::default().fg(Color::DarkGray))
+}
+
+fn push_state_color(state: StackPushState) -> Color {
+    match state {
+        StackPushState::Pushed => Color::Green,
+        StackPushState::NeedsPush => Color::Yellow,
+        StackPushState::Local => Color::DarkGray,
+    }
+}
+
+fn build_entry_line(entry: &StackEntry, is_selected: bool) -> Line<'static> {
+    let marker = if entry.is_working_copy {
+        " @ "
+    } else {
+        " ○ "
+    };
+    let marker_color = if entry.is_working_copy {
+        theme::log_view::WORKING_COPY_MARKER
+    } else {
+        theme::log_view::NORMAL_MARKER
+    };
+
+    let bookmarks = entry.bookmarks.join(",");
+    let mut flags = Vec::new();
+    if entry.has_conflict {
+        flags.push("conflict");
+    }
+    if entry.is_empty {
+        flags.push("empty");
+    }
+    let flags_color = if entry.has_conflict {
+        theme::log_view::CONFLICT_MARKER
+    } else {
+        theme::log_view::EMPTY_LABEL
+    };
+
+    let description = if entry.description.is_empty() {
+        "(no description set)"
+    } else {
+        &entry.description
+    };
+
+    let spans = vec![
+        Span::styled(marker, Style::default().fg(marker_color)),
+        Span::styled(
+            format!("{:<10}", entry.change_id.as_str()),
+            Style::default().fg(theme::log_view::CHANGE_ID),
+        ),
+        Span::styled(
+            format!(
+                "  {:<width$}",
+                truncate_str(&bookmarks, BOOKMARK_WIDTH),
+                width = BOOKMARK_WIDTH
+            ),
+            Style::default().fg(theme::log_view::BOOKMARK),
+        ),
+        Span::styled(
+            format!("  {:<10}", entry.push_state.label()),
+            Style::default().fg(push_state_color(entry.push_state)),
+        ),
+        Span::styled(
+            format!("  {:<8}", flags.join(",")),
+            Style::default().fg(flags_color),
+        ),
+        Span::styled(
+            format!("  {}", description),
+            Style::default().fg(Color::White),
+        ),
+    ];
+
+    let mut line = Line::from(spans);
+    if is_selected {
+        line = line.style(
+            Style::default()
+                .fg(theme::selection::FG)
+                .bg(theme::selection::BG)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    line
+}
+
+fn truncate_str(s: &str, max_len: usize) -> String {
+    let char_count = s.chars().count();
+    if char_count <= max_len {
+        s.to_string()
+    } else if max_len > 3 {
+        let truncated: String = s.chars().take(max_len - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        s.chars().take(max_len).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ChangeId;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_entry_line_shows_columns() {
+        let entry = StackEntry {
+            change_id: ChangeId::new("zsuskuln".to_string()),
+            description: "Add parser".to_string(),
+            bookmarks: vec!["feature".to_string()],
+            has_conflict: true,
+            push_state: StackPushState::NeedsPush,
+            ..Default::default()
+        };
+        let text = line_text(&build_entry_line(&entry, false));
+        assert!(text.starts_with(" ○ zsuskuln"));
+        assert!(text.contains("feature"));
+        assert!(text.contains("needs push"));
+        assert!(text.contains("conflict"));
+        assert!(text.ends_with("Add parser"));
+    }
+
+    #[test]
+    fn test_entry_line_working_copy_and_empty() {
+        let entry = StackEntry {
+            change_id: ChangeId::new("wqnwkozp".to_string()),
+            is_working_copy: true,
+            is_empty: true,
+            ..Default::default()
+        };
+        let text = line_text(&build_entry_line(&entry, true));
+        assert!(text.starts_with(" @ "));
+        assert!(text.contains("local"));
+        assert!(text.contains("empty"));
+        assert!(text.contains("(no description set)"));
+    }
+}
@@ -1,9 +1,11 @@
 //! Status View key handling
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 
 use super::{StatusAction, StatusInputMode, StatusView};
 use crate::keys;
+use crate::model::FileState;
+use crate::ui::components::TextAreaEvent;
 
 impl StatusView {
     /// Handle key event
@@ -39,7 +41,7 @@ impl StatusView {
             }
             code if code == keys::OPEN_DIFF => {
                 if let (Some(change_id), Some(file_path)) =
-                    (self.working_copy_id(), self.selected_file_path())
+                    (self.working_copy_id(), self.selected_tracked_path())
                 {
                     StatusAction::ShowFileDiff {
                         change_id: change_id.to_string(),
@@ -57,7 +59,7 @@ impl StatusView {
                 StatusAction::None
             }
             code if code == keys::ANNOTATE => {
-                if let Some(file_path) = self.selected_file_path() {
+                if let Some(file_path) = self.selected_tracked_path() {
                     StatusAction::OpenBlame {
                         file_path: file_path.to_string(),
                     }
@@ -65,6 +67,15 @@ impl StatusView {
                     StatusAction::None
                 }
             }
+            code if code == keys::FILE_LOG => {
+                if let Some(file_path) = self.selected_tracked_path() {
+                    StatusAction::OpenFileLog {
+                        file_path: file_path.to_string(),
+                    }
+                } else {
+                    StatusAction::None
+                }
+            }
             code if code == keys::JUMP_CONFLICT => {
                 if self.jump_to_first_conflict() {
                     StatusAction::JumpToConflict
@@ -73,7 +84,7 @@ impl StatusView {
                 }
             }
             code if code == keys::RESTORE_FILE => {
-                if let Some(file_path) = self.selected_file_path() {
+                if let Some(file_path) = self.selected_tracked_path() {
                     StatusAction::RestoreFile {
                         file_path: file_path.to_string(),
                     }
@@ -81,6 +92,36 @@ impl StatusView {
                     StatusAction::None
                 }
             }
+            code if code == keys::RESTORE_HUNKS => {
+                if let Some(file_path) = self.selected_tracked_path() {
+                    StatusAction::RestoreHunks {
+                        file_path: file_path.to_string(),
+                    }
+                } else {
+                    StatusAction::None
+                }
+            }
+            code if code == keys::SQUASH_LINES => {
+                if let Some(file_path) = self.selected_tracked_path() {
+                    StatusAction::SquashLines {
+                        file_path: file_path.to_string(),
+                    }
+                } else {
+                    StatusAction::None
+                }
+            }
+            code if code == keys::GITIGNORE => {
+                if let Some(file) = self
+                    .selected_file()
+                    .filter(|f| f.state != FileState::Ignored)
+                {
+                    StatusAction::AddToGitignore {
+                        file_path: file.path.clone(),
+                    }
+                } else {
+                    StatusAction::None
+                }
+            }
             code if code == keys::RESTORE_ALL => {
                 // Guard: only when there are files to restore
                 if self.status.as_ref().is_some_and(|s| !s.is_clean()) {
@@ -89,8 +130,17 @@ impl StatusView {
                     StatusAction::None
                 }
             }
+            code if code == keys::DIFF_TOOL => {
+                if let Some(file_path) = self.selected_tracked_path() {
+                    StatusAction::OpenDiffTool {
+                        file_path: file_path.to_string(),
+                    }
+                } else {
+                    StatusAction::None
+                }
+            }
             code if code == keys::DIFFEDIT => {
-                if let Some(file_path) = self.selected_file_path() {
+                if let Some(file_path) = self.selected_tracked_path() {
                     StatusAction::DiffEdit {
                         file_path: file_path.to_string(),
                     }
@@ -98,36 +148,46 @@ impl StatusView {
                     StatusAction::None
                 }
             }
+            code if code == keys::TOGGLE_UNTRACKED => {
+                self.toggle_untracked();
+                StatusAction::None
+            }
+            code if code == keys::TOGGLE_IGNORED => {
+                if self.toggle_ignored() {
+                    StatusAction::LoadIgnored
+                } else {
+                    StatusAction::None
+                }
+            }
+            code if code == keys::TOGGLE_WARNINGS => {
+                if self.status.as_ref().is_some_and(|s| !s.warnings.is_empty()) {
+                    self.warnings_expanded = !self.warnings_expanded;
+                }
+                StatusAction::None
+            }
+            code if code == keys::SPARSE_VIEW => StatusAction::OpenSparseView,
             // Note: QUIT, TAB, ESC are handled by global key handler in input.rs
             _ => StatusAction::None,
         }
     }
 
     fn handle_commit_input_key(&mut self, key: KeyEvent) -> StatusAction {
-        match key.code {
-            KeyCode::Esc => {
+        match self.commit_editor.handle_key(key) {
+            TextAreaEvent::None => StatusAction::None,
+            TextAreaEvent::Cancel => {
                 self.cancel_input();
                 StatusAction::None
             }
-            KeyCode::Enter => {
-                let message = std::mem::take(&mut self.input_buffer);
-                self.input_mode = StatusInputMode::Normal;
-                if message.is_empty() {
+            TextAreaEvent::Save => {
+                let message = self.commit_editor.text().trim_end().to_string();
+                self.cancel_input();
+                if message.trim().is_empty() {
                     // Empty message = cancel
                     StatusAction::None
                 } else {
                     StatusAction::Commit { message }
                 }
             }
-            KeyCode::Char(c) => {
-                self.input_buffer.push(c);
-                StatusAction::None
-            }
-            KeyCode::Backspace => {
-                self.input_buffer.pop();
-                StatusAction::None
-            }
-            _ => StatusAction::None,
         }
     }
 }
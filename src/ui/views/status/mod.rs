@@ -5,7 +5,8 @@
 mod input;
 mod render;
 
-use crate::model::{FileState, Status};
+use crate::model::{FileState, FileStatus, Status};
+use crate::ui::components::TextArea;
 use crate::ui::navigation;
 
 /// Input mode for Status View
@@ -14,7 +15,7 @@ pub enum StatusInputMode {
     /// Normal navigation mode
     #[default]
     Normal,
-    /// Commit message input mode
+    /// Commit message editor (multi-line)
     CommitInput,
 }
 
@@ -33,16 +34,33 @@ pub enum StatusAction {
         /// File path to annotate
         file_path: String,
     },
+    /// Show the history of the selected file
+    OpenFileLog {
+        /// File path to show history for
+        file_path: String,
+    },
     /// Commit with message
     Commit { message: String },
     /// Jump to first conflict file
     JumpToConflict,
     /// Restore a single file (jj restore <file>)
     RestoreFile { file_path: String },
+    /// Discard some hunks/lines of a file (pick them, then confirm)
+    RestoreHunks { file_path: String },
+    /// Move some hunks/lines of a file into the parent (jj squash)
+    SquashLines { file_path: String },
     /// Restore all files (jj restore)
     RestoreAll,
+    /// Add the file (or a pattern derived from it) to .gitignore
+    AddToGitignore { file_path: String },
     /// Open diffedit for selected file (jj diffedit -r @ <file>)
     DiffEdit { file_path: String },
+    /// Open selected file in the GUI difftool (jj diff --tool <tool> -r @ <file>)
+    OpenDiffTool { file_path: String },
+    /// Open Sparse View (sparse checkout patterns)
+    OpenSparseView,
+    /// Ignored paths were switched on: scan the workspace for them
+    LoadIgnored,
     /// No action
     None,
 }
@@ -50,9 +68,22 @@ pub enum StatusAction {
 /// Status View state
 #[derive(Debug)]
 pub struct StatusView {
-    /// Current status (None if not loaded)
+    /// Current status as displayed (None if not loaded): `full_status` with
+    /// the untracked/ignored filters applied
     pub(super) status: Option<Status>,
 
+    /// Status as parsed from `jj status`
+    full_status: Option<Status>,
+
+    /// Ignored paths from the last workspace scan
+    ignored: Vec<String>,
+
+    /// List untracked paths (`U`)
+    pub(super) show_untracked: bool,
+
+    /// List ignored paths (`I`)
+    pub(super) show_ignored: bool,
+
     /// Selected file index
     pub(super) selected_index: usize,
 
@@ -62,8 +93,11 @@ pub struct StatusView {
     /// Current input mode
     pub input_mode: StatusInputMode,
 
-    /// Input buffer for commit message
-    pub input_buffer: String,
+    /// Commit message editor
+    pub commit_editor: TextArea,
+
+    /// Show every jj warning/hint line instead of the one-line summary
+    pub(super) warnings_expanded: bool,
 }
 
 impl Default for StatusView {
@@ -80,46 +114,174 @@ impl StatusView {
     pub fn new() -> Self {
         Self {
             status: None,
+            full_status: None,
+            ignored: Vec::new(),
+            show_untracked: true,
+            show_ignored: false,
             selected_index: 0,
             scroll_offset: 0,
             input_mode: StatusInputMode::Normal,
-            input_buffer: String::new(),
+            commit_editor: TextArea::default(),
+            warnings_expanded: false,
         }
     }
 
     /// Start commit input mode
     pub fn start_commit_input(&mut self) {
         self.input_mode = StatusInputMode::CommitInput;
-        self.input_buffer.clear();
+        self.commit_editor = TextArea::default();
     }
 
     /// Cancel input mode
     pub fn cancel_input(&mut self) {
         self.input_mode = StatusInputMode::Normal;
-        self.input_buffer.clear();
+        self.commit_editor = TextArea::default();
     }
 
     /// Set the status data
     pub fn set_status(&mut self, status: Status) {
-        self.status = Some(status);
-        // Reset selection and scroll if out of bounds
-        if let Some(ref s) = self.status {
-            if self.selected_index >= s.files.len() {
-                self.selected_index = 0;
-                self.scroll_offset = 0;
+        self.full_status = Some(status);
+        self.apply_filters();
+    }
+
+    /// Set the ignored paths found by a workspace scan
+    pub fn set_ignored(&mut self, ignored: Vec<String>) {
+        self.ignored = ignored;
+        self.apply_filters();
+    }
+
+    /// Whether ignored paths are listed (and must be rescanned on refresh)
+    pub fn show_ignored(&self) -> bool {
+        self.show_ignored
+    }
+
+    /// State of a path in the last `jj status` (untracked/ignored included)
+    pub fn file_state(&self, path: &str) -> Option<&FileState> {
+        self.full_status
+            .iter()
+            .flat_map(|s| &s.files)
+            .find(|f| f.path == path)
+            .map(|f| &f.state)
+    }
+
+    /// Replace the entry for `path` with `entry`, or drop it (`None`)
+    ///
+    /// Lets a file-level operation update one row without re-running
+    /// `jj status`. New entries go after the other changed files; the
+    /// selection stays on the same row (or the last one).
+    pub fn replace_file(&mut self, path: &str, entry: Option<FileStatus>) {
+        let Some(ref mut full) = self.full_status else {
+            return;
+        };
+        let existing = full.files.iter().position(|f| f.path == path);
+        match (existing, entry) {
+            (Some(index), Some(entry)) => full.files[index] = entry,
+            (Some(index), None) => {
+                full.files.remove(index);
             }
-            // Also reset scroll if it would show empty area
-            if self.scroll_offset >= s.files.len() {
-                self.scroll_offset = 0;
+            (None, Some(entry)) => {
+                let at = full
+                    .files
+                    .iter()
+                    .position(|f| !f.state.is_tracked())
+                    .unwrap_or(full.files.len());
+                full.files.insert(at, entry);
             }
+            (None, None) => return,
+        }
+        let selected = self.selected_index;
+        self.apply_filters();
+        if let Some(ref status) = self.status
+            && !status.files.is_empty()
+        {
+            self.selected_index = selected.min(status.files.len() - 1);
         }
     }
 
-    /// Get the selected file path
-    pub fn selected_file_path(&self) -> Option<&str> {
+    /// Untracked paths reported by `jj status`
+    pub fn untracked_paths(&self) -> Vec<String> {
+        self.full_status
+            .iter()
+            .flat_map(|s| &s.files)
+            .filter(|f| f.state == FileState::Untracked)
+            .map(|f| f.path.clone())
+            .collect()
+    }
+
+    /// Counts for the header: (changed, untracked, ignored)
+    pub(super) fn category_counts(&self) -> (usize, usize, usize) {
+        let files = self.full_status.iter().flat_map(|s| &s.files);
+        let untracked = files
+            .clone()
+            .filter(|f| f.state == FileState::Untracked)
+            .count();
+        let changed = files.filter(|f| f.state.is_tracked()).count();
+        (changed, untracked, self.ignored.len())
+    }
+
+    /// Toggle untracked paths in the list
+    fn toggle_untracked(&mut self) {
+        self.show_untracked = !self.show_untracked;
+        self.apply_filters();
+    }
+
+    /// Toggle ignored paths; returns true when they need to be scanned
+    fn toggle_ignored(&mut self) -> bool {
+        self.show_ignored = !self.show_ignored;
+        if !self.show_ignored {
+            self.ignored.clear();
+        }
+        self.apply_filters();
+        self.show_ignored
+    }
+
+    /// Rebuild the displayed status from `full_status` and the toggles
+    fn apply_filters(&mut self) {
+        let Some(ref full) = self.full_status else {
+            return;
+        };
+        let mut status = full.clone();
+        if !self.show_untracked {
+            status.files.retain(|f| f.state != FileState::Untracked);
+        }
+        if self.show_ignored {
+            status
+                .files
+                .extend(self.ignored.iter().map(|path| FileStatus {
+                    path: path.clone(),
+                    state: FileState::Ignored,
+                }));
+        }
+
+        // Reset selection and scroll if out of bounds
+        if self.selected_index >= status.files.len() {
+            self.selected_index = 0;
+            self.scroll_offset = 0;
+        }
+        // Also reset scroll if it would show empty area
+        if self.scroll_offset >= status.files.len() {
+            self.scroll_offset = 0;
+        }
+        self.status = Some(status);
+    }
+
+    /// Get the selected file
+    pub fn selected_file(&self) -> Option<&FileStatus> {
         self.status
             .as_ref()
             .and_then(|s| s.files.get(self.selected_index))
+    }
+
+    /// Get the selected file path
+    pub fn selected_file_path(&self) -> Option<&str> {
+        self.selected_file().map(|f| f.path.as_str())
+    }
+
+    /// Get the selected file path if it is part of the working copy commit
+    /// (jj commands on untracked/ignored paths have nothing to act on)
+    pub fn selected_tracked_path(&self) -> Option<&str> {
+        self.selected_file()
+            .filter(|f| f.state.is_tracked())
             .map(|f| f.path.as_str())
     }
 
@@ -217,6 +379,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc12345".to_string()),
             parent_change_id: ChangeId::new("xyz98765".to_string()),
+            warnings: Vec::new(),
         }
     }
 
@@ -236,6 +399,36 @@ mod tests {
         assert_eq!(view.status.as_ref().unwrap().files.len(), 3);
     }
 
+    #[test]
+    fn test_replace_file_updates_one_entry() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+        view.selected_index = 2;
+
+        view.replace_file("old.rs", None);
+        assert_eq!(view.status.as_ref().unwrap().files.len(), 2);
+        assert_eq!(view.selected_file_path(), Some("src/new.rs"));
+
+        view.replace_file(
+            "src/main.rs",
+            Some(FileStatus {
+                path: "src/main.rs".to_string(),
+                state: FileState::Deleted,
+            }),
+        );
+        assert_eq!(view.file_state("src/main.rs"), Some(&FileState::Deleted));
+
+        view.replace_file(
+            "README.md",
+            Some(FileStatus {
+                path: "README.md".to_string(),
+                state: FileState::Added,
+            }),
+        );
+        assert_eq!(view.status.as_ref().unwrap().files.len(), 3);
+        assert_eq!(view.file_state("missing.rs"), None);
+    }
+
     #[test]
     fn test_move_down() {
         let mut view = StatusView::new();
@@ -338,6 +531,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         view.set_status(empty_status);
 
@@ -357,6 +551,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         view.set_status(no_conflict_status);
         assert!(!view.has_conflicts());
@@ -367,6 +562,7 @@ mod tests {
             has_conflicts: true,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         view.set_status(conflict_status);
         assert!(view.has_conflicts());
@@ -391,6 +587,7 @@ mod tests {
             has_conflicts: true,
             working_copy_change_id: ChangeId::new("abc12345".to_string()),
             parent_change_id: ChangeId::new("xyz98765".to_string()),
+            warnings: Vec::new(),
         }
     }
 
@@ -442,6 +639,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_l_uppercase_returns_file_log() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('L')));
+        assert_eq!(
+            action,
+            StatusAction::OpenFileLog {
+                file_path: "src/main.rs".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_s_uppercase_opens_sparse_view() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('S')));
+        assert_eq!(action, StatusAction::OpenSparseView);
+    }
+
     #[test]
     fn test_r_uppercase_returns_restore_all() {
         let mut view = StatusView::new();
@@ -451,6 +671,116 @@ mod tests {
         assert_eq!(action, StatusAction::RestoreAll);
     }
 
+    #[test]
+    fn test_x_returns_restore_hunks() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('x')));
+        assert_eq!(
+            action,
+            StatusAction::RestoreHunks {
+                file_path: "src/main.rs".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_m_returns_squash_lines() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('m')));
+        assert_eq!(
+            action,
+            StatusAction::SquashLines {
+                file_path: "src/main.rs".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_i_returns_add_to_gitignore() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('i')));
+        assert_eq!(
+            action,
+            StatusAction::AddToGitignore {
+                file_path: "src/main.rs".to_string()
+            }
+        );
+    }
+
+    fn status_with_untracked() -> Status {
+        let mut status = sample_status();
+        status.files.push(FileStatus {
+            path: "notes.txt".to_string(),
+            state: FileState::Untracked,
+        });
+        status
+    }
+
+    #[test]
+    fn test_toggle_untracked_hides_untracked_paths() {
+        let mut view = StatusView::new();
+        view.set_status(status_with_untracked());
+        view.jump_to_bottom(10);
+        assert_eq!(view.selected_file_path(), Some("notes.txt"));
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('U')));
+        assert_eq!(action, StatusAction::None);
+        assert_eq!(view.status.as_ref().unwrap().files.len(), 3);
+        // Selection fell out of range and was reset
+        assert_eq!(view.selected_file_path(), Some("src/main.rs"));
+        assert_eq!(view.category_counts(), (3, 1, 0));
+
+        view.handle_key(KeyEvent::from(KeyCode::Char('U')));
+        assert_eq!(view.status.as_ref().unwrap().files.len(), 4);
+    }
+
+    #[test]
+    fn test_toggle_ignored_requests_scan_and_lists_results() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('I')));
+        assert_eq!(action, StatusAction::LoadIgnored);
+        view.set_ignored(vec!["target/".to_string()]);
+        let files = &view.status.as_ref().unwrap().files;
+        assert_eq!(files.last().unwrap().state, FileState::Ignored);
+        assert_eq!(view.category_counts(), (3, 0, 1));
+
+        // Switching off drops the scan result
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('I')));
+        assert_eq!(action, StatusAction::None);
+        assert_eq!(view.status.as_ref().unwrap().files.len(), 3);
+        assert_eq!(view.category_counts(), (3, 0, 0));
+    }
+
+    #[test]
+    fn test_untracked_file_only_allows_gitignore() {
+        let mut view = StatusView::new();
+        view.set_status(status_with_untracked());
+        view.jump_to_bottom(10);
+
+        for key in ['r', 'x', 'a', 'L', 'D', 'E'] {
+            let action = view.handle_key(KeyEvent::from(KeyCode::Char(key)));
+            assert_eq!(action, StatusAction::None, "key {}", key);
+        }
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Enter)),
+            StatusAction::None
+        );
+        assert_eq!(
+            view.handle_key(KeyEvent::from(KeyCode::Char('i'))),
+            StatusAction::AddToGitignore {
+                file_path: "notes.txt".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_r_key_different_from_r_uppercase() {
         let mut view = StatusView::new();
@@ -481,6 +811,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         view.set_status(empty_status);
 
@@ -497,6 +828,7 @@ mod tests {
             has_conflicts: false,
             working_copy_change_id: ChangeId::new("abc".to_string()),
             parent_change_id: ChangeId::new("xyz".to_string()),
+            warnings: Vec::new(),
         };
         view.set_status(empty_status);
 
@@ -514,7 +846,7 @@ mod tests {
         // r should be treated as text input, not restore
         let action = view.handle_key(KeyEvent::from(KeyCode::Char('r')));
         assert_eq!(action, StatusAction::None);
-        assert_eq!(view.input_buffer, "r");
+        assert_eq!(view.commit_editor.text(), "r");
     }
 
     // =============================================================================
@@ -535,6 +867,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_d_uppercase_returns_open_diff_tool() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+
+        let action = view.handle_key(KeyEvent::from(KeyCode::Char('D')));
+        assert_eq!(
+            action,
+            StatusAction::OpenDiffTool {
+                file_path: "src/main.rs".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_f_key_without_conflicts() {
         let mut view = StatusView::new();
@@ -544,4 +890,26 @@ mod tests {
         assert_eq!(action, StatusAction::None);
         assert_eq!(view.selected_index, 0);
     }
+
+    #[test]
+    fn test_toggle_warnings_key() {
+        let mut view = StatusView::new();
+        view.set_status(sample_status());
+        view.handle_key(KeyEvent::from(KeyCode::Char('!')));
+        assert!(
+            !view.warnings_expanded,
+            "nothing to expand without warnings"
+        );
+
+        let mut status = sample_status();
+        status.warnings.push(crate::model::StatusWarning {
+            kind: crate::model::StatusWarningKind::Hint,
+            lines: vec!["Hint: run `jj file track`".to_string()],
+        });
+        view.set_status(status);
+        view.handle_key(KeyEvent::from(KeyCode::Char('!')));
+        assert!(view.warnings_expanded);
+        view.handle_key(KeyEvent::from(KeyCode::Char('!')));
+        assert!(!view.warnings_expanded);
+    }
 }
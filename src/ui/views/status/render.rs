@@ -9,9 +9,12 @@ use ratatui::{
 };
 
 use super::{StatusInputMode, StatusView};
-use crate::model::{FileState, Notification, Status};
+use crate::model::{FileState, Notification, Status, StatusWarning, StatusWarningKind};
 use crate::ui::{components, theme};
 
+/// Height of the commit message editor pane (including borders)
+const COMMIT_EDITOR_HEIGHT: u16 = 8;
+
 impl StatusView {
     /// Render the view with optional notification in title bar
     pub fn render(&self, frame: &mut Frame, area: Rect, notification: Option<&Notification>) {
@@ -19,8 +22,11 @@ impl StatusView {
         let (status_area, input_area) = match self.input_mode {
             StatusInputMode::Normal => (area, None),
             StatusInputMode::CommitInput => {
-                let chunks =
-                    Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).split(area);
+                let chunks = Layout::vertical([
+                    Constraint::Min(1),
+                    Constraint::Length(COMMIT_EDITOR_HEIGHT),
+                ])
+                .split(area);
                 (chunks[0], Some(chunks[1]))
             }
         };
@@ -49,7 +55,7 @@ impl StatusView {
                 let content = components::empty_state("Loading...", None).block(block);
                 frame.render_widget(content, status_area);
             }
-            Some(status) if status.is_clean() => {
+            Some(status) if status.files.is_empty() && status.warnings.is_empty() => {
                 // Clean state
                 let content =
                     components::empty_state("Working copy is clean.", Some("No modified files."))
@@ -62,51 +68,13 @@ impl StatusView {
             }
         }
 
-        // Render input bar if in input mode
+        // Render commit message editor if in input mode
         if let Some(input_area) = input_area {
-            self.render_input_bar(frame, input_area);
+            self.commit_editor
+                .render(frame, input_area, "Commit message");
         }
     }
 
-    /// Render the commit input bar
-    fn render_input_bar(&self, frame: &mut Frame, area: Rect) {
-        let prompt = "Commit message: ";
-        let input_text = format!("{}{}", prompt, self.input_buffer);
-
-        // Calculate available width (area width minus borders)
-        let available_width = area.width.saturating_sub(2) as usize;
-
-        if available_width == 0 {
-            return;
-        }
-
-        // Truncate display text if too long (show end of input, UTF-8 safe)
-        let char_count = input_text.chars().count();
-        let display_text = if char_count > available_width {
-            let skip = char_count.saturating_sub(available_width.saturating_sub(1));
-            format!("…{}", input_text.chars().skip(skip).collect::<String>())
-        } else {
-            input_text.clone()
-        };
-
-        // Title with key hints
-        let title = Line::from(vec![
-            Span::raw(" "),
-            Span::styled("[Enter]", Style::default().fg(theme::status_view::ADDED)),
-            Span::raw(" Save  "),
-            Span::styled("[Esc]", Style::default().fg(theme::status_view::DELETED)),
-            Span::raw(" Cancel "),
-        ]);
-
-        let paragraph = Paragraph::new(display_text).block(components::bordered_block(title));
-
-        frame.render_widget(paragraph, area);
-
-        // Show cursor
-        let cursor_pos = char_count.min(available_width);
-        frame.set_cursor_position((area.x + cursor_pos as u16 + 1, area.y + 1));
-    }
-
     /// Render the file list
     fn render_file_list(
         &self,
@@ -124,9 +92,15 @@ impl StatusView {
             .count();
         let has_conflict_line = conflict_count > 0;
 
+        let warning_lines = build_warning_lines(&status.warnings, self.warnings_expanded);
+        let category_line = self.build_category_line();
+
         // Calculate available height for files (minus borders and header)
-        // 2 borders + 3 header lines (+ 1 if conflict line shown)
-        let header_lines = if has_conflict_line { 4 } else { 3 };
+        // 2 borders + 3 header lines (+ 1 if conflict line shown, + 1 if
+        // category counts shown, + warnings)
+        let header_lines = if has_conflict_line { 4 } else { 3 }
+            + usize::from(category_line.is_some())
+            + warning_lines.len();
         let inner_height = area.height.saturating_sub(2 + header_lines as u16) as usize;
 
         // Build lines
@@ -161,8 +135,18 @@ impl StatusView {
             )]));
         }
 
+        lines.extend(category_line);
+        lines.extend(warning_lines);
+
         lines.push(Line::from("")); // Separator
 
+        if status.is_clean() {
+            lines.push(Line::from(Span::styled(
+                " Working copy is clean.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
         // File list
         let header_count = header_lines + 1; // +1 for separator
         for (idx, file) in status.files.iter().enumerate().skip(self.scroll_offset) {
@@ -188,6 +172,40 @@ impl StatusView {
         frame.render_widget(paragraph, area);
     }
 
+    /// Per-category counts with the toggle state, shown once untracked or
+    /// ignored paths are involved
+    fn build_category_line(&self) -> Option<Line<'static>> {
+        let (changed, untracked, ignored) = self.category_counts();
+        if untracked == 0 && !self.show_ignored {
+            return None;
+        }
+        let untracked_toggle = if self.show_untracked {
+            "U: hide"
+        } else {
+            "U: show"
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!(" Changed: {}", changed),
+                Style::default().fg(theme::status_view::HEADER),
+            ),
+            Span::styled(
+                format!("   Untracked: {} ({})", untracked, untracked_toggle),
+                Style::default().fg(theme::status_view::UNTRACKED),
+            ),
+        ];
+        let ignored_label = if self.show_ignored {
+            format!("   Ignored: {} (I: hide)", ignored)
+        } else {
+            "   Ignored: - (I: show)".to_string()
+        };
+        spans.push(Span::styled(
+            ignored_label,
+            Style::default().fg(theme::status_view::IGNORED),
+        ));
+        Some(Line::from(spans))
+    }
+
     /// Build a line for a file entry
     fn build_file_line(&self, file: &crate::model::FileStatus, is_selected: bool) -> Line<'static> {
         let indicator = file.indicator();
@@ -197,6 +215,8 @@ impl StatusView {
             FileState::Deleted => theme::status_view::DELETED,
             FileState::Renamed { .. } => theme::status_view::RENAMED,
             FileState::Conflicted => theme::status_view::CONFLICTED,
+            FileState::Untracked => theme::status_view::UNTRACKED,
+            FileState::Ignored => theme::status_view::IGNORED,
         };
 
         let mut spans = vec![
@@ -238,3 +258,96 @@ impl StatusView {
         line
     }
 }
+
+/// Lines for the jj warnings section: a one-line summary when collapsed,
+/// every block (headline + details) when expanded
+fn build_warning_lines(warnings: &[StatusWarning], expanded: bool) -> Vec<Line<'static>> {
+    let Some(first) = warnings.first() else {
+        return Vec::new();
+    };
+    let summary_style = Style::default().fg(Color::Yellow);
+    if !expanded {
+        let count = if warnings.len() == 1 {
+            "1 jj message".to_string()
+        } else {
+            format!("{} jj messages", warnings.len())
+        };
+        return vec![Line::from(Span::styled(
+            format!(" \u{25B8} {}: {} (! to expand)", count, first.headline()),
+            summary_style,
+        ))];
+    }
+
+    let mut lines = vec![Line::from(Span::styled(
+        " \u{25BE} jj messages (! to collapse)",
+        summary_style,
+    ))];
+    for warning in warnings {
+        let color = match warning.kind {
+            StatusWarningKind::Warning => Color::Yellow,
+            StatusWarningKind::Hint => Color::Cyan,
+            StatusWarningKind::Info => Color::Gray,
+        };
+        lines.push(Line::from(Span::styled(
+            format!("   {}", warning.headline()),
+            Style::default().fg(color),
+        )));
+        for detail in warning.lines.iter().skip(1) {
+            lines.push(Line::from(Span::styled(
+                format!("     {}", detail.trim()),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn sample_warnings() -> Vec<StatusWarning> {
+        vec![
+            StatusWarning {
+                kind: StatusWarningKind::Warning,
+                lines: vec![
+                    "Warning: Refused to snapshot some files:".to_string(),
+                    "  big.bin: 12.0MiB (12582912 bytes); the maximum size allowed is 1.0MiB (1048576 bytes)".to_string(),
+                ],
+            },
+            StatusWarning {
+                kind: StatusWarningKind::Hint,
+                lines: vec!["Hint: This is to prevent large files from being added by accident.".to_string()],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_warning_lines_collapsed_summary() {
+        let lines = build_warning_lines(&sample_warnings(), false);
+        assert_eq!(lines.len(), 1);
+        let text = line_text(&lines[0]);
+        assert!(text.contains("2 jj messages"));
+        assert!(text.contains("Refused to snapshot"));
+        assert!(text.ends_with("(! to expand)"));
+    }
+
+    #[test]
+    fn test_warning_lines_expanded_shows_details() {
+        let lines = build_warning_lines(&sample_warnings(), true);
+        let texts: Vec<String> = lines.iter().map(line_text).collect();
+        assert_eq!(texts.len(), 4);
+        assert_eq!(texts[1], "   Warning: Refused to snapshot some files:");
+        assert!(texts[2].starts_with("     big.bin: 12.0MiB"));
+        assert!(texts[3].starts_with("   Hint: This is to prevent"));
+    }
+
+    #[test]
+    fn test_warning_lines_empty() {
+        assert!(build_warning_lines(&[], true).is_empty());
+    }
+}
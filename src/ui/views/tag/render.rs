@@ -71,7 +71,7 @@ fn build_tag_line(tag: &TagInfo, is_selected: bool) -> Line<'static> {
 
     if let Some(ref change_id) = tag.change_id {
         spans.push(Span::styled(
-            format!("  {:<10}", change_id),
+            format!("  {:<10}", change_id.as_str()),
             Style::default().fg(Color::Yellow),
         ));
     } else {
@@ -76,7 +76,7 @@ fn build_workspace_line(ws: &WorkspaceInfo, is_selected: bool, is_current: bool)
             Style::default().fg(Color::Green),
         ),
         Span::styled(
-            format!("  {:<10}", ws.change_id),
+            format!("  {:<10}", ws.change_id.as_str()),
             Style::default().fg(Color::Yellow),
         ),
     ];
@@ -0,0 +1,185 @@
+//! Progress screen for `tij clone`
+//!
+//! Shown before the full TUI exists: the URL, the destination, a gauge when
+//! git reports a percentage (a spinner otherwise) and the latest output.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    Frame,
+    layout::Flex,
+    prelude::*,
+    widgets::{Block, Borders, Gauge, Paragraph},
+};
+
+use crate::jj::progress_percent;
+
+/// Output lines kept on screen
+const MAX_LINES: usize = 6;
+
+const SPINNER: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// What the progress screen shows
+#[derive(Debug)]
+pub struct CloneProgress {
+    url: String,
+    destination: String,
+    started: Instant,
+    percent: Option<u16>,
+    lines: VecDeque<String>,
+}
+
+impl CloneProgress {
+    pub fn new(url: impl Into<String>, destination: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            destination: destination.into(),
+            started: Instant::now(),
+            percent: None,
+            lines: VecDeque::new(),
+        }
+    }
+
+    /// Record a line of jj/git output
+    ///
+    /// A progress line replaces the previous one of the same phase
+    /// (`Receiving objects: 10%` → `Receiving objects: 20%`).
+    pub fn push_line(&mut self, line: String) {
+        let percent = progress_percent(&line);
+        if percent.is_some() {
+            self.percent = percent;
+            if self.lines.back().is_some_and(|last| {
+                progress_percent(last).is_some() && phase_of(last) == phase_of(&line)
+            }) {
+                self.lines.pop_back();
+            }
+        }
+        self.lines.push_back(line);
+        while self.lines.len() > MAX_LINES {
+            self.lines.pop_front();
+        }
+    }
+
+    /// Latest reported percentage
+    pub fn percent(&self) -> Option<u16> {
+        self.percent
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+/// `Receiving objects` of `Receiving objects:  45% (450/1000)`
+fn phase_of(line: &str) -> &str {
+    let before_percent = line.split('%').next().unwrap_or(line);
+    before_percent
+        .rsplit_once(':')
+        .map_or(before_percent, |(phase, _)| phase)
+        .trim()
+}
+
+/// Render the clone progress box in the middle of the screen
+pub fn render_clone_progress(frame: &mut Frame, progress: &CloneProgress) {
+    let width = frame.area().width.clamp(20, 80);
+    let height = (MAX_LINES as u16 + 7).min(frame.area().height);
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(" Cloning ").bold().fg(Color::Cyan).centered())
+        .title_bottom(Line::from(" Esc: cancel ").fg(Color::DarkGray).centered());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [info, gauge, output] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Length(2),
+        Constraint::Min(0),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(vec![
+                Span::styled("From ", Style::default().fg(Color::DarkGray)),
+                Span::raw(progress.url.as_str()),
+            ]),
+            Line::from(vec![
+                Span::styled("Into ", Style::default().fg(Color::DarkGray)),
+                Span::raw(progress.destination.as_str()),
+            ]),
+        ]),
+        info,
+    );
+
+    let elapsed = progress.elapsed();
+    let gauge_area = Rect { height: 1, ..gauge };
+    match progress.percent {
+        Some(percent) => frame.render_widget(
+            Gauge::default()
+                .gauge_style(Style::default().fg(Color::Green))
+                .percent(percent)
+                .label(format!("{}% · {}s", percent, elapsed.as_secs())),
+            gauge_area,
+        ),
+        None => {
+            let frame_index = (elapsed.as_millis() / 100) as usize % SPINNER.len();
+            frame.render_widget(
+                Paragraph::new(format!(
+                    "{} working… {}s",
+                    SPINNER[frame_index],
+                    elapsed.as_secs()
+                ))
+                .fg(Color::Yellow),
+                gauge_area,
+            );
+        }
+    }
+
+    let lines: Vec<Line> = progress
+        .lines
+        .iter()
+        .map(|line| Line::from(line.as_str()).fg(Color::DarkGray))
+        .collect();
+    frame.render_widget(Paragraph::new(lines), output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_lines_replace_same_phase() {
+        let mut progress = CloneProgress::new("https://example.com/repo.git", "repo");
+        progress.push_line("Fetching into new repo".to_string());
+        progress.push_line("remote: Receiving objects:  10% (1/10)".to_string());
+        progress.push_line("remote: Receiving objects:  50% (5/10)".to_string());
+        progress.push_line("remote: Resolving deltas:  20% (1/5)".to_string());
+        assert_eq!(progress.percent(), Some(20));
+        assert_eq!(
+            progress.lines,
+            [
+                "Fetching into new repo",
+                "remote: Receiving objects:  50% (5/10)",
+                "remote: Resolving deltas:  20% (1/5)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_progress_keeps_last_lines() {
+        let mut progress = CloneProgress::new("url", "repo");
+        for i in 0..10 {
+            progress.push_line(format!("line {}", i));
+        }
+        assert_eq!(progress.lines.len(), MAX_LINES);
+        assert_eq!(progress.lines.front().map(String::as_str), Some("line 4"));
+    }
+}
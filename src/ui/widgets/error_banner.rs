@@ -1,4 +1,4 @@
-//! Error banner widget
+//! Error and suggestion banner widgets
 
 use ratatui::{Frame, prelude::*, widgets::Paragraph};
 
@@ -20,3 +20,17 @@ pub fn render_error_banner(frame: &mut Frame, error: &str, status_bar_height: u1
     let error_line = components::build_error_line(error);
     frame.render_widget(Paragraph::new(error_line), error_area);
 }
+
+/// Render a suggestion in the error banner's place (errors take precedence)
+pub fn render_suggestion_banner(frame: &mut Frame, suggestion: &str, status_bar_height: u16) {
+    let area = frame.area();
+    let banner_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(status_bar_height + 1),
+        width: area.width,
+        height: 1,
+    };
+
+    let line = components::build_suggestion_line(suggestion);
+    frame.render_widget(Paragraph::new(line), banner_area);
+}
@@ -7,10 +7,16 @@ use ratatui::{
     layout::{Constraint, Layout},
     prelude::*,
     text::Line,
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
 
-use crate::keys;
+use crate::app::View;
+use crate::keys::{self, KeymapPreset};
+use crate::ui::components::LineInput;
+
+/// Columns/rows of the underlying view left visible around the overlay
+const OVERLAY_MARGIN_X: u16 = 4;
+const OVERLAY_MARGIN_Y: u16 = 2;
 
 /// Synonym map for keyword-linked highlighting.
 /// When a search query matches a trigger keyword (prefix match supported),
@@ -62,14 +68,23 @@ pub struct HelpLine {
 
 /// Build all help panel lines (Single Source of Truth for rendering and search).
 ///
+/// `context` is the view Help was opened from: its section comes first,
+/// marked as the current view. Sections are generated from the keymap
+/// registry ([`keys::view_keymap`]), so a view's bindings appear once.
+///
 /// When `search_query` is `Some`, matching entries get `matched = true` and
 /// are rendered with a highlight style.
-pub fn build_help_lines(search_query: Option<&str>) -> Vec<HelpLine> {
+pub fn build_help_lines(
+    context: Option<View>,
+    preset: KeymapPreset,
+    search_query: Option<&str>,
+) -> Vec<HelpLine> {
     let query_lower = search_query.map(|q| q.to_lowercase());
     let synonyms = query_lower
         .as_deref()
         .map(expand_synonyms)
         .unwrap_or_default();
+    let query = query_lower.as_deref();
 
     let mut lines = Vec::new();
 
@@ -85,90 +100,72 @@ pub fn build_help_lines(search_query: Option<&str>) -> Vec<HelpLine> {
         matched: false,
     });
 
+    let context_keymap = context.and_then(keys::view_keymap);
+    if let Some((title, entries)) = context_keymap {
+        push_section(
+            &mut lines,
+            preset,
+            &format!("{title} (current)"),
+            context,
+            entries,
+            query,
+            &synonyms,
+        );
+    }
     push_section(
         &mut lines,
+        preset,
         "Global",
+        None,
         keys::GLOBAL_KEYS,
-        query_lower.as_deref(),
+        query,
         &synonyms,
     );
     push_section(
         &mut lines,
+        preset,
         "Navigation",
+        None,
         keys::NAV_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Log View",
-        keys::LOG_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Input Mode",
-        keys::INPUT_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Diff View",
-        keys::DIFF_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Status View",
-        keys::STATUS_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Bookmark View",
-        keys::BOOKMARK_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Tag View",
-        keys::TAG_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Workspace View",
-        keys::WORKSPACE_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Command History View",
-        keys::COMMAND_HISTORY_KEYS,
-        query_lower.as_deref(),
-        &synonyms,
-    );
-    push_section(
-        &mut lines,
-        "Operation View",
-        keys::OPERATION_KEYS,
-        query_lower.as_deref(),
+        query,
         &synonyms,
     );
+    for view in keys::HELP_VIEW_ORDER {
+        if Some(*view) != context
+            && let Some((title, entries)) = keys::view_keymap(*view)
+        {
+            push_section(
+                &mut lines,
+                preset,
+                title,
+                Some(*view),
+                entries,
+                query,
+                &synonyms,
+            );
+        }
+        // Text input keys belong with the Log View they are used in
+        if *view == View::Log {
+            push_section(
+                &mut lines,
+                preset,
+                "Input Mode",
+                None,
+                keys::INPUT_KEYS,
+                query,
+                &synonyms,
+            );
+        }
+    }
 
     lines
 }
 
 fn push_section(
     lines: &mut Vec<HelpLine>,
+    preset: KeymapPreset,
     title: &str,
+    view: Option<View>,
     entries: &[keys::KeyBindEntry],
     query_lower: Option<&str>,
     synonyms: &[&str],
@@ -181,8 +178,9 @@ fn push_section(
     });
 
     for entry in entries {
+        let key = keys::preset_label(preset, view, entry.key);
         let matched = query_lower.is_some_and(|q| {
-            let key_lc = entry.key.to_lowercase();
+            let key_lc = key.to_lowercase();
             let desc_lc = entry.description.to_lowercase();
             key_lc.contains(q)
                 || desc_lc.contains(q)
@@ -205,7 +203,7 @@ fn push_section(
 
         lines.push(HelpLine {
             line: Line::from(vec![
-                Span::styled(format!("  {:10}", entry.key), key_style),
+                Span::styled(format!("  {:10}", key), key_style),
                 Span::styled(entry.description.to_string(), style),
             ]),
             is_entry: true,
@@ -222,8 +220,8 @@ fn push_section(
 }
 
 /// Collect indices of matching lines (for n/N navigation)
-pub fn matching_line_indices(query: &str) -> Vec<u16> {
-    build_help_lines(Some(query))
+pub fn matching_line_indices(context: Option<View>, preset: KeymapPreset, query: &str) -> Vec<u16> {
+    build_help_lines(context, preset, Some(query))
         .iter()
         .enumerate()
         .filter(|(_, l)| l.matched)
@@ -241,12 +239,76 @@ pub fn matching_line_indices(query: &str) -> Vec<u16> {
 pub fn render_help_panel(
     frame: &mut Frame,
     area: Rect,
+    preset: KeymapPreset,
     scroll: u16,
     search_query: Option<&str>,
-    search_input: Option<&str>,
+    search_input: Option<&LineInput>,
 ) {
     let title = Line::from(" Tij - Help ").bold().white().centered();
+    render_help(
+        frame,
+        area,
+        title,
+        None,
+        preset,
+        scroll,
+        search_query,
+        search_input,
+    );
+}
 
+/// Render help as an overlay on top of the view it was opened from
+///
+/// The section of `context` is listed first and named in the title; the
+/// view stays visible around the overlay's margins.
+pub fn render_help_overlay(
+    frame: &mut Frame,
+    area: Rect,
+    context: Option<View>,
+    preset: KeymapPreset,
+    scroll: u16,
+    search_query: Option<&str>,
+    search_input: Option<&LineInput>,
+) {
+    let overlay = Rect {
+        x: area.x + OVERLAY_MARGIN_X.min(area.width / 8),
+        y: area.y + OVERLAY_MARGIN_Y.min(area.height / 8),
+        width: area
+            .width
+            .saturating_sub(2 * OVERLAY_MARGIN_X.min(area.width / 8)),
+        height: area
+            .height
+            .saturating_sub(2 * OVERLAY_MARGIN_Y.min(area.height / 8)),
+    };
+    let title = match context.and_then(keys::view_keymap) {
+        Some((name, _)) => format!(" Help: {name} "),
+        None => " Tij - Help ".to_string(),
+    };
+    let title = Line::from(title).bold().white().centered();
+    frame.render_widget(Clear, overlay);
+    render_help(
+        frame,
+        overlay,
+        title,
+        context,
+        preset,
+        scroll,
+        search_query,
+        search_input,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_help(
+    frame: &mut Frame,
+    area: Rect,
+    title: Line<'static>,
+    context: Option<View>,
+    preset: KeymapPreset,
+    scroll: u16,
+    search_query: Option<&str>,
+    search_input: Option<&LineInput>,
+) {
     // Split area for input bar if searching
     let (help_area, input_area) = if search_input.is_some() {
         let chunks = Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).split(area);
@@ -255,7 +317,7 @@ pub fn render_help_panel(
         (area, None)
     };
 
-    let help_lines = build_help_lines(search_query);
+    let help_lines = build_help_lines(context, preset, search_query);
     let display_lines: Vec<Line<'static>> = help_lines.into_iter().map(|hl| hl.line).collect();
 
     frame.render_widget(
@@ -267,15 +329,8 @@ pub fn render_help_panel(
 
     // Render search input bar
     if let Some(buffer) = search_input {
-        let input_text = format!("Search: {buffer}");
         let available_width = input_area.unwrap().width.saturating_sub(2) as usize;
-        let char_count = input_text.chars().count();
-        let display_text = if char_count > available_width && available_width > 0 {
-            let skip = char_count.saturating_sub(available_width.saturating_sub(1));
-            format!("…{}", input_text.chars().skip(skip).collect::<String>())
-        } else {
-            input_text.clone()
-        };
+        let (display_text, cursor_pos) = buffer.display("Search: ", available_width);
 
         let input_bar = Paragraph::new(display_text).block(
             Block::default()
@@ -286,7 +341,6 @@ pub fn render_help_panel(
         frame.render_widget(input_bar, ia);
 
         // Cursor position
-        let cursor_pos = char_count.min(available_width);
         frame.set_cursor_position((ia.x + cursor_pos as u16 + 1, ia.y + 1));
     }
 }
@@ -297,21 +351,21 @@ mod tests {
 
     #[test]
     fn build_help_lines_no_query_has_no_matches() {
-        let lines = build_help_lines(None);
+        let lines = build_help_lines(None, KeymapPreset::Vim, None);
         assert!(lines.iter().all(|l| !l.matched));
         assert!(!lines.is_empty());
     }
 
     #[test]
     fn build_help_lines_quit_matches() {
-        let lines = build_help_lines(Some("quit"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("quit"));
         let matched: Vec<_> = lines.iter().filter(|l| l.matched).collect();
         assert!(!matched.is_empty(), "Should match at least one Quit entry");
     }
 
     #[test]
     fn build_help_lines_bookmark_matches_multiple_sections() {
-        let lines = build_help_lines(Some("bookmark"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("bookmark"));
         let matched: Vec<_> = lines.iter().filter(|l| l.matched).collect();
         assert!(
             matched.len() >= 2,
@@ -321,14 +375,14 @@ mod tests {
 
     #[test]
     fn build_help_lines_no_match_returns_all_false() {
-        let lines = build_help_lines(Some("zzzzzznonexistent"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("zzzzzznonexistent"));
         assert!(lines.iter().all(|l| !l.matched));
     }
 
     #[test]
     fn build_help_lines_case_insensitive() {
-        let upper = build_help_lines(Some("QUIT"));
-        let lower = build_help_lines(Some("quit"));
+        let upper = build_help_lines(None, KeymapPreset::Vim, Some("QUIT"));
+        let lower = build_help_lines(None, KeymapPreset::Vim, Some("quit"));
         let upper_count = upper.iter().filter(|l| l.matched).count();
         let lower_count = lower.iter().filter(|l| l.matched).count();
         assert_eq!(
@@ -340,10 +394,10 @@ mod tests {
 
     #[test]
     fn matching_line_indices_returns_correct_indices() {
-        let indices = matching_line_indices("quit");
+        let indices = matching_line_indices(None, KeymapPreset::Vim, "quit");
         assert!(!indices.is_empty());
         // Verify indices are valid
-        let lines = build_help_lines(Some("quit"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("quit"));
         for &idx in &indices {
             assert!(lines[idx as usize].matched);
         }
@@ -351,13 +405,13 @@ mod tests {
 
     #[test]
     fn matching_line_indices_empty_for_nonexistent() {
-        let indices = matching_line_indices("zzzzz");
+        let indices = matching_line_indices(None, KeymapPreset::Vim, "zzzzz");
         assert!(indices.is_empty());
     }
 
     #[test]
     fn build_help_lines_entries_have_is_entry_true() {
-        let lines = build_help_lines(None);
+        let lines = build_help_lines(None, KeymapPreset::Vim, None);
         let entries: Vec<_> = lines.iter().filter(|l| l.is_entry).collect();
         assert!(entries.len() > 20, "Should have many key binding entries");
     }
@@ -395,7 +449,7 @@ mod tests {
 
     #[test]
     fn build_help_lines_commit_highlights_describe() {
-        let lines = build_help_lines(Some("commit"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("commit"));
         let matched_descs: Vec<_> = lines
             .iter()
             .filter(|l| l.matched && l.is_entry)
@@ -409,7 +463,7 @@ mod tests {
 
     #[test]
     fn build_help_lines_rebase_prefix_highlights_move() {
-        let lines = build_help_lines(Some("reb"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("reb"));
         let matched_descs: Vec<_> = lines
             .iter()
             .filter(|l| l.matched && l.is_entry)
@@ -423,7 +477,7 @@ mod tests {
 
     #[test]
     fn build_help_lines_original_search_unaffected() {
-        let lines = build_help_lines(Some("quit"));
+        let lines = build_help_lines(None, KeymapPreset::Vim, Some("quit"));
         let matched: Vec<_> = lines.iter().filter(|l| l.matched).collect();
         assert!(
             !matched.is_empty(),
@@ -433,8 +487,8 @@ mod tests {
 
     #[test]
     fn matching_line_indices_includes_synonyms() {
-        let commit_indices = matching_line_indices("commit");
-        let describe_indices = matching_line_indices("describe");
+        let commit_indices = matching_line_indices(None, KeymapPreset::Vim, "commit");
+        let describe_indices = matching_line_indices(None, KeymapPreset::Vim, "describe");
         // "commit" should pick up at least one "describe" match via synonyms
         assert!(
             !describe_indices.is_empty(),
@@ -449,4 +503,13 @@ mod tests {
             "commit search should include at least one describe match via synonyms"
         );
     }
+
+    #[test]
+    fn build_help_lines_context_section_comes_first_once() {
+        let lines = build_help_lines(Some(View::Diff), KeymapPreset::Vim, None);
+        let text: Vec<String> = lines.iter().map(|l| l.line.to_string()).collect();
+        assert_eq!(text[2], "Diff View (current):");
+        assert!(!text.iter().any(|t| t == "Diff View:"));
+        assert!(text.iter().any(|t| t == "Log View:"));
+    }
 }
@@ -1,13 +1,18 @@
 //! Reusable UI widgets
 
+mod clone_progress;
 mod error_banner;
 mod help_panel;
 mod placeholder;
 mod status_bar;
+mod status_template;
 
-pub use error_banner::render_error_banner;
-pub use help_panel::{matching_line_indices, render_help_panel};
+pub use clone_progress::{CloneProgress, render_clone_progress};
+pub use error_banner::{render_error_banner, render_suggestion_banner};
+pub use help_panel::{matching_line_indices, render_help_overlay, render_help_panel};
 pub use placeholder::render_placeholder;
 pub use status_bar::{
-    render_blame_status_bar, render_diff_status_bar, render_status_hints, status_hints_height,
+    fit_hints, render_blame_status_bar, render_diff_status_bar, render_status_hints,
+    status_hints_height,
 };
+pub use status_template::{StatusTemplate, StatusVar, StatusVars};
@@ -2,10 +2,14 @@
 //!
 //! Provides key hint display at the bottom of the screen.
 //! Automatically switches to 2-row layout when terminal is too narrow.
+//! The `tij.status-bar` template text is shown right-aligned on the last row.
 
 use ratatui::{Frame, prelude::*, text::Line, widgets::Paragraph};
 
 use crate::keys::KeyHint;
+
+/// Narrowest truncated status text worth showing
+const MIN_STATUS_TEXT_WIDTH: usize = 8;
 use crate::ui::views::{BlameView, DiffView};
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -85,6 +89,29 @@ fn build_content(hints: &[KeyHint], width: u16) -> Vec<Line<'static>> {
     ]
 }
 
+/// Right-align `text` after the hints of `line`, truncated with `…` when
+/// short of room (dropped when even that doesn't fit)
+fn with_status_text(mut line: Line<'static>, text: &str, width: u16) -> Line<'static> {
+    let free = (width as usize).saturating_sub(line.width() + 2);
+    let len = text.chars().count();
+    if text.is_empty() || free < MIN_STATUS_TEXT_WIDTH.min(len) {
+        return line;
+    }
+    let text = if len > free {
+        let kept: String = text.chars().take(free - 1).collect();
+        format!("{}…", kept)
+    } else {
+        text.to_string()
+    };
+    let padding = (width as usize).saturating_sub(line.width() + text.chars().count() + 1);
+    line.spans.push(Span::raw(" ".repeat(padding)));
+    line.spans.push(Span::styled(
+        text,
+        Style::default().fg(crate::ui::theme::status_bar::TEMPLATE),
+    ));
+    line
+}
+
 /// Build a status bar line with a prefix and key hints
 pub fn build_status_bar_with_prefix(
     prefix: Vec<Span<'static>>,
@@ -104,6 +131,15 @@ pub fn build_status_bar_with_prefix(
 // Height calculation (for layout)
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// The hints that fit in one row of `width` (compact layout)
+pub fn fit_hints(hints: &[KeyHint], width: u16) -> &[KeyHint] {
+    let count = (0..=hints.len())
+        .rev()
+        .find(|&n| total_hints_width(&hints[..n]) <= width as usize)
+        .unwrap_or(0);
+    &hints[..count]
+}
+
 /// Calculate status bar height for given hints and width
 pub fn status_hints_height(hints: &[KeyHint], width: u16) -> u16 {
     if total_hints_width(hints) > width as usize {
@@ -137,17 +173,21 @@ fn status_bar_area(frame: &Frame, hints: &[KeyHint]) -> Option<Rect> {
     })
 }
 
-/// Render status bar hints at the bottom of the screen
-pub fn render_status_hints(frame: &mut Frame, hints: &[KeyHint]) {
+/// Render status bar hints at the bottom of the screen, with the rendered
+/// status template (may be empty) on the right
+pub fn render_status_hints(frame: &mut Frame, hints: &[KeyHint], status_text: &str) {
     let Some(status_area) = status_bar_area(frame, hints) else {
         return;
     };
 
-    let content = if status_area.height >= 3 {
+    let mut content = if status_area.height >= 3 {
         build_content(hints, status_area.width)
     } else {
         vec![build_line(hints)]
     };
+    if let Some(last) = content.pop() {
+        content.push(with_status_text(last, status_text, status_area.width));
+    }
 
     frame.render_widget(Paragraph::new(content), status_area);
 }
@@ -160,7 +200,7 @@ pub fn render_diff_status_bar(frame: &mut Frame, diff_view: &DiffView) {
     };
 
     let context = diff_view.current_context();
-    let prefix = vec![
+    let mut prefix = vec![
         Span::styled(
             format!(" {} ", diff_view.revision),
             Style::default().fg(Color::Black).bg(Color::Yellow),
@@ -168,12 +208,31 @@ pub fn render_diff_status_bar(frame: &mut Frame, diff_view: &DiffView) {
         Span::raw(" "),
         Span::styled(format!(" {} ", context), Style::default().fg(Color::Cyan)),
     ];
+    if let Some(conflicts) = diff_view.conflict_context() {
+        prefix.push(Span::styled(
+            format!(" {} (c/C, X) ", conflicts),
+            Style::default().fg(Color::Red).bold(),
+        ));
+    }
 
     let status = build_status_bar_with_prefix(prefix, hints);
     frame.render_widget(Paragraph::new(status), status_area);
 }
 
-/// Render the status bar for blame view (special: includes file path prefix)
+/// Heatmap legend for the blame status bar: `old ■■■■■■ new`
+fn blame_heat_legend() -> Vec<Span<'static>> {
+    let mut spans = vec![Span::styled("old ", Style::default().fg(Color::DarkGray))];
+    spans.extend(
+        crate::ui::theme::blame_view::HEAT
+            .iter()
+            .map(|&color| Span::styled("■", Style::default().fg(color))),
+    );
+    spans.push(Span::styled(" new", Style::default().fg(Color::DarkGray)));
+    spans
+}
+
+/// Render the status bar for blame view (special: includes file path prefix
+/// and the age heatmap legend)
 pub fn render_blame_status_bar(frame: &mut Frame, blame_view: &BlameView) {
     let hints = crate::keys::BLAME_VIEW_HINTS;
     let Some(status_area) = status_bar_area(frame, hints) else {
@@ -181,13 +240,14 @@ pub fn render_blame_status_bar(frame: &mut Frame, blame_view: &BlameView) {
     };
 
     let file_path = blame_view.file_path();
-    let prefix = vec![
+    let mut prefix = vec![
         Span::styled(
             format!(" {} ", file_path),
             Style::default().fg(Color::Black).bg(Color::Yellow),
         ),
         Span::raw(" "),
     ];
+    prefix.extend(blame_heat_legend());
 
     let status = build_status_bar_with_prefix(prefix, hints);
     frame.render_widget(Paragraph::new(status), status_area);
@@ -288,6 +348,43 @@ mod tests {
         assert!(!line.spans.is_empty());
     }
 
+    #[test]
+    fn test_blame_heat_legend_lists_every_heat_color() {
+        let legend = blame_heat_legend();
+        let text: String = legend.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "old ■■■■■■ new");
+        assert_eq!(
+            legend[1].style.fg,
+            Some(crate::ui::theme::blame_view::HEAT[0])
+        );
+    }
+
+    #[test]
+    fn test_status_text_is_right_aligned() {
+        let hints = &[KeyHint {
+            key: "q",
+            label: "Quit",
+            color: Color::Red,
+        }];
+        let line = with_status_text(build_line(hints), "Log @abc", 30);
+        assert_eq!(line.width(), 29);
+        assert_eq!(line.spans.last().unwrap().content, "Log @abc");
+    }
+
+    #[test]
+    fn test_status_text_truncated_or_dropped_when_narrow() {
+        let hints = &[KeyHint {
+            key: "q",
+            label: "Quit",
+            color: Color::Red,
+        }];
+        // " [q] Quit " is 10 wide: 10 columns left after the gap
+        let line = with_status_text(build_line(hints), "Log · @kxqyzprt · 12:34", 22);
+        assert_eq!(line.spans.last().unwrap().content, "Log · @kx…");
+        let line = with_status_text(build_line(hints), "Log · @kxqyzprt", 16);
+        assert_eq!(line.spans.len(), 1);
+    }
+
     #[test]
     fn test_status_hints_height_single() {
         let hints = &[KeyHint {
@@ -0,0 +1,238 @@
+//! Status bar template (`tij.status-bar`)
+//!
+//! A template is plain text with `{variable}` placeholders and `[...]`
+//! conditional segments. A segment is dropped when any variable directly
+//! inside it is empty, so separators and labels disappear with their value:
+//!
+//! ```toml
+//! [tij]
+//! status-bar = "{view}[ · {revset}][ · {bookmark}[ {ahead_behind}]] · @{change_id} · {time}"
+//! ```
+//!
+//! `\` escapes the next character (`\{`, `\[`, `\\`).
+
+/// A value the status bar can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusVar {
+    /// Current view (`Log`, `Diff`, ...)
+    View,
+    /// Log View revset, empty for the default
+    Revset,
+    /// First bookmark on `@`
+    Bookmark,
+    /// Short change ID of `@`
+    ChangeId,
+    /// Current operation ID
+    OpId,
+    /// Ahead/behind of `{bookmark}` against its remote (`↑2 ↓5`, empty in sync)
+    AheadBehind,
+    /// Clock time (`HH:MM`)
+    Time,
+}
+
+impl StatusVar {
+    const ALL: [StatusVar; 7] = [
+        StatusVar::View,
+        StatusVar::Revset,
+        StatusVar::Bookmark,
+        StatusVar::ChangeId,
+        StatusVar::OpId,
+        StatusVar::AheadBehind,
+        StatusVar::Time,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StatusVar::View => "view",
+            StatusVar::Revset => "revset",
+            StatusVar::Bookmark => "bookmark",
+            StatusVar::ChangeId => "change_id",
+            StatusVar::OpId => "op_id",
+            StatusVar::AheadBehind => "ahead_behind",
+            StatusVar::Time => "time",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|var| var.name() == name)
+    }
+}
+
+/// Values substituted into a [`StatusTemplate`] (empty = unavailable)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusVars {
+    pub view: String,
+    pub revset: String,
+    pub bookmark: String,
+    pub change_id: String,
+    pub op_id: String,
+    pub ahead_behind: String,
+    pub time: String,
+}
+
+impl StatusVars {
+    fn get(&self, var: StatusVar) -> &str {
+        match var {
+            StatusVar::View => &self.view,
+            StatusVar::Revset => &self.revset,
+            StatusVar::Bookmark => &self.bookmark,
+            StatusVar::ChangeId => &self.change_id,
+            StatusVar::OpId => &self.op_id,
+            StatusVar::AheadBehind => &self.ahead_behind,
+            StatusVar::Time => &self.time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Var(StatusVar),
+    Optional(Vec<Part>),
+}
+
+/// Parsed `tij.status-bar` template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusTemplate {
+    parts: Vec<Part>,
+}
+
+impl StatusTemplate {
+    /// Parse a template; the error names the first problem found
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut chars = template.chars();
+        let parts = parse_parts(&mut chars, false)?;
+        Ok(Self { parts })
+    }
+
+    /// Whether the template shows `var` (to skip fetching unused values)
+    pub fn uses(&self, var: StatusVar) -> bool {
+        fn any(parts: &[Part], var: StatusVar) -> bool {
+            parts.iter().any(|part| match part {
+                Part::Var(v) => *v == var,
+                Part::Optional(inner) => any(inner, var),
+                Part::Text(_) => false,
+            })
+        }
+        any(&self.parts, var)
+    }
+
+    /// Substitute `vars`, dropping conditional segments with an empty value
+    pub fn render(&self, vars: &StatusVars) -> String {
+        let mut out = String::new();
+        render_parts(&self.parts, vars, &mut out);
+        out
+    }
+}
+
+/// Parse up to the end of input, or up to `]` when `nested`
+fn parse_parts(chars: &mut std::str::Chars, nested: bool) -> Result<Vec<Part>, String> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let flush = |text: &mut String, parts: &mut Vec<Part>| {
+        if !text.is_empty() {
+            parts.push(Part::Text(std::mem::take(text)));
+        }
+    };
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => text.push(chars.next().ok_or("trailing '\\'")?),
+            '{' => {
+                let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let var = StatusVar::parse(name.trim())
+                    .ok_or_else(|| format!("unknown variable {{{}}}", name))?;
+                flush(&mut text, &mut parts);
+                parts.push(Part::Var(var));
+            }
+            '[' => {
+                flush(&mut text, &mut parts);
+                parts.push(Part::Optional(parse_parts(chars, true)?));
+            }
+            ']' if nested => {
+                flush(&mut text, &mut parts);
+                return Ok(parts);
+            }
+            ']' => return Err("unmatched ']'".to_string()),
+            c => text.push(c),
+        }
+    }
+    if nested {
+        return Err("unclosed '['".to_string());
+    }
+    flush(&mut text, &mut parts);
+    Ok(parts)
+}
+
+fn render_parts(parts: &[Part], vars: &StatusVars, out: &mut String) {
+    for part in parts {
+        match part {
+            Part::Text(text) => out.push_str(text),
+            Part::Var(var) => out.push_str(vars.get(*var)),
+            Part::Optional(inner) => {
+                let complete = inner.iter().all(|part| match part {
+                    Part::Var(var) => !vars.get(*var).is_empty(),
+                    _ => true,
+                });
+                if complete {
+                    render_parts(inner, vars, out);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> StatusVars {
+        StatusVars {
+            view: "Log".to_string(),
+            change_id: "kxqyzprt".to_string(),
+            bookmark: "main".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_substitutes_variables() {
+        let template = StatusTemplate::parse("{view} @{ change_id }").unwrap();
+        assert_eq!(template.render(&vars()), "Log @kxqyzprt");
+    }
+
+    #[test]
+    fn test_optional_segment_drops_with_empty_variable() {
+        let template =
+            StatusTemplate::parse("{view}[ · {revset}][ · {bookmark}[ {ahead_behind}]]").unwrap();
+        assert_eq!(template.render(&vars()), "Log · main");
+
+        let mut vars = vars();
+        vars.ahead_behind = "↑2".to_string();
+        vars.revset = "mine()".to_string();
+        assert_eq!(template.render(&vars), "Log · mine() · main ↑2");
+    }
+
+    #[test]
+    fn test_escapes_are_literal() {
+        let template = StatusTemplate::parse(r"\[{view}\] \{x\} \\").unwrap();
+        assert_eq!(template.render(&vars()), r"[Log] {x} \");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            StatusTemplate::parse("{branch}"),
+            Err("unknown variable {branch}".to_string())
+        );
+        assert!(StatusTemplate::parse("[{view}").is_err());
+        assert!(StatusTemplate::parse("{view}]").is_err());
+        assert!(StatusTemplate::parse("{view}\\").is_err());
+    }
+
+    #[test]
+    fn test_uses_looks_inside_segments() {
+        let template = StatusTemplate::parse("{view}[ op {op_id}]").unwrap();
+        assert!(template.uses(StatusVar::OpId));
+        assert!(!template.uses(StatusVar::Time));
+    }
+}
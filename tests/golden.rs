@@ -0,0 +1,121 @@
+//! Golden-file tests: jj templates and rendered views as plain text
+//!
+//! Files live in `tests/snapshots/`. Regenerate them with
+//! `scripts/update-golden.sh` and review the diff like any other change.
+
+use tij::jj::Templates;
+use tij::model::{
+    Bookmark, BookmarkInfo, Change, ChangeId, DEFAULT_ID_LENGTH, LogRow, Operation, TrackingCounts,
+};
+use tij::ui::testing::{assert_golden, render_to_text};
+use tij::ui::views::{BookmarkView, LogView, OperationView};
+
+/// One template per line, so a changed field shows up as one changed line
+fn template_lines(template: &str) -> String {
+    let mut text = template.replace(" ++ ", "\n++ ");
+    text.push('\n');
+    text
+}
+
+#[test]
+fn golden_templates() {
+    for (name, template) in [
+        (
+            "template_log",
+            Templates::log(&LogRow::default(), DEFAULT_ID_LENGTH),
+        ),
+        ("template_op_log", Templates::op_log().to_string()),
+        (
+            "template_op_log_with_parents",
+            Templates::op_log_with_parents().to_string(),
+        ),
+        (
+            "template_change_info",
+            Templates::change_info(DEFAULT_ID_LENGTH),
+        ),
+        ("template_stack", Templates::stack(DEFAULT_ID_LENGTH)),
+        (
+            "template_workspace_list",
+            Templates::workspace_list(DEFAULT_ID_LENGTH),
+        ),
+        (
+            "template_file_annotate",
+            Templates::file_annotate(DEFAULT_ID_LENGTH),
+        ),
+    ] {
+        assert_golden(name, &template_lines(&template));
+    }
+}
+
+fn change(change_id: &str, description: &str, prefix: &str, bookmarks: &[&str]) -> Change {
+    Change {
+        change_id: change_id.to_string().into(),
+        commit_id: format!("{}c0", &change_id[..6]).into(),
+        author: "test@example.com".to_string(),
+        timestamp: "2025-01-15 10:30:00".to_string(),
+        description: description.to_string(),
+        is_working_copy: prefix.starts_with('@'),
+        bookmarks: bookmarks.iter().map(|b| b.to_string()).collect(),
+        graph_prefix: prefix.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn golden_log_view() {
+    let mut view = LogView::new();
+    view.set_changes(vec![
+        change("kxryzmql", "Add user authentication", "@  ", &["main"]),
+        change("mzvwqtsr", "Refactor database layer", "○  ", &[]),
+        change("zzzzzzzz", "", "◆  ", &[]),
+    ]);
+    let screen = render_to_text(80, 8, |frame| view.render(frame, frame.area(), None));
+    assert_golden("log_view", &screen);
+}
+
+fn bookmark(name: &str, remote: Option<&str>, ahead: usize, change_id: &str) -> BookmarkInfo {
+    BookmarkInfo {
+        bookmark: Bookmark {
+            name: name.to_string(),
+            remote: remote.map(str::to_string),
+            is_tracked: remote.is_some(),
+            tracking: (ahead > 0).then_some(TrackingCounts { ahead, behind: 0 }),
+        },
+        change_id: Some(ChangeId::new(change_id.to_string())),
+        commit_id: None,
+        description: Some(format!("Work on {}", name)),
+        timestamp: None,
+    }
+}
+
+#[test]
+fn golden_bookmark_view() {
+    let mut view = BookmarkView::new();
+    view.set_bookmarks(vec![
+        bookmark("main", None, 0, "kxryzmql"),
+        bookmark("feature", None, 2, "mzvwqtsr"),
+        bookmark("main", Some("origin"), 0, "kxryzmql"),
+    ]);
+    let screen = render_to_text(80, 10, |frame| view.render(frame, frame.area(), None));
+    assert_golden("bookmark_view", &screen);
+}
+
+#[test]
+fn golden_operation_view() {
+    let operation = |id: &str, description: &str, is_current: bool| Operation {
+        id: id.to_string(),
+        user: "test@host".to_string(),
+        timestamp: "5 minutes ago".to_string(),
+        started_at: "2025-01-15 10:30:00".to_string(),
+        description: description.to_string(),
+        is_current,
+        ..Default::default()
+    };
+    let mut view = OperationView::new();
+    view.set_operations(vec![
+        operation("abc123def456", "snapshot working copy", true),
+        operation("def456abc123", "describe commit 1a2b3c4d", false),
+    ]);
+    let screen = render_to_text(80, 16, |frame| view.render(frame, frame.area(), None));
+    assert_golden("operation_view", &screen);
+}
@@ -6,7 +6,7 @@
 use insta::assert_snapshot;
 use ratatui::{Terminal, backend::TestBackend};
 
-use tij::ui::components::dialog::{Dialog, DialogCallback, SelectItem};
+use tij::ui::components::dialog::{BatchResult, Dialog, DialogCallback, SelectItem};
 
 #[test]
 fn test_confirm_dialog_basic() {
@@ -141,3 +141,28 @@ fn test_select_single_dialog() {
 
     assert_snapshot!(terminal.backend());
 }
+
+#[test]
+fn test_results_dialog() {
+    let dialog = Dialog::results(
+        "Push Results",
+        "Push to origin",
+        vec![
+            BatchResult::ok("main"),
+            BatchResult::failed(
+                "feature",
+                "Refusing to push a bookmark that unexpectedly moved on the remote",
+            ),
+            BatchResult::ok("docs"),
+        ],
+    );
+
+    let mut terminal = Terminal::new(TestBackend::new(70, 14)).unwrap();
+    terminal
+        .draw(|frame| {
+            dialog.render(frame, frame.area());
+        })
+        .unwrap();
+
+    assert_snapshot!(terminal.backend());
+}
@@ -6,19 +6,32 @@
 use insta::assert_snapshot;
 use ratatui::{Terminal, backend::TestBackend};
 
-use tij::ui::widgets::render_help_panel;
+use tij::app::View;
+use tij::keys::KeymapPreset;
+use tij::ui::widgets::{render_help_overlay, render_help_panel};
 
 #[test]
 fn test_help_panel_full() {
     // Height sized to fit every section without scrolling so the snapshot
     // catches accidental drops of trailing sections when new keys are added.
-    let mut terminal = Terminal::new(TestBackend::new(80, 200)).unwrap();
+    let mut terminal = Terminal::new(TestBackend::new(80, 400)).unwrap();
     terminal
         .draw(|frame| {
-            render_help_panel(frame, frame.area(), 0, None, None);
+            render_help_panel(frame, frame.area(), KeymapPreset::Vim, 0, None, None);
         })
         .unwrap();
 
+    // The last row inside the border stays blank while everything fits
+    let buffer = terminal.backend().buffer();
+    let last_row = buffer.area.height - 2;
+    let text: String = (1..buffer.area.width - 1)
+        .map(|x| buffer[(x, last_row)].symbol())
+        .collect();
+    assert!(
+        text.trim().is_empty(),
+        "help panel no longer fits: raise the height"
+    );
+
     assert_snapshot!(terminal.backend());
 }
 
@@ -28,7 +41,27 @@ fn test_help_panel_narrow() {
     let mut terminal = Terminal::new(TestBackend::new(50, 30)).unwrap();
     terminal
         .draw(|frame| {
-            render_help_panel(frame, frame.area(), 0, None, None);
+            render_help_panel(frame, frame.area(), KeymapPreset::Vim, 0, None, None);
+        })
+        .unwrap();
+
+    assert_snapshot!(terminal.backend());
+}
+
+#[test]
+fn test_help_overlay_lists_context_view_first() {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| {
+            render_help_overlay(
+                frame,
+                frame.area(),
+                Some(View::Diff),
+                KeymapPreset::Vim,
+                0,
+                None,
+                None,
+            );
         })
         .unwrap();
 
@@ -7,6 +7,7 @@ use insta::assert_snapshot;
 use ratatui::{Terminal, backend::TestBackend};
 
 use tij::model::Change;
+use tij::ui::components::LineInput;
 use tij::ui::views::{InputMode, LogView};
 
 /// Helper: create a Change with common defaults
@@ -32,6 +33,10 @@ fn make_change(
         is_graph_only: false,
         has_conflict,
         working_copy_names: Vec::new(),
+        is_immutable: false,
+        is_divergent: false,
+        is_hidden: false,
+        extras: Default::default(),
     }
 }
 
@@ -140,7 +145,7 @@ fn test_log_view_search_input() {
         false,
     )]);
     view.input_mode = InputMode::SearchInput;
-    view.input_buffer = "auth".to_string();
+    view.input_buffer = LineInput::new("auth");
 
     let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
     terminal
@@ -6,7 +6,9 @@
 use insta::assert_snapshot;
 use ratatui::{Terminal, backend::TestBackend};
 
-use tij::model::{FileState, FileStatus, Status};
+use crossterm::event::{KeyCode, KeyEvent};
+use tij::model::{FileState, FileStatus, Status, StatusWarning, StatusWarningKind};
+use tij::ui::components::TextArea;
 use tij::ui::views::{StatusInputMode, StatusView};
 
 #[test]
@@ -31,6 +33,7 @@ fn test_status_view_clean() {
         has_conflicts: false,
         working_copy_change_id: "kxryzmql".to_string().into(),
         parent_change_id: "mzvwqtsr".to_string().into(),
+        warnings: Vec::new(),
     });
 
     let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
@@ -70,6 +73,7 @@ fn test_status_view_with_files() {
         has_conflicts: false,
         working_copy_change_id: "kxryzmql".to_string().into(),
         parent_change_id: "mzvwqtsr".to_string().into(),
+        warnings: Vec::new(),
     });
 
     let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
@@ -103,6 +107,7 @@ fn test_status_view_with_conflicts() {
         has_conflicts: true,
         working_copy_change_id: "kxryzmql".to_string().into(),
         parent_change_id: "mzvwqtsr".to_string().into(),
+        warnings: Vec::new(),
     });
 
     let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
@@ -126,9 +131,76 @@ fn test_status_view_commit_input() {
         has_conflicts: false,
         working_copy_change_id: "kxryzmql".to_string().into(),
         parent_change_id: "mzvwqtsr".to_string().into(),
+        warnings: Vec::new(),
     });
     view.input_mode = StatusInputMode::CommitInput;
-    view.input_buffer = "fix: resolve login bug".to_string();
+    view.commit_editor = TextArea::new("fix: resolve login bug\n\nToken refresh raced the logout.");
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| {
+            view.render(frame, frame.area(), None);
+        })
+        .unwrap();
+
+    assert_snapshot!(terminal.backend());
+}
+
+#[test]
+fn test_status_view_clean_with_warnings() {
+    let mut view = StatusView::new();
+    view.set_status(Status {
+        files: vec![],
+        has_conflicts: false,
+        working_copy_change_id: "kxryzmql".to_string().into(),
+        parent_change_id: "mzvwqtsr".to_string().into(),
+        warnings: vec![
+            StatusWarning {
+                kind: StatusWarningKind::Warning,
+                lines: vec![
+                    "Warning: Refused to snapshot some files:".to_string(),
+                    "  big.bin: 12.0MiB (12582912 bytes)".to_string(),
+                ],
+            },
+            StatusWarning {
+                kind: StatusWarningKind::Hint,
+                lines: vec!["Hint: Add big.bin to .gitignore".to_string()],
+            },
+        ],
+    });
+    view.handle_key(KeyEvent::from(KeyCode::Char('!')));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| {
+            view.render(frame, frame.area(), None);
+        })
+        .unwrap();
+
+    assert_snapshot!(terminal.backend());
+}
+
+#[test]
+fn test_status_view_untracked_and_ignored() {
+    let mut view = StatusView::new();
+    view.set_status(Status {
+        files: vec![
+            FileStatus {
+                path: "src/main.rs".to_string(),
+                state: FileState::Modified,
+            },
+            FileStatus {
+                path: "notes.txt".to_string(),
+                state: FileState::Untracked,
+            },
+        ],
+        has_conflicts: false,
+        working_copy_change_id: "kxryzmql".to_string().into(),
+        parent_change_id: "mzvwqtsr".to_string().into(),
+        warnings: Vec::new(),
+    });
+    view.handle_key(KeyEvent::from(KeyCode::Char('I')));
+    view.set_ignored(vec![".env".to_string(), "target/".to_string()]);
 
     let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
     terminal